@@ -0,0 +1,250 @@
+//! Queries over message definitions, for tools which need to know
+//! the structure of generated code without parsing it.
+//!
+//! Descriptors can be obtained from `protoc --descriptor_set_out --include_imports`
+//! or from `file_descriptor_proto()` functions of generated files.
+//!
+//! Type names are fully qualified names without leading dot, e. g. `foo.bar.Baz`
+//! for message `Baz` in package `foo.bar`, or `foo.bar.Baz.Nested`.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::FileDescriptorSet;
+use protobuf::descriptorx::full_name;
+use protobuf::descriptorx::strip_leading_dot;
+use protobuf::parse_from_bytes;
+use protobuf::ProtobufResult;
+
+
+struct MessageInfo {
+    file: String,
+    map_entry: bool,
+    // fully qualified names of message and enum types of fields
+    field_types: Vec<String>,
+}
+
+/// Index of messages and enums of a set of `.proto` files.
+pub struct Analysis {
+    messages: BTreeMap<String, MessageInfo>,
+    // enum name -> file name
+    enums: BTreeMap<String, String>,
+}
+
+impl Analysis {
+    /// Index given files.
+    ///
+    /// Files must include all dependencies of files being analyzed,
+    /// otherwise types from missing files are not found.
+    pub fn new(files: &[FileDescriptorProto]) -> Analysis {
+        let mut analysis = Analysis {
+            messages: BTreeMap::new(),
+            enums: BTreeMap::new(),
+        };
+        for file in files {
+            for e in file.get_enum_type() {
+                analysis.enums.insert(
+                    full_name(file.get_package(), e.get_name()),
+                    file.get_name().to_owned(),
+                );
+            }
+            for m in file.get_message_type() {
+                analysis.add_message(file.get_name(), file.get_package(), m);
+            }
+        }
+        analysis
+    }
+
+    /// Index files of serialized `FileDescriptorSet`.
+    pub fn from_descriptor_set_bytes(bytes: &[u8]) -> ProtobufResult<Analysis> {
+        let set: FileDescriptorSet = parse_from_bytes(bytes)?;
+        Ok(Analysis::new(set.get_file()))
+    }
+
+    fn add_message(&mut self, file: &str, prefix: &str, message: &DescriptorProto) {
+        let name = full_name(prefix, message.get_name());
+        for e in message.get_enum_type() {
+            self.enums.insert(full_name(&name, e.get_name()), file.to_owned());
+        }
+        for nested in message.get_nested_type() {
+            self.add_message(file, &name, nested);
+        }
+        let field_types = message
+            .get_field()
+            .iter()
+            .filter(|f| !f.get_type_name().is_empty())
+            .map(|f| strip_leading_dot(f.get_type_name()).to_owned())
+            .collect();
+        self.messages.insert(
+            name,
+            MessageInfo {
+                file: file.to_owned(),
+                map_entry: message.get_options().get_map_entry(),
+                field_types: field_types,
+            },
+        );
+    }
+
+    /// All messages except map entries.
+    pub fn messages(&self) -> Vec<&str> {
+        self.messages
+            .iter()
+            .filter(|&(_, m)| !m.map_entry)
+            .map(|(name, _)| &name[..])
+            .collect()
+    }
+
+    /// All enums.
+    pub fn enums(&self) -> Vec<&str> {
+        self.enums.keys().map(|name| &name[..]).collect()
+    }
+
+    /// Name of `.proto` file where message or enum is defined.
+    pub fn file_of(&self, type_name: &str) -> Option<&str> {
+        match self.messages.get(type_name) {
+            Some(m) => Some(&m.file),
+            None => self.enums.get(type_name).map(|f| &f[..]),
+        }
+    }
+
+    // field types of message, including types of values of map fields
+    fn field_types<'a>(&'a self, message: &'a MessageInfo) -> Vec<&'a str> {
+        let mut r = Vec::new();
+        for t in &message.field_types {
+            match self.messages.get(t) {
+                Some(entry) if entry.map_entry => {
+                    r.extend(entry.field_types.iter().map(|t| &t[..]));
+                }
+                _ => r.push(&t[..]),
+            }
+        }
+        r
+    }
+
+    /// Messages which have a field (including repeated and map fields)
+    /// of given message or enum type.
+    pub fn messages_with_field_of_type(&self, type_name: &str) -> Vec<&str> {
+        self.messages
+            .iter()
+            .filter(|&(_, m)| !m.map_entry && self.field_types(m).contains(&type_name))
+            .map(|(name, _)| &name[..])
+            .collect()
+    }
+
+    /// Messages and enums used by fields of given message directly or transitively,
+    /// or `None` if message is not found.
+    ///
+    /// Message itself is included only if it is recursive.
+    pub fn dependency_closure(&self, message: &str) -> Option<Vec<&str>> {
+        let info = match self.messages.get(message) {
+            Some(info) => info,
+            None => return None,
+        };
+
+        let mut closure = BTreeSet::new();
+        let mut queue = self.field_types(info);
+        while let Some(t) = queue.pop() {
+            if !closure.insert(t) {
+                continue;
+            }
+            if let Some(m) = self.messages.get(t) {
+                queue.extend(self.field_types(m));
+            }
+        }
+        Some(closure.into_iter().collect())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use protobuf::descriptor::EnumDescriptorProto;
+    use protobuf::descriptor::FieldDescriptorProto;
+    use protobuf::descriptor::FieldDescriptorProto_Label;
+    use protobuf::descriptor::FieldDescriptorProto_Type;
+
+    fn field(name: &str, type_name: &str) -> FieldDescriptorProto {
+        let mut f = FieldDescriptorProto::new();
+        f.set_name(name.to_owned());
+        if type_name.is_empty() {
+            f.set_field_type(FieldDescriptorProto_Type::TYPE_INT32);
+        } else {
+            f.set_field_type(FieldDescriptorProto_Type::TYPE_MESSAGE);
+            f.set_type_name(type_name.to_owned());
+        }
+        f
+    }
+
+    fn message(name: &str, fields: Vec<FieldDescriptorProto>) -> DescriptorProto {
+        let mut m = DescriptorProto::new();
+        m.set_name(name.to_owned());
+        for f in fields {
+            m.mut_field().push(f);
+        }
+        m
+    }
+
+    fn files() -> Vec<FileDescriptorProto> {
+        let mut a = FileDescriptorProto::new();
+        a.set_name("a.proto".to_owned());
+        a.set_package("p".to_owned());
+        let mut color = EnumDescriptorProto::new();
+        color.set_name("Color".to_owned());
+        a.mut_enum_type().push(color);
+        a.mut_message_type().push(message("Leaf", vec![field("color", ".p.Color")]));
+        a.mut_message_type().push(message("Tree", vec![
+            field("leaf", ".p.Leaf"),
+            field("children", ".p.Tree"),
+        ]));
+
+        let mut b = FileDescriptorProto::new();
+        b.set_name("b.proto".to_owned());
+        let mut entry = message("LeavesEntry", vec![field("key", ""), field("value", ".p.Leaf")]);
+        entry.mut_options().set_map_entry(true);
+        let mut forest = message("Forest", vec![field("trees", ".p.Tree")]);
+        let mut leaves = field("leaves", ".Forest.LeavesEntry");
+        leaves.set_label(FieldDescriptorProto_Label::LABEL_REPEATED);
+        forest.mut_field().push(leaves);
+        forest.mut_nested_type().push(entry);
+        b.mut_message_type().push(forest);
+        b.mut_message_type().push(message("Empty", vec![field("n", "")]));
+
+        vec![a, b]
+    }
+
+    #[test]
+    fn index() {
+        let analysis = Analysis::new(&files());
+        assert_eq!(vec!["Empty", "Forest", "p.Leaf", "p.Tree"], analysis.messages());
+        assert_eq!(vec!["p.Color"], analysis.enums());
+        assert_eq!(Some("b.proto"), analysis.file_of("Forest"));
+        assert_eq!(Some("a.proto"), analysis.file_of("p.Color"));
+        assert_eq!(None, analysis.file_of("p.Forest"));
+    }
+
+    #[test]
+    fn with_field_of_type() {
+        let analysis = Analysis::new(&files());
+        assert_eq!(vec!["Forest", "p.Tree"], analysis.messages_with_field_of_type("p.Leaf"));
+        assert_eq!(vec!["p.Leaf"], analysis.messages_with_field_of_type("p.Color"));
+        assert!(analysis.messages_with_field_of_type("Empty").is_empty());
+    }
+
+    #[test]
+    fn closure() {
+        let analysis = Analysis::new(&files());
+        assert_eq!(
+            Some(vec!["p.Color", "p.Leaf", "p.Tree"]),
+            analysis.dependency_closure("Forest")
+        );
+        assert_eq!(
+            Some(vec!["p.Color", "p.Leaf", "p.Tree"]),
+            analysis.dependency_closure("p.Tree")
+        );
+        assert_eq!(Some(vec![]), analysis.dependency_closure("Empty"));
+        assert_eq!(None, analysis.dependency_closure("Missing"));
+    }
+}
@@ -0,0 +1,32 @@
+extern crate protobuf_codegen;
+
+use std::env;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+
+use protobuf_codegen::bootstrap;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    // repository root, parent of protobuf-codegen directory by default
+    let root = match args.len() {
+        1 => Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap().to_owned(),
+        2 => PathBuf::from(&args[1]),
+        _ => panic!("usage: protobuf-bootstrap [repository-root]"),
+    };
+
+    match bootstrap::regenerate(&root) {
+        Ok(ref changed) if changed.is_empty() => println!("generated code is up to date"),
+        Ok(changed) => {
+            for path in changed {
+                println!("updated {}", path.display());
+            }
+            println!("rebuild and run again until nothing changes");
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
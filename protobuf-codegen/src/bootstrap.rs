@@ -0,0 +1,224 @@
+//! Regeneration of code which protobuf crate itself contains:
+//! `descriptor.rs`, `plugin.rs`, `rustproto.rs` and well-known types.
+//!
+//! Code generator uses these files, so after `.proto` sources in `proto` directory
+//! of the repository are changed (e. g. new option is added to `descriptor.proto`
+//! or `rustproto.proto`), regeneration must be repeated until generated code
+//! no longer changes:
+//!
+//! ```text
+//! cargo run --manifest-path protobuf-codegen/Cargo.toml --bin protobuf-bootstrap
+//! ```
+//!
+//! The command runs `protoc` (`$PROTOC` or `protoc` from `$PATH`, version 3)
+//! to parse `.proto` files, generates code in process, writes files which changed,
+//! and prints their names. Each run uses generator built from the current sources,
+//! so when files changed the command should be run again.
+//! When code generator reads new `rustproto` option, the code reading it can only
+//! be compiled after the first run generated the option in `rustproto.rs`.
+
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process;
+use std::process::Command;
+
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::FileDescriptorSet;
+use protobuf::parse_from_bytes;
+
+use customize::Customize;
+
+
+/// `.proto` files relative to `proto` directory of the repository.
+pub const PROTO_FILES: &[&str] = &[
+    "google/protobuf/any.proto",
+    "google/protobuf/api.proto",
+    "google/protobuf/compiler/plugin.proto",
+    "google/protobuf/descriptor.proto",
+    "google/protobuf/duration.proto",
+    "google/protobuf/empty.proto",
+    "google/protobuf/field_mask.proto",
+    "google/protobuf/source_context.proto",
+    "google/protobuf/struct.proto",
+    "google/protobuf/timestamp.proto",
+    "google/protobuf/type.proto",
+    "google/protobuf/wrappers.proto",
+    "rustproto.proto",
+];
+
+// generated files which are not well-known types
+const PROTOBUF_SRC_FILES: &[&str] = &["descriptor.rs", "plugin.rs", "rustproto.rs"];
+
+fn invalid_data<E : ToString>(e: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+/// Parse `.proto` files with `protoc`.
+///
+/// Source info is included, so generated code contains comments.
+pub fn parse_with_protoc(proto_dir: &Path, files: &[&str]) -> io::Result<Vec<FileDescriptorProto>> {
+    let protoc = env::var_os("PROTOC").unwrap_or_else(|| "protoc".into());
+    let descriptor_set = env::temp_dir().join(format!("protobuf-bootstrap-{}.pb", process::id()));
+
+    let status = Command::new(&protoc)
+        .current_dir(proto_dir)
+        .arg("-I.")
+        .arg("--include_imports")
+        .arg("--include_source_info")
+        .arg(format!("--descriptor_set_out={}", descriptor_set.display()))
+        .args(files)
+        .status()?;
+    if !status.success() {
+        return Err(io::Error::new(io::ErrorKind::Other, format!("protoc failed: {}", status)));
+    }
+
+    let mut bytes = Vec::new();
+    let read = File::open(&descriptor_set).and_then(|mut f| f.read_to_end(&mut bytes));
+    let _ = fs::remove_file(&descriptor_set);
+    read?;
+    let set: FileDescriptorSet = parse_from_bytes(&bytes).map_err(invalid_data)?;
+    Ok(set.get_file().to_vec())
+}
+
+/// Path of generated file relative to repository root.
+pub fn output_path(generated_name: &str) -> PathBuf {
+    let mut path = PathBuf::from("protobuf/src");
+    if !PROTOBUF_SRC_FILES.contains(&generated_name) {
+        path.push("well_known_types");
+    }
+    path.push(generated_name);
+    path
+}
+
+// `mod.rs` of `well_known_types` module
+fn well_known_types_mod_rs(generated_names: &[String]) -> Vec<u8> {
+    let mut mods: Vec<&str> = generated_names
+        .iter()
+        .filter(|name| !PROTOBUF_SRC_FILES.contains(&name.as_str()))
+        .map(|name| name.trim_end_matches(".rs"))
+        .collect();
+    mods.sort();
+
+    let mut r = String::new();
+    r.push_str("// This file is generated. Do not edit\n");
+    r.push_str("\n");
+    for m in &mods {
+        r.push_str(&format!("mod {};\n", m));
+    }
+    r.push_str("\n");
+    for m in &mods {
+        r.push_str(&format!("pub use self::{}::*;\n", m));
+    }
+    r.into_bytes()
+}
+
+/// Generate files from descriptors of bundled `.proto` files.
+///
+/// Returns paths relative to repository root and contents of files,
+/// including `mod.rs` of well-known types.
+pub fn generate(file_descriptors: &[FileDescriptorProto], files_to_generate: &[String])
+    -> Vec<(PathBuf, Vec<u8>)>
+{
+    let results = ::gen(file_descriptors, files_to_generate, &Customize::default());
+    let names: Vec<String> = results.iter().map(|r| r.name.clone()).collect();
+    let mut files: Vec<(PathBuf, Vec<u8>)> =
+        results.into_iter().map(|r| (output_path(&r.name), r.content)).collect();
+    if names.iter().any(|name| !PROTOBUF_SRC_FILES.contains(&name.as_str())) {
+        files.push((output_path("mod.rs"), well_known_types_mod_rs(&names)));
+    }
+    files
+}
+
+/// Write files which content differs from generated, return their paths.
+pub fn write_changed(root: &Path, files: &[(PathBuf, Vec<u8>)]) -> io::Result<Vec<PathBuf>> {
+    let mut changed = Vec::new();
+    for &(ref path, ref content) in files {
+        let full_path = root.join(path);
+        let mut old = Vec::new();
+        if let Ok(mut f) = File::open(&full_path) {
+            f.read_to_end(&mut old)?;
+        }
+        if &old != content {
+            File::create(&full_path)?.write_all(content)?;
+            changed.push(path.clone());
+        }
+    }
+    Ok(changed)
+}
+
+/// Parse `.proto` files in `proto` directory of repository at `root`,
+/// regenerate code and write changed files, return their paths.
+pub fn regenerate(root: &Path) -> io::Result<Vec<PathBuf>> {
+    let file_descriptors = parse_with_protoc(&root.join("proto"), PROTO_FILES)?;
+    let files_to_generate: Vec<String> = PROTO_FILES.iter().map(|f| f.to_string()).collect();
+    ::validate::validate(&file_descriptors, &files_to_generate, &Customize::default())
+        .map_err(invalid_data)?;
+    let files = generate(&file_descriptors, &files_to_generate);
+    write_changed(root, &files)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use protobuf::descriptor;
+    use protobuf::plugin;
+    use protobuf::rustproto;
+
+    #[test]
+    fn paths() {
+        assert_eq!(PathBuf::from("protobuf/src/descriptor.rs"), output_path("descriptor.rs"));
+        assert_eq!(
+            PathBuf::from("protobuf/src/well_known_types/struct_pb.rs"),
+            output_path("struct_pb.rs")
+        );
+    }
+
+    #[test]
+    fn mod_rs() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let names: Vec<String> = fs::read_dir(root.join("protobuf/src/well_known_types"))
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .filter(|name| name != "mod.rs")
+            .chain(PROTOBUF_SRC_FILES.iter().map(|name| name.to_string()))
+            .collect();
+        let mut expected = Vec::new();
+        File::open(root.join("protobuf/src/well_known_types/mod.rs"))
+            .unwrap()
+            .read_to_end(&mut expected)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(expected).unwrap(),
+            String::from_utf8(well_known_types_mod_rs(&names)).unwrap()
+        );
+    }
+
+    // checked-in files are generated by current code generator
+    // from descriptors embedded into them
+    #[test]
+    fn up_to_date() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let file_descriptors = vec![
+            descriptor::file_descriptor_proto().clone(),
+            plugin::file_descriptor_proto().clone(),
+            rustproto::file_descriptor_proto().clone(),
+        ];
+        let files_to_generate: Vec<String> =
+            file_descriptors.iter().map(|f| f.get_name().to_owned()).collect();
+        let files = generate(&file_descriptors, &files_to_generate);
+        assert_eq!(3, files.len());
+        for &(ref path, ref content) in &files {
+            let mut checked_in = Vec::new();
+            File::open(root.join(path)).unwrap().read_to_end(&mut checked_in).unwrap();
+            assert!(&checked_in == content, "{} is not up to date", path.display());
+        }
+    }
+}
@@ -1,20 +1,68 @@
-// TODO: used by grpc-rust, should move it into separate crate.
-#![doc(hidden)]
+//! Writer of indented Rust code used by code generator.
+//!
+//! Plugins generating code next to protobuf-codegen output (e. g. service stubs)
+//! can use it so generated code is formatted the same way:
+//!
+//! ```
+//! use protobuf_codegen::code_writer::CodeWriter;
+//!
+//! let mut out = Vec::new();
+//! {
+//!     let mut w = CodeWriter::new(&mut out);
+//!     w.doc_comment("Client of `Greeter` service.");
+//!     w.derive(&["Clone"]);
+//!     w.pub_struct("GreeterClient", |w| {
+//!         w.field_decl("channel", "Channel");
+//!     });
+//!     w.write_line("");
+//!     w.cfg("feature = \"async\"");
+//!     w.impl_self_block("GreeterClient", |w| {
+//!         w.pub_fn("new(channel: Channel) -> GreeterClient", |w| {
+//!             w.write_line("GreeterClient { channel: channel }");
+//!         });
+//!     });
+//! }
+//! assert_eq!(
+//!     "\
+//! /// Client of `Greeter` service.
+//! #[derive(Clone)]
+//! pub struct GreeterClient {
+//!     channel: Channel,
+//! }
+//!
+//! #[cfg(feature = \"async\")]
+//! impl GreeterClient {
+//!     pub fn new(channel: Channel) -> GreeterClient {
+//!         GreeterClient { channel: channel }
+//!     }
+//! }
+//! ",
+//!     String::from_utf8(out).unwrap()
+//! );
+//! ```
+//!
+//! Block helpers take a callback which writes block contents
+//! with one more level of indentation. Arguments are inserted as is,
+//! so they must be valid Rust code.
+//!
+//! Writer panics if underlying writer returns an error.
 
 use std::io::Write;
 
-/// Field visibility.
+/// Field visibility, see `field_decl_vis`.
 pub enum Visibility {
     Public,
     Default,
 }
 
+/// Writer of Rust code with current indentation.
 pub struct CodeWriter<'a> {
     writer: &'a mut (Write + 'a),
     indent: String,
 }
 
 impl<'a> CodeWriter<'a> {
+    /// Writer with no indentation.
     pub fn new(writer: &'a mut Write) -> CodeWriter<'a> {
         CodeWriter {
             writer: writer,
@@ -22,6 +70,7 @@ impl<'a> CodeWriter<'a> {
         }
     }
 
+    /// Write line with current indentation, empty line is written without indentation.
     pub fn write_line<S : AsRef<str>>(&mut self, line: S) {
         (if line.as_ref().is_empty() {
              self.writer.write_all("\n".as_bytes())
@@ -31,11 +80,13 @@ impl<'a> CodeWriter<'a> {
          }).unwrap();
     }
 
+    /// Write header of generated file: comments and `#![allow(...)]` attributes.
     pub fn write_generated(&mut self) {
         self.write_line("// This file is generated. Do not edit");
         self.write_generated_common();
     }
 
+    /// Write header of generated file mentioning generator package and version.
     pub fn write_generated_by(&mut self, pkg: &str, version: &str) {
         self.write_line(format!(
             "// This file is generated by {pkg} {version}. Do not edit",
@@ -68,14 +119,17 @@ impl<'a> CodeWriter<'a> {
         self.write_line("#![allow(unused_results)]");
     }
 
+    /// Write `panic!("TODO: ...")` statement.
     pub fn todo(&mut self, message: &str) {
         self.write_line(format!("panic!(\"TODO: {}\");", message));
     }
 
+    /// Write `unimplemented!()` statement.
     pub fn unimplemented(&mut self) {
         self.write_line(format!("unimplemented!();"));
     }
 
+    /// Call `cb` with writer with one more level of indentation.
     pub fn indented<F>(&mut self, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -86,7 +140,7 @@ impl<'a> CodeWriter<'a> {
         });
     }
 
-    #[allow(dead_code)]
+    /// Call `cb` with writer which comments out each line.
     pub fn commented<F>(&mut self, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -97,41 +151,36 @@ impl<'a> CodeWriter<'a> {
         });
     }
 
+    /// `pub const name: field_type = init;`
     pub fn pub_const(&mut self, name: &str, field_type: &str, init: &str) {
         self.write_line(&format!("pub const {}: {} = {};", name, field_type, init));
     }
 
+    /// `static` of `::protobuf::lazy::LazyV2` type.
     pub fn lazy_static(&mut self, name: &str, ty: &str) {
-        self.stmt_block(
-            &format!(
-                "static mut {}: ::protobuf::lazy::Lazy<{}> = ::protobuf::lazy::Lazy",
-                name,
-                ty
-            ),
-            |w| {
-                w.field_entry("lock", "::protobuf::lazy::ONCE_INIT");
-                w.field_entry("ptr", &format!("0 as *const {}", ty));
-            },
-        );
+        self.write_line(&format!(
+            "static {}: ::protobuf::lazy::LazyV2<{}> = ::protobuf::lazy::LazyV2::INIT;",
+            name,
+            ty
+        ));
     }
 
+    /// `LazyV2` static and expression getting it initialized with `init` block.
     pub fn lazy_static_decl_get<F>(&mut self, name: &str, ty: &str, init: F)
     where
         F : Fn(&mut CodeWriter),
     {
         self.lazy_static(name, ty);
-        self.unsafe_expr(|w| {
-            w.write_line(&format!("{}.get(|| {{", name));
-            w.indented(|w| init(w));
-            w.write_line(&format!("}})"));
-        });
+        self.block(&format!("{}.get(|| {{", name), "})", init);
     }
 
+    /// `LazyV2` static and expression getting it initialized with `init` function.
     pub fn lazy_static_decl_get_simple(&mut self, name: &str, ty: &str, init: &str) {
         self.lazy_static(name, ty);
-        self.unsafe_expr(|w| { w.write_line(&format!("{}.get({})", name, init)); });
+        self.write_line(&format!("{}.get({})", name, init));
     }
 
+    /// Indented block between `first_line` and `last_line`.
     pub fn block<F>(&mut self, first_line: &str, last_line: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -141,6 +190,7 @@ impl<'a> CodeWriter<'a> {
         self.write_line(last_line);
     }
 
+    /// `prefix { ... }`
     pub fn expr_block<F>(&mut self, prefix: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -148,6 +198,7 @@ impl<'a> CodeWriter<'a> {
         self.block(&format!("{} {{", prefix), "}", cb);
     }
 
+    /// `prefix { ... };`
     pub fn stmt_block<S : AsRef<str>, F>(&mut self, prefix: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -155,6 +206,7 @@ impl<'a> CodeWriter<'a> {
         self.block(&format!("{} {{", prefix.as_ref()), "};", cb);
     }
 
+    /// `unsafe { ... }`
     pub fn unsafe_expr<F>(&mut self, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -162,6 +214,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block("unsafe", cb);
     }
 
+    /// `impl name { ... }`
     pub fn impl_self_block<S : AsRef<str>, F>(&mut self, name: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -169,6 +222,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("impl {}", name.as_ref()), cb);
     }
 
+    /// `impl tr for ty { ... }`
     pub fn impl_for_block<S1 : AsRef<str>, S2 : AsRef<str>, F>(&mut self, tr: S1, ty: S2, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -176,10 +230,12 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("impl {} for {}", tr.as_ref(), ty.as_ref()), cb);
     }
 
+    /// `unsafe impl what for for_what {}`
     pub fn unsafe_impl(&mut self, what: &str, for_what: &str) {
         self.write_line(&format!("unsafe impl {} for {} {{}}", what, for_what));
     }
 
+    /// `pub struct name { ... }`
     pub fn pub_struct<S : AsRef<str>, F>(&mut self, name: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -187,6 +243,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("pub struct {}", name.as_ref()), cb);
     }
 
+    /// `struct name { ... }`
     pub fn def_struct<S : AsRef<str>, F>(&mut self, name: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -194,6 +251,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("struct {}", name.as_ref()), cb);
     }
 
+    /// `pub enum name { ... }`
     pub fn pub_enum<F>(&mut self, name: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -201,6 +259,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("pub enum {}", name), cb);
     }
 
+    /// `pub trait name { ... }`
     pub fn pub_trait<F>(&mut self, name: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -208,6 +267,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("pub trait {}", name), cb);
     }
 
+    /// `pub trait name : extend { ... }`
     pub fn pub_trait_extend<F>(&mut self, name: &str, extend: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -215,18 +275,22 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("pub trait {} : {}", name, extend), cb);
     }
 
+    /// `name: value,` in struct expression.
     pub fn field_entry(&mut self, name: &str, value: &str) {
         self.write_line(&format!("{}: {},", name, value));
     }
 
+    /// `name: field_type,` in struct declaration.
     pub fn field_decl(&mut self, name: &str, field_type: &str) {
         self.write_line(&format!("{}: {},", name, field_type));
     }
 
+    /// `pub name: field_type,` in struct declaration.
     pub fn pub_field_decl(&mut self, name: &str, field_type: &str) {
         self.write_line(&format!("pub {}: {},", name, field_type));
     }
 
+    /// Field declaration with given visibility.
     pub fn field_decl_vis(&mut self, vis: Visibility, name: &str, field_type: &str) {
         match vis {
             Visibility::Public => self.pub_field_decl(name, field_type),
@@ -234,16 +298,61 @@ impl<'a> CodeWriter<'a> {
         }
     }
 
+    /// `#[derive(...)]`
     pub fn derive(&mut self, derive: &[&str]) {
         let v: Vec<String> = derive.iter().map(|&s| s.to_string()).collect();
         self.write_line(&format!("#[derive({})]", v.join(",")));
     }
 
+    /// `#[allow(...)]`
     pub fn allow(&mut self, what: &[&str]) {
         let v: Vec<String> = what.iter().map(|&s| s.to_string()).collect();
         self.write_line(&format!("#[allow({})]", v.join(",")));
     }
 
+    /// `#[attr]`, e. g. `w.attr("inline")`.
+    pub fn attr(&mut self, attr: &str) {
+        self.write_line(&format!("#[{}]", attr));
+    }
+
+    /// `#[cfg(cond)]`
+    pub fn cfg(&mut self, cond: &str) {
+        self.write_line(&format!("#[cfg({})]", cond));
+    }
+
+    /// `#[cfg_attr(cond, attr)]`
+    pub fn cfg_attr(&mut self, cond: &str, attr: &str) {
+        self.write_line(&format!("#[cfg_attr({}, {})]", cond, attr));
+    }
+
+    /// `/// doc` line for each line of `doc`.
+    pub fn doc_comment(&mut self, doc: &str) {
+        for line in doc.lines() {
+            if line.is_empty() {
+                self.write_line("///");
+            } else {
+                self.write_line(&format!("/// {}", line));
+            }
+        }
+    }
+
+    /// Write each line of `text` with current indentation,
+    /// e. g. code section copied from template.
+    pub fn write_raw(&mut self, text: &str) {
+        for line in text.lines() {
+            self.write_line(line);
+        }
+    }
+
+    /// `// @@protoc_insertion_point(name)` marker.
+    ///
+    /// `protoc` plugins invoked after this generator can insert code before
+    /// the marker by returning file with the same name and `insertion_point`.
+    pub fn insertion_point(&mut self, name: &str) {
+        self.write_line(&format!("// @@protoc_insertion_point({})", name));
+    }
+
+    /// `// comment`, or `//` if comment is empty.
     pub fn comment(&mut self, comment: &str) {
         if comment.is_empty() {
             self.write_line("//");
@@ -252,10 +361,12 @@ impl<'a> CodeWriter<'a> {
         }
     }
 
+    /// `fn sig;` declaration in trait.
     pub fn fn_def(&mut self, sig: &str) {
         self.write_line(&format!("fn {};", sig));
     }
 
+    /// `fn sig { ... }`, `pub` if `public`.
     pub fn fn_block<F>(&mut self, public: bool, sig: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -267,6 +378,7 @@ impl<'a> CodeWriter<'a> {
         }
     }
 
+    /// `pub fn sig { ... }`
     pub fn pub_fn<F>(&mut self, sig: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -274,6 +386,7 @@ impl<'a> CodeWriter<'a> {
         self.fn_block(true, sig, cb);
     }
 
+    /// `fn sig { ... }`
     pub fn def_fn<F>(&mut self, sig: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -281,6 +394,7 @@ impl<'a> CodeWriter<'a> {
         self.fn_block(false, sig, cb);
     }
 
+    /// `mod name { ... }`
     pub fn def_mod<F>(&mut self, name: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -288,6 +402,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("mod {}", name), cb)
     }
 
+    /// `pub mod name { ... }`
     pub fn pub_mod<F>(&mut self, name: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -295,6 +410,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("pub mod {}", name), cb)
     }
 
+    /// `while cond { ... }`
     pub fn while_block<S : AsRef<str>, F>(&mut self, cond: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -302,7 +418,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("while {}", cond.as_ref()), cb);
     }
 
-    // if ... { ... }
+    /// `if cond { ... }`
     pub fn if_stmt<S : AsRef<str>, F>(&mut self, cond: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -310,7 +426,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("if {}", cond.as_ref()), cb);
     }
 
-    // if ... {} else { ... }
+    /// `if cond {} else { ... }`
     pub fn if_else_stmt<S : AsRef<str>, F>(&mut self, cond: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -321,7 +437,7 @@ impl<'a> CodeWriter<'a> {
         self.write_line("}");
     }
 
-    // if let ... = ... { ... }
+    /// `if let decl = expr { ... }`
     pub fn if_let_stmt<F>(&mut self, decl: &str, expr: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -329,7 +445,7 @@ impl<'a> CodeWriter<'a> {
         self.if_stmt(&format!("let {} = {}", decl, expr), cb);
     }
 
-    // if let ... = ... { } else { ... }
+    /// `if let decl = expr {} else { ... }`
     pub fn if_let_else_stmt<F>(&mut self, decl: &str, expr: &str, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -337,6 +453,7 @@ impl<'a> CodeWriter<'a> {
         self.if_else_stmt(&format!("let {} = {}", decl, expr), cb);
     }
 
+    /// `for varn in over { ... };`
     pub fn for_stmt<S1 : AsRef<str>, S2 : AsRef<str>, F>(&mut self, over: S1, varn: S2, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -344,6 +461,7 @@ impl<'a> CodeWriter<'a> {
         self.stmt_block(&format!("for {} in {}", varn.as_ref(), over.as_ref()), cb)
     }
 
+    /// `match value { ... };`
     pub fn match_block<S : AsRef<str>, F>(&mut self, value: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -351,6 +469,7 @@ impl<'a> CodeWriter<'a> {
         self.stmt_block(&format!("match {}", value.as_ref()), cb);
     }
 
+    /// `match value { ... }`
     pub fn match_expr<S : AsRef<str>, F>(&mut self, value: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -358,6 +477,7 @@ impl<'a> CodeWriter<'a> {
         self.expr_block(&format!("match {}", value.as_ref()), cb);
     }
 
+    /// `cond => { ... },` in match.
     pub fn case_block<S : AsRef<str>, F>(&mut self, cond: S, cb: F)
     where
         F : Fn(&mut CodeWriter),
@@ -365,7 +485,45 @@ impl<'a> CodeWriter<'a> {
         self.block(&format!("{} => {{", cond.as_ref()), "},", cb);
     }
 
+    /// `cond => body,` in match.
     pub fn case_expr<S1 : AsRef<str>, S2 : AsRef<str>>(&mut self, cond: S1, body: S2) {
         self.write_line(&format!("{} => {},", cond.as_ref(), body.as_ref()));
     }
 }
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn write<F : Fn(&mut CodeWriter)>(cb: F) -> String {
+        let mut out = Vec::new();
+        cb(&mut CodeWriter::new(&mut out));
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn attributes() {
+        let text = write(|w| {
+            w.doc_comment("First.\n\nSecond.");
+            w.attr("inline");
+            w.cfg_attr("feature = \"serde\"", "derive(Serialize)");
+        });
+        assert_eq!(
+            "/// First.\n///\n/// Second.\n\
+             #[inline]\n\
+             #[cfg_attr(feature = \"serde\", derive(Serialize))]\n",
+            text
+        );
+    }
+
+    #[test]
+    fn raw() {
+        let text = write(|w| {
+            w.def_mod("m", |w| {
+                w.write_raw("fn f() {}\n\nfn g() {}\n");
+            });
+        });
+        assert_eq!("mod m {\n    fn f() {}\n\n    fn g() {}\n}\n", text);
+    }
+}
@@ -1,7 +1,12 @@
+use std::error::Error;
+use std::fmt;
+
 use protobuf::rustproto;
 use protobuf::descriptor::FieldOptions;
 use protobuf::descriptor::MessageOptions;
 use protobuf::descriptor::FileOptions;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptor::FileOptions_OptimizeMode;
 
 
 /// Specifies style of generated code.
@@ -17,6 +22,83 @@ pub struct Customize {
     pub carllerche_bytes_for_bytes: Option<bool>,
     /// Use `bytes::Bytes` for `string` fields
     pub carllerche_bytes_for_string: Option<bool>,
+    /// Map proto packages to nested Rust modules and generate `mod.rs` files.
+    /// Can only be specified in invocation, not in `.proto` file.
+    pub package_modules: Option<bool>,
+    /// Generate shims with rust-protobuf 3 names (e. g. `foo()` for `get_foo()`)
+    /// to simplify migration. Can only be specified in invocation.
+    pub v3_compat: Option<bool>,
+    /// Generate each top-level message into its own file to reduce compilation time
+    /// of large `.proto` files. Can only be specified in invocation.
+    ///
+    /// Generated file declares `pub mod` for each message file, so it must be
+    /// included with `mod` declaration, not with `include!`.
+    pub split_messages: Option<bool>,
+    /// When false, file descriptor data is not embedded into generated code,
+    /// and reflection is not available, same as with `optimize_for = LITE_RUNTIME`.
+    /// Can only be specified in invocation.
+    pub embed_descriptor: Option<bool>,
+    /// Do not embed `SourceCodeInfo` (comments and locations) into file descriptor data.
+    /// Can only be specified in invocation.
+    pub strip_source_code_info: Option<bool>,
+    /// Store embedded file descriptor data deflate-compressed.
+    /// Generated code then requires `with-deflate` feature of protobuf crate.
+    /// Can only be specified in invocation.
+    pub compress_descriptor: Option<bool>,
+    /// Store singular message fields serialized after parsing,
+    /// and decode them on first access (see `protobuf::LazyMessageField`).
+    pub lazy_field: Option<bool>,
+    /// Store singular message fields as `Option<Box<T>>` instead of `SingularPtrField<T>`,
+    /// so fields have standard types. Ignored for fields with `lazy_field` option.
+    /// `clear` drops messages of such fields instead of keeping them for reuse.
+    pub option_box_for_messages: Option<bool>,
+    /// Store repeated fields as `Vec<T>` instead of `RepeatedField<T>`.
+    /// `RepeatedField` keeps cleared elements to reuse their allocations
+    /// when message is parsed again, which is not needed in most programs.
+    pub repeated_field_vec: Option<bool>,
+    /// Store repeated numeric and `bool` fields as `SmallVec<[T; N]>` with given `N`,
+    /// so up to `N` elements are stored without heap allocation. `0` disables the option.
+    /// Generated code then requires `with-smallvec` feature of protobuf crate.
+    pub smallvec_for_repeated: Option<u32>,
+    /// Do not generate `set_` accessors taking value by value for fields which have
+    /// `mut_` accessor (`bytes`, `string`, message, repeated and map fields),
+    /// so large values are only accessed by reference or moved with `take_`.
+    pub ref_accessors_only: Option<bool>,
+    /// Generate `json_names` module with `&'static str` constants containing
+    /// JSON names of message fields, e. g. `json_names::Person::FIRST_NAME`
+    /// is `"firstName"`.
+    pub json_names: Option<bool>,
+    /// Generate `Display` implementation printing message in short text format,
+    /// so messages can be passed to code which takes `T: Display`.
+    /// Ignored for `LITE_RUNTIME`, because text format requires reflection.
+    pub generate_display: Option<bool>,
+    /// Store proto2 optional numeric and `bool` fields as plain values,
+    /// and their presence in bitset shared by all such fields of the message,
+    /// like C++ implementation does. Reduces message size and makes `clear` faster.
+    /// Ignored for fields without accessors or with public fields.
+    pub has_bits: Option<bool>,
+    /// Generate `FooVisitor` trait for each message `Foo` and `Foo::accept` method
+    /// calling visitor for each populated field, descending into messages of the same file.
+    /// Can be specified for file or in invocation.
+    pub generate_visitor: Option<bool>,
+    /// Generate `From<Foo> for Vec<u8>` and `From<&Foo> for Vec<u8>` implementations
+    /// serializing message with `Message::to_bytes`, so messages can be passed to code
+    /// which takes `T: Into<Vec<u8>>`.
+    pub generate_into_bytes: Option<bool>,
+    /// Generate `arbitrary::Arbitrary` implementations for messages, so fuzz targets
+    /// can take structured messages instead of bytes. Nested messages must be generated
+    /// with this option too. Generated code then requires `with-arbitrary` feature
+    /// of protobuf crate.
+    pub generate_arbitrary: Option<bool>,
+    /// Generate proptest `Arbitrary` implementations and `arbitrary_foo()` strategy
+    /// functions for messages, for property-based tests of generated types.
+    /// Nested messages must be generated with this option too. Generated code
+    /// then requires `with-proptest` feature of protobuf crate.
+    pub generate_proptest: Option<bool>,
+    /// Write `foo.report.json` next to `foo.rs` with JSON summary of generated
+    /// messages: field counts, derived traits and enabled options.
+    /// Can only be specified in invocation.
+    pub gen_report: Option<bool>,
 }
 
 impl Customize {
@@ -37,6 +119,63 @@ impl Customize {
         if let Some(v) = that.carllerche_bytes_for_string {
             self.carllerche_bytes_for_string = Some(v);
         }
+        if let Some(v) = that.package_modules {
+            self.package_modules = Some(v);
+        }
+        if let Some(v) = that.v3_compat {
+            self.v3_compat = Some(v);
+        }
+        if let Some(v) = that.split_messages {
+            self.split_messages = Some(v);
+        }
+        if let Some(v) = that.embed_descriptor {
+            self.embed_descriptor = Some(v);
+        }
+        if let Some(v) = that.strip_source_code_info {
+            self.strip_source_code_info = Some(v);
+        }
+        if let Some(v) = that.compress_descriptor {
+            self.compress_descriptor = Some(v);
+        }
+        if let Some(v) = that.lazy_field {
+            self.lazy_field = Some(v);
+        }
+        if let Some(v) = that.option_box_for_messages {
+            self.option_box_for_messages = Some(v);
+        }
+        if let Some(v) = that.repeated_field_vec {
+            self.repeated_field_vec = Some(v);
+        }
+        if let Some(v) = that.smallvec_for_repeated {
+            self.smallvec_for_repeated = Some(v);
+        }
+        if let Some(v) = that.ref_accessors_only {
+            self.ref_accessors_only = Some(v);
+        }
+        if let Some(v) = that.json_names {
+            self.json_names = Some(v);
+        }
+        if let Some(v) = that.generate_display {
+            self.generate_display = Some(v);
+        }
+        if let Some(v) = that.has_bits {
+            self.has_bits = Some(v);
+        }
+        if let Some(v) = that.generate_visitor {
+            self.generate_visitor = Some(v);
+        }
+        if let Some(v) = that.generate_into_bytes {
+            self.generate_into_bytes = Some(v);
+        }
+        if let Some(v) = that.generate_arbitrary {
+            self.generate_arbitrary = Some(v);
+        }
+        if let Some(v) = that.generate_proptest {
+            self.generate_proptest = Some(v);
+        }
+        if let Some(v) = that.gen_report {
+            self.gen_report = Some(v);
+        }
     }
 
     /// Update unset fields of self with fields from other customize
@@ -45,6 +184,104 @@ impl Customize {
         tmp.update_with(self);
         *self = tmp;
     }
+
+    /// Parse options from `protoc` plugin parameter, e. g. `expose_fields,lazy_field=false`
+    /// for `protoc --rust_out=expose_fields,lazy_field=false:out`.
+    ///
+    /// Parameter is a comma-separated list of `name=value` pairs, where name is
+    /// the name of `Customize` field. `name` without value means `name=true`.
+    pub fn parse_from_parameter(parameter: &str)
+        -> Result<Customize, CustomizeParseParameterError>
+    {
+        let mut r = Customize::default();
+        for nv in parameter.split(',').filter(|nv| !nv.is_empty()) {
+            let (name, value) = match nv.find('=') {
+                Some(pos) => (&nv[..pos], &nv[pos + 1..]),
+                None => (nv, "true"),
+            };
+            let invalid_value = || CustomizeParseParameterError::InvalidValue {
+                name: name.to_owned(),
+                value: value.to_owned(),
+            };
+            let field = match name {
+                "expose_oneof" => &mut r.expose_oneof,
+                "expose_fields" => &mut r.expose_fields,
+                "generate_accessors" => &mut r.generate_accessors,
+                "carllerche_bytes_for_bytes" => &mut r.carllerche_bytes_for_bytes,
+                "carllerche_bytes_for_string" => &mut r.carllerche_bytes_for_string,
+                "package_modules" => &mut r.package_modules,
+                "v3_compat" => &mut r.v3_compat,
+                "split_messages" => &mut r.split_messages,
+                "embed_descriptor" => &mut r.embed_descriptor,
+                "strip_source_code_info" => &mut r.strip_source_code_info,
+                "compress_descriptor" => &mut r.compress_descriptor,
+                "lazy_field" => &mut r.lazy_field,
+                "option_box_for_messages" => &mut r.option_box_for_messages,
+                "repeated_field_vec" => &mut r.repeated_field_vec,
+                "ref_accessors_only" => &mut r.ref_accessors_only,
+                "json_names" => &mut r.json_names,
+                "generate_display" => &mut r.generate_display,
+                "has_bits" => &mut r.has_bits,
+                "generate_visitor" => &mut r.generate_visitor,
+                "generate_into_bytes" => &mut r.generate_into_bytes,
+                "generate_arbitrary" => &mut r.generate_arbitrary,
+                "generate_proptest" => &mut r.generate_proptest,
+                "gen_report" => &mut r.gen_report,
+                "smallvec_for_repeated" => {
+                    r.smallvec_for_repeated = Some(value.parse().map_err(|_| invalid_value())?);
+                    continue;
+                }
+                _ => return Err(CustomizeParseParameterError::UnknownOption(name.to_owned())),
+            };
+            *field = Some(match value {
+                "true" => true,
+                "false" => false,
+                _ => return Err(invalid_value()),
+            });
+        }
+        Ok(r)
+    }
+}
+
+/// Error returned by `Customize::parse_from_parameter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CustomizeParseParameterError {
+    /// Name is not a field of `Customize`
+    UnknownOption(String),
+    /// Value is not valid for the option, e. g. not `true` or `false` for `bool` option
+    InvalidValue {
+        name: String,
+        value: String,
+    },
+}
+
+impl fmt::Display for CustomizeParseParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CustomizeParseParameterError::UnknownOption(ref name) => {
+                write!(f, "unknown option: {}", name)
+            }
+            CustomizeParseParameterError::InvalidValue { ref name, ref value } => {
+                write!(f, "invalid value of option {}: {}", name, value)
+            }
+        }
+    }
+}
+
+impl Error for CustomizeParseParameterError {
+    fn description(&self) -> &str {
+        match *self {
+            CustomizeParseParameterError::UnknownOption(..) => "unknown option",
+            CustomizeParseParameterError::InvalidValue { .. } => "invalid option value",
+        }
+    }
+}
+
+
+/// True if descriptors and reflection are not generated for the file
+pub fn lite_runtime(file: &FileDescriptorProto, customize: &Customize) -> bool {
+    file.get_options().get_optimize_for() == FileOptions_OptimizeMode::LITE_RUNTIME ||
+        !customize.embed_descriptor.unwrap_or(true)
 }
 
 
@@ -54,12 +291,42 @@ pub fn customize_from_rustproto_for_message(source: &MessageOptions) -> Customiz
     let generate_accessors = rustproto::exts::generate_accessors.get(source);
     let carllerche_bytes_for_bytes = rustproto::exts::carllerche_bytes_for_bytes.get(source);
     let carllerche_bytes_for_string = rustproto::exts::carllerche_bytes_for_string.get(source);
+    let lazy_field = rustproto::exts::lazy_field.get(source);
+    let option_box_for_messages = rustproto::exts::option_box_for_messages.get(source);
+    let repeated_field_vec = rustproto::exts::repeated_field_vec.get(source);
+    let smallvec_for_repeated = rustproto::exts::smallvec_for_repeated.get(source);
+    let ref_accessors_only = rustproto::exts::ref_accessors_only.get(source);
+    let json_names = rustproto::exts::json_names.get(source);
+    let generate_display = rustproto::exts::generate_display.get(source);
+    let has_bits = rustproto::exts::has_bits.get(source);
+    let generate_into_bytes = rustproto::exts::generate_into_bytes.get(source);
+    let generate_arbitrary = rustproto::exts::generate_arbitrary.get(source);
+    let generate_proptest = rustproto::exts::generate_proptest.get(source);
     Customize {
         expose_oneof,
         expose_fields,
         generate_accessors,
         carllerche_bytes_for_bytes,
         carllerche_bytes_for_string,
+        package_modules: None,
+        v3_compat: None,
+        split_messages: None,
+        embed_descriptor: None,
+        strip_source_code_info: None,
+        compress_descriptor: None,
+        lazy_field,
+        option_box_for_messages,
+        repeated_field_vec,
+        smallvec_for_repeated,
+        ref_accessors_only,
+        json_names,
+        generate_display,
+        has_bits,
+        generate_visitor: None,
+        generate_into_bytes,
+        generate_arbitrary,
+        generate_proptest,
+        gen_report: None,
     }
 }
 
@@ -69,12 +336,36 @@ pub fn customize_from_rustproto_for_field(source: &FieldOptions) -> Customize {
     let generate_accessors = rustproto::exts::generate_accessors_field.get(source);
     let carllerche_bytes_for_bytes = rustproto::exts::carllerche_bytes_for_bytes_field.get(source);
     let carllerche_bytes_for_string = rustproto::exts::carllerche_bytes_for_string_field.get(source);
+    let lazy_field = rustproto::exts::lazy_field_field.get(source);
+    let option_box_for_messages = rustproto::exts::option_box_for_messages_field.get(source);
+    let repeated_field_vec = rustproto::exts::repeated_field_vec_field.get(source);
+    let smallvec_for_repeated = rustproto::exts::smallvec_for_repeated_field.get(source);
+    let ref_accessors_only = rustproto::exts::ref_accessors_only_field.get(source);
     Customize {
         expose_oneof,
         expose_fields,
         generate_accessors,
         carllerche_bytes_for_bytes,
         carllerche_bytes_for_string,
+        package_modules: None,
+        v3_compat: None,
+        split_messages: None,
+        embed_descriptor: None,
+        strip_source_code_info: None,
+        compress_descriptor: None,
+        lazy_field,
+        option_box_for_messages,
+        repeated_field_vec,
+        smallvec_for_repeated,
+        ref_accessors_only,
+        json_names: None,
+        generate_display: None,
+        has_bits: None,
+        generate_visitor: None,
+        generate_into_bytes: None,
+        generate_arbitrary: None,
+        generate_proptest: None,
+        gen_report: None,
     }
 }
 
@@ -84,11 +375,71 @@ pub fn customize_from_rustproto_for_file(source: &FileOptions) -> Customize {
     let generate_accessors = rustproto::exts::generate_accessors_all.get(source);
     let carllerche_bytes_for_bytes = rustproto::exts::carllerche_bytes_for_bytes_all.get(source);
     let carllerche_bytes_for_string = rustproto::exts::carllerche_bytes_for_string_all.get(source);
+    let lazy_field = rustproto::exts::lazy_field_all.get(source);
+    let option_box_for_messages = rustproto::exts::option_box_for_messages_all.get(source);
+    let repeated_field_vec = rustproto::exts::repeated_field_vec_all.get(source);
+    let smallvec_for_repeated = rustproto::exts::smallvec_for_repeated_all.get(source);
+    let ref_accessors_only = rustproto::exts::ref_accessors_only_all.get(source);
+    let json_names = rustproto::exts::json_names_all.get(source);
+    let generate_display = rustproto::exts::generate_display_all.get(source);
+    let has_bits = rustproto::exts::has_bits_all.get(source);
+    let generate_visitor = rustproto::exts::generate_visitor_all.get(source);
+    let generate_into_bytes = rustproto::exts::generate_into_bytes_all.get(source);
+    let generate_arbitrary = rustproto::exts::generate_arbitrary_all.get(source);
+    let generate_proptest = rustproto::exts::generate_proptest_all.get(source);
     Customize {
         expose_oneof,
         expose_fields,
         generate_accessors,
         carllerche_bytes_for_bytes,
         carllerche_bytes_for_string,
+        package_modules: None,
+        v3_compat: None,
+        split_messages: None,
+        embed_descriptor: None,
+        strip_source_code_info: None,
+        compress_descriptor: None,
+        lazy_field,
+        option_box_for_messages,
+        repeated_field_vec,
+        smallvec_for_repeated,
+        ref_accessors_only,
+        json_names,
+        generate_display,
+        has_bits,
+        generate_visitor,
+        generate_into_bytes,
+        generate_arbitrary,
+        generate_proptest,
+        gen_report: None,
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_from_parameter() {
+        let parameter = "expose_fields,lazy_field=false,smallvec_for_repeated=8";
+        let customize = Customize::parse_from_parameter(parameter).unwrap();
+        assert_eq!(Some(true), customize.expose_fields);
+        assert_eq!(Some(false), customize.lazy_field);
+        assert_eq!(Some(8), customize.smallvec_for_repeated);
+        assert_eq!(None, customize.expose_oneof);
+
+        assert!(Customize::parse_from_parameter("").is_ok());
+    }
+
+    #[test]
+    fn parse_from_parameter_errors() {
+        assert_eq!(
+            Err(CustomizeParseParameterError::UnknownOption("serde_derive".to_owned())),
+            Customize::parse_from_parameter("serde_derive").map(|_| ())
+        );
+        let e = Customize::parse_from_parameter("expose_fields=1").unwrap_err();
+        assert_eq!("invalid value of option expose_fields: 1", e.to_string());
+        assert!(Customize::parse_from_parameter("smallvec_for_repeated=x").is_err());
     }
 }
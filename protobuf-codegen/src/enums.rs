@@ -5,6 +5,8 @@ use protobuf::descriptorx::*;
 
 use super::code_writer::*;
 use super::customize::Customize;
+use super::customize::lite_runtime;
+use super::package_modules;
 
 
 #[derive(Clone)]
@@ -51,16 +53,27 @@ impl<'a> EnumGen<'a> {
     pub fn new(
         enum_with_scope: &'a EnumWithScope<'a>,
         current_file: &FileDescriptorProto,
-        _customize: &Customize
+        customize: &Customize
     ) -> EnumGen<'a> {
+        // enums of other files are only referenced from messages,
+        // which are generated into submodules with split messages
+        let subm = customize.split_messages.unwrap_or(false);
         let rust_name = if enum_with_scope.get_scope().get_file_descriptor().get_name() ==
             current_file.get_name()
         {
             // field type is a message or enum declared in the same file
             enum_with_scope.rust_name()
+        } else if customize.package_modules.unwrap_or(false) {
+            format!(
+                "{}{}::{}",
+                package_modules::path_to_root(current_file, subm),
+                package_modules::file_rust_mod_path(enum_with_scope.get_file_descriptor()).join("::"),
+                enum_with_scope.rust_name()
+            )
         } else {
             format!(
-                "super::{}::{}",
+                "{}super::{}::{}",
+                if subm { "super::" } else { "" },
                 proto_path_to_rust_mod(
                     enum_with_scope.get_scope().get_file_descriptor().get_name(),
                 ),
@@ -70,12 +83,7 @@ impl<'a> EnumGen<'a> {
         EnumGen {
             enum_with_scope: enum_with_scope,
             type_name: rust_name,
-            lite_runtime: enum_with_scope
-                .get_scope()
-                .get_file_descriptor()
-                .get_options()
-                .get_optimize_for() ==
-                FileOptions_OptimizeMode::LITE_RUNTIME,
+            lite_runtime: lite_runtime(enum_with_scope.get_file_descriptor(), customize),
         }
     }
 
@@ -120,6 +128,10 @@ impl<'a> EnumGen<'a> {
         }
         w.write_line("");
         self.write_impl_enum(w);
+        if !self.lite_runtime {
+            w.write_line("");
+            self.write_impl_enum_full(w);
+        }
         w.write_line("");
         self.write_impl_copy(w);
         if self.enum_with_scope.scope.file_scope.syntax() == Syntax::PROTO3 {
@@ -206,27 +218,48 @@ impl<'a> EnumGen<'a> {
                 w.write_line("values");
             });
 
+            w.write_line("");
+            w.def_fn("enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor>", |w| {
+                if self.lite_runtime {
+                    w.write_line("::std::option::Option::None");
+                } else {
+                    w.write_line("::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())");
+                }
+            });
             if !self.lite_runtime {
                 w.write_line("");
-                let ref type_name = self.type_name;
+                // for callers of deprecated `ProtobufEnum::enum_descriptor_static`
                 w.def_fn(&format!("enum_descriptor_static(_: ::std::option::Option<{}>) -> &'static ::protobuf::reflect::EnumDescriptor", type_name), |w| {
-                    w.lazy_static_decl_get("descriptor", "::protobuf::reflect::EnumDescriptor", |w| {
-                        let ref type_name = self.type_name;
-                        w.write_line(&format!("::protobuf::reflect::EnumDescriptor::new(\"{}\", file_descriptor_proto())", type_name));
-                    });
+                    w.write_line("<Self as ::protobuf::ProtobufEnumFull>::type_descriptor()");
                 });
             }
         });
     }
 
+    // not implemented without descriptor, so reflection of such enum does not compile
+    fn write_impl_enum_full(&self, w: &mut CodeWriter) {
+        let ref type_name = self.type_name;
+        w.impl_for_block("::protobuf::ProtobufEnumFull", &type_name, |w| {
+            w.def_fn("type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor", |w| {
+                w.lazy_static_decl_get("descriptor", "::protobuf::reflect::EnumDescriptor", |w| {
+                    w.write_line(&format!("::protobuf::reflect::EnumDescriptor::new(\"{}\", file_descriptor_proto())", type_name));
+                });
+            });
+        });
+    }
+
     fn write_impl_value(&self, w: &mut CodeWriter) {
         w.impl_for_block("::protobuf::reflect::ProtobufValue", &self.type_name, |w| {
             w.def_fn(
                 "as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef",
                 |w| {
-                    w.write_line(
-                        "::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())",
-                    )
+                    if self.lite_runtime {
+                        w.write_line("panic!(\"enum is generated without descriptor\")");
+                    } else {
+                        w.write_line(
+                            "::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))",
+                        );
+                    }
                 },
             )
         })
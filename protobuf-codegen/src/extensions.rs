@@ -2,17 +2,25 @@ use protobuf::descriptor::*;
 use protobuf::descriptorx::*;
 use super::code_writer::CodeWriter;
 use super::rust_types_values::*;
+use super::customize::Customize;
 
 
 struct ExtGen<'a> {
     file: &'a FileDescriptorProto,
     root_scope: &'a RootScope<'a>,
     field: &'a FieldDescriptorProto,
+    customize: &'a Customize,
 }
 
 impl<'a> ExtGen<'a> {
     fn extendee_rust_name(&self) -> String {
-        type_name_to_rust_relative(self.field.get_extendee(), self.file, true, self.root_scope)
+        type_name_to_rust_relative(
+            self.field.get_extendee(),
+            self.file,
+            true,
+            self.root_scope,
+            self.customize,
+        )
     }
 
     fn repeated(&self) -> bool {
@@ -32,6 +40,7 @@ impl<'a> ExtGen<'a> {
                 self.file,
                 true,
                 self.root_scope,
+                self.customize,
             );
             match self.field.get_field_type() {
                 FieldDescriptorProto_Type::TYPE_MESSAGE => ProtobufTypeGen::Message(
@@ -70,7 +79,12 @@ impl<'a> ExtGen<'a> {
 }
 
 
-pub fn write_extensions(file: &FileDescriptorProto, root_scope: &RootScope, w: &mut CodeWriter) {
+pub fn write_extensions(
+    file: &FileDescriptorProto,
+    root_scope: &RootScope,
+    w: &mut CodeWriter,
+    customize: &Customize,
+) {
     if file.get_extension().is_empty() {
         return;
     }
@@ -89,6 +103,7 @@ pub fn write_extensions(file: &FileDescriptorProto, root_scope: &RootScope, w: &
                 file: file,
                 root_scope: root_scope,
                 field: field,
+                customize: customize,
             }.write(w);
         }
     });
@@ -3,6 +3,7 @@ use protobuf::descriptorx::*;
 use protobuf::wire_format;
 use protobuf::rt;
 use protobuf::rust;
+use protobuf::rustproto;
 use protobuf::text_format;
 
 use super::rust_types_values::*;
@@ -12,6 +13,7 @@ use super::code_writer::CodeWriter;
 use super::customize::Customize;
 use super::customize::customize_from_rustproto_for_field;
 use oneof::OneofField;
+use validate::is_packable;
 
 
 fn type_is_copy(field_type: FieldDescriptorProto_Type) -> bool {
@@ -108,6 +110,30 @@ fn field_type_size(field_type: FieldDescriptorProto_Type) -> Option<u32> {
     }
 }
 
+// max serialized size of value without tag, `None` for length-delimited types
+fn field_type_max_size(field_type: FieldDescriptorProto_Type) -> Option<u32> {
+    match field_type {
+        FieldDescriptorProto_Type::TYPE_UINT32 |
+        FieldDescriptorProto_Type::TYPE_SINT32 => Some(5),
+        // negative `int32` and enum values are encoded as 64-bit varints
+        FieldDescriptorProto_Type::TYPE_INT32 |
+        FieldDescriptorProto_Type::TYPE_INT64 |
+        FieldDescriptorProto_Type::TYPE_UINT64 |
+        FieldDescriptorProto_Type::TYPE_SINT64 |
+        FieldDescriptorProto_Type::TYPE_ENUM => Some(10),
+        t => field_type_size(t),
+    }
+}
+
+
+/// Where sizes of nested messages are kept between computing size and writing.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SizesStorage {
+    /// `cached_size` field of each message
+    CachedSize,
+    /// `::protobuf::SizeCache` passed as `sizes` parameter
+    SizeCache,
+}
 
 #[derive(Clone, PartialEq, Eq)]
 pub enum SingularFieldFlag {
@@ -130,13 +156,28 @@ impl SingularFieldFlag {
 pub struct SingularField {
     pub flag: SingularFieldFlag,
     pub elem: FieldElem,
+    // message field decoded on first access
+    pub lazy: bool,
+    // message field stored as `Option<Box<T>>`
+    pub option_box: bool,
+    // index of presence bit in `has_bits` if field value is stored without `Option`
+    pub has_bit: Option<u32>,
 }
 
 impl SingularField {
     fn rust_storage_type(&self) -> RustType {
         match self.flag {
+            SingularFieldFlag::WithFlag { .. } if self.has_bit.is_some() => {
+                self.elem.rust_storage_type()
+            }
             SingularFieldFlag::WithFlag { .. } => {
                 match self.elem.proto_type() {
+                    FieldDescriptorProto_Type::TYPE_MESSAGE if self.lazy => {
+                        RustType::LazyMessageField(Box::new(self.elem.rust_storage_type()))
+                    }
+                    FieldDescriptorProto_Type::TYPE_MESSAGE if self.option_box => {
+                        RustType::OptionBox(Box::new(self.elem.rust_storage_type()))
+                    }
                     FieldDescriptorProto_Type::TYPE_MESSAGE => RustType::SingularPtrField(
                         Box::new(self.elem.rust_storage_type()),
                     ),
@@ -157,11 +198,17 @@ impl SingularField {
 pub struct RepeatedField {
     pub elem: FieldElem,
     pub packed: bool,
+    // stored as `Vec<T>` even if elements are not copy
+    pub vec: bool,
+    // inline capacity if stored as `SmallVec`, zero otherwise
+    pub smallvec: u32,
 }
 
 impl RepeatedField {
     fn rust_type(&self) -> RustType {
-        if !self.elem.is_copy() &&
+        if self.is_smallvec() {
+            RustType::SmallVec(Box::new(self.elem.rust_storage_type()), self.smallvec)
+        } else if !self.vec && !self.elem.is_copy() &&
             self.elem.primitive_type_variant() != PrimitiveTypeVariant::Carllerche
         {
             RustType::RepeatedField(Box::new(self.elem.rust_storage_type()))
@@ -169,6 +216,15 @@ impl RepeatedField {
             RustType::Vec(Box::new(self.elem.rust_storage_type()))
         }
     }
+
+    // only numeric and `bool` fields can be stored as `SmallVec`,
+    // enums are read with unknown values preserved, which requires `Vec`
+    fn is_smallvec(&self) -> bool {
+        match self.elem {
+            FieldElem::Primitive(..) => self.smallvec != 0 && self.elem.is_copy(),
+            _ => false,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -269,6 +325,53 @@ impl FieldElem {
         self.protobuf_type_gen().rust_type()
     }
 
+    /// Expression of type `rust_storage_type()` with arbitrary value in `ArbitraryFields`
+    /// implementation, unstructured data is in `u` variable.
+    pub fn arbitrary_value(&self) -> String {
+        match *self {
+            FieldElem::Primitive(t, PrimitiveTypeVariant::Default) => {
+                format!("u.arbitrary::<{}>()?", rust_name(t))
+            }
+            FieldElem::Primitive(t, PrimitiveTypeVariant::Carllerche) => format!(
+                "{}::from(u.arbitrary::<{}>()?)",
+                self.rust_storage_type(),
+                rust_name(t)
+            ),
+            FieldElem::Enum(ref name, ..) => {
+                format!("::protobuf::rt::arbitrary_enum::<{}>(u)?", name)
+            }
+            FieldElem::Message(ref name, ..) => format!("u.arbitrary::<{}>()?", name),
+            FieldElem::Group => unreachable!(),
+        }
+    }
+
+    /// Expression of proptest strategy generating values of type `rust_storage_type()`,
+    /// `depth` is expression of `ProptestDepth` of nested messages.
+    pub fn proptest_strategy(&self, depth: &str) -> String {
+        match *self {
+            FieldElem::Primitive(t, PrimitiveTypeVariant::Default) => {
+                format!("::protobuf::proptest::arbitrary::any::<{}>()", rust_name(t))
+            }
+            FieldElem::Primitive(t, PrimitiveTypeVariant::Carllerche) => format!(
+                "::protobuf::proptest::arbitrary::any::<{}>().prop_map({}::from)",
+                rust_name(t),
+                self.rust_storage_type()
+            ),
+            FieldElem::Enum(ref name, ..) => format!("::protobuf::rt::proptest_enum::<{}>()", name),
+            FieldElem::Message(ref name, ..) => {
+                format!("::protobuf::proptest::arbitrary::any_with::<{}>({})", name, depth)
+            }
+            FieldElem::Group => unreachable!(),
+        }
+    }
+
+    pub fn is_message(&self) -> bool {
+        match *self {
+            FieldElem::Message(..) => true,
+            _ => false,
+        }
+    }
+
     fn primitive_type_variant(&self) -> PrimitiveTypeVariant {
         match self {
             &FieldElem::Primitive(_, v) => v,
@@ -277,6 +380,17 @@ impl FieldElem {
     }
 }
 
+// explicit `packed` option, otherwise proto3 scalar fields are packed by default
+fn is_packed(field: &FieldWithContext) -> bool {
+    let options = field.field.get_options();
+    if options.has_packed() {
+        options.get_packed()
+    } else {
+        field.message.scope.file_scope.syntax() == Syntax::PROTO3 &&
+            is_packable(field.field.get_field_type())
+    }
+}
+
 fn field_elem(
     field: &FieldWithContext,
     root_scope: &RootScope,
@@ -293,11 +407,14 @@ fn field_elem(
             .file_descriptor
             .get_name()
             .to_owned();
+        // with split messages, messages are generated into submodules
+        let subm = customize.split_messages.unwrap_or(false);
         let rust_relative_name = type_name_to_rust_relative(
             field.field.get_type_name(),
             field.message.get_scope().file_scope.file_descriptor,
-            false,
+            subm,
             root_scope,
+            customize,
         );
         match (field.field.get_field_type(), message_or_enum) {
             (
@@ -410,6 +527,14 @@ pub struct FieldGen<'a> {
     pub kind: FieldKind,
     pub expose_field: bool,
     pub generate_accessors: bool,
+    // do not generate `set_` for fields with `mut_`
+    ref_accessors_only: bool,
+    // print `<redacted>` instead of value in `Debug`
+    pub redact: bool,
+    // generate `get_foo_as::<T>()` checked conversion accessor
+    checked_as: bool,
+    // type used in `get_` and `set_` accessors instead of field value type
+    rust_field_type: Option<String>,
 }
 
 impl<'a> FieldGen<'a> {
@@ -425,6 +550,11 @@ impl<'a> FieldGen<'a> {
 
         let expose_field = customize.expose_fields.unwrap_or(default_expose_field);
         let generate_accessors = customize.generate_accessors.unwrap_or(true);
+        let ref_accessors_only = customize.ref_accessors_only.unwrap_or(false);
+        let redact = rustproto::exts::redact.get(field.field.get_options()).unwrap_or(false);
+        let checked_as =
+            rustproto::exts::checked_as.get(field.field.get_options()).unwrap_or(false);
+        let rust_field_type = rustproto::exts::rust_field_type.get(field.field.get_options());
 
         let kind = if field.field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED {
             match (elem, true) {
@@ -437,7 +567,9 @@ impl<'a> FieldGen<'a> {
                 // regular repeated field
                 (elem, _) => FieldKind::Repeated(RepeatedField {
                     elem,
-                    packed: field.field.get_options().get_packed(),
+                    packed: is_packed(&field),
+                    vec: customize.repeated_field_vec.unwrap_or(false),
+                    smallvec: customize.smallvec_for_repeated.unwrap_or(0),
                 }),
             }
         } else if let Some(oneof) = field.oneof() {
@@ -455,6 +587,12 @@ impl<'a> FieldGen<'a> {
             FieldKind::Singular(SingularField {
                 elem,
                 flag,
+                lazy: customize
+                    .lazy_field
+                    .unwrap_or(field.field.get_options().get_lazy()),
+                option_box: customize.option_box_for_messages.unwrap_or(false),
+                // assigned by message
+                has_bit: None,
             })
         };
 
@@ -469,6 +607,10 @@ impl<'a> FieldGen<'a> {
             kind: kind,
             expose_field: expose_field,
             generate_accessors: generate_accessors,
+            ref_accessors_only: ref_accessors_only,
+            redact: redact,
+            checked_as: checked_as,
+            rust_field_type: rust_field_type,
         }
     }
 
@@ -490,6 +632,72 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    // singular message field decoded on first access
+    pub fn is_lazy(&self) -> bool {
+        match self.kind {
+            FieldKind::Singular(..) => match self.full_storage_type() {
+                RustType::LazyMessageField(..) => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    // singular message field stored as `Option<Box<T>>`
+    fn is_option_box(&self) -> bool {
+        match self.kind {
+            FieldKind::Singular(..) => match self.full_storage_type() {
+                RustType::OptionBox(..) => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// True if presence of this field can be stored in `has_bits` of message
+    pub fn can_use_has_bit(&self) -> bool {
+        match self.kind {
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ref elem,
+                ..
+            }) => {
+                // proto2 enums do not implement `Default`, so values of unset
+                // enum fields cannot be stored
+                let scalar = match *elem {
+                    FieldElem::Primitive(..) => elem.is_copy(),
+                    _ => false,
+                };
+                scalar && self.generate_accessors && !self.expose_field
+            }
+            _ => false,
+        }
+    }
+
+    pub fn set_has_bit(&mut self, bit: u32) {
+        match self.kind {
+            FieldKind::Singular(ref mut singular) => singular.has_bit = Some(bit),
+            _ => panic!("field is not singular"),
+        }
+    }
+
+    pub fn has_bit(&self) -> Option<u32> {
+        match self.kind {
+            FieldKind::Singular(SingularField { has_bit, .. }) => has_bit,
+            _ => None,
+        }
+    }
+
+    // `has_bits` element and mask of presence bit
+    fn has_bit_word_mask(&self, bit: u32) -> (String, String) {
+        (format!("self.has_bits[{}]", bit / 32), format!("0x{:x}", 1u32 << (bit % 32)))
+    }
+
+    fn write_set_has_bit(&self, bit: u32, w: &mut CodeWriter) {
+        let (word, mask) = self.has_bit_word_mask(bit);
+        w.write_line(&format!("{} |= {};", word, mask));
+    }
+
     fn is_singular(&self) -> bool {
         match self.kind {
             FieldKind::Singular(..) => true,
@@ -504,6 +712,16 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    // repeated `string`, `bytes` or message field stored as `Vec<T>`
+    fn is_vec_not_copy(&self) -> bool {
+        match self.kind {
+            FieldKind::Repeated(RepeatedField { vec: true, ref elem, .. }) => {
+                !elem.is_copy() && elem.primitive_type_variant() == PrimitiveTypeVariant::Default
+            }
+            _ => false,
+        }
+    }
+
     fn is_repeated_or_map(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) |
@@ -519,6 +737,266 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    // tag written by generated code, packed fields are length-delimited
+    pub fn tag(&self) -> u32 {
+        let wire_type = if self.is_repeated_packed() {
+            wire_format::WireTypeLengthDelimited
+        } else {
+            self.wire_type
+        };
+        wire_format::Tag::make(self.proto_field.number(), wire_type).value()
+    }
+
+    // name of generated `FIELD_FOO_NUMBER` and `FIELD_FOO_TAG` constants,
+    // not unique if field names differ only in case
+    pub fn const_name_base(&self) -> String {
+        format!("FIELD_{}", self.proto_field.name().to_uppercase())
+    }
+
+    pub fn write_field_number_consts(&self, w: &mut CodeWriter, base: &str) {
+        w.pub_const(
+            &format!("{}_NUMBER", base),
+            "u32",
+            &self.proto_field.number().to_string(),
+        );
+        w.pub_const(&format!("{}_TAG", base), "u32", &self.tag().to_string());
+    }
+
+    // name of visitor trait method called for values of this field
+    fn visit_fn_name(&self) -> String {
+        format!("visit_{}", self.rust_name)
+    }
+
+    // call of visitor trait method by fully qualified name, because
+    // fields of different messages visited by one `accept` may have same names
+    fn visit_fn_call(&self, args: &str) -> String {
+        format!(
+            "{}Visitor::{}(visitor, {});",
+            self.proto_field.message.rust_name(),
+            self.visit_fn_name(),
+            args
+        )
+    }
+
+    // `accept` descends only into messages declared in the same file,
+    // because visitor traits of other files may be not generated
+    fn is_same_file_message(&self, elem: &FieldElem) -> bool {
+        match *elem {
+            FieldElem::Message(_, ref file_name, None) => {
+                file_name == self.proto_field.message.get_file_descriptor().get_name()
+            }
+            _ => false,
+        }
+    }
+
+    /// Method of visitor trait with empty default implementation.
+    pub fn write_visitor_fn(&self, w: &mut CodeWriter) {
+        let params = match self.kind {
+            FieldKind::Map(MapField { ref key, ref value, .. }) => format!(
+                "_key: &{}, _value: &mut {}",
+                key.rust_storage_type(),
+                value.rust_storage_type()
+            ),
+            _ => format!("_value: &mut {}", self.elem().rust_storage_type()),
+        };
+        w.write_line(&format!("fn {}(&mut self, {}) {{}}", self.visit_fn_name(), params));
+    }
+
+    // call visitor for value `v` of type `elem`, and visit the value fields
+    fn write_visit_value(&self, elem: &FieldElem, w: &mut CodeWriter) {
+        w.write_line(&self.visit_fn_call("v"));
+        if self.is_same_file_message(elem) {
+            w.write_line("v.accept(visitor);");
+        }
+    }
+
+    /// Part of `accept` function visiting this field if it is set.
+    pub fn write_accept_field(&self, w: &mut CodeWriter) {
+        match self.kind {
+            FieldKind::Singular(SingularField { ref elem, has_bit: Some(bit), .. }) => {
+                let (word, mask) = self.has_bit_word_mask(bit);
+                w.if_stmt(&format!("{} & {} != 0", word, mask), |w| {
+                    w.write_line(&format!("let v = &mut {};", self.self_field()));
+                    self.write_visit_value(elem, w);
+                });
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ref elem,
+                ..
+            }) => {
+                w.if_let_stmt("Some(v)", &format!("{}.as_mut()", self.self_field()), |w| {
+                    self.write_visit_value(elem, w);
+                });
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ref elem,
+                ..
+            }) => {
+                let cond = match *elem {
+                    FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_STRING, ..) |
+                    FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_BYTES, ..) => {
+                        format!("!{}.is_empty()", self.self_field())
+                    }
+                    _ => format!(
+                        "{} != {}",
+                        self.self_field(),
+                        self.full_storage_type().default_value()
+                    ),
+                };
+                w.if_stmt(&cond, |w| {
+                    w.write_line(&format!("let v = &mut {};", self.self_field()));
+                    self.write_visit_value(elem, w);
+                });
+            }
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) => {
+                w.for_stmt(&format!("{}.iter_mut()", self.self_field()), "v", |w| {
+                    self.write_visit_value(elem, w);
+                });
+            }
+            FieldKind::Map(MapField { ref value, .. }) => {
+                w.for_stmt(&format!("{}.iter_mut()", self.self_field()), "(k, v)", |w| {
+                    w.write_line(&self.visit_fn_call("k, v"));
+                    if self.is_same_file_message(value) {
+                        w.write_line("v.accept(visitor);");
+                    }
+                });
+            }
+            FieldKind::Oneof(OneofField { ref elem, ref oneof_type_name, .. }) => {
+                let cond = format!("Some({}::{}(ref mut v))", oneof_type_name, self.rust_name);
+                w.if_let_stmt(&cond, &self.self_field_oneof(), |w| {
+                    self.write_visit_value(elem, w);
+                });
+            }
+        }
+    }
+
+    /// Part of `ArbitraryFields` implementation setting this field.
+    ///
+    /// Optional fields are set or left unset, repeated and map fields
+    /// get at most `ARBITRARY_MAX_LEN` elements.
+    pub fn write_arbitrary_field(&self, w: &mut CodeWriter) {
+        match self.kind {
+            FieldKind::Singular(SingularField { ref elem, ref flag, .. }) => {
+                let value = elem.arbitrary_value();
+                let value_type = elem.rust_storage_type();
+                match *flag {
+                    SingularFieldFlag::WithFlag { required: false } => {
+                        w.if_stmt("u.arbitrary::<bool>()?", |w| {
+                            self.write_self_field_assign_value(w, &value, &value_type);
+                        });
+                    }
+                    _ => self.write_self_field_assign_value(w, &value, &value_type),
+                }
+            }
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) => {
+                w.for_stmt("0..::protobuf::rt::arbitrary_len(u)?", "_", |w| {
+                    w.write_line(&format!(
+                        "{}.push({});",
+                        self.self_field(),
+                        elem.arbitrary_value()
+                    ));
+                });
+            }
+            FieldKind::Map(MapField { ref key, ref value, .. }) => {
+                w.for_stmt("0..::protobuf::rt::arbitrary_len(u)?", "_", |w| {
+                    w.write_line(&format!(
+                        "{}.insert({}, {});",
+                        self.self_field(),
+                        key.arbitrary_value(),
+                        value.arbitrary_value()
+                    ));
+                });
+            }
+            FieldKind::Oneof(..) => panic!("oneof fields are set by OneofGen"),
+        }
+    }
+
+    /// Whether strategy of this field generates nested messages.
+    pub fn proptest_nested(&self) -> bool {
+        match self.kind {
+            FieldKind::Singular(SingularField { ref elem, .. }) |
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) => elem.is_message(),
+            FieldKind::Map(MapField { ref value, .. }) => value.is_message(),
+            FieldKind::Oneof(OneofField { ref elem, .. }) => elem.is_message(),
+        }
+    }
+
+    // `s = (s, strategy).prop_map(|(mut m, v)| { ...; m }).boxed();`
+    fn write_proptest_step<F>(&self, w: &mut CodeWriter, strategy: &str, cb: F)
+    where
+        F : Fn(&mut CodeWriter),
+    {
+        w.block(&format!("s = (s, {}).prop_map(|(mut m, v)| {{", strategy), "}).boxed();", |w| {
+            cb(w);
+            w.write_line("m");
+        });
+    }
+
+    /// Add this field to proptest strategy of message in `s` variable,
+    /// depth of the message is in `depth` variable.
+    pub fn write_proptest_field(&self, w: &mut CodeWriter) {
+        let required = match self.kind {
+            FieldKind::Singular(SingularField { ref flag, .. }) => flag.is_required(),
+            _ => false,
+        };
+        if self.proptest_nested() && !required {
+            w.if_let_stmt("::std::option::Option::Some(depth)", "depth.nested()", |w| {
+                self.write_proptest_field_step(w, "depth");
+            });
+        } else {
+            // required message is generated even past depth limit
+            self.write_proptest_field_step(w, "depth.nested().unwrap_or(depth)");
+        }
+    }
+
+    fn write_proptest_field_step(&self, w: &mut CodeWriter, depth: &str) {
+        let max_len = "0..=::protobuf::rt::PROPTEST_MAX_LEN";
+        match self.kind {
+            FieldKind::Singular(SingularField { ref elem, ref flag, .. }) => {
+                let ty = elem.rust_storage_type();
+                if *flag == (SingularFieldFlag::WithFlag { required: false }) {
+                    let strategy = format!(
+                        "::protobuf::proptest::option::of({})",
+                        elem.proptest_strategy(depth)
+                    );
+                    self.write_proptest_step(w, &strategy, |w| {
+                        w.if_let_stmt("::std::option::Option::Some(v)", "v", |w| {
+                            self.write_field_assign_value(w, "m", "v", &ty);
+                        });
+                    });
+                } else {
+                    self.write_proptest_step(w, &elem.proptest_strategy(depth), |w| {
+                        self.write_field_assign_value(w, "m", "v", &ty);
+                    });
+                }
+            }
+            FieldKind::Repeated(RepeatedField { ref elem, .. }) => {
+                let strategy = format!(
+                    "::protobuf::proptest::collection::vec({}, {})",
+                    elem.proptest_strategy(depth),
+                    max_len
+                );
+                self.write_proptest_step(w, &strategy, |w| {
+                    w.write_line(&format!("m.{} = ::std::convert::From::from(v);", self.rust_name));
+                });
+            }
+            FieldKind::Map(MapField { ref key, ref value, .. }) => {
+                let strategy = format!(
+                    "::protobuf::proptest::collection::hash_map({}, {}, {})",
+                    key.proptest_strategy(depth),
+                    value.proptest_strategy(depth),
+                    max_len
+                );
+                self.write_proptest_step(w, &strategy, |w| {
+                    w.write_line(&format!("m.{} = v;", self.rust_name));
+                });
+            }
+            FieldKind::Oneof(..) => panic!("oneof fields are set by OneofGen"),
+        }
+    }
+
     #[allow(dead_code)]
     fn repeated(&self) -> &RepeatedField {
         match self.kind {
@@ -556,6 +1034,11 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    // element is stored as `::protobuf::Chars`
+    fn is_chars(&self) -> bool {
+        self.elem().rust_storage_type() == RustType::Chars
+    }
+
     // type of field in struct
     pub fn full_storage_type(&self) -> RustType {
         match self.kind {
@@ -790,13 +1273,19 @@ impl<'a> FieldGen<'a> {
             FieldKind::Repeated(RepeatedField { ref elem, .. }) => {
                 let coll = match self.full_storage_type() {
                     RustType::Vec(..) => "vec",
+                    RustType::SmallVec(..) => "smallvec",
                     RustType::RepeatedField(..) => "repeated_field",
                     _ => unreachable!(),
                 };
                 let name = format!("make_{}_accessor", coll);
+                let mut type_params = vec![elem.lib_protobuf_type()];
+                if coll == "smallvec" {
+                    // array type is inferred from lambdas
+                    type_params.push("_".to_owned());
+                }
                 AccessorFn {
                     name: name,
-                    type_params: vec![elem.lib_protobuf_type()],
+                    type_params: type_params,
                     style: AccessorStyle::Lambda,
                 }
             }
@@ -810,6 +1299,7 @@ impl<'a> FieldGen<'a> {
             FieldKind::Singular(SingularField {
                 ref elem,
                 flag: SingularFieldFlag::WithoutFlag,
+                ..
             }) => {
                 if let &FieldElem::Message(ref name, ..) = elem {
                     // TODO: old style, needed because of default instance
@@ -827,14 +1317,29 @@ impl<'a> FieldGen<'a> {
                     }
                 }
             }
+            FieldKind::Singular(SingularField {
+                ref elem,
+                has_bit: Some(..),
+                ..
+            }) => {
+                // value is not stored in `Option`, so lambda accessor cannot be used
+                AccessorFn {
+                    name: format!("make_singular_{}_accessor", elem.rust_storage_type()),
+                    type_params: Vec::new(),
+                    style: AccessorStyle::HasGet,
+                }
+            }
             FieldKind::Singular(SingularField {
                 ref elem,
                 flag: SingularFieldFlag::WithFlag { .. },
+                ..
             }) => {
                 let coll = match self.full_storage_type() {
                     RustType::Option(..) => "option",
                     RustType::SingularField(..) => "singular_field",
                     RustType::SingularPtrField(..) => "singular_ptr_field",
+                    RustType::LazyMessageField(..) => "lazy_message_field",
+                    RustType::OptionBox(..) => "option_box",
                     _ => unreachable!(),
                 };
                 let name = format!("make_{}_accessor", coll);
@@ -882,6 +1387,15 @@ impl<'a> FieldGen<'a> {
                 "self.{} = ::std::option::Option::None;",
                 self.oneof().oneof_name
             ));
+        } else if let Some(bit) = self.has_bit() {
+            // value is reset, so derived `PartialEq` ignores values of unset fields
+            let (word, mask) = self.has_bit_word_mask(bit);
+            w.write_line(&format!("{} &= !{};", word, mask));
+            w.write_line(&format!(
+                "{} = {};",
+                self.self_field(),
+                self.full_storage_type().default_value()
+            ));
         } else {
             let clear_expr = self.full_storage_type().clear(&self.self_field());
             w.write_line(&format!("{};", clear_expr));
@@ -904,6 +1418,14 @@ impl<'a> FieldGen<'a> {
                             var
                         )
                     }
+                    // `Chars` may hold invalid UTF-8 preserved by parser
+                    FieldDescriptorProto_Type::TYPE_STRING if self.is_chars() => {
+                        format!(
+                            "::protobuf::rt::bytes_size({}, {}.raw_bytes())",
+                            self.proto_field.number(),
+                            var
+                        )
+                    }
                     FieldDescriptorProto_Type::TYPE_STRING => {
                         format!(
                             "::protobuf::rt::string_size({}, &{})",
@@ -948,7 +1470,14 @@ impl<'a> FieldGen<'a> {
     }
 
     // output code that writes single element to stream
-    pub fn write_write_element(&self, w: &mut CodeWriter, os: &str, var: &str, ty: &RustType) {
+    pub fn write_write_element(
+        &self,
+        w: &mut CodeWriter,
+        os: &str,
+        var: &str,
+        ty: &RustType,
+        sizes: SizesStorage,
+    ) {
         if let FieldKind::Repeated(RepeatedField { packed: true, .. }) = self.kind {
             unreachable!();
         };
@@ -961,12 +1490,31 @@ impl<'a> FieldGen<'a> {
                     self.proto_field.number(),
                     wire_format::WireTypeLengthDelimited
                 ));
+                match sizes {
+                    SizesStorage::CachedSize => {
+                        w.write_line(&format!(
+                            "{}.write_raw_varint32({}.get_cached_size())?;",
+                            os,
+                            var
+                        ));
+                        w.write_line(&format!("{}.write_to_with_cached_sizes({})?;", var, os));
+                    }
+                    SizesStorage::SizeCache => {
+                        w.write_line(&format!(
+                            "{}.write_raw_varint32(sizes.take_size())?;",
+                            os
+                        ));
+                        w.write_line(&format!("{}.write_to_with_size_cache({}, sizes)?;", var, os));
+                    }
+                }
+            }
+            FieldDescriptorProto_Type::TYPE_STRING if self.is_chars() => {
                 w.write_line(&format!(
-                    "{}.write_raw_varint32({}.get_cached_size())?;",
+                    "{}.write_bytes({}, {}.raw_bytes())?;",
                     os,
+                    self.proto_field.number(),
                     var
                 ));
-                w.write_line(&format!("{}.write_to_with_cached_sizes({})?;", var, os));
             }
             _ => {
                 let param_type = self.os_write_fn_param_type();
@@ -989,6 +1537,10 @@ impl<'a> FieldGen<'a> {
 
     fn self_field_is_some(&self) -> String {
         assert!(self.is_singular());
+        if let Some(bit) = self.has_bit() {
+            let (word, mask) = self.has_bit_word_mask(bit);
+            return format!("{} & {} != 0", word, mask);
+        }
         format!("{}.is_some()", self.self_field())
     }
 
@@ -999,6 +1551,10 @@ impl<'a> FieldGen<'a> {
 
     fn self_field_is_none(&self) -> String {
         assert!(self.is_singular());
+        if let Some(bit) = self.has_bit() {
+            let (word, mask) = self.has_bit_word_mask(bit);
+            return format!("{} & {} == 0", word, mask);
+        }
         format!("{}.is_none()", self.self_field())
     }
 
@@ -1009,7 +1565,9 @@ impl<'a> FieldGen<'a> {
             RustType::Option(ref e) if e.is_copy() => RustType::Option(e.clone()),
             RustType::Option(e) => RustType::Option(Box::new(e.ref_type())),
             RustType::SingularField(ty) |
-            RustType::SingularPtrField(ty) => RustType::Option(Box::new(RustType::Ref(ty))),
+            RustType::SingularPtrField(ty) |
+            RustType::LazyMessageField(ty) |
+            RustType::OptionBox(ty) => RustType::Option(Box::new(RustType::Ref(ty))),
             x => panic!("cannot convert {} to option", x),
         }
     }
@@ -1020,6 +1578,7 @@ impl<'a> FieldGen<'a> {
 
         let suffix = match self.full_storage_type() {
             RustType::Option(ref e) if e.is_copy() => "",
+            RustType::OptionBox(..) => ".as_ref().map(|v| &**v)",
             _ => ".as_ref()",
         };
 
@@ -1034,9 +1593,16 @@ impl<'a> FieldGen<'a> {
         match self.kind {
             FieldKind::Repeated(..) |
             FieldKind::Map(..) => panic!("field is not singular"),
+            FieldKind::Singular(..) if self.is_lazy() || self.has_bit().is_some() => {
+                // size and serialization of lazy field do not decode it
+                w.if_stmt(self.self_field_is_some(), |w| {
+                    cb(&self.self_field(), &self.full_storage_type(), w);
+                });
+            }
             FieldKind::Singular(SingularField {
                 flag: SingularFieldFlag::WithFlag { .. },
                 ref elem,
+                ..
             }) => {
                 let var = "v";
                 let ref_prefix = match elem.rust_storage_type().is_copy() {
@@ -1056,6 +1622,7 @@ impl<'a> FieldGen<'a> {
             FieldKind::Singular(SingularField {
                 flag: SingularFieldFlag::WithoutFlag,
                 ref elem,
+                ..
             }) => {
                 match *elem {
                     FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_STRING, ..) |
@@ -1135,6 +1702,11 @@ impl<'a> FieldGen<'a> {
 
     fn write_self_field_assign_some(&self, w: &mut CodeWriter, value: &str) {
         let full_storage_type = self.full_storage_type();
+        if let Some(bit) = self.has_bit() {
+            self.write_self_field_assign(w, value);
+            self.write_set_has_bit(bit, w);
+            return;
+        }
         match self.singular() {
             &SingularField { flag: SingularFieldFlag::WithFlag { .. }, .. } => {
                 self.write_self_field_assign(w, &full_storage_type.wrap_value(value));
@@ -1146,20 +1718,42 @@ impl<'a> FieldGen<'a> {
     }
 
     fn write_self_field_assign_value(&self, w: &mut CodeWriter, value: &str, ty: &RustType) {
+        self.write_field_assign_value(w, "self", value, ty);
+    }
+
+    // assign field of message in `target` variable, e. g. in closure
+    fn write_field_assign_value(
+        &self,
+        w: &mut CodeWriter,
+        target: &str,
+        value: &str,
+        ty: &RustType,
+    ) {
+        let field = format!("{}.{}", target, self.rust_name);
         match self.kind {
             FieldKind::Repeated(..) |
             FieldKind::Map(..) => {
                 let converted = ty.into_target(&self.full_storage_type(), value);
-                self.write_self_field_assign(w, &converted);
+                w.write_line(&format!("{} = {};", field, converted));
             }
-            FieldKind::Singular(SingularField { ref elem, ref flag }) => {
+            FieldKind::Singular(SingularField { ref elem, has_bit: Some(bit), .. }) => {
+                let converted = ty.into_target(&elem.rust_storage_type(), value);
+                w.write_line(&format!("{} = {};", field, converted));
+                w.write_line(&format!(
+                    "{}.has_bits[{}] |= 0x{:x};",
+                    target,
+                    bit / 32,
+                    1u32 << (bit % 32)
+                ));
+            }
+            FieldKind::Singular(SingularField { ref elem, ref flag, .. }) => {
                 let converted = ty.into_target(&elem.rust_storage_type(), value);
                 let wrapped = if *flag == SingularFieldFlag::WithoutFlag {
                     converted
                 } else {
                     self.full_storage_type().wrap_value(&converted)
                 };
-                self.write_self_field_assign(w, &wrapped);
+                w.write_line(&format!("{} = {};", field, wrapped));
             }
             FieldKind::Oneof(..) => unreachable!(),
         }
@@ -1181,7 +1775,8 @@ impl<'a> FieldGen<'a> {
         } else {
             match self.full_storage_type() {
                 RustType::SingularField(..) |
-                RustType::SingularPtrField(..) => {
+                RustType::SingularPtrField(..) |
+                RustType::LazyMessageField(..) => {
                     let self_field = self.self_field();
                     w.write_line(&format!("{}.set_default();", self_field));
                 }
@@ -1275,7 +1870,15 @@ impl<'a> FieldGen<'a> {
             PrimitiveTypeVariant::Carllerche => "carllerche_",
             PrimitiveTypeVariant::Default => "",
         };
-        let type_name_for_fn = protobuf_name(self.proto_type);
+        let type_name_for_fn = if self.is_lazy() {
+            "lazy_message".to_owned()
+        } else if self.is_option_box() {
+            "option_box_message".to_owned()
+        } else if self.is_vec_not_copy() {
+            format!("vec_{}", protobuf_name(self.proto_type))
+        } else {
+            protobuf_name(self.proto_type).to_owned()
+        };
         w.write_line(&format!(
             "::protobuf::rt::read_{}_{}{}_into(wire_type, is, &mut self.{})?;",
             singular_or_repeated,
@@ -1385,6 +1988,13 @@ impl<'a> FieldGen<'a> {
                     self.proto_field.number()
                 ));
             }
+            _ if field.is_smallvec() => {
+                w.write_line(&format!(
+                    "::protobuf::rt::read_repeated_smallvec_into::<{}, _>({}, is, &mut self.{})?;",
+                    field.elem.lib_protobuf_type(),
+                    wire_type_var,
+                    self.rust_name));
+            }
             _ => {
                 w.write_line(&format!(
                     "::protobuf::rt::read_repeated_{}_into({}, is, &mut self.{})?;",
@@ -1405,6 +2015,75 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    // Write `merge_from_message` part for this field, `other` is `&Self`
+    pub fn write_merge_from_message_field(&self, w: &mut CodeWriter) {
+        let self_field = self.self_field();
+        let other_field = format!("other.{}", self.rust_name);
+        match self.kind {
+            FieldKind::Oneof(..) => panic!("oneof is merged by message"),
+            FieldKind::Map(..) => {
+                w.for_stmt(format!("&{}", other_field), "(k, v)", |w| {
+                    w.write_line(&format!("{}.insert(k.clone(), v.clone());", self_field));
+                });
+            }
+            FieldKind::Repeated(..) => {
+                w.for_stmt(format!("&{}", other_field), "v", |w| {
+                    w.write_line(&format!("{}.push(v.clone());", self_field));
+                });
+            }
+            FieldKind::Singular(..) if self.is_lazy() => {
+                w.write_line(&format!("{}.merge_from_field(&{});", self_field, other_field));
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                elem: FieldElem::Message(..),
+                ..
+            }) => {
+                w.if_let_stmt("Some(v)", &format!("{}.as_ref()", other_field), |w| {
+                    w.write_line(&format!("self.mut_{}().merge_from_message(v);", self.rust_name));
+                });
+            }
+            FieldKind::Singular(SingularField { has_bit: Some(bit), .. }) => {
+                let (word, mask) = self.has_bit_word_mask(bit);
+                let other_word = word.replacen("self.", "other.", 1);
+                w.if_stmt(format!("{} & {} != 0", other_word, mask), |w| {
+                    w.write_line(&format!("{} = {};", self_field, other_field));
+                    self.write_set_has_bit(bit, w);
+                });
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithFlag { .. },
+                ..
+            }) => {
+                w.if_stmt(format!("{}.is_some()", other_field), |w| {
+                    w.write_line(&format!("{} = {}.clone();", self_field, other_field));
+                });
+            }
+            FieldKind::Singular(SingularField {
+                flag: SingularFieldFlag::WithoutFlag,
+                ref elem,
+                ..
+            }) => {
+                let cond = match *elem {
+                    FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_STRING, ..) |
+                    FieldElem::Primitive(FieldDescriptorProto_Type::TYPE_BYTES, ..) => {
+                        format!("!{}.is_empty()", other_field)
+                    }
+                    _ => {
+                        format!(
+                            "{} != {}",
+                            other_field,
+                            self.full_storage_type().default_value()
+                        )
+                    }
+                };
+                w.if_stmt(cond, |w| {
+                    w.write_line(&format!("{} = {}.clone();", self_field, other_field));
+                });
+            }
+        }
+    }
+
     fn self_field_vec_packed_size(&self) -> String {
         match self.kind {
             FieldKind::Repeated(RepeatedField { packed: true, .. }) => {
@@ -1427,12 +2106,23 @@ impl<'a> FieldGen<'a> {
         item_var: &str,
         item_var_type: &RustType,
         sum_var: &str,
+        sizes: SizesStorage,
     ) {
         assert!(!self.is_repeated_packed());
 
         match self.proto_type {
             FieldDescriptorProto_Type::TYPE_MESSAGE => {
-                w.write_line(&format!("let len = {}.compute_size();", item_var));
+                match sizes {
+                    SizesStorage::CachedSize => {
+                        w.write_line(&format!("let len = {}.compute_size();", item_var));
+                    }
+                    SizesStorage::SizeCache => {
+                        w.write_line(&format!(
+                            "let len = {}.compute_size_with_cache(sizes);",
+                            item_var
+                        ));
+                    }
+                }
                 let tag_size = self.tag_size();
                 w.write_line(&format!(
                     "{} += {} + ::protobuf::rt::compute_raw_varint32_size(len) + len;",
@@ -1450,16 +2140,16 @@ impl<'a> FieldGen<'a> {
         }
     }
 
-    pub fn write_message_write_field(&self, w: &mut CodeWriter) {
+    pub fn write_message_write_field(&self, w: &mut CodeWriter, sizes: SizesStorage) {
         match self.kind {
             FieldKind::Singular(..) => {
                 self.write_if_let_self_field_is_some(w, |v, v_type, w| {
-                    self.write_write_element(w, "os", v, v_type);
+                    self.write_write_element(w, "os", v, v_type, sizes);
                 });
             }
             FieldKind::Repeated(RepeatedField { packed: false, .. }) => {
                 self.write_for_self_field(w, "v", |w, v_type| {
-                    self.write_write_element(w, "os", "v", v_type);
+                    self.write_write_element(w, "os", "v", v_type, sizes);
                 });
             }
             FieldKind::Repeated(RepeatedField { packed: true, .. }) => {
@@ -1485,19 +2175,37 @@ impl<'a> FieldGen<'a> {
                 });
             }
             FieldKind::Map(MapField { ref key, ref value, .. }) => {
-                w.write_line(&format!(
-                    "::protobuf::rt::write_map_with_cached_sizes::<{}, {}>({}, &{}, os)?;",
-                    key.lib_protobuf_type(),
-                    value.lib_protobuf_type(),
-                    self.proto_field.number(),
-                    self.self_field()
-                ));
+                match sizes {
+                    SizesStorage::CachedSize => {
+                        w.write_line(&format!(
+                            "::protobuf::rt::write_map_with_cached_sizes::<{}, {}>({}, &{}, os)?;",
+                            key.lib_protobuf_type(),
+                            value.lib_protobuf_type(),
+                            self.proto_field.number(),
+                            self.self_field()
+                        ));
+                    }
+                    SizesStorage::SizeCache => {
+                        w.write_line(&format!(
+                            "::protobuf::rt::write_map_with_size_cache::<{}, {}>({}, &{}, os, sizes)?;",
+                            key.lib_protobuf_type(),
+                            value.lib_protobuf_type(),
+                            self.proto_field.number(),
+                            self.self_field()
+                        ));
+                    }
+                }
             }
             FieldKind::Oneof(..) => unreachable!(),
         };
     }
 
-    pub fn write_message_compute_field_size(&self, sum_var: &str, w: &mut CodeWriter) {
+    pub fn write_message_compute_field_size(
+        &self,
+        sum_var: &str,
+        w: &mut CodeWriter,
+        sizes: SizesStorage,
+    ) {
         match self.kind {
             FieldKind::Singular(..) => {
                 self.write_if_let_self_field_is_some(w, |v, v_type, w| {
@@ -1507,7 +2215,7 @@ impl<'a> FieldGen<'a> {
                             w.write_line(&format!("{} += {};", sum_var, (s + tag_size) as isize));
                         }
                         None => {
-                            self.write_element_size(w, v, v_type, sum_var);
+                            self.write_element_size(w, v, v_type, sum_var, sizes);
                         }
                     };
                 });
@@ -1526,27 +2234,120 @@ impl<'a> FieldGen<'a> {
                     }
                     None => {
                         self.write_for_self_field(w, "value", |w, value_type| {
-                            self.write_element_size(w, "value", value_type, sum_var);
+                            self.write_element_size(w, "value", value_type, sum_var, sizes);
                         });
                     }
                 };
             }
             FieldKind::Map(MapField { ref key, ref value, .. }) => {
+                match sizes {
+                    SizesStorage::CachedSize => {
+                        w.write_line(&format!(
+                            "{} += ::protobuf::rt::compute_map_size::<{}, {}>({}, &{});",
+                            sum_var,
+                            key.lib_protobuf_type(),
+                            value.lib_protobuf_type(),
+                            self.proto_field.number(),
+                            self.self_field()
+                        ));
+                    }
+                    SizesStorage::SizeCache => {
+                        w.write_line(&format!(
+                            "{} += ::protobuf::rt::compute_map_size_with_cache::<{}, {}>({}, &{}, sizes);",
+                            sum_var,
+                            key.lib_protobuf_type(),
+                            value.lib_protobuf_type(),
+                            self.proto_field.number(),
+                            self.self_field()
+                        ));
+                    }
+                }
+            }
+            FieldKind::Repeated(RepeatedField { packed: true, .. }) => {
+                self.write_if_self_field_is_not_empty(w, |w| {
+                    let size_expr = self.self_field_vec_packed_size();
+                    w.write_line(&format!("{} += {};", sum_var, size_expr));
+                });
+            }
+            FieldKind::Oneof(..) => unreachable!(),
+        }
+    }
+
+    pub fn write_element_size_upper_bound(
+        &self,
+        w: &mut CodeWriter,
+        item_var: &str,
+        item_var_type: &RustType,
+        sum_var: &str,
+    ) {
+        match field_type_max_size(self.proto_type) {
+            Some(s) => {
+                w.write_line(&format!("{} += {};", sum_var, s + self.tag_size()));
+            }
+            None if self.proto_type == FieldDescriptorProto_Type::TYPE_MESSAGE => {
+                w.write_line(&format!("let len = {}.size_hint_upper_bound();", item_var));
                 w.write_line(&format!(
-                    "{} += ::protobuf::rt::compute_map_size::<{}, {}>({}, &{});",
+                    "{} += {} + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;",
                     sum_var,
-                    key.lib_protobuf_type(),
-                    value.lib_protobuf_type(),
-                    self.proto_field.number(),
-                    self.self_field()
+                    self.tag_size()
+                ));
+            }
+            None => {
+                w.write_line(&format!(
+                    "{} += {} as u64;",
+                    sum_var,
+                    self.element_size(item_var, item_var_type)
                 ));
             }
+        }
+    }
+
+    pub fn write_message_field_size_upper_bound(&self, sum_var: &str, w: &mut CodeWriter) {
+        match self.kind {
+            FieldKind::Singular(..) => {
+                self.write_if_let_self_field_is_some(w, |v, v_type, w| {
+                    self.write_element_size_upper_bound(w, v, v_type, sum_var);
+                });
+            }
+            FieldKind::Repeated(RepeatedField { packed: false, .. }) => {
+                match field_type_max_size(self.proto_type) {
+                    Some(s) => {
+                        w.write_line(&format!(
+                            "{} += {} * {}.len() as u64;",
+                            sum_var,
+                            s + self.tag_size(),
+                            self.self_field()
+                        ));
+                    }
+                    None => {
+                        self.write_for_self_field(w, "value", |w, value_type| {
+                            self.write_element_size_upper_bound(w, "value", value_type, sum_var);
+                        });
+                    }
+                }
+            }
             FieldKind::Repeated(RepeatedField { packed: true, .. }) => {
                 self.write_if_self_field_is_not_empty(w, |w| {
-                    let size_expr = self.self_field_vec_packed_size();
-                    w.write_line(&format!("{} += {};", sum_var, size_expr));
+                    // tag, length of at most 5 bytes, data
+                    w.write_line(&format!(
+                        "{} += {} + {} * {}.len() as u64;",
+                        sum_var,
+                        self.tag_size() + 5,
+                        field_type_max_size(self.proto_type).unwrap(),
+                        self.self_field()
+                    ));
                 });
             }
+            FieldKind::Map(MapField { ref key, ref value, .. }) => {
+                w.write_line(&format!(
+                    "{} += ::protobuf::rt::compute_map_size::<{}, {}>({}, &{}) as u64;",
+                    sum_var,
+                    key.lib_protobuf_type(),
+                    value.lib_protobuf_type(),
+                    self.proto_field.number(),
+                    self.self_field()
+                ));
+            }
             FieldKind::Oneof(..) => unreachable!(),
         }
     }
@@ -1555,17 +2356,25 @@ impl<'a> FieldGen<'a> {
         let get_xxx_return_type = self.get_xxx_return_type();
 
         if self.proto_type == FieldDescriptorProto_Type::TYPE_MESSAGE {
-            let self_field = self.self_field();
+            let as_option = self.self_field_as_option();
             let ref field_type_name = self.elem().rust_storage_type();
             w.write_line(&format!(
-                "{}.as_ref().unwrap_or_else(|| {}::default_instance())",
-                self_field,
+                "{}.unwrap_or_else(|| {}::default_instance())",
+                as_option.value,
                 field_type_name
             ));
         } else {
             let get_xxx_default_value_rust = self.get_xxx_default_value_rust();
             let self_field = self.self_field();
             match self.singular() {
+                &SingularField { has_bit: Some(..), .. } => {
+                    w.write_line(&format!(
+                        "if {} {{ {} }} else {{ {} }}",
+                        self.self_field_is_some(),
+                        self_field,
+                        get_xxx_default_value_rust
+                    ));
+                }
                 &SingularField { flag: SingularFieldFlag::WithFlag { .. }, .. } => {
                     if get_xxx_return_type.is_ref() {
                         let as_option = self.self_field_as_option();
@@ -1596,9 +2405,23 @@ impl<'a> FieldGen<'a> {
 
     fn write_message_field_get(&self, w: &mut CodeWriter) {
         let get_xxx_return_type = self.get_xxx_return_type();
-        let fn_def = format!("get_{}(&self) -> {}", self.rust_name, get_xxx_return_type);
+        if let Some(rust_field_type) = self.rust_field_type() {
+            let fn_def = format!("get_{}(&self) -> {}", self.rust_name, rust_field_type);
+            w.pub_fn(&fn_def, |w| {
+                w.block(&format!("let v: {} = {{", get_xxx_return_type), "};", |w| {
+                    self.write_message_field_get_value(w);
+                });
+                w.write_line("::protobuf::ProtobufConvert::from_protobuf(v)");
+            });
+        } else {
+            let fn_def = format!("get_{}(&self) -> {}", self.rust_name, get_xxx_return_type);
+            w.pub_fn(&fn_def, |w| self.write_message_field_get_value(w));
+        }
+    }
 
-        w.pub_fn(&fn_def, |w| match self.kind {
+    fn write_message_field_get_value(&self, w: &mut CodeWriter) {
+        let get_xxx_return_type = self.get_xxx_return_type();
+        match self.kind {
             FieldKind::Oneof(OneofField { ref elem, .. }) => {
                 let self_field_oneof = self.self_field_oneof();
                 w.match_expr(self_field_oneof, |w| {
@@ -1626,7 +2449,7 @@ impl<'a> FieldGen<'a> {
                 let self_field = self.self_field();
                 w.write_line(&format!("&{}", self_field));
             }
-        });
+        }
     }
 
     fn has_has(&self) -> bool {
@@ -1653,6 +2476,10 @@ impl<'a> FieldGen<'a> {
         }
     }
 
+    fn has_set(&self) -> bool {
+        !(self.ref_accessors_only && self.has_mut())
+    }
+
     fn has_take(&self) -> bool {
         match self.kind {
             FieldKind::Repeated(..) |
@@ -1690,7 +2517,17 @@ impl<'a> FieldGen<'a> {
         let set_xxx_param_type = self.set_xxx_param_type();
         w.comment("Param is passed by value, moved");
         let ref name = self.rust_name;
-        w.pub_fn(&format!("set_{}(&mut self, v: {})", name, set_xxx_param_type), |w| {
+        let param_type = match self.rust_field_type() {
+            Some(rust_field_type) => rust_field_type.to_owned(),
+            None => set_xxx_param_type.to_string(),
+        };
+        w.pub_fn(&format!("set_{}(&mut self, v: {})", name, param_type), |w| {
+            if self.rust_field_type().is_some() {
+                w.write_line(&format!(
+                    "let v: {} = ::protobuf::ProtobufConvert::to_protobuf(&v);",
+                    set_xxx_param_type
+                ));
+            }
             if !self.is_oneof() {
                 self.write_self_field_assign_value(w, "v", &set_xxx_param_type);
             } else {
@@ -1826,8 +2663,15 @@ impl<'a> FieldGen<'a> {
                 FieldKind::Singular(SingularField {
                     ref elem,
                     flag: SingularFieldFlag::WithFlag { .. },
+                    ..
                 }) => {
-                    if !elem.is_copy() {
+                    if self.is_option_box() {
+                        w.write_line(&format!(
+                            "{}.take().map(|v| *v).unwrap_or_else(|| {})",
+                            self.self_field(),
+                            elem.rust_storage_type().default_value()
+                        ));
+                    } else if !elem.is_copy() {
                         w.write_line(&format!(
                             "{}.take().unwrap_or_else(|| {})",
                             self.self_field(),
@@ -1863,8 +2707,10 @@ impl<'a> FieldGen<'a> {
             self.write_message_field_has(w);
         }
 
-        w.write_line("");
-        self.write_message_field_set(w);
+        if self.has_set() {
+            w.write_line("");
+            self.write_message_field_set(w);
+        }
 
         if self.has_mut() {
             w.write_line("");
@@ -1878,5 +2724,141 @@ impl<'a> FieldGen<'a> {
 
         w.write_line("");
         self.write_message_field_get(w);
+
+        if self.has_get_as() {
+            w.write_line("");
+            self.write_message_field_get_as(w);
+        }
+
+        if let Some(value_type) = self.wrapper_value_type() {
+            w.write_line("");
+            self.write_message_field_get_opt(w, value_type);
+            if self.has_set() {
+                w.write_line("");
+                self.write_message_field_set_opt(w, value_type);
+            }
+        }
+    }
+
+    /// Type of value of well-known wrapper type (`google.protobuf.Int32Value` etc.)
+    /// of singular field, `None` for other fields.
+    fn wrapper_value_type(&self) -> Option<&'static str> {
+        match self.kind {
+            FieldKind::Singular(..) if self.proto_type == FieldDescriptorProto_Type::TYPE_MESSAGE => {}
+            _ => return None,
+        }
+        Some(match self.proto_field.field.get_type_name() {
+            ".google.protobuf.DoubleValue" => "f64",
+            ".google.protobuf.FloatValue" => "f32",
+            ".google.protobuf.Int64Value" => "i64",
+            ".google.protobuf.UInt64Value" => "u64",
+            ".google.protobuf.Int32Value" => "i32",
+            ".google.protobuf.UInt32Value" => "u32",
+            ".google.protobuf.BoolValue" => "bool",
+            ".google.protobuf.StringValue" => "::std::string::String",
+            ".google.protobuf.BytesValue" => "::std::vec::Vec<u8>",
+            _ => return None,
+        })
+    }
+
+    fn write_message_field_get_opt(&self, w: &mut CodeWriter, value_type: &str) {
+        let fn_def = format!(
+            "get_{}_opt(&self) -> ::std::option::Option<{}>",
+            self.rust_name,
+            value_type
+        );
+        w.pub_fn(&fn_def, |w| {
+            w.if_stmt(&format!("!self.{}()", self.has_name()), |w| {
+                w.write_line("return ::std::option::Option::None;");
+            });
+            w.write_line(&format!(
+                "::std::option::Option::Some(::protobuf::wrapper_option::WrapperValue::value_ref(self.get_{}()).clone())",
+                self.rust_name
+            ));
+        });
+    }
+
+    fn write_message_field_set_opt(&self, w: &mut CodeWriter, value_type: &str) {
+        let fn_def = format!(
+            "set_{}_opt(&mut self, v: ::std::option::Option<{}>)",
+            self.rust_name,
+            value_type
+        );
+        w.pub_fn(&fn_def, |w| {
+            w.match_expr("v", |w| {
+                w.case_expr(
+                    "::std::option::Option::Some(v)",
+                    &format!(
+                        "self.set_{}(::protobuf::wrapper_option::WrapperValue::from_value(v))",
+                        self.rust_name
+                    ),
+                );
+                w.case_expr("::std::option::Option::None", &format!("self.{}()", self.clear_field_func()));
+            });
+        });
+    }
+
+    /// Type given with `rust_field_type` option, which is only applied
+    /// to singular numeric, `bool` and enum fields with accessors.
+    pub fn rust_field_type(&self) -> Option<&str> {
+        let rust_field_type = match self.rust_field_type {
+            Some(ref t) if self.generate_accessors => t,
+            _ => return None,
+        };
+        match self.kind {
+            FieldKind::Singular(SingularField { ref elem, .. }) |
+            FieldKind::Oneof(OneofField { ref elem, .. }) => match elem.rust_storage_type() {
+                RustType::Int(..) | RustType::Float(..) | RustType::Bool | RustType::Enum(..) => {
+                    Some(rust_field_type)
+                }
+                _ => None,
+            },
+            FieldKind::Repeated(..) |
+            FieldKind::Map(..) => None,
+        }
+    }
+
+    // `checked_as` is only meaningful for singular integer fields
+    fn has_get_as(&self) -> bool {
+        if !self.checked_as || self.rust_field_type().is_some() {
+            return false;
+        }
+        match self.kind {
+            FieldKind::Singular(SingularField { ref elem, .. }) |
+            FieldKind::Oneof(OneofField { ref elem, .. }) => match elem.rust_storage_type() {
+                RustType::Int(..) => true,
+                _ => false,
+            },
+            FieldKind::Repeated(..) |
+            FieldKind::Map(..) => false,
+        }
+    }
+
+    fn write_message_field_get_as(&self, w: &mut CodeWriter) {
+        let fn_def = format!(
+            "get_{}_as<T : ::std::convert::TryFrom<{}>>(&self) -> {}",
+            self.rust_name,
+            self.elem().rust_storage_type(),
+            "::std::result::Result<T, ::protobuf::ConvertError>",
+        );
+        w.pub_fn(&fn_def, |w| {
+            w.write_line(&format!(
+                "::protobuf::rt::checked_convert(\"{}\", self.get_{}())",
+                self.proto_field.name(),
+                self.rust_name,
+            ));
+        });
+    }
+
+    /// Getter named as in rust-protobuf 3: `foo()` delegating to `get_foo()`
+    pub fn write_message_field_get_v3(&self, w: &mut CodeWriter) {
+        let return_type = match self.rust_field_type() {
+            Some(rust_field_type) => rust_field_type.to_owned(),
+            None => self.get_xxx_return_type().to_string(),
+        };
+        let fn_def = format!("{}(&self) -> {}", self.rust_name, return_type);
+        w.pub_fn(&fn_def, |w| {
+            w.write_line(&format!("self.get_{}()", self.rust_name));
+        });
     }
 }
@@ -0,0 +1,124 @@
+//! Constants with JSON names of message fields.
+//!
+//! For message `Person` with field `first_name` generated code contains
+//!
+//! ```ignore
+//! pub mod json_names {
+//!     pub mod Person {
+//!         pub const FIRST_NAME: &'static str = "firstName";
+//!     }
+//! }
+//! ```
+
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptorx::*;
+
+use code_writer::CodeWriter;
+use customize::customize_from_rustproto_for_message;
+use customize::Customize;
+
+
+/// JSON name of the field: `json_name` if it is set by `protoc`,
+/// otherwise field name converted to lowerCamelCase the same way `protoc` does.
+pub fn json_name(field: &FieldDescriptorProto) -> String {
+    if field.has_json_name() {
+        return field.get_json_name().to_owned();
+    }
+
+    let mut r = String::new();
+    let mut capitalize_next = false;
+    for c in field.get_name().chars() {
+        if c == '_' {
+            capitalize_next = true;
+        } else if capitalize_next {
+            r.extend(c.to_uppercase());
+            capitalize_next = false;
+        } else {
+            r.push(c);
+        }
+    }
+    r
+}
+
+struct MessageJsonNames {
+    rust_name: String,
+    // (constant name, JSON name)
+    fields: Vec<(String, String)>,
+}
+
+fn collect_messages(scope: &Scope, customize: &Customize, r: &mut Vec<MessageJsonNames>) {
+    for message in scope.get_messages() {
+        // map entries are not generated
+        if message.map_entry().is_some() {
+            continue;
+        }
+
+        let mut customize = customize.clone();
+        customize.update_with(&customize_from_rustproto_for_message(message.message.get_options()));
+
+        if customize.json_names.unwrap_or(false) {
+            r.push(MessageJsonNames {
+                rust_name: message.rust_name(),
+                fields: message
+                    .fields()
+                    .iter()
+                    .map(|f| (f.rust_name().to_uppercase(), json_name(f.field)))
+                    .collect(),
+            });
+        }
+
+        collect_messages(&message.to_scope(), &customize, r);
+    }
+}
+
+pub fn write_json_names(file: &FileDescriptorProto, w: &mut CodeWriter, customize: &Customize) {
+    let mut messages = Vec::new();
+    collect_messages(&FileScope { file_descriptor: file }.to_scope(), customize, &mut messages);
+
+    if messages.is_empty() {
+        return;
+    }
+
+    w.write_line("");
+    w.pub_mod("json_names", |w| {
+        for (i, message) in messages.iter().enumerate() {
+            if i != 0 {
+                w.write_line("");
+            }
+            w.pub_mod(&message.rust_name, |w| {
+                for &(ref name, ref json_name) in &message.fields {
+                    w.pub_const(name, "&'static str", &format!("{:?}", json_name));
+                }
+            });
+        }
+    });
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn field(name: &str) -> FieldDescriptorProto {
+        let mut f = FieldDescriptorProto::new();
+        f.set_name(name.to_owned());
+        f
+    }
+
+    #[test]
+    fn json_name_from_field_name() {
+        assert_eq!("foo", json_name(&field("foo")));
+        assert_eq!("fooBar", json_name(&field("foo_bar")));
+        assert_eq!("fooBar2", json_name(&field("foo_bar_2")));
+        assert_eq!("FooBar", json_name(&field("_foo__bar")));
+        assert_eq!("fooBAR", json_name(&field("fooBAR")));
+    }
+
+    #[test]
+    fn json_name_from_descriptor() {
+        let mut f = field("foo_bar");
+        f.set_json_name("custom".to_owned());
+        assert_eq!("custom", json_name(&f));
+    }
+}
@@ -1,8 +1,10 @@
 extern crate protobuf;
+extern crate miniz_oxide;
 
 use std::collections::hash_map::HashMap;
 use std::fmt::Write as FmtWrite;
 use std::path::Path;
+use std::fs;
 use std::fs::File;
 use std::io;
 use std::io::Write as Write;
@@ -20,15 +22,26 @@ mod field;
 mod customize;
 mod extensions;
 mod oneof;
+mod package_modules;
+mod split_messages;
+mod json_names;
+mod report;
 
 pub use customize::Customize;
+pub use customize::CustomizeParseParameterError;
 use customize::customize_from_rustproto_for_file;
+use customize::lite_runtime;
 
 pub mod code_writer;
+pub mod analysis;
+pub mod lint;
+pub mod validate;
+pub mod bootstrap;
 
 use self::message::*;
 use self::enums::*;
 use self::extensions::*;
+use self::json_names::write_json_names;
 use self::code_writer::CodeWriter;
 
 fn escape_byte(s: &mut String, b: u8) {
@@ -50,8 +63,17 @@ fn escape_byte(s: &mut String, b: u8) {
     }
 }
 
-fn write_file_descriptor_data(file: &FileDescriptorProto, w: &mut CodeWriter) {
-    let fdp_bytes = file.write_to_bytes().unwrap();
+fn write_file_descriptor_data(file: &FileDescriptorProto, customize: &Customize, w: &mut CodeWriter) {
+    let compress = customize.compress_descriptor.unwrap_or(false);
+
+    let mut file = file.clone();
+    if customize.strip_source_code_info.unwrap_or(false) {
+        file.clear_source_code_info();
+    }
+    let mut fdp_bytes = file.write_to_bytes().unwrap();
+    if compress {
+        fdp_bytes = miniz_oxide::deflate::compress_to_vec(&fdp_bytes, 9);
+    }
     w.write_line("static file_descriptor_proto_data: &'static [u8] = b\"\\");
     w.indented(|w| {
         const MAX_LINE_LEN: usize = 72;
@@ -87,14 +109,19 @@ fn write_file_descriptor_data(file: &FileDescriptorProto, w: &mut CodeWriter) {
     );
     w.write_line("");
     w.def_fn("parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto", |w| {
-        w.write_line("::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()");
+        if compress {
+            w.comment("requires `with-deflate` feature of protobuf crate");
+            w.write_line(
+                "::protobuf::rt::parse_compressed_file_descriptor(file_descriptor_proto_data)",
+            );
+        } else {
+            w.write_line("::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()");
+        }
     });
     w.write_line("");
     w.pub_fn("file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto", |w| {
-        w.unsafe_expr(|w| {
-            w.block("file_descriptor_proto_lazy.get(|| {", "})", |w| {
-                w.write_line("parse_descriptor_proto()");
-            });
+        w.block("file_descriptor_proto_lazy.get(|| {", "})", |w| {
+            w.write_line("parse_descriptor_proto()");
         });
     });
 }
@@ -104,7 +131,7 @@ fn gen_file(
     _files_map: &HashMap<&str, &FileDescriptorProto>,
     root_scope: &RootScope,
     customize: &Customize,
-) -> Option<compiler_plugin::GenResult> {
+) -> Vec<compiler_plugin::GenResult> {
     // TODO: use it
     let mut customize = customize.clone();
     // options specified in invocation have precedence over options specified in file
@@ -112,6 +139,17 @@ fn gen_file(
 
     let scope = FileScope { file_descriptor: file }.to_scope();
 
+    let package_modules = customize.package_modules.unwrap_or(false);
+    let split_messages = customize.split_messages.unwrap_or(false);
+
+    let name = if package_modules {
+        package_modules::file_output_name(file)
+    } else {
+        format!("{}.rs", proto_path_to_rust_mod(file.get_name()))
+    };
+
+    let mut results = Vec::new();
+
     let mut v = Vec::new();
 
     {
@@ -125,11 +163,25 @@ fn gen_file(
             "use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;",
         );
 
-        for message in &scope.get_messages() {
-            // ignore map entries, because they are not used in map fields
-            if message.map_entry().is_none() {
+        if split_messages {
+            for (message, mod_name) in split_messages::message_mods(file) {
                 w.write_line("");
-                MessageGen::new(message, &root_scope, &customize).write(&mut w);
+                split_messages::write_message_mod(&message, &mod_name, &customize, &mut w);
+                results.push(split_messages::gen_message_file(
+                    &message,
+                    &mod_name,
+                    &name,
+                    root_scope,
+                    &customize,
+                ));
+            }
+        } else {
+            for message in &scope.get_messages() {
+                // ignore map entries, because they are not used in map fields
+                if message.map_entry().is_none() {
+                    w.write_line("");
+                    MessageGen::new(message, &root_scope, &customize).write(&mut w);
+                }
             }
         }
         for enum_type in &scope.get_enums() {
@@ -137,24 +189,36 @@ fn gen_file(
             EnumGen::new(enum_type, file, &customize).write(&mut w);
         }
 
-        write_extensions(file, &root_scope, &mut w);
+        write_extensions(file, &root_scope, &mut w, &customize);
 
-        if file.get_options().get_optimize_for() != FileOptions_OptimizeMode::LITE_RUNTIME {
+        write_json_names(file, &mut w, &customize);
+
+        // with split messages aliases are written into message modules
+        if package_modules && !split_messages {
+            package_modules::write_nested_type_modules(file, &mut w);
+        }
+
+        if !lite_runtime(file, &customize) {
             w.write_line("");
-            write_file_descriptor_data(file, &mut w);
+            write_file_descriptor_data(file, &customize, &mut w);
         }
+
+        w.write_line("");
+        w.insertion_point("module_scope");
     }
 
-    Some(compiler_plugin::GenResult {
-        name: format!("{}.rs", proto_path_to_rust_mod(file.get_name())),
+    if customize.gen_report.unwrap_or(false) {
+        results.push(report::gen_report(file, &name, root_scope, &customize));
+    }
+
+    results.insert(0, compiler_plugin::GenResult {
+        name: name,
         content: v,
-    })
+    });
+    results
 }
 
-// This function is also used externally by cargo plugin
-// https://github.com/plietar/rust-protobuf-build
-// So be careful changing its signature.
-pub fn gen(
+fn gen_validated(
     file_descriptors: &[FileDescriptorProto],
     files_to_generate: &[String],
     customize: &Customize,
@@ -165,19 +229,37 @@ pub fn gen(
     let files_map: HashMap<&str, &FileDescriptorProto> =
         file_descriptors.iter().map(|f| (f.get_name(), f)).collect();
 
-    let all_file_names: Vec<&str> = file_descriptors.iter().map(|f| f.get_name()).collect();
-
+    let mut generated_files = Vec::new();
     for file_name in files_to_generate {
-        let file = files_map.get(&file_name[..]).expect(&format!(
-            "file not found in file descriptors: {:?}, files: {:?}",
-            file_name,
-            all_file_names
-        ));
+        // presence of files is checked by `validate`
+        let file = files_map[&file_name[..]];
         results.extend(gen_file(file, &files_map, &root_scope, customize));
+        generated_files.push(file);
+    }
+
+    if customize.package_modules.unwrap_or(false) {
+        results.extend(package_modules::gen_mod_rs(&generated_files));
     }
     results
 }
 
+// This function is also used externally by cargo plugin
+// https://github.com/plietar/rust-protobuf-build
+// So be careful changing its signature.
+/// Generate code for given files.
+///
+/// Panics if code cannot be generated for given descriptors, see `validate::validate`.
+pub fn gen(
+    file_descriptors: &[FileDescriptorProto],
+    files_to_generate: &[String],
+    customize: &Customize,
+) -> Vec<compiler_plugin::GenResult> {
+    if let Err(e) = validate::validate(file_descriptors, files_to_generate, customize) {
+        panic!("{}", e);
+    }
+    gen_validated(file_descriptors, files_to_generate, customize)
+}
+
 pub fn gen_and_write(
     file_descriptors: &[FileDescriptorProto],
     files_to_generate: &[String],
@@ -185,11 +267,17 @@ pub fn gen_and_write(
     customize: &Customize)
     -> io::Result<()>
 {
-    let results = gen(file_descriptors, files_to_generate, customize);
+    // nothing is written if any of files cannot be generated
+    validate::validate(file_descriptors, files_to_generate, customize)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let results = gen_validated(file_descriptors, files_to_generate, customize);
 
     for r in &results {
         let mut file_path = out_dir.to_owned();
         file_path.push(&r.name);
+        if let Some(parent) = file_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
         let mut file_writer = File::create(&file_path)?;
         file_writer.write_all(&r.content)?;
         file_writer.flush()?;
@@ -198,6 +286,15 @@ pub fn gen_and_write(
     Ok(())
 }
 
+/// `main` of `protoc-gen-rust` plugin.
+///
+/// Options can be passed in plugin parameter,
+/// e. g. `protoc --rust_out=expose_fields,lazy_field:out`,
+/// see `Customize::parse_from_parameter`.
 pub fn protoc_gen_rust_main() {
-    compiler_plugin::plugin_main(|fds, files| gen(fds, files, &Default::default()));
+    compiler_plugin::plugin_main_with_parameter(|fds, files, parameter| {
+        let customize = Customize::parse_from_parameter(parameter).map_err(|e| e.to_string())?;
+        validate::validate(fds, files, &customize).map_err(|e| e.to_string())?;
+        Ok(gen_validated(fds, files, &customize))
+    });
 }
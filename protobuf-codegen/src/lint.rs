@@ -0,0 +1,400 @@
+//! Checks of `.proto` definitions for schema conventions.
+//!
+//! Intended to be run from build scripts before code generation,
+//! so that violations fail the build:
+//!
+//! ```ignore
+//! let lint = protobuf_codegen::lint::Lint::new()
+//!     .allow(protobuf_codegen::lint::Rule::NoRequiredFields);
+//! if let Err(e) = lint.run(&file_descriptors) {
+//!     panic!("{}", e);
+//! }
+//! ```
+
+use std::error::Error;
+use std::fmt;
+
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::EnumDescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto_Label;
+use protobuf::descriptor::FieldDescriptorProto_Type;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptorx::full_name;
+
+
+/// Lint rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// Field names are `lower_snake_case`.
+    FieldNaming,
+    /// Every enum has a value with number zero,
+    /// which is default value of enum fields in proto3.
+    EnumZeroValue,
+    /// No fields are declared `required`.
+    NoRequiredFields,
+    /// Messages are not nested deeper than configured limit.
+    MessageNesting,
+    /// No fields have `weak` option, or `lazy` option on fields
+    /// other than singular non-oneof message fields,
+    /// because such options are ignored by code generator.
+    IgnoredFieldOptions,
+}
+
+impl Rule {
+    /// All rules.
+    pub fn all() -> &'static [Rule] {
+        static ALL: &'static [Rule] = &[
+            Rule::FieldNaming,
+            Rule::EnumZeroValue,
+            Rule::NoRequiredFields,
+            Rule::MessageNesting,
+            Rule::IgnoredFieldOptions,
+        ];
+        ALL
+    }
+
+    /// Rule name as displayed in violations.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Rule::FieldNaming => "field_naming",
+            Rule::EnumZeroValue => "enum_zero_value",
+            Rule::NoRequiredFields => "no_required_fields",
+            Rule::MessageNesting => "message_nesting",
+            Rule::IgnoredFieldOptions => "ignored_field_options",
+        }
+    }
+}
+
+/// Violation of a lint rule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub rule: Rule,
+    /// Name of `.proto` file.
+    pub file: String,
+    /// Fully qualified name of message, field or enum without leading dot.
+    pub element: String,
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}: {} [{}]", self.file, self.element, self.message, self.rule.name())
+    }
+}
+
+/// Error returned by `Lint::run`.
+#[derive(Debug)]
+pub struct LintError {
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for LintError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} lint violation(s):", self.violations.len())?;
+        for v in &self.violations {
+            write!(f, "\n{}", v)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for LintError {
+    fn description(&self) -> &str {
+        "lint violations"
+    }
+}
+
+/// Set of enabled rules and their parameters.
+///
+/// All rules are enabled by default, max message nesting is `3`.
+#[derive(Debug, Clone)]
+pub struct Lint {
+    rules: Vec<Rule>,
+    max_nesting: usize,
+}
+
+impl Default for Lint {
+    fn default() -> Lint {
+        Lint::new()
+    }
+}
+
+fn is_lower_snake_case(name: &str) -> bool {
+    let mut prev = '_';
+    for c in name.chars() {
+        let ok = match c {
+            '_' => prev != '_',
+            c if c.is_ascii_digit() => prev != '_',
+            c => c.is_ascii_lowercase(),
+        };
+        if !ok {
+            return false;
+        }
+        prev = c;
+    }
+    prev != '_'
+}
+
+struct Checker<'a> {
+    lint: &'a Lint,
+    file: &'a str,
+    violations: Vec<Violation>,
+}
+
+impl<'a> Checker<'a> {
+    fn report(&mut self, rule: Rule, element: String, message: String) {
+        if self.lint.is_enabled(rule) {
+            self.violations.push(Violation {
+                rule,
+                file: self.file.to_owned(),
+                element,
+                message,
+            });
+        }
+    }
+
+    fn check_enum(&mut self, prefix: &str, e: &EnumDescriptorProto) {
+        if !e.get_value().iter().any(|v| v.get_number() == 0) {
+            let name = full_name(prefix, e.get_name());
+            self.report(Rule::EnumZeroValue, name, "enum has no value with number 0".to_owned());
+        }
+    }
+
+    fn check_field_options(&mut self, field_name: &str, field: &FieldDescriptorProto) {
+        if field.get_options().get_weak() {
+            self.report(
+                Rule::IgnoredFieldOptions,
+                field_name.to_owned(),
+                "weak option is ignored, field is generated as regular field".to_owned(),
+            );
+        }
+        if field.get_options().get_lazy() {
+            let lazy_supported = field.get_field_type() == FieldDescriptorProto_Type::TYPE_MESSAGE
+                && field.get_label() != FieldDescriptorProto_Label::LABEL_REPEATED
+                && !field.has_oneof_index();
+            if !lazy_supported {
+                self.report(
+                    Rule::IgnoredFieldOptions,
+                    field_name.to_owned(),
+                    "lazy option is ignored on fields other than singular message fields"
+                        .to_owned(),
+                );
+            }
+        }
+    }
+
+    fn check_message(&mut self, prefix: &str, message: &DescriptorProto, depth: usize) {
+        let name = full_name(prefix, message.get_name());
+        if depth > self.lint.max_nesting {
+            self.report(
+                Rule::MessageNesting,
+                name.clone(),
+                format!("message nesting depth {} exceeds {}", depth, self.lint.max_nesting),
+            );
+        }
+
+        for field in message.get_field() {
+            let field_name = full_name(&name, field.get_name());
+            if !is_lower_snake_case(field.get_name()) {
+                self.report(
+                    Rule::FieldNaming,
+                    field_name.clone(),
+                    "field name is not lower_snake_case".to_owned(),
+                );
+            }
+            if field.get_label() == FieldDescriptorProto_Label::LABEL_REQUIRED {
+                self.report(
+                    Rule::NoRequiredFields,
+                    field_name.clone(),
+                    "field is required".to_owned(),
+                );
+            }
+            self.check_field_options(&field_name, field);
+        }
+
+        for e in message.get_enum_type() {
+            self.check_enum(&name, e);
+        }
+        for nested in message.get_nested_type() {
+            // map entries are generated by protoc
+            if !nested.get_options().get_map_entry() {
+                self.check_message(&name, nested, depth + 1);
+            }
+        }
+    }
+}
+
+impl Lint {
+    /// All rules enabled.
+    pub fn new() -> Lint {
+        Lint {
+            rules: Rule::all().to_vec(),
+            max_nesting: 3,
+        }
+    }
+
+    /// Disable rule.
+    pub fn allow(mut self, rule: Rule) -> Lint {
+        self.rules.retain(|r| *r != rule);
+        self
+    }
+
+    /// Enable rule.
+    pub fn deny(mut self, rule: Rule) -> Lint {
+        if !self.is_enabled(rule) {
+            self.rules.push(rule);
+        }
+        self
+    }
+
+    /// Max depth of message declarations, top-level message has depth `1`.
+    pub fn max_nesting(mut self, max_nesting: usize) -> Lint {
+        self.max_nesting = max_nesting;
+        self
+    }
+
+    /// Is rule enabled.
+    pub fn is_enabled(&self, rule: Rule) -> bool {
+        self.rules.contains(&rule)
+    }
+
+    /// Find violations of enabled rules in given files.
+    ///
+    /// Only definitions of given files are checked, so dependencies
+    /// not owned by the project (e. g. `descriptor.proto`) should not be passed.
+    pub fn check(&self, files: &[FileDescriptorProto]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for file in files {
+            let mut checker = Checker {
+                lint: self,
+                file: file.get_name(),
+                violations: Vec::new(),
+            };
+            for e in file.get_enum_type() {
+                checker.check_enum(file.get_package(), e);
+            }
+            for m in file.get_message_type() {
+                checker.check_message(file.get_package(), m, 1);
+            }
+            violations.extend(checker.violations);
+        }
+        violations
+    }
+
+    /// Check files and return error if there are violations.
+    pub fn run(&self, files: &[FileDescriptorProto]) -> Result<(), LintError> {
+        let violations = self.check(files);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(LintError { violations })
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use protobuf::descriptor::EnumValueDescriptorProto;
+
+    fn field(name: &str, label: FieldDescriptorProto_Label) -> FieldDescriptorProto {
+        let mut f = FieldDescriptorProto::new();
+        f.set_name(name.to_owned());
+        f.set_label(label);
+        f
+    }
+
+    fn message(name: &str) -> DescriptorProto {
+        let mut m = DescriptorProto::new();
+        m.set_name(name.to_owned());
+        m
+    }
+
+    fn enum_with_value(name: &str, number: i32) -> EnumDescriptorProto {
+        let mut e = EnumDescriptorProto::new();
+        e.set_name(name.to_owned());
+        let mut v = EnumValueDescriptorProto::new();
+        v.set_name("V".to_owned());
+        v.set_number(number);
+        e.mut_value().push(v);
+        e
+    }
+
+    fn file() -> FileDescriptorProto {
+        let mut f = FileDescriptorProto::new();
+        f.set_name("a.proto".to_owned());
+        f.set_package("p".to_owned());
+
+        let mut outer = message("Outer");
+        outer.mut_field().push(field("good_name2", FieldDescriptorProto_Label::LABEL_OPTIONAL));
+        outer.mut_field().push(field("badName", FieldDescriptorProto_Label::LABEL_OPTIONAL));
+        outer.mut_field().push(field("id", FieldDescriptorProto_Label::LABEL_REQUIRED));
+        let mut weak = field("weak", FieldDescriptorProto_Label::LABEL_OPTIONAL);
+        weak.mut_options().set_weak(true);
+        outer.mut_field().push(weak);
+        let mut lazy = field("lazy", FieldDescriptorProto_Label::LABEL_OPTIONAL);
+        lazy.set_field_type(FieldDescriptorProto_Type::TYPE_MESSAGE);
+        lazy.mut_options().set_lazy(true);
+        outer.mut_field().push(lazy);
+        let mut lazy_repeated = field("lazy_repeated", FieldDescriptorProto_Label::LABEL_REPEATED);
+        lazy_repeated.set_field_type(FieldDescriptorProto_Type::TYPE_MESSAGE);
+        lazy_repeated.mut_options().set_lazy(true);
+        outer.mut_field().push(lazy_repeated);
+        outer.mut_enum_type().push(enum_with_value("Kind", 1));
+        let mut inner = message("Inner");
+        inner.mut_nested_type().push(message("Deep"));
+        outer.mut_nested_type().push(inner);
+        f.mut_message_type().push(outer);
+        f.mut_enum_type().push(enum_with_value("Color", 0));
+        f
+    }
+
+    fn elements(violations: &[Violation], rule: Rule) -> Vec<&str> {
+        violations
+            .iter()
+            .filter(|v| v.rule == rule)
+            .map(|v| &v.element[..])
+            .collect()
+    }
+
+    #[test]
+    fn snake_case() {
+        assert!(is_lower_snake_case("foo"));
+        assert!(is_lower_snake_case("foo_bar2"));
+        assert!(!is_lower_snake_case("fooBar"));
+        assert!(!is_lower_snake_case("foo__bar"));
+        assert!(!is_lower_snake_case("_foo"));
+        assert!(!is_lower_snake_case("foo_"));
+        assert!(!is_lower_snake_case("foo_2"));
+    }
+
+    #[test]
+    fn all_rules() {
+        let violations = Lint::new().max_nesting(2).check(&[file()]);
+        assert_eq!(vec!["p.Outer.badName"], elements(&violations, Rule::FieldNaming));
+        assert_eq!(vec!["p.Outer.Kind"], elements(&violations, Rule::EnumZeroValue));
+        assert_eq!(vec!["p.Outer.id"], elements(&violations, Rule::NoRequiredFields));
+        assert_eq!(vec!["p.Outer.Inner.Deep"], elements(&violations, Rule::MessageNesting));
+        assert_eq!(
+            vec!["p.Outer.weak", "p.Outer.lazy_repeated"],
+            elements(&violations, Rule::IgnoredFieldOptions)
+        );
+        assert_eq!(6, violations.len());
+    }
+
+    #[test]
+    fn allow() {
+        let lint = Lint::new()
+            .allow(Rule::FieldNaming)
+            .allow(Rule::EnumZeroValue)
+            .allow(Rule::NoRequiredFields)
+            .allow(Rule::IgnoredFieldOptions);
+        assert!(lint.run(&[file()]).is_ok());
+
+        let err = lint.max_nesting(1).run(&[file()]).unwrap_err();
+        assert_eq!(2, err.violations.len());
+        assert!(format!("{}", err).contains("a.proto: p.Outer.Inner: "));
+    }
+}
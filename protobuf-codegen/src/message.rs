@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use protobuf::descriptor::*;
 use protobuf::descriptorx::*;
 
@@ -7,8 +9,65 @@ use super::field::*;
 use super::code_writer::*;
 use super::customize::Customize;
 use super::customize::customize_from_rustproto_for_message;
+use super::customize::lite_runtime;
 use oneof::OneofGen;
 use oneof::OneofVariantGen;
+use package_modules::snake_case;
+
+
+/// Methods of traits implemented by messages, which must not be shadowed
+/// by v3-style getters
+static V3_GETTER_RESERVED_NAMES: &'static [&'static str] = &[
+    "new",
+    "reset",
+    "as_any",
+    "as_any_mut",
+    "as_ref",
+    "canonical_hash",
+    "check_initialized",
+    "clear",
+    "clone",
+    "compute_size",
+    "compute_size_with_cache",
+    "default",
+    "default_instance",
+    "descriptor",
+    "descriptor_dyn",
+    "descriptor_static",
+    "eq",
+    "fmt",
+    "get_cached_size",
+    "get_unknown_fields",
+    "into_any",
+    "is_initialized",
+    "merge_from",
+    "merge_from_bytes",
+    "merge_from_message",
+    "merged_from_bytes",
+    "message_descriptor",
+    "mut_unknown_fields",
+    "ne",
+    "parse",
+    "size_hint_upper_bound",
+    "to_bytes",
+    "try_from",
+    "type_descriptor",
+    "type_id",
+    "write_length_delimited_to",
+    "write_length_delimited_to_bytes",
+    "write_length_delimited_to_carllerche_bytes_mut",
+    "write_length_delimited_to_vec",
+    "write_length_delimited_to_writer",
+    "write_to",
+    "write_to_bytes",
+    "write_to_bytes_with_sizes",
+    "write_to_carllerche_bytes_mut",
+    "write_to_vec",
+    "write_to_with_cached_sizes",
+    "write_to_with_size_cache",
+    "write_to_with_sizes",
+    "write_to_writer",
+];
 
 
 /// Message info for codegen
@@ -31,21 +90,24 @@ impl<'a> MessageGen<'a> {
         let mut customize = customize.clone();
         customize.update_with(&customize_from_rustproto_for_message(message.message.get_options()));
 
-        let fields: Vec<_> = message
+        let mut fields: Vec<_> = message
             .fields()
             .into_iter()
             .map(|field| FieldGen::parse(field, root_scope, &customize))
             .collect();
+        if customize.has_bits.unwrap_or(false) {
+            let mut bit = 0;
+            for field in fields.iter_mut().filter(|f| f.can_use_has_bit()) {
+                field.set_has_bit(bit);
+                bit += 1;
+            }
+        }
         MessageGen {
             message: message,
             root_scope: root_scope,
             type_name: message.rust_name(),
             fields: fields,
-            lite_runtime: message
-                .get_file_descriptor()
-                .get_options()
-                .get_optimize_for() ==
-                FileOptions_OptimizeMode::LITE_RUNTIME,
+            lite_runtime: lite_runtime(message.get_file_descriptor(), &customize),
             customize,
         }
     }
@@ -86,6 +148,16 @@ impl<'a> MessageGen<'a> {
             .collect()
     }
 
+    // number of `u32` words in `has_bits` field, zero if field is not generated
+    fn has_bits_words(&self) -> u32 {
+        let bits = self.fields.iter().filter(|f| f.has_bit().is_some()).count() as u32;
+        (bits + 31) / 32
+    }
+
+    fn has_redacted_fields(&self) -> bool {
+        self.fields.iter().any(|f| f.redact)
+    }
+
     fn fields_except_group(&'a self) -> Vec<&'a FieldGen> {
         self.fields
             .iter()
@@ -127,17 +199,29 @@ impl<'a> MessageGen<'a> {
         }
     }
 
+    fn write_write_fields(&self, w: &mut CodeWriter, sizes: SizesStorage) {
+        // To have access to its methods but not polute the name space.
+        for f in self.fields_except_oneof_and_group() {
+            f.write_message_write_field(w, sizes);
+        }
+        self.write_match_each_oneof_variant(w, |w, variant, v, v_type| {
+            variant.field.write_write_element(w, "os", v, v_type, sizes);
+        });
+        w.write_line("os.write_unknown_fields(self.get_unknown_fields())?;");
+        w.write_line("::std::result::Result::Ok(())");
+    }
+
     fn write_write_to_with_cached_sizes(&self, w: &mut CodeWriter) {
         w.def_fn("write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()>", |w| {
-            // To have access to its methods but not polute the name space.
-            for f in self.fields_except_oneof_and_group() {
-                f.write_message_write_field(w);
-            }
-            self.write_match_each_oneof_variant(w, |w, variant, v, v_type| {
-                variant.field.write_write_element(w, "os", v, v_type);
-            });
-            w.write_line("os.write_unknown_fields(self.get_unknown_fields())?;");
-            w.write_line("::std::result::Result::Ok(())");
+            self.write_write_fields(w, SizesStorage::CachedSize);
+        });
+    }
+
+    fn write_write_to_with_size_cache(&self, w: &mut CodeWriter) {
+        // there are unused variables in messages without nested messages
+        w.allow(&["unused_variables"]);
+        w.def_fn("write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()>", |w| {
+            self.write_write_fields(w, SizesStorage::SizeCache);
         });
     }
 
@@ -164,40 +248,189 @@ impl<'a> MessageGen<'a> {
         // there are unused variables in oneof
         w.allow(&["unused_variables"]);
         w.def_fn("compute_size(&self) -> u32", |w| {
-            // To have access to its methods but not polute the name space.
+            self.write_compute_fields_size(w, SizesStorage::CachedSize);
+            w.write_line("self.cached_size.set(my_size);");
+            w.write_line("my_size");
+        });
+    }
+
+    fn write_compute_size_with_cache(&self, w: &mut CodeWriter) {
+        w.comment("Compute sizes of nested messages storing them in `sizes`");
+        w.allow(&["unused_variables"]);
+        w.def_fn("compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32", |w| {
+            w.write_line("let slot = sizes.reserve_slot();");
+            self.write_compute_fields_size(w, SizesStorage::SizeCache);
+            w.write_line("sizes.set_slot(slot, my_size);");
+            w.write_line("my_size");
+        });
+    }
+
+    fn write_compute_fields_size(&self, w: &mut CodeWriter, sizes: SizesStorage) {
+        // To have access to its methods but not polute the name space.
+        w.write_line("let mut my_size = 0;");
+        for field in self.fields_except_oneof_and_group() {
+            field.write_message_compute_field_size("my_size", w, sizes);
+        }
+        self.write_match_each_oneof_variant(w, |w, variant, v, vtype| {
+            variant.field.write_element_size(w, v, vtype, "my_size", sizes);
+        });
+        w.write_line(
+            "my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());",
+        );
+    }
+
+    fn write_size_hint_upper_bound(&self, w: &mut CodeWriter) {
+        w.allow(&["unused_variables"]);
+        w.def_fn("size_hint_upper_bound(&self) -> u64", |w| {
             w.write_line("let mut my_size = 0;");
             for field in self.fields_except_oneof_and_group() {
-                field.write_message_compute_field_size("my_size", w);
+                field.write_message_field_size_upper_bound("my_size", w);
             }
             self.write_match_each_oneof_variant(w, |w, variant, v, vtype| {
-                variant.field.write_element_size(w, v, vtype, "my_size");
+                variant.field.write_element_size_upper_bound(w, v, vtype, "my_size");
             });
             w.write_line(
-                "my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());",
+                "my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;",
             );
-            w.write_line("self.cached_size.set(my_size);");
             w.write_line("my_size");
         });
     }
 
+    fn generate_visitor(&self) -> bool {
+        self.customize.generate_visitor.unwrap_or(false)
+    }
+
+    // visitor traits of this message and all messages of the same file
+    // reachable through message and map fields, `accept` descends into them
+    fn visitor_trait_names(&self) -> Vec<String> {
+        let file = self.message.get_file_descriptor();
+        let subm = self.customize.split_messages.unwrap_or(false);
+        let mut r = vec![format!("{}Visitor", self.type_name)];
+        let mut visited = HashSet::new();
+        visited.insert(self.message.name_to_package());
+        let mut queue = vec![self.message.clone()];
+        while let Some(message) = queue.pop() {
+            for field in message.message.get_field() {
+                if field.get_field_type() != FieldDescriptorProto_Type::TYPE_MESSAGE {
+                    continue;
+                }
+                let mut type_name = field.get_type_name().to_owned();
+                let nested = self.root_scope.find_message(&type_name);
+                if let Some((_, value)) = nested.map_entry() {
+                    if value.field.get_field_type() != FieldDescriptorProto_Type::TYPE_MESSAGE {
+                        continue;
+                    }
+                    type_name = value.field.get_type_name().to_owned();
+                }
+                let nested = self.root_scope.find_message(&type_name);
+                if nested.get_file_descriptor().get_name() != file.get_name() {
+                    continue;
+                }
+                if visited.insert(nested.name_to_package()) {
+                    r.push(format!(
+                        "{}Visitor",
+                        type_name_to_rust_relative(
+                            &type_name,
+                            file,
+                            subm,
+                            self.root_scope,
+                            &self.customize,
+                        )
+                    ));
+                    queue.push(nested);
+                }
+            }
+        }
+        r
+    }
+
+    fn write_visitor_trait(&self, w: &mut CodeWriter) {
+        w.pub_trait(&format!("{}Visitor", self.type_name), |w| {
+            for f in self.fields_except_group() {
+                f.write_visitor_fn(w);
+            }
+        });
+    }
+
+    fn write_accept(&self, w: &mut CodeWriter) {
+        let sig = format!(
+            "accept<V : {}>(&mut self, visitor: &mut V)",
+            self.visitor_trait_names().join(" + ")
+        );
+        w.pub_fn(&sig, |w| {
+            if self.fields_except_group().is_empty() {
+                w.write_line("let _ = visitor;");
+            }
+            for f in self.fields_except_group() {
+                f.write_accept_field(w);
+            }
+        });
+    }
+
+    fn v3_compat(&self) -> bool {
+        self.customize.v3_compat.unwrap_or(false)
+    }
+
+    // names of generated methods v3 getters must not shadow
+    fn method_names(&self) -> HashSet<String> {
+        let mut names: HashSet<String> = V3_GETTER_RESERVED_NAMES
+            .iter()
+            .map(|n| n.to_string())
+            .collect();
+        for f in self.fields_except_group() {
+            for prefix in &["get_", "set_", "has_", "clear_", "mut_", "take_"] {
+                names.insert(format!("{}{}", prefix, f.rust_name));
+            }
+        }
+        names
+    }
+
     fn write_field_accessors(&self, w: &mut CodeWriter) {
+        let method_names = self.method_names();
         for f in self.fields_except_group() {
             w.write_line("");
             let reconstruct_def = f.reconstruct_def();
             w.comment(&(reconstruct_def + ";"));
             w.write_line("");
             f.write_message_single_field_accessors(w);
+
+            if self.v3_compat() && !method_names.contains(&f.rust_name) {
+                w.write_line("");
+                f.write_message_field_get_v3(w);
+            }
         }
     }
 
     fn write_impl_self(&self, w: &mut CodeWriter) {
         w.impl_self_block(&self.type_name, |w| {
+            let bases: Vec<String> = self.fields.iter().map(|f| f.const_name_base()).collect();
+            for (f, base) in self.fields.iter().zip(&bases) {
+                if bases.iter().filter(|b| *b == base).count() > 1 {
+                    // names differ only in case, e. g. `FIELD_FOO_2_NUMBER`
+                    let base = format!("{}_{}", base, f.proto_field.number());
+                    f.write_field_number_consts(w, &base);
+                } else {
+                    f.write_field_number_consts(w, base);
+                }
+            }
+            if !self.fields.is_empty() {
+                w.write_line("");
+            }
+
             // TODO: new should probably be a part of Message trait
             w.pub_fn(&format!("new() -> {}", self.type_name), |w| {
                 w.write_line("::std::default::Default::default()");
             });
 
+            if self.generate_visitor() {
+                w.write_line("");
+                self.write_accept(w);
+            }
+
             self.write_field_accessors(w);
+
+            w.write_line("");
+            w.insertion_point(&format!("message_impl:{}", self.message.full_name()));
         });
     }
 
@@ -232,6 +465,22 @@ impl<'a> MessageGen<'a> {
         });
     }
 
+    fn write_merge_from_message(&self, w: &mut CodeWriter) {
+        w.def_fn("merge_from_message(&mut self, other: &Self)", |w| {
+            for f in self.fields_except_oneof_and_group() {
+                f.write_merge_from_message_field(w);
+            }
+            // like in `merge_from`, oneof message is replaced, not merged
+            for oneof in self.oneofs() {
+                let other_field = format!("other.{}", oneof.name());
+                w.if_stmt(format!("{}.is_some()", other_field), |w| {
+                    w.write_line(&format!("self.{} = {}.clone();", oneof.name(), other_field));
+                });
+            }
+            w.write_line("self.unknown_fields.merge_from(&other.unknown_fields);");
+        });
+    }
+
     fn write_descriptor_field(&self, fields_var: &str, field: &FieldGen, w: &mut CodeWriter) {
         let accessor_fn = field.accessor_fn();
         w.write_line(&format!(
@@ -248,15 +497,49 @@ impl<'a> MessageGen<'a> {
                 }
                 AccessorStyle::HasGet => {
                     w.write_line(&format!("{}::has_{},", self.type_name, field.rust_name));
-                    w.write_line(&format!("{}::get_{},", self.type_name, field.rust_name));
+                    if field.rust_field_type().is_some() {
+                        // accessor takes field value type
+                        w.write_line(&format!(
+                            "|m: &{}| ::protobuf::ProtobufConvert::to_protobuf(&m.get_{}()),",
+                            self.type_name,
+                            field.rust_name
+                        ));
+                    } else {
+                        w.write_line(&format!("{}::get_{},", self.type_name, field.rust_name));
+                    }
                 }
             }
         });
         w.write_line("));");
     }
 
-    fn write_descriptor_static(&self, w: &mut CodeWriter) {
-        w.def_fn(&format!("descriptor_static(_: ::std::option::Option<{}>) -> &'static ::protobuf::reflect::MessageDescriptor", self.type_name), |w| {
+    // accessor returning number of the field which is set
+    fn write_descriptor_oneof(&self, oneofs_var: &str, oneof: &OneofGen, w: &mut CodeWriter) {
+        w.write_line(&format!(
+            "{}.push(::protobuf::reflect::accessor::make_oneof_accessor(",
+            oneofs_var
+        ));
+        w.indented(|w| {
+            w.write_line(&format!("\"{}\",", oneof.proto_name()));
+            let first_line = format!("|m: &{}| match m.{} {{", self.type_name, oneof.name());
+            w.block(&first_line, "},", |w| {
+                for variant in oneof.variants_except_group() {
+                    w.case_expr(
+                        format!("::std::option::Option::Some({}(..))", variant.path()),
+                        format!(
+                            "::std::option::Option::Some({})",
+                            variant.field.proto_field.number()
+                        ),
+                    );
+                }
+                w.case_expr("_", "::std::option::Option::None");
+            });
+        });
+        w.write_line("));");
+    }
+
+    fn write_type_descriptor(&self, w: &mut CodeWriter) {
+        w.def_fn("type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor", |w| {
             w.lazy_static_decl_get("descriptor", "::protobuf::reflect::MessageDescriptor", |w| {
                 let fields = self.fields_except_group();
                 if fields.is_empty() {
@@ -267,11 +550,22 @@ impl<'a> MessageGen<'a> {
                 for field in fields {
                     self.write_descriptor_field("fields", field, w);;
                 }
+                let oneofs = self.oneofs();
+                if !oneofs.is_empty() {
+                    w.write_line("let mut oneofs = ::std::vec::Vec::new();");
+                }
+                for oneof in &oneofs {
+                    self.write_descriptor_oneof("oneofs", oneof, w);
+                }
+                let new_fn = if oneofs.is_empty() { "new" } else { "new_with_oneofs" };
                 w.write_line(&format!(
-                    "::protobuf::reflect::MessageDescriptor::new::<{}>(", self.type_name));
+                    "::protobuf::reflect::MessageDescriptor::{}::<{}>(", new_fn, self.type_name));
                 w.indented(|w| {
                     w.write_line(&format!("\"{}\",", self.type_name));
                     w.write_line("fields,");
+                    if !oneofs.is_empty() {
+                        w.write_line("oneofs,");
+                    }
                     w.write_line("file_descriptor_proto()");
                 });
                 w.write_line(")");
@@ -293,6 +587,20 @@ impl<'a> MessageGen<'a> {
                     continue;
                 }
 
+                if f.is_oneof() {
+                    // checked below with single match per oneof
+                    continue;
+                }
+
+                if f.is_lazy() {
+                    // not decoded here
+                    w.if_stmt(
+                        &format!("!self.{}.is_initialized()", f.rust_name),
+                        |w| { w.write_line("return false;"); },
+                    );
+                    continue;
+                }
+
                 // TODO:
                 // if message is declared in this file and has no message fields,
                 // we could skip the check here
@@ -303,6 +611,30 @@ impl<'a> MessageGen<'a> {
                     );
                 });
             }
+
+            for oneof in self.oneofs() {
+                let variants: Vec<_> = oneof
+                    .variants_except_group()
+                    .into_iter()
+                    .filter(|v| v.field.proto_type == FieldDescriptorProto_Type::TYPE_MESSAGE)
+                    .collect();
+                if variants.is_empty() {
+                    continue;
+                }
+                w.match_block(&format!("self.{}", oneof.name()), |w| {
+                    for variant in &variants {
+                        let pattern =
+                            format!("::std::option::Option::Some({}(ref v))", variant.path());
+                        w.case_block(pattern, |w| {
+                            w.if_stmt(
+                                "!v.is_initialized()",
+                                |w| { w.write_line("return false;"); },
+                            );
+                        });
+                    }
+                    w.case_expr("_", "{}");
+                });
+            }
             w.write_line("true");
         });
     }
@@ -313,12 +645,20 @@ impl<'a> MessageGen<'a> {
             w.write_line("");
             self.write_merge_from(w);
             w.write_line("");
+            self.write_merge_from_message(w);
+            w.write_line("");
             self.write_compute_size(w);
             w.write_line("");
             self.write_write_to_with_cached_sizes(w);
             w.write_line("");
             self.write_get_cached_size(w);
             w.write_line("");
+            self.write_compute_size_with_cache(w);
+            w.write_line("");
+            self.write_write_to_with_size_cache(w);
+            w.write_line("");
+            self.write_size_hint_upper_bound(w);
+            w.write_line("");
             self.write_unknown_fields(w);
             w.write_line("");
             w.def_fn("as_any(&self) -> &::std::any::Any", |w| {
@@ -331,22 +671,36 @@ impl<'a> MessageGen<'a> {
                 w.write_line("self");
             });
             w.write_line("");
-            w.def_fn("descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor", |w| {
-                w.write_line("::protobuf::Message::descriptor_static(None::<Self>)");
+            w.def_fn("descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor>", |w| {
+                if self.lite_runtime {
+                    w.write_line("::std::option::Option::None");
+                } else {
+                    w.write_line("::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())");
+                }
             });
             w.write_line("");
-            w.def_fn(&format!("new() -> {}", self.type_name), |w| {
-                w.write_line(&format!("{}::new()", self.type_name));
-            });
             if !self.lite_runtime {
+                // for callers of deprecated `Message::descriptor_static`
+                w.def_fn(&format!("descriptor_static(_: ::std::option::Option<{}>) -> &'static ::protobuf::reflect::MessageDescriptor", self.type_name), |w| {
+                    w.write_line("<Self as ::protobuf::MessageFull>::type_descriptor()");
+                });
                 w.write_line("");
-                self.write_descriptor_static(w);
             }
+            w.def_fn(&format!("new() -> {}", self.type_name), |w| {
+                w.write_line(&format!("{}::new()", self.type_name));
+            });
             w.write_line("");
             self.write_default_instance(w);
         });
     }
 
+    // not implemented without descriptor, so reflection of such message does not compile
+    fn write_impl_message_full(&self, w: &mut CodeWriter) {
+        w.impl_for_block("::protobuf::MessageFull", &self.type_name, |w| {
+            self.write_type_descriptor(w);
+        });
+    }
+
     fn write_impl_value(&self, w: &mut CodeWriter) {
         w.impl_for_block("::protobuf::reflect::ProtobufValue", &self.type_name, |w| {
             w.def_fn(
@@ -364,25 +718,215 @@ impl<'a> MessageGen<'a> {
         });
     }
 
+    fn write_impl_display(&self, w: &mut CodeWriter) {
+        w.impl_for_block("::std::fmt::Display", &self.type_name, |w| {
+            w.def_fn("fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result", |w| {
+                w.write_line("::protobuf::text_format::fmt(self, f)");
+            });
+        });
+    }
+
+    // `Debug` for lite runtime messages with redacted fields,
+    // same as derived except redacted fields and oneofs
+    fn write_impl_debug_redacted(&self, w: &mut CodeWriter) {
+        w.impl_for_block("::std::fmt::Debug", &self.type_name, |w| {
+            w.def_fn("fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result", |w| {
+                w.write_line(&format!("f.debug_struct({:?})", self.type_name));
+                w.indented(|w| {
+                    let mut fields: Vec<(&str, bool)> = self
+                        .fields_except_oneof_and_group()
+                        .into_iter()
+                        .map(|f| (&f.rust_name[..], f.redact))
+                        .collect();
+                    let oneofs = self.oneofs();
+                    fields.extend(oneofs.iter().map(|o| (o.name(), o.redact())));
+                    for (name, redact) in fields {
+                        if redact {
+                            w.write_line(&format!(
+                                ".field({:?}, &format_args!(\"<redacted>\"))",
+                                name
+                            ));
+                        } else {
+                            w.write_line(&format!(".field({:?}, &self.{})", name, name));
+                        }
+                    }
+                    w.write_line(".field(\"unknown_fields\", &self.unknown_fields)");
+                    w.write_line(".field(\"cached_size\", &self.cached_size)");
+                    w.write_line(".finish()");
+                });
+            });
+        });
+    }
+
     fn write_impl_clear(&self, w: &mut CodeWriter) {
         w.impl_for_block("::protobuf::Clear", &self.type_name, |w| {
             w.def_fn("clear(&mut self)", |w| {
-                // TODO: no need to clear oneof fields in loop
-                for f in self.fields_except_group() {
+                for f in self.fields_except_oneof_and_group() {
                     let clear_field_func = f.clear_field_func();
                     w.write_line(&format!("self.{}();", clear_field_func));
                 }
+                // single assignment instead of clearing each variant
+                for oneof in self.oneofs() {
+                    w.write_line(&format!("self.{} = ::std::option::Option::None;", oneof.name()));
+                }
                 w.write_line("self.unknown_fields.clear();");
             });
         });
     }
 
-    fn write_struct(&self, w: &mut CodeWriter) {
+    // `Bytes` type is referenced only if generated code already depends on `bytes` crate
+    fn carllerche_bytes(&self) -> bool {
+        self.customize.carllerche_bytes_for_bytes.unwrap_or(false) ||
+            self.customize.carllerche_bytes_for_string.unwrap_or(false)
+    }
+
+    fn write_impl_try_from(&self, w: &mut CodeWriter) {
+        let impl_line =
+            format!("impl<'a> ::std::convert::TryFrom<&'a [u8]> for {}", self.type_name);
+        w.expr_block(&impl_line, |w| {
+            w.write_line("type Error = ::protobuf::ProtobufError;");
+            w.write_line("");
+            let sig = format!(
+                "try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<{}>",
+                self.type_name
+            );
+            w.def_fn(&sig, |w| w.write_line("::protobuf::parse_from_bytes(bytes)"));
+        });
+        if self.carllerche_bytes() {
+            w.write_line("");
+            w.impl_for_block("::std::convert::TryFrom<::bytes::Bytes>", &self.type_name, |w| {
+                w.write_line("type Error = ::protobuf::ProtobufError;");
+                w.write_line("");
+                let sig = format!(
+                    "try_from(bytes: ::bytes::Bytes) -> ::protobuf::ProtobufResult<{}>",
+                    self.type_name
+                );
+                w.def_fn(&sig, |w| {
+                    w.write_line("::protobuf::parse_from_carllerche_bytes(&bytes)")
+                });
+            });
+        }
+    }
+
+    fn write_impl_arbitrary(&self, w: &mut CodeWriter) {
+        let fields = self.fields_except_oneof_and_group();
+        let oneofs = self.oneofs();
+        // avoid unused variable warning for messages without fields
+        let u = if fields.is_empty() && oneofs.is_empty() { "_u" } else { "u" };
+        w.impl_for_block("::protobuf::rt::ArbitraryFields", &self.type_name, |w| {
+            let sig = format!(
+                "arbitrary_fields(&mut self, {}: &mut ::protobuf::arbitrary::Unstructured) \
+                 -> ::protobuf::arbitrary::Result<()>",
+                u
+            );
+            w.def_fn(&sig, |w| {
+                for f in &fields {
+                    f.write_arbitrary_field(w);
+                }
+                for oneof in &oneofs {
+                    oneof.write_arbitrary(w);
+                }
+                w.write_line("::std::result::Result::Ok(())");
+            });
+        });
+        w.write_line("");
+        let impl_line =
+            format!("impl<'a> ::protobuf::arbitrary::Arbitrary<'a> for {}", self.type_name);
+        w.expr_block(&impl_line, |w| {
+            w.def_fn(
+                "arbitrary(u: &mut ::protobuf::arbitrary::Unstructured<'a>) \
+                 -> ::protobuf::arbitrary::Result<Self>",
+                |w| w.write_line("::protobuf::rt::arbitrary_message(u)"),
+            );
+        });
+    }
+
+    fn write_impl_proptest(&self, w: &mut CodeWriter) {
+        let fields = self.fields_except_oneof_and_group();
+        let oneofs = self.oneofs();
+        let nested = fields.iter().any(|f| f.proptest_nested()) || oneofs.iter().any(|o| {
+            o.variants_except_group().iter().any(|v| v.field.proptest_nested())
+        });
+        // avoid unused variable warning for messages without message fields
+        let depth = if nested { "depth" } else { "_depth" };
+        let tr = "::protobuf::proptest::arbitrary::Arbitrary";
+        w.impl_for_block(tr, &self.type_name, |w| {
+            w.write_line("type Parameters = ::protobuf::rt::ProptestDepth;");
+            w.write_line(&format!(
+                "type Strategy = ::protobuf::proptest::strategy::BoxedStrategy<{}>;",
+                self.type_name
+            ));
+            w.write_line("");
+            let sig = format!(
+                "arbitrary_with({}: ::protobuf::rt::ProptestDepth) -> Self::Strategy",
+                depth
+            );
+            w.def_fn(&sig, |w| {
+                w.write_line("use ::protobuf::proptest::strategy::Strategy;");
+                let empty = "::protobuf::proptest::strategy::Just(Self::new()).boxed()";
+                if fields.is_empty() && oneofs.is_empty() {
+                    w.write_line(empty);
+                    return;
+                }
+                w.write_line(&format!("let mut s = {};", empty));
+                for f in &fields {
+                    f.write_proptest_field(w);
+                }
+                for oneof in &oneofs {
+                    oneof.write_proptest(w);
+                }
+                w.write_line("s");
+            });
+        });
+        w.write_line("");
+        w.doc_comment(&format!(
+            "Strategy generating `{}` for property-based tests.",
+            self.type_name
+        ));
+        let sig = format!(
+            "arbitrary_{}() -> impl ::protobuf::proptest::strategy::Strategy<Value = {}>",
+            snake_case(&self.type_name),
+            self.type_name
+        );
+        w.pub_fn(&sig, |w| {
+            w.write_line(&format!("::protobuf::proptest::arbitrary::any::<{}>()", self.type_name));
+        });
+    }
+
+    fn write_impl_into_bytes(&self, w: &mut CodeWriter) {
+        let tr = format!("::std::convert::From<{}>", self.type_name);
+        w.impl_for_block(&tr, "::std::vec::Vec<u8>", |w| {
+            let sig = format!("from(m: {}) -> ::std::vec::Vec<u8>", self.type_name);
+            w.def_fn(&sig, |w| w.write_line("::protobuf::Message::to_bytes(&m)"));
+        });
+        w.write_line("");
+        let impl_line = format!(
+            "impl<'a> ::std::convert::From<&'a {}> for ::std::vec::Vec<u8>",
+            self.type_name
+        );
+        w.expr_block(&impl_line, |w| {
+            let sig = format!("from(m: &'a {}) -> ::std::vec::Vec<u8>", self.type_name);
+            w.def_fn(&sig, |w| w.write_line("::protobuf::Message::to_bytes(m)"));
+        });
+    }
+
+    /// Traits in `derive` attribute of generated struct.
+    ///
+    /// Derived `Default` must not allocate (see `Message::new`), so types of fields
+    /// must have non-allocating `Default`, e. g. unknown fields are `Option<Box<_>>`.
+    pub fn derives(&self) -> Vec<&'static str> {
         let mut derive = vec!["PartialEq", "Clone", "Default"];
-        if self.lite_runtime {
+        if self.lite_runtime && !self.has_redacted_fields() {
             derive.push("Debug");
         }
-        w.derive(&derive);
+        derive
+    }
+
+    // Fields are declared in `.proto` order. Struct has default representation,
+    // so rustc reorders fields to minimize padding, and declaration order
+    // does not affect message size.
+    fn write_struct(&self, w: &mut CodeWriter) {
+        w.derive(&self.derives());
         w.pub_struct(&self.type_name, |w| {
             if !self.fields_except_oneof().is_empty() {
                 w.comment("message fields");
@@ -423,10 +967,15 @@ impl<'a> MessageGen<'a> {
                     w.field_decl_vis(vis, oneof.name(), &oneof.full_storage_type().to_string());
                 }
             }
+            if self.has_bits_words() != 0 {
+                w.comment("presence of fields stored without `Option`");
+                w.field_decl("has_bits", &format!("[u32; {}]", self.has_bits_words()));
+            }
             w.comment("special fields");
             // TODO: make public
             w.field_decl("unknown_fields", "::protobuf::UnknownFields");
             w.field_decl("cached_size", "::protobuf::CachedSize");
+            w.insertion_point(&format!("message:{}", self.message.full_name()));
         });
     }
 
@@ -436,20 +985,51 @@ impl<'a> MessageGen<'a> {
         for oneof in self.oneofs() {
             w.write_line("");
             oneof.write_enum(w);
+            oneof.write_from_impls(w);
+        }
+
+        if self.generate_visitor() {
+            w.write_line("");
+            self.write_visitor_trait(w);
         }
 
         w.write_line("");
         self.write_impl_self(w);
         w.write_line("");
         self.write_impl_message(w);
+        if !self.lite_runtime {
+            w.write_line("");
+            self.write_impl_message_full(w);
+        }
         w.write_line("");
         self.write_impl_clear(w);
         if !self.lite_runtime {
             w.write_line("");
             self.write_impl_show(w);
+            if self.customize.generate_display.unwrap_or(false) {
+                w.write_line("");
+                self.write_impl_display(w);
+            }
+        } else if self.has_redacted_fields() {
+            w.write_line("");
+            self.write_impl_debug_redacted(w);
         }
         w.write_line("");
         self.write_impl_value(w);
+        w.write_line("");
+        self.write_impl_try_from(w);
+        if self.customize.generate_into_bytes.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_into_bytes(w);
+        }
+        if self.customize.generate_arbitrary.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_arbitrary(w);
+        }
+        if self.customize.generate_proptest.unwrap_or(false) {
+            w.write_line("");
+            self.write_impl_proptest(w);
+        }
 
         let mut nested_prefix = self.type_name.to_string();
         nested_prefix.push_str("_");
@@ -469,3 +1049,36 @@ impl<'a> MessageGen<'a> {
         }
     }
 }
+
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::Path;
+
+    use super::V3_GETTER_RESERVED_NAMES;
+
+    #[test]
+    fn v3_getter_reserved_names_contain_message_methods() {
+        let core = Path::new(env!("CARGO_MANIFEST_DIR")).join("../protobuf/src/core.rs");
+        let core = fs::read_to_string(core).unwrap();
+        let start = core.find("\npub trait Message:").unwrap();
+        let end = start + core[start..].find("\n}\n").unwrap();
+        let mut methods = 0;
+        for line in core[start..end].lines() {
+            let line = line.trim();
+            if !line.starts_with("fn ") {
+                continue;
+            }
+            let name = &line["fn ".len()..];
+            let name = &name[..name.find(|c| c == '<' || c == '(').unwrap()];
+            assert!(
+                V3_GETTER_RESERVED_NAMES.contains(&name),
+                "Message::{} is not reserved",
+                name
+            );
+            methods += 1;
+        }
+        assert!(methods > 10);
+    }
+}
@@ -6,6 +6,7 @@ use field::FieldGen;
 use field::FieldElem;
 use rust_types_values::RustType;
 use protobuf::descriptorx::OneofWithContext;
+use protobuf::descriptorx::strip_leading_dot;
 use protobuf::descriptor::FieldDescriptorProto;
 use message::MessageGen;
 use Customize;
@@ -25,16 +26,13 @@ pub struct OneofField {
 impl OneofField {
     pub fn parse(
         oneof: &OneofWithContext,
-        _field: &FieldDescriptorProto,
+        field: &FieldDescriptorProto,
         elem: FieldElem,
     ) -> OneofField {
-        // detecting recursion
-        let boxed = if let &FieldElem::Message(ref name, ..) = &elem {
-            if *name == oneof.message.rust_name() {
-                true
-            } else {
-                false
-            }
+        // detecting recursion; proto names are compared, because
+        // rust name of element depends on module it is referenced from
+        let boxed = if let &FieldElem::Message(..) = &elem {
+            strip_leading_dot(field.get_type_name()) == oneof.message.full_name()
         } else {
             false
         };
@@ -127,6 +125,11 @@ impl<'a> OneofGen<'a> {
         }
     }
 
+    // name in `.proto` file
+    pub fn proto_name(&self) -> &str {
+        self.oneof.oneof.get_name()
+    }
+
     pub fn variants_except_group(&'a self) -> Vec<OneofVariantGen<'a>> {
         self.oneof
             .variants()
@@ -147,10 +150,86 @@ impl<'a> OneofGen<'a> {
             .collect()
     }
 
+    // any variant is redacted
+    pub fn redact(&'a self) -> bool {
+        self.variants_except_group().iter().any(|v| v.field.redact)
+    }
+
     pub fn full_storage_type(&self) -> RustType {
         RustType::Option(Box::new(self.type_name.clone()))
     }
 
+    /// Part of `ArbitraryFields` implementation setting one of variants or none.
+    pub fn write_arbitrary(&'a self, w: &mut CodeWriter) {
+        let variants = self.variants_except_group();
+        if variants.is_empty() {
+            return;
+        }
+        // zero (also returned when data is exhausted) leaves oneof unset
+        w.match_block(&format!("u.int_in_range(0..={})?", variants.len()), |w| {
+            for (i, variant) in variants.iter().enumerate() {
+                let elem = variant.field.elem();
+                let value = elem.rust_storage_type()
+                    .into_target(&variant.rust_type(), &elem.arbitrary_value());
+                w.case_expr(
+                    &(i + 1).to_string(),
+                    &format!(
+                        "self.{} = ::std::option::Option::Some({}({}))",
+                        self.name(),
+                        variant.path(),
+                        value
+                    ),
+                );
+            }
+            w.case_expr("_", "{}");
+        });
+    }
+
+    /// Part of proptest strategy of message in `s` variable setting one of variants or none.
+    pub fn write_proptest(&'a self, w: &mut CodeWriter) {
+        let variants = self.variants_except_group();
+        if variants.is_empty() {
+            return;
+        }
+        // variants are generated as messages with only this oneof set,
+        // because oneof enum does not implement `Debug` required by strategies
+        w.write_line(
+            "let mut variants = vec![::protobuf::proptest::strategy::Just(Self::new()).boxed()];",
+        );
+        for variant in &variants {
+            let elem = variant.field.elem();
+            let write_push = |w: &mut CodeWriter, depth: &str| {
+                let value = elem.rust_storage_type().into_target(&variant.rust_type(), "v");
+                w.block(
+                    &format!("variants.push({}.prop_map(|v| {{", elem.proptest_strategy(depth)),
+                    "}).boxed());",
+                    |w| {
+                        w.write_line("let mut m = Self::new();");
+                        w.write_line(&format!(
+                            "m.{} = ::std::option::Option::Some({}({}));",
+                            self.name(),
+                            variant.path(),
+                            value
+                        ));
+                        w.write_line("m");
+                    },
+                );
+            };
+            if elem.is_message() {
+                w.if_let_stmt("::std::option::Option::Some(depth)", "depth.nested()", |w| {
+                    write_push(w, "depth");
+                });
+            } else {
+                write_push(w, "depth");
+            }
+        }
+        let strategy = "(s, ::protobuf::proptest::strategy::Union::new(variants))";
+        w.block(&format!("s = {}.prop_map(|(mut m, v)| {{", strategy), "}).boxed();", |w| {
+            w.write_line(&format!("m.{} = v.{};", self.name(), self.name()));
+            w.write_line("m");
+        });
+    }
+
     pub fn write_enum(&self, w: &mut CodeWriter) {
         let mut derive = vec!["Clone", "PartialEq"];
         if self.lite_runtime {
@@ -167,4 +246,30 @@ impl<'a> OneofGen<'a> {
             }
         });
     }
+
+    // `From` is implemented for variant types, except types of several variants
+    pub fn write_from_impls(&self, w: &mut CodeWriter) {
+        let variants = self.variants_except_group();
+        let types: Vec<String> = variants
+            .iter()
+            .map(|v| v.oneof_field.elem.rust_storage_type().to_string())
+            .collect();
+        for (variant, t) in variants.iter().zip(&types) {
+            if types.iter().filter(|u| *u == t).count() != 1 {
+                continue;
+            }
+            w.write_line("");
+            let tr = format!("::std::convert::From<{}>", t);
+            w.impl_for_block(&tr, &self.type_name.to_string(), |w| {
+                w.def_fn(&format!("from(v: {}) -> {}", t, self.type_name), |w| {
+                    let v = if variant.oneof_field.boxed {
+                        "::std::boxed::Box::new(v)"
+                    } else {
+                        "v"
+                    };
+                    w.write_line(&format!("{}({})", variant.path(), v));
+                });
+            });
+        }
+    }
 }
@@ -0,0 +1,309 @@
+//! Layout of generated code where proto packages are mapped to nested Rust modules.
+//!
+//! File `foo.proto` with `package a.b;` is generated into `a/b/foo.rs`,
+//! and `mod.rs` files are generated for each directory, so types
+//! are accessible as `a::b::TypeName` after `mod` of the output directory.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptorx::*;
+use protobuf::compiler_plugin;
+use protobuf::rust::is_rust_keyword;
+
+use code_writer::CodeWriter;
+
+
+/// Rust module names for proto package
+pub fn package_to_rust_mods(package: &str) -> Vec<String> {
+    if package.is_empty() {
+        return Vec::new();
+    }
+    package.split('.').map(proto_path_to_rust_mod).collect()
+}
+
+/// Path from output directory root module to file module
+pub fn file_rust_mod_path(file: &FileDescriptorProto) -> Vec<String> {
+    let mut r = package_to_rust_mods(file.get_package());
+    r.push(proto_path_to_rust_mod(file.get_name()));
+    r
+}
+
+/// Name of generated file relative to output directory
+pub fn file_output_name(file: &FileDescriptorProto) -> String {
+    format!("{}.rs", file_rust_mod_path(file).join("/"))
+}
+
+/// `super::` prefix to get from file module (or its submodule when `subm`)
+/// to output directory root module
+pub fn path_to_root(file: &FileDescriptorProto, subm: bool) -> String {
+    let mut depth = file_rust_mod_path(file).len();
+    if subm {
+        depth += 1;
+    }
+    let mut r = String::new();
+    for _ in 0..depth {
+        r.push_str("super::");
+    }
+    r
+}
+
+#[derive(Default)]
+struct ModTree {
+    // module name -> files are in module
+    submods: BTreeMap<String, ModTree>,
+    files: BTreeSet<String>,
+}
+
+impl ModTree {
+    fn add(&mut self, package: &[String], file_mod: String) {
+        match package.split_first() {
+            None => {
+                self.files.insert(file_mod);
+            }
+            Some((first, rem)) => {
+                self.submods
+                    .entry(first.clone())
+                    .or_insert_with(Default::default)
+                    .add(rem, file_mod);
+            }
+        }
+    }
+
+    fn write_mod_rs(&self, dir: &str, results: &mut Vec<compiler_plugin::GenResult>) {
+        for file in &self.files {
+            if self.submods.contains_key(file) {
+                panic!(
+                    "file module {:?} conflicts with package module in {:?}",
+                    file,
+                    dir
+                );
+            }
+        }
+
+        let mut v = Vec::new();
+        {
+            let mut w = CodeWriter::new(&mut v);
+            w.write_generated_by("rust-protobuf", env!("CARGO_PKG_VERSION"));
+            w.write_line("");
+            for submod in self.submods.keys() {
+                w.write_line(&format!("pub mod {};", submod));
+            }
+            for file in &self.files {
+                w.write_line(&format!("pub mod {};", file));
+            }
+            if !self.files.is_empty() {
+                w.write_line("");
+                for file in &self.files {
+                    w.write_line(&format!("pub use self::{}::*;", file));
+                }
+            }
+        }
+
+        let name = if dir.is_empty() {
+            "mod.rs".to_owned()
+        } else {
+            format!("{}/mod.rs", dir)
+        };
+        results.push(compiler_plugin::GenResult {
+            name: name,
+            content: v,
+        });
+
+        for (name, submod) in &self.submods {
+            let subdir = if dir.is_empty() {
+                name.clone()
+            } else {
+                format!("{}/{}", dir, name)
+            };
+            submod.write_mod_rs(&subdir, results);
+        }
+    }
+}
+
+/// Generate `mod.rs` files for all directories of generated files
+pub fn gen_mod_rs(files: &[&FileDescriptorProto]) -> Vec<compiler_plugin::GenResult> {
+    let mut tree = ModTree::default();
+    for file in files {
+        tree.add(
+            &package_to_rust_mods(file.get_package()),
+            proto_path_to_rust_mod(file.get_name()),
+        );
+    }
+
+    let mut results = Vec::new();
+    tree.write_mod_rs("", &mut results);
+    results
+}
+
+/// `FooBar` -> `foo_bar`
+pub fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut r = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev_lower = i > 0 && (chars[i - 1].is_lowercase() || chars[i - 1].is_digit(10));
+            let next_lower = i > 0 && chars[i - 1].is_uppercase() &&
+                chars.get(i + 1).map_or(false, |c| c.is_lowercase());
+            if prev_lower || next_lower {
+                r.push('_');
+            }
+            r.extend(c.to_lowercase());
+        } else {
+            r.push(c);
+        }
+    }
+    r
+}
+
+/// Name of module for message `FooBar` is `foo_bar`
+pub fn nested_mod_name(message: &MessageWithScope) -> String {
+    let name = snake_case(message.get_name());
+    if is_rust_keyword(&name) {
+        format!("{}_pb", name)
+    } else {
+        name
+    }
+}
+
+fn nested_type_name<'a, W : WithScope<'a>>(t: &W) -> String {
+    if is_rust_keyword(t.get_name()) {
+        format!("{}{}", t.escape_prefix(), t.get_name())
+    } else {
+        t.get_name().to_owned()
+    }
+}
+
+/// Write aliases to types nested in message, where `depth` is the number
+/// of modules between the message type and the aliases.
+pub fn write_nested_aliases(message: &MessageWithScope, depth: usize, w: &mut CodeWriter) {
+    let mut supers = String::new();
+    for _ in 0..depth {
+        supers.push_str("super::");
+    }
+    if depth == 0 {
+        supers.push_str("self::");
+    }
+
+    let scope = message.to_scope();
+    let nested_messages: Vec<_> = scope
+        .get_messages()
+        .into_iter()
+        .filter(|m| m.map_entry().is_none())
+        .collect();
+
+    for nested in &nested_messages {
+        w.write_line(&format!(
+            "pub use {}{} as {};",
+            supers,
+            nested.rust_name(),
+            nested_type_name(nested)
+        ));
+    }
+    for nested in &scope.get_enums() {
+        w.write_line(&format!(
+            "pub use {}{} as {};",
+            supers,
+            nested.rust_name(),
+            nested_type_name(nested)
+        ));
+    }
+
+    for nested in &nested_messages {
+        if has_nested_types(nested) {
+            w.write_line("");
+            w.pub_mod(&nested_mod_name(nested), |w| {
+                write_nested_aliases(nested, depth + 1, w);
+            });
+        }
+    }
+}
+
+pub fn has_nested_types(message: &MessageWithScope) -> bool {
+    let scope = message.to_scope();
+    !scope.get_enums().is_empty() ||
+        scope.get_messages().iter().any(|m| m.map_entry().is_none())
+}
+
+/// For each top-level message with nested types write a module
+/// with aliases to nested types, so `Outer_Inner` is also accessible
+/// as `outer::Inner`.
+///
+/// Module is not generated if its name conflicts with other type in the file.
+pub fn write_nested_type_modules(file: &FileDescriptorProto, w: &mut CodeWriter) {
+    let scope = FileScope { file_descriptor: file }.to_scope();
+
+    let mut taken: HashSet<String> = HashSet::new();
+    taken.insert("exts".to_owned());
+    for m in scope.get_messages() {
+        taken.insert(m.rust_name());
+    }
+    for e in scope.get_enums() {
+        taken.insert(e.rust_name());
+    }
+
+    for message in &scope.get_messages() {
+        if message.map_entry().is_some() || !has_nested_types(message) {
+            continue;
+        }
+        let mod_name = nested_mod_name(message);
+        if !taken.insert(mod_name.clone()) {
+            continue;
+        }
+
+        w.write_line("");
+        w.pub_mod(&mod_name, |w| {
+            write_nested_aliases(message, 1, w);
+        });
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn file(name: &str, package: &str) -> FileDescriptorProto {
+        let mut file = FileDescriptorProto::new();
+        file.set_name(name.to_owned());
+        file.set_package(package.to_owned());
+        file
+    }
+
+    #[test]
+    fn output_name() {
+        assert_eq!("a/b/foo.rs", file_output_name(&file("x/foo.proto", "a.b")));
+        assert_eq!("foo.rs", file_output_name(&file("foo.proto", "")));
+        assert_eq!("a/type_pb/foo.rs", file_output_name(&file("foo.proto", "a.type")));
+    }
+
+    #[test]
+    fn snake() {
+        assert_eq!("foo_bar", snake_case("FooBar"));
+        assert_eq!("foo_bar", snake_case("foo_bar"));
+        assert_eq!("http_request", snake_case("HTTPRequest"));
+        assert_eq!("foo2_bar", snake_case("Foo2Bar"));
+    }
+
+    #[test]
+    fn root_path() {
+        assert_eq!("super::super::", path_to_root(&file("foo.proto", "a"), false));
+        assert_eq!("super::super::super::", path_to_root(&file("foo.proto", "a"), true));
+    }
+
+    #[test]
+    fn mod_rs() {
+        let a = file("a.proto", "p.q");
+        let b = file("b.proto", "p");
+        let c = file("c.proto", "");
+        let results = gen_mod_rs(&[&a, &b, &c]);
+        let names: Vec<_> = results.iter().map(|r| &r.name[..]).collect();
+        assert_eq!(vec!["mod.rs", "p/mod.rs", "p/q/mod.rs"], names);
+
+        let p = String::from_utf8(results[1].content.clone()).unwrap();
+        assert!(p.contains("pub mod q;\npub mod b;\n"));
+        assert!(p.contains("pub use self::b::*;\n"));
+    }
+}
@@ -0,0 +1,303 @@
+//! Machine-readable summary of generated code.
+//!
+//! With `gen_report` option, for `foo.proto` file `foo.report.json`
+//! is written next to `foo.rs`:
+//!
+//! ```json
+//! {
+//!   "file": "foo.proto",
+//!   "messages": [
+//!     {
+//!       "name": "pkg.Outer",
+//!       "rust_name": "Outer",
+//!       "fields": 3,
+//!       "repeated_fields": 1,
+//!       "map_fields": 1,
+//!       "oneofs": 0,
+//!       "lite_runtime": false,
+//!       "derives": ["PartialEq", "Clone", "Default"],
+//!       "options": ["expose_fields"]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! Messages (including nested, excluding map entries) are listed in order of definition.
+//! `fields` is total number of fields including repeated, map and oneof fields,
+//! `options` are codegen options enabled for the message.
+
+use protobuf::compiler_plugin;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptorx::*;
+
+use customize::customize_from_rustproto_for_message;
+use customize::Customize;
+use field::FieldKind;
+use message::MessageGen;
+
+
+struct MessageReport {
+    name: String,
+    rust_name: String,
+    fields: usize,
+    repeated_fields: usize,
+    map_fields: usize,
+    oneofs: usize,
+    lite_runtime: bool,
+    derives: Vec<&'static str>,
+    options: Vec<&'static str>,
+}
+
+// names of boolean options set to true
+fn enabled_options(customize: &Customize) -> Vec<&'static str> {
+    let options = [
+        ("expose_oneof", customize.expose_oneof),
+        ("expose_fields", customize.expose_fields),
+        ("generate_accessors", customize.generate_accessors),
+        ("carllerche_bytes_for_bytes", customize.carllerche_bytes_for_bytes),
+        ("carllerche_bytes_for_string", customize.carllerche_bytes_for_string),
+        ("v3_compat", customize.v3_compat),
+        ("split_messages", customize.split_messages),
+        ("lazy_field", customize.lazy_field),
+        ("option_box_for_messages", customize.option_box_for_messages),
+        ("repeated_field_vec", customize.repeated_field_vec),
+        ("ref_accessors_only", customize.ref_accessors_only),
+        ("json_names", customize.json_names),
+        ("generate_display", customize.generate_display),
+        ("has_bits", customize.has_bits),
+        ("generate_visitor", customize.generate_visitor),
+        ("generate_into_bytes", customize.generate_into_bytes),
+        ("generate_arbitrary", customize.generate_arbitrary),
+        ("generate_proptest", customize.generate_proptest),
+    ];
+    options.iter().filter(|&&(_, v)| v == Some(true)).map(|&(name, _)| name).collect()
+}
+
+fn collect_messages(
+    scope: &Scope,
+    root_scope: &RootScope,
+    customize: &Customize,
+    r: &mut Vec<MessageReport>,
+) {
+    for message in scope.get_messages() {
+        // map entries are not generated
+        if message.map_entry().is_some() {
+            continue;
+        }
+
+        let gen = MessageGen::new(&message, root_scope, customize);
+
+        let mut message_customize = customize.clone();
+        message_customize.update_with(
+            &customize_from_rustproto_for_message(message.message.get_options()),
+        );
+
+        let package = message.get_file_descriptor().get_package();
+        let name = if package.is_empty() {
+            message.name_to_package()
+        } else {
+            format!("{}.{}", package, message.name_to_package())
+        };
+
+        r.push(MessageReport {
+            name: name,
+            rust_name: message.rust_name(),
+            fields: gen.fields.len(),
+            repeated_fields: gen.fields.iter().filter(|f| match f.kind {
+                FieldKind::Repeated(..) => true,
+                _ => false,
+            }).count(),
+            map_fields: gen.fields.iter().filter(|f| match f.kind {
+                FieldKind::Map(..) => true,
+                _ => false,
+            }).count(),
+            oneofs: message.message.get_oneof_decl().len(),
+            lite_runtime: gen.lite_runtime,
+            derives: gen.derives(),
+            options: enabled_options(&message_customize),
+        });
+
+        collect_messages(&message.to_scope(), root_scope, &message_customize, r);
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut r = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => r.push_str("\\\""),
+            '\\' => r.push_str("\\\\"),
+            c if (c as u32) < 0x20 => r.push_str(&format!("\\u{:04x}", c as u32)),
+            c => r.push(c),
+        }
+    }
+    r.push('"');
+    r
+}
+
+fn json_string_list(list: &[&str]) -> String {
+    let items: Vec<String> = list.iter().map(|s| json_string(s)).collect();
+    format!("[{}]", items.join(", "))
+}
+
+fn report_json(file: &FileDescriptorProto, messages: &[MessageReport]) -> String {
+    let mut r = String::new();
+    r.push_str("{\n");
+    r.push_str(&format!("  \"file\": {},\n", json_string(file.get_name())));
+    if messages.is_empty() {
+        r.push_str("  \"messages\": []\n");
+    } else {
+        r.push_str("  \"messages\": [\n");
+        for (i, m) in messages.iter().enumerate() {
+            r.push_str("    {\n");
+            r.push_str(&format!("      \"name\": {},\n", json_string(&m.name)));
+            r.push_str(&format!("      \"rust_name\": {},\n", json_string(&m.rust_name)));
+            r.push_str(&format!("      \"fields\": {},\n", m.fields));
+            r.push_str(&format!("      \"repeated_fields\": {},\n", m.repeated_fields));
+            r.push_str(&format!("      \"map_fields\": {},\n", m.map_fields));
+            r.push_str(&format!("      \"oneofs\": {},\n", m.oneofs));
+            r.push_str(&format!("      \"lite_runtime\": {},\n", m.lite_runtime));
+            r.push_str(&format!("      \"derives\": {},\n", json_string_list(&m.derives)));
+            r.push_str(&format!("      \"options\": {}\n", json_string_list(&m.options)));
+            r.push_str(if i + 1 == messages.len() { "    }\n" } else { "    },\n" });
+        }
+        r.push_str("  ]\n");
+    }
+    r.push_str("}\n");
+    r
+}
+
+/// Report for file generated into file `rs_name`.
+///
+/// `customize` must already include file options.
+pub fn gen_report(
+    file: &FileDescriptorProto,
+    rs_name: &str,
+    root_scope: &RootScope,
+    customize: &Customize,
+) -> compiler_plugin::GenResult {
+    let mut messages = Vec::new();
+    let scope = FileScope { file_descriptor: file }.to_scope();
+    collect_messages(&scope, root_scope, customize, &mut messages);
+
+    let base = if rs_name.ends_with(".rs") { &rs_name[..rs_name.len() - 3] } else { rs_name };
+    compiler_plugin::GenResult {
+        name: format!("{}.report.json", base),
+        content: report_json(file, &messages).into_bytes(),
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use protobuf::descriptor::DescriptorProto;
+    use protobuf::descriptor::FieldDescriptorProto;
+    use protobuf::descriptor::FieldDescriptorProto_Label;
+    use protobuf::descriptor::FieldDescriptorProto_Type;
+    use protobuf::descriptor::MessageOptions;
+    use protobuf::descriptor::OneofDescriptorProto;
+
+    fn field(
+        name: &str,
+        number: i32,
+        label: FieldDescriptorProto_Label,
+        field_type: FieldDescriptorProto_Type,
+        type_name: &str,
+    ) -> FieldDescriptorProto {
+        let mut f = FieldDescriptorProto::new();
+        f.set_name(name.to_owned());
+        f.set_number(number);
+        f.set_label(label);
+        f.set_field_type(field_type);
+        if !type_name.is_empty() {
+            f.set_type_name(type_name.to_owned());
+        }
+        f
+    }
+
+    fn file() -> FileDescriptorProto {
+        use protobuf::descriptor::FieldDescriptorProto_Label::*;
+        use protobuf::descriptor::FieldDescriptorProto_Type::*;
+
+        let mut entry = DescriptorProto::new();
+        entry.set_name("TagsEntry".to_owned());
+        entry.mut_field().push(field("key", 1, LABEL_OPTIONAL, TYPE_STRING, ""));
+        entry.mut_field().push(field("value", 2, LABEL_OPTIONAL, TYPE_INT32, ""));
+        let mut entry_options = MessageOptions::new();
+        entry_options.set_map_entry(true);
+        entry.set_options(entry_options);
+
+        let mut inner = DescriptorProto::new();
+        inner.set_name("Inner".to_owned());
+        inner.mut_field().push(field("a", 1, LABEL_OPTIONAL, TYPE_INT32, ""));
+        inner.mut_field().push(field("b", 2, LABEL_OPTIONAL, TYPE_INT32, ""));
+        inner.mut_field()[0].set_oneof_index(0);
+        inner.mut_field()[1].set_oneof_index(0);
+        let mut oneof = OneofDescriptorProto::new();
+        oneof.set_name("value".to_owned());
+        inner.mut_oneof_decl().push(oneof);
+
+        let mut outer = DescriptorProto::new();
+        outer.set_name("Outer".to_owned());
+        outer.mut_field().push(field("id", 1, LABEL_OPTIONAL, TYPE_STRING, ""));
+        outer.mut_field().push(field("ids", 2, LABEL_REPEATED, TYPE_INT64, ""));
+        outer.mut_field().push(
+            field("tags", 3, LABEL_REPEATED, TYPE_MESSAGE, ".p.Outer.TagsEntry"),
+        );
+        outer.mut_nested_type().push(entry);
+        outer.mut_nested_type().push(inner);
+
+        let mut f = FileDescriptorProto::new();
+        f.set_name("dir/a.proto".to_owned());
+        f.set_package("p".to_owned());
+        f.mut_message_type().push(outer);
+        f
+    }
+
+    #[test]
+    fn report() {
+        let file = file();
+        let files = [file.clone()];
+        let root_scope = RootScope { file_descriptors: &files };
+        let mut customize = Customize::default();
+        customize.expose_fields = Some(true);
+        let report = gen_report(&file, "dir/a.rs", &root_scope, &customize);
+        assert_eq!("dir/a.report.json", report.name);
+
+        let expected = r#"{
+  "file": "dir/a.proto",
+  "messages": [
+    {
+      "name": "p.Outer",
+      "rust_name": "Outer",
+      "fields": 3,
+      "repeated_fields": 1,
+      "map_fields": 1,
+      "oneofs": 0,
+      "lite_runtime": false,
+      "derives": ["PartialEq", "Clone", "Default"],
+      "options": ["expose_fields"]
+    },
+    {
+      "name": "p.Outer.Inner",
+      "rust_name": "Outer_Inner",
+      "fields": 2,
+      "repeated_fields": 0,
+      "map_fields": 0,
+      "oneofs": 1,
+      "lite_runtime": false,
+      "derives": ["PartialEq", "Clone", "Default"],
+      "options": ["expose_fields"]
+    }
+  ]
+}
+"#;
+        assert_eq!(expected, String::from_utf8(report.content).unwrap());
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(r#""a\"b\\c\u000a""#, json_string("a\"b\\c\n"));
+    }
+}
@@ -4,6 +4,8 @@ use std::cmp;
 use protobuf::descriptor::*;
 use protobuf::descriptorx::*;
 use super::well_known_types::is_well_known_type_full;
+use super::customize::Customize;
+use super::package_modules;
 
 
 // Represent subset of rust types used in generated code
@@ -24,7 +26,12 @@ pub enum RustType {
     Option(Box<RustType>),
     SingularField(Box<RustType>),
     SingularPtrField(Box<RustType>),
+    LazyMessageField(Box<RustType>),
+    // Option<Box<T>>
+    OptionBox(Box<RustType>),
     RepeatedField(Box<RustType>),
+    // SmallVec<[T; N]>
+    SmallVec(Box<RustType>, u32),
     // Box<T>
     Uniq(Box<RustType>),
     // &T
@@ -65,9 +72,18 @@ impl fmt::Display for RustType {
             RustType::SingularPtrField(ref param) => {
                 write!(f, "::protobuf::SingularPtrField<{}>", **param)
             }
+            RustType::LazyMessageField(ref param) => {
+                write!(f, "::protobuf::LazyMessageField<{}>", **param)
+            }
+            RustType::OptionBox(ref param) => {
+                write!(f, "::std::option::Option<::std::boxed::Box<{}>>", **param)
+            }
             RustType::RepeatedField(ref param) => {
                 write!(f, "::protobuf::RepeatedField<{}>", **param)
             }
+            RustType::SmallVec(ref param, size) => {
+                write!(f, "::protobuf::SmallVec<[{}; {}]>", **param, size)
+            }
             RustType::Uniq(ref param) => write!(f, "::std::boxed::Box<{}>", **param),
             RustType::Ref(ref param) => write!(f, "&{}", **param),
             RustType::Message(ref name) |
@@ -167,10 +183,13 @@ impl RustType {
             RustType::String => "::std::string::String::new()".to_string(),
             RustType::Bytes => "::bytes::Bytes::new()".to_string(),
             RustType::Chars => "::protobuf::Chars::new()".to_string(),
-            RustType::Option(..) => "::std::option::Option::None".to_string(),
+            RustType::Option(..) |
+            RustType::OptionBox(..) => "::std::option::Option::None".to_string(),
             RustType::SingularField(..) => "::protobuf::SingularField::none()".to_string(),
             RustType::SingularPtrField(..) => "::protobuf::SingularPtrField::none()".to_string(),
+            RustType::LazyMessageField(..) => "::protobuf::LazyMessageField::none()".to_string(),
             RustType::RepeatedField(..) => "::protobuf::RepeatedField::new()".to_string(),
+            RustType::SmallVec(..) => "::protobuf::SmallVec::new()".to_string(),
             RustType::Message(ref name) => format!("{}::new()", name),
             RustType::Ref(ref m) if m.is_message() => {
                 match **m {
@@ -194,13 +213,16 @@ impl RustType {
     /// Emit a code to clear a variable `v`
     pub fn clear(&self, v: &str) -> String {
         match *self {
-            RustType::Option(..) => format!("{} = ::std::option::Option::None", v),
+            RustType::Option(..) |
+            RustType::OptionBox(..) => format!("{} = ::std::option::Option::None", v),
             RustType::Vec(..) |
             RustType::Bytes |
             RustType::String |
             RustType::RepeatedField(..) |
+            RustType::SmallVec(..) |
             RustType::SingularField(..) |
             RustType::SingularPtrField(..) |
+            RustType::LazyMessageField(..) |
             RustType::HashMap(..) => format!("{}.clear()", v),
             RustType::Chars => format!("::protobuf::Clear::clear(&mut {})", v),
             RustType::Bool |
@@ -219,6 +241,12 @@ impl RustType {
             RustType::SingularPtrField(..) => {
                 format!("::protobuf::SingularPtrField::some({})", value)
             }
+            RustType::LazyMessageField(..) => {
+                format!("::protobuf::LazyMessageField::some({})", value)
+            }
+            RustType::OptionBox(..) => {
+                format!("::std::option::Option::Some(::std::boxed::Box::new({}))", value)
+            }
             _ => panic!("not a wrapper type: {}", *self),
         }
     }
@@ -261,6 +289,11 @@ impl RustType {
                        RustType::Slice(ref y) => x == y,
                        _ => false,
                    } => return Ok(format!("&{}", v)),
+            (&RustType::SmallVec(ref x, _), &RustType::Ref(ref t))
+                if match **t {
+                       RustType::Slice(ref y) => x == y,
+                       _ => false,
+                   } => return Ok(format!("&{}", v)),
             (&RustType::Bytes, &RustType::Ref(ref t))
                 if match **t {
                        RustType::Slice(ref y) => **y == RustType::u8(),
@@ -293,7 +326,8 @@ impl RustType {
             &RustType::String |
             &RustType::Chars => RustType::Str,
             &RustType::Vec(ref p) |
-            &RustType::RepeatedField(ref p) => RustType::Slice(p.clone()),
+            &RustType::RepeatedField(ref p) |
+            &RustType::SmallVec(ref p, _) => RustType::Slice(p.clone()),
             &RustType::Bytes => RustType::Slice(Box::new(RustType::u8())),
             &RustType::Message(ref p) => RustType::Message(p.clone()),
             x => panic!("no ref type for {}", x),
@@ -313,8 +347,10 @@ impl RustType {
             &RustType::Vec(ref ty) |
             &RustType::Option(ref ty) |
             &RustType::RepeatedField(ref ty) |
+            &RustType::SmallVec(ref ty, _) |
             &RustType::SingularField(ref ty) |
             &RustType::SingularPtrField(ref ty) => RustType::Ref(ty.clone()),
+            &RustType::OptionBox(ref ty) => RustType::Ref(Box::new(RustType::Uniq(ty.clone()))),
             x => panic!("cannot iterate {}", x),
         }
     }
@@ -425,6 +461,7 @@ pub fn type_name_to_rust_relative(
     file: &FileDescriptorProto,
     subm: bool,
     root_scope: &RootScope,
+    customize: &Customize,
 ) -> String {
     let message_or_enum = root_scope.find_message_or_enum(type_name);
     if message_or_enum.get_scope().get_file_descriptor().get_name() == file.get_name() {
@@ -441,6 +478,13 @@ pub fn type_name_to_rust_relative(
     } else if is_descriptor_proto(message_or_enum.get_file_descriptor()) {
         // Messages defined in descriptor.proto
         format!("::protobuf::descriptor::{}", message_or_enum.name_to_package())
+    } else if customize.package_modules.unwrap_or(false) {
+        format!(
+            "{}{}::{}",
+            package_modules::path_to_root(file, subm),
+            package_modules::file_rust_mod_path(message_or_enum.get_file_descriptor()).join("::"),
+            message_or_enum.rust_name()
+        )
     } else {
         if subm {
             format!("super::super::{}", message_or_enum.rust_fq_name())
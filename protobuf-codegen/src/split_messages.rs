@@ -0,0 +1,154 @@
+//! Layout of generated code where each top-level message (with its nested types)
+//! is generated into its own file.
+//!
+//! For `foo.proto` with messages `Outer` and `Other`, `foo.rs` contains
+//! enums, extensions and file descriptor, and declares modules `outer` and `other`
+//! generated into `foo/outer.rs` and `foo/other.rs`. Message types are
+//! reexported from `foo.rs`, so generated code is used the same way as
+//! when it is generated into single file.
+//!
+//! `foo.rs` must be declared as module (`mod foo;`, possibly with `#[path]`):
+//! when it is included with `include!`, `pub mod outer;` is resolved relative
+//! to the file containing `include!`, not to `foo.rs`.
+
+use std::collections::HashSet;
+
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptorx::*;
+use protobuf::compiler_plugin;
+
+use code_writer::CodeWriter;
+use customize::Customize;
+use message::MessageGen;
+use package_modules;
+
+
+/// Top-level messages with names of modules they are generated into
+pub fn message_mods<'a>(file: &'a FileDescriptorProto) -> Vec<(MessageWithScope<'a>, String)> {
+    let scope = FileScope { file_descriptor: file }.to_scope();
+
+    let mut taken: HashSet<String> = HashSet::new();
+    taken.insert("exts".to_owned());
+    taken.insert("json_names".to_owned());
+    for m in scope.get_messages() {
+        taken.insert(m.rust_name());
+    }
+    for e in scope.get_enums() {
+        taken.insert(e.rust_name());
+    }
+
+    let mut r = Vec::new();
+    for message in scope.get_messages() {
+        // ignore map entries, because they are not used in map fields
+        if message.map_entry().is_some() {
+            continue;
+        }
+        let mut mod_name = package_modules::nested_mod_name(&message);
+        while !taken.insert(mod_name.clone()) {
+            mod_name.push_str("_pb");
+        }
+        r.push((message, mod_name));
+    }
+    r
+}
+
+// rust names of all types generated for the message
+fn message_type_names(message: &MessageWithScope, visitor: bool, r: &mut Vec<String>) {
+    r.push(message.rust_name());
+    if visitor {
+        r.push(format!("{}Visitor", message.rust_name()));
+    }
+    for oneof in message.oneofs() {
+        r.push(oneof.rust_name());
+    }
+    let scope = message.to_scope();
+    for nested in &scope.get_messages() {
+        if nested.map_entry().is_none() {
+            message_type_names(nested, visitor, r);
+        }
+    }
+    for nested in &scope.get_enums() {
+        r.push(nested.rust_name());
+    }
+}
+
+/// Declare message module and reexport types from it
+pub fn write_message_mod(
+    message: &MessageWithScope,
+    mod_name: &str,
+    customize: &Customize,
+    w: &mut CodeWriter,
+) {
+    w.write_line(&format!("pub mod {};", mod_name));
+    let mut names = Vec::new();
+    let visitor = customize.generate_visitor.unwrap_or(false);
+    message_type_names(message, visitor, &mut names);
+    for name in names {
+        w.write_line(&format!("pub use self::{}::{};", mod_name, name));
+    }
+}
+
+/// Generate file for top-level message
+///
+/// `file_output_name` is name of file generated for `.proto` file.
+pub fn gen_message_file(
+    message: &MessageWithScope,
+    mod_name: &str,
+    file_output_name: &str,
+    root_scope: &RootScope,
+    customize: &Customize,
+) -> compiler_plugin::GenResult {
+    let mut v = Vec::new();
+    {
+        let mut w = CodeWriter::new(&mut v);
+
+        w.write_generated_by("rust-protobuf", env!("CARGO_PKG_VERSION"));
+
+        w.write_line("");
+        w.write_line("use protobuf::Message as Message_imported_for_functions;");
+        w.write_line(
+            "use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;",
+        );
+        w.write_line("");
+        w.comment("types of other messages and file descriptor");
+        w.write_line("use super::*;");
+
+        w.write_line("");
+        MessageGen::new(message, root_scope, customize).write(&mut w);
+
+        if customize.package_modules.unwrap_or(false) {
+            w.write_line("");
+            package_modules::write_nested_aliases(message, 0, &mut w);
+        }
+
+        w.write_line("");
+        w.insertion_point("module_scope");
+    }
+
+    assert!(file_output_name.ends_with(".rs"));
+    let dir = &file_output_name[..file_output_name.len() - ".rs".len()];
+    compiler_plugin::GenResult {
+        name: format!("{}/{}.rs", dir, mod_name),
+        content: v,
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use protobuf::descriptor::DescriptorProto;
+
+    #[test]
+    fn mod_names() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("foo.proto".to_owned());
+        for name in &["FooBar", "foo_bar", "Exts"] {
+            let mut m = DescriptorProto::new();
+            m.set_name(name.to_string());
+            file.mut_message_type().push(m);
+        }
+        let names: Vec<String> = message_mods(&file).into_iter().map(|(_, n)| n).collect();
+        assert_eq!(vec!["foo_bar_pb", "foo_bar_pb_pb", "exts_pb"], names);
+    }
+}
@@ -0,0 +1,944 @@
+//! Checks of descriptors performed before code generation.
+//!
+//! Code generator assumes descriptors are produced by `protoc`,
+//! so descriptors which `protoc` never produces (e. g. message used as map key,
+//! which is possible with descriptors constructed or modified by hand),
+//! or combinations of options not supported by generated code would otherwise
+//! result in panic in code generator or in generated code which does not compile.
+//!
+//! `gen_and_write` and `protoc-gen-rust` run this check before writing any files,
+//! so calling this function directly is only needed to check descriptors
+//! without generating code.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use protobuf::descriptor::DescriptorProto;
+use protobuf::descriptor::EnumDescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto;
+use protobuf::descriptor::FieldDescriptorProto_Label;
+use protobuf::descriptor::FieldDescriptorProto_Type;
+use protobuf::descriptor::FileDescriptorProto;
+use protobuf::descriptorx::full_name;
+use protobuf::rust::is_rust_keyword;
+use protobuf::rustproto;
+
+use customize::Customize;
+use customize::customize_from_rustproto_for_field;
+use customize::customize_from_rustproto_for_file;
+use customize::customize_from_rustproto_for_message;
+
+
+// `FileDescriptorProto.message_type`
+const FILE_MESSAGE_TYPE: i32 = 4;
+// `DescriptorProto.field`
+const MESSAGE_FIELD: i32 = 2;
+// `DescriptorProto.nested_type`
+const MESSAGE_NESTED_TYPE: i32 = 3;
+
+// sizes for which `smallvec::Array` is implemented
+const SMALLVEC_SIZES: &[u32] = &[
+    36, 0x40, 0x60, 0x80, 0x100, 0x200, 0x400, 0x600, 0x800, 0x1000,
+    0x2000, 0x4000, 0x6000, 0x8000, 0x10000, 0x20000, 0x40000, 0x60000, 0x80000, 0x10_0000,
+];
+
+/// Definition which cannot be generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Problem {
+    /// Name of `.proto` file.
+    pub file: String,
+    /// One-based line and column of the definition in `.proto` file,
+    /// if descriptor contains source code info.
+    pub location: Option<(u32, u32)>,
+    /// Fully qualified name of message or field without leading dot.
+    pub element: String,
+    pub message: String,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.location {
+            Some((line, column)) => write!(f, "{}:{}:{}: ", self.file, line, column)?,
+            None => write!(f, "{}: ", self.file)?,
+        }
+        write!(f, "{}: {}", self.element, self.message)
+    }
+}
+
+/// Error returned by `validate`.
+#[derive(Debug)]
+pub struct ValidationError {
+    pub problems: Vec<Problem>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot generate code, {} problem(s) found:", self.problems.len())?;
+        for p in &self.problems {
+            write!(f, "\n{}", p)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationError {
+    fn description(&self) -> &str {
+        "cannot generate code"
+    }
+}
+
+enum Type<'a> {
+    Message(&'a DescriptorProto),
+    Enum,
+}
+
+fn add_types<'a>(
+    prefix: &str,
+    messages: &'a [DescriptorProto],
+    enums: &'a [EnumDescriptorProto],
+    types: &mut HashMap<String, Type<'a>>,
+) {
+    for e in enums {
+        types.insert(format!(".{}", full_name(prefix, e.get_name())), Type::Enum);
+    }
+    for m in messages {
+        let name = full_name(prefix, m.get_name());
+        add_types(&name, m.get_nested_type(), m.get_enum_type(), types);
+        types.insert(format!(".{}", name), Type::Message(m));
+    }
+}
+
+// proto name of field type, e. g. double or message `.foo.Bar`
+fn type_display(field: &FieldDescriptorProto) -> String {
+    match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_MESSAGE => format!("message `{}`", field.get_type_name()),
+        FieldDescriptorProto_Type::TYPE_ENUM => format!("enum `{}`", field.get_type_name()),
+        t => format!("{:?}", t).trim_start_matches("TYPE_").to_lowercase(),
+    }
+}
+
+/// Whether repeated fields of this type can be packed.
+pub fn is_packable(t: FieldDescriptorProto_Type) -> bool {
+    match t {
+        FieldDescriptorProto_Type::TYPE_STRING |
+        FieldDescriptorProto_Type::TYPE_BYTES |
+        FieldDescriptorProto_Type::TYPE_MESSAGE |
+        FieldDescriptorProto_Type::TYPE_GROUP => false,
+        _ => true,
+    }
+}
+
+// repeated field stored as `SmallVec` with `smallvec_for_repeated` option
+fn is_smallvec_field(field: &FieldDescriptorProto, map: bool) -> bool {
+    let repeated = field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+    repeated && !map && match field.get_field_type() {
+        FieldDescriptorProto_Type::TYPE_ENUM => false,
+        t => is_packable(t),
+    }
+}
+
+fn is_smallvec_size_supported(size: u32) -> bool {
+    size <= 32 || SMALLVEC_SIZES.contains(&size)
+}
+
+// identifier which can be used as field name without `r#`
+fn is_rust_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    name != "_" && !is_rust_keyword(name) && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// field name in generated code, same as `FieldWithContext::rust_name`
+fn field_rust_name(field: &FieldDescriptorProto) -> String {
+    match rustproto::exts::rust_name.get(field.get_options()) {
+        Some(rust_name) => rust_name,
+        None if is_rust_keyword(field.get_name()) => format!("field_{}", field.get_name()),
+        None => field.get_name().to_owned(),
+    }
+}
+
+struct Checker<'a> {
+    file: &'a FileDescriptorProto,
+    types: &'a HashMap<String, Type<'a>>,
+    locations: HashMap<&'a [i32], (u32, u32)>,
+    problems: Vec<Problem>,
+}
+
+impl<'a> Checker<'a> {
+    fn new(file: &'a FileDescriptorProto, types: &'a HashMap<String, Type<'a>>) -> Checker<'a> {
+        let locations = file
+            .get_source_code_info()
+            .get_location()
+            .iter()
+            .filter(|l| l.get_span().len() >= 2)
+            .map(|l| (l.get_path(), (l.get_span()[0] as u32 + 1, l.get_span()[1] as u32 + 1)))
+            .collect();
+        Checker {
+            file: file,
+            types: types,
+            locations: locations,
+            problems: Vec::new(),
+        }
+    }
+
+    fn report(&mut self, path: &[i32], element: &str, message: String) {
+        self.problems.push(Problem {
+            file: self.file.get_name().to_owned(),
+            location: self.locations.get(path).cloned(),
+            element: element.to_owned(),
+            message: message,
+        });
+    }
+
+    // find message or enum referenced by the field
+    fn resolve(&mut self, path: &[i32], element: &str, field: &FieldDescriptorProto)
+        -> Option<&'a Type<'a>>
+    {
+        let types = self.types;
+        let field_type = field.get_field_type();
+        match field_type {
+            FieldDescriptorProto_Type::TYPE_MESSAGE |
+            FieldDescriptorProto_Type::TYPE_GROUP |
+            FieldDescriptorProto_Type::TYPE_ENUM => {}
+            _ => return None,
+        }
+        if !field.has_type_name() {
+            self.report(path, element, "message or enum field has no type name".to_owned());
+            return None;
+        }
+        match types.get(field.get_type_name()) {
+            None => {
+                self.report(
+                    path,
+                    element,
+                    format!(
+                        "type `{}` is not found, file which defines it must be passed \
+                         to code generator with other file descriptors",
+                        field.get_type_name()
+                    ),
+                );
+                None
+            }
+            Some(&Type::Enum) if field_type != FieldDescriptorProto_Type::TYPE_ENUM => {
+                self.report(
+                    path,
+                    element,
+                    format!("field type is message, but `{}` is enum", field.get_type_name()),
+                );
+                None
+            }
+            Some(&Type::Message(..)) if field_type == FieldDescriptorProto_Type::TYPE_ENUM => {
+                self.report(
+                    path,
+                    element,
+                    format!("field type is enum, but `{}` is message", field.get_type_name()),
+                );
+                None
+            }
+            Some(t) => Some(t),
+        }
+    }
+
+    // map entries have no source location, so problems are reported at map field
+    fn check_map_field(
+        &mut self,
+        path: &[i32],
+        element: &str,
+        field: &FieldDescriptorProto,
+        entry: &DescriptorProto,
+    ) {
+        if field.get_label() != FieldDescriptorProto_Label::LABEL_REPEATED {
+            self.report(
+                path,
+                element,
+                format!(
+                    "map entry message `{}` can only be used by map fields, \
+                     which are repeated",
+                    field.get_type_name()
+                ),
+            );
+            return;
+        }
+
+        let key = entry.get_field().iter().find(|f| f.get_number() == 1);
+        let value = entry.get_field().iter().find(|f| f.get_number() == 2);
+        let (key, value) = match (key, value) {
+            (Some(key), Some(value)) => (key, value),
+            _ => {
+                self.report(
+                    path,
+                    element,
+                    format!(
+                        "map entry message `{}` must have key field with number 1 \
+                         and value field with number 2",
+                        field.get_type_name()
+                    ),
+                );
+                return;
+            }
+        };
+
+        match key.get_field_type() {
+            FieldDescriptorProto_Type::TYPE_FLOAT |
+            FieldDescriptorProto_Type::TYPE_DOUBLE |
+            FieldDescriptorProto_Type::TYPE_BYTES |
+            FieldDescriptorProto_Type::TYPE_MESSAGE |
+            FieldDescriptorProto_Type::TYPE_GROUP |
+            FieldDescriptorProto_Type::TYPE_ENUM => {
+                self.report(
+                    path,
+                    element,
+                    format!(
+                        "map key must be of integral, bool or string type, found {}",
+                        type_display(key)
+                    ),
+                );
+            }
+            _ => {}
+        }
+        self.resolve(path, element, value);
+    }
+
+    // option is only honored for some fields, `allowed` describes them
+    fn report_not_allowed(
+        &mut self,
+        path: &[i32],
+        element: &str,
+        field: &FieldDescriptorProto,
+        option: &str,
+        allowed: &str,
+    ) {
+        let repeated = field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+        self.report(
+            path,
+            element,
+            format!(
+                "{} option is only allowed for {}, field is {} {}",
+                option,
+                allowed,
+                if repeated { "repeated" } else { "singular" },
+                type_display(field)
+            ),
+        );
+    }
+
+    // options specified for the field itself, which are ignored for fields
+    // of other kinds; options inherited from message or file are not checked,
+    // because they apply to all fields where applicable
+    fn check_field_options(
+        &mut self,
+        path: &[i32],
+        element: &str,
+        field: &FieldDescriptorProto,
+        map: bool,
+    ) {
+        let options = field.get_options();
+        let field_type = field.get_field_type();
+        let repeated = field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+        let singular = !repeated && !field.has_oneof_index();
+
+        if rustproto::exts::lazy_field_field.get(options) == Some(true) &&
+            !(singular && field_type == FieldDescriptorProto_Type::TYPE_MESSAGE)
+        {
+            self.report_not_allowed(
+                path,
+                element,
+                field,
+                "lazy_field",
+                "singular message fields",
+            );
+        }
+        if rustproto::exts::option_box_for_messages_field.get(options) == Some(true) &&
+            !(singular && field_type == FieldDescriptorProto_Type::TYPE_MESSAGE)
+        {
+            self.report_not_allowed(
+                path,
+                element,
+                field,
+                "option_box_for_messages",
+                "singular message fields",
+            );
+        }
+        if rustproto::exts::carllerche_bytes_for_bytes_field.get(options) == Some(true) &&
+            field_type != FieldDescriptorProto_Type::TYPE_BYTES
+        {
+            self.report_not_allowed(
+                path,
+                element,
+                field,
+                "carllerche_bytes_for_bytes",
+                "bytes fields",
+            );
+        }
+        if rustproto::exts::carllerche_bytes_for_string_field.get(options) == Some(true) &&
+            field_type != FieldDescriptorProto_Type::TYPE_STRING
+        {
+            self.report_not_allowed(
+                path,
+                element,
+                field,
+                "carllerche_bytes_for_string",
+                "string fields",
+            );
+        }
+        if rustproto::exts::repeated_field_vec_field.get(options) == Some(true) &&
+            (!repeated || map)
+        {
+            self.report_not_allowed(
+                path,
+                element,
+                field,
+                "repeated_field_vec",
+                "repeated fields",
+            );
+        }
+        match rustproto::exts::smallvec_for_repeated_field.get(options) {
+            Some(size) if size != 0 => {
+                if !is_smallvec_field(field, map) {
+                    self.report_not_allowed(
+                        path,
+                        element,
+                        field,
+                        "smallvec_for_repeated",
+                        "repeated fields of scalar numeric or bool types",
+                    );
+                } else if rustproto::exts::repeated_field_vec_field.get(options) == Some(true) {
+                    self.report(
+                        path,
+                        element,
+                        "smallvec_for_repeated and repeated_field_vec options \
+                         cannot be used together"
+                            .to_owned(),
+                    );
+                }
+            }
+            _ => {}
+        }
+        // `checked_as` is ignored for non-integer singular fields
+        if rustproto::exts::checked_as.get(options) == Some(true) {
+            if repeated {
+                self.report_not_allowed(
+                    path,
+                    element,
+                    field,
+                    "checked_as",
+                    "singular fields",
+                );
+            } else if rustproto::exts::rust_field_type.get(options).is_some() {
+                self.report(
+                    path,
+                    element,
+                    "checked_as and rust_field_type options cannot be used together".to_owned(),
+                );
+            }
+        }
+    }
+
+    fn check_field(
+        &mut self,
+        path: &[i32],
+        message_name: &str,
+        field: &FieldDescriptorProto,
+        customize: &Customize,
+    ) {
+        let mut customize = customize.clone();
+        customize.update_with(&customize_from_rustproto_for_field(field.get_options()));
+
+        let element = full_name(message_name, field.get_name());
+        let repeated = field.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+
+        let mut map = false;
+        if let Some(&Type::Message(m)) = self.resolve(path, &element, field) {
+            if m.get_options().get_map_entry() {
+                map = true;
+                self.check_map_field(path, &element, field, m);
+            }
+        }
+
+        if field.get_options().get_packed() && !(repeated && is_packable(field.get_field_type())) {
+            self.report_not_allowed(
+                path,
+                &element,
+                field,
+                "packed",
+                "repeated fields of scalar numeric, bool or enum types",
+            );
+        }
+
+        if let Some(rust_field_type) = rustproto::exts::rust_field_type.get(field.get_options()) {
+            let supported = !repeated && match field.get_field_type() {
+                FieldDescriptorProto_Type::TYPE_STRING |
+                FieldDescriptorProto_Type::TYPE_BYTES |
+                FieldDescriptorProto_Type::TYPE_MESSAGE |
+                FieldDescriptorProto_Type::TYPE_GROUP => false,
+                _ => true,
+            };
+            if !supported {
+                self.report_not_allowed(
+                    path,
+                    &element,
+                    field,
+                    "rust_field_type",
+                    "singular fields of scalar numeric, bool or enum types",
+                );
+            } else if rust_field_type.is_empty() {
+                self.report(path, &element, "rust_field_type option is empty".to_owned());
+            } else if !customize.generate_accessors.unwrap_or(true) {
+                self.report(
+                    path,
+                    &element,
+                    "rust_field_type option requires generate_accessors".to_owned(),
+                );
+            }
+        }
+
+        if let Some(rust_name) = rustproto::exts::rust_name.get(field.get_options()) {
+            if !is_rust_identifier(&rust_name) {
+                self.report(
+                    path,
+                    &element,
+                    format!("rust_name `{}` is not a valid Rust identifier", rust_name),
+                );
+            }
+        }
+
+        self.check_field_options(path, &element, field, map);
+
+        let smallvec = customize.smallvec_for_repeated.unwrap_or(0);
+        if is_smallvec_field(field, map) && !is_smallvec_size_supported(smallvec) {
+            self.report(
+                path,
+                &element,
+                format!(
+                    "smallvec_for_repeated = {} is not supported, \
+                     size must be 0 to 32 or one of {:?}",
+                    smallvec,
+                    SMALLVEC_SIZES
+                ),
+            );
+        }
+    }
+
+    fn check_message(
+        &mut self,
+        path: &[i32],
+        prefix: &str,
+        message: &DescriptorProto,
+        customize: &Customize,
+    ) {
+        let mut customize = customize.clone();
+        customize.update_with(&customize_from_rustproto_for_message(message.get_options()));
+
+        let name = full_name(prefix, message.get_name());
+
+        let mut field_path = path.to_vec();
+        field_path.push(MESSAGE_FIELD);
+        // field names in generated code, which may be changed by `rust_name` option
+        let mut rust_names = HashMap::new();
+        for (i, field) in message.get_field().iter().enumerate() {
+            field_path.push(i as i32);
+            self.check_field(&field_path, &name, field, &customize);
+            let rust_name = field_rust_name(field);
+            if let Some(other) = rust_names.insert(rust_name.clone(), field.get_name()) {
+                self.report(
+                    &field_path,
+                    &full_name(&name, field.get_name()),
+                    format!(
+                        "field name `{}` in generated code is also used by field `{}`",
+                        rust_name,
+                        other
+                    ),
+                );
+            }
+            field_path.pop();
+        }
+
+        let mut nested_path = path.to_vec();
+        nested_path.push(MESSAGE_NESTED_TYPE);
+        for (i, nested) in message.get_nested_type().iter().enumerate() {
+            // map entries are checked with map fields
+            if !nested.get_options().get_map_entry() {
+                nested_path.push(i as i32);
+                self.check_message(&nested_path, &name, nested, &customize);
+                nested_path.pop();
+            }
+        }
+    }
+}
+
+/// Check that code can be generated for given files.
+///
+/// Arguments are the same as arguments of `gen`.
+pub fn validate(
+    file_descriptors: &[FileDescriptorProto],
+    files_to_generate: &[String],
+    customize: &Customize,
+) -> Result<(), ValidationError> {
+    let mut types = HashMap::new();
+    for file in file_descriptors {
+        add_types(file.get_package(), file.get_message_type(), file.get_enum_type(), &mut types);
+    }
+
+    let mut problems = Vec::new();
+    for file_name in files_to_generate {
+        let file = match file_descriptors.iter().find(|f| f.get_name() == file_name) {
+            Some(file) => file,
+            None => {
+                problems.push(Problem {
+                    file: file_name.clone(),
+                    location: None,
+                    element: file_name.clone(),
+                    message: "file is not found in file descriptors".to_owned(),
+                });
+                continue;
+            }
+        };
+
+        let mut customize = customize.clone();
+        customize.update_with(&customize_from_rustproto_for_file(file.get_options()));
+
+        let mut checker = Checker::new(file, &types);
+        for (i, message) in file.get_message_type().iter().enumerate() {
+            checker.check_message(
+                &[FILE_MESSAGE_TYPE, i as i32],
+                file.get_package(),
+                message,
+                &customize,
+            );
+        }
+        problems.extend(checker.problems);
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(ValidationError { problems: problems })
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use protobuf::descriptor::SourceCodeInfo_Location;
+    use protobuf::Message;
+
+    fn field(
+        name: &str,
+        number: i32,
+        label: FieldDescriptorProto_Label,
+        field_type: FieldDescriptorProto_Type,
+        type_name: Option<&str>,
+    ) -> FieldDescriptorProto {
+        let mut f = FieldDescriptorProto::new();
+        f.set_name(name.to_owned());
+        f.set_number(number);
+        f.set_label(label);
+        f.set_field_type(field_type);
+        if let Some(type_name) = type_name {
+            f.set_type_name(type_name.to_owned());
+        }
+        f
+    }
+
+    fn message(name: &str) -> DescriptorProto {
+        let mut m = DescriptorProto::new();
+        m.set_name(name.to_owned());
+        m
+    }
+
+    fn file(name: &str, messages: Vec<DescriptorProto>) -> FileDescriptorProto {
+        let mut f = FileDescriptorProto::new();
+        f.set_name(name.to_owned());
+        f.set_package("p".to_owned());
+        for m in messages {
+            f.mut_message_type().push(m);
+        }
+        f
+    }
+
+    fn validate_file(f: FileDescriptorProto, customize: &Customize) -> Vec<String> {
+        let name = f.get_name().to_owned();
+        match validate(&[f], &[name], customize) {
+            Ok(()) => Vec::new(),
+            Err(e) => e.problems.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    fn map_entry(key_type: FieldDescriptorProto_Type, key_type_name: Option<&str>)
+        -> DescriptorProto
+    {
+        let mut entry = message("MEntry");
+        entry.mut_options().set_map_entry(true);
+        entry.mut_field().push(field(
+            "key",
+            1,
+            FieldDescriptorProto_Label::LABEL_OPTIONAL,
+            key_type,
+            key_type_name,
+        ));
+        entry.mut_field().push(field(
+            "value",
+            2,
+            FieldDescriptorProto_Label::LABEL_OPTIONAL,
+            FieldDescriptorProto_Type::TYPE_STRING,
+            None,
+        ));
+        entry
+    }
+
+    fn message_with_map(entry: DescriptorProto, label: FieldDescriptorProto_Label)
+        -> DescriptorProto
+    {
+        let mut m = message("M");
+        m.mut_field().push(field(
+            "m",
+            1,
+            label,
+            FieldDescriptorProto_Type::TYPE_MESSAGE,
+            Some(".p.M.MEntry"),
+        ));
+        m.mut_nested_type().push(entry);
+        m
+    }
+
+    #[test]
+    fn valid() {
+        let entry = map_entry(FieldDescriptorProto_Type::TYPE_STRING, None);
+        let mut m = message_with_map(entry, FieldDescriptorProto_Label::LABEL_REPEATED);
+        let mut packed = field(
+            "packed",
+            2,
+            FieldDescriptorProto_Label::LABEL_REPEATED,
+            FieldDescriptorProto_Type::TYPE_INT32,
+            None,
+        );
+        packed.mut_options().set_packed(true);
+        m.mut_field().push(packed);
+        let customize = Customize {
+            smallvec_for_repeated: Some(64),
+            ..Default::default()
+        };
+        assert_eq!(Vec::<String>::new(), validate_file(file("a.proto", vec![m]), &customize));
+    }
+
+    #[test]
+    fn message_map_key() {
+        let entry = map_entry(FieldDescriptorProto_Type::TYPE_MESSAGE, Some(".p.Key"));
+        let m = message_with_map(entry, FieldDescriptorProto_Label::LABEL_REPEATED);
+        let mut f = file("a.proto", vec![m, message("Key")]);
+        let mut location = SourceCodeInfo_Location::new();
+        location.set_path(vec![4, 0, 2, 0]);
+        location.set_span(vec![9, 4, 30]);
+        f.mut_source_code_info().mut_location().push(location);
+        assert_eq!(
+            vec![
+                "a.proto:10:5: p.M.m: map key must be of integral, bool or string type, \
+                 found message `.p.Key`",
+            ],
+            validate_file(f, &Customize::default())
+        );
+    }
+
+    #[test]
+    fn map_entry_problems() {
+        let entry = map_entry(FieldDescriptorProto_Type::TYPE_STRING, None);
+        let m = message_with_map(entry, FieldDescriptorProto_Label::LABEL_OPTIONAL);
+        assert_eq!(
+            vec![
+                "a.proto: p.M.m: map entry message `.p.M.MEntry` can only be used \
+                 by map fields, which are repeated",
+            ],
+            validate_file(file("a.proto", vec![m]), &Customize::default())
+        );
+
+        let mut entry = map_entry(FieldDescriptorProto_Type::TYPE_STRING, None);
+        entry.mut_field().pop();
+        let m = message_with_map(entry, FieldDescriptorProto_Label::LABEL_REPEATED);
+        assert_eq!(
+            vec![
+                "a.proto: p.M.m: map entry message `.p.M.MEntry` must have key field \
+                 with number 1 and value field with number 2",
+            ],
+            validate_file(file("a.proto", vec![m]), &Customize::default())
+        );
+    }
+
+    #[test]
+    fn unresolved_types() {
+        let mut m = message("M");
+        m.mut_field().push(field(
+            "a",
+            1,
+            FieldDescriptorProto_Label::LABEL_OPTIONAL,
+            FieldDescriptorProto_Type::TYPE_MESSAGE,
+            Some(".q.Missing"),
+        ));
+        m.mut_field().push(field(
+            "b",
+            2,
+            FieldDescriptorProto_Label::LABEL_OPTIONAL,
+            FieldDescriptorProto_Type::TYPE_ENUM,
+            Some(".p.M"),
+        ));
+        let problems = validate_file(file("a.proto", vec![m]), &Customize::default());
+        assert_eq!(2, problems.len());
+        assert!(problems[0].starts_with("a.proto: p.M.a: type `.q.Missing` is not found"));
+        assert_eq!("a.proto: p.M.b: field type is enum, but `.p.M` is message", problems[1]);
+    }
+
+    #[test]
+    fn options() {
+        let mut m = message("M");
+        let mut packed = field(
+            "s",
+            1,
+            FieldDescriptorProto_Label::LABEL_REPEATED,
+            FieldDescriptorProto_Type::TYPE_STRING,
+            None,
+        );
+        packed.mut_options().set_packed(true);
+        m.mut_field().push(packed);
+        m.mut_field().push(field(
+            "i",
+            2,
+            FieldDescriptorProto_Label::LABEL_REPEATED,
+            FieldDescriptorProto_Type::TYPE_INT32,
+            None,
+        ));
+        let customize = Customize {
+            smallvec_for_repeated: Some(33),
+            ..Default::default()
+        };
+        let problems = validate_file(file("a.proto", vec![m]), &customize);
+        assert_eq!(2, problems.len());
+        assert!(problems[0].starts_with("a.proto: p.M.s: packed option is only allowed"));
+        assert!(problems[1].starts_with("a.proto: p.M.i: smallvec_for_repeated = 33"));
+    }
+
+    #[test]
+    fn rust_field_type() {
+        let mut m = message("M");
+        for (name, number, field_type, rust_field_type) in vec![
+            ("s", 1, FieldDescriptorProto_Type::TYPE_STRING, "MyString"),
+            ("i", 2, FieldDescriptorProto_Type::TYPE_INT32, ""),
+            ("u", 3, FieldDescriptorProto_Type::TYPE_UINT64, "UserId"),
+        ] {
+            let label = FieldDescriptorProto_Label::LABEL_OPTIONAL;
+            let mut f = field(name, number, label, field_type, None);
+            f.mut_options()
+                .mut_unknown_fields()
+                .add_length_delimited(17025, rust_field_type.as_bytes().to_vec());
+            m.mut_field().push(f);
+        }
+        let problems = validate_file(file("a.proto", vec![m]), &Customize::default());
+        assert_eq!(2, problems.len());
+        assert!(problems[0].starts_with("a.proto: p.M.s: rust_field_type option is only allowed"));
+        assert_eq!("a.proto: p.M.i: rust_field_type option is empty", problems[1]);
+    }
+
+    #[test]
+    fn field_options() {
+        let mut m = message("M");
+        let label = FieldDescriptorProto_Label::LABEL_OPTIONAL;
+        let repeated = FieldDescriptorProto_Label::LABEL_REPEATED;
+        // (name, label, type, options)
+        let fields: Vec<(&str, _, _, &[u32])> = vec![
+            ("a", label, FieldDescriptorProto_Type::TYPE_INT32, &[17013]),
+            ("b", label, FieldDescriptorProto_Type::TYPE_INT32, &[17018]),
+            ("c", label, FieldDescriptorProto_Type::TYPE_STRING, &[17011]),
+            ("d", label, FieldDescriptorProto_Type::TYPE_BYTES, &[17012]),
+            ("e", label, FieldDescriptorProto_Type::TYPE_INT32, &[17020]),
+            ("f", repeated, FieldDescriptorProto_Type::TYPE_STRING, &[17022]),
+            ("g", repeated, FieldDescriptorProto_Type::TYPE_INT32, &[17020, 17022]),
+            ("h", repeated, FieldDescriptorProto_Type::TYPE_INT32, &[17021]),
+            ("i", label, FieldDescriptorProto_Type::TYPE_INT32, &[17021, 17025]),
+            // valid
+            ("j", label, FieldDescriptorProto_Type::TYPE_MESSAGE, &[17013]),
+            ("k", repeated, FieldDescriptorProto_Type::TYPE_INT32, &[17022]),
+            ("l", label, FieldDescriptorProto_Type::TYPE_UINT64, &[17021]),
+            // ignored
+            ("m", label, FieldDescriptorProto_Type::TYPE_MESSAGE, &[17013, 17018]),
+            ("n", label, FieldDescriptorProto_Type::TYPE_STRING, &[17021]),
+        ];
+        for (i, (name, label, field_type, options)) in fields.into_iter().enumerate() {
+            let type_name = match field_type {
+                FieldDescriptorProto_Type::TYPE_MESSAGE => Some(".p.M"),
+                _ => None,
+            };
+            let mut f = field(name, i as i32 + 1, label, field_type, type_name);
+            for &option in options {
+                let unknown_fields = f.mut_options().mut_unknown_fields();
+                match option {
+                    17022 => unknown_fields.add_varint(option, 4),
+                    17025 => unknown_fields.add_length_delimited(option, b"T".to_vec()),
+                    _ => unknown_fields.add_varint(option, 1),
+                }
+            }
+            m.mut_field().push(f);
+        }
+        assert_eq!(
+            vec![
+                "a.proto: p.M.a: lazy_field option is only allowed for singular message fields, \
+                 field is singular int32",
+                "a.proto: p.M.b: option_box_for_messages option is only allowed \
+                 for singular message fields, field is singular int32",
+                "a.proto: p.M.c: carllerche_bytes_for_bytes option is only allowed \
+                 for bytes fields, field is singular string",
+                "a.proto: p.M.d: carllerche_bytes_for_string option is only allowed \
+                 for string fields, field is singular bytes",
+                "a.proto: p.M.e: repeated_field_vec option is only allowed \
+                 for repeated fields, field is singular int32",
+                "a.proto: p.M.f: smallvec_for_repeated option is only allowed \
+                 for repeated fields of scalar numeric or bool types, field is repeated string",
+                "a.proto: p.M.g: smallvec_for_repeated and repeated_field_vec options \
+                 cannot be used together",
+                "a.proto: p.M.h: checked_as option is only allowed \
+                 for singular fields, field is repeated int32",
+                "a.proto: p.M.i: checked_as and rust_field_type options cannot be used together",
+            ],
+            validate_file(file("a.proto", vec![m]), &Customize::default())
+        );
+    }
+
+    #[test]
+    fn rust_name() {
+        let mut m = message("M");
+        for &(name, number, rust_name) in &[
+            ("a", 1, Some("type")),
+            ("b", 2, Some("c")),
+            ("c", 3, None),
+            ("type", 4, Some("kind")),
+        ] {
+            let label = FieldDescriptorProto_Label::LABEL_OPTIONAL;
+            let mut f = field(name, number, label, FieldDescriptorProto_Type::TYPE_INT32, None);
+            if let Some(rust_name) = rust_name {
+                f.mut_options()
+                    .mut_unknown_fields()
+                    .add_length_delimited(17026, rust_name.as_bytes().to_vec());
+            }
+            m.mut_field().push(f);
+        }
+        let problems = validate_file(file("a.proto", vec![m]), &Customize::default());
+        assert_eq!(2, problems.len());
+        assert_eq!("a.proto: p.M.a: rust_name `type` is not a valid Rust identifier", problems[0]);
+        assert_eq!(
+            "a.proto: p.M.c: field name `c` in generated code is also used by field `b`",
+            problems[1]
+        );
+    }
+
+    #[test]
+    fn file_not_found() {
+        let e = validate(&[], &["a.proto".to_owned()], &Customize::default()).unwrap_err();
+        assert_eq!(
+            "cannot generate code, 1 problem(s) found:\n\
+             a.proto: a.proto: file is not found in file descriptors",
+            e.to_string()
+        );
+    }
+}
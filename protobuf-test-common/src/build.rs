@@ -112,6 +112,15 @@ pub fn gen_mod_rs_in_dir(dir: &str) {
         if mod_name.contains("carllerche") {
             writeln!(mod_rs, r#"#[cfg(feature = "with-bytes")]"#).expect("write");
         }
+        if mod_name.contains("smallvec") {
+            writeln!(mod_rs, r#"#[cfg(feature = "with-smallvec")]"#).expect("write");
+        }
+        if mod_name.contains("arbitrary") {
+            writeln!(mod_rs, r#"#[cfg(feature = "with-arbitrary")]"#).expect("write");
+        }
+        if mod_name.contains("proptest") {
+            writeln!(mod_rs, r#"#[cfg(feature = "with-proptest")]"#).expect("write");
+        }
         writeln!(mod_rs, "mod {};", mod_name).expect("write");
     }
 
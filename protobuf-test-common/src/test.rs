@@ -24,13 +24,17 @@ pub fn test_serialize_deserialize<M : Message + PartialEq>(hex: &str, msg: &M) {
         expected_hex,
         serialized_hex,
         "message {}",
-        M::descriptor_static(None).name()
+        msg.descriptor_dyn().map(|d| d.name()).unwrap_or("<lite>")
     );
     let parsed = parse_from_bytes::<M>(&expected_bytes).unwrap();
     assert_eq!(*msg, parsed);
 
     assert_eq!(expected_bytes.len(), msg.compute_size() as usize);
 
+    let mut sizes = SizeCache::new();
+    let serialized_with_sizes = msg.write_to_bytes_with_sizes(&mut sizes).unwrap();
+    assert_eq!(expected_hex, encode_hex(&serialized_with_sizes));
+
     test_serialize_deserialize_length_delimited(msg);
 }
 
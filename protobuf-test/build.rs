@@ -135,12 +135,67 @@ fn generate_pb_rs() {
     }
 }
 
+// Same protos generated with different `Customize` options
+fn generate_customize() {
+    let protos = glob_simple("src/customize/*.proto");
+    let protos: Vec<&str> = protos.iter().map(|a| a.as_ref()).collect();
+
+    let gen = |dir: &str, customize: Customize| {
+        protoc_rust::run(protoc_rust::Args {
+            out_dir: dir,
+            input: &protos,
+            includes: &["../proto", "src/customize"],
+            customize: customize,
+        }).expect("protoc");
+
+        gen_mod_rs_in_dir(dir);
+    };
+
+    gen("src/customize/split_messages", Customize {
+        split_messages: Some(true),
+        ..Default::default()
+    });
+    gen("src/customize/v3_compat", Customize {
+        v3_compat: Some(true),
+        ..Default::default()
+    });
+    // compiled only with `with-deflate` feature
+    gen("src/customize/compress_descriptor", Customize {
+        compress_descriptor: Some(true),
+        ..Default::default()
+    });
+    gen("src/customize/strip_source_code_info", Customize {
+        strip_source_code_info: Some(true),
+        ..Default::default()
+    });
+    gen("src/customize/embed_descriptor", Customize {
+        embed_descriptor: Some(false),
+        ..Default::default()
+    });
+    gen("src/customize/gen_report", Customize {
+        gen_report: Some(true),
+        ..Default::default()
+    });
+
+    // `mod.rs` files are generated by codegen, test is declared in hand-written `mod.rs`
+    protoc_rust::run(protoc_rust::Args {
+        out_dir: "src/customize/package_modules/generated",
+        input: &protos,
+        includes: &["../proto", "src/customize"],
+        customize: Customize {
+            package_modules: Some(true),
+            ..Default::default()
+        },
+    }).expect("protoc");
+}
+
 fn main() {
     env_logger::init();
 
     clean_old_files();
     generate_v_from_common();
     generate_pb_rs();
+    generate_customize();
 
     if protoc::Protoc::from_env_path()
         .version()
@@ -0,0 +1,68 @@
+use protobuf::arbitrary::Arbitrary;
+use protobuf::arbitrary::Unstructured;
+use protobuf::canonical::canonical_bytes;
+use protobuf::parse_from_bytes;
+use protobuf::rt::ARBITRARY_MAX_LEN;
+use protobuf::Message;
+
+use super::test_arbitrary_pb::*;
+
+// deterministic pseudo-random bytes
+fn data(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+    (0..len)
+        .map(|_| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 56) as u8
+        })
+        .collect()
+}
+
+// messages are compared by serialized form, because `NaN` values are not equal
+fn assert_round_trip<M: Message>(m: &M) {
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed: M = parse_from_bytes(&bytes).unwrap();
+    assert_eq!(canonical_bytes(m, true).unwrap(), canonical_bytes(&parsed, true).unwrap());
+}
+
+#[test]
+fn test_arbitrary() {
+    let mut set_fields = 0;
+    for seed in 0..200 {
+        let data = data(seed, 1000);
+        let m = TestArbitrary::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert!(m.get_repeated_nested().len() <= ARBITRARY_MAX_LEN);
+        assert!(m.get_map_nested().len() <= ARBITRARY_MAX_LEN);
+        assert_round_trip(&m);
+        let has_one = m.has_one_uint32() || m.has_one_string() || m.has_one_recursive();
+        let has_string = !m.get_string_field().is_empty();
+        if has_string && m.has_lazy() && has_one && !m.get_map_enum().is_empty() {
+            set_fields += 1;
+        }
+    }
+    assert!(set_fields > 0);
+}
+
+#[test]
+fn test_arbitrary_exhausted_data() {
+    let m = TestArbitrary::arbitrary(&mut Unstructured::new(&[])).unwrap();
+    assert_eq!(TestArbitrary::new(), m);
+
+    let m = TestArbitraryEmpty::arbitrary(&mut Unstructured::new(&[1, 2, 3])).unwrap();
+    assert_eq!(TestArbitraryEmpty::new(), m);
+}
+
+#[test]
+fn test_arbitrary_recursion_limit() {
+    // each byte sets the nested field, so nesting is only limited by recursion limit
+    let data = vec![1; 1000];
+    let m = TestArbitraryRecursive::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    let mut depth = 0;
+    let mut nested = &m;
+    while nested.has_nested() {
+        nested = nested.get_nested();
+        depth += 1;
+    }
+    assert_eq!(100, depth);
+    assert_round_trip(&m);
+}
@@ -195,7 +195,7 @@ fn test_default_instance() {
 
 #[test]
 fn test_message_descriptor() {
-    assert_eq!("TestDescriptor", TestDescriptor::new().descriptor().name());
+    assert_eq!("TestDescriptor", TestDescriptor::new().message_descriptor().name());
 
     let d = reflect::MessageDescriptor::for_type::<TestDescriptor>();
     assert_eq!("TestDescriptor", d.name());
@@ -210,7 +210,7 @@ fn test_message_descriptor() {
 
 #[test]
 fn test_enum_descriptor() {
-    let d = TestEnumDescriptor::RED.enum_descriptor();
+    let d = TestEnumDescriptor::type_descriptor();
     assert_eq!("TestEnumDescriptor", d.name());
     assert_eq!(
         "TestEnumDescriptor",
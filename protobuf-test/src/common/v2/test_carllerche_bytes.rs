@@ -1,5 +1,8 @@
 use bytes::Bytes;
 use protobuf::Chars;
+use protobuf::Message;
+use protobuf::ParseOptions;
+use protobuf::Utf8Policy;
 
 use super::test_carllerche_bytes_pb::*;
 
@@ -23,3 +26,65 @@ fn test() {
 
     test_serialize_deserialize_no_hex(&m);
 }
+
+#[test]
+fn test_parse_zero_copy() {
+    // `Bytes` stores values shorter than 32 bytes inline, so use longer values
+    let mut m = TestCarllercheBytes::new();
+    m.set_b1(Bytes::from(vec![b'a'; 40]));
+    m.set_s1(Chars::from("c".repeat(40)));
+
+    let bytes = Bytes::from(m.write_to_bytes().unwrap());
+    let parsed: TestCarllercheBytes = protobuf::parse_from_carllerche_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+
+    let input = bytes.as_ptr() as usize..bytes.as_ptr() as usize + bytes.len();
+    assert!(input.contains(&(parsed.get_b1().as_ptr() as usize)));
+    assert!(input.contains(&(parsed.get_s1().as_ptr() as usize)));
+}
+
+#[test]
+fn test_parse_no_zero_copy() {
+    let mut m = TestCarllercheBytes::new();
+    m.set_b1(Bytes::from(vec![b'a'; 40]));
+    m.set_s1(Chars::from("c".repeat(40)));
+
+    let bytes = Bytes::from(m.write_to_bytes().unwrap());
+    let options = ParseOptions::new().zero_copy(false);
+    let parsed: TestCarllercheBytes = options.parse_from_carllerche_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+
+    // fields are copied separately, not sliced from a copy of the whole input
+    let b1 = parsed.get_b1().as_ptr() as usize..parsed.get_b1().as_ptr() as usize + 40;
+    assert!(!b1.contains(&(parsed.get_s1().as_ptr() as usize)));
+    let input = bytes.as_ptr() as usize..bytes.as_ptr() as usize + bytes.len();
+    assert!(!input.contains(&(parsed.get_b1().as_ptr() as usize)));
+    assert!(!input.contains(&(parsed.get_s1().as_ptr() as usize)));
+}
+
+#[test]
+fn test_preserve_invalid_utf8() {
+    // s1 = "a\xffb"
+    let bytes = Bytes::from(&[0x12, 0x03, 0x61, 0xff, 0x62][..]);
+
+    let options = ParseOptions::new().utf8_policy(Utf8Policy::Preserve);
+    let m: TestCarllercheBytes = options.parse_from_carllerche_bytes(&bytes).unwrap();
+    assert_eq!("a\u{fffd}b", &*m.get_s1());
+    assert_eq!(&bytes[..], &m.write_to_bytes().unwrap()[..]);
+
+    let options = ParseOptions::new().utf8_policy(Utf8Policy::Lossy);
+    let m: TestCarllercheBytes = options.parse_from_carllerche_bytes(&bytes).unwrap();
+    assert_eq!(&[0x12, 0x05, 0x61, 0xef, 0xbf, 0xbd, 0x62], &m.write_to_bytes().unwrap()[..]);
+}
+
+#[test]
+fn test_try_from() {
+    use std::convert::TryFrom;
+
+    let mut m = TestCarllercheBytes::new();
+    m.set_s1(Chars::from("ccdd"));
+    let bytes = Bytes::from(m.write_to_bytes().unwrap());
+
+    assert_eq!(m, TestCarllercheBytes::try_from(bytes.clone()).unwrap());
+    assert_eq!(m, TestCarllercheBytes::try_from(&bytes[..]).unwrap());
+}
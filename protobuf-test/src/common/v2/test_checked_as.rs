@@ -0,0 +1,41 @@
+use super::test_checked_as_pb::*;
+
+#[test]
+fn test_in_range() {
+    let mut m = TestCheckedAs::new();
+    m.set_index(10);
+    m.set_timestamp(1_500_000_000);
+    m.set_delta(-3);
+    assert_eq!(Ok(10usize), m.get_index_as());
+    assert_eq!(Ok(1_500_000_000u32), m.get_timestamp_as());
+    assert_eq!(Ok(-3i8), m.get_delta_as());
+    assert_eq!(Ok(-3i64), m.get_delta_as());
+}
+
+#[test]
+fn test_out_of_range() {
+    let mut m = TestCheckedAs::new();
+    m.set_timestamp(-1);
+    let e = m.get_timestamp_as::<u32>().unwrap_err();
+    assert_eq!("timestamp", e.field_name());
+    assert_eq!("value -1 of field timestamp is out of range of target type", e.to_string());
+
+    m.set_index(1 << 40);
+    assert!(m.get_index_as::<u32>().is_err());
+    assert_eq!(Ok(1u64 << 40), m.get_index_as());
+
+    m.set_delta(200);
+    assert!(m.get_delta_as::<i8>().is_err());
+    assert_eq!(Ok(200u8), m.get_delta_as());
+}
+
+#[test]
+fn test_unset_and_oneof() {
+    let mut m = TestCheckedAs::new();
+    // unset field converts default value
+    assert_eq!(Ok(0u8), m.get_index_as());
+    assert_eq!(Ok(0u16), m.get_small_size_as());
+    m.set_small_size(70000);
+    assert!(m.get_small_size_as::<u16>().is_err());
+    assert_eq!(Ok(70000usize), m.get_small_size_as());
+}
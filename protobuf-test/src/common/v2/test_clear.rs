@@ -0,0 +1,90 @@
+use protobuf::Clear;
+use protobuf::Message;
+
+use super::test_clear_pb::*;
+
+fn filled() -> TestClear {
+    let mut m = TestClear::new();
+    m.set_s("string with some capacity".to_owned());
+    m.set_b(vec![1; 100]);
+    m.mut_ints().extend(0..100);
+    for i in 0..10 {
+        let mut nested = TestClearNested::new();
+        nested.set_name(format!("nested {}", i));
+        m.mut_nested().push(nested);
+    }
+    for i in 0..10 {
+        m.mut_string_map().insert(format!("k{}", i), format!("v{}", i));
+    }
+    m.mut_singular_nested().set_name("singular".to_owned());
+    m.set_oneof_string("oneof".to_owned());
+    m.mut_boxed_nested().set_name("boxed".to_owned());
+    m
+}
+
+#[test]
+fn test_clear_retains_capacity() {
+    let mut m = filled();
+    m.clear();
+    assert_eq!(TestClear::new(), m);
+    assert_eq!(Vec::<u8>::new(), m.write_to_bytes().unwrap());
+
+    assert!(m.mut_s().capacity() > 0);
+    assert!(m.mut_b().capacity() >= 100);
+    assert!(m.mut_ints().capacity() >= 100);
+    assert!(m.mut_nested().capacity() >= 10);
+    assert!(m.mut_string_map().capacity() >= 10);
+    assert!(m.mut_singular_nested().mut_name().capacity() > 0);
+}
+
+#[test]
+fn test_parse_into_cleared_reuses_buffers() {
+    let bytes = filled().write_to_bytes().unwrap();
+    let mut m = filled();
+    let s_ptr = m.get_s().as_ptr();
+    let b_ptr = m.get_b().as_ptr();
+    let nested_name_ptr = m.get_nested()[0].get_name().as_ptr();
+
+    m.clear();
+    m.merge_from_bytes(&bytes).unwrap();
+    assert_eq!(filled(), m);
+    assert_eq!(s_ptr, m.get_s().as_ptr());
+    assert_eq!(b_ptr, m.get_b().as_ptr());
+    assert_eq!(nested_name_ptr, m.get_nested()[0].get_name().as_ptr());
+}
+
+#[test]
+fn test_clear_drops_oneof_and_boxed() {
+    let mut m = filled();
+    m.clear();
+    assert!(!m.has_oneof_string());
+    assert!(!m.has_boxed_nested());
+    assert_eq!(0, m.mut_boxed_nested().mut_name().capacity());
+}
+
+#[test]
+fn test_reset_frees_memory() {
+    let mut m = filled();
+    m.reset();
+    assert_eq!(TestClear::new(), m);
+
+    // unlike `clear`, buffers of the filled message are dropped
+    assert_eq!(0, m.mut_s().capacity());
+    assert_eq!(0, m.mut_b().capacity());
+    assert_eq!(0, m.mut_ints().capacity());
+    assert_eq!(0, m.mut_nested().capacity());
+    assert_eq!(0, m.mut_string_map().capacity());
+    assert!(!m.has_singular_nested());
+    assert!(!m.has_oneof_string());
+}
+
+#[test]
+fn test_cleared_message_reused() {
+    let mut m = filled();
+    m.clear();
+    m.set_s("x".to_owned());
+    m.mut_nested().push(TestClearNested::new());
+    assert_eq!(1, m.get_nested().len());
+    assert_eq!("", m.get_nested()[0].get_name());
+    assert!(!m.has_oneof_string());
+}
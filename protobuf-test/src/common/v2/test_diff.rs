@@ -0,0 +1,140 @@
+use protobuf::Message;
+use protobuf::reflect::FieldDiff;
+use protobuf::reflect::ProtobufValueBox;
+use protobuf::reflect::diff;
+use protobuf::reflect::eq_ignoring_unknown_fields;
+use protobuf::reflect::eq_with_mask;
+use protobuf::well_known_types::FieldMask;
+
+use super::test_diff_pb::*;
+
+fn nested(name: &str) -> Nested {
+    let mut nested = Nested::new();
+    nested.set_name(name.to_owned());
+    nested
+}
+
+fn filled() -> TestDiff {
+    let mut m = TestDiff::new();
+    m.set_name("a".to_owned());
+    m.set_color(Color::RED);
+    m.set_nested(nested("b"));
+    m.mut_nested_list().push(nested("c"));
+    m.mut_ints().push(1);
+    m.mut_nested_map().insert("d".to_owned(), nested("e"));
+    m.mut_int_map().insert(1, "f".to_owned());
+    m.set_one_nested(nested("g"));
+    m
+}
+
+fn paths(diffs: &[FieldDiff]) -> Vec<&str> {
+    diffs.iter().map(|d| &d.path[..]).collect()
+}
+
+#[test]
+fn equal() {
+    assert!(diff(&filled(), &filled()).is_empty());
+    assert!(diff(&TestDiff::new(), &TestDiff::new()).is_empty());
+}
+
+#[test]
+fn nested_fields() {
+    let mut other = filled();
+    other.set_name("b".to_owned());
+    other.set_color(Color::GREEN);
+    other.mut_nested().set_number(1);
+    other.mut_nested_list()[0].set_name("x".to_owned());
+    other.mut_ints().push(2);
+    other.mut_one_nested().set_name("y".to_owned());
+
+    let diffs = diff(&filled(), &other);
+    assert_eq!(
+        vec![
+            "name",
+            "color",
+            "nested.number",
+            "nested_list[0].name",
+            "ints[1]",
+            "one_nested.name",
+        ],
+        paths(&diffs)
+    );
+    assert_eq!("name: String(\"a\") != String(\"b\")", diffs[0].to_string());
+    assert_eq!("ints[1]: <unset> != I32(2)", diffs[4].to_string());
+    match (&diffs[1].left, &diffs[1].right) {
+        (&Some(ProtobufValueBox::Enum(l)), &Some(ProtobufValueBox::Enum(r))) => {
+            assert_eq!("RED", l.name());
+            assert_eq!("GREEN", r.name());
+        }
+        _ => panic!("expecting enum values: {:?}", diffs[1]),
+    }
+}
+
+#[test]
+fn oneof() {
+    let mut other = filled();
+    other.set_one_string("h".to_owned());
+
+    let diffs = diff(&filled(), &other);
+    assert_eq!(vec!["one_string", "one_nested"], paths(&diffs));
+    assert!(diffs[0].left.is_none() && diffs[0].right.is_some());
+    match diffs[1].left {
+        Some(ProtobufValueBox::Message(ref m)) => {
+            assert_eq!("Nested", m.descriptor_dyn().unwrap().name())
+        }
+        ref v => panic!("{:?}", v),
+    }
+    assert!(diffs[1].right.is_none());
+}
+
+#[test]
+fn map_and_unknown_fields() {
+    let mut left = filled();
+    left.mut_nested_map().insert("x".to_owned(), nested("x"));
+    let mut right = left.clone();
+    right.mut_nested_map().get_mut("d").unwrap().set_number(2);
+    right.mut_nested_map().remove("x");
+    right.mut_int_map().insert(2, "z".to_owned());
+    right.mut_unknown_fields().add_varint(1000, 1);
+
+    let diffs = diff(&left, &right);
+    assert_eq!(
+        vec!["nested_map[\"d\"].number", "nested_map[\"x\"]", "int_map[2]", "1000"],
+        paths(&diffs)
+    );
+    assert!(diffs[1].left.is_some() && diffs[1].right.is_none());
+}
+
+#[test]
+fn eq_ignoring_unknown() {
+    let mut other = filled();
+    other.mut_unknown_fields().add_varint(1000, 1);
+    other.mut_nested().mut_unknown_fields().add_fixed32(1001, 2);
+    assert!(other != filled());
+    assert!(eq_ignoring_unknown_fields(&filled(), &other));
+
+    other.mut_nested_list().clear();
+    assert!(!eq_ignoring_unknown_fields(&filled(), &other));
+}
+
+#[test]
+fn eq_mask() {
+    fn mask(paths: &[&str]) -> FieldMask {
+        let mut mask = FieldMask::new();
+        mask.set_paths(paths.iter().map(|p| p.to_string()).collect());
+        mask
+    }
+
+    let mut other = filled();
+    other.set_name("b".to_owned());
+    other.mut_nested_list()[0].set_number(1);
+    other.mut_nested_map().get_mut("d").unwrap().set_number(1);
+
+    assert!(!eq_with_mask(&filled(), &other, &mask(&[])));
+    assert!(!eq_with_mask(&filled(), &other, &mask(&["name", "nested_list"])));
+    assert!(!eq_with_mask(&filled(), &other, &mask(&["name", "nested_list.name", "nested_map"])));
+    assert!(eq_with_mask(&filled(), &other, &mask(&["name", "nested_list.number", "nested_map"])));
+    assert!(eq_with_mask(&filled(), &other, &mask(&["name", "nested_list", "nested_map.number"])));
+    // prefix of field name does not match
+    assert!(!eq_with_mask(&filled(), &other, &mask(&["nam", "nested_list", "nested_map"])));
+}
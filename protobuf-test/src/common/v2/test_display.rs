@@ -0,0 +1,31 @@
+use std::fmt;
+
+use protobuf::text_format;
+
+use super::test_display_pb::*;
+
+fn message() -> TestDisplay {
+    let mut m = TestDisplay::new();
+    m.set_name("x".to_owned());
+    m.mut_ids().push(1);
+    m.mut_ids().push(2);
+    m.mut_nested().set_value(3);
+    m
+}
+
+fn show<T: fmt::Display>(t: &T) -> String {
+    format!("{}", t)
+}
+
+#[test]
+fn test_display() {
+    let m = message();
+    assert_eq!("name: \"x\" ids: 1 ids: 2 nested {value: 3}", show(&m));
+    assert_eq!(text_format::print_to_string(&m), show(&m));
+    assert_eq!(format!("{:?}", m), show(&m));
+}
+
+#[test]
+fn test_display_pretty() {
+    assert_eq!(text_format::print_pretty(&message()), format!("{:#}", message()));
+}
@@ -0,0 +1,45 @@
+use protobuf::reflect::MessageDescriptor;
+use protobuf::Message;
+
+use super::test_field_number_consts_pb::*;
+
+#[test]
+fn test_numbers() {
+    let numbers = [
+        ("id", TestFieldNumberConsts::FIELD_ID_NUMBER),
+        ("name", TestFieldNumberConsts::FIELD_NAME_NUMBER),
+        ("values", TestFieldNumberConsts::FIELD_VALUES_NUMBER),
+        ("tags", TestFieldNumberConsts::FIELD_TAGS_NUMBER),
+        ("child", TestFieldNumberConsts::FIELD_CHILD_NUMBER),
+        ("ratio", TestFieldNumberConsts::FIELD_RATIO_NUMBER),
+        ("counts", TestFieldNumberConsts::FIELD_COUNTS_NUMBER),
+    ];
+    let descriptor = MessageDescriptor::for_type::<TestFieldNumberConsts>();
+    for &(name, number) in &numbers {
+        assert_eq!(name, descriptor.field_by_number(number).name());
+    }
+}
+
+#[test]
+fn test_tags() {
+    assert_eq!(8, TestFieldNumberConsts::FIELD_ID_TAG);
+    assert_eq!(18, TestFieldNumberConsts::FIELD_NAME_TAG);
+    // packed
+    assert_eq!(26, TestFieldNumberConsts::FIELD_VALUES_TAG);
+    assert_eq!(41, TestFieldNumberConsts::FIELD_RATIO_TAG);
+    assert_eq!(50, TestFieldNumberConsts::FIELD_COUNTS_TAG);
+}
+
+#[test]
+fn test_tags_match_output() {
+    let mut m = TestFieldNumberConsts::new();
+    m.set_tags(::protobuf::RepeatedField::from_vec(vec!["a".to_owned()]));
+    m.mut_child().set_id(1);
+
+    let bytes = m.write_to_bytes().unwrap();
+    // tags of fields 16 and 200 are two-byte varints
+    let tags = TestFieldNumberConsts::FIELD_TAGS_TAG;
+    assert_eq!(&[(tags as u8 & 0x7f) | 0x80, (tags >> 7) as u8], &bytes[0..2]);
+    let child = TestFieldNumberConsts::FIELD_CHILD_TAG;
+    assert_eq!(&[(child as u8 & 0x7f) | 0x80, (child >> 7) as u8], &bytes[4..6]);
+}
@@ -0,0 +1,58 @@
+use super::test_insertion_point_pb::*;
+
+// code generated from `test_insertion_point_pb.proto`
+const GENERATED: &str = include_str!("test_insertion_point_pb.rs");
+
+// line numbers of lines equal to `line` after trimming
+fn find_lines(line: &str) -> Vec<usize> {
+    GENERATED
+        .lines()
+        .enumerate()
+        .filter(|&(_, l)| l.trim() == line)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+fn find_line(line: &str) -> usize {
+    let lines = find_lines(line);
+    assert_eq!(1, lines.len(), "{}", line);
+    lines[0]
+}
+
+#[test]
+fn test_message_markers() {
+    for name in &[
+        "insertion_point.TestInsertionPoint",
+        "insertion_point.TestInsertionPoint.Nested",
+        "insertion_point.TestInsertionPointEmpty",
+    ] {
+        find_line(&format!("// @@protoc_insertion_point(message:{})", name));
+        find_line(&format!("// @@protoc_insertion_point(message_impl:{})", name));
+    }
+}
+
+#[test]
+fn test_marker_scopes() {
+    // markers are the last lines of struct body and of inherent impl
+    let lines: Vec<&str> = GENERATED.lines().collect();
+    let message =
+        find_line("// @@protoc_insertion_point(message:insertion_point.TestInsertionPoint)");
+    assert_eq!("}", lines[message + 1]);
+    assert!(lines[..message].iter().rev().any(|l| *l == "pub struct TestInsertionPoint {"));
+    let message_impl = find_line(
+        "// @@protoc_insertion_point(message_impl:insertion_point.TestInsertionPoint)",
+    );
+    assert_eq!("}", lines[message_impl + 1]);
+
+    assert_eq!("// @@protoc_insertion_point(module_scope)", *lines.last().unwrap());
+    assert_eq!(1, find_lines("// @@protoc_insertion_point(module_scope)").len());
+}
+
+#[test]
+fn test_messages_unchanged() {
+    let mut m = TestInsertionPoint::new();
+    m.set_x(1);
+    assert_eq!(1, m.get_x());
+    TestInsertionPoint_Nested::new();
+    TestInsertionPointEmpty::new();
+}
@@ -0,0 +1,26 @@
+use protobuf::parse_from_bytes;
+use protobuf::Message;
+
+use super::test_into_bytes_pb::*;
+
+fn send<T: Into<Vec<u8>>>(t: T) -> Vec<u8> {
+    t.into()
+}
+
+#[test]
+fn test_into_bytes() {
+    let mut m = TestIntoBytes::new();
+    m.set_name("x".to_owned());
+    m.set_id(10);
+    let bytes = m.write_to_bytes().unwrap();
+    assert_eq!(bytes, send(&m));
+    assert_eq!(bytes, send(m.clone()));
+    assert_eq!(m, parse_from_bytes::<TestIntoBytes>(&send(&m)).unwrap());
+}
+
+#[test]
+fn test_to_bytes() {
+    let mut m = TestIntoBytesDisabled::new();
+    m.set_name("x".to_owned());
+    assert_eq!(m.write_to_bytes().unwrap(), m.to_bytes());
+}
@@ -0,0 +1,15 @@
+use super::test_json_names_pb::json_names;
+
+#[test]
+fn test_json_names() {
+    assert_eq!("firstName", json_names::TestJsonNames::FIRST_NAME);
+    assert_eq!("fieldWith2Numbers", json_names::TestJsonNames::FIELD_WITH_2_NUMBERS);
+    assert_eq!("type", json_names::TestJsonNames::FIELD_TYPE);
+    assert_eq!("customName", json_names::TestJsonNames::RENAMED);
+    assert_eq!("inOneof", json_names::TestJsonNames::IN_ONEOF);
+}
+
+#[test]
+fn test_nested_message() {
+    assert_eq!("innerValue", json_names::TestJsonNames_Nested::INNER_VALUE);
+}
@@ -0,0 +1,101 @@
+use protobuf::Message;
+use protobuf::SizeCache;
+
+use super::test_lazy_field_pb::*;
+
+fn sample() -> TestLazyField {
+    let mut attachment = TestLazyFieldAttachment::new();
+    attachment.set_name("a.txt".to_owned());
+    attachment.mut_chunks().push(b"abc".to_vec());
+    let mut m = TestLazyField::new();
+    m.set_id("x".to_owned());
+    m.set_attachment(attachment.clone());
+    m.set_eager(attachment);
+    m
+}
+
+#[test]
+fn test_round_trip() {
+    let m = sample();
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed: TestLazyField = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!("a.txt", parsed.get_attachment().get_name());
+    assert_eq!(m, parsed);
+}
+
+#[test]
+fn test_unmodified_written_as_parsed() {
+    let bytes = sample().write_to_bytes().unwrap();
+    let parsed: TestLazyField = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(bytes, parsed.write_to_bytes().unwrap());
+    let mut sizes = SizeCache::new();
+    assert_eq!(bytes, parsed.write_to_bytes_with_sizes(&mut sizes).unwrap());
+}
+
+#[test]
+fn test_modified() {
+    let bytes = sample().write_to_bytes().unwrap();
+    let mut parsed: TestLazyField = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    parsed.mut_attachment().set_name("b.txt".to_owned());
+    let reparsed: TestLazyField = ::protobuf::parse_from_bytes(&parsed.write_to_bytes().unwrap()).unwrap();
+    assert_eq!("b.txt", reparsed.get_attachment().get_name());
+    assert_eq!(b"abc", &reparsed.get_attachment().get_chunks()[0][..]);
+}
+
+#[test]
+fn test_merge() {
+    let mut first = TestLazyField::new();
+    first.mut_attachment().set_name("a.txt".to_owned());
+    let mut second = TestLazyField::new();
+    second.mut_attachment().mut_chunks().push(b"abc".to_vec());
+
+    let mut bytes = first.write_to_bytes().unwrap();
+    bytes.extend(second.write_to_bytes().unwrap());
+    let parsed: TestLazyField = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!("a.txt", parsed.get_attachment().get_name());
+    assert_eq!(1, parsed.get_attachment().get_chunks().len());
+}
+
+#[test]
+fn test_merge_from_message() {
+    let mut first = TestLazyField::new();
+    first.mut_attachment().set_name("a.txt".to_owned());
+    let mut second = TestLazyField::new();
+    second.mut_attachment().mut_chunks().push(b"abc".to_vec());
+    let second: TestLazyField =
+        ::protobuf::parse_from_bytes(&second.write_to_bytes().unwrap()).unwrap();
+
+    first.merge_from_message(&second);
+    assert_eq!("a.txt", first.get_attachment().get_name());
+    assert_eq!(1, first.get_attachment().get_chunks().len());
+}
+
+#[test]
+fn test_clear() {
+    let bytes = sample().write_to_bytes().unwrap();
+    let mut parsed: TestLazyField = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert!(parsed.has_attachment());
+    parsed.clear_attachment();
+    assert!(!parsed.has_attachment());
+    assert_eq!("", parsed.get_attachment().get_name());
+}
+
+#[test]
+fn test_standard_lazy_option() {
+    // attachment fields in non-canonical order: chunks before name
+    let attachment = [0x12, 3, b'a', b'b', b'c', 0x0a, 1, b'a'];
+
+    let mut bytes = vec![0x22, 8];
+    bytes.extend(&attachment);
+    let parsed: TestLazyField = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(bytes, parsed.write_to_bytes().unwrap());
+    assert_eq!("a", parsed.get_standard_lazy().get_name());
+
+    let mut bytes = vec![0x2a, 8];
+    bytes.extend(&attachment);
+    let parsed: TestLazyField = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(
+        vec![0x2a, 8, 0x0a, 1, b'a', 0x12, 3, b'a', b'b', b'c'],
+        parsed.write_to_bytes().unwrap()
+    );
+}
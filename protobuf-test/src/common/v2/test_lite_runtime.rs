@@ -1,3 +1,6 @@
+use protobuf::Message;
+use protobuf::ProtobufEnum;
+
 use protobuf_test_common::*;
 
 use super::test_lite_runtime_pb::*;
@@ -11,3 +14,9 @@ fn test_lite_runtime() {
     // test it doesn't crash
     format!("{:?}", m);
 }
+
+#[test]
+fn test_no_descriptor() {
+    assert!(TestLiteRuntime::new().descriptor_dyn().is_none());
+    assert!(EnumTestLiteRuntime::enum_descriptor_dyn().is_none());
+}
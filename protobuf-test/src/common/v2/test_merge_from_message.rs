@@ -0,0 +1,136 @@
+use protobuf::Message;
+use protobuf::reflect::merge_dyn;
+
+use super::test_merge_from_message_pb::*;
+
+fn nested(name: &str, value: i32) -> TestMergeFromMessageNested {
+    let mut r = TestMergeFromMessageNested::new();
+    if !name.is_empty() {
+        r.set_name(name.to_owned());
+    }
+    if value != 0 {
+        r.set_value(value);
+    }
+    r
+}
+
+// merge through serialization, which `merge_from_message` must be equivalent to
+fn merge_serialized(base: &TestMergeFromMessage, other: &TestMergeFromMessage)
+    -> TestMergeFromMessage
+{
+    let mut r = base.clone();
+    r.merge_from_bytes(&other.write_to_bytes().unwrap()).unwrap();
+    r
+}
+
+fn test_merge(base: &TestMergeFromMessage, other: &TestMergeFromMessage) {
+    let expected = merge_serialized(base, other);
+
+    let mut merged = base.clone();
+    merged.merge_from_message(other);
+    assert_eq!(expected, merged);
+
+    let mut merged = base.clone();
+    merge_dyn(&mut merged, other);
+    assert_eq!(expected, merged);
+}
+
+fn sample() -> TestMergeFromMessage {
+    let mut m = TestMergeFromMessage::new();
+    m.set_int32_field(10);
+    m.set_string_field("base".to_owned());
+    m.set_enum_field(TestMergeFromMessageEnum::ONE);
+    m.set_nested(nested("base", 1));
+    m.mut_repeated_int32().push(1);
+    m.mut_repeated_nested().push(nested("a", 0));
+    m.mut_map_nested().insert("k1".to_owned(), nested("v1", 0));
+    m.mut_map_nested().insert("k2".to_owned(), nested("v2", 0));
+    m.set_oneof_nested(nested("one", 0));
+    m
+}
+
+#[test]
+fn test_empty() {
+    test_merge(&TestMergeFromMessage::new(), &TestMergeFromMessage::new());
+    test_merge(&sample(), &TestMergeFromMessage::new());
+    test_merge(&TestMergeFromMessage::new(), &sample());
+}
+
+#[test]
+fn test_singular_overwritten() {
+    let mut other = TestMergeFromMessage::new();
+    other.set_int32_field(20);
+    other.set_bytes_field(vec![1, 2]);
+
+    let mut expected = sample();
+    expected.set_int32_field(20);
+    expected.set_bytes_field(vec![1, 2]);
+
+    let mut merged = sample();
+    merged.merge_from_message(&other);
+    assert_eq!(expected, merged);
+    test_merge(&sample(), &other);
+}
+
+#[test]
+fn test_repeated_appended() {
+    let mut other = TestMergeFromMessage::new();
+    other.mut_repeated_int32().push(2);
+    other.mut_repeated_nested().push(nested("b", 0));
+
+    let mut merged = sample();
+    merged.merge_from_message(&other);
+    assert_eq!(&[1, 2], merged.get_repeated_int32());
+    assert_eq!(2, merged.get_repeated_nested().len());
+    test_merge(&sample(), &other);
+}
+
+#[test]
+fn test_nested_merged() {
+    let mut other = TestMergeFromMessage::new();
+    other.set_nested(nested("", 2));
+    other.mut_map_nested().insert("k2".to_owned(), nested("", 3));
+    other.mut_map_nested().insert("k3".to_owned(), nested("v3", 0));
+    other.set_oneof_nested(nested("", 4));
+
+    let mut merged = sample();
+    merged.merge_from_message(&other);
+    assert_eq!(&nested("base", 2), merged.get_nested());
+    // oneof message is replaced like in `merge_from`
+    assert_eq!(&nested("", 4), merged.get_oneof_nested());
+    // map entries are replaced, not merged
+    assert_eq!(&nested("", 3), &merged.get_map_nested()["k2"]);
+    assert_eq!(3, merged.get_map_nested().len());
+    test_merge(&sample(), &other);
+}
+
+#[test]
+fn test_oneof_replaced() {
+    let mut other = TestMergeFromMessage::new();
+    other.set_oneof_uint32(5);
+
+    let mut merged = sample();
+    merged.merge_from_message(&other);
+    assert_eq!(5, merged.get_oneof_uint32());
+    assert!(!merged.has_oneof_nested());
+    test_merge(&sample(), &other);
+    test_merge(&other, &sample());
+}
+
+#[test]
+fn test_unknown_fields_appended() {
+    let mut other = TestMergeFromMessage::new();
+    other.mut_unknown_fields().add_varint(100, 1);
+    other.mut_unknown_fields().add_length_delimited(101, vec![1]);
+
+    let mut base = sample();
+    base.mut_unknown_fields().add_varint(100, 2);
+
+    let mut merged = base.clone();
+    merged.merge_from_message(&other);
+    assert_eq!(
+        &[2, 1],
+        &merged.get_unknown_fields().get(100).unwrap().varint[..]
+    );
+    test_merge(&base, &other);
+}
@@ -1,3 +1,5 @@
+use protobuf::Clear;
+
 use protobuf_test_common::*;
 
 use super::test_oneof_pb::*;
@@ -61,3 +63,14 @@ fn test_types() {
     t(|o| o.set_enum_field(EnumForOneof::A));
     t(|o| o.mut_message_field().set_f(22));
 }
+
+#[test]
+fn test_clear() {
+    let mut test_message = TestOneof::new();
+    test_message.set_s("s".to_owned());
+    test_message.mut_message_field().set_f(1);
+    test_message.clear();
+    assert_eq!("", test_message.get_s());
+    assert!(!test_message.has_message_field());
+    assert_eq!(TestOneof::new(), test_message);
+}
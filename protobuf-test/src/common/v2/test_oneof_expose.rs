@@ -4,3 +4,15 @@ use super::test_oneof_expose_pb::*;
 fn test_simple() {
     let _aaa = TestOneofExpose::new().aaa;
 }
+
+#[test]
+fn test_from() {
+    let mut m = TestOneofFrom::new();
+    m.value = Some(10.into());
+    assert_eq!(10, m.get_i());
+    m.value = Some(TestOneofExpose::new().into());
+    assert!(m.has_m());
+    // no `From<String>`, because there are two `string` variants
+    m.value = Some(TestOneofFrom_oneof_value::last_name("x".to_owned()));
+    assert_eq!("x", m.get_last_name());
+}
@@ -4,3 +4,13 @@ use super::test_oneof_recursive_pb::*;
 fn test() {
     let _ = LinkedList::new();
 }
+
+#[test]
+fn test_from_boxed() {
+    let mut node = LinkedList::new();
+    node.set_tail(true);
+    match LinkedList_oneof_x::from(node.clone()) {
+        LinkedList_oneof_x::node(n) => assert_eq!(node, *n),
+        _ => panic!(),
+    }
+}
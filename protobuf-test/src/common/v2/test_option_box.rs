@@ -0,0 +1,73 @@
+use protobuf::Message;
+use protobuf::MessageFull;
+
+use super::test_option_box_pb::*;
+
+#[test]
+fn test_storage_type() {
+    let mut m = TestOptionBox::new();
+    let nested: &Option<Box<TestOptionBoxNested>> = &m.nested;
+    assert!(nested.is_none());
+    m.mut_nested().set_value(10);
+    assert_eq!(Some(10), m.nested.as_ref().map(|n| n.get_value()));
+    // field with option disabled is stored as usual
+    let _: &::protobuf::SingularPtrField<TestOptionBoxNested> = &m.boxed_disabled;
+}
+
+#[test]
+fn test_accessors() {
+    let mut m = TestOptionBox::new();
+    assert!(!m.has_nested());
+    assert_eq!(0, m.get_nested().get_value());
+
+    let mut nested = TestOptionBoxNested::new();
+    nested.mut_child().set_value(2);
+    m.set_nested(nested.clone());
+    assert!(m.has_nested());
+    assert_eq!(2, m.get_nested().get_child().get_value());
+
+    assert_eq!(nested, m.take_nested());
+    assert!(!m.has_nested());
+    assert_eq!(TestOptionBoxNested::new(), m.take_nested());
+
+    m.mut_nested();
+    m.clear_nested();
+    assert!(!m.has_nested());
+}
+
+#[test]
+fn test_round_trip() {
+    let mut m = TestOptionBox::new();
+    m.set_name("x".to_owned());
+    m.mut_nested().mut_child().mut_child().set_value(3);
+    m.mut_boxed_disabled().set_value(4);
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed: TestOptionBox = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+    assert_eq!(3, parsed.get_nested().get_child().get_child().get_value());
+}
+
+#[test]
+fn test_merge() {
+    let mut first = TestOptionBox::new();
+    first.mut_nested().set_value(1);
+    let mut second = TestOptionBox::new();
+    second.mut_nested().mut_child().set_value(2);
+
+    let mut bytes = first.write_to_bytes().unwrap();
+    bytes.extend(second.write_to_bytes().unwrap());
+    let parsed: TestOptionBox = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(1, parsed.get_nested().get_value());
+    assert_eq!(2, parsed.get_nested().get_child().get_value());
+}
+
+#[test]
+fn test_reflect() {
+    let mut m = TestOptionBox::new();
+    let field = m.message_descriptor().field_by_name("nested");
+    assert!(!field.has_field(&m));
+    m.mut_nested().set_value(5);
+    assert!(field.has_field(&m));
+    let nested = field.get_message(&m);
+    assert_eq!(5, nested.descriptor_dyn().unwrap().field_by_name("value").get_i32(nested));
+}
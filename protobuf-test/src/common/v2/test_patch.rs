@@ -0,0 +1,124 @@
+use protobuf::Message;
+use protobuf::patch::PatchError;
+use protobuf::patch::apply_patch;
+use protobuf::patch::diff_serialize;
+use protobuf::well_known_types::Duration;
+
+use super::test_patch_pb::*;
+
+fn nested(name: &str) -> Nested {
+    let mut nested = Nested::new();
+    nested.set_name(name.to_owned());
+    nested
+}
+
+fn base() -> TestPatch {
+    let mut m = TestPatch::new();
+    m.set_name("a".to_owned());
+    m.set_color(Color::RED);
+    m.set_nested(nested("b"));
+    m.mut_nested_list().push(nested("c"));
+    m.mut_ints().push(1);
+    m.mut_nested_map().insert("d".to_owned(), nested("e"));
+    m.set_one_nested(nested("f"));
+    m
+}
+
+fn check_patch<M : Message + PartialEq + Clone>(base: &M, updated: &M) {
+    let patch = diff_serialize(base, updated).unwrap();
+    assert!(patch.len() <= updated.write_to_bytes().unwrap().len());
+    let mut patched = base.clone();
+    apply_patch(&mut patched, &patch).unwrap();
+    assert_eq!(*updated, patched);
+}
+
+fn check_not_representable(updated: &TestPatch, expected_path: &str) {
+    match diff_serialize(&base(), updated) {
+        Err(PatchError::NotRepresentable(ref path)) if path == expected_path => {}
+        r => panic!("{:?}", r),
+    }
+}
+
+#[test]
+fn unchanged() {
+    assert_eq!(Vec::<u8>::new(), diff_serialize(&base(), &base()).unwrap());
+    assert_eq!(Vec::<u8>::new(), diff_serialize(&TestPatch::new(), &TestPatch::new()).unwrap());
+}
+
+#[test]
+fn changed() {
+    let mut updated = base();
+    updated.set_name("b".to_owned());
+    updated.set_color(Color::GREEN);
+    updated.mut_nested_list().push(nested("g"));
+    updated.mut_ints().push(2);
+    updated.mut_one_nested().set_number(3);
+    check_patch(&base(), &updated);
+
+    check_patch(&TestPatch::new(), &base());
+}
+
+#[test]
+fn nested_field() {
+    let mut updated = base();
+    updated.mut_nested().set_name("x".to_owned());
+    let patch = diff_serialize(&base(), &updated).unwrap();
+    // only nested field is written
+    assert_eq!(b"\x1a\x03\x0a\x01x", &patch[..]);
+    check_patch(&base(), &updated);
+}
+
+#[test]
+fn map() {
+    let mut updated = base();
+    updated.mut_nested_map().get_mut("d").unwrap().set_number(1);
+    updated.mut_nested_map().insert("h".to_owned(), Nested::new());
+    check_patch(&base(), &updated);
+
+    updated.mut_nested_map().remove("d");
+    check_not_representable(&updated, "nested_map");
+}
+
+#[test]
+fn oneof() {
+    let mut updated = base();
+    updated.set_one_string("i".to_owned());
+    check_patch(&base(), &updated);
+
+    let mut updated = base();
+    updated.clear_one_nested();
+    check_not_representable(&updated, "one_nested");
+}
+
+#[test]
+fn proto3_zero() {
+    let mut base = Duration::new();
+    base.set_seconds(10);
+    base.set_nanos(5);
+    let mut updated = base.clone();
+    updated.set_seconds(0);
+    check_patch(&base, &updated);
+}
+
+#[test]
+fn not_representable() {
+    let mut updated = base();
+    updated.clear_nested();
+    check_not_representable(&updated, "nested");
+
+    let mut updated = base();
+    updated.mut_ints().clear();
+    check_not_representable(&updated, "ints");
+
+    let mut updated = base();
+    updated.mut_nested_list()[0].set_name("x".to_owned());
+    check_not_representable(&updated, "nested_list");
+
+    let mut updated = base();
+    updated.mut_unknown_fields().add_varint(1000, 1);
+    check_not_representable(&updated, "<unknown fields>");
+
+    let mut updated = base();
+    updated.mut_nested().mut_unknown_fields().add_varint(1000, 1);
+    check_not_representable(&updated, "nested.<unknown fields>");
+}
@@ -0,0 +1,63 @@
+use protobuf::proptest::arbitrary::any_with;
+use protobuf::proptest::test_runner::TestRunner;
+use protobuf::parse_from_bytes;
+use protobuf::rt::ProptestDepth;
+use protobuf::rt::PROPTEST_MAX_LEN;
+use protobuf::Message;
+
+use super::test_proptest_pb::*;
+
+fn depth(m: &TestProptest) -> u32 {
+    let mut depth = 0;
+    let mut nested = m;
+    while nested.has_recursive() {
+        nested = nested.get_recursive();
+        depth += 1;
+    }
+    depth
+}
+
+#[test]
+fn test_proptest_round_trip() {
+    let mut runner = TestRunner::default();
+    runner
+        .run(&arbitrary_test_proptest(), |m| {
+            // required fields are always set
+            assert!(m.is_initialized());
+            assert!(m.get_repeated_nested().len() <= PROPTEST_MAX_LEN);
+            assert!(m.get_map_enum().len() <= PROPTEST_MAX_LEN);
+            let bytes = m.write_to_bytes().unwrap();
+            assert_eq!(m, parse_from_bytes::<TestProptest>(&bytes).unwrap());
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_proptest_depth() {
+    let mut runner = TestRunner::default();
+    runner
+        .run(&any_with::<TestProptest>(ProptestDepth(2)), |m| {
+            assert!(depth(&m) <= 2);
+            Ok(())
+        })
+        .unwrap();
+    runner
+        .run(&any_with::<TestProptest>(ProptestDepth(0)), |m| {
+            assert!(!m.has_recursive());
+            assert!(m.get_repeated_nested().is_empty());
+            Ok(())
+        })
+        .unwrap();
+}
+
+#[test]
+fn test_proptest_empty() {
+    let mut runner = TestRunner::default();
+    runner
+        .run(&arbitrary_test_proptest_empty(), |m| {
+            assert_eq!(TestProptestEmpty::new(), m);
+            Ok(())
+        })
+        .unwrap();
+}
@@ -0,0 +1,230 @@
+use protobuf::ProtobufEnumFull;
+use protobuf::reflect::ProtobufValueBox;
+use protobuf::reflect::Record;
+use protobuf::reflect::RecordError;
+use protobuf::reflect::from_record;
+use protobuf::reflect::merge_from_record;
+use protobuf::reflect::to_record;
+
+use super::test_record_pb::*;
+
+fn nested(name: &str) -> Nested {
+    let mut nested = Nested::new();
+    nested.set_name(name.to_owned());
+    nested.mut_values().push(1);
+    nested
+}
+
+fn filled() -> TestRecord {
+    let mut m = TestRecord::new();
+    m.set_s("s".to_owned());
+    m.set_si(-2);
+    m.set_color(Color::GREEN);
+    m.set_nested(nested("a"));
+    m.mut_nested_list().push(nested("b"));
+    m.mut_strings().push("c".to_owned());
+    m.mut_nested_map().insert("d".to_owned(), nested("e"));
+    m.mut_int_map().insert(3, "f".to_owned());
+    m.set_one_nested(nested("g"));
+    m
+}
+
+fn string(s: &str) -> ProtobufValueBox {
+    ProtobufValueBox::String(s.to_owned())
+}
+
+#[test]
+fn to_record_fields() {
+    let record = to_record(&filled());
+    assert_eq!(9, record.len());
+    assert!(!record.contains_key("i"));
+    assert!(!record.contains_key("one_string"));
+
+    match record["s"] {
+        ProtobufValueBox::String(ref s) => assert_eq!("s", s),
+        ref v => panic!("{:?}", v),
+    }
+    match record["si"] {
+        ProtobufValueBox::I64(-2) => {}
+        ref v => panic!("{:?}", v),
+    }
+    match record["color"] {
+        ProtobufValueBox::Enum(v) => assert_eq!("GREEN", v.name()),
+        ref v => panic!("{:?}", v),
+    }
+    match record["nested"] {
+        ProtobufValueBox::Record(ref nested) => {
+            assert_eq!(2, nested.len());
+            match nested["values"] {
+                ProtobufValueBox::Repeated(ref values) => match values[..] {
+                    [ProtobufValueBox::I32(1)] => {}
+                    ref v => panic!("{:?}", v),
+                },
+                ref v => panic!("{:?}", v),
+            }
+        }
+        ref v => panic!("{:?}", v),
+    }
+    match record["nested_list"] {
+        ProtobufValueBox::Repeated(ref values) => match values[..] {
+            [ProtobufValueBox::Record(ref nested)] => assert!(nested.contains_key("name")),
+            ref v => panic!("{:?}", v),
+        },
+        ref v => panic!("{:?}", v),
+    }
+    match record["strings"] {
+        ProtobufValueBox::Repeated(ref values) => match values[..] {
+            [ProtobufValueBox::String(ref s)] => assert_eq!("c", s),
+            ref v => panic!("{:?}", v),
+        },
+        ref v => panic!("{:?}", v),
+    }
+    match record["nested_map"] {
+        ProtobufValueBox::Map(ref entries) => match entries[..] {
+            [(ProtobufValueBox::String(ref k), ProtobufValueBox::Record(ref v))] => {
+                assert_eq!("d", k);
+                assert_eq!(2, v.len());
+            }
+            ref v => panic!("{:?}", v),
+        },
+        ref v => panic!("{:?}", v),
+    }
+    match record["int_map"] {
+        ProtobufValueBox::Map(ref entries) => match entries[..] {
+            [(ProtobufValueBox::I32(3), ProtobufValueBox::String(ref v))] => assert_eq!("f", v),
+            ref v => panic!("{:?}", v),
+        },
+        ref v => panic!("{:?}", v),
+    }
+    match record["one_nested"] {
+        ProtobufValueBox::Record(ref nested) => assert_eq!(2, nested.len()),
+        ref v => panic!("{:?}", v),
+    }
+}
+
+#[test]
+fn round_trip() {
+    let m = filled();
+    assert_eq!(m, from_record(&to_record(&m)).unwrap());
+
+    let mut m = TestRecord::new();
+    m.set_one_string("h".to_owned());
+    assert_eq!(m, from_record(&to_record(&m)).unwrap());
+
+    assert_eq!(TestRecord::new(), from_record(&Record::new()).unwrap());
+}
+
+#[test]
+fn merge() {
+    let mut record = Record::new();
+    record.insert("s".to_owned(), string("t"));
+    record.insert("strings".to_owned(), ProtobufValueBox::Repeated(vec![string("u")]));
+    let mut m = filled();
+    merge_from_record(&mut m, &record).unwrap();
+    assert_eq!("t", m.get_s());
+    assert_eq!(&["c".to_owned(), "u".to_owned()], m.get_strings());
+
+    // enum field from number
+    let mut record = Record::new();
+    record.insert("color".to_owned(), ProtobufValueBox::I32(1));
+    let m: TestRecord = from_record(&record).unwrap();
+    assert_eq!(Color::RED, m.get_color());
+
+    // nested messages as records and as messages, map entries
+    let mut nested_record = Record::new();
+    nested_record.insert("name".to_owned(), string("v"));
+    let mut record = Record::new();
+    record.insert("nested".to_owned(), ProtobufValueBox::Record(nested_record));
+    record.insert(
+        "nested_list".to_owned(),
+        ProtobufValueBox::Repeated(vec![ProtobufValueBox::Message(Box::new(nested("w")))]),
+    );
+    record.insert(
+        "int_map".to_owned(),
+        ProtobufValueBox::Map(vec![(ProtobufValueBox::I32(4), string("x"))]),
+    );
+    let mut m = filled();
+    merge_from_record(&mut m, &record).unwrap();
+    assert_eq!("v", m.get_nested().get_name());
+    assert_eq!(&[1], m.get_nested().get_values());
+    assert_eq!("w", m.get_nested_list()[1].get_name());
+    assert_eq!(2, m.get_int_map().len());
+
+    // other oneof variant replaces set one
+    let mut record = Record::new();
+    record.insert("one_string".to_owned(), string("y"));
+    let mut m = filled();
+    merge_from_record(&mut m, &record).unwrap();
+    assert_eq!("y", m.get_one_string());
+    assert!(!m.has_one_nested());
+}
+
+#[test]
+fn errors() {
+    let mut record = Record::new();
+    record.insert("ss".to_owned(), string("a"));
+    match from_record::<TestRecord>(&record) {
+        Err(RecordError::UnknownField(ref name)) if name == "ss" => {}
+        r => panic!("{:?}", r),
+    }
+
+    let mut record = Record::new();
+    record.insert("s".to_owned(), ProtobufValueBox::I32(1));
+    match from_record::<TestRecord>(&record) {
+        Err(RecordError::TypeMismatch(ref name)) if name == "s" => {}
+        r => panic!("{:?}", r),
+    }
+
+    // message of other type
+    let mut record = Record::new();
+    record.insert("nested".to_owned(), ProtobufValueBox::Message(Box::new(TestRecord::new())));
+    match from_record::<TestRecord>(&record) {
+        Err(RecordError::TypeMismatch(ref name)) if name == "nested" => {}
+        r => panic!("{:?}", r),
+    }
+
+    // value of other enum
+    let mut record = Record::new();
+    record.insert("color".to_owned(), ProtobufValueBox::Enum(Shape::CIRCLE.value_descriptor()));
+    match from_record::<TestRecord>(&record) {
+        Err(RecordError::TypeMismatch(ref name)) if name == "color" => {}
+        r => panic!("{:?}", r),
+    }
+
+    // unknown field of nested record
+    let mut nested_record = Record::new();
+    nested_record.insert("nme".to_owned(), string("a"));
+    let mut record = Record::new();
+    record.insert("nested".to_owned(), ProtobufValueBox::Record(nested_record));
+    match from_record::<TestRecord>(&record) {
+        Err(RecordError::UnknownField(ref name)) if name == "nme" => {}
+        r => panic!("{:?}", r),
+    }
+
+    // singular value for repeated field
+    let mut record = Record::new();
+    record.insert("strings".to_owned(), string("a"));
+    match from_record::<TestRecord>(&record) {
+        Err(RecordError::TypeMismatch(ref name)) if name == "strings" => {}
+        r => panic!("{:?}", r),
+    }
+
+    // repeated value for map field
+    let mut record = Record::new();
+    record.insert("int_map".to_owned(), ProtobufValueBox::Repeated(vec![string("a")]));
+    match from_record::<TestRecord>(&record) {
+        Err(RecordError::TypeMismatch(ref name)) if name == "int_map" => {}
+        r => panic!("{:?}", r),
+    }
+
+    // map entry of wrong type
+    let mut record = Record::new();
+    record.insert(
+        "int_map".to_owned(),
+        ProtobufValueBox::Map(vec![(string("a"), string("b"))]),
+    );
+    match from_record::<TestRecord>(&record) {
+        Err(RecordError::TypeMismatch(ref name)) if name == "int_map" => {}
+        r => panic!("{:?}", r),
+    }
+}
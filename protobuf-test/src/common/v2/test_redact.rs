@@ -0,0 +1,37 @@
+use protobuf::text_format;
+
+use super::test_redact_pb::*;
+
+fn request() -> TestRedact {
+    let mut m = TestRedact::new();
+    m.set_method("login".to_owned());
+    m.set_token(b"secret token".to_vec());
+    m.mut_keys().push("secret key".to_owned());
+    m.mut_keys().push("another secret key".to_owned());
+    m.mut_credentials().set_user("root".to_owned());
+    m.mut_credentials().set_password("secret password".to_owned());
+    m.mut_headers().insert("authorization".to_owned(), "secret".to_owned());
+    m
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(
+        "method: \"login\" token: <redacted> keys: <redacted> \
+         credentials {user: \"root\" password: <redacted>} headers: <redacted>",
+        format!("{:?}", request())
+    );
+    assert!(!format!("{:#?}", request()).contains("secret"));
+}
+
+#[test]
+fn test_debug_unset() {
+    let mut m = TestRedact::new();
+    m.set_method("login".to_owned());
+    assert_eq!("method: \"login\"", format!("{:?}", m));
+}
+
+#[test]
+fn test_text_format_not_redacted() {
+    assert!(text_format::print_to_string(&request()).contains("secret password"));
+}
@@ -0,0 +1,15 @@
+use super::test_redact_lite_pb::*;
+
+#[test]
+fn test_debug() {
+    let mut m = TestRedactLite::new();
+    m.set_user("root".to_owned());
+    m.set_password("secret password".to_owned());
+    m.set_certificate("secret certificate".to_owned());
+
+    let debug = format!("{:?}", m);
+    assert!(debug.contains("root"));
+    assert!(debug.contains("password: <redacted>"));
+    assert!(debug.contains("credentials: <redacted>"));
+    assert!(!debug.contains("secret"));
+}
@@ -0,0 +1,20 @@
+use super::test_ref_accessors_only_pb::*;
+
+#[test]
+fn test_accessors() {
+    let mut m = TestRefAccessorsOnly::new();
+    m.mut_blob().extend_from_slice(b"abc");
+    m.mut_nested().set_x(10);
+    m.mut_chunks().push(b"d".to_vec());
+    // `set_` is still generated for fields of copy types
+    m.set_size(3);
+
+    assert_eq!(b"abc", m.get_blob());
+    assert_eq!(10, m.get_nested().get_x());
+    assert_eq!(1, m.get_chunks().len());
+    assert_eq!(3, m.get_size());
+
+    let blob = m.take_blob();
+    assert_eq!(b"abc", &blob[..]);
+    assert!(m.get_blob().is_empty());
+}
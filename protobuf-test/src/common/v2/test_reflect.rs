@@ -1,6 +1,10 @@
-use super::test_reflect_pb::M;
+use super::test_reflect_pb::*;
 
-use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf::ProtobufEnum;
+use protobuf::descriptor::FieldDescriptorProto_Type;
+use protobuf::reflect::EnumDescriptor;
+use protobuf::reflect::ProtobufValueRef;
 
 #[test]
 fn test_get_sub_message_via_reflection() {
@@ -8,10 +12,98 @@ fn test_get_sub_message_via_reflection() {
     m.mut_sub_m().set_n(42);
     assert!(m.has_sub_m());
 
-    let descriptor = m.descriptor().field_by_name("sub_m");
+    let descriptor = m.message_descriptor().field_by_name("sub_m");
     assert_eq!("sub_m", descriptor.name());
 
     let sub_m = descriptor.get_message(&m);
-    assert_eq!("SubM", sub_m.descriptor().full_name());
-    assert_eq!(42, sub_m.descriptor().field_by_name("n").get_i32(sub_m));
+    assert_eq!("SubM", sub_m.descriptor_dyn().unwrap().full_name());
+    assert_eq!(42, sub_m.descriptor_dyn().unwrap().field_by_name("n").get_i32(sub_m));
+}
+
+#[test]
+fn test_enum_values() {
+    let descriptor = EnumDescriptor::for_type::<Color>();
+    let names: Vec<_> = descriptor.values().iter().map(|v| v.name()).collect();
+    assert_eq!(vec!["COLOR_UNSPECIFIED", "RED", "GREEN", "BLUE"], names);
+
+    assert_eq!(2, descriptor.get_value_by_name("GREEN").unwrap().value());
+    assert_eq!("BLUE", descriptor.get_value_by_number(3).unwrap().name());
+    assert!(descriptor.get_value_by_name("PURPLE").is_none());
+    assert!(descriptor.get_value_by_number(4).is_none());
+}
+
+#[test]
+fn test_field_enum_descriptor() {
+    let descriptor = WithEnums::new().message_descriptor();
+    for name in &["color", "colors", "color_by_name"] {
+        let field = descriptor.field_by_name(name);
+        assert_eq!("Color", field.enum_descriptor().unwrap().name(), "{}", name);
+    }
+    assert!(descriptor.field_by_name("n").enum_descriptor().is_none());
+
+    // set field from user-supplied enum name
+    let field = descriptor.field_by_name("color");
+    let value = field.enum_descriptor().unwrap().get_value_by_name("BLUE").unwrap();
+    let mut m = WithEnums::new();
+    m.set_color(Color::from_i32(value.value()).unwrap());
+    assert_eq!(Color::BLUE, m.get_color());
+}
+
+#[test]
+fn test_which_oneof() {
+    let descriptor = WithOneof::new().message_descriptor();
+    let oneof_names: Vec<_> = descriptor.oneofs().iter().map(|o| o.name()).collect();
+    assert_eq!(vec!["result"], oneof_names);
+    assert_eq!(&[1, 2], descriptor.get_oneof_by_name("result").unwrap().field_numbers());
+    assert!(descriptor.get_oneof_by_name("n").is_none());
+
+    let mut m = WithOneof::new();
+    m.set_n(10);
+    assert!(descriptor.which_oneof(&m, "result").is_none());
+
+    m.set_ok("yes".to_owned());
+    let field = descriptor.which_oneof(&m, "result").unwrap();
+    assert_eq!("ok", field.name());
+    assert_eq!("yes", field.get_str(&m));
+
+    m.mut_sub_m().set_n(42);
+    let field = descriptor.which_oneof(&m, "result").unwrap();
+    assert_eq!("sub_m", field.name());
+    let sub_m = field.get_message(&m);
+    assert_eq!(42, sub_m.descriptor_dyn().unwrap().field_by_name("n").get_i32(sub_m));
+}
+
+#[test]
+fn test_map_field() {
+    let mut m = WithEnums::new();
+    m.mut_color_by_name().insert("sky".to_owned(), Color::BLUE);
+    m.mut_color_by_name().insert("grass".to_owned(), Color::GREEN);
+
+    let descriptor = m.message_descriptor();
+    let field = descriptor.field_by_name("color_by_name");
+    assert!(field.is_map());
+    assert_eq!(Some(FieldDescriptorProto_Type::TYPE_STRING), field.map_key_type());
+    assert_eq!(Some(FieldDescriptorProto_Type::TYPE_ENUM), field.map_value_type());
+    assert!(!descriptor.field_by_name("colors").is_map());
+    assert_eq!(None, descriptor.field_by_name("n").map_key_type());
+
+    let map = field.get_map(&m);
+    assert_eq!(2, map.len());
+    match map.get(ProtobufValueRef::String("sky")).map(|v| v.as_ref()) {
+        Some(ProtobufValueRef::Enum(v)) => assert_eq!("BLUE", v.name()),
+        _ => panic!("expecting enum value"),
+    }
+    assert!(map.get(ProtobufValueRef::String("sea")).is_none());
+    // key of another type
+    assert!(map.get(ProtobufValueRef::I32(1)).is_none());
+
+    let mut keys: Vec<_> = map
+        .reflect_iter()
+        .map(|(k, _)| match k.as_ref() {
+            ProtobufValueRef::String(k) => k.to_owned(),
+            _ => panic!("expecting string key"),
+        })
+        .collect();
+    keys.sort();
+    assert_eq!(vec!["grass", "sky"], keys);
 }
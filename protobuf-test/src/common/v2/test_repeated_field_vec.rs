@@ -0,0 +1,82 @@
+use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf::reflect::ProtobufValueRef;
+use protobuf::reflect::ReflectFieldRef;
+
+use super::test_repeated_field_vec_pb::*;
+
+fn item(value: i32) -> TestRepeatedFieldVecItem {
+    let mut item = TestRepeatedFieldVecItem::new();
+    item.set_value(value);
+    item
+}
+
+#[test]
+fn test_accessors() {
+    let mut m = TestRepeatedFieldVec::new();
+    let strings: &[String] = m.get_strings();
+    assert!(strings.is_empty());
+
+    m.set_strings(vec!["a".to_owned(), "b".to_owned()]);
+    m.mut_strings().push("c".to_owned());
+    assert_eq!(&["a", "b", "c"], &m.get_strings()[..]);
+
+    let items: &mut Vec<TestRepeatedFieldVecItem> = m.mut_items();
+    items.push(item(1));
+    assert_eq!(vec![item(1)], m.take_items());
+    assert!(m.get_items().is_empty());
+
+    m.clear_strings();
+    assert!(m.get_strings().is_empty());
+
+    // field with option disabled is stored as usual
+    let _: &mut ::protobuf::RepeatedField<String> = m.mut_strings_disabled();
+}
+
+#[test]
+fn test_round_trip() {
+    let mut m = TestRepeatedFieldVec::new();
+    m.set_strings(vec!["x".to_owned(), String::new()]);
+    m.set_bytes_values(vec![vec![1, 2], vec![]]);
+    let mut parent = item(1);
+    parent.mut_children().push(item(2));
+    m.set_items(vec![parent, item(3)]);
+    m.set_ints(vec![4, 5]);
+    m.mut_strings_disabled().push("y".to_owned());
+
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed: TestRepeatedFieldVec = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+    assert_eq!(2, parsed.get_items()[0].get_children()[0].get_value());
+}
+
+#[test]
+fn test_merge() {
+    let mut first = TestRepeatedFieldVec::new();
+    first.mut_items().push(item(1));
+    let mut second = TestRepeatedFieldVec::new();
+    second.mut_items().push(item(2));
+
+    first.merge_from_bytes(&second.write_to_bytes().unwrap()).unwrap();
+    assert_eq!(vec![item(1), item(2)], first.get_items().to_vec());
+}
+
+#[test]
+fn test_reflect() {
+    let mut m = TestRepeatedFieldVec::new();
+    m.mut_items().push(item(7));
+    m.mut_strings().push("s".to_owned());
+    let descriptor = m.message_descriptor();
+    assert_eq!(1, descriptor.field_by_name("items").len_field(&m));
+    assert_eq!(1, descriptor.field_by_name("strings").len_field(&m));
+    let items = match descriptor.field_by_name("items").get_reflect(&m) {
+        ReflectFieldRef::Repeated(items) => items,
+        _ => panic!(),
+    };
+    match items.get(0).as_ref() {
+        ProtobufValueRef::Message(item) => {
+            assert_eq!(7, item.descriptor_dyn().unwrap().field_by_name("value").get_i32(item));
+        }
+        _ => panic!(),
+    }
+}
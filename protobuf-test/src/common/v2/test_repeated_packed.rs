@@ -1,3 +1,6 @@
+use protobuf::parse_from_bytes;
+use protobuf::Message;
+
 use super::test_repeated_packed_pb::*;
 
 use protobuf_test_common::*;
@@ -81,3 +84,55 @@ fn test_issue_281() {
     test.set_values((0..100).collect());
     test_serialize_deserialize_no_hex(&test);
 }
+
+macro_rules! set_all_types {
+    ($m:expr) => {{
+        $m.set_int32s(vec![1, -1, 300]);
+        $m.set_int64s(vec![1, -1, 300]);
+        $m.set_uint32s(vec![1, 0, 300]);
+        $m.set_uint64s(vec![1, 0, 300]);
+        $m.set_sint32s(vec![1, -1, 300]);
+        $m.set_sint64s(vec![1, -1, 300]);
+        $m.set_fixed32s(vec![1, 0, 300]);
+        $m.set_fixed64s(vec![1, 0, 300]);
+        $m.set_sfixed32s(vec![1, -1, 300]);
+        $m.set_sfixed64s(vec![1, -1, 300]);
+        $m.set_doubles(vec![1.5, -1.0, 0.0]);
+        $m.set_floats(vec![1.5, -1.0, 0.0]);
+        $m.set_bools(vec![true, false, true]);
+        $m.set_enums(vec![
+            TestPackedEnum::ONE,
+            TestPackedEnum::NEGATIVE,
+            TestPackedEnum::UNKNOWN,
+        ]);
+    }};
+}
+
+#[test]
+fn test_write_packed_bool_and_enum() {
+    let mut test = TestPackedAllTypes::new();
+    test.set_bools(vec![true, false]);
+    test_serialize_deserialize("6a 02 01 00", &test);
+    // negative enum values are sign-extended to ten bytes
+    let mut test = TestPackedAllTypes::new();
+    test.set_enums(vec![TestPackedEnum::ONE, TestPackedEnum::NEGATIVE]);
+    test_serialize_deserialize("72 0b 01 ff ff ff ff ff ff ff ff ff 01", &test);
+}
+
+#[test]
+fn test_all_types_packed_and_unpacked() {
+    let mut packed = TestPackedAllTypes::new();
+    set_all_types!(packed);
+    let mut unpacked = TestUnpackedAllTypes::new();
+    set_all_types!(unpacked);
+
+    let packed_bytes = packed.write_to_bytes().unwrap();
+    let unpacked_bytes = unpacked.write_to_bytes().unwrap();
+    assert!(packed_bytes.len() < unpacked_bytes.len());
+
+    // both encodings are accepted for every type
+    assert_eq!(packed, parse_from_bytes::<TestPackedAllTypes>(&unpacked_bytes).unwrap());
+    assert_eq!(unpacked, parse_from_bytes::<TestUnpackedAllTypes>(&packed_bytes).unwrap());
+    test_serialize_deserialize_no_hex(&packed);
+    test_serialize_deserialize_no_hex(&unpacked);
+}
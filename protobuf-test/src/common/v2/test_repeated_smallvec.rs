@@ -0,0 +1,71 @@
+use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf::SmallVec;
+
+use super::test_repeated_smallvec_pb::*;
+
+#[test]
+fn test_storage_type() {
+    let mut m = TestRepeatedSmallvec::new();
+    let ints: &mut SmallVec<[i32; 3]> = m.mut_ints();
+    ints.extend(vec![1, 2, 3]);
+    assert!(!ints.spilled());
+    ints.push(4);
+    assert!(ints.spilled());
+
+    let _: &mut SmallVec<[f64; 1]> = m.mut_doubles();
+    let _: &mut Vec<i64> = m.mut_disabled();
+    let _: &mut ::protobuf::RepeatedField<String> = m.mut_strings();
+    let _: &mut Vec<TestRepeatedSmallvecEnum> = m.mut_enums();
+}
+
+#[test]
+fn test_accessors() {
+    let mut m = TestRepeatedSmallvec::new();
+    assert_eq!(&[] as &[bool], m.get_bools());
+    let mut bools = SmallVec::new();
+    bools.push(true);
+    m.set_bools(bools);
+    assert_eq!(&[true], m.get_bools());
+    assert_eq!(&[true][..], &m.take_bools()[..]);
+    assert!(m.get_bools().is_empty());
+
+    m.mut_ints().push(5);
+    m.clear_ints();
+    assert!(m.get_ints().is_empty());
+}
+
+#[test]
+fn test_round_trip() {
+    let mut m = TestRepeatedSmallvec::new();
+    m.mut_ints().extend(vec![1, -2, 3, 4]);
+    m.mut_fixeds().extend(vec![10, 20]);
+    m.mut_bools().push(true);
+    m.mut_doubles().extend(vec![1.5, 2.5]);
+    m.mut_disabled().push(-7);
+    m.mut_enums().push(TestRepeatedSmallvecEnum::RED);
+
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed: TestRepeatedSmallvec = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+    assert_eq!(&[10, 20], parsed.get_fixeds());
+}
+
+#[test]
+fn test_packed_and_unpacked_input() {
+    let mut m = TestRepeatedSmallvec::new();
+    m.mut_fixeds().push(1);
+    let mut packed = m.write_to_bytes().unwrap();
+    // same field written unpacked: tag 2 with wire type fixed64
+    packed.extend(&[0x11, 2, 0, 0, 0, 0, 0, 0, 0]);
+    let parsed: TestRepeatedSmallvec = ::protobuf::parse_from_bytes(&packed).unwrap();
+    assert_eq!(&[1, 2], parsed.get_fixeds());
+}
+
+#[test]
+fn test_reflect() {
+    let mut m = TestRepeatedSmallvec::new();
+    m.mut_ints().extend(vec![7, 8]);
+    let field = m.message_descriptor().field_by_name("ints");
+    assert_eq!(2, field.len_field(&m));
+}
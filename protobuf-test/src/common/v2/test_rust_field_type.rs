@@ -0,0 +1,87 @@
+use protobuf::*;
+
+use super::test_rust_field_type_pb::*;
+
+#[derive(Debug, PartialEq)]
+pub struct UserId(pub u64);
+
+impl ProtobufConvert<u64> for UserId {
+    fn from_protobuf(value: u64) -> UserId {
+        UserId(value)
+    }
+
+    fn to_protobuf(&self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Celsius(pub f64);
+
+impl ProtobufConvert<f64> for Celsius {
+    fn from_protobuf(value: f64) -> Celsius {
+        Celsius(value)
+    }
+
+    fn to_protobuf(&self) -> f64 {
+        self.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Important(pub bool);
+
+impl ProtobufConvert<TestRustFieldTypeLevel> for Important {
+    fn from_protobuf(value: TestRustFieldTypeLevel) -> Important {
+        Important(value == TestRustFieldTypeLevel::HIGH)
+    }
+
+    fn to_protobuf(&self) -> TestRustFieldTypeLevel {
+        match self.0 {
+            true => TestRustFieldTypeLevel::HIGH,
+            false => TestRustFieldTypeLevel::LOW,
+        }
+    }
+}
+
+#[test]
+fn test_get_set() {
+    let mut m = TestRustFieldType::new();
+    assert_eq!(UserId(0), m.get_user_id());
+    assert_eq!(Celsius(0.0), m.get_temperature());
+    assert_eq!(Important(false), m.get_level());
+    assert_eq!(UserId(0), m.get_owner_id());
+
+    m.set_user_id(UserId(10));
+    m.set_temperature(Celsius(-5.5));
+    m.set_level(Important(true));
+    m.set_owner_id(UserId(20));
+    assert_eq!(UserId(10), m.get_user_id());
+    assert_eq!(Celsius(-5.5), m.get_temperature());
+    assert_eq!(Important(true), m.get_level());
+    assert_eq!(UserId(20), m.get_owner_id());
+}
+
+#[test]
+fn test_serialize() {
+    let mut m = TestRustFieldType::new();
+    m.set_user_id(UserId(150));
+    m.set_level(Important(true));
+    assert_eq!(vec![0x08, 0x96, 0x01, 0x18, 0x01], m.write_to_bytes().unwrap());
+
+    let parsed = parse_from_bytes::<TestRustFieldType>(&[0x20, 0x07]).unwrap();
+    assert_eq!(UserId(7), parsed.get_owner_id());
+}
+
+#[test]
+fn test_reflect() {
+    let mut m = TestRustFieldType::new();
+    m.set_user_id(UserId(3));
+    m.set_temperature(Celsius(36.6));
+    m.set_owner_id(UserId(4));
+    let descriptor = m.message_descriptor();
+    // reflection uses field value types
+    assert_eq!(3, descriptor.field_by_name("user_id").get_u64(&m));
+    assert_eq!(36.6, descriptor.field_by_name("temperature").get_f64(&m));
+    assert_eq!(4, descriptor.field_by_name("owner_id").get_u64(&m));
+}
@@ -0,0 +1,33 @@
+use protobuf::*;
+
+use super::test_rust_name_pb::*;
+
+#[test]
+fn test_accessors() {
+    let mut m = TestRustName::new();
+    m.set_kind("a".to_owned());
+    m.set_id(10);
+    m.mut_labels().push("b".to_owned());
+    m.set_text("c".to_owned());
+    assert_eq!("a", m.get_kind());
+    assert_eq!(10, m.get_id());
+    assert_eq!(&["b".to_owned()], m.get_labels());
+    match m.value {
+        Some(TestRustName_oneof_value::text(ref text)) => assert_eq!("c", text),
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_proto_names() {
+    assert_eq!(1, TestRustName::FIELD_TYPE_NUMBER);
+
+    let mut m = TestRustName::new();
+    m.set_kind("a".to_owned());
+    m.set_text("c".to_owned());
+    // reflection and text format use names from `.proto` file
+    let descriptor = m.message_descriptor();
+    assert_eq!("a", descriptor.field_by_name("type").get_str(&m));
+    assert_eq!("c", descriptor.field_by_name("str").get_str(&m));
+    assert_eq!("type: \"a\" str: \"c\"", text_format::print_to_string(&m));
+}
@@ -0,0 +1,69 @@
+use protobuf::MessageFull;
+use protobuf::reflect::MessageDescriptor;
+use protobuf::reflect::RustprotoOptions;
+
+use super::test_rustproto_options_pb;
+use super::test_rustproto_options_pb::*;
+
+#[test]
+fn inherited() {
+    let file = test_rustproto_options_pb::file_descriptor_proto();
+    let options = RustprotoOptions::for_file(file);
+    assert_eq!(Some(false), options.expose_fields);
+    assert_eq!(Some(true), options.generate_display);
+    assert_eq!(None, options.generate_into_bytes);
+
+    let outer = MessageDescriptor::for_type::<Outer>();
+    let options = RustprotoOptions::for_message(outer);
+    assert_eq!(Some(false), options.expose_fields);
+    assert_eq!(Some(true), options.generate_display);
+    assert_eq!(Some(true), options.generate_into_bytes);
+    assert_eq!(None, options.repeated_field_vec);
+
+    let inner = Outer_Inner::type_descriptor();
+    let options = RustprotoOptions::for_message(inner);
+    assert_eq!(Some(true), options.generate_into_bytes);
+    assert_eq!(Some(true), options.repeated_field_vec);
+    assert_eq!(None, options.rust_name);
+}
+
+#[test]
+fn fields() {
+    let outer = Outer::type_descriptor();
+    let options = RustprotoOptions::for_field(outer, outer.field_by_name("inner"));
+    assert_eq!(Some(true), options.option_box_for_messages);
+    assert_eq!(Some(true), options.generate_into_bytes);
+
+    for name in &["name", "inners", "color", "one_string", "one_inner"] {
+        let options = RustprotoOptions::for_field(outer, outer.field_by_name(name));
+        assert_eq!(RustprotoOptions::for_message(outer), options, "{}", name);
+    }
+
+    let inner = Outer_Inner::type_descriptor();
+    let options = RustprotoOptions::for_field(inner, inner.field_by_name("tag"));
+    assert_eq!(Some("label".to_owned()), options.rust_name);
+    assert_eq!(Some(true), options.repeated_field_vec);
+}
+
+#[test]
+fn match_generated_code() {
+    let mut outer = Outer::new();
+    // `repeated_field_vec`
+    let values: &mut Vec<i32> = outer.mut_inner().mut_values();
+    values.push(1);
+    // `rust_name`
+    outer.mut_inner().set_label("a".to_owned());
+    // `generate_into_bytes`
+    let bytes: Vec<u8> = (&outer).into();
+    assert!(!bytes.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn message_from_other_file() {
+    let file = test_rustproto_options_pb::file_descriptor_proto().clone();
+    RustprotoOptions::for_message_proto(
+        test_rustproto_options_pb::file_descriptor_proto(),
+        &file.get_message_type()[0],
+    );
+}
@@ -0,0 +1,71 @@
+use std::thread;
+use std::sync::Arc;
+
+use protobuf::Message;
+use protobuf::SizeCache;
+
+use super::test_size_cache_pb::*;
+
+fn nested(name: &str, children: usize) -> TestSizeCacheNested {
+    let mut r = TestSizeCacheNested::new();
+    r.set_name(name.to_owned());
+    for i in 0..children {
+        r.mut_children().push(nested(&format!("{}.{}", name, i), children - 1));
+    }
+    r
+}
+
+fn sample() -> TestSizeCache {
+    let mut m = TestSizeCache::new();
+    m.set_nested(nested("a", 2));
+    m.mut_repeated_nested().push(nested("b", 1));
+    m.mut_repeated_nested().push(nested("c", 3));
+    m.mut_map_nested().insert(10, nested("d", 2));
+    m.mut_map_nested().insert(20, nested("e", 0));
+    m.set_oneof_nested(nested("f", 2));
+    m
+}
+
+#[test]
+fn test_same_bytes_as_cached_size() {
+    let m = sample();
+    let mut sizes = SizeCache::new();
+    let with_sizes = m.write_to_bytes_with_sizes(&mut sizes).unwrap();
+    assert_eq!(m.write_to_bytes().unwrap(), with_sizes);
+    assert_eq!(m.compute_size() as usize, with_sizes.len());
+}
+
+#[test]
+fn test_size_cache_reused() {
+    // same instance, because order of map entries differs between instances
+    let sample = sample();
+    let mut sizes = SizeCache::new();
+    let first = sample.write_to_bytes_with_sizes(&mut sizes).unwrap();
+
+    let mut m = TestSizeCache::new();
+    m.set_oneof_uint32(17);
+    let second = m.write_to_bytes_with_sizes(&mut sizes).unwrap();
+
+    assert_eq!(sample.write_to_bytes().unwrap(), first);
+    assert_eq!(m.write_to_bytes().unwrap(), second);
+}
+
+#[test]
+fn test_concurrent_serialization() {
+    let m = Arc::new(sample());
+    let expected = m.write_to_bytes().unwrap();
+
+    let threads: Vec<_> = (0..4)
+        .map(|_| {
+            let m_copy = m.clone();
+            thread::spawn(move || {
+                let mut sizes = SizeCache::new();
+                m_copy.write_to_bytes_with_sizes(&mut sizes).unwrap()
+            })
+        })
+        .collect();
+
+    for t in threads {
+        assert_eq!(expected, t.join().unwrap());
+    }
+}
@@ -0,0 +1,60 @@
+use protobuf::Message;
+
+use super::test_size_hint_pb::*;
+
+fn nested(name: &str) -> TestSizeHintNested {
+    let mut r = TestSizeHintNested::new();
+    r.set_name(name.to_owned());
+    r
+}
+
+fn assert_bound(m: &TestSizeHint) {
+    let size = m.compute_size() as u64;
+    let bound = m.size_hint_upper_bound();
+    assert!(bound >= size, "bound {} < size {}", bound, size);
+}
+
+#[test]
+fn test_empty() {
+    assert_eq!(0, TestSizeHint::new().size_hint_upper_bound());
+}
+
+#[test]
+fn test_singular() {
+    let mut m = TestSizeHint::new();
+    m.set_int32_field(-1);
+    m.set_sint64_field(::std::i64::MIN);
+    m.set_fixed32_field(1);
+    m.set_bool_field(true);
+    m.set_enum_field(TestSizeHintEnum::NEGATIVE);
+    // all scalars have maximum size
+    assert_eq!(m.compute_size() as u64, m.size_hint_upper_bound());
+
+    m.set_string_field("abc".to_owned());
+    m.set_bytes_field(vec![0; 200]);
+    m.set_nested(nested("n"));
+    assert_bound(&m);
+}
+
+#[test]
+fn test_repeated() {
+    let mut m = TestSizeHint::new();
+    m.set_repeated_int32(vec![1, -1, 1000]);
+    m.set_repeated_double(vec![1.0, 2.0]);
+    m.mut_repeated_string().push("a".to_owned());
+    m.mut_repeated_nested().push(nested("b"));
+    m.mut_repeated_nested().push(TestSizeHintNested::new());
+    m.set_packed_int64(vec![0, 1, ::std::i64::MAX]);
+    m.set_packed_float(vec![1.0; 40]);
+    m.mut_map_nested().insert("c".to_owned(), nested("d"));
+    assert_bound(&m);
+}
+
+#[test]
+fn test_oneof() {
+    let mut m = TestSizeHint::new();
+    m.set_oneof_uint32(::std::u32::MAX);
+    assert_eq!(m.compute_size() as u64, m.size_hint_upper_bound());
+    m.set_oneof_nested(nested("e"));
+    assert_bound(&m);
+}
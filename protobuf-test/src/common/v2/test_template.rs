@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use protobuf::Message;
+use protobuf::reflect::TemplateError;
+use protobuf::reflect::expand_placeholders;
+use protobuf::reflect::expand_placeholders_with;
+
+use super::test_template_pb::*;
+
+fn vars() -> HashMap<String, String> {
+    let mut vars = HashMap::new();
+    vars.insert("NAME".to_owned(), "a".to_owned());
+    vars.insert("DIR".to_owned(), "/tmp".to_owned());
+    vars
+}
+
+fn nested(name: &str) -> Nested {
+    let mut nested = Nested::new();
+    nested.set_name(name.to_owned());
+    nested.mut_paths().push("${DIR}/n".to_owned());
+    nested
+}
+
+fn template() -> TestTemplate {
+    let mut m = TestTemplate::new();
+    m.set_name("${NAME}.conf".to_owned());
+    m.set_data(b"${NAME}".to_vec());
+    m.set_color(Color::RED);
+    m.set_nested(nested("${NAME}"));
+    m.mut_paths().push("${DIR}/${NAME}".to_owned());
+    m.mut_paths().push("b".to_owned());
+    m.mut_nested_list().push(nested("c"));
+    m.mut_env().insert("${NAME}".to_owned(), "${DIR}".to_owned());
+    m.set_one_nested(nested("${NAME}"));
+    m.mut_unknown_fields().add_varint(1000, 1);
+    m
+}
+
+#[test]
+fn expand() {
+    let mut m = template();
+    expand_placeholders(&mut m, &vars(), true).unwrap();
+    assert_eq!("a.conf", m.get_name());
+    // bytes fields and map keys are not expanded
+    assert_eq!(b"${NAME}", m.get_data());
+    assert_eq!("/tmp", m.get_env()["${NAME}"]);
+    assert_eq!(Color::RED, m.get_color());
+    assert_eq!(&["/tmp/a".to_owned(), "b".to_owned()], m.get_paths());
+    assert_eq!("a", m.get_nested().get_name());
+    assert_eq!(&["/tmp/n".to_owned()], m.get_nested().get_paths());
+    assert_eq!(&["/tmp/n".to_owned()], m.get_nested_list()[0].get_paths());
+    assert_eq!("a", m.get_one_nested().get_name());
+    assert!(m.get_unknown_fields().get(1000).is_some());
+}
+
+#[test]
+fn not_nested() {
+    let mut m = template();
+    expand_placeholders(&mut m, &vars(), false).unwrap();
+    assert_eq!("a.conf", m.get_name());
+    assert_eq!("${NAME}", m.get_nested().get_name());
+    assert_eq!(&["${DIR}/n".to_owned()], m.get_nested_list()[0].get_paths());
+    assert_eq!("${NAME}", m.get_one_nested().get_name());
+}
+
+#[test]
+fn oneof_string() {
+    let mut m = TestTemplate::new();
+    m.set_one_string("${NAME}".to_owned());
+    expand_placeholders_with(&mut m, |name| Some(name.to_lowercase()), false).unwrap();
+    assert_eq!("name", m.get_one_string());
+}
+
+#[test]
+fn unchanged() {
+    let mut m = template();
+    m.clear_name();
+    let expected = m.clone();
+    expand_placeholders_with(&mut m, |_| None, false).unwrap_err();
+    assert_eq!(expected, m);
+
+    let mut m = TestTemplate::new();
+    m.set_name("a".to_owned());
+    let expected = m.clone();
+    expand_placeholders(&mut m, &vars(), true).unwrap();
+    assert_eq!(expected, m);
+}
+
+#[test]
+fn errors() {
+    let mut m = template();
+    m.mut_nested_list()[0].mut_paths().push("${GO}".to_owned());
+    let e = expand_placeholders(&mut m, &vars(), true).unwrap_err();
+    assert_eq!("undefined variable GO in field nested_list.paths", e.to_string());
+    // message is not modified
+    assert_eq!("${NAME}.conf", m.get_name());
+
+    m.set_name("${NAME".to_owned());
+    let e = expand_placeholders(&mut m, &vars(), true).unwrap_err();
+    assert_eq!(TemplateError::UnclosedPlaceholder { field: "name".to_owned() }, e);
+}
@@ -0,0 +1,150 @@
+use super::test_visitor_pb::*;
+
+// trims all strings
+struct Trim;
+
+impl TestVisitorPersonVisitor for Trim {
+    fn visit_name(&mut self, value: &mut String) {
+        *value = value.trim().to_owned();
+    }
+
+    fn visit_email(&mut self, value: &mut String) {
+        *value = value.trim().to_owned();
+    }
+}
+
+impl TestVisitorAddressVisitor for Trim {
+    fn visit_city(&mut self, value: &mut String) {
+        *value = value.trim().to_owned();
+    }
+
+    fn visit_lines(&mut self, value: &mut String) {
+        *value = value.trim().to_owned();
+    }
+}
+
+// records visited fields
+#[derive(Default)]
+struct Log {
+    visited: Vec<String>,
+}
+
+impl TestVisitorPersonVisitor for Log {
+    fn visit_name(&mut self, value: &mut String) {
+        self.visited.push(format!("name {}", value));
+    }
+
+    fn visit_age(&mut self, value: &mut i32) {
+        self.visited.push(format!("age {}", value));
+    }
+
+    fn visit_address(&mut self, _value: &mut TestVisitorAddress) {
+        self.visited.push("address".to_owned());
+    }
+
+    fn visit_friends(&mut self, _value: &mut TestVisitorPerson) {
+        self.visited.push("friend".to_owned());
+    }
+
+    fn visit_places(&mut self, key: &String, _value: &mut TestVisitorAddress) {
+        self.visited.push(format!("place {}", key));
+    }
+
+    fn visit_office(&mut self, _value: &mut TestVisitorAddress) {
+        self.visited.push("office".to_owned());
+    }
+}
+
+impl TestVisitorAddressVisitor for Log {
+    fn visit_city(&mut self, value: &mut String) {
+        self.visited.push(format!("city {}", value));
+    }
+
+    fn visit_name(&mut self, value: &mut String) {
+        self.visited.push(format!("address name {}", value));
+    }
+}
+
+fn address(city: &str) -> TestVisitorAddress {
+    let mut address = TestVisitorAddress::new();
+    address.set_city(city.to_owned());
+    address
+}
+
+#[test]
+fn test_only_populated_fields_visited() {
+    let mut person = TestVisitorPerson::new();
+    person.set_age(30);
+
+    let mut log = Log::default();
+    person.accept(&mut log);
+    assert_eq!(vec!["age 30".to_owned()], log.visited);
+}
+
+#[test]
+fn test_nested() {
+    let mut friend = TestVisitorPerson::new();
+    friend.set_name("bob".to_owned());
+    friend.set_office(address("paris"));
+
+    let mut person = TestVisitorPerson::new();
+    person.set_name("alice".to_owned());
+    person.set_address(address("london"));
+    person.mut_friends().push(friend);
+    person.mut_places().insert("home".to_owned(), address("oxford"));
+
+    let mut log = Log::default();
+    person.accept(&mut log);
+    assert_eq!(
+        vec![
+            "name alice",
+            "address",
+            "city london",
+            "friend",
+            "name bob",
+            "office",
+            "city paris",
+            "place home",
+            "city oxford",
+        ],
+        log.visited
+    );
+}
+
+#[test]
+fn test_modify() {
+    let mut friend = TestVisitorPerson::new();
+    friend.set_email(" bob@example.com ".to_owned());
+
+    let mut home = address(" oxford ");
+    home.mut_lines().push(" 1 High St ".to_owned());
+
+    let mut person = TestVisitorPerson::new();
+    person.set_name(" alice ".to_owned());
+    person.mut_friends().push(friend);
+    person.mut_places().insert("home".to_owned(), home);
+
+    person.accept(&mut Trim);
+    assert_eq!("alice", person.get_name());
+    assert_eq!("bob@example.com", person.get_friends()[0].get_email());
+    let home = &person.get_places()["home"];
+    assert_eq!("oxford", home.get_city());
+    assert_eq!(&["1 High St".to_owned()], home.get_lines());
+}
+
+#[test]
+fn test_same_field_name() {
+    let mut home = address("oxford");
+    home.set_name("home".to_owned());
+
+    let mut person = TestVisitorPerson::new();
+    person.set_name("alice".to_owned());
+    person.set_address(home);
+
+    let mut log = Log::default();
+    person.accept(&mut log);
+    assert_eq!(
+        vec!["name alice", "address", "city oxford", "address name home"],
+        log.visited
+    );
+}
@@ -0,0 +1,122 @@
+use protobuf::Message;
+use protobuf::UnknownValueRef;
+use protobuf::parse_from_bytes;
+use protobuf::wire_editor::WireEditor;
+use protobuf::wire_editor::WireValue;
+
+use super::test_wire_editor_pb::*;
+
+fn nested(name: &str) -> Nested {
+    let mut nested = Nested::new();
+    nested.set_name(name.to_owned());
+    nested
+}
+
+fn message() -> TestWireEditor {
+    let mut m = TestWireEditor::new();
+    m.set_name("a".to_owned());
+    m.set_nested(nested("b"));
+    m.mut_deps().push("c".to_owned());
+    m.mut_deps().push("d".to_owned());
+    m.mut_counts().insert("e".to_owned(), 1);
+    m.set_color(Color::GREEN);
+    m.set_one_int(2);
+    m
+}
+
+#[test]
+fn scan() {
+    let bytes = message().write_to_bytes().unwrap();
+    let editor = WireEditor::new(&bytes).unwrap();
+    let numbers: Vec<u32> = editor.fields().iter().map(|f| f.number).collect();
+    assert_eq!(vec![1, 2, 3, 3, 4, 5, 7], numbers);
+    assert_eq!(WireValue::LengthDelimited(b"d"), editor.get(3).unwrap().value);
+    assert_eq!(
+        WireValue::LengthDelimited(&nested("b").write_to_bytes().unwrap()),
+        editor.get(2).unwrap().value
+    );
+    assert_eq!(WireValue::Varint(2), editor.get(5).unwrap().value);
+    assert_eq!(WireValue::Varint(2), editor.get(7).unwrap().value);
+    assert!(editor.get(6).is_none());
+    assert_eq!(bytes, editor.to_bytes());
+
+    assert!(WireEditor::new(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn edit() {
+    let bytes = message().write_to_bytes().unwrap();
+    let nested_bytes = nested("x").write_to_bytes().unwrap();
+    let mut editor = WireEditor::new(&bytes).unwrap();
+    editor.replace(1, UnknownValueRef::LengthDelimited(b"f"));
+    editor.replace(2, UnknownValueRef::LengthDelimited(&nested_bytes));
+    editor.replace(3, UnknownValueRef::LengthDelimited(b"g"));
+    editor.remove(5);
+    editor.add(3, UnknownValueRef::LengthDelimited(b"h"));
+    // other field of oneof overrides the original one
+    editor.add(6, UnknownValueRef::LengthDelimited(b"i"));
+
+    let mut expected = message();
+    expected.set_name("f".to_owned());
+    expected.set_nested(nested("x"));
+    expected.set_deps(vec!["g".to_owned(), "h".to_owned()].into());
+    expected.clear_color();
+    expected.set_one_string("i".to_owned());
+
+    let edited: TestWireEditor = parse_from_bytes(&editor.to_bytes()).unwrap();
+    assert_eq!(expected, edited);
+
+    let mut written = Vec::new();
+    editor.write_to_writer(&mut written).unwrap();
+    assert_eq!(editor.to_bytes(), written);
+}
+
+#[cfg(feature = "with-groups")]
+#[test]
+fn groups() {
+    use protobuf::UnknownFields;
+
+    let mut group = UnknownFields::new();
+    group.add_varint(1, 5);
+    let mut nested = UnknownFields::new();
+    nested.add_fixed32(3, 7);
+    group.add_group(2, nested);
+    let mut m = message();
+    m.mut_unknown_fields().add_group(100, group);
+    m.mut_unknown_fields().add_varint(101, 1);
+    let bytes = m.write_to_bytes().unwrap();
+
+    let editor = WireEditor::new(&bytes).unwrap();
+    // start and end tags of field 100 are two bytes each
+    let group = editor.get(100).unwrap();
+    let body = match group.value {
+        WireValue::Group(body) => body,
+        value => panic!("{:?}", value),
+    };
+    assert_eq!(body.len() + 4, group.data.len());
+    let mut numbers: Vec<u32> =
+        WireEditor::new(body).unwrap().fields().iter().map(|f| f.number).collect();
+    // unknown fields are not ordered
+    numbers.sort();
+    assert_eq!(vec![1, 2], numbers);
+    assert_eq!(WireValue::Varint(1), editor.get(101).unwrap().value);
+    assert_eq!(bytes, editor.to_bytes());
+
+    let mut editor = WireEditor::new(&bytes).unwrap();
+    editor.remove(100);
+    editor.remove(101);
+    assert_eq!(message().write_to_bytes().unwrap(), editor.to_bytes());
+}
+
+#[test]
+fn replace_missing_field() {
+    let mut editor = WireEditor::new(&[]).unwrap();
+    editor.add(3, UnknownValueRef::LengthDelimited(b"a"));
+    editor.add(3, UnknownValueRef::LengthDelimited(b"b"));
+    editor.replace(1, UnknownValueRef::LengthDelimited(b"c"));
+    editor.replace(3, UnknownValueRef::LengthDelimited(b"d"));
+    let m: TestWireEditor = parse_from_bytes(&editor.to_bytes()).unwrap();
+    assert_eq!("c", m.get_name());
+    assert_eq!(&["d".to_owned()], m.get_deps());
+    assert_eq!(m.write_to_bytes().unwrap(), editor.to_bytes());
+}
@@ -0,0 +1,146 @@
+use protobuf::error::WireError;
+use protobuf::field_order::FieldOrder;
+use protobuf::parse_from_bytes;
+use protobuf::parse_partial_from_bytes;
+use protobuf::wire_editor::WireEditor;
+use protobuf::CodedInputStream;
+use protobuf::Message;
+use protobuf::ProtobufError;
+use protobuf::ProtobufResult;
+use protobuf::UnknownValueRef;
+
+use super::test_wire_types_pb::*;
+
+fn wire_error<T>(r: ProtobufResult<T>) -> Option<String> {
+    match r {
+        Ok(..) => None,
+        Err(ref e @ ProtobufError::WireError(..)) => Some(format!("{:?}", e.wire_error().unwrap())),
+        Err(e) => panic!("{:?}", e),
+    }
+}
+
+fn read_events(bytes: &[u8]) -> ProtobufResult<()> {
+    let mut is = CodedInputStream::from_bytes(bytes);
+    while let Some(..) = is.read_event()? {}
+    Ok(())
+}
+
+// results of parsing functions which support groups
+fn parse_with_groups(bytes: &[u8]) -> Vec<(&'static str, Option<String>)> {
+    let mut nested = vec![0x1a, bytes.len() as u8];
+    nested.extend_from_slice(bytes);
+    vec![
+        ("generated", wire_error(parse_from_bytes::<TestWireTypes>(bytes))),
+        ("generated nested", wire_error(parse_from_bytes::<TestWireTypes>(&nested))),
+        ("partial", wire_error(parse_partial_from_bytes::<TestWireTypes>(bytes, &[1, 2, 3]))),
+        ("partial skipped", wire_error(parse_partial_from_bytes::<TestWireTypes>(bytes, &[]))),
+        (
+            "unknown fields",
+            wire_error(UnknownValueRef::LengthDelimited(bytes).parse_unknown_fields()),
+        ),
+    ]
+}
+
+// results of all parsing functions
+fn parse_all(bytes: &[u8]) -> Vec<(&'static str, Option<String>)> {
+    let mut r = parse_with_groups(bytes);
+    r.push(("events", wire_error(read_events(bytes))));
+    r.push(("wire editor", wire_error(WireEditor::new(bytes))));
+    r.push(("field order", wire_error(FieldOrder::scan(bytes))));
+    r
+}
+
+fn assert_all(expected: Option<&str>, results: Vec<(&'static str, Option<String>)>) {
+    for (name, result) in results {
+        assert_eq!(expected.map(|e| e.to_owned()), result, "{}", name);
+    }
+}
+
+#[test]
+fn test_valid_wire_types() {
+    // varint, fixed64, length-delimited and fixed32 fields, known and unknown
+    let bytes = [
+        0x08, 0x96, 0x01, 0x12, 0x01, b'a', 0x1a, 0x02, 0x08, 0x01, 0x21, 1, 2, 3, 4, 5, 6, 7, 8,
+        0x2d, 1, 2, 3, 4, 0xa0, 0x06, 0x01,
+    ];
+    assert_all(None, parse_all(&bytes));
+}
+
+#[test]
+fn test_reserved_wire_types() {
+    // fields 1, 2 (known), 15 (unknown) and 1000 (unknown) with wire types 6 and 7
+    for &(ref tag, value) in &[
+        (vec![0x0e], 14),
+        (vec![0x0f], 15),
+        (vec![0x16], 22),
+        (vec![0x7e], 126),
+        (vec![0xc7, 0x3e], 8007),
+    ] {
+        let expected = format!("{:?}", WireError::IncorrectTag(value));
+        for payload in &[&[][..], &[0x00][..], &[0x01, 0x00][..]] {
+            let mut bytes = tag.clone();
+            bytes.extend_from_slice(payload);
+            assert_all(Some(&expected), parse_all(&bytes));
+            // after valid field
+            let mut after = vec![0x08, 0x01];
+            after.extend_from_slice(&bytes);
+            assert_all(Some(&expected), parse_all(&after));
+            // inside unknown group
+            let mut group = vec![0xa3, 0x06];
+            group.extend_from_slice(&bytes);
+            group.extend_from_slice(&[0xa4, 0x06]);
+            assert_all(Some(&expected), parse_with_groups(&group));
+        }
+    }
+}
+
+// unknown group 100 with nested group 101, then field 1
+const GROUP: [u8; 12] = [0xa3, 0x06, 0xab, 0x06, 0x08, 0x01, 0xac, 0x06, 0xa4, 0x06, 0x08, 0x01];
+
+#[test]
+fn test_groups() {
+    assert_all(None, parse_with_groups(&GROUP));
+    let m: TestWireTypes = parse_from_bytes(&GROUP).unwrap();
+    assert_eq!(1, m.get_i());
+
+    let unexpected_end_group = Some("UnexpectedWireType(WireTypeEndGroup)");
+    // end group without start group
+    assert_all(unexpected_end_group, parse_with_groups(&[0xa4, 0x06]));
+    // end group of other field
+    assert_all(unexpected_end_group, parse_with_groups(&[0xa3, 0x06, 0xac, 0x06]));
+    // nested group closed by end group of outer group
+    let bytes = [0xa3, 0x06, 0xab, 0x06, 0xa4, 0x06];
+    assert_all(unexpected_end_group, parse_with_groups(&bytes));
+    // unterminated group
+    assert_all(Some("UnexpectedEof"), parse_with_groups(&[0xa3, 0x06, 0x08, 0x01]));
+}
+
+#[cfg(not(feature = "with-groups"))]
+#[test]
+fn test_groups_skipped() {
+    let m: TestWireTypes = parse_from_bytes(&GROUP).unwrap();
+    assert_eq!(vec![0x08, 0x01], m.write_to_bytes().unwrap());
+}
+
+#[cfg(feature = "with-groups")]
+#[test]
+fn test_groups_preserved() {
+    let group = GROUP;
+    let m: TestWireTypes = parse_from_bytes(&group).unwrap();
+    assert_eq!("100: group {101: group {1: varint 1}}", m.get_unknown_fields().debug_dump());
+    // known fields are written first
+    let mut expected = vec![0x08, 0x01];
+    expected.extend_from_slice(&group[..10]);
+    assert_eq!(expected, m.write_to_bytes().unwrap());
+    // nested message with group
+    let mut nested = vec![0x1a, group.len() as u8];
+    nested.extend_from_slice(&group);
+    let m: TestWireTypes = parse_from_bytes(&nested).unwrap();
+    assert_eq!(
+        "100: group {101: group {1: varint 1}}",
+        m.get_nested().get_unknown_fields().debug_dump()
+    );
+    let mut expected = vec![0x1a, 12, 0x08, 0x01];
+    expected.extend_from_slice(&group[..10]);
+    assert_eq!(expected, m.write_to_bytes().unwrap());
+}
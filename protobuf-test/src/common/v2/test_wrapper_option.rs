@@ -0,0 +1,30 @@
+use super::test_wrapper_option_pb::*;
+
+#[test]
+fn test_get_opt() {
+    let mut m = TestWrapperOption::new();
+    assert_eq!(None, m.get_count_opt());
+    assert_eq!(None, m.get_label_opt());
+
+    m.mut_count().set_value(0);
+    // zero value is distinct from unset field
+    assert_eq!(Some(0), m.get_count_opt());
+    m.mut_label().set_value("abc".to_owned());
+    assert_eq!(Some("abc".to_owned()), m.get_label_opt());
+}
+
+#[test]
+fn test_set_opt() {
+    let mut m = TestWrapperOption::new();
+    m.set_enabled_opt(Some(false));
+    assert!(m.has_enabled());
+    assert_eq!(false, m.get_enabled().get_value());
+    assert_eq!(Some(false), m.get_enabled_opt());
+
+    m.set_payload_opt(Some(vec![1, 2]));
+    assert_eq!(Some(vec![1, 2]), m.get_payload_opt());
+
+    m.set_enabled_opt(None);
+    assert!(!m.has_enabled());
+    assert_eq!(None, m.get_enabled_opt());
+}
@@ -0,0 +1,1014 @@
+// This file is generated by rust-protobuf 1.6.0. Do not edit
+// @generated
+
+// https://github.com/Manishearth/rust-clippy/issues/702
+#![allow(unknown_lints)]
+#![allow(clippy)]
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+#![allow(box_pointers)]
+#![allow(dead_code)]
+#![allow(missing_docs)]
+#![allow(non_camel_case_types)]
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+#![allow(trivial_casts)]
+#![allow(unsafe_code)]
+#![allow(unused_imports)]
+#![allow(unused_results)]
+
+use protobuf::Message as Message_imported_for_functions;
+use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CompatNested {
+    // message fields
+    value: ::std::option::Option<i32>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+impl CompatNested {
+    pub fn new() -> CompatNested {
+        ::std::default::Default::default()
+    }
+
+    // optional int32 value = 1;
+
+    pub fn clear_value(&mut self) {
+        self.value = ::std::option::Option::None;
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: i32) {
+        self.value = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_value(&self) -> i32 {
+        self.value.unwrap_or(0)
+    }
+}
+
+impl ::protobuf::Message for CompatNested {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.value = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.value {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.value {
+            os.write_int32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::Message::descriptor_static(None::<Self>)
+    }
+
+    fn new() -> CompatNested {
+        CompatNested::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CompatNested>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "value",
+                    |m: &CompatNested| { &m.value },
+                    |m: &mut CompatNested| { &mut m.value },
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CompatNested>(
+                    "CompatNested",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CompatNested {
+        static mut instance: ::protobuf::lazy::Lazy<CompatNested> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CompatNested,
+        };
+        unsafe {
+            instance.get(CompatNested::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CompatNested {
+    fn clear(&mut self) {
+        self.clear_value();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompatNested {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompatNested {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CompatMessage {
+    // message fields
+    id: ::std::option::Option<i32>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    data: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    color: ::std::option::Option<CompatEnum>,
+    nested: ::protobuf::SingularPtrField<CompatNested>,
+    numbers: ::std::vec::Vec<i64>,
+    nested_list: ::protobuf::RepeatedField<CompatNested>,
+    pub nested_map: ::std::collections::HashMap<::std::string::String, CompatNested>,
+    // message oneof groups
+    pub choice: ::std::option::Option<CompatMessage_oneof_choice>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+#[derive(Clone,PartialEq)]
+pub enum CompatMessage_oneof_choice {
+    choice_int(u32),
+    choice_string(::std::string::String),
+    choice_nested(CompatNested),
+}
+
+impl CompatMessage {
+    pub fn new() -> CompatMessage {
+        ::std::default::Default::default()
+    }
+
+    // required int32 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: i32) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> i32 {
+        self.id.unwrap_or(0)
+    }
+
+    // optional string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "unnamed",
+        }
+    }
+
+    // optional bytes data = 3;
+
+    pub fn clear_data(&mut self) {
+        self.data.clear();
+    }
+
+    pub fn has_data(&self) -> bool {
+        self.data.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_data(&mut self, v: ::std::vec::Vec<u8>) {
+        self.data = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_data(&mut self) -> &mut ::std::vec::Vec<u8> {
+        if self.data.is_none() {
+            self.data.set_default();
+        }
+        self.data.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_data(&mut self) -> ::std::vec::Vec<u8> {
+        self.data.take().unwrap_or_else(|| ::std::vec::Vec::new())
+    }
+
+    pub fn get_data(&self) -> &[u8] {
+        match self.data.as_ref() {
+            Some(v) => &v,
+            None => &[],
+        }
+    }
+
+    // optional .compat_1_6.CompatEnum color = 4;
+
+    pub fn clear_color(&mut self) {
+        self.color = ::std::option::Option::None;
+    }
+
+    pub fn has_color(&self) -> bool {
+        self.color.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_color(&mut self, v: CompatEnum) {
+        self.color = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_color(&self) -> CompatEnum {
+        self.color.unwrap_or(CompatEnum::UNKNOWN)
+    }
+
+    // optional .compat_1_6.CompatNested nested = 5;
+
+    pub fn clear_nested(&mut self) {
+        self.nested.clear();
+    }
+
+    pub fn has_nested(&self) -> bool {
+        self.nested.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nested(&mut self, v: CompatNested) {
+        self.nested = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nested(&mut self) -> &mut CompatNested {
+        if self.nested.is_none() {
+            self.nested.set_default();
+        }
+        self.nested.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_nested(&mut self) -> CompatNested {
+        self.nested.take().unwrap_or_else(|| CompatNested::new())
+    }
+
+    pub fn get_nested(&self) -> &CompatNested {
+        self.nested.as_ref().unwrap_or_else(|| CompatNested::default_instance())
+    }
+
+    // repeated int64 numbers = 6;
+
+    pub fn clear_numbers(&mut self) {
+        self.numbers.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_numbers(&mut self, v: ::std::vec::Vec<i64>) {
+        self.numbers = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_numbers(&mut self) -> &mut ::std::vec::Vec<i64> {
+        &mut self.numbers
+    }
+
+    // Take field
+    pub fn take_numbers(&mut self) -> ::std::vec::Vec<i64> {
+        ::std::mem::replace(&mut self.numbers, ::std::vec::Vec::new())
+    }
+
+    pub fn get_numbers(&self) -> &[i64] {
+        &self.numbers
+    }
+
+    // repeated .compat_1_6.CompatNested nested_list = 7;
+
+    pub fn clear_nested_list(&mut self) {
+        self.nested_list.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nested_list(&mut self, v: ::protobuf::RepeatedField<CompatNested>) {
+        self.nested_list = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nested_list(&mut self) -> &mut ::protobuf::RepeatedField<CompatNested> {
+        &mut self.nested_list
+    }
+
+    // Take field
+    pub fn take_nested_list(&mut self) -> ::protobuf::RepeatedField<CompatNested> {
+        ::std::mem::replace(&mut self.nested_list, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_nested_list(&self) -> &[CompatNested] {
+        &self.nested_list
+    }
+
+    // repeated .compat_1_6.CompatMessage.NestedMapEntry nested_map = 8;
+
+    pub fn clear_nested_map(&mut self) {
+        self.nested_map.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nested_map(&mut self, v: ::std::collections::HashMap<::std::string::String, CompatNested>) {
+        self.nested_map = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_nested_map(&mut self) -> &mut ::std::collections::HashMap<::std::string::String, CompatNested> {
+        &mut self.nested_map
+    }
+
+    // Take field
+    pub fn take_nested_map(&mut self) -> ::std::collections::HashMap<::std::string::String, CompatNested> {
+        ::std::mem::replace(&mut self.nested_map, ::std::collections::HashMap::new())
+    }
+
+    pub fn get_nested_map(&self) -> &::std::collections::HashMap<::std::string::String, CompatNested> {
+        &self.nested_map
+    }
+
+    // optional uint32 choice_int = 10;
+
+    pub fn clear_choice_int(&mut self) {
+        self.choice = ::std::option::Option::None;
+    }
+
+    pub fn has_choice_int(&self) -> bool {
+        match self.choice {
+            ::std::option::Option::Some(CompatMessage_oneof_choice::choice_int(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_choice_int(&mut self, v: u32) {
+        self.choice = ::std::option::Option::Some(CompatMessage_oneof_choice::choice_int(v))
+    }
+
+    pub fn get_choice_int(&self) -> u32 {
+        match self.choice {
+            ::std::option::Option::Some(CompatMessage_oneof_choice::choice_int(v)) => v,
+            _ => 0,
+        }
+    }
+
+    // optional string choice_string = 11;
+
+    pub fn clear_choice_string(&mut self) {
+        self.choice = ::std::option::Option::None;
+    }
+
+    pub fn has_choice_string(&self) -> bool {
+        match self.choice {
+            ::std::option::Option::Some(CompatMessage_oneof_choice::choice_string(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_choice_string(&mut self, v: ::std::string::String) {
+        self.choice = ::std::option::Option::Some(CompatMessage_oneof_choice::choice_string(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_choice_string(&mut self) -> &mut ::std::string::String {
+        if let ::std::option::Option::Some(CompatMessage_oneof_choice::choice_string(_)) = self.choice {
+        } else {
+            self.choice = ::std::option::Option::Some(CompatMessage_oneof_choice::choice_string(::std::string::String::new()));
+        }
+        match self.choice {
+            ::std::option::Option::Some(CompatMessage_oneof_choice::choice_string(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_choice_string(&mut self) -> ::std::string::String {
+        if self.has_choice_string() {
+            match self.choice.take() {
+                ::std::option::Option::Some(CompatMessage_oneof_choice::choice_string(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            ::std::string::String::new()
+        }
+    }
+
+    pub fn get_choice_string(&self) -> &str {
+        match self.choice {
+            ::std::option::Option::Some(CompatMessage_oneof_choice::choice_string(ref v)) => v,
+            _ => "",
+        }
+    }
+
+    // optional .compat_1_6.CompatNested choice_nested = 12;
+
+    pub fn clear_choice_nested(&mut self) {
+        self.choice = ::std::option::Option::None;
+    }
+
+    pub fn has_choice_nested(&self) -> bool {
+        match self.choice {
+            ::std::option::Option::Some(CompatMessage_oneof_choice::choice_nested(..)) => true,
+            _ => false,
+        }
+    }
+
+    // Param is passed by value, moved
+    pub fn set_choice_nested(&mut self, v: CompatNested) {
+        self.choice = ::std::option::Option::Some(CompatMessage_oneof_choice::choice_nested(v))
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_choice_nested(&mut self) -> &mut CompatNested {
+        if let ::std::option::Option::Some(CompatMessage_oneof_choice::choice_nested(_)) = self.choice {
+        } else {
+            self.choice = ::std::option::Option::Some(CompatMessage_oneof_choice::choice_nested(CompatNested::new()));
+        }
+        match self.choice {
+            ::std::option::Option::Some(CompatMessage_oneof_choice::choice_nested(ref mut v)) => v,
+            _ => panic!(),
+        }
+    }
+
+    // Take field
+    pub fn take_choice_nested(&mut self) -> CompatNested {
+        if self.has_choice_nested() {
+            match self.choice.take() {
+                ::std::option::Option::Some(CompatMessage_oneof_choice::choice_nested(v)) => v,
+                _ => panic!(),
+            }
+        } else {
+            CompatNested::new()
+        }
+    }
+
+    pub fn get_choice_nested(&self) -> &CompatNested {
+        match self.choice {
+            ::std::option::Option::Some(CompatMessage_oneof_choice::choice_nested(ref v)) => v,
+            _ => CompatNested::default_instance(),
+        }
+    }
+}
+
+impl ::protobuf::Message for CompatMessage {
+    fn is_initialized(&self) -> bool {
+        if self.id.is_none() {
+            return false;
+        }
+        for v in &self.nested {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.nested_list {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        if let Some(CompatMessage_oneof_choice::choice_nested(ref v)) = self.choice {
+            if !v.is_initialized() {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int32()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.data)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto2_enum_with_unknown_fields_into(wire_type, is, &mut self.color, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.nested)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_int64_into(wire_type, is, &mut self.numbers)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.nested_list)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_map_into::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<CompatNested>>(wire_type, is, &mut self.nested_map)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.choice = ::std::option::Option::Some(CompatMessage_oneof_choice::choice_int(is.read_uint32()?));
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.choice = ::std::option::Option::Some(CompatMessage_oneof_choice::choice_string(is.read_string()?));
+                },
+                12 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeLengthDelimited {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    self.choice = ::std::option::Option::Some(CompatMessage_oneof_choice::choice_nested(is.read_message()?));
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.data.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(3, &v);
+        }
+        if let Some(v) = self.color {
+            my_size += ::protobuf::rt::enum_size(4, v);
+        }
+        if let Some(ref v) = self.nested.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.numbers {
+            my_size += ::protobuf::rt::value_size(6, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.nested_list {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<CompatNested>>(8, &self.nested_map);
+        if let ::std::option::Option::Some(ref v) = self.choice {
+            match v {
+                &CompatMessage_oneof_choice::choice_int(v) => {
+                    my_size += ::protobuf::rt::value_size(10, v, ::protobuf::wire_format::WireTypeVarint);
+                },
+                &CompatMessage_oneof_choice::choice_string(ref v) => {
+                    my_size += ::protobuf::rt::string_size(11, &v);
+                },
+                &CompatMessage_oneof_choice::choice_nested(ref v) => {
+                    let len = v.compute_size();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_int32(1, v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.data.as_ref() {
+            os.write_bytes(3, &v)?;
+        }
+        if let Some(v) = self.color {
+            os.write_enum(4, v.value())?;
+        }
+        if let Some(ref v) = self.nested.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.numbers {
+            os.write_int64(6, *v)?;
+        };
+        for v in &self.nested_list {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        ::protobuf::rt::write_map_with_cached_sizes::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<CompatNested>>(8, &self.nested_map, os)?;
+        if let ::std::option::Option::Some(ref v) = self.choice {
+            match v {
+                &CompatMessage_oneof_choice::choice_int(v) => {
+                    os.write_uint32(10, v)?;
+                },
+                &CompatMessage_oneof_choice::choice_string(ref v) => {
+                    os.write_string(11, v)?;
+                },
+                &CompatMessage_oneof_choice::choice_nested(ref v) => {
+                    os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(v.get_cached_size())?;
+                    v.write_to_with_cached_sizes(os)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::Message::descriptor_static(None::<Self>)
+    }
+
+    fn new() -> CompatMessage {
+        CompatMessage::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CompatMessage>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                    "id",
+                    |m: &CompatMessage| { &m.id },
+                    |m: &mut CompatMessage| { &mut m.id },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    |m: &CompatMessage| { &m.name },
+                    |m: &mut CompatMessage| { &mut m.name },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                    "data",
+                    |m: &CompatMessage| { &m.data },
+                    |m: &mut CompatMessage| { &mut m.data },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CompatEnum>>(
+                    "color",
+                    |m: &CompatMessage| { &m.color },
+                    |m: &mut CompatMessage| { &mut m.color },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CompatNested>>(
+                    "nested",
+                    |m: &CompatMessage| { &m.nested },
+                    |m: &mut CompatMessage| { &mut m.nested },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                    "numbers",
+                    |m: &CompatMessage| { &m.numbers },
+                    |m: &mut CompatMessage| { &mut m.numbers },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CompatNested>>(
+                    "nested_list",
+                    |m: &CompatMessage| { &m.nested_list },
+                    |m: &mut CompatMessage| { &mut m.nested_list },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<CompatNested>>(
+                    "nested_map",
+                    |m: &CompatMessage| { &m.nested_map },
+                    |m: &mut CompatMessage| { &mut m.nested_map },
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_u32_accessor::<_>(
+                    "choice_int",
+                    CompatMessage::has_choice_int,
+                    CompatMessage::get_choice_int,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                    "choice_string",
+                    CompatMessage::has_choice_string,
+                    CompatMessage::get_choice_string,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, CompatNested>(
+                    "choice_nested",
+                    CompatMessage::has_choice_nested,
+                    CompatMessage::get_choice_nested,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<CompatMessage>(
+                    "CompatMessage",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+
+    fn default_instance() -> &'static CompatMessage {
+        static mut instance: ::protobuf::lazy::Lazy<CompatMessage> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const CompatMessage,
+        };
+        unsafe {
+            instance.get(CompatMessage::new)
+        }
+    }
+}
+
+impl ::protobuf::Clear for CompatMessage {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_name();
+        self.clear_data();
+        self.clear_color();
+        self.clear_nested();
+        self.clear_numbers();
+        self.clear_nested_list();
+        self.clear_nested_map();
+        self.clear_choice_int();
+        self.clear_choice_string();
+        self.clear_choice_nested();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CompatMessage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompatMessage {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CompatEnum {
+    UNKNOWN = 0,
+    RED = 1,
+    GREEN = 2,
+}
+
+impl ::protobuf::ProtobufEnum for CompatEnum {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CompatEnum> {
+        match value {
+            0 => ::std::option::Option::Some(CompatEnum::UNKNOWN),
+            1 => ::std::option::Option::Some(CompatEnum::RED),
+            2 => ::std::option::Option::Some(CompatEnum::GREEN),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CompatEnum] = &[
+            CompatEnum::UNKNOWN,
+            CompatEnum::RED,
+            CompatEnum::GREEN,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<CompatEnum>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("CompatEnum", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for CompatEnum {
+}
+
+impl ::protobuf::reflect::ProtobufValue for CompatEnum {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+static file_descriptor_proto_data: &'static [u8] = b"\
+    \n\x10compat_1_6.proto\x12\ncompat_1_6\"$\n\x0cCompatNested\x12\x14\n\
+    \x05value\x18\x01\x20\x01(\x05R\x05value\"\xb9\x04\n\rCompatMessage\x12\
+    \x0e\n\x02id\x18\x01\x20\x02(\x05R\x02id\x12\x1b\n\x04name\x18\x02\x20\
+    \x01(\t:\x07unnamedR\x04name\x12\x12\n\x04data\x18\x03\x20\x01(\x0cR\x04\
+    data\x12,\n\x05color\x18\x04\x20\x01(\x0e2\x16.compat_1_6.CompatEnumR\
+    \x05color\x120\n\x06nested\x18\x05\x20\x01(\x0b2\x18.compat_1_6.CompatNe\
+    stedR\x06nested\x12\x18\n\x07numbers\x18\x06\x20\x03(\x03R\x07numbers\
+    \x129\n\x0bnested_list\x18\x07\x20\x03(\x0b2\x18.compat_1_6.CompatNested\
+    R\nnestedList\x12G\n\nnested_map\x18\x08\x20\x03(\x0b2(.compat_1_6.Compa\
+    tMessage.NestedMapEntryR\tnestedMap\x12\x1f\n\nchoice_int\x18\n\x20\x01(\
+    \rH\0R\tchoiceInt\x12%\n\rchoice_string\x18\x0b\x20\x01(\tH\0R\x0cchoice\
+    String\x12?\n\rchoice_nested\x18\x0c\x20\x01(\x0b2\x18.compat_1_6.Compat\
+    NestedH\0R\x0cchoiceNested\x1aV\n\x0eNestedMapEntry\x12\x10\n\x03key\x18\
+    \x01\x20\x01(\tR\x03key\x12.\n\x05value\x18\x02\x20\x01(\x0b2\x18.compat\
+    _1_6.CompatNestedR\x05value:\x028\x01B\x08\n\x06choice*-\n\nCompatEnum\
+    \x12\x0b\n\x07UNKNOWN\x10\0\x12\x07\n\x03RED\x10\x01\x12\t\n\x05GREEN\
+    \x10\x02J\xc8\t\n\x06\x12\x04\0\0\x20\x01\n\x08\n\x01\x0c\x12\x03\0\0\
+    \x12\n\xb4\x01\n\x01\x02\x12\x03\x06\0\x132\xa9\x01\x20Source\x20of\x20`\
+    compat_1_6.rs`,\x20which\x20is\x20generated\x20by\x20rust-protobuf\x201.\
+    6.0\n\x20and\x20checked\x20in\x20to\x20test\x20that\x20old\x20generated\
+    \x20code\x20compiles\x20with\x20current\x20runtime.\n\x20Do\x20not\x20re\
+    generate.\n\n\n\n\x02\x05\0\x12\x04\x08\0\x0c\x01\n\n\n\x03\x05\0\x01\
+    \x12\x03\x08\x05\x0f\n\x0b\n\x04\x05\0\x02\0\x12\x03\t\x04\x10\n\x0c\n\
+    \x05\x05\0\x02\0\x01\x12\x03\t\x04\x0b\n\x0c\n\x05\x05\0\x02\0\x02\x12\
+    \x03\t\x0e\x0f\n\x0b\n\x04\x05\0\x02\x01\x12\x03\n\x04\x0c\n\x0c\n\x05\
+    \x05\0\x02\x01\x01\x12\x03\n\x04\x07\n\x0c\n\x05\x05\0\x02\x01\x02\x12\
+    \x03\n\n\x0b\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x0b\x04\x0e\n\x0c\n\x05\
+    \x05\0\x02\x02\x01\x12\x03\x0b\x04\t\n\x0c\n\x05\x05\0\x02\x02\x02\x12\
+    \x03\x0b\x0c\r\n\n\n\x02\x04\0\x12\x04\x0e\0\x10\x01\n\n\n\x03\x04\0\x01\
+    \x12\x03\x0e\x08\x14\n\x0b\n\x04\x04\0\x02\0\x12\x03\x0f\x04\x1d\n\x0c\n\
+    \x05\x04\0\x02\0\x04\x12\x03\x0f\x04\x0c\n\x0c\n\x05\x04\0\x02\0\x05\x12\
+    \x03\x0f\r\x12\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x0f\x13\x18\n\x0c\n\
+    \x05\x04\0\x02\0\x03\x12\x03\x0f\x1b\x1c\n\n\n\x02\x04\x01\x12\x04\x12\0\
+    \x20\x01\n\n\n\x03\x04\x01\x01\x12\x03\x12\x08\x15\n\x0b\n\x04\x04\x01\
+    \x02\0\x12\x03\x13\x04\x1a\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03\x13\x04\
+    \x0c\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x13\r\x12\n\x0c\n\x05\x04\x01\
+    \x02\0\x01\x12\x03\x13\x13\x15\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03\x13\
+    \x18\x19\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x14\x043\n\x0c\n\x05\x04\
+    \x01\x02\x01\x04\x12\x03\x14\x04\x0c\n\x0c\n\x05\x04\x01\x02\x01\x05\x12\
+    \x03\x14\r\x13\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x14\x14\x18\n\x0c\
+    \n\x05\x04\x01\x02\x01\x03\x12\x03\x14\x1b\x1c\n\x0c\n\x05\x04\x01\x02\
+    \x01\x08\x12\x03\x14\x1d2\n\x0c\n\x05\x04\x01\x02\x01\x07\x12\x03\x14(1\
+    \n\x0b\n\x04\x04\x01\x02\x02\x12\x03\x15\x04\x1c\n\x0c\n\x05\x04\x01\x02\
+    \x02\x04\x12\x03\x15\x04\x0c\n\x0c\n\x05\x04\x01\x02\x02\x05\x12\x03\x15\
+    \r\x12\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03\x15\x13\x17\n\x0c\n\x05\
+    \x04\x01\x02\x02\x03\x12\x03\x15\x1a\x1b\n\x0b\n\x04\x04\x01\x02\x03\x12\
+    \x03\x16\x04\"\n\x0c\n\x05\x04\x01\x02\x03\x04\x12\x03\x16\x04\x0c\n\x0c\
+    \n\x05\x04\x01\x02\x03\x06\x12\x03\x16\r\x17\n\x0c\n\x05\x04\x01\x02\x03\
+    \x01\x12\x03\x16\x18\x1d\n\x0c\n\x05\x04\x01\x02\x03\x03\x12\x03\x16\x20\
+    !\n\x0b\n\x04\x04\x01\x02\x04\x12\x03\x17\x04%\n\x0c\n\x05\x04\x01\x02\
+    \x04\x04\x12\x03\x17\x04\x0c\n\x0c\n\x05\x04\x01\x02\x04\x06\x12\x03\x17\
+    \r\x19\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03\x17\x1a\x20\n\x0c\n\x05\
+    \x04\x01\x02\x04\x03\x12\x03\x17#$\n\x0b\n\x04\x04\x01\x02\x05\x12\x03\
+    \x18\x04\x1f\n\x0c\n\x05\x04\x01\x02\x05\x04\x12\x03\x18\x04\x0c\n\x0c\n\
+    \x05\x04\x01\x02\x05\x05\x12\x03\x18\r\x12\n\x0c\n\x05\x04\x01\x02\x05\
+    \x01\x12\x03\x18\x13\x1a\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03\x18\x1d\
+    \x1e\n\x0b\n\x04\x04\x01\x02\x06\x12\x03\x19\x04*\n\x0c\n\x05\x04\x01\
+    \x02\x06\x04\x12\x03\x19\x04\x0c\n\x0c\n\x05\x04\x01\x02\x06\x06\x12\x03\
+    \x19\r\x19\n\x0c\n\x05\x04\x01\x02\x06\x01\x12\x03\x19\x1a%\n\x0c\n\x05\
+    \x04\x01\x02\x06\x03\x12\x03\x19()\n\x0b\n\x04\x04\x01\x02\x07\x12\x03\
+    \x1a\x04-\n\x0c\n\x05\x04\x01\x02\x07\x06\x12\x03\x1a\x04\x1d\n\x0c\n\
+    \x05\x04\x01\x02\x07\x01\x12\x03\x1a\x1e(\n\x0c\n\x05\x04\x01\x02\x07\
+    \x03\x12\x03\x1a+,\n\x0c\n\x04\x04\x01\x08\0\x12\x04\x1b\x04\x1f\x05\n\
+    \x0c\n\x05\x04\x01\x08\0\x01\x12\x03\x1b\n\x10\n\x0b\n\x04\x04\x01\x02\
+    \x08\x12\x03\x1c\x08\x1f\n\x0c\n\x05\x04\x01\x02\x08\x05\x12\x03\x1c\x08\
+    \x0e\n\x0c\n\x05\x04\x01\x02\x08\x01\x12\x03\x1c\x0f\x19\n\x0c\n\x05\x04\
+    \x01\x02\x08\x03\x12\x03\x1c\x1c\x1e\n\x0b\n\x04\x04\x01\x02\t\x12\x03\
+    \x1d\x08\"\n\x0c\n\x05\x04\x01\x02\t\x05\x12\x03\x1d\x08\x0e\n\x0c\n\x05\
+    \x04\x01\x02\t\x01\x12\x03\x1d\x0f\x1c\n\x0c\n\x05\x04\x01\x02\t\x03\x12\
+    \x03\x1d\x1f!\n\x0b\n\x04\x04\x01\x02\n\x12\x03\x1e\x08(\n\x0c\n\x05\x04\
+    \x01\x02\n\x06\x12\x03\x1e\x08\x14\n\x0c\n\x05\x04\x01\x02\n\x01\x12\x03\
+    \x1e\x15\"\n\x0c\n\x05\x04\x01\x02\n\x03\x12\x03\x1e%'\
+";
+
+static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
+    lock: ::protobuf::lazy::ONCE_INIT,
+    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
+};
+
+fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
+    ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
+}
+
+pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
+    unsafe {
+        file_descriptor_proto_lazy.get(|| {
+            parse_descriptor_proto()
+        })
+    }
+}
@@ -0,0 +1,6 @@
+// Code generated by earlier 1.x codegen must compile with current runtime.
+
+// uses deprecated runtime functions
+#[allow(deprecated)]
+mod compat_1_6;
+mod test_compat_1_6;
@@ -0,0 +1,69 @@
+#![allow(deprecated)]
+
+use super::compat_1_6::*;
+
+use protobuf::Message;
+use protobuf::ProtobufEnum;
+use protobuf::parse_from_bytes;
+
+fn sample() -> CompatMessage {
+    let mut m = CompatMessage::new();
+    m.set_id(17);
+    m.set_data(b"abc".to_vec());
+    m.set_color(CompatEnum::GREEN);
+    m.mut_nested().set_value(3);
+    m.mut_numbers().push(-5);
+    m.mut_nested_list().push(CompatNested::new());
+    m.mut_nested_map().insert("k".to_owned(), CompatNested::new());
+    m.set_choice_string("s".to_owned());
+    m
+}
+
+#[test]
+fn test_round_trip() {
+    let m = sample();
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed: CompatMessage = parse_from_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+    assert_eq!("unnamed", parsed.get_name());
+    assert_eq!("s", parsed.get_choice_string());
+}
+
+#[test]
+fn test_check_initialized() {
+    assert!(CompatMessage::new().write_to_bytes().is_err());
+}
+
+#[test]
+fn test_debug() {
+    let m = sample();
+    assert!(format!("{:?}", m).starts_with("id: 17 "), "{:?}", m);
+}
+
+#[test]
+fn test_message_descriptor() {
+    let m = sample();
+    let descriptor = m.descriptor();
+    assert_eq!("CompatMessage", descriptor.name());
+    assert_eq!(17, descriptor.field_by_name("id").get_i32(&m));
+    assert_eq!(
+        "CompatMessage",
+        CompatMessage::descriptor_static(None).name()
+    );
+    let dyn_descriptor = m.descriptor_dyn().unwrap();
+    assert_eq!("compat_1_6.CompatMessage", dyn_descriptor.full_name());
+}
+
+#[test]
+fn test_enum_descriptor() {
+    assert_eq!("GREEN", CompatEnum::GREEN.descriptor().name());
+    assert_eq!(
+        "CompatEnum",
+        CompatEnum::enum_descriptor_static(None).name()
+    );
+    assert_eq!(
+        "CompatEnum",
+        CompatEnum::enum_descriptor_dyn().unwrap().name()
+    );
+    assert_eq!(Some(CompatEnum::RED), CompatEnum::from_i32(1));
+}
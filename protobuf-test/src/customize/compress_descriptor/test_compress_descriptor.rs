@@ -0,0 +1,29 @@
+use protobuf::MessageFull;
+
+use protobuf_test_common::*;
+
+use super::customize_a_pb;
+use super::customize_a_pb::*;
+use super::customize_b_pb::*;
+
+#[test]
+fn test_round_trip() {
+    let mut t = Tree::new();
+    t.set_value(1);
+    t.mut_tree().set_leaf("leaf".to_owned());
+
+    let mut f = Forest::new();
+    f.mut_trees().push(t);
+    f.mut_forest().mut_nested().set_name("n".to_owned());
+    test_serialize_deserialize_no_hex(&f);
+}
+
+#[test]
+fn test_descriptor() {
+    let file = customize_a_pb::file_descriptor_proto();
+    assert_eq!("customize.a", file.get_package());
+
+    let descriptor = Forest::type_descriptor();
+    assert_eq!("customize.b.Forest", descriptor.full_name());
+    assert_eq!("Tree", descriptor.field_by_name("first_tree").message_descriptor().unwrap().name());
+}
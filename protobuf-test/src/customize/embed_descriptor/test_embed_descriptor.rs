@@ -0,0 +1,27 @@
+use protobuf::Message;
+use protobuf::ProtobufEnum;
+
+use protobuf_test_common::*;
+
+use super::customize_a_pb::*;
+use super::customize_b_pb::*;
+
+#[test]
+fn test_round_trip() {
+    let mut t = Tree::new();
+    t.set_value(1);
+    t.mut_tree().set_leaf("leaf".to_owned());
+    t.mut_nested().set_name("n".to_owned());
+
+    let mut f = Forest::new();
+    f.mut_trees().push(t);
+    f.set_color(Color::GREEN);
+    test_serialize_deserialize_no_hex(&f);
+}
+
+#[test]
+fn test_no_descriptor() {
+    assert!(Forest::new().descriptor_dyn().is_none());
+    assert!(Tree::new().descriptor_dyn().is_none());
+    assert!(Color::enum_descriptor_dyn().is_none());
+}
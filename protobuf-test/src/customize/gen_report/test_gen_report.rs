@@ -0,0 +1,27 @@
+use protobuf_test_common::*;
+
+use super::customize_a_pb::*;
+use super::customize_b_pb::*;
+
+#[test]
+fn test_round_trip() {
+    let mut t = Tree::new();
+    t.set_value(1);
+    t.mut_tree().set_leaf("leaf".to_owned());
+
+    let mut f = Forest::new();
+    f.mut_trees().push(t);
+    f.set_color(Color::GREEN);
+    test_serialize_deserialize_no_hex(&f);
+}
+
+#[test]
+fn test_report() {
+    let report = include_str!("customize_a_pb.report.json");
+    assert!(report.contains("\"file\": \"customize_a_pb.proto\""), "{}", report);
+    assert!(report.contains("\"name\": \"customize.a.Tree\""), "{}", report);
+    assert!(report.contains("\"name\": \"customize.a.Tree.Nested\""), "{}", report);
+
+    let report = include_str!("customize_b_pb.report.json");
+    assert!(report.contains("\"name\": \"customize.b.Forest\""), "{}", report);
+}
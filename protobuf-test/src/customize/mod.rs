@@ -0,0 +1,11 @@
+// Protos of this directory generated with different `Customize` options,
+// see `build.rs`
+
+mod split_messages;
+mod v3_compat;
+#[cfg(feature = "with-deflate")]
+mod compress_descriptor;
+mod strip_source_code_info;
+mod embed_descriptor;
+mod gen_report;
+mod package_modules;
@@ -0,0 +1,4 @@
+// `generated` directory with `mod.rs` files is written by `build.rs`
+
+mod generated;
+mod test_package_modules;
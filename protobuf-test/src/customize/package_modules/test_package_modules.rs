@@ -0,0 +1,34 @@
+use protobuf::MessageFull;
+
+use protobuf_test_common::*;
+
+use super::generated::customize::a::Color;
+use super::generated::customize::a::Tree;
+use super::generated::customize::a::tree;
+use super::generated::customize::b::Forest;
+
+#[test]
+fn test_round_trip() {
+    let mut nested = tree::Nested::new();
+    nested.set_name("n".to_owned());
+
+    let mut t = Tree::new();
+    t.set_value(1);
+    t.mut_tree().set_leaf("leaf".to_owned());
+    t.mut_counts().insert("a".to_owned(), 2);
+    t.set_nested(nested.clone());
+
+    let mut f = Forest::new();
+    f.mut_trees().push(t.clone());
+    f.set_nested(nested);
+    f.set_color(Color::GREEN);
+    f.set_first_tree(t);
+    test_serialize_deserialize_no_hex(&f);
+}
+
+#[test]
+fn test_reflect() {
+    let descriptor = Forest::type_descriptor();
+    assert_eq!("customize.b.Forest", descriptor.full_name());
+    assert_eq!("Tree", descriptor.field_by_name("trees").message_descriptor().unwrap().name());
+}
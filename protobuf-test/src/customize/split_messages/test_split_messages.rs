@@ -0,0 +1,42 @@
+use protobuf::MessageFull;
+
+use protobuf_test_common::*;
+
+use super::customize_a_pb::*;
+use super::customize_b_pb::*;
+
+fn tree(value: i32) -> Tree {
+    let mut t = Tree::new();
+    t.set_value(value);
+    t.set_color(Color::GREEN);
+    t.mut_counts().insert("a".to_owned(), value);
+    t.mut_nested().set_name(format!("n{}", value));
+    t
+}
+
+#[test]
+fn test_recursive_oneof() {
+    let mut t = tree(1);
+    t.set_tree(tree(2));
+    t.mut_tree().set_leaf("leaf".to_owned());
+    t.mut_children().push(tree(3));
+    test_serialize_deserialize_no_hex(&t);
+    assert_eq!("leaf", t.get_tree().get_leaf());
+}
+
+#[test]
+fn test_other_file() {
+    let mut f = Forest::new();
+    f.mut_trees().push(tree(1));
+    f.mut_nested().set_name("n".to_owned());
+    f.set_color(Color::GREEN);
+    f.mut_forest().set_first_tree(tree(2));
+    test_serialize_deserialize_no_hex(&f);
+}
+
+#[test]
+fn test_reflect() {
+    let descriptor = Tree::type_descriptor();
+    assert_eq!("customize.a.Tree", descriptor.full_name());
+    assert_eq!("Tree", descriptor.field_by_name("tree").message_descriptor().unwrap().name());
+}
@@ -0,0 +1,28 @@
+use protobuf::MessageFull;
+
+use protobuf_test_common::*;
+
+use super::customize_a_pb;
+use super::customize_b_pb;
+use super::customize_a_pb::*;
+use super::customize_b_pb::*;
+
+#[test]
+fn test_round_trip() {
+    let mut t = Tree::new();
+    t.set_value(1);
+    t.mut_counts().insert("a".to_owned(), 2);
+
+    let mut f = Forest::new();
+    f.set_first_tree(t);
+    f.set_color(Color::GREEN);
+    test_serialize_deserialize_no_hex(&f);
+}
+
+#[test]
+fn test_descriptor() {
+    assert!(!customize_a_pb::file_descriptor_proto().has_source_code_info());
+    assert!(!customize_b_pb::file_descriptor_proto().has_source_code_info());
+
+    assert_eq!("customize.a.Tree", Tree::type_descriptor().full_name());
+}
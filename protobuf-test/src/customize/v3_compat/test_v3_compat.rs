@@ -0,0 +1,30 @@
+use protobuf_test_common::*;
+
+use super::customize_a_pb::*;
+use super::customize_b_pb::*;
+
+#[test]
+fn test_round_trip() {
+    let mut t = Tree::new();
+    t.set_value(1);
+    t.mut_tree().set_leaf("leaf".to_owned());
+    t.mut_nested().set_name("n".to_owned());
+
+    let mut f = Forest::new();
+    f.mut_trees().push(t);
+    f.set_color(Color::GREEN);
+    test_serialize_deserialize_no_hex(&f);
+}
+
+#[test]
+fn test_v3_getters() {
+    let mut t = Tree::new();
+    t.set_value(1);
+    t.set_leaf("leaf".to_owned());
+    t.mut_nested().set_name("n".to_owned());
+
+    assert_eq!(1, t.value());
+    assert_eq!("leaf", t.leaf());
+    assert_eq!("n", t.nested().name());
+    assert_eq!(Color::RED, t.color());
+}
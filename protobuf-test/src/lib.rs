@@ -16,6 +16,10 @@ mod v3;
 
 mod common;
 
+mod compat;
+
+mod customize;
+
 #[cfg(proto3)]
 mod google;
 
@@ -0,0 +1,10 @@
+use super::test_field_number_consts_case_pb::*;
+
+#[test]
+fn test_names_differ_in_case() {
+    assert_eq!(1, TestFieldNumberConstsCase::FIELD_FOO_1_NUMBER);
+    assert_eq!(8, TestFieldNumberConstsCase::FIELD_FOO_1_TAG);
+    assert_eq!(2, TestFieldNumberConstsCase::FIELD_FOO_2_NUMBER);
+    assert_eq!(18, TestFieldNumberConstsCase::FIELD_FOO_2_TAG);
+    assert_eq!(3, TestFieldNumberConstsCase::FIELD_BAR_NUMBER);
+}
@@ -0,0 +1,46 @@
+use protobuf::*;
+
+use protobuf_test_common::*;
+use protobuf_test_common::hex::decode_hex;
+
+use super::test_group_pb::*;
+
+
+// aaa = "a", then two `Identifier` groups: {sss: "x"} and {iii: 6};
+// groups have single field, because order of unknown fields is unspecified
+const WITH_GROUPS: &str = "0a 01 61 93 01 a2 01 01 78 94 01 93 01 98 01 06 94 01";
+
+#[cfg(not(feature = "with-groups"))]
+#[test]
+fn test_groups_skipped() {
+    let m: MessageWithGroup = parse_from_bytes(&decode_hex(WITH_GROUPS)).unwrap();
+    assert_eq!("a", m.get_aaa());
+    assert_eq!("", m.get_unknown_fields().debug_dump());
+    test_serialize_deserialize("0a 01 61", &m);
+}
+
+#[cfg(feature = "with-groups")]
+#[test]
+fn test_groups_preserved_in_unknown_fields() {
+    let m: MessageWithGroup = parse_from_bytes(&decode_hex(WITH_GROUPS)).unwrap();
+    assert_eq!("a", m.get_aaa());
+    assert_eq!(
+        "18: group {20: bytes 78}\n\
+         18: group {19: varint 6}",
+        m.get_unknown_fields().debug_dump()
+    );
+    test_serialize_deserialize(WITH_GROUPS, &m);
+}
+
+#[cfg(feature = "with-groups")]
+#[test]
+fn test_groups_merged() {
+    let mut m: MessageWithGroup = parse_from_bytes(&decode_hex(WITH_GROUPS)).unwrap();
+    m.merge_from_bytes(&decode_hex("93 01 94 01")).unwrap();
+    assert_eq!(3, m.get_unknown_fields().get(18).unwrap().group.len());
+
+    let mut other = MessageWithGroup::new();
+    other.set_aaa("a".to_owned());
+    other.mut_unknown_fields().merge_from(m.get_unknown_fields());
+    assert_eq!(m, other);
+}
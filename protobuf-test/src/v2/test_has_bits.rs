@@ -0,0 +1,116 @@
+use protobuf::Message;
+use protobuf::MessageFull;
+use protobuf::Clear;
+
+use super::test_has_bits_pb::*;
+
+fn sample() -> TestHasBits {
+    let mut m = TestHasBits::new();
+    m.set_int32_field(0);
+    m.set_bool_field(false);
+    m.set_enum_field(TestHasBitsEnum::RED);
+    m.set_sint64_field(-5);
+    m.set_required_field(7);
+    m.set_string_field("s".to_owned());
+    m
+}
+
+#[test]
+fn test_accessors() {
+    let mut m = TestHasBits::new();
+    assert!(!m.has_int32_field());
+    assert_eq!(0, m.get_int32_field());
+    assert!(m.get_bool_field());
+    assert_eq!(TestHasBitsEnum::GREEN, m.get_enum_field());
+
+    // zero and default values are distinct from unset field
+    m.set_int32_field(0);
+    m.set_bool_field(true);
+    assert!(m.has_int32_field());
+    assert!(m.has_bool_field());
+    assert!(!m.has_double_field());
+
+    m.clear_int32_field();
+    assert!(!m.has_int32_field());
+    assert!(m.has_bool_field());
+}
+
+#[test]
+fn test_round_trip() {
+    let m = sample();
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed: TestHasBits = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+    assert!(parsed.has_int32_field());
+    assert!(!parsed.get_bool_field());
+    assert!(!parsed.has_double_field());
+    assert_eq!(-5, parsed.get_sint64_field());
+}
+
+#[test]
+fn test_enum_field_without_has_bit() {
+    let mut unknown = TestHasBits::new();
+    unknown.set_required_field(1);
+    unknown.mut_unknown_fields().add_varint(3, 10);
+    let bytes = unknown.write_to_bytes().unwrap();
+    let parsed: TestHasBits = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert!(!parsed.has_enum_field());
+    assert_eq!(bytes, parsed.write_to_bytes().unwrap());
+}
+
+#[test]
+fn test_clear_and_eq() {
+    let mut m = sample();
+    m.clear();
+    assert!(!m.has_sint64_field());
+    assert_eq!(TestHasBits::new(), m);
+
+    let mut m = TestHasBits::new();
+    m.set_double_field(1.5);
+    m.clear_double_field();
+    assert_eq!(TestHasBits::new(), m);
+}
+
+#[test]
+fn test_required() {
+    let mut m = TestHasBits::new();
+    assert!(!m.is_initialized());
+    m.set_required_field(0);
+    assert!(m.is_initialized());
+}
+
+#[test]
+fn test_merge_from_message() {
+    let mut a = TestHasBits::new();
+    a.set_int32_field(1);
+    a.set_double_field(2.0);
+    let mut b = TestHasBits::new();
+    b.set_int32_field(3);
+    a.merge_from_message(&b);
+    assert_eq!(3, a.get_int32_field());
+    assert_eq!(2.0, a.get_double_field());
+    assert!(!a.has_sint64_field());
+}
+
+#[test]
+fn test_reflect() {
+    let m = sample();
+    let descriptor = m.message_descriptor();
+    assert!(descriptor.field_by_name("int32_field").has_field(&m));
+    assert!(!descriptor.field_by_name("double_field").has_field(&m));
+    assert_eq!(-5, descriptor.field_by_name("sint64_field").get_i64(&m));
+    assert!(!descriptor.field_by_name("bool_field").get_bool(&m));
+}
+
+#[test]
+fn test_many_fields() {
+    let mut m = TestHasBitsMany::new();
+    m.set_f33(33);
+    assert!(m.has_f33());
+    assert!(!m.has_f1());
+    assert!(!m.has_f34());
+    let bytes = m.write_to_bytes().unwrap();
+    let parsed: TestHasBitsMany = ::protobuf::parse_from_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+    assert_eq!(33, parsed.get_f33());
+}
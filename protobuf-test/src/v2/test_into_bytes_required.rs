@@ -0,0 +1,18 @@
+use protobuf::Message;
+
+use super::test_into_bytes_required_pb::*;
+
+fn send<T: Into<Vec<u8>>>(t: T) -> Vec<u8> {
+    t.into()
+}
+
+#[test]
+fn test_into_bytes_not_initialized() {
+    let mut m = TestIntoBytesRequired::new();
+    m.set_name("x".to_owned());
+    assert!(m.write_to_bytes().is_err());
+    let bytes = send(&m);
+    let mut parsed = TestIntoBytesRequired::new();
+    parsed.merge_from_bytes(&bytes).unwrap();
+    assert_eq!(m, parsed);
+}
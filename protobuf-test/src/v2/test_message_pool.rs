@@ -0,0 +1,76 @@
+use protobuf::Message;
+use protobuf::MessagePool;
+
+use super::test_message_pool_pb::*;
+
+fn message(name: &str) -> TestMessagePool {
+    let mut m = TestMessagePool::new();
+    m.set_name(name.to_owned());
+    m.set_color(Color::RED);
+    m.mut_nested().set_name(name.to_owned());
+    for i in 0..3 {
+        let mut nested = Nested::new();
+        nested.set_name(format!("{}{}", name, i));
+        m.mut_nested_list().push(nested);
+    }
+    m.mut_ints().extend(0..10);
+    m.mut_attrs().insert("k".to_owned(), name.to_owned());
+    m.mut_one_nested().set_name(name.to_owned());
+    m
+}
+
+#[test]
+fn reuse() {
+    let mut pool = MessagePool::<TestMessagePool>::new();
+    let m = pool.parse_from_bytes(&message("a").write_to_bytes().unwrap()).unwrap();
+    let name_ptr = m.get_name().as_ptr();
+    let nested_name_ptr = m.get_nested().get_name().as_ptr();
+    let nested_list_ptr = m.get_nested_list().as_ptr();
+    let ints_ptr = m.get_ints().as_ptr();
+    pool.put(m);
+    assert_eq!(1, pool.free());
+
+    let m = pool.parse_from_bytes(&message("b").write_to_bytes().unwrap()).unwrap();
+    assert_eq!(0, pool.free());
+    assert_eq!(message("b"), m);
+    // buffers of the cleared message are reused
+    assert_eq!(name_ptr, m.get_name().as_ptr());
+    assert_eq!(nested_name_ptr, m.get_nested().get_name().as_ptr());
+    assert_eq!(nested_list_ptr, m.get_nested_list().as_ptr());
+    assert_eq!(ints_ptr, m.get_ints().as_ptr());
+}
+
+#[test]
+fn cleared() {
+    let mut pool = MessagePool::<TestMessagePool>::new();
+    pool.put(message("a"));
+    let mut m = pool.get();
+    assert_eq!(TestMessagePool::new(), m);
+    assert!(m.mut_attrs().capacity() > 0);
+}
+
+#[test]
+fn max_free() {
+    let mut pool = MessagePool::<TestMessagePool>::with_max_free(1);
+    let a = pool.get();
+    let b = pool.get();
+    pool.put(a);
+    pool.put(b);
+    assert_eq!(1, pool.free());
+    pool.shrink();
+    assert_eq!(0, pool.free());
+}
+
+#[test]
+fn errors() {
+    let mut pool = MessagePool::<TestMessagePool>::new();
+    // `name` is required
+    assert!(pool.parse_from_bytes(&[]).is_err());
+    assert_eq!(1, pool.free());
+
+    // truncated message
+    let bytes = message("a").write_to_bytes().unwrap();
+    assert!(pool.parse_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    assert_eq!(1, pool.free());
+    assert_eq!(TestMessagePool::new(), pool.get());
+}
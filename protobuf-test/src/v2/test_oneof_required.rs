@@ -0,0 +1,21 @@
+use protobuf::*;
+
+use super::test_oneof_required_pb::*;
+
+#[test]
+fn test_is_initialized() {
+    let mut test_message = TestOneofRequired::new();
+    assert!(test_message.is_initialized());
+
+    test_message.set_int32_field(1);
+    assert!(test_message.is_initialized());
+
+    test_message.mut_message_field();
+    assert!(test_message.is_initialized());
+
+    test_message.mut_required_field();
+    assert!(!test_message.is_initialized());
+
+    test_message.mut_required_field().set_r(1);
+    assert!(test_message.is_initialized());
+}
@@ -36,6 +36,17 @@ fn test_map_with_object() {
     test_serialize_deserialize_no_hex(&map);
 }
 
+#[test]
+fn test_map_negative_int32() {
+    let mut map = TestMap::new();
+    // negative int32 and enum values are sign-extended to ten bytes
+    map.mut_int32_to_enum().insert(-1, TestMapEnum::NEGATIVE);
+    test_serialize_deserialize(
+        "1a 16 08 ff ff ff ff ff ff ff ff ff 01 10 ff ff ff ff ff ff ff ff ff 01",
+        &map,
+    );
+}
+
 #[test]
 fn text_format() {
     let mut map = TestMap::new();
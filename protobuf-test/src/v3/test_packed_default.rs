@@ -0,0 +1,37 @@
+use protobuf_test_common::*;
+
+use super::test_packed_default_pb::*;
+
+#[test]
+fn test_scalars_packed_by_default() {
+    let mut m = TestPackedDefault::new();
+    m.set_int32_values(vec![1, 2]);
+    test_serialize_deserialize("0a 02 01 02", &m);
+
+    let mut m = TestPackedDefault::new();
+    m.set_enum_values(vec![TestPackedDefaultEnum::ONE, TestPackedDefaultEnum::UNKNOWN]);
+    test_serialize_deserialize("12 02 01 00", &m);
+
+    let mut m = TestPackedDefault::new();
+    m.set_bool_values(vec![true, false]);
+    test_serialize_deserialize("1a 02 01 00", &m);
+}
+
+#[test]
+fn test_strings_and_explicit_unpacked_are_not_packed() {
+    let mut m = TestPackedDefault::new();
+    let strings = vec!["a".to_owned(), "b".to_owned()];
+    m.set_string_values(::protobuf::RepeatedField::from_vec(strings));
+    test_serialize_deserialize("22 01 61 22 01 62", &m);
+
+    let mut m = TestPackedDefault::new();
+    m.set_unpacked_values(vec![1, 2]);
+    test_serialize_deserialize("28 01 28 02", &m);
+}
+
+#[test]
+fn test_read_unpacked_to_packed_by_default() {
+    let mut m = TestPackedDefault::new();
+    m.set_enum_values(vec![TestPackedDefaultEnum::ONE, TestPackedDefaultEnum::UNKNOWN]);
+    test_deserialize("10 01 10 00", &m);
+}
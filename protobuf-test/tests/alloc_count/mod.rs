@@ -0,0 +1,41 @@
+//! Global allocator counting allocations made by current thread,
+//! so tests running in parallel do not affect each other.
+
+use std::alloc::GlobalAlloc;
+use std::alloc::Layout;
+use std::alloc::System;
+use std::cell::Cell;
+
+struct CountingAlloc;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = Cell::new(0);
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        // counter is not available while thread is being destroyed
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|c| c.set(c.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// Number of heap allocations performed by `f` in current thread.
+pub fn count_allocations<R, F : FnOnce() -> R>(f: F) -> (usize, R) {
+    let before = ALLOCATIONS.with(|c| c.get());
+    let r = f();
+    let after = ALLOCATIONS.with(|c| c.get());
+    (after - before, r)
+}
@@ -0,0 +1,54 @@
+//! Empty generated messages must not allocate.
+//!
+//! This is a separate test binary because it replaces the global allocator.
+
+extern crate protobuf;
+
+mod alloc_count;
+
+#[path = "../src/v2/test_default_alloc_pb.rs"]
+mod test_default_alloc_pb;
+
+use protobuf::*;
+
+use alloc_count::count_allocations;
+
+use test_default_alloc_pb::*;
+
+#[test]
+fn test_new() {
+    // default instance returned for unset message fields is allocated once
+    TestDefaultAllocNested::default_instance();
+
+    let (allocations, m) = count_allocations(|| TestDefaultAlloc::new());
+    assert_eq!(0, allocations);
+    // proto2 defaults are returned by getters without allocation
+    let (allocations, ()) = count_allocations(|| {
+        assert_eq!("abc", m.get_s());
+        assert_eq!(b"def", m.get_b());
+        assert_eq!(TestDefaultAllocEnum::SECOND, m.get_e());
+        assert_eq!(0, m.get_nested().get_x());
+        assert_eq!("", m.get_os());
+    });
+    assert_eq!(0, allocations);
+
+    let (allocations, _) = count_allocations(|| TestDefaultAllocHasBits::new());
+    assert_eq!(0, allocations);
+}
+
+#[test]
+fn test_default() {
+    let (allocations, m) = count_allocations(|| TestDefaultAlloc::default());
+    assert_eq!(0, allocations);
+    assert_eq!(0, m.compute_size());
+}
+
+#[test]
+fn test_clear() {
+    let mut m = TestDefaultAlloc::new();
+    m.set_os("x".to_owned());
+    m.mut_r().push(1);
+    let (allocations, ()) = count_allocations(|| m.clear());
+    assert_eq!(0, allocations);
+    assert_eq!(TestDefaultAlloc::new(), m);
+}
@@ -0,0 +1,109 @@
+//! Generated code must only reference items listed in `protobuf::gen_support`.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Paths (relative to `protobuf` crate root) reexported by `gen_support`.
+///
+/// `pub use rt::string_size;` inside `pub mod rt` is `rt::string_size`,
+/// `pub use descriptor;` at top level is `descriptor`.
+fn listed_paths(gen_support: &str) -> HashSet<String> {
+    let mut r = HashSet::new();
+    let mut modules: Vec<String> = Vec::new();
+    for line in gen_support.lines() {
+        let line = line.trim();
+        if line == "#[cfg(test)]" {
+            break;
+        }
+        if line.starts_with("pub mod ") && line.ends_with("{") {
+            let name = line["pub mod ".len()..line.len() - 1].trim();
+            modules.push(name.to_owned());
+        } else if line == "}" {
+            modules.pop();
+        } else if line.starts_with("pub use ") && line.ends_with(";") {
+            let used = &line["pub use ".len()..line.len() - 1];
+            let name = used.rsplit("::").next().unwrap();
+            let mut path = modules.clone();
+            path.push(name.to_owned());
+            r.insert(path.join("::"));
+        }
+    }
+    r
+}
+
+/// `::protobuf::` paths referenced in generated source, truncated
+/// after the first type-like segment (`Message::to_bytes` is `Message`).
+fn referenced_paths(source: &str, r: &mut HashSet<String>) {
+    let prefix = "::protobuf::";
+    let mut rem = source;
+    while let Some(pos) = rem.find(prefix) {
+        rem = &rem[pos + prefix.len()..];
+        let end = rem
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == ':'))
+            .unwrap_or(rem.len());
+        let mut path = Vec::new();
+        for segment in rem[..end].split("::") {
+            if segment.is_empty() {
+                break;
+            }
+            path.push(segment);
+            if segment.chars().next().unwrap().is_uppercase() {
+                break;
+            }
+        }
+        r.insert(path.join("::"));
+    }
+}
+
+fn is_listed(path: &str, listed: &HashSet<String>) -> bool {
+    let segments: Vec<&str> = path.split("::").collect();
+    (1..segments.len() + 1).any(|n| listed.contains(&segments[..n].join("::")))
+}
+
+fn collect_rs_files(dir: &Path, suffix: &str, r: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            collect_rs_files(&path, suffix, r);
+        } else if path.to_str().unwrap().ends_with(suffix) {
+            r.push(path);
+        }
+    }
+}
+
+#[test]
+fn generated_code_uses_only_gen_support_items() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let runtime = root.join("../protobuf/src");
+
+    let gen_support = fs::read_to_string(runtime.join("gen_support.rs")).unwrap();
+    let listed = listed_paths(&gen_support);
+
+    let mut files = Vec::new();
+    collect_rs_files(&root.join("src"), "_pb.rs", &mut files);
+    collect_rs_files(&runtime.join("well_known_types"), ".rs", &mut files);
+    files.push(runtime.join("descriptor.rs"));
+    files.push(runtime.join("plugin.rs"));
+    files.push(runtime.join("rustproto.rs"));
+    // generated by rust-protobuf 1.6.0
+    files.push(root.join("src/compat/compat_1_6.rs"));
+    assert!(files.len() > 10, "generated files not found");
+
+    let mut referenced = HashSet::new();
+    for file in &files {
+        referenced_paths(&fs::read_to_string(file).unwrap(), &mut referenced);
+    }
+
+    let mut unlisted: Vec<&String> = referenced
+        .iter()
+        .filter(|p| !is_listed(p, &listed))
+        .collect();
+    unlisted.sort();
+    assert!(
+        unlisted.is_empty(),
+        "generated code references items not listed in gen_support: {:?}",
+        unlisted
+    );
+}
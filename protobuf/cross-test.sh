@@ -0,0 +1,13 @@
+#!/bin/sh -ex
+
+# Run runtime tests on big-endian target emulated with QEMU
+# using cross (https://github.com/rust-embedded/cross), which requires docker.
+# Target can be overridden with `CROSS_TARGET`, e. g. `powerpc-unknown-linux-gnu`.
+
+cd $(dirname $0)
+
+target=${CROSS_TARGET:-s390x-unknown-linux-gnu}
+
+cross test --target "$target" --features "with-bytes with-deflate with-serde-json with-smallvec"
+
+# vim: set ts=4 sw=4 et:
@@ -0,0 +1,186 @@
+//! Parsing many short-lived messages without hitting the allocator.
+//!
+//! Generated messages own their fields, so they cannot be allocated
+//! from a bump allocator. Instead `Arena` keeps messages parsed into it
+//! until `reset`, and then reuses them for subsequent parsing. Clearing
+//! a message keeps capacity of its `string`, `bytes`, repeated and nested
+//! message fields (see `SingularField` and `RepeatedField`), so after
+//! the first few rounds parsing similar messages allocates almost nothing:
+//!
+//! ```ignore
+//! let mut arena = Arena::new();
+//! for batch in batches {
+//!     for bytes in batch {
+//!         let m = parse_from_bytes_in::<Event>(&arena, bytes)?;
+//!         process(m);
+//!     }
+//!     // all messages of the batch are freed together
+//!     arena.reset();
+//! }
+//! ```
+
+use std::any::Any;
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use core::Message;
+use error::ProtobufResult;
+use stream::CodedInputStream;
+
+
+// messages of one type
+trait Pool {
+    fn allocated(&self) -> usize;
+    fn reset(&mut self);
+    fn as_any_mut(&mut self) -> &mut Any;
+}
+
+struct TypedPool<M> {
+    // allocated since last reset, created with `Box::into_raw`
+    // because references to them are handed out by `Arena::alloc`
+    used: Vec<*mut M>,
+    // cleared messages available for reuse
+    free: Vec<Box<M>>,
+}
+
+impl<M : Message> Pool for TypedPool<M> {
+    fn allocated(&self) -> usize {
+        self.used.len()
+    }
+
+    fn reset(&mut self) {
+        for p in self.used.drain(..) {
+            // `reset` requires `&mut Arena`, so references are no longer used
+            let mut m = unsafe { Box::from_raw(p) };
+            m.clear();
+            self.free.push(m);
+        }
+    }
+
+    fn as_any_mut(&mut self) -> &mut Any {
+        self
+    }
+}
+
+impl<M> Drop for TypedPool<M> {
+    fn drop(&mut self) {
+        for p in self.used.drain(..) {
+            drop(unsafe { Box::from_raw(p) });
+        }
+    }
+}
+
+/// Storage of messages which are freed together.
+///
+/// Messages returned by `alloc` live until `reset` or drop of the arena,
+/// after `reset` their memory is reused by messages of the same type.
+#[derive(Default)]
+pub struct Arena {
+    pools: RefCell<HashMap<TypeId, Box<Pool>>>,
+}
+
+impl Arena {
+    /// Create an empty arena.
+    pub fn new() -> Arena {
+        Default::default()
+    }
+
+    /// Get an empty message stored in the arena.
+    pub fn alloc<M : Message>(&self) -> &mut M {
+        let mut pools = self.pools.borrow_mut();
+        let pool = pools.entry(TypeId::of::<M>()).or_insert_with(|| {
+            Box::new(TypedPool::<M> {
+                used: Vec::new(),
+                free: Vec::new(),
+            })
+        });
+        let pool = pool.as_any_mut().downcast_mut::<TypedPool<M>>().unwrap();
+        let m = pool.free.pop().unwrap_or_else(|| Box::new(M::new()));
+        let p = Box::into_raw(m);
+        pool.used.push(p);
+        // arena does not access the message until `reset` or drop,
+        // which require `&mut self`
+        unsafe { &mut *p }
+    }
+
+    /// Number of messages allocated since last reset.
+    pub fn allocated(&self) -> usize {
+        self.pools.borrow().values().map(|pool| pool.allocated()).sum()
+    }
+
+    /// Free all messages allocated in the arena keeping their memory for reuse.
+    pub fn reset(&mut self) {
+        for pool in self.pools.get_mut().values_mut() {
+            pool.reset();
+        }
+    }
+}
+
+/// Parse message from bytes into the arena.
+///
+/// Like `parse_from_bytes`, fails if required fields are not set.
+pub fn parse_from_bytes_in<'a, M : Message>(arena: &'a Arena, bytes: &[u8])
+    -> ProtobufResult<&'a mut M>
+{
+    let m = arena.alloc::<M>();
+    let mut is = CodedInputStream::from_bytes(bytes);
+    m.merge_from(&mut is).map_err(|e| is.locate_error(e))?;
+    m.check_initialized()?;
+    Ok(m)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::DescriptorProto;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::UninterpretedOption_NamePart;
+
+    fn message(name: &str, fields: usize) -> Vec<u8> {
+        let mut message = DescriptorProto::new();
+        message.set_name(name.to_owned());
+        for _ in 0..fields {
+            message.mut_field().push(FieldDescriptorProto::new());
+        }
+        message.write_to_bytes().unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let arena = Arena::new();
+        let a = parse_from_bytes_in::<DescriptorProto>(&arena, &message("A", 0)).unwrap();
+        let b = parse_from_bytes_in::<DescriptorProto>(&arena, &message("B", 1)).unwrap();
+        assert_eq!("A", a.get_name());
+        assert_eq!("B", b.get_name());
+        assert_eq!(1, b.get_field().len());
+        assert_eq!(2, arena.allocated());
+    }
+
+    #[test]
+    fn reuse() {
+        let mut arena = Arena::new();
+        let (name_ptr, field_ptr) = {
+            let m = parse_from_bytes_in::<DescriptorProto>(&arena, &message("A", 2)).unwrap();
+            (m.get_name().as_ptr(), m.get_field().as_ptr())
+        };
+        arena.reset();
+        assert_eq!(0, arena.allocated());
+
+        let m = parse_from_bytes_in::<DescriptorProto>(&arena, &message("B", 1)).unwrap();
+        assert_eq!("B", m.get_name());
+        assert_eq!(1, m.get_field().len());
+        // buffers of the cleared message are reused
+        assert_eq!(name_ptr, m.get_name().as_ptr());
+        assert_eq!(field_ptr, m.get_field().as_ptr());
+    }
+
+    #[test]
+    fn not_initialized() {
+        let arena = Arena::new();
+        // `name_part` and `is_extension` are required
+        assert!(parse_from_bytes_in::<UninterpretedOption_NamePart>(&arena, &[]).is_err());
+        assert!(arena.alloc::<UninterpretedOption_NamePart>().get_name_part().is_empty());
+    }
+}
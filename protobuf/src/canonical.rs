@@ -0,0 +1,432 @@
+//! Canonical serialization and hashing of messages.
+//!
+//! Bytes written by `write_to_bytes` depend on iteration order of `HashMap`,
+//! which is used for map fields and unknown fields, so equal messages
+//! may be serialized differently, even in the same process.
+//! Canonical form is a deterministic serialization which can be used
+//! for content addressing, e. g. as a cache key or in signatures:
+//!
+//! * known fields are written in order of field numbers,
+//!   elements of repeated fields keep their order
+//! * map entries are sorted by key, and both key and value are written
+//! * unknown fields are written after known fields in order of field numbers,
+//!   or skipped; fields of unknown groups are ordered by field numbers too
+//!
+//! This is the same order as in deterministic serialization of C++ and Go
+//! implementations, so canonical forms of messages without unknown fields
+//! are the same across languages.
+//!
+//! ```ignore
+//! let mut hasher = Sha256Hasher::new();
+//! request.canonical_hash(&mut hasher)?;
+//! let key = hasher.finish();
+//! ```
+//!
+//! Hash is only stable if the hasher is: `DefaultHasher` of the standard
+//! library may change between Rust versions and should not be used for hashes
+//! which are stored or sent to other processes.
+
+use std::collections::HashMap;
+use std::hash::Hasher;
+use std::str;
+
+use core::Message;
+use descriptor::FieldDescriptorProto_Type;
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
+use reflect::FieldDescriptor;
+use reflect::ProtobufValueRef;
+use reflect::ReflectFieldRef;
+use reflect::descriptor_dyn;
+use stream::CodedOutputStream;
+use unknown::UnknownValueRef;
+use wire_editor::WireEditor;
+use wire_editor::WireField;
+use wire_editor::WireValue;
+use wire_format::WireType;
+use zigzag::decode_zig_zag_32;
+use zigzag::decode_zig_zag_64;
+
+
+// Decoded map key, keys of entries of one map have the same variant.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum MapKey<'a> {
+    Bool(bool),
+    I32(i32),
+    I64(i64),
+    U32(u32),
+    U64(u64),
+    String(&'a str),
+}
+
+impl<'a> MapKey<'a> {
+    fn to_value_ref(&self) -> ProtobufValueRef<'a> {
+        match *self {
+            MapKey::Bool(v) => ProtobufValueRef::Bool(v),
+            MapKey::I32(v) => ProtobufValueRef::I32(v),
+            MapKey::I64(v) => ProtobufValueRef::I64(v),
+            MapKey::U32(v) => ProtobufValueRef::U32(v),
+            MapKey::U64(v) => ProtobufValueRef::U64(v),
+            MapKey::String(v) => ProtobufValueRef::String(v),
+        }
+    }
+}
+
+fn unexpected_wire_type(value: WireValue) -> ProtobufError {
+    ProtobufError::WireError(WireError::UnexpectedWireType(value.wire_type()))
+}
+
+fn map_key<'a>(key_type: FieldDescriptorProto_Type, value: WireValue<'a>)
+    -> ProtobufResult<MapKey<'a>>
+{
+    use descriptor::FieldDescriptorProto_Type::*;
+
+    Ok(match (key_type, value) {
+        (TYPE_BOOL, WireValue::Varint(v)) => MapKey::Bool(v != 0),
+        (TYPE_INT32, WireValue::Varint(v)) => MapKey::I32(v as i32),
+        (TYPE_SINT32, WireValue::Varint(v)) => MapKey::I32(decode_zig_zag_32(v as u32)),
+        (TYPE_SFIXED32, WireValue::Fixed32(v)) => MapKey::I32(v as i32),
+        (TYPE_INT64, WireValue::Varint(v)) => MapKey::I64(v as i64),
+        (TYPE_SINT64, WireValue::Varint(v)) => MapKey::I64(decode_zig_zag_64(v)),
+        (TYPE_SFIXED64, WireValue::Fixed64(v)) => MapKey::I64(v as i64),
+        (TYPE_UINT32, WireValue::Varint(v)) => MapKey::U32(v as u32),
+        (TYPE_FIXED32, WireValue::Fixed32(v)) => MapKey::U32(v),
+        (TYPE_UINT64, WireValue::Varint(v)) => MapKey::U64(v),
+        (TYPE_FIXED64, WireValue::Fixed64(v)) => MapKey::U64(v),
+        (TYPE_STRING, WireValue::LengthDelimited(bytes)) => {
+            let s = str::from_utf8(bytes)
+                .map_err(|_| ProtobufError::WireError(WireError::Utf8Error))?;
+            MapKey::String(s)
+        }
+        (_, value) => return Err(unexpected_wire_type(value)),
+    })
+}
+
+fn length_delimited<'a>(field: &WireField<'a>) -> ProtobufResult<&'a [u8]> {
+    match field.value {
+        WireValue::LengthDelimited(bytes) => Ok(bytes),
+        value => Err(unexpected_wire_type(value)),
+    }
+}
+
+fn write_length_delimited(number: u32, bytes: &[u8], out: &mut Vec<u8>) {
+    let mut os = CodedOutputStream::vec(out);
+    // writing to vec cannot fail
+    os.write_unknown(number, UnknownValueRef::LengthDelimited(bytes)).unwrap();
+    os.flush().unwrap();
+}
+
+fn write_tag(number: u32, wire_type: WireType, out: &mut Vec<u8>) {
+    let mut os = CodedOutputStream::vec(out);
+    // writing to vec cannot fail
+    os.write_tag(number, wire_type).unwrap();
+    os.flush().unwrap();
+}
+
+// copy field, fields of groups are reordered by number recursively;
+// groups are not generated as message fields, so their fields are unknown
+fn write_wire_field(field: &WireField, out: &mut Vec<u8>) -> ProtobufResult<()> {
+    let body = match field.value {
+        WireValue::Group(body) => body,
+        _ => {
+            out.extend_from_slice(field.data);
+            return Ok(());
+        }
+    };
+    let editor = WireEditor::new(body)?;
+    let mut fields: Vec<&WireField> = editor.fields().iter().collect();
+    fields.sort_by_key(|f| f.number);
+    write_tag(field.number, WireType::WireTypeStartGroup, out);
+    for f in fields {
+        write_wire_field(f, out)?;
+    }
+    write_tag(field.number, WireType::WireTypeEndGroup, out);
+    Ok(())
+}
+
+// `index`-th element of message field, singular fields have one element
+fn nested_message<'a>(m: &'a Message, field: &FieldDescriptor, index: usize) -> &'a Message {
+    match field.get_reflect(m) {
+        ReflectFieldRef::Repeated(repeated) => match repeated.get(index).as_ref() {
+            ProtobufValueRef::Message(nested) => nested,
+            _ => panic!("not a message field: {}", field.name()),
+        },
+        _ => field.get_message(m),
+    }
+}
+
+fn write_map(m: &Message, field: &FieldDescriptor, entries: &[&WireField],
+    unknown_fields: bool, out: &mut Vec<u8>) -> ProtobufResult<()>
+{
+    let key_type = field.map_key_type().expect("map field");
+    let message_values = field.map_value_type() == Some(FieldDescriptorProto_Type::TYPE_MESSAGE);
+
+    let mut sorted = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let editor = WireEditor::new(length_delimited(entry)?)?;
+        let key = match editor.get(1) {
+            Some(key) => map_key(key_type, key.value)?,
+            None => return Err(ProtobufError::WireError(WireError::IncompleteMap)),
+        };
+        sorted.push((key, entry, editor));
+    }
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for (key, entry, editor) in sorted {
+        if !message_values {
+            // entries are written with key followed by value
+            out.extend_from_slice(entry.data);
+            continue;
+        }
+
+        let value = field.get_map(m).get(key.to_value_ref()).expect("serialized key is in map");
+        let value = match value.as_ref() {
+            ProtobufValueRef::Message(value) => value,
+            _ => panic!("not a message value: {}", field.name()),
+        };
+        let value_bytes = match editor.get(2) {
+            Some(value_field) => length_delimited(value_field)?,
+            None => &[],
+        };
+        let mut canonical_value = Vec::new();
+        write_canonical(value, value_bytes, unknown_fields, &mut canonical_value)?;
+
+        let mut canonical_entry = editor.get(1).expect("key").data.to_vec();
+        write_length_delimited(2, &canonical_value, &mut canonical_entry);
+        write_length_delimited(entry.number, &canonical_entry, out);
+    }
+    Ok(())
+}
+
+// write all occurrences of a known field
+fn write_field(m: &Message, field: &FieldDescriptor, occurrences: &[&WireField],
+    unknown_fields: bool, out: &mut Vec<u8>) -> ProtobufResult<()>
+{
+    if field.is_map() {
+        return write_map(m, field, occurrences, unknown_fields, out);
+    }
+
+    if field.proto().get_field_type() != FieldDescriptorProto_Type::TYPE_MESSAGE {
+        for occurrence in occurrences {
+            write_wire_field(occurrence, out)?;
+        }
+        return Ok(());
+    }
+
+    for (i, occurrence) in occurrences.iter().enumerate() {
+        let nested = nested_message(m, field, i);
+        let nested_bytes = length_delimited(occurrence)?;
+        let mut canonical_nested = Vec::new();
+        write_canonical(nested, nested_bytes, unknown_fields, &mut canonical_nested)?;
+        write_length_delimited(occurrence.number, &canonical_nested, out);
+    }
+    Ok(())
+}
+
+// `bytes` is serialized `m`, nested messages are found with reflection
+fn write_canonical(m: &Message, bytes: &[u8], unknown_fields: bool, out: &mut Vec<u8>)
+    -> ProtobufResult<()>
+{
+    let editor = WireEditor::new(bytes)?;
+    let mut wire_fields: Vec<&WireField> = editor.fields().iter().collect();
+    // stable sort keeps order of elements of repeated fields
+    wire_fields.sort_by_key(|f| f.number);
+
+    let fields: HashMap<u32, &FieldDescriptor> = descriptor_dyn(m)
+        .fields()
+        .iter()
+        .map(|f| (f.proto().get_number() as u32, f))
+        .collect();
+
+    let mut unknown = Vec::new();
+    let mut start = 0;
+    while start < wire_fields.len() {
+        let number = wire_fields[start].number;
+        let count = wire_fields[start..].iter().take_while(|f| f.number == number).count();
+        let occurrences = &wire_fields[start..start + count];
+        match fields.get(&number) {
+            Some(field) => write_field(m, field, occurrences, unknown_fields, out)?,
+            None => unknown.extend_from_slice(occurrences),
+        }
+        start += count;
+    }
+
+    if unknown_fields {
+        for field in unknown {
+            write_wire_field(field, out)?;
+        }
+    }
+    Ok(())
+}
+
+/// Serialize message in canonical form.
+///
+/// Unknown fields are written if `unknown_fields` is true and skipped otherwise.
+/// Fails if message is not initialized.
+pub fn canonical_bytes(m: &Message, unknown_fields: bool) -> ProtobufResult<Vec<u8>> {
+    let bytes = m.write_to_bytes()?;
+    let mut r = Vec::with_capacity(bytes.len());
+    write_canonical(m, &bytes, unknown_fields, &mut r)?;
+    Ok(r)
+}
+
+/// Feed canonical form of message to hasher.
+///
+/// Messages which are equal (ignoring unknown fields if `unknown_fields` is false)
+/// produce the same hash.
+pub fn canonical_hash<H : Hasher>(m: &Message, unknown_fields: bool, state: &mut H)
+    -> ProtobufResult<()>
+{
+    state.write(&canonical_bytes(m, unknown_fields)?);
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use std::collections::hash_map::DefaultHasher;
+
+    use core::parse_from_bytes;
+    use descriptor::DescriptorProto;
+    use descriptor::FileDescriptorProto;
+    #[cfg(feature = "with-groups")]
+    use unknown::UnknownFields;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    fn string(s: &str) -> Value {
+        let mut value = Value::new();
+        value.set_string_value(s.to_owned());
+        value
+    }
+
+    fn nested_struct(keys: &[&str]) -> Struct {
+        let mut inner = Struct::new();
+        for k in keys {
+            inner.mut_fields().insert(k.to_string(), string(k));
+        }
+        let mut value = Value::new();
+        value.set_struct_value(inner);
+        let mut s = Struct::new();
+        s.mut_fields().insert("nested".to_owned(), value);
+        for k in keys {
+            s.mut_fields().insert(k.to_string(), string(k));
+        }
+        s
+    }
+
+    fn hash(m: &Message, unknown_fields: bool) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        canonical_hash(m, unknown_fields, &mut hasher).unwrap();
+        hasher.finish()
+    }
+
+    #[test]
+    fn map_entries_sorted() {
+        let keys = ["e", "b", "d", "a", "c", "f", "h", "g"];
+        let s = nested_struct(&keys);
+        let bytes = canonical_bytes(&s, true).unwrap();
+        assert_eq!(s, parse_from_bytes::<Struct>(&bytes).unwrap());
+
+        let mut reversed = keys.to_vec();
+        reversed.reverse();
+        let other = nested_struct(&reversed);
+        assert_eq!(bytes, canonical_bytes(&other, true).unwrap());
+        assert_eq!(hash(&s, true), hash(&other, true));
+
+        assert_eq!(vec!["a", "b", "c", "d", "e", "f", "g", "h", "nested"], parse_keys(&bytes));
+    }
+
+    fn parse_keys(bytes: &[u8]) -> Vec<String> {
+        let editor = WireEditor::new(bytes).unwrap();
+        editor
+            .fields()
+            .iter()
+            .map(|entry| {
+                let entry = WireEditor::new(length_delimited(entry).unwrap()).unwrap();
+                let key = length_delimited(entry.get(1).unwrap()).unwrap();
+                String::from_utf8(key.to_vec()).unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn field_order() {
+        // fields of `FileDescriptorProto` are not declared in order of numbers
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.mut_public_dependency().push(0);
+        file.mut_dependency().push("b.proto".to_owned());
+        file.mut_message_type().push(DescriptorProto::new());
+
+        let bytes = canonical_bytes(&file, true).unwrap();
+        let numbers: Vec<u32> =
+            WireEditor::new(&bytes).unwrap().fields().iter().map(|f| f.number).collect();
+        assert_eq!(vec![1, 3, 4, 10], numbers);
+        assert_eq!(file, parse_from_bytes::<FileDescriptorProto>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn unknown_fields() {
+        let mut a = string("x");
+        a.mut_unknown_fields().add_varint(20, 1);
+        a.mut_unknown_fields().add_fixed32(10, 2);
+        let mut b = string("x");
+        b.mut_unknown_fields().add_fixed32(10, 2);
+        b.mut_unknown_fields().add_varint(20, 1);
+
+        let bytes = canonical_bytes(&a, true).unwrap();
+        assert_eq!(bytes, canonical_bytes(&b, true).unwrap());
+        let numbers: Vec<u32> =
+            WireEditor::new(&bytes).unwrap().fields().iter().map(|f| f.number).collect();
+        assert_eq!(vec![3, 10, 20], numbers);
+
+        assert_eq!(string("x").write_to_bytes().unwrap(), canonical_bytes(&a, false).unwrap());
+        assert_eq!(hash(&string("x"), false), hash(&a, false));
+        assert!(hash(&string("x"), true) != hash(&a, true));
+
+        let mut hasher = DefaultHasher::new();
+        b.canonical_hash(&mut hasher).unwrap();
+        assert_eq!(hash(&a, true), hasher.finish());
+    }
+
+    #[cfg(feature = "with-groups")]
+    #[test]
+    fn unknown_groups() {
+        fn with_group(numbers: &[u32]) -> Value {
+            let mut nested = UnknownFields::new();
+            for &n in numbers {
+                nested.add_varint(n, n as u64);
+            }
+            let mut group = UnknownFields::new();
+            for &n in numbers {
+                group.add_fixed32(n, n);
+            }
+            group.add_group(30, nested);
+            let mut m = string("x");
+            m.mut_unknown_fields().add_group(20, group);
+            m
+        }
+
+        let a = with_group(&[5, 1, 4, 2, 3, 6, 8, 7]);
+        let b = with_group(&[8, 7, 6, 5, 4, 3, 2, 1]);
+        let bytes = canonical_bytes(&a, true).unwrap();
+        assert_eq!(bytes, canonical_bytes(&b, true).unwrap());
+        assert_eq!(hash(&a, true), hash(&b, true));
+        assert_eq!(a, parse_from_bytes::<Value>(&bytes).unwrap());
+
+        let group = WireEditor::new(&bytes).unwrap().get(20).unwrap().value;
+        let group = match group {
+            WireValue::Group(body) => body,
+            value => panic!("{:?}", value),
+        };
+        let numbers: Vec<u32> =
+            WireEditor::new(group).unwrap().fields().iter().map(|f| f.number).collect();
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8, 30], numbers);
+
+        assert_eq!(string("x").write_to_bytes().unwrap(), canonical_bytes(&a, false).unwrap());
+    }
+}
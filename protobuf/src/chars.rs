@@ -7,41 +7,84 @@ use bytes::Bytes;
 use clear::Clear;
 
 /// Thin wrapper around `Bytes` which guarantees that bytes are valid UTF-8 string.
+///
+/// Object created with `from_bytes_preserving` may hold invalid UTF-8:
+/// it dereferences to lossy text, and original bytes are available
+/// with `raw_bytes`.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub struct Chars(Bytes);
+pub struct Chars {
+    bytes: Bytes,
+    // lossy text when `bytes` is not valid UTF-8
+    lossy: Option<Box<str>>,
+}
 
 impl Chars {
     /// New empty object.
     pub fn new() -> Chars {
-        Chars(Bytes::new())
+        Chars {
+            bytes: Bytes::new(),
+            lossy: None,
+        }
     }
 
     /// Try convert from `Bytes`
     pub fn from_bytes(bytes: Bytes) -> Result<Chars, str::Utf8Error> {
         str::from_utf8(&bytes)?;
 
-        Ok(Chars(bytes))
+        Ok(Chars {
+            bytes: bytes,
+            lossy: None,
+        })
+    }
+
+    /// Convert from `Bytes` keeping invalid UTF-8 bytes as is.
+    pub fn from_bytes_preserving(bytes: Bytes) -> Chars {
+        let lossy = match str::from_utf8(&bytes) {
+            Ok(_) => None,
+            Err(_) => Some(String::from_utf8_lossy(&bytes).into_owned().into_boxed_str()),
+        };
+        Chars {
+            bytes: bytes,
+            lossy: lossy,
+        }
+    }
+
+    /// Original bytes, which are not valid UTF-8 if `is_valid_utf8` is false.
+    pub fn raw_bytes(&self) -> &[u8] {
+        &self.bytes
     }
 
-    /// Len in bytes.
+    /// False if object holds invalid UTF-8 preserved by `from_bytes_preserving`.
+    pub fn is_valid_utf8(&self) -> bool {
+        self.lossy.is_none()
+    }
+
+    /// Len in bytes of text, which may differ from len of `raw_bytes`
+    /// when invalid UTF-8 is replaced with `U+FFFD`.
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.deref().len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.bytes.is_empty()
     }
 }
 
 impl<'a> From<&'a str> for Chars {
     fn from(src: &'a str) -> Chars {
-        Chars(Bytes::from(src))
+        Chars {
+            bytes: Bytes::from(src),
+            lossy: None,
+        }
     }
 }
 
 impl From<String> for Chars {
     fn from(src: String) -> Chars {
-        Chars(Bytes::from(src))
+        Chars {
+            bytes: Bytes::from(src),
+            lossy: None,
+        }
     }
 }
 
@@ -55,13 +98,17 @@ impl Deref for Chars {
     type Target = str;
 
     fn deref(&self) -> &str {
-        unsafe { str::from_utf8_unchecked(&self.0) }
+        match self.lossy {
+            Some(ref lossy) => lossy,
+            None => unsafe { str::from_utf8_unchecked(&self.bytes) },
+        }
     }
 }
 
 impl Clear for Chars {
     fn clear(&mut self) {
-        self.0.clear();
+        self.bytes.clear();
+        self.lossy = None;
     }
 }
 
@@ -93,4 +140,17 @@ mod test {
         assert_eq!(format!("{}", string), format!("{}", chars));
         assert_eq!(format!("{:?}", string), format!("{:?}", chars));
     }
+
+    #[test]
+    fn test_from_bytes_preserving() {
+        let chars = Chars::from_bytes_preserving(b"a\xffb"[..].into());
+        assert!(!chars.is_valid_utf8());
+        assert_eq!("a\u{fffd}b", &*chars);
+        assert_eq!(5, chars.len());
+        assert_eq!(b"a\xffb", chars.raw_bytes());
+
+        let chars = Chars::from_bytes_preserving(b"ab"[..].into());
+        assert!(chars.is_valid_utf8());
+        assert_eq!("ab", &*chars);
+    }
 }
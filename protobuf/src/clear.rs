@@ -4,6 +4,18 @@ use bytes::Bytes;
 /// anything that can be cleared
 pub trait Clear {
     /// Clear this make, make it equivalent to newly created object.
+    ///
+    /// Allocated memory is retained where storage allows it:
+    /// capacity of strings, bytes, repeated and map fields is kept,
+    /// and unset singular message fields keep previously allocated message.
+    /// Values of oneof fields, of message fields generated with
+    /// `option_box_for_messages` or `lazy_field` option are dropped,
+    /// because their storage has no place for unset value.
+    /// Use `Message::reset` to free memory.
+    ///
+    /// Neither clearing nor creating a new message allocates: empty strings,
+    /// bytes, repeated and map fields have no heap buffer. Parsing into
+    /// a cleared message reuses retained buffers of string and bytes fields.
     fn clear(&mut self);
 }
 
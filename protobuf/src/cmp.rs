@@ -0,0 +1,591 @@
+//! Comparison of messages with relaxed rules.
+//!
+//! Generated `PartialEq` compares messages exactly. Tests often need
+//! to ignore some fields (e. g. timestamps), compare floating point
+//! values approximately or ignore order of repeated fields:
+//!
+//! ```ignore
+//! let options = CmpOptions::new()
+//!     .ignore_field("header.timestamp")
+//!     .float_margin(1e-9)
+//!     .unordered_repeated(true);
+//! assert!(protobuf::cmp::eq_with_options(&expected, &actual, &options));
+//! ```
+//!
+//! In tests `assert_messages_eq!` reports only differing fields
+//! instead of printing both messages:
+//!
+//! ```ignore
+//! assert_messages_eq!(expected, actual, &options);
+//! // panics with
+//! // messages are not equal:
+//! // modified: header.name: "a" -> "b"
+//! // added: items[2]: {id: 3}
+//! ```
+
+use std::fmt::Write;
+
+use core::Message;
+use text_format;
+use well_known_types::FieldMask;
+
+use reflect::diff::child_path;
+use reflect::diff::diff_unknown_fields;
+use reflect::diff::format_key;
+use reflect::FieldDiff;
+use reflect::ProtobufValue;
+use reflect::ProtobufValueBox;
+use reflect::ProtobufValueRef;
+use reflect::ReflectFieldRef;
+use reflect::descriptor_dyn;
+
+
+/// Options of message comparison.
+///
+/// Default options compare messages the same way as generated `PartialEq`.
+#[derive(Clone, Debug, Default)]
+pub struct CmpOptions {
+    ignored: Vec<String>,
+    float_margin: f64,
+    float_fraction: f64,
+    missing_as_default: bool,
+    unordered_repeated: bool,
+}
+
+impl CmpOptions {
+    pub fn new() -> CmpOptions {
+        Default::default()
+    }
+
+    /// Do not compare field with given path, like `message_type.name`.
+    ///
+    /// Path ignores the whole subtree: all elements of repeated field,
+    /// all entries of map and all fields of nested message.
+    pub fn ignore_field(mut self, path: &str) -> CmpOptions {
+        self.ignored.push(path.to_owned());
+        self
+    }
+
+    /// Do not compare fields listed in the mask.
+    pub fn ignore_fields(mut self, mask: &FieldMask) -> CmpOptions {
+        self.ignored.extend(mask.get_paths().iter().cloned());
+        self
+    }
+
+    /// Floating point values are equal if they differ by at most `margin`.
+    pub fn float_margin(mut self, margin: f64) -> CmpOptions {
+        self.float_margin = margin;
+        self
+    }
+
+    /// Floating point values are equal if they differ by at most `fraction`
+    /// of the larger absolute value. Can be combined with `float_margin`,
+    /// values are equal if either check passes.
+    pub fn float_fraction(mut self, fraction: f64) -> CmpOptions {
+        self.float_fraction = fraction;
+        self
+    }
+
+    /// Unset singular field is equal to field set to zero value,
+    /// empty string or bytes, or message equal to empty message.
+    ///
+    /// Useful to compare proto2 messages with proto3 semantics.
+    pub fn missing_as_default(mut self, missing_as_default: bool) -> CmpOptions {
+        self.missing_as_default = missing_as_default;
+        self
+    }
+
+    /// Compare repeated fields as multisets, ignoring order of elements.
+    ///
+    /// Elements are matched greedily, so with float tolerance result may
+    /// depend on the order when elements are approximately equal to each other.
+    pub fn unordered_repeated(mut self, unordered_repeated: bool) -> CmpOptions {
+        self.unordered_repeated = unordered_repeated;
+        self
+    }
+
+    fn is_ignored(&self, path: &str) -> bool {
+        self.ignored.iter().any(|p| {
+            path.starts_with(&p[..]) && match path[p.len()..].chars().next() {
+                None | Some('.') => true,
+                _ => false,
+            }
+        })
+    }
+
+    fn float_eq(&self, a: f64, b: f64) -> bool {
+        if a == b {
+            return true;
+        }
+        let d = (a - b).abs();
+        d <= self.float_margin || d <= self.float_fraction * a.abs().max(b.abs())
+    }
+}
+
+/// Check messages of the same type are equal with given options.
+///
+/// Unknown fields are compared exactly.
+///
+/// # Panics
+///
+/// If messages are of different types, or generated without reflection.
+pub fn eq_with_options(a: &Message, b: &Message, options: &CmpOptions) -> bool {
+    let mut d = Differences::new(options, false);
+    d.messages(&Path::root(), check_types(a, b), b);
+    d.equal
+}
+
+/// Fields which differ in messages with given options.
+///
+/// `left` of `FieldDiff` is the value in `expected`, `right` is the value in `actual`.
+/// When repeated fields are compared unordered, unmatched elements are reported
+/// with their indices in the message where they are found.
+///
+/// # Panics
+///
+/// If messages are of different types, or generated without reflection.
+pub fn differences(expected: &Message, actual: &Message, options: &CmpOptions) -> Vec<FieldDiff> {
+    let mut d = Differences::new(options, true);
+    d.messages(&Path::root(), check_types(expected, actual), actual);
+    d.diffs.unwrap()
+}
+
+/// Human readable report of differences, one line per field,
+/// empty if messages are equal.
+///
+/// Lines are `modified: <path>: <expected> -> <actual>`,
+/// `added: <path>: <actual>` for fields set only in `actual`
+/// and `deleted: <path>: <expected>` for fields set only in `expected`.
+pub fn report(expected: &Message, actual: &Message, options: &CmpOptions) -> String {
+    let mut r = String::new();
+    for d in differences(expected, actual, options) {
+        match (d.left, d.right) {
+            (Some(l), Some(a)) => writeln!(
+                r,
+                "modified: {}: {} -> {}",
+                d.path,
+                format_value(&l),
+                format_value(&a)
+            ),
+            (None, Some(a)) => writeln!(r, "added: {}: {}", d.path, format_value(&a)),
+            (Some(l), None) => writeln!(r, "deleted: {}: {}", d.path, format_value(&l)),
+            (None, None) => unreachable!("difference without values"),
+        }.unwrap();
+    }
+    r
+}
+
+/// Assert messages are equal with `cmp::eq_with_options`,
+/// and panic with `cmp::report` if they are not.
+///
+/// Messages are borrowed, options are passed by reference:
+/// `assert_messages_eq!(expected, actual, &options)`.
+#[macro_export]
+macro_rules! assert_messages_eq {
+    ($expected:expr, $actual:expr) => {
+        assert_messages_eq!($expected, $actual, &$crate::cmp::CmpOptions::new())
+    };
+    ($expected:expr, $actual:expr, $options:expr) => {{
+        let report = $crate::cmp::report(&$expected, &$actual, $options);
+        if !report.is_empty() {
+            panic!("messages are not equal:\n{}", report);
+        }
+    }};
+}
+
+fn check_types<'a>(a: &'a Message, b: &Message) -> &'a Message {
+    assert!(
+        Message::type_id(a) == Message::type_id(b),
+        "cannot compare messages of different types"
+    );
+    a
+}
+
+fn format_value(value: &ProtobufValueBox) -> String {
+    match *value {
+        ProtobufValueBox::U32(v) => v.to_string(),
+        ProtobufValueBox::U64(v) => v.to_string(),
+        ProtobufValueBox::I32(v) => v.to_string(),
+        ProtobufValueBox::I64(v) => v.to_string(),
+        ProtobufValueBox::F32(v) => v.to_string(),
+        ProtobufValueBox::F64(v) => v.to_string(),
+        ProtobufValueBox::Bool(v) => v.to_string(),
+        ProtobufValueBox::String(ref v) => text_format::quote_escape_bytes(v.as_bytes()),
+        ProtobufValueBox::Bytes(ref v) => text_format::quote_escape_bytes(v),
+        ProtobufValueBox::Enum(v) => v.name().to_owned(),
+        ProtobufValueBox::Message(ref m) => format!("{{{}}}", text_format::print_to_string(&**m)),
+        ProtobufValueBox::Record(ref record) => {
+            let mut fields: Vec<String> = record
+                .iter()
+                .map(|(name, v)| format!("{}: {}", name, format_value(v)))
+                .collect();
+            fields.sort();
+            format!("{{{}}}", fields.join(", "))
+        }
+        ProtobufValueBox::Repeated(ref values) => {
+            let values: Vec<String> = values.iter().map(format_value).collect();
+            format!("[{}]", values.join(", "))
+        }
+        ProtobufValueBox::Map(ref entries) => {
+            let entries: Vec<String> = entries
+                .iter()
+                .map(|&(ref k, ref v)| format!("{}: {}", format_value(k), format_value(v)))
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        }
+    }
+}
+
+// path of compared field: names only, matched against ignored fields,
+// and with indices and map keys, used in report
+struct Path {
+    names: String,
+    full: String,
+}
+
+impl Path {
+    fn root() -> Path {
+        Path {
+            names: String::new(),
+            full: String::new(),
+        }
+    }
+
+    fn field(&self, name: &str) -> Path {
+        Path {
+            names: child_path(&self.names, name),
+            full: child_path(&self.full, name),
+        }
+    }
+
+    fn index(&self, index: &str) -> Path {
+        Path {
+            names: self.names.clone(),
+            full: format!("{}[{}]", self.full, index),
+        }
+    }
+}
+
+struct Differences<'o> {
+    options: &'o CmpOptions,
+    // `None` when only equality is checked
+    diffs: Option<Vec<FieldDiff>>,
+    equal: bool,
+}
+
+impl<'o> Differences<'o> {
+    fn new(options: &'o CmpOptions, report: bool) -> Differences<'o> {
+        Differences {
+            options: options,
+            diffs: if report { Some(Vec::new()) } else { None },
+            equal: true,
+        }
+    }
+
+    // no need to continue when only equality is checked
+    fn done(&self) -> bool {
+        !self.equal && self.diffs.is_none()
+    }
+
+    fn push(&mut self, path: &Path, a: Option<ProtobufValueRef>, b: Option<ProtobufValueRef>) {
+        self.equal = false;
+        if let Some(ref mut diffs) = self.diffs {
+            diffs.push(FieldDiff {
+                path: path.full.clone(),
+                left: a.map(|v| v.to_box()),
+                right: b.map(|v| v.to_box()),
+            });
+        }
+    }
+
+    fn values_eq(&self, a: ProtobufValueRef, b: ProtobufValueRef) -> bool {
+        let mut d = Differences::new(self.options, false);
+        d.values(&Path::root(), a, b);
+        d.equal
+    }
+
+    fn messages(&mut self, path: &Path, a: &Message, b: &Message) {
+        for field in descriptor_dyn(a).fields() {
+            if self.done() {
+                return;
+            }
+            let path = path.field(field.name());
+            if self.options.is_ignored(&path.names) {
+                continue;
+            }
+            match (field.get_reflect(a), field.get_reflect(b)) {
+                (ReflectFieldRef::Optional(x), ReflectFieldRef::Optional(y)) => {
+                    self.optional(&path, x, y)
+                }
+                (ReflectFieldRef::Repeated(x), ReflectFieldRef::Repeated(y)) => {
+                    let x: Vec<&ProtobufValue> = x.reflect_iter().collect();
+                    let y: Vec<&ProtobufValue> = y.reflect_iter().collect();
+                    self.repeated(&path, &x, &y)
+                }
+                (ReflectFieldRef::Map(x), ReflectFieldRef::Map(y)) => {
+                    let x: Vec<(&ProtobufValue, &ProtobufValue)> = x.reflect_iter().collect();
+                    let y: Vec<(&ProtobufValue, &ProtobufValue)> = y.reflect_iter().collect();
+                    self.map(&path, &x, &y)
+                }
+                _ => unreachable!("same field of messages of the same type"),
+            }
+        }
+
+        if a.get_unknown_fields() != b.get_unknown_fields() {
+            self.equal = false;
+            if let Some(ref mut diffs) = self.diffs {
+                diff_unknown_fields(&path.full, a, b, diffs);
+            }
+        }
+    }
+
+    fn optional(&mut self, path: &Path, a: Option<ProtobufValueRef>, b: Option<ProtobufValueRef>) {
+        match (a, b) {
+            (None, None) => {}
+            (Some(a), Some(b)) => self.values(path, a, b),
+            (Some(a), None) => {
+                if !self.missing_as_default(path, &a) {
+                    self.push(path, Some(a), None);
+                }
+            }
+            (None, Some(b)) => {
+                if !self.missing_as_default(path, &b) {
+                    self.push(path, None, Some(b));
+                }
+            }
+        }
+    }
+
+    // field set only in one message is equal to unset field
+    fn missing_as_default(&self, path: &Path, value: &ProtobufValueRef) -> bool {
+        self.options.missing_as_default && self.is_default_value(path, value)
+    }
+
+    fn is_default_value(&self, path: &Path, value: &ProtobufValueRef) -> bool {
+        match *value {
+            ProtobufValueRef::Message(m) => {
+                let mut d = Differences::new(self.options, false);
+                d.messages(path, m, &*descriptor_dyn(m).new_instance());
+                d.equal
+            }
+            ProtobufValueRef::F32(v) => self.options.float_eq(v as f64, 0.0),
+            ProtobufValueRef::F64(v) => self.options.float_eq(v, 0.0),
+            _ => !value.is_non_zero(),
+        }
+    }
+
+    fn repeated(&mut self, path: &Path, a: &[&ProtobufValue], b: &[&ProtobufValue]) {
+        if !self.options.unordered_repeated {
+            for i in 0..a.len().max(b.len()) {
+                let path = path.index(&i.to_string());
+                match (a.get(i), b.get(i)) {
+                    (Some(x), Some(y)) => self.values(&path, x.as_ref(), y.as_ref()),
+                    (x, y) => self.push(&path, x.map(|v| v.as_ref()), y.map(|v| v.as_ref())),
+                }
+            }
+            return;
+        }
+
+        let mut matched = vec![false; b.len()];
+        for (i, x) in a.iter().enumerate() {
+            let found = (0..b.len())
+                .find(|&j| !matched[j] && self.values_eq(x.as_ref(), b[j].as_ref()));
+            match found {
+                Some(j) => matched[j] = true,
+                None => self.push(&path.index(&i.to_string()), Some(x.as_ref()), None),
+            }
+        }
+        for (j, y) in b.iter().enumerate() {
+            if !matched[j] {
+                self.push(&path.index(&j.to_string()), None, Some(y.as_ref()));
+            }
+        }
+    }
+
+    fn map(
+        &mut self,
+        path: &Path,
+        a: &[(&ProtobufValue, &ProtobufValue)],
+        b: &[(&ProtobufValue, &ProtobufValue)],
+    ) {
+        // keys are never floats, so they are compared exactly
+        for &(k, v) in a {
+            let path = path.index(&format_key(k.as_ref()));
+            match b.iter().find(|&&(y_k, _)| self.values_eq(k.as_ref(), y_k.as_ref())) {
+                Some(&(_, y_v)) => self.values(&path, v.as_ref(), y_v.as_ref()),
+                None => self.push(&path, Some(v.as_ref()), None),
+            }
+        }
+        for &(k, v) in b {
+            if !a.iter().any(|&(x_k, _)| self.values_eq(x_k.as_ref(), k.as_ref())) {
+                self.push(&path.index(&format_key(k.as_ref())), None, Some(v.as_ref()));
+            }
+        }
+    }
+
+    fn values(&mut self, path: &Path, a: ProtobufValueRef, b: ProtobufValueRef) {
+        let eq = match (&a, &b) {
+            (&ProtobufValueRef::Message(x), &ProtobufValueRef::Message(y)) => {
+                return self.messages(path, x, y);
+            }
+            (&ProtobufValueRef::F32(x), &ProtobufValueRef::F32(y)) => {
+                self.options.float_eq(x as f64, y as f64)
+            }
+            (&ProtobufValueRef::F64(x), &ProtobufValueRef::F64(y)) => self.options.float_eq(x, y),
+            (&ProtobufValueRef::U32(x), &ProtobufValueRef::U32(y)) => x == y,
+            (&ProtobufValueRef::U64(x), &ProtobufValueRef::U64(y)) => x == y,
+            (&ProtobufValueRef::I32(x), &ProtobufValueRef::I32(y)) => x == y,
+            (&ProtobufValueRef::I64(x), &ProtobufValueRef::I64(y)) => x == y,
+            (&ProtobufValueRef::Bool(x), &ProtobufValueRef::Bool(y)) => x == y,
+            (&ProtobufValueRef::String(x), &ProtobufValueRef::String(y)) => x == y,
+            (&ProtobufValueRef::Bytes(x), &ProtobufValueRef::Bytes(y)) => x == y,
+            (&ProtobufValueRef::Enum(x), &ProtobufValueRef::Enum(y)) => x.value() == y.value(),
+            _ => false,
+        };
+        if !eq {
+            self.push(path, Some(a), Some(b));
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FileDescriptorProto;
+    use well_known_types::ListValue;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    fn number(v: f64) -> Value {
+        let mut value = Value::new();
+        value.set_number_value(v);
+        value
+    }
+
+    fn list(values: Vec<f64>) -> Value {
+        let mut list = ListValue::new();
+        list.set_values(values.into_iter().map(number).collect());
+        let mut value = Value::new();
+        value.set_list_value(list);
+        value
+    }
+
+    #[test]
+    fn default_options() {
+        let options = CmpOptions::new();
+        assert!(eq_with_options(&list(vec![1.0, 2.0]), &list(vec![1.0, 2.0]), &options));
+        assert!(!eq_with_options(&list(vec![1.0, 2.0]), &list(vec![2.0, 1.0]), &options));
+        assert!(!eq_with_options(&number(1.0), &number(1.0 + 1e-12), &options));
+    }
+
+    #[test]
+    fn float_tolerance() {
+        let margin = CmpOptions::new().float_margin(1e-9);
+        assert!(eq_with_options(&number(1.0), &number(1.0 + 1e-12), &margin));
+        assert!(!eq_with_options(&number(1e12), &number(1e12 + 1.0), &margin));
+
+        let fraction = CmpOptions::new().float_fraction(1e-9);
+        assert!(eq_with_options(&number(1e12), &number(1e12 + 1.0), &fraction));
+        assert!(!eq_with_options(&number(1e-12), &number(2e-12), &fraction));
+    }
+
+    #[test]
+    fn unordered() {
+        let options = CmpOptions::new().unordered_repeated(true);
+        assert!(eq_with_options(&list(vec![1.0, 2.0, 1.0]), &list(vec![2.0, 1.0, 1.0]), &options));
+        assert!(!eq_with_options(&list(vec![1.0, 2.0, 2.0]), &list(vec![2.0, 1.0, 1.0]), &options));
+        assert!(!eq_with_options(&list(vec![1.0]), &list(vec![1.0, 1.0]), &options));
+    }
+
+    #[test]
+    fn map() {
+        let mut a = Struct::new();
+        a.mut_fields().insert("x".to_owned(), number(1.0));
+        a.mut_fields().insert("y".to_owned(), list(vec![1.0, 2.0]));
+        let mut b = a.clone();
+        b.mut_fields().insert("y".to_owned(), list(vec![2.0, 1.0 + 1e-12]));
+
+        assert!(!eq_with_options(&a, &b, &CmpOptions::new()));
+        let options = CmpOptions::new().unordered_repeated(true).float_margin(1e-9);
+        assert!(eq_with_options(&a, &b, &options));
+
+        b.mut_fields().remove("x");
+        b.mut_fields().insert("z".to_owned(), number(1.0));
+        assert!(!eq_with_options(&a, &b, &options));
+    }
+
+    #[test]
+    fn ignore_and_missing() {
+        let mut a = FileDescriptorProto::new();
+        a.set_name("a.proto".to_owned());
+        let mut b = a.clone();
+        b.set_package(String::new());
+        b.mut_options().set_java_multiple_files(false);
+
+        assert!(!eq_with_options(&a, &b, &CmpOptions::new()));
+        assert!(eq_with_options(&a, &b, &CmpOptions::new().missing_as_default(true)));
+
+        b.mut_options().set_java_package("x".to_owned());
+        assert!(!eq_with_options(&a, &b, &CmpOptions::new().missing_as_default(true)));
+        let options = CmpOptions::new().missing_as_default(true).ignore_field("options");
+        assert!(eq_with_options(&a, &b, &options));
+
+        let mut mask = FieldMask::new();
+        mask.mut_paths().push("options.java_package".to_owned());
+        let options = CmpOptions::new().missing_as_default(true).ignore_fields(&mask);
+        assert!(eq_with_options(&a, &b, &options));
+        // `name` is not covered by `nam`
+        b.set_name("b.proto".to_owned());
+        assert!(!eq_with_options(&a, &b, &options.ignore_field("nam")));
+    }
+
+    #[test]
+    fn report_lines() {
+        let mut expected = Struct::new();
+        expected.mut_fields().insert("a".to_owned(), list(vec![1.0, 2.0]));
+        expected.mut_fields().insert("b".to_owned(), number(1.0));
+        let mut actual = Struct::new();
+        actual.mut_fields().insert("a".to_owned(), list(vec![1.0, 3.0, 4.0]));
+        actual.mut_fields().insert("c".to_owned(), Value::new());
+
+        let mut lines: Vec<String> = report(&expected, &actual, &CmpOptions::new())
+            .lines()
+            .map(|l| l.to_owned())
+            .collect();
+        lines.sort();
+        assert_eq!(
+            vec![
+                "added: fields[\"a\"].list_value.values[2]: {number_value: 4}",
+                "added: fields[\"c\"]: {}",
+                "deleted: fields[\"b\"]: {number_value: 1}",
+                "modified: fields[\"a\"].list_value.values[1].number_value: 2 -> 3",
+            ],
+            lines
+        );
+        assert_eq!("", report(&expected, &expected, &CmpOptions::new()));
+    }
+
+    #[test]
+    fn report_unordered() {
+        let options = CmpOptions::new().unordered_repeated(true);
+        let diffs = differences(&list(vec![1.0, 2.0, 3.0]), &list(vec![3.0, 4.0, 1.0]), &options);
+        let paths: Vec<&str> = diffs.iter().map(|d| &d.path[..]).collect();
+        assert_eq!(vec!["list_value.values[1]", "list_value.values[1]"], paths);
+        assert!(diffs[0].right.is_none());
+        assert!(diffs[1].left.is_none());
+    }
+
+    #[test]
+    fn assert_macro() {
+        assert_messages_eq!(number(1.0), number(1.0));
+        let options = CmpOptions::new().float_margin(1e-9);
+        assert_messages_eq!(number(1.0), number(1.0 + 1e-12), &options);
+    }
+
+    #[test]
+    #[should_panic(expected = "modified: number_value: 1 -> 2")]
+    fn assert_macro_fails() {
+        assert_messages_eq!(number(1.0), number(2.0));
+    }
+}
@@ -17,20 +17,42 @@ pub struct GenResult {
 
 pub fn plugin_main<F>(gen: F)
     where F : Fn(&[FileDescriptorProto], &[String]) -> Vec<GenResult>
+{
+    plugin_main_checked(|file_descriptors, files_to_generate| {
+        Ok(gen(file_descriptors, files_to_generate))
+    })
+}
+
+/// Like `plugin_main`, but generator error is reported to `protoc`,
+/// which prints it and exits with error.
+pub fn plugin_main_checked<F>(gen: F)
+    where F : Fn(&[FileDescriptorProto], &[String]) -> Result<Vec<GenResult>, String>
+{
+    plugin_main_with_parameter(|file_descriptors, files_to_generate, _parameter| {
+        gen(file_descriptors, files_to_generate)
+    })
+}
+
+/// Like `plugin_main_checked`, but generator also gets plugin parameter,
+/// e. g. `a=b,c` for `protoc --rust_out=a=b,c:out`, or empty string.
+pub fn plugin_main_with_parameter<F>(gen: F)
+    where F : Fn(&[FileDescriptorProto], &[String], &str) -> Result<Vec<GenResult>, String>
 {
     let req = parse_from_reader::<CodeGeneratorRequest>(&mut stdin()).unwrap();
-    let result = gen(req.get_proto_file(), req.get_file_to_generate());
     let mut resp = CodeGeneratorResponse::new();
-    resp.set_file(
-        result
-            .iter()
-            .map(|file| {
-                let mut r = CodeGeneratorResponse_File::new();
-                r.set_name(file.name.to_string());
-                r.set_content(str::from_utf8(file.content.as_ref()).unwrap().to_string());
-                r
-            })
-            .collect(),
-    );
+    match gen(req.get_proto_file(), req.get_file_to_generate(), req.get_parameter()) {
+        Ok(result) => resp.set_file(
+            result
+                .iter()
+                .map(|file| {
+                    let mut r = CodeGeneratorResponse_File::new();
+                    r.set_name(file.name.to_string());
+                    r.set_content(str::from_utf8(file.content.as_ref()).unwrap().to_string());
+                    r
+                })
+                .collect(),
+        ),
+        Err(error) => resp.set_error(error),
+    }
     resp.write_to_writer(&mut stdout()).unwrap();
 }
@@ -0,0 +1,114 @@
+//! Build serialized message from parts.
+
+use std::marker::PhantomData;
+
+use core::Message;
+use error::ProtobufResult;
+use stream::CodedOutputStream;
+
+
+/// Builder of serialized message from serialized parts.
+///
+/// Concatenation of serialized messages is a valid serialization of
+/// the merged message: singular fields of later parts override earlier,
+/// repeated fields are appended, nested messages are merged.
+/// So parts can be joined without parsing them, e. g. to merge
+/// responses of several shards.
+///
+/// Parts are not validated: if a byte chunk is not a valid serialized `M`,
+/// error is reported when the result is parsed.
+pub struct ConcatBuilder<M> {
+    buf: Vec<u8>,
+    _marker: PhantomData<M>,
+}
+
+impl<M : Message> Default for ConcatBuilder<M> {
+    fn default() -> ConcatBuilder<M> {
+        ConcatBuilder::new()
+    }
+}
+
+impl<M : Message> ConcatBuilder<M> {
+    /// New empty builder.
+    pub fn new() -> ConcatBuilder<M> {
+        ConcatBuilder::with_capacity(0)
+    }
+
+    /// New empty builder with buffer preallocated for `capacity` bytes.
+    pub fn with_capacity(capacity: usize) -> ConcatBuilder<M> {
+        ConcatBuilder {
+            buf: Vec::with_capacity(capacity),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Append serialized message.
+    ///
+    /// Required fields are not checked, because they may be set by other parts.
+    pub fn push_message(&mut self, message: &M) -> ProtobufResult<()> {
+        message.compute_size();
+        let mut os = CodedOutputStream::vec(&mut self.buf);
+        message.write_to_with_cached_sizes(&mut os)?;
+        os.flush()?;
+        Ok(())
+    }
+
+    /// Append pre-serialized message bytes.
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    /// Number of bytes appended so far.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Nothing or only empty parts appended.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Serialized merged message.
+    pub fn finish(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::parse_from_bytes;
+    use descriptor::FileDescriptorProto;
+    use descriptor::FileOptions;
+
+    #[test]
+    fn concat() {
+        let mut first = FileDescriptorProto::new();
+        first.set_name("a.proto".to_owned());
+        first.mut_dependency().push("b.proto".to_owned());
+        first.mut_options().set_java_package("a".to_owned());
+
+        let mut second = FileDescriptorProto::new();
+        second.set_name("c.proto".to_owned());
+        second.mut_dependency().push("d.proto".to_owned());
+        let second_bytes = second.write_to_bytes().unwrap();
+
+        let mut third = FileDescriptorProto::new();
+        let mut options = FileOptions::new();
+        options.set_java_outer_classname("A".to_owned());
+        third.set_options(options);
+
+        let mut builder = ConcatBuilder::<FileDescriptorProto>::new();
+        assert!(builder.is_empty());
+        builder.push_message(&first).unwrap();
+        builder.push_bytes(&second_bytes);
+        builder.push_message(&third).unwrap();
+        let merged: FileDescriptorProto = parse_from_bytes(&builder.finish()).unwrap();
+
+        assert_eq!("c.proto", merged.get_name());
+        assert_eq!(&["b.proto".to_owned(), "d.proto".to_owned()], merged.get_dependency());
+        assert_eq!("a", merged.get_options().get_java_package());
+        assert_eq!("A", merged.get_options().get_java_outer_classname());
+    }
+}
@@ -0,0 +1,31 @@
+/// Conversion between Rust type of field given with `rust_field_type` option
+/// and field value type `V`, e. g. `u64` for `uint64` field.
+///
+/// ```ignore
+/// // message User { optional uint64 id = 1 [(rustproto.rust_field_type) = "UserId"]; }
+/// #[derive(Clone, Copy)]
+/// struct UserId(u64);
+///
+/// impl ProtobufConvert<u64> for UserId {
+///     fn from_protobuf(value: u64) -> UserId {
+///         UserId(value)
+///     }
+///
+///     fn to_protobuf(&self) -> u64 {
+///         self.0
+///     }
+/// }
+///
+/// user.set_id(UserId(10));
+/// let id: UserId = user.get_id();
+/// ```
+///
+/// Field value is stored as `V`, so conversion is done on each access.
+pub trait ProtobufConvert<V> {
+    /// Convert field value, called by `get_` accessor,
+    /// including when the field is not set and getter returns default value.
+    fn from_protobuf(value: V) -> Self;
+
+    /// Convert to field value, called by `set_` accessor.
+    fn to_protobuf(&self) -> V;
+}
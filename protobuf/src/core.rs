@@ -1,31 +1,61 @@
 use std::any::Any;
 use std::any::TypeId;
 use std::fmt;
+use std::hash::Hasher;
 use std::io::Read;
 use std::io::Write;
 
 #[cfg(feature = "bytes")]
 use bytes::Bytes;
+#[cfg(feature = "bytes")]
+use bytes::BytesMut;
 
+use canonical;
 use clear::Clear;
 use reflect::MessageDescriptor;
 use reflect::EnumDescriptor;
 use reflect::EnumValueDescriptor;
+use reflect::merge_dyn;
 use unknown::UnknownFields;
+use size_cache::SizeCache;
 use stream::WithCodedInputStream;
 use stream::WithCodedOutputStream;
 use stream::CodedInputStream;
 use stream::CodedOutputStream;
 use stream::with_coded_output_stream_to_bytes;
+use stream::wire_format::WireTypeStartGroup;
+use stream::wire_format::WireTypeEndGroup;
 use error::ProtobufError;
+use error::WireError;
 use error::ProtobufResult;
+use parse_options::ParseOptions;
+use rt::compute_raw_varint32_size;
 
 
 /// Trait implemented for all generated structs for protobuf messages.
 /// Also, generated messages implement `Clone + Default + PartialEq`
 pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
+    /// Message descriptor for reflection of trait objects, `None` if message
+    /// is generated without descriptor (for `LITE_RUNTIME` or with `embed_descriptor = false`).
+    ///
+    /// Use `MessageFull::message_descriptor` if message type is known.
+    ///
+    /// Default implementation calls `descriptor`, which is implemented by code
+    /// generated by earlier versions; other implementations must override this function.
+    #[allow(deprecated)]
+    fn descriptor_dyn(&self) -> Option<&'static MessageDescriptor> {
+        Some(self.descriptor())
+    }
+
     /// Message descriptor for this message, used for reflection.
-    fn descriptor(&self) -> &'static MessageDescriptor;
+    ///
+    /// # Panics
+    ///
+    /// If message is generated without descriptor.
+    #[deprecated(note = "use `MessageFull::message_descriptor` or `Message::descriptor_dyn`")]
+    fn descriptor(&self) -> &'static MessageDescriptor {
+        self.descriptor_dyn().expect("message is generated without descriptor")
+    }
 
     /// True iff all required fields are initialized.
     /// Always returns `true` for protobuf 3.
@@ -46,6 +76,44 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
     /// Get size previously computed by `compute_size`.
     fn get_cached_size(&self) -> u32;
 
+    /// Compute size of this message and all nested messages,
+    /// storing them in `sizes` instead of `cached_size` fields.
+    ///
+    /// Size of this message is stored before sizes of nested messages.
+    fn compute_size_with_cache(&self, sizes: &mut SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let size = self.compute_size();
+        sizes.set_slot(slot, size);
+        size
+    }
+
+    /// Write message to the stream.
+    ///
+    /// Sizes of nested messages must be computed by `compute_size_with_cache`
+    /// prior to this call, and size of this message must be already taken
+    /// from `sizes`.
+    fn write_to_with_size_cache(
+        &self,
+        os: &mut CodedOutputStream,
+        _sizes: &mut SizeCache,
+    ) -> ProtobufResult<()> {
+        self.write_to_with_cached_sizes(os)
+    }
+
+    /// Upper bound of serialized size of this message, not less than
+    /// the size returned by `compute_size`.
+    ///
+    /// Generated code computes it from cheap per-field bounds: fixed
+    /// maximum sizes of scalars and lengths of strings, bytes and
+    /// repeated fields, without computing varint sizes or caching sizes,
+    /// so it can be used to check if a message fits a frame before
+    /// serializing it. Map fields are computed exactly.
+    ///
+    /// Default implementation returns `compute_size`.
+    fn size_hint_upper_bound(&self) -> u64 {
+        self.compute_size() as u64
+    }
+
     /// Write the message to the stream.
     ///
     /// Results in error if message is not fully initialized.
@@ -62,6 +130,23 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
         Ok(())
     }
 
+    /// Write the message to the stream without touching `cached_size` fields.
+    ///
+    /// Sizes are computed into caller-owned `sizes`, so the same message
+    /// can be serialized from several threads concurrently.
+    fn write_to_with_sizes(
+        &self,
+        os: &mut CodedOutputStream,
+        sizes: &mut SizeCache,
+    ) -> ProtobufResult<()> {
+        self.check_initialized()?;
+
+        sizes.clear();
+        self.compute_size_with_cache(sizes);
+        sizes.take_size();
+        self.write_to_with_size_cache(os, sizes)
+    }
+
     /// Write the message to the stream prepending the message with message length
     /// encoded as varint.
     fn write_length_delimited_to(&self, os: &mut CodedOutputStream) -> ProtobufResult<()> {
@@ -74,11 +159,17 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
         Ok(())
     }
 
-    /// Write the message to the vec, prepend the message with message length
+    /// Append the message to the vec, prepend the message with message length
     /// encoded as varint.
+    ///
+    /// Vec is grown at most once, so a buffer reused for many messages
+    /// is not reallocated once it is large enough.
     fn write_length_delimited_to_vec(&self, vec: &mut Vec<u8>) -> ProtobufResult<()> {
+        let size = self.compute_size();
+        vec.reserve((compute_raw_varint32_size(size) + size) as usize);
         let mut os = CodedOutputStream::vec(vec);
-        self.write_length_delimited_to(&mut os)?;
+        os.write_raw_varint32(size)?;
+        self.write_to_with_cached_sizes(&mut os)?;
         os.flush()?;
         Ok(())
     }
@@ -86,13 +177,50 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
     /// Update this message object with fields read from given stream.
     fn merge_from_bytes(&mut self, bytes: &[u8]) -> ProtobufResult<()> {
         let mut is = CodedInputStream::from_bytes(bytes);
-        self.merge_from(&mut is)
+        self.merge_from(&mut is).map_err(|e| is.locate_error(e))
+    }
+
+    /// Update this message object with fields read from given bytes
+    /// and return the updated message.
+    fn merged_from_bytes(mut self, bytes: &[u8]) -> ProtobufResult<Self>
+    where
+        Self : Sized,
+    {
+        self.merge_from_bytes(bytes)?;
+        Ok(self)
+    }
+
+    /// Merge fields of other message into this message, same as merging
+    /// serialized `other` with `merge_from`: singular fields set in `other`
+    /// are overwritten, repeated fields are appended, map entries are inserted,
+    /// nested messages are merged recursively (except oneof members, which
+    /// are replaced like in `merge_from`), unknown fields are appended.
+    ///
+    /// Generated code merges fields without serialization,
+    /// default implementation calls `reflect::merge_dyn`.
+    fn merge_from_message(&mut self, other: &Self)
+    where
+        Self : Sized,
+    {
+        merge_dyn(self, other)
+    }
+
+    /// Parse message from byte array with given options.
+    fn parse(bytes: &[u8], options: &ParseOptions) -> ProtobufResult<Self>
+    where
+        Self : Sized,
+    {
+        options.parse_from_bytes(bytes)
     }
 
     /// Check if all required fields of this object are initialized.
     fn check_initialized(&self) -> ProtobufResult<()> {
         if !self.is_initialized() {
-            Err(ProtobufError::message_not_initialized(self.descriptor().name()))
+            let name = match self.descriptor_dyn() {
+                Some(d) => d.name(),
+                None => "<message generated without descriptor>",
+            };
+            Err(ProtobufError::message_not_initialized(name))
         } else {
             Ok(())
         }
@@ -103,9 +231,46 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
         w.with_coded_output_stream(|os| self.write_to(os))
     }
 
-    /// Write the message to bytes vec.
+    /// Append the message to bytes vec.
+    ///
+    /// Vec is grown at most once, so a buffer reused for many messages
+    /// (e. g. cleared after each message is sent) is not reallocated
+    /// once it is large enough.
     fn write_to_vec(&self, v: &mut Vec<u8>) -> ProtobufResult<()> {
-        v.with_coded_output_stream(|os| self.write_to(os))
+        self.check_initialized()?;
+
+        let size = self.compute_size();
+        v.reserve(size as usize);
+        v.with_coded_output_stream(|os| self.write_to_with_cached_sizes(os))
+    }
+
+    /// Append the message to `BytesMut`.
+    ///
+    /// Like `write_to_vec`, but written bytes can be frozen into `Bytes`
+    /// (e. g. for passing to tokio network code) without copying.
+    #[cfg(feature = "bytes")]
+    fn write_to_carllerche_bytes_mut(&self, bytes: &mut BytesMut) -> ProtobufResult<()> {
+        self.check_initialized()?;
+
+        let size = self.compute_size();
+        bytes.reserve(size as usize);
+        bytes.with_coded_output_stream(|os| self.write_to_with_cached_sizes(os))
+    }
+
+    /// Append the message to `BytesMut`, prepend the message with message length
+    /// encoded as varint.
+    #[cfg(feature = "bytes")]
+    fn write_length_delimited_to_carllerche_bytes_mut(
+        &self,
+        bytes: &mut BytesMut,
+    ) -> ProtobufResult<()> {
+        let size = self.compute_size();
+        bytes.reserve((compute_raw_varint32_size(size) + size) as usize);
+        let mut os = CodedOutputStream::carllerche_bytes_mut(bytes);
+        os.write_raw_varint32(size)?;
+        self.write_to_with_cached_sizes(&mut os)?;
+        os.flush()?;
+        Ok(())
     }
 
     /// Write the message to bytes vec.
@@ -126,6 +291,41 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
         Ok(v)
     }
 
+    /// Write the message to bytes vec, even if it is not fully initialized.
+    ///
+    /// Unlike `write_to_bytes`, required fields are not checked, and writing
+    /// to a vec of size computed by `compute_size` cannot fail otherwise,
+    /// so this function returns bytes directly.
+    fn to_bytes(&self) -> Vec<u8> {
+        let size = self.compute_size() as usize;
+        let mut v = Vec::with_capacity(size);
+        v.with_coded_output_stream(|os| self.write_to_with_cached_sizes(os))
+            .expect("serialization to vec cannot fail");
+        v
+    }
+
+    /// Write the message to bytes vec without touching `cached_size` fields.
+    ///
+    /// See `write_to_with_sizes`.
+    fn write_to_bytes_with_sizes(&self, sizes: &mut SizeCache) -> ProtobufResult<Vec<u8>> {
+        self.check_initialized()?;
+
+        sizes.clear();
+        let size = self.compute_size_with_cache(sizes) as usize;
+        sizes.take_size();
+        let mut v = Vec::with_capacity(size);
+        // skip zerofill
+        unsafe {
+            v.set_len(size);
+        }
+        {
+            let mut os = CodedOutputStream::bytes(&mut v);
+            self.write_to_with_size_cache(&mut os, sizes)?;
+            os.check_eof();
+        }
+        Ok(v)
+    }
+
     /// Write the message to the writer, prepend the message with message length
     /// encoded as varint.
     fn write_length_delimited_to_writer(&self, w: &mut Write) -> ProtobufResult<()> {
@@ -138,6 +338,16 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
         with_coded_output_stream_to_bytes(|os| self.write_length_delimited_to(os))
     }
 
+    /// Feed canonical serialization of the message including unknown fields to hasher.
+    ///
+    /// Equal messages produce the same hash regardless of order of map entries,
+    /// see `canonical` module.
+    fn canonical_hash<H : Hasher>(&self, state: &mut H) -> ProtobufResult<()>
+        where Self : Sized
+    {
+        canonical::canonical_hash(self, true, state)
+    }
+
     /// Get a reference to unknown fields.
     fn get_unknown_fields<'s>(&'s self) -> &'s UnknownFields;
     /// Get a mutable reference to unknown fields.
@@ -167,10 +377,24 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
     // }
 
     /// Create an empty message object.
+    ///
+    /// Generated `new` and `Default::default` do not allocate: repeated, map,
+    /// string and bytes fields are empty, message and oneof fields are unset,
+    /// and storage for unknown fields is allocated when first unknown field is added.
+    /// proto2 `[default = ...]` values are returned by getters without being stored.
     fn new() -> Self where Self : Sized;
 
+    /// Reset message to newly created state, freeing memory held by fields.
+    ///
+    /// Unlike `Clear::clear`, which keeps allocated memory to reuse it
+    /// when the message is filled again.
+    fn reset(&mut self) where Self : Sized {
+        *self = Self::new();
+    }
+
     /// Get message descriptor for message type.
     // http://stackoverflow.com/q/20342436/15018
+    #[deprecated(note = "use `MessageFull::type_descriptor`")]
     fn descriptor_static(_: Option<Self>) -> &'static MessageDescriptor
         where Self : Sized
     {
@@ -185,6 +409,25 @@ pub trait Message: fmt::Debug + Clear + Any + Send + Sync {
         where Self : Sized;
 }
 
+/// Message generated with descriptor, i. e. not for `LITE_RUNTIME`
+/// and not with `embed_descriptor = false`.
+///
+/// Reflection by message type is available only for messages implementing
+/// this trait, so reflection of message generated without descriptor
+/// is a compile-time error.
+pub trait MessageFull : Message {
+    /// Get message descriptor for message type.
+    fn type_descriptor() -> &'static MessageDescriptor
+        where Self : Sized;
+
+    /// Message descriptor for this message, used for reflection.
+    fn message_descriptor(&self) -> &'static MessageDescriptor
+        where Self : Sized
+    {
+        Self::type_descriptor()
+    }
+}
+
 pub fn message_down_cast<'a, M : Message + 'a>(m: &'a Message) -> &'a M {
     m.as_any().downcast_ref::<M>().unwrap()
 }
@@ -204,27 +447,54 @@ pub trait ProtobufEnum: Eq + Sized + Copy + 'static {
         panic!();
     }
 
+    /// Enum descriptor for generic code, `None` if enum is generated without descriptor.
+    ///
+    /// Use `ProtobufEnumFull::type_descriptor` if enum type is known.
+    ///
+    /// Default implementation calls `enum_descriptor_static`, which is implemented by code
+    /// generated by earlier versions; other implementations must override this function.
+    #[allow(deprecated)]
+    fn enum_descriptor_dyn() -> Option<&'static EnumDescriptor> {
+        Some(ProtobufEnum::enum_descriptor_static(None::<Self>))
+    }
+
     /// Get enum value descriptor.
+    #[deprecated(note = "use `ProtobufEnumFull::value_descriptor`")]
+    #[allow(deprecated)]
     fn descriptor(&self) -> &'static EnumValueDescriptor {
-        self.enum_descriptor().value_by_number(self.value())
+        ProtobufEnum::enum_descriptor(self).value_by_number(self.value())
     }
 
     /// Get enum descriptor.
+    #[deprecated(note = "use `ProtobufEnumFull::type_descriptor`")]
+    #[allow(deprecated)]
     fn enum_descriptor(&self) -> &'static EnumDescriptor {
         ProtobufEnum::enum_descriptor_static(None::<Self>)
     }
 
     /// Get enum descriptor by type.
     // http://stackoverflow.com/q/20342436/15018
+    #[deprecated(note = "use `ProtobufEnumFull::type_descriptor`")]
     fn enum_descriptor_static(_: Option<Self>) -> &'static EnumDescriptor {
-        panic!();
+        panic!("enum is generated without descriptor");
+    }
+}
+
+/// Enum generated with descriptor, see `MessageFull`.
+pub trait ProtobufEnumFull : ProtobufEnum {
+    /// Get enum descriptor for enum type.
+    fn type_descriptor() -> &'static EnumDescriptor;
+
+    /// Get enum value descriptor.
+    fn value_descriptor(&self) -> &'static EnumValueDescriptor {
+        Self::type_descriptor().value_by_number(self.value())
     }
 }
 
 /// Parse message from stream.
 pub fn parse_from<M : Message>(is: &mut CodedInputStream) -> ProtobufResult<M> {
     let mut r: M = Message::new();
-    r.merge_from(is)?;
+    r.merge_from(is).map_err(|e| is.locate_error(e))?;
     r.check_initialized()?;
     Ok(r)
 }
@@ -240,6 +510,51 @@ pub fn parse_from_bytes<M : Message>(bytes: &[u8]) -> ProtobufResult<M> {
     bytes.with_coded_input_stream(|is| parse_from::<M>(is))
 }
 
+/// Parse only fields with given numbers from byte array.
+///
+/// Other fields are skipped without decoding or allocation,
+/// so reading a few fields of a large message is cheap.
+/// Required fields are not checked, because they may be not requested.
+pub fn parse_partial_from_bytes<M : Message>(
+    bytes: &[u8],
+    field_numbers: &[u32],
+) -> ProtobufResult<M> {
+    let mut r: M = Message::new();
+    let mut is = CodedInputStream::from_bytes(bytes);
+    // start of current run of requested fields
+    let mut run_start = None;
+    loop {
+        is.skip_event_payload()?;
+        let start = is.pos() as usize;
+        let event = match is.read_event()? {
+            Some(event) => event,
+            None => break,
+        };
+        let requested = field_numbers.contains(&event.field_number);
+        if event.wire_type == WireTypeStartGroup {
+            // `read_event` does not read fields of a group, so skip them here;
+            // if the group is requested, generated code stores it in unknown fields
+            is.skip_group(event.field_number)?;
+        } else if event.wire_type == WireTypeEndGroup {
+            // rejected by generated code too
+            return Err(ProtobufError::WireError(WireError::UnexpectedWireType(event.wire_type)));
+        }
+        if requested {
+            if run_start.is_none() {
+                run_start = Some(start);
+            }
+            continue;
+        }
+        if let Some(run_start) = run_start.take() {
+            r.merge_from_bytes(&bytes[run_start..start])?;
+        }
+    }
+    if let Some(run_start) = run_start {
+        r.merge_from_bytes(&bytes[run_start..])?;
+    }
+    Ok(r)
+}
+
 /// Parse message from `Bytes` object.
 /// Resulting message may share references to the passed bytes object.
 #[cfg(feature = "bytes")]
@@ -274,3 +589,138 @@ pub fn parse_length_delimited_from_bytes<M : Message>(
 ) -> ProtobufResult<M> {
     bytes.with_coded_input_stream(|is| is.read_message::<M>())
 }
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::DescriptorProto;
+    use descriptor::FileDescriptorProto;
+
+    #[test]
+    fn partial() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.set_package("p".to_owned());
+        file.mut_dependency().push("b.proto".to_owned());
+        file.mut_message_type().push(DescriptorProto::new());
+        file.mut_dependency().push("c.proto".to_owned());
+        file.set_syntax("proto3".to_owned());
+        let mut bytes = file.write_to_bytes().unwrap();
+        // unknown group field 100 containing varint field 1
+        bytes.extend_from_slice(&[0xa3, 0x06, 0x08, 0x01, 0xa4, 0x06]);
+
+        // name = 1, dependency = 3, syntax = 12
+        let partial: FileDescriptorProto = parse_partial_from_bytes(&bytes, &[1, 3, 12]).unwrap();
+        let mut expected = file.clone();
+        expected.clear_package();
+        expected.clear_message_type();
+        assert_eq!(expected, partial);
+
+        let partial: FileDescriptorProto = parse_partial_from_bytes(&bytes, &[]).unwrap();
+        assert_eq!(FileDescriptorProto::new(), partial);
+    }
+
+    #[test]
+    fn size_hint_upper_bound() {
+        use descriptor::FieldDescriptorProto_Label;
+        use descriptor::SourceCodeInfo_Location;
+        use well_known_types::Value;
+
+        let mut file = FileDescriptorProto::new();
+        assert_eq!(0, file.size_hint_upper_bound());
+
+        file.set_name("a.proto".to_owned());
+        file.mut_public_dependency().push(-1);
+        let mut message = DescriptorProto::new();
+        message.set_name("M".to_owned());
+        message.mut_field().push(Default::default());
+        message.mut_field()[0].set_label(FieldDescriptorProto_Label::LABEL_REPEATED);
+        file.mut_message_type().push(message);
+        let mut location = SourceCodeInfo_Location::new();
+        location.set_path(vec![4, 0, 2, 0]);
+        file.mut_source_code_info().mut_location().push(location);
+        let bound = file.size_hint_upper_bound();
+        assert!(bound >= file.compute_size() as u64, "{}", bound);
+
+        let mut value = Value::new();
+        value.set_number_value(1.0);
+        assert_eq!(value.compute_size() as u64, value.size_hint_upper_bound());
+        value.mut_list_value().mut_values().push(Value::new());
+        assert!(value.size_hint_upper_bound() >= value.compute_size() as u64);
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        use std::convert::TryFrom;
+
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        let bytes = file.write_to_bytes().unwrap();
+
+        fn parse<M : for<'a> TryFrom<&'a [u8], Error = ProtobufError>>(bytes: &[u8])
+            -> ProtobufResult<M>
+        {
+            M::try_from(bytes)
+        }
+        assert_eq!(file, parse::<FileDescriptorProto>(&bytes).unwrap());
+        assert!(parse::<FileDescriptorProto>(&[0x0a]).is_err());
+    }
+
+    #[test]
+    fn to_bytes() {
+        use descriptor::UninterpretedOption_NamePart;
+
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        assert_eq!(file.write_to_bytes().unwrap(), file.to_bytes());
+
+        // required `is_extension` field is not set
+        let mut part = UninterpretedOption_NamePart::new();
+        part.set_name_part("a".to_owned());
+        assert!(part.write_to_bytes().is_err());
+        let parsed = UninterpretedOption_NamePart::new().merged_from_bytes(&part.to_bytes());
+        assert_eq!(part, parsed.unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn write_to_carllerche_bytes_mut() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.mut_dependency().push("b".repeat(100));
+
+        let mut buf = BytesMut::new();
+        file.write_to_carllerche_bytes_mut(&mut buf).unwrap();
+        let first = buf.take().freeze();
+        file.write_length_delimited_to_carllerche_bytes_mut(&mut buf).unwrap();
+        assert_eq!(file.write_to_bytes().unwrap(), &first[..]);
+        assert_eq!(file.write_length_delimited_to_bytes().unwrap(), &buf[..]);
+
+        let parsed: FileDescriptorProto = parse_from_carllerche_bytes(&first).unwrap();
+        assert_eq!(file, parsed);
+    }
+
+    #[test]
+    fn write_to_vec_reuse() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.mut_dependency().push("b.proto".to_owned());
+        let bytes = file.write_to_bytes().unwrap();
+        let delimited = file.write_length_delimited_to_bytes().unwrap();
+
+        let mut buf = Vec::with_capacity(bytes.len() + delimited.len());
+        let ptr = buf.as_ptr();
+        file.write_to_vec(&mut buf).unwrap();
+        file.write_length_delimited_to_vec(&mut buf).unwrap();
+        assert_eq!(ptr, buf.as_ptr());
+        assert_eq!(bytes.len() + delimited.len(), buf.capacity());
+        assert_eq!(&bytes[..], &buf[..bytes.len()]);
+        assert_eq!(&delimited[..], &buf[bytes.len()..]);
+
+        buf.clear();
+        file.write_to_vec(&mut buf).unwrap();
+        assert_eq!(ptr, buf.as_ptr());
+        assert_eq!(bytes, buf);
+    }
+}
@@ -0,0 +1,387 @@
+//! Tools for debugging serialized messages.
+//!
+//! `annotate_wire` renders serialized message as a list of fields
+//! with their offsets and raw tag and length bytes, like `protoc --decode_raw`
+//! with a hexdump column, so it is possible to find where corrupted payload
+//! stops being valid:
+//!
+//! ```text
+//! 000000  0a 01                   1 name: string "x"
+//! 000003  18 05                   3 number: int32 5
+//! 000005  42 02                   8 options: message (2 bytes)
+//! 000007  10 01                     2 packed: bool true
+//! ```
+//!
+//! When message descriptor is given, field names are printed and values
+//! are decoded according to field types, otherwise length-delimited values
+//! are printed as nested messages if they are valid messages, or as strings
+//! or bytes.
+
+use std::fmt::Write;
+use std::str;
+
+use descriptor::FieldDescriptorProto_Type;
+use error::ProtobufResult;
+use reflect::FieldDescriptor;
+use reflect::MessageDescriptor;
+use stream::wire_format::WireType;
+use stream::CodedInputStream;
+use zigzag::decode_zig_zag_32;
+use zigzag::decode_zig_zag_64;
+
+
+// nested messages deeper than this are printed as bytes
+const MAX_DEPTH: usize = 100;
+
+// width of the column with tag and length bytes
+const HEX_WIDTH: usize = 23;
+
+fn hex(bytes: &[u8]) -> String {
+    let mut r = String::new();
+    for (i, b) in bytes.iter().enumerate() {
+        if i != 0 {
+            r.push(' ');
+        }
+        write!(r, "{:02x}", b).unwrap();
+    }
+    r
+}
+
+fn write_line(r: &mut String, offset: usize, raw: &[u8], depth: usize, text: &str) {
+    write!(r, "{:06x}  {:<width$} ", offset, hex(raw), width = HEX_WIDTH).unwrap();
+    for _ in 0..depth {
+        r.push_str("  ");
+    }
+    r.push_str(text);
+    r.push('\n');
+}
+
+// fields of message and their values are valid, groups are not supported
+fn is_message(bytes: &[u8]) -> bool {
+    let mut is = CodedInputStream::from_bytes(bytes);
+    let mut fields = 0;
+    loop {
+        match is.eof() {
+            Ok(true) => return fields != 0,
+            Ok(false) => {}
+            Err(..) => return false,
+        }
+        let wire_type = match is.read_tag_unpack() {
+            Ok((_, wire_type)) => wire_type,
+            Err(..) => return false,
+        };
+        let ok = match wire_type {
+            WireType::WireTypeVarint => is.read_raw_varint64().is_ok(),
+            WireType::WireTypeFixed64 => is.read_fixed64().is_ok(),
+            WireType::WireTypeFixed32 => is.read_fixed32().is_ok(),
+            WireType::WireTypeLengthDelimited => match is.read_raw_varint32() {
+                Ok(len) => is.skip_raw_bytes(len).is_ok(),
+                Err(..) => false,
+            },
+            WireType::WireTypeStartGroup | WireType::WireTypeEndGroup => false,
+        };
+        if !ok {
+            return false;
+        }
+        fields += 1;
+    }
+}
+
+fn varint_value(v: u64, field_type: FieldDescriptorProto_Type, field: &FieldDescriptor)
+    -> Option<String>
+{
+    Some(match field_type {
+        FieldDescriptorProto_Type::TYPE_INT32 => format!("int32 {}", v as i32),
+        FieldDescriptorProto_Type::TYPE_INT64 => format!("int64 {}", v as i64),
+        FieldDescriptorProto_Type::TYPE_UINT32 => format!("uint32 {}", v as u32),
+        FieldDescriptorProto_Type::TYPE_UINT64 => format!("uint64 {}", v),
+        FieldDescriptorProto_Type::TYPE_SINT32 => {
+            format!("sint32 {}", decode_zig_zag_32(v as u32))
+        }
+        FieldDescriptorProto_Type::TYPE_SINT64 => format!("sint64 {}", decode_zig_zag_64(v)),
+        FieldDescriptorProto_Type::TYPE_BOOL => format!("bool {}", v != 0),
+        FieldDescriptorProto_Type::TYPE_ENUM => {
+            let number = v as i32;
+            let value = field.enum_descriptor().and_then(|e| e.get_value_by_number(number));
+            match value {
+                Some(value) => format!("enum {} = {}", value.name(), number),
+                None => format!("enum {}", number),
+            }
+        }
+        _ => return None,
+    })
+}
+
+fn fixed32_value(v: u32, field_type: FieldDescriptorProto_Type) -> Option<String> {
+    Some(match field_type {
+        FieldDescriptorProto_Type::TYPE_FIXED32 => format!("fixed32 {}", v),
+        FieldDescriptorProto_Type::TYPE_SFIXED32 => format!("sfixed32 {}", v as i32),
+        FieldDescriptorProto_Type::TYPE_FLOAT => format!("float {}", f32::from_bits(v)),
+        _ => return None,
+    })
+}
+
+fn fixed64_value(v: u64, field_type: FieldDescriptorProto_Type) -> Option<String> {
+    Some(match field_type {
+        FieldDescriptorProto_Type::TYPE_FIXED64 => format!("fixed64 {}", v),
+        FieldDescriptorProto_Type::TYPE_SFIXED64 => format!("sfixed64 {}", v as i64),
+        FieldDescriptorProto_Type::TYPE_DOUBLE => format!("double {}", f64::from_bits(v)),
+        _ => return None,
+    })
+}
+
+// elements of packed repeated field, `None` if payload is malformed
+fn packed_values(bytes: &[u8], field: &FieldDescriptor) -> Option<String> {
+    let field_type = field.proto().get_field_type();
+    let mut values = Vec::new();
+    let mut is = CodedInputStream::from_bytes(bytes);
+    while !is.eof().ok()? {
+        let value = match field_type {
+            FieldDescriptorProto_Type::TYPE_FIXED32 |
+            FieldDescriptorProto_Type::TYPE_SFIXED32 |
+            FieldDescriptorProto_Type::TYPE_FLOAT => {
+                fixed32_value(is.read_fixed32().ok()?, field_type)?
+            }
+            FieldDescriptorProto_Type::TYPE_FIXED64 |
+            FieldDescriptorProto_Type::TYPE_SFIXED64 |
+            FieldDescriptorProto_Type::TYPE_DOUBLE => {
+                fixed64_value(is.read_fixed64().ok()?, field_type)?
+            }
+            _ => varint_value(is.read_raw_varint64().ok()?, field_type, field)?,
+        };
+        values.push(value);
+    }
+    Some(format!("packed [{}]", values.join(", ")))
+}
+
+struct Annotator<'a> {
+    // whole input, offsets are relative to it
+    input: &'a [u8],
+    r: String,
+}
+
+impl<'a> Annotator<'a> {
+    // write value of length-delimited field, followed by nested fields
+    fn length_delimited(
+        &mut self,
+        start: usize,
+        header: &[u8],
+        depth: usize,
+        label: &str,
+        payload: (usize, usize),
+        field: Option<&FieldDescriptor>,
+    ) {
+        let (pos, end) = payload;
+        let bytes = &self.input[pos..end];
+        let field_type = field.map(|f| f.proto().get_field_type());
+        let string = || match str::from_utf8(bytes) {
+            Ok(s) => format!("string {:?}", s),
+            Err(..) => format!("string (invalid UTF-8) {}", hex(bytes)),
+        };
+        // nested message with its descriptor if known
+        let nested = |descriptor| (Some(descriptor), format!("message ({} bytes)", bytes.len()));
+
+        let (nested, text) = match (field, field_type) {
+            (Some(..), Some(FieldDescriptorProto_Type::TYPE_STRING)) => (None, string()),
+            (Some(..), Some(FieldDescriptorProto_Type::TYPE_BYTES)) => {
+                (None, format!("bytes {}", hex(bytes)))
+            }
+            (Some(f), Some(FieldDescriptorProto_Type::TYPE_MESSAGE)) => {
+                // descriptor of map entry type is not available
+                let descriptor = if f.is_map() { None } else { f.message_descriptor() };
+                nested(descriptor)
+            }
+            (Some(f), Some(..)) if f.is_repeated() => match packed_values(bytes, f) {
+                Some(values) => (None, values),
+                None => (None, format!("malformed packed field {}", hex(bytes))),
+            },
+            _ if depth < MAX_DEPTH && is_message(bytes) => nested(None),
+            _ if str::from_utf8(bytes).is_ok() => (None, string()),
+            _ => (None, format!("bytes {}", hex(bytes))),
+        };
+
+        write_line(&mut self.r, start, header, depth, &format!("{}{}", label, text));
+        if let Some(descriptor) = nested {
+            if depth < MAX_DEPTH {
+                self.message(pos, end, descriptor, depth + 1);
+            }
+        }
+    }
+
+    fn message(&mut self, begin: usize, end: usize, descriptor: Option<&MessageDescriptor>,
+        depth: usize)
+    {
+        let input = self.input;
+        let mut is = CodedInputStream::from_bytes(&input[begin..end]);
+        // nesting of groups
+        let mut group_depth = 0;
+        loop {
+            let start = begin + is.pos() as usize;
+            let depth = depth + group_depth;
+            let r: ProtobufResult<bool> = (|| {
+                if is.eof()? {
+                    return Ok(false);
+                }
+                let (number, wire_type) = is.read_tag_unpack()?;
+                let field = descriptor.and_then(|d| {
+                    d.fields().iter().find(|f| f.proto().get_number() as u32 == number)
+                });
+                let label = match field {
+                    Some(f) => format!("{} {}: ", number, f.name()),
+                    None => format!("{}: ", number),
+                };
+                let field_type = field.map(|f| f.proto().get_field_type());
+                let known = |value: Option<String>| value.map(|v| format!("{}{}", label, v));
+
+                match wire_type {
+                    WireType::WireTypeVarint => {
+                        let v = is.read_raw_varint64()?;
+                        let typed = match (field, field_type) {
+                            (Some(f), Some(t)) => known(varint_value(v, t, f)),
+                            _ => None,
+                        };
+                        let text = typed.unwrap_or_else(|| format!("{}varint {}", label, v));
+                        let raw = &input[start..begin + is.pos() as usize];
+                        write_line(&mut self.r, start, raw, depth, &text);
+                    }
+                    WireType::WireTypeFixed32 => {
+                        let v = is.read_fixed32()?;
+                        let typed = field_type.and_then(|t| known(fixed32_value(v, t)));
+                        let text = typed.unwrap_or_else(|| format!("{}fixed32 {}", label, v));
+                        let raw = &input[start..begin + is.pos() as usize];
+                        write_line(&mut self.r, start, raw, depth, &text);
+                    }
+                    WireType::WireTypeFixed64 => {
+                        let v = is.read_fixed64()?;
+                        let typed = field_type.and_then(|t| known(fixed64_value(v, t)));
+                        let text = typed.unwrap_or_else(|| format!("{}fixed64 {}", label, v));
+                        let raw = &input[start..begin + is.pos() as usize];
+                        write_line(&mut self.r, start, raw, depth, &text);
+                    }
+                    WireType::WireTypeLengthDelimited => {
+                        let len = is.read_raw_varint32()?;
+                        let pos = begin + is.pos() as usize;
+                        is.skip_raw_bytes(len)?;
+                        let header = &input[start..pos];
+                        let payload = (pos, pos + len as usize);
+                        self.length_delimited(start, header, depth, &label, payload, field);
+                    }
+                    WireType::WireTypeStartGroup => {
+                        let raw = &input[start..begin + is.pos() as usize];
+                        write_line(&mut self.r, start, raw, depth, &format!("{}group {{", label));
+                        group_depth += 1;
+                    }
+                    WireType::WireTypeEndGroup => {
+                        group_depth = group_depth.saturating_sub(1);
+                        let raw = &input[start..begin + is.pos() as usize];
+                        let depth = depth.saturating_sub(1);
+                        write_line(&mut self.r, start, raw, depth, &format!("}} {}", number));
+                    }
+                }
+                Ok(true)
+            })();
+            match r {
+                Ok(true) => {}
+                Ok(false) => return,
+                Err(e) => {
+                    // remaining bytes of the message are not decoded
+                    let rest = &input[start..end];
+                    let shown = &rest[..rest.len().min(8)];
+                    let text = format!("error: {}, {} bytes left", e, rest.len());
+                    write_line(&mut self.r, start, shown, depth, &text);
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Render serialized message as offset-annotated list of fields.
+///
+/// Each line contains offset of the field, its tag, value or length bytes
+/// (contents of length-delimited values are not included), field number,
+/// name if field is known, and decoded value. Nested messages are indented.
+/// Decoding stops at the first malformed field of a message,
+/// which is reported with an `error:` line.
+pub fn annotate_wire(bytes: &[u8], descriptor: Option<&MessageDescriptor>) -> String {
+    let mut annotator = Annotator {
+        input: bytes,
+        r: String::new(),
+    };
+    annotator.message(0, bytes.len(), descriptor, 0);
+    annotator.r
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::Message;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FieldDescriptorProto_Label;
+    use descriptor::SourceCodeInfo_Location;
+
+    fn field_descriptor_proto() -> Vec<u8> {
+        let mut f = FieldDescriptorProto::new();
+        f.set_name("x".to_owned());
+        f.set_number(-5);
+        f.set_label(FieldDescriptorProto_Label::LABEL_REPEATED);
+        f.mut_options().set_packed(true);
+        f.write_to_bytes().unwrap()
+    }
+
+    #[test]
+    fn with_descriptor() {
+        let bytes = field_descriptor_proto();
+        let expected = "\
+000000  0a 01                   1 name: string \"x\"
+000003  18 fb ff ff ff ff ff ff ff ff 01 3 number: int32 -5
+00000e  20 03                   4 label: enum LABEL_REPEATED = 3
+000010  42 02                   8 options: message (2 bytes)
+000012  10 01                     2 packed: bool true
+";
+        let descriptor = MessageDescriptor::for_type::<FieldDescriptorProto>();
+        assert_eq!(expected, annotate_wire(&bytes, Some(descriptor)));
+    }
+
+    #[test]
+    fn without_descriptor() {
+        let bytes = field_descriptor_proto();
+        let expected = "\
+000000  0a 01                   1: string \"x\"
+000003  18 fb ff ff ff ff ff ff ff ff 01 3: varint 18446744073709551611
+00000e  20 03                   4: varint 3
+000010  42 02                   8: message (2 bytes)
+000012  10 01                     2: varint 1
+";
+        assert_eq!(expected, annotate_wire(&bytes, None));
+    }
+
+    #[test]
+    fn packed() {
+        let mut location = SourceCodeInfo_Location::new();
+        location.set_path(vec![4, 300]);
+        location.set_leading_comments("c".to_owned());
+        let bytes = location.write_to_bytes().unwrap();
+        let expected = "\
+000000  0a 03                   1 path: packed [int32 4, int32 300]
+000005  1a 01                   3 leading_comments: string \"c\"
+";
+        let descriptor = MessageDescriptor::for_type::<SourceCodeInfo_Location>();
+        assert_eq!(expected, annotate_wire(&bytes, Some(descriptor)));
+    }
+
+    #[test]
+    fn malformed() {
+        let mut bytes = field_descriptor_proto();
+        // options length is larger than remaining bytes
+        bytes[0x11] = 0x05;
+        let expected = "\
+000000  0a 01                   1: string \"x\"
+000003  18 fb ff ff ff ff ff ff ff ff 01 3: varint 18446744073709551611
+00000e  20 03                   4: varint 3
+000010  42 05 10 01             error: WireError(UnexpectedEof), 4 bytes left
+";
+        assert_eq!(expected, annotate_wire(&bytes, None));
+    }
+}
@@ -28,9 +28,13 @@ pub struct FileDescriptorSet {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.FileDescriptorSet)
 }
 
 impl FileDescriptorSet {
+    pub const FIELD_FILE_NUMBER: u32 = 1;
+    pub const FIELD_FILE_TAG: u32 = 10;
+
     pub fn new() -> FileDescriptorSet {
         ::std::default::Default::default()
     }
@@ -59,6 +63,8 @@ impl FileDescriptorSet {
     pub fn get_file(&self) -> &[FileDescriptorProto] {
         &self.file
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.FileDescriptorSet)
 }
 
 impl ::protobuf::Message for FileDescriptorSet {
@@ -86,6 +92,13 @@ impl ::protobuf::Message for FileDescriptorSet {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.file {
+            self.file.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -113,6 +126,42 @@ impl ::protobuf::Message for FileDescriptorSet {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        for value in &self.file {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.file {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.file {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -131,44 +180,40 @@ impl ::protobuf::Message for FileDescriptorSet {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> FileDescriptorSet {
-        FileDescriptorSet::new()
+    fn descriptor_static(_: ::std::option::Option<FileDescriptorSet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<FileDescriptorSet>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileDescriptorProto>>(
-                    "file",
-                    |m: &FileDescriptorSet| { &m.file },
-                    |m: &mut FileDescriptorSet| { &mut m.file },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<FileDescriptorSet>(
-                    "FileDescriptorSet",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> FileDescriptorSet {
+        FileDescriptorSet::new()
     }
 
     fn default_instance() -> &'static FileDescriptorSet {
-        static mut instance: ::protobuf::lazy::Lazy<FileDescriptorSet> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const FileDescriptorSet,
-        };
-        unsafe {
-            instance.get(FileDescriptorSet::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<FileDescriptorSet> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(FileDescriptorSet::new)
+    }
+}
+
+impl ::protobuf::MessageFull for FileDescriptorSet {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileDescriptorProto>>(
+                "file",
+                |m: &FileDescriptorSet| { &m.file },
+                |m: &mut FileDescriptorSet| { &mut m.file },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<FileDescriptorSet>(
+                "FileDescriptorSet",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -191,6 +236,14 @@ impl ::protobuf::reflect::ProtobufValue for FileDescriptorSet {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for FileDescriptorSet {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<FileDescriptorSet> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct FileDescriptorProto {
     // message fields
@@ -209,9 +262,35 @@ pub struct FileDescriptorProto {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.FileDescriptorProto)
 }
 
 impl FileDescriptorProto {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_PACKAGE_NUMBER: u32 = 2;
+    pub const FIELD_PACKAGE_TAG: u32 = 18;
+    pub const FIELD_DEPENDENCY_NUMBER: u32 = 3;
+    pub const FIELD_DEPENDENCY_TAG: u32 = 26;
+    pub const FIELD_PUBLIC_DEPENDENCY_NUMBER: u32 = 10;
+    pub const FIELD_PUBLIC_DEPENDENCY_TAG: u32 = 80;
+    pub const FIELD_WEAK_DEPENDENCY_NUMBER: u32 = 11;
+    pub const FIELD_WEAK_DEPENDENCY_TAG: u32 = 88;
+    pub const FIELD_MESSAGE_TYPE_NUMBER: u32 = 4;
+    pub const FIELD_MESSAGE_TYPE_TAG: u32 = 34;
+    pub const FIELD_ENUM_TYPE_NUMBER: u32 = 5;
+    pub const FIELD_ENUM_TYPE_TAG: u32 = 42;
+    pub const FIELD_SERVICE_NUMBER: u32 = 6;
+    pub const FIELD_SERVICE_TAG: u32 = 50;
+    pub const FIELD_EXTENSION_NUMBER: u32 = 7;
+    pub const FIELD_EXTENSION_TAG: u32 = 58;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 8;
+    pub const FIELD_OPTIONS_TAG: u32 = 66;
+    pub const FIELD_SOURCE_CODE_INFO_NUMBER: u32 = 9;
+    pub const FIELD_SOURCE_CODE_INFO_TAG: u32 = 74;
+    pub const FIELD_SYNTAX_NUMBER: u32 = 12;
+    pub const FIELD_SYNTAX_TAG: u32 = 98;
+
     pub fn new() -> FileDescriptorProto {
         ::std::default::Default::default()
     }
@@ -564,6 +643,8 @@ impl FileDescriptorProto {
             None => "",
         }
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.FileDescriptorProto)
 }
 
 impl ::protobuf::Message for FileDescriptorProto {
@@ -649,6 +730,46 @@ impl ::protobuf::Message for FileDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.package.is_some() {
+            self.package = other.package.clone();
+        }
+        for v in &other.dependency {
+            self.dependency.push(v.clone());
+        };
+        for v in &other.public_dependency {
+            self.public_dependency.push(v.clone());
+        };
+        for v in &other.weak_dependency {
+            self.weak_dependency.push(v.clone());
+        };
+        for v in &other.message_type {
+            self.message_type.push(v.clone());
+        };
+        for v in &other.enum_type {
+            self.enum_type.push(v.clone());
+        };
+        for v in &other.service {
+            self.service.push(v.clone());
+        };
+        for v in &other.extension {
+            self.extension.push(v.clone());
+        };
+        if let Some(v) = other.options.as_ref() {
+            self.mut_options().merge_from_message(v);
+        }
+        if let Some(v) = other.source_code_info.as_ref() {
+            self.mut_source_code_info().merge_from_message(v);
+        }
+        if other.syntax.is_some() {
+            self.syntax = other.syntax.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -757,6 +878,157 @@ impl ::protobuf::Message for FileDescriptorProto {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.package.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        for value in &self.dependency {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.public_dependency {
+            my_size += ::protobuf::rt::value_size(10, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.weak_dependency {
+            my_size += ::protobuf::rt::value_size(11, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.message_type {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.enum_type {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.service {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.extension {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.source_code_info.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.syntax.as_ref() {
+            my_size += ::protobuf::rt::string_size(12, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.package.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        for v in &self.dependency {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.public_dependency {
+            os.write_int32(10, *v)?;
+        };
+        for v in &self.weak_dependency {
+            os.write_int32(11, *v)?;
+        };
+        for v in &self.message_type {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.enum_type {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.service {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.extension {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        if let Some(ref v) = self.source_code_info.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        if let Some(ref v) = self.syntax.as_ref() {
+            os.write_string(12, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        if let Some(ref v) = self.package.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v) as u64;
+        }
+        for value in &self.dependency {
+            my_size += ::protobuf::rt::string_size(3, &value) as u64;
+        };
+        my_size += 11 * self.public_dependency.len() as u64;
+        my_size += 11 * self.weak_dependency.len() as u64;
+        for value in &self.message_type {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.enum_type {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.service {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.extension {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        if let Some(ref v) = self.source_code_info.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        if let Some(ref v) = self.syntax.as_ref() {
+            my_size += ::protobuf::rt::string_size(12, &v) as u64;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -775,99 +1047,95 @@ impl ::protobuf::Message for FileDescriptorProto {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> FileDescriptorProto {
-        FileDescriptorProto::new()
+    fn descriptor_static(_: ::std::option::Option<FileDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<FileDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &FileDescriptorProto| { &m.name },
-                    |m: &mut FileDescriptorProto| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "package",
-                    |m: &FileDescriptorProto| { &m.package },
-                    |m: &mut FileDescriptorProto| { &mut m.package },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "dependency",
-                    |m: &FileDescriptorProto| { &m.dependency },
-                    |m: &mut FileDescriptorProto| { &mut m.dependency },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "public_dependency",
-                    |m: &FileDescriptorProto| { &m.public_dependency },
-                    |m: &mut FileDescriptorProto| { &mut m.public_dependency },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "weak_dependency",
-                    |m: &FileDescriptorProto| { &m.weak_dependency },
-                    |m: &mut FileDescriptorProto| { &mut m.weak_dependency },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DescriptorProto>>(
-                    "message_type",
-                    |m: &FileDescriptorProto| { &m.message_type },
-                    |m: &mut FileDescriptorProto| { &mut m.message_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumDescriptorProto>>(
-                    "enum_type",
-                    |m: &FileDescriptorProto| { &m.enum_type },
-                    |m: &mut FileDescriptorProto| { &mut m.enum_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ServiceDescriptorProto>>(
-                    "service",
-                    |m: &FileDescriptorProto| { &m.service },
-                    |m: &mut FileDescriptorProto| { &mut m.service },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FieldDescriptorProto>>(
-                    "extension",
-                    |m: &FileDescriptorProto| { &m.extension },
-                    |m: &mut FileDescriptorProto| { &mut m.extension },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileOptions>>(
-                    "options",
-                    |m: &FileDescriptorProto| { &m.options },
-                    |m: &mut FileDescriptorProto| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SourceCodeInfo>>(
-                    "source_code_info",
-                    |m: &FileDescriptorProto| { &m.source_code_info },
-                    |m: &mut FileDescriptorProto| { &mut m.source_code_info },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "syntax",
-                    |m: &FileDescriptorProto| { &m.syntax },
-                    |m: &mut FileDescriptorProto| { &mut m.syntax },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<FileDescriptorProto>(
-                    "FileDescriptorProto",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> FileDescriptorProto {
+        FileDescriptorProto::new()
     }
 
     fn default_instance() -> &'static FileDescriptorProto {
-        static mut instance: ::protobuf::lazy::Lazy<FileDescriptorProto> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const FileDescriptorProto,
-        };
-        unsafe {
-            instance.get(FileDescriptorProto::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(FileDescriptorProto::new)
+    }
+}
+
+impl ::protobuf::MessageFull for FileDescriptorProto {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &FileDescriptorProto| { &m.name },
+                |m: &mut FileDescriptorProto| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "package",
+                |m: &FileDescriptorProto| { &m.package },
+                |m: &mut FileDescriptorProto| { &mut m.package },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "dependency",
+                |m: &FileDescriptorProto| { &m.dependency },
+                |m: &mut FileDescriptorProto| { &mut m.dependency },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "public_dependency",
+                |m: &FileDescriptorProto| { &m.public_dependency },
+                |m: &mut FileDescriptorProto| { &mut m.public_dependency },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "weak_dependency",
+                |m: &FileDescriptorProto| { &m.weak_dependency },
+                |m: &mut FileDescriptorProto| { &mut m.weak_dependency },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DescriptorProto>>(
+                "message_type",
+                |m: &FileDescriptorProto| { &m.message_type },
+                |m: &mut FileDescriptorProto| { &mut m.message_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumDescriptorProto>>(
+                "enum_type",
+                |m: &FileDescriptorProto| { &m.enum_type },
+                |m: &mut FileDescriptorProto| { &mut m.enum_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ServiceDescriptorProto>>(
+                "service",
+                |m: &FileDescriptorProto| { &m.service },
+                |m: &mut FileDescriptorProto| { &mut m.service },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FieldDescriptorProto>>(
+                "extension",
+                |m: &FileDescriptorProto| { &m.extension },
+                |m: &mut FileDescriptorProto| { &mut m.extension },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FileOptions>>(
+                "options",
+                |m: &FileDescriptorProto| { &m.options },
+                |m: &mut FileDescriptorProto| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SourceCodeInfo>>(
+                "source_code_info",
+                |m: &FileDescriptorProto| { &m.source_code_info },
+                |m: &mut FileDescriptorProto| { &mut m.source_code_info },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "syntax",
+                |m: &FileDescriptorProto| { &m.syntax },
+                |m: &mut FileDescriptorProto| { &mut m.syntax },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<FileDescriptorProto>(
+                "FileDescriptorProto",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -901,6 +1169,14 @@ impl ::protobuf::reflect::ProtobufValue for FileDescriptorProto {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for FileDescriptorProto {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<FileDescriptorProto> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct DescriptorProto {
     // message fields
@@ -917,9 +1193,31 @@ pub struct DescriptorProto {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.DescriptorProto)
 }
 
 impl DescriptorProto {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_FIELD_NUMBER: u32 = 2;
+    pub const FIELD_FIELD_TAG: u32 = 18;
+    pub const FIELD_EXTENSION_NUMBER: u32 = 6;
+    pub const FIELD_EXTENSION_TAG: u32 = 50;
+    pub const FIELD_NESTED_TYPE_NUMBER: u32 = 3;
+    pub const FIELD_NESTED_TYPE_TAG: u32 = 26;
+    pub const FIELD_ENUM_TYPE_NUMBER: u32 = 4;
+    pub const FIELD_ENUM_TYPE_TAG: u32 = 34;
+    pub const FIELD_EXTENSION_RANGE_NUMBER: u32 = 5;
+    pub const FIELD_EXTENSION_RANGE_TAG: u32 = 42;
+    pub const FIELD_ONEOF_DECL_NUMBER: u32 = 8;
+    pub const FIELD_ONEOF_DECL_TAG: u32 = 66;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 7;
+    pub const FIELD_OPTIONS_TAG: u32 = 58;
+    pub const FIELD_RESERVED_RANGE_NUMBER: u32 = 9;
+    pub const FIELD_RESERVED_RANGE_TAG: u32 = 74;
+    pub const FIELD_RESERVED_NAME_NUMBER: u32 = 10;
+    pub const FIELD_RESERVED_NAME_TAG: u32 = 82;
+
     pub fn new() -> DescriptorProto {
         ::std::default::Default::default()
     }
@@ -1192,6 +1490,8 @@ impl DescriptorProto {
     pub fn get_reserved_name(&self) -> &[::std::string::String] {
         &self.reserved_name
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.DescriptorProto)
 }
 
 impl ::protobuf::Message for DescriptorProto {
@@ -1281,6 +1581,40 @@ impl ::protobuf::Message for DescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        for v in &other.field {
+            self.field.push(v.clone());
+        };
+        for v in &other.extension {
+            self.extension.push(v.clone());
+        };
+        for v in &other.nested_type {
+            self.nested_type.push(v.clone());
+        };
+        for v in &other.enum_type {
+            self.enum_type.push(v.clone());
+        };
+        for v in &other.extension_range {
+            self.extension_range.push(v.clone());
+        };
+        for v in &other.oneof_decl {
+            self.oneof_decl.push(v.clone());
+        };
+        if let Some(v) = other.options.as_ref() {
+            self.mut_options().merge_from_message(v);
+        }
+        for v in &other.reserved_range {
+            self.reserved_range.push(v.clone());
+        };
+        for v in &other.reserved_name {
+            self.reserved_name.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1383,111 +1717,252 @@ impl ::protobuf::Message for DescriptorProto {
         self.cached_size.get()
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
-    }
-
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
-    }
-
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
-    }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
-    }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
-        self
-    }
-
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
-    }
-
-    fn new() -> DescriptorProto {
-        DescriptorProto::new()
-    }
-
-    fn descriptor_static(_: ::std::option::Option<DescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &DescriptorProto| { &m.name },
-                    |m: &mut DescriptorProto| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FieldDescriptorProto>>(
-                    "field",
-                    |m: &DescriptorProto| { &m.field },
-                    |m: &mut DescriptorProto| { &mut m.field },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FieldDescriptorProto>>(
-                    "extension",
-                    |m: &DescriptorProto| { &m.extension },
-                    |m: &mut DescriptorProto| { &mut m.extension },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DescriptorProto>>(
-                    "nested_type",
-                    |m: &DescriptorProto| { &m.nested_type },
-                    |m: &mut DescriptorProto| { &mut m.nested_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumDescriptorProto>>(
-                    "enum_type",
-                    |m: &DescriptorProto| { &m.enum_type },
-                    |m: &mut DescriptorProto| { &mut m.enum_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DescriptorProto_ExtensionRange>>(
-                    "extension_range",
-                    |m: &DescriptorProto| { &m.extension_range },
-                    |m: &mut DescriptorProto| { &mut m.extension_range },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OneofDescriptorProto>>(
-                    "oneof_decl",
-                    |m: &DescriptorProto| { &m.oneof_decl },
-                    |m: &mut DescriptorProto| { &mut m.oneof_decl },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MessageOptions>>(
-                    "options",
-                    |m: &DescriptorProto| { &m.options },
-                    |m: &mut DescriptorProto| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DescriptorProto_ReservedRange>>(
-                    "reserved_range",
-                    |m: &DescriptorProto| { &m.reserved_range },
-                    |m: &mut DescriptorProto| { &mut m.reserved_range },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "reserved_name",
-                    |m: &DescriptorProto| { &m.reserved_name },
-                    |m: &mut DescriptorProto| { &mut m.reserved_name },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<DescriptorProto>(
-                    "DescriptorProto",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
-    }
-
-    fn default_instance() -> &'static DescriptorProto {
-        static mut instance: ::protobuf::lazy::Lazy<DescriptorProto> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const DescriptorProto,
+        for value in &self.field {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.extension {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.nested_type {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.enum_type {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.extension_range {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.oneof_decl {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
-        unsafe {
-            instance.get(DescriptorProto::new)
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        for value in &self.reserved_range {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.reserved_name {
+            my_size += ::protobuf::rt::string_size(10, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
     }
-}
 
-impl ::protobuf::Clear for DescriptorProto {
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        for v in &self.field {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.extension {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.nested_type {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.enum_type {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.extension_range {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.oneof_decl {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        for v in &self.reserved_range {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.reserved_name {
+            os.write_string(10, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        for value in &self.field {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.extension {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.nested_type {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.enum_type {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.extension_range {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.oneof_decl {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        for value in &self.reserved_range {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.reserved_name {
+            my_size += ::protobuf::rt::string_size(10, &value) as u64;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<DescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
+    }
+
+    fn new() -> DescriptorProto {
+        DescriptorProto::new()
+    }
+
+    fn default_instance() -> &'static DescriptorProto {
+        static instance: ::protobuf::lazy::LazyV2<DescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(DescriptorProto::new)
+    }
+}
+
+impl ::protobuf::MessageFull for DescriptorProto {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &DescriptorProto| { &m.name },
+                |m: &mut DescriptorProto| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FieldDescriptorProto>>(
+                "field",
+                |m: &DescriptorProto| { &m.field },
+                |m: &mut DescriptorProto| { &mut m.field },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FieldDescriptorProto>>(
+                "extension",
+                |m: &DescriptorProto| { &m.extension },
+                |m: &mut DescriptorProto| { &mut m.extension },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DescriptorProto>>(
+                "nested_type",
+                |m: &DescriptorProto| { &m.nested_type },
+                |m: &mut DescriptorProto| { &mut m.nested_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumDescriptorProto>>(
+                "enum_type",
+                |m: &DescriptorProto| { &m.enum_type },
+                |m: &mut DescriptorProto| { &mut m.enum_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DescriptorProto_ExtensionRange>>(
+                "extension_range",
+                |m: &DescriptorProto| { &m.extension_range },
+                |m: &mut DescriptorProto| { &mut m.extension_range },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OneofDescriptorProto>>(
+                "oneof_decl",
+                |m: &DescriptorProto| { &m.oneof_decl },
+                |m: &mut DescriptorProto| { &mut m.oneof_decl },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MessageOptions>>(
+                "options",
+                |m: &DescriptorProto| { &m.options },
+                |m: &mut DescriptorProto| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DescriptorProto_ReservedRange>>(
+                "reserved_range",
+                |m: &DescriptorProto| { &m.reserved_range },
+                |m: &mut DescriptorProto| { &mut m.reserved_range },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "reserved_name",
+                |m: &DescriptorProto| { &m.reserved_name },
+                |m: &mut DescriptorProto| { &mut m.reserved_name },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<DescriptorProto>(
+                "DescriptorProto",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+}
+
+impl ::protobuf::Clear for DescriptorProto {
     fn clear(&mut self) {
         self.clear_name();
         self.clear_field();
@@ -1515,6 +1990,14 @@ impl ::protobuf::reflect::ProtobufValue for DescriptorProto {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for DescriptorProto {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<DescriptorProto> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct DescriptorProto_ExtensionRange {
     // message fields
@@ -1523,9 +2006,15 @@ pub struct DescriptorProto_ExtensionRange {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.DescriptorProto.ExtensionRange)
 }
 
 impl DescriptorProto_ExtensionRange {
+    pub const FIELD_START_NUMBER: u32 = 1;
+    pub const FIELD_START_TAG: u32 = 8;
+    pub const FIELD_END_NUMBER: u32 = 2;
+    pub const FIELD_END_TAG: u32 = 16;
+
     pub fn new() -> DescriptorProto_ExtensionRange {
         ::std::default::Default::default()
     }
@@ -1567,6 +2056,8 @@ impl DescriptorProto_ExtensionRange {
     pub fn get_end(&self) -> i32 {
         self.end.unwrap_or(0)
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.DescriptorProto.ExtensionRange)
 }
 
 impl ::protobuf::Message for DescriptorProto_ExtensionRange {
@@ -1600,6 +2091,16 @@ impl ::protobuf::Message for DescriptorProto_ExtensionRange {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.start.is_some() {
+            self.start = other.start.clone();
+        }
+        if other.end.is_some() {
+            self.end = other.end.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1630,6 +2131,47 @@ impl ::protobuf::Message for DescriptorProto_ExtensionRange {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.end {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.start {
+            os.write_int32(1, v)?;
+        }
+        if let Some(v) = self.end {
+            os.write_int32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.start {
+            my_size += 11;
+        }
+        if let Some(v) = self.end {
+            my_size += 11;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -1648,49 +2190,45 @@ impl ::protobuf::Message for DescriptorProto_ExtensionRange {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> DescriptorProto_ExtensionRange {
-        DescriptorProto_ExtensionRange::new()
+    fn descriptor_static(_: ::std::option::Option<DescriptorProto_ExtensionRange>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<DescriptorProto_ExtensionRange>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "start",
-                    |m: &DescriptorProto_ExtensionRange| { &m.start },
-                    |m: &mut DescriptorProto_ExtensionRange| { &mut m.start },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "end",
-                    |m: &DescriptorProto_ExtensionRange| { &m.end },
-                    |m: &mut DescriptorProto_ExtensionRange| { &mut m.end },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<DescriptorProto_ExtensionRange>(
-                    "DescriptorProto_ExtensionRange",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> DescriptorProto_ExtensionRange {
+        DescriptorProto_ExtensionRange::new()
     }
 
     fn default_instance() -> &'static DescriptorProto_ExtensionRange {
-        static mut instance: ::protobuf::lazy::Lazy<DescriptorProto_ExtensionRange> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const DescriptorProto_ExtensionRange,
-        };
-        unsafe {
-            instance.get(DescriptorProto_ExtensionRange::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<DescriptorProto_ExtensionRange> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(DescriptorProto_ExtensionRange::new)
+    }
+}
+
+impl ::protobuf::MessageFull for DescriptorProto_ExtensionRange {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "start",
+                |m: &DescriptorProto_ExtensionRange| { &m.start },
+                |m: &mut DescriptorProto_ExtensionRange| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "end",
+                |m: &DescriptorProto_ExtensionRange| { &m.end },
+                |m: &mut DescriptorProto_ExtensionRange| { &mut m.end },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<DescriptorProto_ExtensionRange>(
+                "DescriptorProto_ExtensionRange",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -1714,6 +2252,14 @@ impl ::protobuf::reflect::ProtobufValue for DescriptorProto_ExtensionRange {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for DescriptorProto_ExtensionRange {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<DescriptorProto_ExtensionRange> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct DescriptorProto_ReservedRange {
     // message fields
@@ -1722,9 +2268,15 @@ pub struct DescriptorProto_ReservedRange {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.DescriptorProto.ReservedRange)
 }
 
 impl DescriptorProto_ReservedRange {
+    pub const FIELD_START_NUMBER: u32 = 1;
+    pub const FIELD_START_TAG: u32 = 8;
+    pub const FIELD_END_NUMBER: u32 = 2;
+    pub const FIELD_END_TAG: u32 = 16;
+
     pub fn new() -> DescriptorProto_ReservedRange {
         ::std::default::Default::default()
     }
@@ -1766,6 +2318,8 @@ impl DescriptorProto_ReservedRange {
     pub fn get_end(&self) -> i32 {
         self.end.unwrap_or(0)
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.DescriptorProto.ReservedRange)
 }
 
 impl ::protobuf::Message for DescriptorProto_ReservedRange {
@@ -1799,6 +2353,16 @@ impl ::protobuf::Message for DescriptorProto_ReservedRange {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.start.is_some() {
+            self.start = other.start.clone();
+        }
+        if other.end.is_some() {
+            self.end = other.end.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1829,6 +2393,47 @@ impl ::protobuf::Message for DescriptorProto_ReservedRange {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.end {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.start {
+            os.write_int32(1, v)?;
+        }
+        if let Some(v) = self.end {
+            os.write_int32(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.start {
+            my_size += 11;
+        }
+        if let Some(v) = self.end {
+            my_size += 11;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -1847,49 +2452,45 @@ impl ::protobuf::Message for DescriptorProto_ReservedRange {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> DescriptorProto_ReservedRange {
-        DescriptorProto_ReservedRange::new()
+    fn descriptor_static(_: ::std::option::Option<DescriptorProto_ReservedRange>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<DescriptorProto_ReservedRange>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "start",
-                    |m: &DescriptorProto_ReservedRange| { &m.start },
-                    |m: &mut DescriptorProto_ReservedRange| { &mut m.start },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "end",
-                    |m: &DescriptorProto_ReservedRange| { &m.end },
-                    |m: &mut DescriptorProto_ReservedRange| { &mut m.end },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<DescriptorProto_ReservedRange>(
-                    "DescriptorProto_ReservedRange",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> DescriptorProto_ReservedRange {
+        DescriptorProto_ReservedRange::new()
     }
 
     fn default_instance() -> &'static DescriptorProto_ReservedRange {
-        static mut instance: ::protobuf::lazy::Lazy<DescriptorProto_ReservedRange> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const DescriptorProto_ReservedRange,
-        };
-        unsafe {
-            instance.get(DescriptorProto_ReservedRange::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<DescriptorProto_ReservedRange> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(DescriptorProto_ReservedRange::new)
+    }
+}
+
+impl ::protobuf::MessageFull for DescriptorProto_ReservedRange {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "start",
+                |m: &DescriptorProto_ReservedRange| { &m.start },
+                |m: &mut DescriptorProto_ReservedRange| { &mut m.start },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "end",
+                |m: &DescriptorProto_ReservedRange| { &m.end },
+                |m: &mut DescriptorProto_ReservedRange| { &mut m.end },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<DescriptorProto_ReservedRange>(
+                "DescriptorProto_ReservedRange",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -1913,6 +2514,14 @@ impl ::protobuf::reflect::ProtobufValue for DescriptorProto_ReservedRange {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for DescriptorProto_ReservedRange {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<DescriptorProto_ReservedRange> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct FieldDescriptorProto {
     // message fields
@@ -1929,9 +2538,31 @@ pub struct FieldDescriptorProto {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.FieldDescriptorProto)
 }
 
 impl FieldDescriptorProto {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_NUMBER_NUMBER: u32 = 3;
+    pub const FIELD_NUMBER_TAG: u32 = 24;
+    pub const FIELD_LABEL_NUMBER: u32 = 4;
+    pub const FIELD_LABEL_TAG: u32 = 32;
+    pub const FIELD_TYPE_NUMBER: u32 = 5;
+    pub const FIELD_TYPE_TAG: u32 = 40;
+    pub const FIELD_TYPE_NAME_NUMBER: u32 = 6;
+    pub const FIELD_TYPE_NAME_TAG: u32 = 50;
+    pub const FIELD_EXTENDEE_NUMBER: u32 = 2;
+    pub const FIELD_EXTENDEE_TAG: u32 = 18;
+    pub const FIELD_DEFAULT_VALUE_NUMBER: u32 = 7;
+    pub const FIELD_DEFAULT_VALUE_TAG: u32 = 58;
+    pub const FIELD_ONEOF_INDEX_NUMBER: u32 = 9;
+    pub const FIELD_ONEOF_INDEX_TAG: u32 = 72;
+    pub const FIELD_JSON_NAME_NUMBER: u32 = 10;
+    pub const FIELD_JSON_NAME_TAG: u32 = 82;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 8;
+    pub const FIELD_OPTIONS_TAG: u32 = 66;
+
     pub fn new() -> FieldDescriptorProto {
         ::std::default::Default::default()
     }
@@ -2224,6 +2855,8 @@ impl FieldDescriptorProto {
     pub fn get_options(&self) -> &FieldOptions {
         self.options.as_ref().unwrap_or_else(|| FieldOptions::default_instance())
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.FieldDescriptorProto)
 }
 
 impl ::protobuf::Message for FieldDescriptorProto {
@@ -2286,6 +2919,40 @@ impl ::protobuf::Message for FieldDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.number.is_some() {
+            self.number = other.number.clone();
+        }
+        if other.label.is_some() {
+            self.label = other.label.clone();
+        }
+        if other.field_type.is_some() {
+            self.field_type = other.field_type.clone();
+        }
+        if other.type_name.is_some() {
+            self.type_name = other.type_name.clone();
+        }
+        if other.extendee.is_some() {
+            self.extendee = other.extendee.clone();
+        }
+        if other.default_value.is_some() {
+            self.default_value = other.default_value.clone();
+        }
+        if other.oneof_index.is_some() {
+            self.oneof_index = other.oneof_index.clone();
+        }
+        if other.json_name.is_some() {
+            self.json_name = other.json_name.clone();
+        }
+        if let Some(v) = other.options.as_ref() {
+            self.mut_options().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -2367,113 +3034,226 @@ impl ::protobuf::Message for FieldDescriptorProto {
         self.cached_size.get()
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
-    }
-
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
-    }
-
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
-    }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
-    }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
-        self
-    }
-
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
-    }
-
-    fn new() -> FieldDescriptorProto {
-        FieldDescriptorProto::new()
-    }
-
-    fn descriptor_static(_: ::std::option::Option<FieldDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &FieldDescriptorProto| { &m.name },
-                    |m: &mut FieldDescriptorProto| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "number",
-                    |m: &FieldDescriptorProto| { &m.number },
-                    |m: &mut FieldDescriptorProto| { &mut m.number },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldDescriptorProto_Label>>(
-                    "label",
-                    |m: &FieldDescriptorProto| { &m.label },
-                    |m: &mut FieldDescriptorProto| { &mut m.label },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldDescriptorProto_Type>>(
-                    "type",
-                    |m: &FieldDescriptorProto| { &m.field_type },
-                    |m: &mut FieldDescriptorProto| { &mut m.field_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "type_name",
-                    |m: &FieldDescriptorProto| { &m.type_name },
-                    |m: &mut FieldDescriptorProto| { &mut m.type_name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "extendee",
-                    |m: &FieldDescriptorProto| { &m.extendee },
-                    |m: &mut FieldDescriptorProto| { &mut m.extendee },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "default_value",
-                    |m: &FieldDescriptorProto| { &m.default_value },
-                    |m: &mut FieldDescriptorProto| { &mut m.default_value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "oneof_index",
-                    |m: &FieldDescriptorProto| { &m.oneof_index },
-                    |m: &mut FieldDescriptorProto| { &mut m.oneof_index },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "json_name",
-                    |m: &FieldDescriptorProto| { &m.json_name },
-                    |m: &mut FieldDescriptorProto| { &mut m.json_name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FieldOptions>>(
-                    "options",
-                    |m: &FieldDescriptorProto| { &m.options },
-                    |m: &mut FieldDescriptorProto| { &mut m.options },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<FieldDescriptorProto>(
-                    "FieldDescriptorProto",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
-    }
-
-    fn default_instance() -> &'static FieldDescriptorProto {
-        static mut instance: ::protobuf::lazy::Lazy<FieldDescriptorProto> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const FieldDescriptorProto,
-        };
-        unsafe {
-            instance.get(FieldDescriptorProto::new)
+        if let Some(v) = self.number {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
         }
-    }
-}
-
-impl ::protobuf::Clear for FieldDescriptorProto {
-    fn clear(&mut self) {
-        self.clear_name();
+        if let Some(v) = self.label {
+            my_size += ::protobuf::rt::enum_size(4, v);
+        }
+        if let Some(v) = self.field_type {
+            my_size += ::protobuf::rt::enum_size(5, v);
+        }
+        if let Some(ref v) = self.type_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(ref v) = self.extendee.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.default_value.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(v) = self.oneof_index {
+            my_size += ::protobuf::rt::value_size(9, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.json_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(10, &v);
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.number {
+            os.write_int32(3, v)?;
+        }
+        if let Some(v) = self.label {
+            os.write_enum(4, v.value())?;
+        }
+        if let Some(v) = self.field_type {
+            os.write_enum(5, v.value())?;
+        }
+        if let Some(ref v) = self.type_name.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(ref v) = self.extendee.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.default_value.as_ref() {
+            os.write_string(7, &v)?;
+        }
+        if let Some(v) = self.oneof_index {
+            os.write_int32(9, v)?;
+        }
+        if let Some(ref v) = self.json_name.as_ref() {
+            os.write_string(10, &v)?;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        if let Some(v) = self.number {
+            my_size += 11;
+        }
+        if let Some(v) = self.label {
+            my_size += 11;
+        }
+        if let Some(v) = self.field_type {
+            my_size += 11;
+        }
+        if let Some(ref v) = self.type_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v) as u64;
+        }
+        if let Some(ref v) = self.extendee.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v) as u64;
+        }
+        if let Some(ref v) = self.default_value.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v) as u64;
+        }
+        if let Some(v) = self.oneof_index {
+            my_size += 11;
+        }
+        if let Some(ref v) = self.json_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(10, &v) as u64;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<FieldDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
+    }
+
+    fn new() -> FieldDescriptorProto {
+        FieldDescriptorProto::new()
+    }
+
+    fn default_instance() -> &'static FieldDescriptorProto {
+        static instance: ::protobuf::lazy::LazyV2<FieldDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(FieldDescriptorProto::new)
+    }
+}
+
+impl ::protobuf::MessageFull for FieldDescriptorProto {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &FieldDescriptorProto| { &m.name },
+                |m: &mut FieldDescriptorProto| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "number",
+                |m: &FieldDescriptorProto| { &m.number },
+                |m: &mut FieldDescriptorProto| { &mut m.number },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldDescriptorProto_Label>>(
+                "label",
+                |m: &FieldDescriptorProto| { &m.label },
+                |m: &mut FieldDescriptorProto| { &mut m.label },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldDescriptorProto_Type>>(
+                "type",
+                |m: &FieldDescriptorProto| { &m.field_type },
+                |m: &mut FieldDescriptorProto| { &mut m.field_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type_name",
+                |m: &FieldDescriptorProto| { &m.type_name },
+                |m: &mut FieldDescriptorProto| { &mut m.type_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "extendee",
+                |m: &FieldDescriptorProto| { &m.extendee },
+                |m: &mut FieldDescriptorProto| { &mut m.extendee },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "default_value",
+                |m: &FieldDescriptorProto| { &m.default_value },
+                |m: &mut FieldDescriptorProto| { &mut m.default_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "oneof_index",
+                |m: &FieldDescriptorProto| { &m.oneof_index },
+                |m: &mut FieldDescriptorProto| { &mut m.oneof_index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "json_name",
+                |m: &FieldDescriptorProto| { &m.json_name },
+                |m: &mut FieldDescriptorProto| { &mut m.json_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<FieldOptions>>(
+                "options",
+                |m: &FieldDescriptorProto| { &m.options },
+                |m: &mut FieldDescriptorProto| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<FieldDescriptorProto>(
+                "FieldDescriptorProto",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+}
+
+impl ::protobuf::Clear for FieldDescriptorProto {
+    fn clear(&mut self) {
+        self.clear_name();
         self.clear_number();
         self.clear_label();
         self.clear_field_type();
@@ -2499,6 +3279,14 @@ impl ::protobuf::reflect::ProtobufValue for FieldDescriptorProto {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for FieldDescriptorProto {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<FieldDescriptorProto> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum FieldDescriptorProto_Type {
     TYPE_DOUBLE = 1,
@@ -2574,16 +3362,21 @@ impl ::protobuf::ProtobufEnum for FieldDescriptorProto_Type {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FieldDescriptorProto_Type>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FieldDescriptorProto_Type", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for FieldDescriptorProto_Type {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("FieldDescriptorProto_Type", file_descriptor_proto())
+        })
     }
 }
 
@@ -2592,7 +3385,7 @@ impl ::std::marker::Copy for FieldDescriptorProto_Type {
 
 impl ::protobuf::reflect::ProtobufValue for FieldDescriptorProto_Type {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -2626,16 +3419,21 @@ impl ::protobuf::ProtobufEnum for FieldDescriptorProto_Label {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FieldDescriptorProto_Label>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FieldDescriptorProto_Label", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for FieldDescriptorProto_Label {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("FieldDescriptorProto_Label", file_descriptor_proto())
+        })
     }
 }
 
@@ -2644,7 +3442,7 @@ impl ::std::marker::Copy for FieldDescriptorProto_Label {
 
 impl ::protobuf::reflect::ProtobufValue for FieldDescriptorProto_Label {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -2656,9 +3454,15 @@ pub struct OneofDescriptorProto {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.OneofDescriptorProto)
 }
 
 impl OneofDescriptorProto {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 2;
+    pub const FIELD_OPTIONS_TAG: u32 = 18;
+
     pub fn new() -> OneofDescriptorProto {
         ::std::default::Default::default()
     }
@@ -2731,6 +3535,8 @@ impl OneofDescriptorProto {
     pub fn get_options(&self) -> &OneofOptions {
         self.options.as_ref().unwrap_or_else(|| OneofOptions::default_instance())
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.OneofDescriptorProto)
 }
 
 impl ::protobuf::Message for OneofDescriptorProto {
@@ -2761,6 +3567,16 @@ impl ::protobuf::Message for OneofDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if let Some(v) = other.options.as_ref() {
+            self.mut_options().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -2794,6 +3610,51 @@ impl ::protobuf::Message for OneofDescriptorProto {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -2812,49 +3673,45 @@ impl ::protobuf::Message for OneofDescriptorProto {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> OneofDescriptorProto {
-        OneofDescriptorProto::new()
+    fn descriptor_static(_: ::std::option::Option<OneofDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OneofDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &OneofDescriptorProto| { &m.name },
-                    |m: &mut OneofDescriptorProto| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OneofOptions>>(
-                    "options",
-                    |m: &OneofDescriptorProto| { &m.options },
-                    |m: &mut OneofDescriptorProto| { &mut m.options },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<OneofDescriptorProto>(
-                    "OneofDescriptorProto",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> OneofDescriptorProto {
+        OneofDescriptorProto::new()
     }
 
     fn default_instance() -> &'static OneofDescriptorProto {
-        static mut instance: ::protobuf::lazy::Lazy<OneofDescriptorProto> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OneofDescriptorProto,
-        };
-        unsafe {
-            instance.get(OneofDescriptorProto::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<OneofDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(OneofDescriptorProto::new)
+    }
+}
+
+impl ::protobuf::MessageFull for OneofDescriptorProto {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &OneofDescriptorProto| { &m.name },
+                |m: &mut OneofDescriptorProto| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<OneofOptions>>(
+                "options",
+                |m: &OneofDescriptorProto| { &m.options },
+                |m: &mut OneofDescriptorProto| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<OneofDescriptorProto>(
+                "OneofDescriptorProto",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -2878,6 +3735,14 @@ impl ::protobuf::reflect::ProtobufValue for OneofDescriptorProto {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for OneofDescriptorProto {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<OneofDescriptorProto> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct EnumDescriptorProto {
     // message fields
@@ -2887,9 +3752,17 @@ pub struct EnumDescriptorProto {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.EnumDescriptorProto)
 }
 
 impl EnumDescriptorProto {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_VALUE_NUMBER: u32 = 2;
+    pub const FIELD_VALUE_TAG: u32 = 18;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 3;
+    pub const FIELD_OPTIONS_TAG: u32 = 26;
+
     pub fn new() -> EnumDescriptorProto {
         ::std::default::Default::default()
     }
@@ -2987,6 +3860,8 @@ impl EnumDescriptorProto {
     pub fn get_options(&self) -> &EnumOptions {
         self.options.as_ref().unwrap_or_else(|| EnumOptions::default_instance())
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.EnumDescriptorProto)
 }
 
 impl ::protobuf::Message for EnumDescriptorProto {
@@ -3025,6 +3900,19 @@ impl ::protobuf::Message for EnumDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        for v in &other.value {
+            self.value.push(v.clone());
+        };
+        if let Some(v) = other.options.as_ref() {
+            self.mut_options().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -3067,6 +3955,64 @@ impl ::protobuf::Message for EnumDescriptorProto {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        for value in &self.value {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        for v in &self.value {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        for value in &self.value {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -3085,54 +4031,50 @@ impl ::protobuf::Message for EnumDescriptorProto {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> EnumDescriptorProto {
-        EnumDescriptorProto::new()
+    fn descriptor_static(_: ::std::option::Option<EnumDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<EnumDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &EnumDescriptorProto| { &m.name },
-                    |m: &mut EnumDescriptorProto| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumValueDescriptorProto>>(
-                    "value",
-                    |m: &EnumDescriptorProto| { &m.value },
-                    |m: &mut EnumDescriptorProto| { &mut m.value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumOptions>>(
-                    "options",
-                    |m: &EnumDescriptorProto| { &m.options },
-                    |m: &mut EnumDescriptorProto| { &mut m.options },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<EnumDescriptorProto>(
-                    "EnumDescriptorProto",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> EnumDescriptorProto {
+        EnumDescriptorProto::new()
     }
 
     fn default_instance() -> &'static EnumDescriptorProto {
-        static mut instance: ::protobuf::lazy::Lazy<EnumDescriptorProto> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const EnumDescriptorProto,
-        };
-        unsafe {
-            instance.get(EnumDescriptorProto::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<EnumDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(EnumDescriptorProto::new)
+    }
+}
+
+impl ::protobuf::MessageFull for EnumDescriptorProto {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &EnumDescriptorProto| { &m.name },
+                |m: &mut EnumDescriptorProto| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumValueDescriptorProto>>(
+                "value",
+                |m: &EnumDescriptorProto| { &m.value },
+                |m: &mut EnumDescriptorProto| { &mut m.value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumOptions>>(
+                "options",
+                |m: &EnumDescriptorProto| { &m.options },
+                |m: &mut EnumDescriptorProto| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<EnumDescriptorProto>(
+                "EnumDescriptorProto",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -3157,6 +4099,14 @@ impl ::protobuf::reflect::ProtobufValue for EnumDescriptorProto {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for EnumDescriptorProto {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<EnumDescriptorProto> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct EnumValueDescriptorProto {
     // message fields
@@ -3166,9 +4116,17 @@ pub struct EnumValueDescriptorProto {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.EnumValueDescriptorProto)
 }
 
 impl EnumValueDescriptorProto {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_NUMBER_NUMBER: u32 = 2;
+    pub const FIELD_NUMBER_TAG: u32 = 16;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 3;
+    pub const FIELD_OPTIONS_TAG: u32 = 26;
+
     pub fn new() -> EnumValueDescriptorProto {
         ::std::default::Default::default()
     }
@@ -3260,6 +4218,8 @@ impl EnumValueDescriptorProto {
     pub fn get_options(&self) -> &EnumValueOptions {
         self.options.as_ref().unwrap_or_else(|| EnumValueOptions::default_instance())
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.EnumValueDescriptorProto)
 }
 
 impl ::protobuf::Message for EnumValueDescriptorProto {
@@ -3297,6 +4257,19 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.number.is_some() {
+            self.number = other.number.clone();
+        }
+        if let Some(v) = other.options.as_ref() {
+            self.mut_options().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -3308,15 +4281,56 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
             my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
         if let Some(ref v) = self.options.as_ref() {
-            let len = v.compute_size();
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.number {
+            os.write_int32(2, v)?;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.number {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
+        sizes.set_slot(slot, my_size);
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
         if let Some(ref v) = self.name.as_ref() {
             os.write_string(1, &v)?;
         }
@@ -3325,15 +4339,28 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
         }
         if let Some(ref v) = self.options.as_ref() {
             os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        if let Some(v) = self.number {
+            my_size += 11;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
     }
 
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
@@ -3354,54 +4381,50 @@ impl ::protobuf::Message for EnumValueDescriptorProto {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> EnumValueDescriptorProto {
-        EnumValueDescriptorProto::new()
+    fn descriptor_static(_: ::std::option::Option<EnumValueDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<EnumValueDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &EnumValueDescriptorProto| { &m.name },
-                    |m: &mut EnumValueDescriptorProto| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "number",
-                    |m: &EnumValueDescriptorProto| { &m.number },
-                    |m: &mut EnumValueDescriptorProto| { &mut m.number },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumValueOptions>>(
-                    "options",
-                    |m: &EnumValueDescriptorProto| { &m.options },
-                    |m: &mut EnumValueDescriptorProto| { &mut m.options },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<EnumValueDescriptorProto>(
-                    "EnumValueDescriptorProto",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> EnumValueDescriptorProto {
+        EnumValueDescriptorProto::new()
     }
 
     fn default_instance() -> &'static EnumValueDescriptorProto {
-        static mut instance: ::protobuf::lazy::Lazy<EnumValueDescriptorProto> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const EnumValueDescriptorProto,
-        };
-        unsafe {
-            instance.get(EnumValueDescriptorProto::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<EnumValueDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(EnumValueDescriptorProto::new)
+    }
+}
+
+impl ::protobuf::MessageFull for EnumValueDescriptorProto {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &EnumValueDescriptorProto| { &m.name },
+                |m: &mut EnumValueDescriptorProto| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "number",
+                |m: &EnumValueDescriptorProto| { &m.number },
+                |m: &mut EnumValueDescriptorProto| { &mut m.number },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumValueOptions>>(
+                "options",
+                |m: &EnumValueDescriptorProto| { &m.options },
+                |m: &mut EnumValueDescriptorProto| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<EnumValueDescriptorProto>(
+                "EnumValueDescriptorProto",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -3426,6 +4449,14 @@ impl ::protobuf::reflect::ProtobufValue for EnumValueDescriptorProto {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for EnumValueDescriptorProto {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<EnumValueDescriptorProto> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct ServiceDescriptorProto {
     // message fields
@@ -3435,9 +4466,17 @@ pub struct ServiceDescriptorProto {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.ServiceDescriptorProto)
 }
 
 impl ServiceDescriptorProto {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_METHOD_NUMBER: u32 = 2;
+    pub const FIELD_METHOD_TAG: u32 = 18;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 3;
+    pub const FIELD_OPTIONS_TAG: u32 = 26;
+
     pub fn new() -> ServiceDescriptorProto {
         ::std::default::Default::default()
     }
@@ -3535,6 +4574,8 @@ impl ServiceDescriptorProto {
     pub fn get_options(&self) -> &ServiceOptions {
         self.options.as_ref().unwrap_or_else(|| ServiceOptions::default_instance())
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.ServiceDescriptorProto)
 }
 
 impl ::protobuf::Message for ServiceDescriptorProto {
@@ -3573,6 +4614,19 @@ impl ::protobuf::Message for ServiceDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        for v in &other.method {
+            self.method.push(v.clone());
+        };
+        if let Some(v) = other.options.as_ref() {
+            self.mut_options().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -3615,6 +4669,64 @@ impl ::protobuf::Message for ServiceDescriptorProto {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        for value in &self.method {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        for v in &self.method {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        for value in &self.method {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -3633,54 +4745,50 @@ impl ::protobuf::Message for ServiceDescriptorProto {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> ServiceDescriptorProto {
-        ServiceDescriptorProto::new()
+    fn descriptor_static(_: ::std::option::Option<ServiceDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ServiceDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &ServiceDescriptorProto| { &m.name },
-                    |m: &mut ServiceDescriptorProto| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MethodDescriptorProto>>(
-                    "method",
-                    |m: &ServiceDescriptorProto| { &m.method },
-                    |m: &mut ServiceDescriptorProto| { &mut m.method },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ServiceOptions>>(
-                    "options",
-                    |m: &ServiceDescriptorProto| { &m.options },
-                    |m: &mut ServiceDescriptorProto| { &mut m.options },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<ServiceDescriptorProto>(
-                    "ServiceDescriptorProto",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> ServiceDescriptorProto {
+        ServiceDescriptorProto::new()
     }
 
     fn default_instance() -> &'static ServiceDescriptorProto {
-        static mut instance: ::protobuf::lazy::Lazy<ServiceDescriptorProto> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ServiceDescriptorProto,
-        };
-        unsafe {
-            instance.get(ServiceDescriptorProto::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<ServiceDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(ServiceDescriptorProto::new)
+    }
+}
+
+impl ::protobuf::MessageFull for ServiceDescriptorProto {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &ServiceDescriptorProto| { &m.name },
+                |m: &mut ServiceDescriptorProto| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MethodDescriptorProto>>(
+                "method",
+                |m: &ServiceDescriptorProto| { &m.method },
+                |m: &mut ServiceDescriptorProto| { &mut m.method },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ServiceOptions>>(
+                "options",
+                |m: &ServiceDescriptorProto| { &m.options },
+                |m: &mut ServiceDescriptorProto| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<ServiceDescriptorProto>(
+                "ServiceDescriptorProto",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -3705,6 +4813,14 @@ impl ::protobuf::reflect::ProtobufValue for ServiceDescriptorProto {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for ServiceDescriptorProto {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<ServiceDescriptorProto> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct MethodDescriptorProto {
     // message fields
@@ -3717,9 +4833,23 @@ pub struct MethodDescriptorProto {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.MethodDescriptorProto)
 }
 
 impl MethodDescriptorProto {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_INPUT_TYPE_NUMBER: u32 = 2;
+    pub const FIELD_INPUT_TYPE_TAG: u32 = 18;
+    pub const FIELD_OUTPUT_TYPE_NUMBER: u32 = 3;
+    pub const FIELD_OUTPUT_TYPE_TAG: u32 = 26;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 4;
+    pub const FIELD_OPTIONS_TAG: u32 = 34;
+    pub const FIELD_CLIENT_STREAMING_NUMBER: u32 = 5;
+    pub const FIELD_CLIENT_STREAMING_TAG: u32 = 40;
+    pub const FIELD_SERVER_STREAMING_NUMBER: u32 = 6;
+    pub const FIELD_SERVER_STREAMING_TAG: u32 = 48;
+
     pub fn new() -> MethodDescriptorProto {
         ::std::default::Default::default()
     }
@@ -3902,6 +5032,8 @@ impl MethodDescriptorProto {
     pub fn get_server_streaming(&self) -> bool {
         self.server_streaming.unwrap_or(false)
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.MethodDescriptorProto)
 }
 
 impl ::protobuf::Message for MethodDescriptorProto {
@@ -3952,6 +5084,28 @@ impl ::protobuf::Message for MethodDescriptorProto {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.input_type.is_some() {
+            self.input_type = other.input_type.clone();
+        }
+        if other.output_type.is_some() {
+            self.output_type = other.output_type.clone();
+        }
+        if let Some(v) = other.options.as_ref() {
+            self.mut_options().merge_from_message(v);
+        }
+        if other.client_streaming.is_some() {
+            self.client_streaming = other.client_streaming.clone();
+        }
+        if other.server_streaming.is_some() {
+            self.server_streaming = other.server_streaming.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -4009,6 +5163,87 @@ impl ::protobuf::Message for MethodDescriptorProto {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.input_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.output_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.client_streaming {
+            my_size += 2;
+        }
+        if let Some(v) = self.server_streaming {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.input_type.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.output_type.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        if let Some(v) = self.client_streaming {
+            os.write_bool(5, v)?;
+        }
+        if let Some(v) = self.server_streaming {
+            os.write_bool(6, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        if let Some(ref v) = self.input_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v) as u64;
+        }
+        if let Some(ref v) = self.output_type.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v) as u64;
+        }
+        if let Some(ref v) = self.options.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        if let Some(v) = self.client_streaming {
+            my_size += 2;
+        }
+        if let Some(v) = self.server_streaming {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -4027,69 +5262,65 @@ impl ::protobuf::Message for MethodDescriptorProto {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> MethodDescriptorProto {
-        MethodDescriptorProto::new()
+    fn descriptor_static(_: ::std::option::Option<MethodDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<MethodDescriptorProto>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &MethodDescriptorProto| { &m.name },
-                    |m: &mut MethodDescriptorProto| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "input_type",
-                    |m: &MethodDescriptorProto| { &m.input_type },
-                    |m: &mut MethodDescriptorProto| { &mut m.input_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "output_type",
-                    |m: &MethodDescriptorProto| { &m.output_type },
-                    |m: &mut MethodDescriptorProto| { &mut m.output_type },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MethodOptions>>(
-                    "options",
-                    |m: &MethodDescriptorProto| { &m.options },
-                    |m: &mut MethodDescriptorProto| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "client_streaming",
-                    |m: &MethodDescriptorProto| { &m.client_streaming },
-                    |m: &mut MethodDescriptorProto| { &mut m.client_streaming },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "server_streaming",
-                    |m: &MethodDescriptorProto| { &m.server_streaming },
-                    |m: &mut MethodDescriptorProto| { &mut m.server_streaming },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<MethodDescriptorProto>(
-                    "MethodDescriptorProto",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> MethodDescriptorProto {
+        MethodDescriptorProto::new()
     }
 
     fn default_instance() -> &'static MethodDescriptorProto {
-        static mut instance: ::protobuf::lazy::Lazy<MethodDescriptorProto> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const MethodDescriptorProto,
-        };
-        unsafe {
-            instance.get(MethodDescriptorProto::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<MethodDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(MethodDescriptorProto::new)
+    }
+}
+
+impl ::protobuf::MessageFull for MethodDescriptorProto {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &MethodDescriptorProto| { &m.name },
+                |m: &mut MethodDescriptorProto| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "input_type",
+                |m: &MethodDescriptorProto| { &m.input_type },
+                |m: &mut MethodDescriptorProto| { &mut m.input_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "output_type",
+                |m: &MethodDescriptorProto| { &m.output_type },
+                |m: &mut MethodDescriptorProto| { &mut m.output_type },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<MethodOptions>>(
+                "options",
+                |m: &MethodDescriptorProto| { &m.options },
+                |m: &mut MethodDescriptorProto| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "client_streaming",
+                |m: &MethodDescriptorProto| { &m.client_streaming },
+                |m: &mut MethodDescriptorProto| { &mut m.client_streaming },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "server_streaming",
+                |m: &MethodDescriptorProto| { &m.server_streaming },
+                |m: &mut MethodDescriptorProto| { &mut m.server_streaming },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<MethodDescriptorProto>(
+                "MethodDescriptorProto",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -4117,6 +5348,14 @@ impl ::protobuf::reflect::ProtobufValue for MethodDescriptorProto {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for MethodDescriptorProto {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<MethodDescriptorProto> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct FileOptions {
     // message fields
@@ -4138,9 +5377,41 @@ pub struct FileOptions {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.FileOptions)
 }
 
 impl FileOptions {
+    pub const FIELD_JAVA_PACKAGE_NUMBER: u32 = 1;
+    pub const FIELD_JAVA_PACKAGE_TAG: u32 = 10;
+    pub const FIELD_JAVA_OUTER_CLASSNAME_NUMBER: u32 = 8;
+    pub const FIELD_JAVA_OUTER_CLASSNAME_TAG: u32 = 66;
+    pub const FIELD_JAVA_MULTIPLE_FILES_NUMBER: u32 = 10;
+    pub const FIELD_JAVA_MULTIPLE_FILES_TAG: u32 = 80;
+    pub const FIELD_JAVA_GENERATE_EQUALS_AND_HASH_NUMBER: u32 = 20;
+    pub const FIELD_JAVA_GENERATE_EQUALS_AND_HASH_TAG: u32 = 160;
+    pub const FIELD_JAVA_STRING_CHECK_UTF8_NUMBER: u32 = 27;
+    pub const FIELD_JAVA_STRING_CHECK_UTF8_TAG: u32 = 216;
+    pub const FIELD_OPTIMIZE_FOR_NUMBER: u32 = 9;
+    pub const FIELD_OPTIMIZE_FOR_TAG: u32 = 72;
+    pub const FIELD_GO_PACKAGE_NUMBER: u32 = 11;
+    pub const FIELD_GO_PACKAGE_TAG: u32 = 90;
+    pub const FIELD_CC_GENERIC_SERVICES_NUMBER: u32 = 16;
+    pub const FIELD_CC_GENERIC_SERVICES_TAG: u32 = 128;
+    pub const FIELD_JAVA_GENERIC_SERVICES_NUMBER: u32 = 17;
+    pub const FIELD_JAVA_GENERIC_SERVICES_TAG: u32 = 136;
+    pub const FIELD_PY_GENERIC_SERVICES_NUMBER: u32 = 18;
+    pub const FIELD_PY_GENERIC_SERVICES_TAG: u32 = 144;
+    pub const FIELD_DEPRECATED_NUMBER: u32 = 23;
+    pub const FIELD_DEPRECATED_TAG: u32 = 184;
+    pub const FIELD_CC_ENABLE_ARENAS_NUMBER: u32 = 31;
+    pub const FIELD_CC_ENABLE_ARENAS_TAG: u32 = 248;
+    pub const FIELD_OBJC_CLASS_PREFIX_NUMBER: u32 = 36;
+    pub const FIELD_OBJC_CLASS_PREFIX_TAG: u32 = 290;
+    pub const FIELD_CSHARP_NAMESPACE_NUMBER: u32 = 37;
+    pub const FIELD_CSHARP_NAMESPACE_TAG: u32 = 298;
+    pub const FIELD_UNINTERPRETED_OPTION_NUMBER: u32 = 999;
+    pub const FIELD_UNINTERPRETED_OPTION_TAG: u32 = 7994;
+
     pub fn new() -> FileOptions {
         ::std::default::Default::default()
     }
@@ -4520,6 +5791,8 @@ impl FileOptions {
     pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
         &self.uninterpreted_option
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.FileOptions)
 }
 
 impl ::protobuf::Message for FileOptions {
@@ -4621,9 +5894,170 @@ impl ::protobuf::Message for FileOptions {
         ::std::result::Result::Ok(())
     }
 
-    // Compute sizes of nested messages
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.java_package.is_some() {
+            self.java_package = other.java_package.clone();
+        }
+        if other.java_outer_classname.is_some() {
+            self.java_outer_classname = other.java_outer_classname.clone();
+        }
+        if other.java_multiple_files.is_some() {
+            self.java_multiple_files = other.java_multiple_files.clone();
+        }
+        if other.java_generate_equals_and_hash.is_some() {
+            self.java_generate_equals_and_hash = other.java_generate_equals_and_hash.clone();
+        }
+        if other.java_string_check_utf8.is_some() {
+            self.java_string_check_utf8 = other.java_string_check_utf8.clone();
+        }
+        if other.optimize_for.is_some() {
+            self.optimize_for = other.optimize_for.clone();
+        }
+        if other.go_package.is_some() {
+            self.go_package = other.go_package.clone();
+        }
+        if other.cc_generic_services.is_some() {
+            self.cc_generic_services = other.cc_generic_services.clone();
+        }
+        if other.java_generic_services.is_some() {
+            self.java_generic_services = other.java_generic_services.clone();
+        }
+        if other.py_generic_services.is_some() {
+            self.py_generic_services = other.py_generic_services.clone();
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if other.cc_enable_arenas.is_some() {
+            self.cc_enable_arenas = other.cc_enable_arenas.clone();
+        }
+        if other.objc_class_prefix.is_some() {
+            self.objc_class_prefix = other.objc_class_prefix.clone();
+        }
+        if other.csharp_namespace.is_some() {
+            self.csharp_namespace = other.csharp_namespace.clone();
+        }
+        for v in &other.uninterpreted_option {
+            self.uninterpreted_option.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.java_package.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.java_outer_classname.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        if let Some(v) = self.java_multiple_files {
+            my_size += 2;
+        }
+        if let Some(v) = self.java_generate_equals_and_hash {
+            my_size += 3;
+        }
+        if let Some(v) = self.java_string_check_utf8 {
+            my_size += 3;
+        }
+        if let Some(v) = self.optimize_for {
+            my_size += ::protobuf::rt::enum_size(9, v);
+        }
+        if let Some(ref v) = self.go_package.as_ref() {
+            my_size += ::protobuf::rt::string_size(11, &v);
+        }
+        if let Some(v) = self.cc_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.java_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.py_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        if let Some(v) = self.cc_enable_arenas {
+            my_size += 3;
+        }
+        if let Some(ref v) = self.objc_class_prefix.as_ref() {
+            my_size += ::protobuf::rt::string_size(36, &v);
+        }
+        if let Some(ref v) = self.csharp_namespace.as_ref() {
+            my_size += ::protobuf::rt::string_size(37, &v);
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.java_package.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.java_outer_classname.as_ref() {
+            os.write_string(8, &v)?;
+        }
+        if let Some(v) = self.java_multiple_files {
+            os.write_bool(10, v)?;
+        }
+        if let Some(v) = self.java_generate_equals_and_hash {
+            os.write_bool(20, v)?;
+        }
+        if let Some(v) = self.java_string_check_utf8 {
+            os.write_bool(27, v)?;
+        }
+        if let Some(v) = self.optimize_for {
+            os.write_enum(9, v.value())?;
+        }
+        if let Some(ref v) = self.go_package.as_ref() {
+            os.write_string(11, &v)?;
+        }
+        if let Some(v) = self.cc_generic_services {
+            os.write_bool(16, v)?;
+        }
+        if let Some(v) = self.java_generic_services {
+            os.write_bool(17, v)?;
+        }
+        if let Some(v) = self.py_generic_services {
+            os.write_bool(18, v)?;
+        }
+        if let Some(v) = self.deprecated {
+            os.write_bool(23, v)?;
+        }
+        if let Some(v) = self.cc_enable_arenas {
+            os.write_bool(31, v)?;
+        }
+        if let Some(ref v) = self.objc_class_prefix.as_ref() {
+            os.write_string(36, &v)?;
+        }
+        if let Some(ref v) = self.csharp_namespace.as_ref() {
+            os.write_string(37, &v)?;
+        }
+        for v in &self.uninterpreted_option {
+            os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    // Compute sizes of nested messages storing them in `sizes`
     #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
         let mut my_size = 0;
         if let Some(ref v) = self.java_package.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
@@ -4668,15 +6102,16 @@ impl ::protobuf::Message for FileOptions {
             my_size += ::protobuf::rt::string_size(37, &v);
         }
         for value in &self.uninterpreted_option {
-            let len = value.compute_size();
+            let len = value.compute_size_with_cache(sizes);
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
+        sizes.set_slot(slot, my_size);
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
         if let Some(ref v) = self.java_package.as_ref() {
             os.write_string(1, &v)?;
         }
@@ -4721,15 +6156,64 @@ impl ::protobuf::Message for FileOptions {
         }
         for v in &self.uninterpreted_option {
             os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.java_package.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        if let Some(ref v) = self.java_outer_classname.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v) as u64;
+        }
+        if let Some(v) = self.java_multiple_files {
+            my_size += 2;
+        }
+        if let Some(v) = self.java_generate_equals_and_hash {
+            my_size += 3;
+        }
+        if let Some(v) = self.java_string_check_utf8 {
+            my_size += 3;
+        }
+        if let Some(v) = self.optimize_for {
+            my_size += 11;
+        }
+        if let Some(ref v) = self.go_package.as_ref() {
+            my_size += ::protobuf::rt::string_size(11, &v) as u64;
+        }
+        if let Some(v) = self.cc_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.java_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.py_generic_services {
+            my_size += 3;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        if let Some(v) = self.cc_enable_arenas {
+            my_size += 3;
+        }
+        if let Some(ref v) = self.objc_class_prefix.as_ref() {
+            my_size += ::protobuf::rt::string_size(36, &v) as u64;
+        }
+        if let Some(ref v) = self.csharp_namespace.as_ref() {
+            my_size += ::protobuf::rt::string_size(37, &v) as u64;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.size_hint_upper_bound();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
     }
 
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
@@ -4750,114 +6234,110 @@ impl ::protobuf::Message for FileOptions {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> FileOptions {
-        FileOptions::new()
+    fn descriptor_static(_: ::std::option::Option<FileOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<FileOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "java_package",
-                    |m: &FileOptions| { &m.java_package },
-                    |m: &mut FileOptions| { &mut m.java_package },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "java_outer_classname",
-                    |m: &FileOptions| { &m.java_outer_classname },
-                    |m: &mut FileOptions| { &mut m.java_outer_classname },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "java_multiple_files",
-                    |m: &FileOptions| { &m.java_multiple_files },
-                    |m: &mut FileOptions| { &mut m.java_multiple_files },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "java_generate_equals_and_hash",
-                    |m: &FileOptions| { &m.java_generate_equals_and_hash },
-                    |m: &mut FileOptions| { &mut m.java_generate_equals_and_hash },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "java_string_check_utf8",
-                    |m: &FileOptions| { &m.java_string_check_utf8 },
-                    |m: &mut FileOptions| { &mut m.java_string_check_utf8 },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FileOptions_OptimizeMode>>(
-                    "optimize_for",
-                    |m: &FileOptions| { &m.optimize_for },
-                    |m: &mut FileOptions| { &mut m.optimize_for },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "go_package",
-                    |m: &FileOptions| { &m.go_package },
-                    |m: &mut FileOptions| { &mut m.go_package },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "cc_generic_services",
-                    |m: &FileOptions| { &m.cc_generic_services },
-                    |m: &mut FileOptions| { &mut m.cc_generic_services },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "java_generic_services",
-                    |m: &FileOptions| { &m.java_generic_services },
-                    |m: &mut FileOptions| { &mut m.java_generic_services },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "py_generic_services",
-                    |m: &FileOptions| { &m.py_generic_services },
-                    |m: &mut FileOptions| { &mut m.py_generic_services },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "deprecated",
-                    |m: &FileOptions| { &m.deprecated },
-                    |m: &mut FileOptions| { &mut m.deprecated },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "cc_enable_arenas",
-                    |m: &FileOptions| { &m.cc_enable_arenas },
-                    |m: &mut FileOptions| { &mut m.cc_enable_arenas },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "objc_class_prefix",
-                    |m: &FileOptions| { &m.objc_class_prefix },
-                    |m: &mut FileOptions| { &mut m.objc_class_prefix },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "csharp_namespace",
-                    |m: &FileOptions| { &m.csharp_namespace },
-                    |m: &mut FileOptions| { &mut m.csharp_namespace },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
-                    "uninterpreted_option",
-                    |m: &FileOptions| { &m.uninterpreted_option },
-                    |m: &mut FileOptions| { &mut m.uninterpreted_option },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<FileOptions>(
-                    "FileOptions",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> FileOptions {
+        FileOptions::new()
     }
 
     fn default_instance() -> &'static FileOptions {
-        static mut instance: ::protobuf::lazy::Lazy<FileOptions> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const FileOptions,
-        };
-        unsafe {
-            instance.get(FileOptions::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<FileOptions> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(FileOptions::new)
+    }
+}
+
+impl ::protobuf::MessageFull for FileOptions {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "java_package",
+                |m: &FileOptions| { &m.java_package },
+                |m: &mut FileOptions| { &mut m.java_package },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "java_outer_classname",
+                |m: &FileOptions| { &m.java_outer_classname },
+                |m: &mut FileOptions| { &mut m.java_outer_classname },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "java_multiple_files",
+                |m: &FileOptions| { &m.java_multiple_files },
+                |m: &mut FileOptions| { &mut m.java_multiple_files },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "java_generate_equals_and_hash",
+                |m: &FileOptions| { &m.java_generate_equals_and_hash },
+                |m: &mut FileOptions| { &mut m.java_generate_equals_and_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "java_string_check_utf8",
+                |m: &FileOptions| { &m.java_string_check_utf8 },
+                |m: &mut FileOptions| { &mut m.java_string_check_utf8 },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FileOptions_OptimizeMode>>(
+                "optimize_for",
+                |m: &FileOptions| { &m.optimize_for },
+                |m: &mut FileOptions| { &mut m.optimize_for },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "go_package",
+                |m: &FileOptions| { &m.go_package },
+                |m: &mut FileOptions| { &mut m.go_package },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "cc_generic_services",
+                |m: &FileOptions| { &m.cc_generic_services },
+                |m: &mut FileOptions| { &mut m.cc_generic_services },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "java_generic_services",
+                |m: &FileOptions| { &m.java_generic_services },
+                |m: &mut FileOptions| { &mut m.java_generic_services },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "py_generic_services",
+                |m: &FileOptions| { &m.py_generic_services },
+                |m: &mut FileOptions| { &mut m.py_generic_services },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "deprecated",
+                |m: &FileOptions| { &m.deprecated },
+                |m: &mut FileOptions| { &mut m.deprecated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "cc_enable_arenas",
+                |m: &FileOptions| { &m.cc_enable_arenas },
+                |m: &mut FileOptions| { &mut m.cc_enable_arenas },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "objc_class_prefix",
+                |m: &FileOptions| { &m.objc_class_prefix },
+                |m: &mut FileOptions| { &mut m.objc_class_prefix },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "csharp_namespace",
+                |m: &FileOptions| { &m.csharp_namespace },
+                |m: &mut FileOptions| { &mut m.csharp_namespace },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
+                "uninterpreted_option",
+                |m: &FileOptions| { &m.uninterpreted_option },
+                |m: &mut FileOptions| { &mut m.uninterpreted_option },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<FileOptions>(
+                "FileOptions",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -4894,6 +6374,14 @@ impl ::protobuf::reflect::ProtobufValue for FileOptions {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for FileOptions {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<FileOptions> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum FileOptions_OptimizeMode {
     SPEED = 1,
@@ -4924,16 +6412,21 @@ impl ::protobuf::ProtobufEnum for FileOptions_OptimizeMode {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FileOptions_OptimizeMode>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FileOptions_OptimizeMode", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for FileOptions_OptimizeMode {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("FileOptions_OptimizeMode", file_descriptor_proto())
+        })
     }
 }
 
@@ -4942,7 +6435,7 @@ impl ::std::marker::Copy for FileOptions_OptimizeMode {
 
 impl ::protobuf::reflect::ProtobufValue for FileOptions_OptimizeMode {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -4957,9 +6450,21 @@ pub struct MessageOptions {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.MessageOptions)
 }
 
 impl MessageOptions {
+    pub const FIELD_MESSAGE_SET_WIRE_FORMAT_NUMBER: u32 = 1;
+    pub const FIELD_MESSAGE_SET_WIRE_FORMAT_TAG: u32 = 8;
+    pub const FIELD_NO_STANDARD_DESCRIPTOR_ACCESSOR_NUMBER: u32 = 2;
+    pub const FIELD_NO_STANDARD_DESCRIPTOR_ACCESSOR_TAG: u32 = 16;
+    pub const FIELD_DEPRECATED_NUMBER: u32 = 3;
+    pub const FIELD_DEPRECATED_TAG: u32 = 24;
+    pub const FIELD_MAP_ENTRY_NUMBER: u32 = 7;
+    pub const FIELD_MAP_ENTRY_TAG: u32 = 56;
+    pub const FIELD_UNINTERPRETED_OPTION_NUMBER: u32 = 999;
+    pub const FIELD_UNINTERPRETED_OPTION_TAG: u32 = 7994;
+
     pub fn new() -> MessageOptions {
         ::std::default::Default::default()
     }
@@ -5064,6 +6569,8 @@ impl MessageOptions {
     pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
         &self.uninterpreted_option
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.MessageOptions)
 }
 
 impl ::protobuf::Message for MessageOptions {
@@ -5119,6 +6626,25 @@ impl ::protobuf::Message for MessageOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.message_set_wire_format.is_some() {
+            self.message_set_wire_format = other.message_set_wire_format.clone();
+        }
+        if other.no_standard_descriptor_accessor.is_some() {
+            self.no_standard_descriptor_accessor = other.no_standard_descriptor_accessor.clone();
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if other.map_entry.is_some() {
+            self.map_entry = other.map_entry.clone();
+        }
+        for v in &other.uninterpreted_option {
+            self.uninterpreted_option.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -5170,6 +6696,78 @@ impl ::protobuf::Message for MessageOptions {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(v) = self.message_set_wire_format {
+            my_size += 2;
+        }
+        if let Some(v) = self.no_standard_descriptor_accessor {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        if let Some(v) = self.map_entry {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.message_set_wire_format {
+            os.write_bool(1, v)?;
+        }
+        if let Some(v) = self.no_standard_descriptor_accessor {
+            os.write_bool(2, v)?;
+        }
+        if let Some(v) = self.deprecated {
+            os.write_bool(3, v)?;
+        }
+        if let Some(v) = self.map_entry {
+            os.write_bool(7, v)?;
+        }
+        for v in &self.uninterpreted_option {
+            os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.message_set_wire_format {
+            my_size += 2;
+        }
+        if let Some(v) = self.no_standard_descriptor_accessor {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        if let Some(v) = self.map_entry {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.size_hint_upper_bound();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -5188,64 +6786,60 @@ impl ::protobuf::Message for MessageOptions {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> MessageOptions {
-        MessageOptions::new()
+    fn descriptor_static(_: ::std::option::Option<MessageOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<MessageOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "message_set_wire_format",
-                    |m: &MessageOptions| { &m.message_set_wire_format },
-                    |m: &mut MessageOptions| { &mut m.message_set_wire_format },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "no_standard_descriptor_accessor",
-                    |m: &MessageOptions| { &m.no_standard_descriptor_accessor },
-                    |m: &mut MessageOptions| { &mut m.no_standard_descriptor_accessor },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "deprecated",
-                    |m: &MessageOptions| { &m.deprecated },
-                    |m: &mut MessageOptions| { &mut m.deprecated },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "map_entry",
-                    |m: &MessageOptions| { &m.map_entry },
-                    |m: &mut MessageOptions| { &mut m.map_entry },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
-                    "uninterpreted_option",
-                    |m: &MessageOptions| { &m.uninterpreted_option },
-                    |m: &mut MessageOptions| { &mut m.uninterpreted_option },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<MessageOptions>(
-                    "MessageOptions",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> MessageOptions {
+        MessageOptions::new()
     }
 
     fn default_instance() -> &'static MessageOptions {
-        static mut instance: ::protobuf::lazy::Lazy<MessageOptions> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const MessageOptions,
-        };
-        unsafe {
-            instance.get(MessageOptions::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<MessageOptions> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(MessageOptions::new)
+    }
+}
+
+impl ::protobuf::MessageFull for MessageOptions {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "message_set_wire_format",
+                |m: &MessageOptions| { &m.message_set_wire_format },
+                |m: &mut MessageOptions| { &mut m.message_set_wire_format },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "no_standard_descriptor_accessor",
+                |m: &MessageOptions| { &m.no_standard_descriptor_accessor },
+                |m: &mut MessageOptions| { &mut m.no_standard_descriptor_accessor },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "deprecated",
+                |m: &MessageOptions| { &m.deprecated },
+                |m: &mut MessageOptions| { &mut m.deprecated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "map_entry",
+                |m: &MessageOptions| { &m.map_entry },
+                |m: &mut MessageOptions| { &mut m.map_entry },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
+                "uninterpreted_option",
+                |m: &MessageOptions| { &m.uninterpreted_option },
+                |m: &mut MessageOptions| { &mut m.uninterpreted_option },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<MessageOptions>(
+                "MessageOptions",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -5272,6 +6866,14 @@ impl ::protobuf::reflect::ProtobufValue for MessageOptions {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for MessageOptions {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<MessageOptions> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct FieldOptions {
     // message fields
@@ -5285,9 +6887,25 @@ pub struct FieldOptions {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.FieldOptions)
 }
 
 impl FieldOptions {
+    pub const FIELD_CTYPE_NUMBER: u32 = 1;
+    pub const FIELD_CTYPE_TAG: u32 = 8;
+    pub const FIELD_PACKED_NUMBER: u32 = 2;
+    pub const FIELD_PACKED_TAG: u32 = 16;
+    pub const FIELD_JSTYPE_NUMBER: u32 = 6;
+    pub const FIELD_JSTYPE_TAG: u32 = 48;
+    pub const FIELD_LAZY_NUMBER: u32 = 5;
+    pub const FIELD_LAZY_TAG: u32 = 40;
+    pub const FIELD_DEPRECATED_NUMBER: u32 = 3;
+    pub const FIELD_DEPRECATED_TAG: u32 = 24;
+    pub const FIELD_WEAK_NUMBER: u32 = 10;
+    pub const FIELD_WEAK_TAG: u32 = 80;
+    pub const FIELD_UNINTERPRETED_OPTION_NUMBER: u32 = 999;
+    pub const FIELD_UNINTERPRETED_OPTION_TAG: u32 = 7994;
+
     pub fn new() -> FieldOptions {
         ::std::default::Default::default()
     }
@@ -5430,6 +7048,8 @@ impl FieldOptions {
     pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
         &self.uninterpreted_option
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.FieldOptions)
 }
 
 impl ::protobuf::Message for FieldOptions {
@@ -5491,9 +7111,98 @@ impl ::protobuf::Message for FieldOptions {
         ::std::result::Result::Ok(())
     }
 
-    // Compute sizes of nested messages
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.ctype.is_some() {
+            self.ctype = other.ctype.clone();
+        }
+        if other.packed.is_some() {
+            self.packed = other.packed.clone();
+        }
+        if other.jstype.is_some() {
+            self.jstype = other.jstype.clone();
+        }
+        if other.lazy.is_some() {
+            self.lazy = other.lazy.clone();
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        if other.weak.is_some() {
+            self.weak = other.weak.clone();
+        }
+        for v in &other.uninterpreted_option {
+            self.uninterpreted_option.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.ctype {
+            my_size += ::protobuf::rt::enum_size(1, v);
+        }
+        if let Some(v) = self.packed {
+            my_size += 2;
+        }
+        if let Some(v) = self.jstype {
+            my_size += ::protobuf::rt::enum_size(6, v);
+        }
+        if let Some(v) = self.lazy {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        if let Some(v) = self.weak {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.ctype {
+            os.write_enum(1, v.value())?;
+        }
+        if let Some(v) = self.packed {
+            os.write_bool(2, v)?;
+        }
+        if let Some(v) = self.jstype {
+            os.write_enum(6, v.value())?;
+        }
+        if let Some(v) = self.lazy {
+            os.write_bool(5, v)?;
+        }
+        if let Some(v) = self.deprecated {
+            os.write_bool(3, v)?;
+        }
+        if let Some(v) = self.weak {
+            os.write_bool(10, v)?;
+        }
+        for v in &self.uninterpreted_option {
+            os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    // Compute sizes of nested messages storing them in `sizes`
     #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
         let mut my_size = 0;
         if let Some(v) = self.ctype {
             my_size += ::protobuf::rt::enum_size(1, v);
@@ -5514,15 +7223,16 @@ impl ::protobuf::Message for FieldOptions {
             my_size += 2;
         }
         for value in &self.uninterpreted_option {
-            let len = value.compute_size();
+            let len = value.compute_size_with_cache(sizes);
             my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
+        sizes.set_slot(slot, my_size);
         my_size
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
         if let Some(v) = self.ctype {
             os.write_enum(1, v.value())?;
         }
@@ -5543,15 +7253,40 @@ impl ::protobuf::Message for FieldOptions {
         }
         for v in &self.uninterpreted_option {
             os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.ctype {
+            my_size += 11;
+        }
+        if let Some(v) = self.packed {
+            my_size += 2;
+        }
+        if let Some(v) = self.jstype {
+            my_size += 11;
+        }
+        if let Some(v) = self.lazy {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        if let Some(v) = self.weak {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.size_hint_upper_bound();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
     }
 
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
@@ -5572,74 +7307,70 @@ impl ::protobuf::Message for FieldOptions {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> FieldOptions {
-        FieldOptions::new()
+    fn descriptor_static(_: ::std::option::Option<FieldOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<FieldOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldOptions_CType>>(
-                    "ctype",
-                    |m: &FieldOptions| { &m.ctype },
-                    |m: &mut FieldOptions| { &mut m.ctype },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "packed",
-                    |m: &FieldOptions| { &m.packed },
-                    |m: &mut FieldOptions| { &mut m.packed },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldOptions_JSType>>(
-                    "jstype",
-                    |m: &FieldOptions| { &m.jstype },
-                    |m: &mut FieldOptions| { &mut m.jstype },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "lazy",
-                    |m: &FieldOptions| { &m.lazy },
-                    |m: &mut FieldOptions| { &mut m.lazy },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "deprecated",
-                    |m: &FieldOptions| { &m.deprecated },
-                    |m: &mut FieldOptions| { &mut m.deprecated },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "weak",
-                    |m: &FieldOptions| { &m.weak },
-                    |m: &mut FieldOptions| { &mut m.weak },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
-                    "uninterpreted_option",
-                    |m: &FieldOptions| { &m.uninterpreted_option },
-                    |m: &mut FieldOptions| { &mut m.uninterpreted_option },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<FieldOptions>(
-                    "FieldOptions",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> FieldOptions {
+        FieldOptions::new()
     }
 
     fn default_instance() -> &'static FieldOptions {
-        static mut instance: ::protobuf::lazy::Lazy<FieldOptions> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const FieldOptions,
-        };
-        unsafe {
-            instance.get(FieldOptions::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<FieldOptions> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(FieldOptions::new)
+    }
+}
+
+impl ::protobuf::MessageFull for FieldOptions {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldOptions_CType>>(
+                "ctype",
+                |m: &FieldOptions| { &m.ctype },
+                |m: &mut FieldOptions| { &mut m.ctype },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "packed",
+                |m: &FieldOptions| { &m.packed },
+                |m: &mut FieldOptions| { &mut m.packed },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<FieldOptions_JSType>>(
+                "jstype",
+                |m: &FieldOptions| { &m.jstype },
+                |m: &mut FieldOptions| { &mut m.jstype },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "lazy",
+                |m: &FieldOptions| { &m.lazy },
+                |m: &mut FieldOptions| { &mut m.lazy },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "deprecated",
+                |m: &FieldOptions| { &m.deprecated },
+                |m: &mut FieldOptions| { &mut m.deprecated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "weak",
+                |m: &FieldOptions| { &m.weak },
+                |m: &mut FieldOptions| { &mut m.weak },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
+                "uninterpreted_option",
+                |m: &FieldOptions| { &m.uninterpreted_option },
+                |m: &mut FieldOptions| { &mut m.uninterpreted_option },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<FieldOptions>(
+                "FieldOptions",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -5668,6 +7399,14 @@ impl ::protobuf::reflect::ProtobufValue for FieldOptions {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for FieldOptions {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<FieldOptions> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum FieldOptions_CType {
     STRING = 0,
@@ -5698,16 +7437,21 @@ impl ::protobuf::ProtobufEnum for FieldOptions_CType {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FieldOptions_CType>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FieldOptions_CType", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for FieldOptions_CType {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("FieldOptions_CType", file_descriptor_proto())
+        })
     }
 }
 
@@ -5716,7 +7460,7 @@ impl ::std::marker::Copy for FieldOptions_CType {
 
 impl ::protobuf::reflect::ProtobufValue for FieldOptions_CType {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -5750,16 +7494,21 @@ impl ::protobuf::ProtobufEnum for FieldOptions_JSType {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<FieldOptions_JSType>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("FieldOptions_JSType", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for FieldOptions_JSType {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("FieldOptions_JSType", file_descriptor_proto())
+        })
     }
 }
 
@@ -5768,7 +7517,7 @@ impl ::std::marker::Copy for FieldOptions_JSType {
 
 impl ::protobuf::reflect::ProtobufValue for FieldOptions_JSType {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -5779,9 +7528,13 @@ pub struct OneofOptions {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.OneofOptions)
 }
 
 impl OneofOptions {
+    pub const FIELD_UNINTERPRETED_OPTION_NUMBER: u32 = 999;
+    pub const FIELD_UNINTERPRETED_OPTION_TAG: u32 = 7994;
+
     pub fn new() -> OneofOptions {
         ::std::default::Default::default()
     }
@@ -5810,6 +7563,8 @@ impl OneofOptions {
     pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
         &self.uninterpreted_option
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.OneofOptions)
 }
 
 impl ::protobuf::Message for OneofOptions {
@@ -5837,6 +7592,13 @@ impl ::protobuf::Message for OneofOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.uninterpreted_option {
+            self.uninterpreted_option.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -5864,6 +7626,42 @@ impl ::protobuf::Message for OneofOptions {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        for value in &self.uninterpreted_option {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.uninterpreted_option {
+            os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.uninterpreted_option {
+            let len = value.size_hint_upper_bound();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -5882,44 +7680,40 @@ impl ::protobuf::Message for OneofOptions {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> OneofOptions {
-        OneofOptions::new()
+    fn descriptor_static(_: ::std::option::Option<OneofOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<OneofOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
-                    "uninterpreted_option",
-                    |m: &OneofOptions| { &m.uninterpreted_option },
-                    |m: &mut OneofOptions| { &mut m.uninterpreted_option },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<OneofOptions>(
-                    "OneofOptions",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> OneofOptions {
+        OneofOptions::new()
     }
 
     fn default_instance() -> &'static OneofOptions {
-        static mut instance: ::protobuf::lazy::Lazy<OneofOptions> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const OneofOptions,
-        };
-        unsafe {
-            instance.get(OneofOptions::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<OneofOptions> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(OneofOptions::new)
+    }
+}
+
+impl ::protobuf::MessageFull for OneofOptions {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
+                "uninterpreted_option",
+                |m: &OneofOptions| { &m.uninterpreted_option },
+                |m: &mut OneofOptions| { &mut m.uninterpreted_option },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<OneofOptions>(
+                "OneofOptions",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -5942,6 +7736,14 @@ impl ::protobuf::reflect::ProtobufValue for OneofOptions {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for OneofOptions {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<OneofOptions> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct EnumOptions {
     // message fields
@@ -5951,9 +7753,17 @@ pub struct EnumOptions {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.EnumOptions)
 }
 
 impl EnumOptions {
+    pub const FIELD_ALLOW_ALIAS_NUMBER: u32 = 2;
+    pub const FIELD_ALLOW_ALIAS_TAG: u32 = 16;
+    pub const FIELD_DEPRECATED_NUMBER: u32 = 3;
+    pub const FIELD_DEPRECATED_TAG: u32 = 24;
+    pub const FIELD_UNINTERPRETED_OPTION_NUMBER: u32 = 999;
+    pub const FIELD_UNINTERPRETED_OPTION_TAG: u32 = 7994;
+
     pub fn new() -> EnumOptions {
         ::std::default::Default::default()
     }
@@ -6020,6 +7830,8 @@ impl EnumOptions {
     pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
         &self.uninterpreted_option
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.EnumOptions)
 }
 
 impl ::protobuf::Message for EnumOptions {
@@ -6061,6 +7873,19 @@ impl ::protobuf::Message for EnumOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.allow_alias.is_some() {
+            self.allow_alias = other.allow_alias.clone();
+        }
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        for v in &other.uninterpreted_option {
+            self.uninterpreted_option.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -6100,6 +7925,60 @@ impl ::protobuf::Message for EnumOptions {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(v) = self.allow_alias {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.allow_alias {
+            os.write_bool(2, v)?;
+        }
+        if let Some(v) = self.deprecated {
+            os.write_bool(3, v)?;
+        }
+        for v in &self.uninterpreted_option {
+            os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.allow_alias {
+            my_size += 2;
+        }
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.size_hint_upper_bound();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -6118,54 +7997,50 @@ impl ::protobuf::Message for EnumOptions {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> EnumOptions {
-        EnumOptions::new()
+    fn descriptor_static(_: ::std::option::Option<EnumOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<EnumOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "allow_alias",
-                    |m: &EnumOptions| { &m.allow_alias },
-                    |m: &mut EnumOptions| { &mut m.allow_alias },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "deprecated",
-                    |m: &EnumOptions| { &m.deprecated },
-                    |m: &mut EnumOptions| { &mut m.deprecated },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
-                    "uninterpreted_option",
-                    |m: &EnumOptions| { &m.uninterpreted_option },
-                    |m: &mut EnumOptions| { &mut m.uninterpreted_option },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<EnumOptions>(
-                    "EnumOptions",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> EnumOptions {
+        EnumOptions::new()
     }
 
     fn default_instance() -> &'static EnumOptions {
-        static mut instance: ::protobuf::lazy::Lazy<EnumOptions> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const EnumOptions,
-        };
-        unsafe {
-            instance.get(EnumOptions::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<EnumOptions> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(EnumOptions::new)
+    }
+}
+
+impl ::protobuf::MessageFull for EnumOptions {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "allow_alias",
+                |m: &EnumOptions| { &m.allow_alias },
+                |m: &mut EnumOptions| { &mut m.allow_alias },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "deprecated",
+                |m: &EnumOptions| { &m.deprecated },
+                |m: &mut EnumOptions| { &mut m.deprecated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
+                "uninterpreted_option",
+                |m: &EnumOptions| { &m.uninterpreted_option },
+                |m: &mut EnumOptions| { &mut m.uninterpreted_option },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<EnumOptions>(
+                "EnumOptions",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -6190,6 +8065,14 @@ impl ::protobuf::reflect::ProtobufValue for EnumOptions {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for EnumOptions {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<EnumOptions> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct EnumValueOptions {
     // message fields
@@ -6198,9 +8081,15 @@ pub struct EnumValueOptions {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.EnumValueOptions)
 }
 
 impl EnumValueOptions {
+    pub const FIELD_DEPRECATED_NUMBER: u32 = 1;
+    pub const FIELD_DEPRECATED_TAG: u32 = 8;
+    pub const FIELD_UNINTERPRETED_OPTION_NUMBER: u32 = 999;
+    pub const FIELD_UNINTERPRETED_OPTION_TAG: u32 = 7994;
+
     pub fn new() -> EnumValueOptions {
         ::std::default::Default::default()
     }
@@ -6248,6 +8137,8 @@ impl EnumValueOptions {
     pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
         &self.uninterpreted_option
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.EnumValueOptions)
 }
 
 impl ::protobuf::Message for EnumValueOptions {
@@ -6282,6 +8173,16 @@ impl ::protobuf::Message for EnumValueOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        for v in &other.uninterpreted_option {
+            self.uninterpreted_option.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -6315,6 +8216,51 @@ impl ::protobuf::Message for EnumValueOptions {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.deprecated {
+            os.write_bool(1, v)?;
+        }
+        for v in &self.uninterpreted_option {
+            os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 2;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.size_hint_upper_bound();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -6333,49 +8279,45 @@ impl ::protobuf::Message for EnumValueOptions {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> EnumValueOptions {
-        EnumValueOptions::new()
+    fn descriptor_static(_: ::std::option::Option<EnumValueOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<EnumValueOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "deprecated",
-                    |m: &EnumValueOptions| { &m.deprecated },
-                    |m: &mut EnumValueOptions| { &mut m.deprecated },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
-                    "uninterpreted_option",
-                    |m: &EnumValueOptions| { &m.uninterpreted_option },
-                    |m: &mut EnumValueOptions| { &mut m.uninterpreted_option },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<EnumValueOptions>(
-                    "EnumValueOptions",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> EnumValueOptions {
+        EnumValueOptions::new()
     }
 
     fn default_instance() -> &'static EnumValueOptions {
-        static mut instance: ::protobuf::lazy::Lazy<EnumValueOptions> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const EnumValueOptions,
-        };
-        unsafe {
-            instance.get(EnumValueOptions::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<EnumValueOptions> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(EnumValueOptions::new)
+    }
+}
+
+impl ::protobuf::MessageFull for EnumValueOptions {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "deprecated",
+                |m: &EnumValueOptions| { &m.deprecated },
+                |m: &mut EnumValueOptions| { &mut m.deprecated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
+                "uninterpreted_option",
+                |m: &EnumValueOptions| { &m.uninterpreted_option },
+                |m: &mut EnumValueOptions| { &mut m.uninterpreted_option },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<EnumValueOptions>(
+                "EnumValueOptions",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -6399,6 +8341,14 @@ impl ::protobuf::reflect::ProtobufValue for EnumValueOptions {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for EnumValueOptions {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<EnumValueOptions> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct ServiceOptions {
     // message fields
@@ -6407,9 +8357,15 @@ pub struct ServiceOptions {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.ServiceOptions)
 }
 
 impl ServiceOptions {
+    pub const FIELD_DEPRECATED_NUMBER: u32 = 33;
+    pub const FIELD_DEPRECATED_TAG: u32 = 264;
+    pub const FIELD_UNINTERPRETED_OPTION_NUMBER: u32 = 999;
+    pub const FIELD_UNINTERPRETED_OPTION_TAG: u32 = 7994;
+
     pub fn new() -> ServiceOptions {
         ::std::default::Default::default()
     }
@@ -6457,6 +8413,8 @@ impl ServiceOptions {
     pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
         &self.uninterpreted_option
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.ServiceOptions)
 }
 
 impl ::protobuf::Message for ServiceOptions {
@@ -6488,7 +8446,17 @@ impl ::protobuf::Message for ServiceOptions {
                 },
             };
         }
-        ::std::result::Result::Ok(())
+        ::std::result::Result::Ok(())
+    }
+
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        for v in &other.uninterpreted_option {
+            self.uninterpreted_option.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
     }
 
     // Compute sizes of nested messages
@@ -6524,6 +8492,51 @@ impl ::protobuf::Message for ServiceOptions {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.deprecated {
+            os.write_bool(33, v)?;
+        }
+        for v in &self.uninterpreted_option {
+            os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.size_hint_upper_bound();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -6542,49 +8555,45 @@ impl ::protobuf::Message for ServiceOptions {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> ServiceOptions {
-        ServiceOptions::new()
+    fn descriptor_static(_: ::std::option::Option<ServiceOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ServiceOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "deprecated",
-                    |m: &ServiceOptions| { &m.deprecated },
-                    |m: &mut ServiceOptions| { &mut m.deprecated },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
-                    "uninterpreted_option",
-                    |m: &ServiceOptions| { &m.uninterpreted_option },
-                    |m: &mut ServiceOptions| { &mut m.uninterpreted_option },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<ServiceOptions>(
-                    "ServiceOptions",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> ServiceOptions {
+        ServiceOptions::new()
     }
 
     fn default_instance() -> &'static ServiceOptions {
-        static mut instance: ::protobuf::lazy::Lazy<ServiceOptions> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ServiceOptions,
-        };
-        unsafe {
-            instance.get(ServiceOptions::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<ServiceOptions> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(ServiceOptions::new)
+    }
+}
+
+impl ::protobuf::MessageFull for ServiceOptions {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "deprecated",
+                |m: &ServiceOptions| { &m.deprecated },
+                |m: &mut ServiceOptions| { &mut m.deprecated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
+                "uninterpreted_option",
+                |m: &ServiceOptions| { &m.uninterpreted_option },
+                |m: &mut ServiceOptions| { &mut m.uninterpreted_option },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<ServiceOptions>(
+                "ServiceOptions",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -6608,6 +8617,14 @@ impl ::protobuf::reflect::ProtobufValue for ServiceOptions {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for ServiceOptions {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<ServiceOptions> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct MethodOptions {
     // message fields
@@ -6616,9 +8633,15 @@ pub struct MethodOptions {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.MethodOptions)
 }
 
 impl MethodOptions {
+    pub const FIELD_DEPRECATED_NUMBER: u32 = 33;
+    pub const FIELD_DEPRECATED_TAG: u32 = 264;
+    pub const FIELD_UNINTERPRETED_OPTION_NUMBER: u32 = 999;
+    pub const FIELD_UNINTERPRETED_OPTION_TAG: u32 = 7994;
+
     pub fn new() -> MethodOptions {
         ::std::default::Default::default()
     }
@@ -6666,6 +8689,8 @@ impl MethodOptions {
     pub fn get_uninterpreted_option(&self) -> &[UninterpretedOption] {
         &self.uninterpreted_option
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.MethodOptions)
 }
 
 impl ::protobuf::Message for MethodOptions {
@@ -6700,6 +8725,16 @@ impl ::protobuf::Message for MethodOptions {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.deprecated.is_some() {
+            self.deprecated = other.deprecated.clone();
+        }
+        for v in &other.uninterpreted_option {
+            self.uninterpreted_option.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -6733,6 +8768,51 @@ impl ::protobuf::Message for MethodOptions {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.deprecated {
+            os.write_bool(33, v)?;
+        }
+        for v in &self.uninterpreted_option {
+            os.write_tag(999, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(v) = self.deprecated {
+            my_size += 3;
+        }
+        for value in &self.uninterpreted_option {
+            let len = value.size_hint_upper_bound();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -6751,49 +8831,45 @@ impl ::protobuf::Message for MethodOptions {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> MethodOptions {
-        MethodOptions::new()
+    fn descriptor_static(_: ::std::option::Option<MethodOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<MethodOptions>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "deprecated",
-                    |m: &MethodOptions| { &m.deprecated },
-                    |m: &mut MethodOptions| { &mut m.deprecated },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
-                    "uninterpreted_option",
-                    |m: &MethodOptions| { &m.uninterpreted_option },
-                    |m: &mut MethodOptions| { &mut m.uninterpreted_option },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<MethodOptions>(
-                    "MethodOptions",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> MethodOptions {
+        MethodOptions::new()
     }
 
     fn default_instance() -> &'static MethodOptions {
-        static mut instance: ::protobuf::lazy::Lazy<MethodOptions> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const MethodOptions,
-        };
-        unsafe {
-            instance.get(MethodOptions::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<MethodOptions> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(MethodOptions::new)
+    }
+}
+
+impl ::protobuf::MessageFull for MethodOptions {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "deprecated",
+                |m: &MethodOptions| { &m.deprecated },
+                |m: &mut MethodOptions| { &mut m.deprecated },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption>>(
+                "uninterpreted_option",
+                |m: &MethodOptions| { &m.uninterpreted_option },
+                |m: &mut MethodOptions| { &mut m.uninterpreted_option },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<MethodOptions>(
+                "MethodOptions",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -6817,6 +8893,14 @@ impl ::protobuf::reflect::ProtobufValue for MethodOptions {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for MethodOptions {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<MethodOptions> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct UninterpretedOption {
     // message fields
@@ -6830,9 +8914,25 @@ pub struct UninterpretedOption {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.UninterpretedOption)
 }
 
 impl UninterpretedOption {
+    pub const FIELD_NAME_NUMBER: u32 = 2;
+    pub const FIELD_NAME_TAG: u32 = 18;
+    pub const FIELD_IDENTIFIER_VALUE_NUMBER: u32 = 3;
+    pub const FIELD_IDENTIFIER_VALUE_TAG: u32 = 26;
+    pub const FIELD_POSITIVE_INT_VALUE_NUMBER: u32 = 4;
+    pub const FIELD_POSITIVE_INT_VALUE_TAG: u32 = 32;
+    pub const FIELD_NEGATIVE_INT_VALUE_NUMBER: u32 = 5;
+    pub const FIELD_NEGATIVE_INT_VALUE_TAG: u32 = 40;
+    pub const FIELD_DOUBLE_VALUE_NUMBER: u32 = 6;
+    pub const FIELD_DOUBLE_VALUE_TAG: u32 = 49;
+    pub const FIELD_STRING_VALUE_NUMBER: u32 = 7;
+    pub const FIELD_STRING_VALUE_TAG: u32 = 58;
+    pub const FIELD_AGGREGATE_VALUE_NUMBER: u32 = 8;
+    pub const FIELD_AGGREGATE_VALUE_TAG: u32 = 66;
+
     pub fn new() -> UninterpretedOption {
         ::std::default::Default::default()
     }
@@ -7026,6 +9126,8 @@ impl UninterpretedOption {
             None => "",
         }
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.UninterpretedOption)
 }
 
 impl ::protobuf::Message for UninterpretedOption {
@@ -7083,6 +9185,31 @@ impl ::protobuf::Message for UninterpretedOption {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.name {
+            self.name.push(v.clone());
+        };
+        if other.identifier_value.is_some() {
+            self.identifier_value = other.identifier_value.clone();
+        }
+        if other.positive_int_value.is_some() {
+            self.positive_int_value = other.positive_int_value.clone();
+        }
+        if other.negative_int_value.is_some() {
+            self.negative_int_value = other.negative_int_value.clone();
+        }
+        if other.double_value.is_some() {
+            self.double_value = other.double_value.clone();
+        }
+        if other.string_value.is_some() {
+            self.string_value = other.string_value.clone();
+        }
+        if other.aggregate_value.is_some() {
+            self.aggregate_value = other.aggregate_value.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -7146,6 +9273,96 @@ impl ::protobuf::Message for UninterpretedOption {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        for value in &self.name {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.identifier_value.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.positive_int_value {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.negative_int_value {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.double_value {
+            my_size += 9;
+        }
+        if let Some(ref v) = self.string_value.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(7, &v);
+        }
+        if let Some(ref v) = self.aggregate_value.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.name {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if let Some(ref v) = self.identifier_value.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.positive_int_value {
+            os.write_uint64(4, v)?;
+        }
+        if let Some(v) = self.negative_int_value {
+            os.write_int64(5, v)?;
+        }
+        if let Some(v) = self.double_value {
+            os.write_double(6, v)?;
+        }
+        if let Some(ref v) = self.string_value.as_ref() {
+            os.write_bytes(7, &v)?;
+        }
+        if let Some(ref v) = self.aggregate_value.as_ref() {
+            os.write_string(8, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.name {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if let Some(ref v) = self.identifier_value.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v) as u64;
+        }
+        if let Some(v) = self.positive_int_value {
+            my_size += 11;
+        }
+        if let Some(v) = self.negative_int_value {
+            my_size += 11;
+        }
+        if let Some(v) = self.double_value {
+            my_size += 9;
+        }
+        if let Some(ref v) = self.string_value.as_ref() {
+            my_size += ::protobuf::rt::bytes_size(7, &v) as u64;
+        }
+        if let Some(ref v) = self.aggregate_value.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v) as u64;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -7164,74 +9381,70 @@ impl ::protobuf::Message for UninterpretedOption {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> UninterpretedOption {
-        UninterpretedOption::new()
+    fn descriptor_static(_: ::std::option::Option<UninterpretedOption>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<UninterpretedOption>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption_NamePart>>(
-                    "name",
-                    |m: &UninterpretedOption| { &m.name },
-                    |m: &mut UninterpretedOption| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "identifier_value",
-                    |m: &UninterpretedOption| { &m.identifier_value },
-                    |m: &mut UninterpretedOption| { &mut m.identifier_value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "positive_int_value",
-                    |m: &UninterpretedOption| { &m.positive_int_value },
-                    |m: &mut UninterpretedOption| { &mut m.positive_int_value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "negative_int_value",
-                    |m: &UninterpretedOption| { &m.negative_int_value },
-                    |m: &mut UninterpretedOption| { &mut m.negative_int_value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
-                    "double_value",
-                    |m: &UninterpretedOption| { &m.double_value },
-                    |m: &mut UninterpretedOption| { &mut m.double_value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "string_value",
-                    |m: &UninterpretedOption| { &m.string_value },
-                    |m: &mut UninterpretedOption| { &mut m.string_value },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "aggregate_value",
-                    |m: &UninterpretedOption| { &m.aggregate_value },
-                    |m: &mut UninterpretedOption| { &mut m.aggregate_value },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<UninterpretedOption>(
-                    "UninterpretedOption",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> UninterpretedOption {
+        UninterpretedOption::new()
     }
 
     fn default_instance() -> &'static UninterpretedOption {
-        static mut instance: ::protobuf::lazy::Lazy<UninterpretedOption> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const UninterpretedOption,
-        };
-        unsafe {
-            instance.get(UninterpretedOption::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<UninterpretedOption> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(UninterpretedOption::new)
+    }
+}
+
+impl ::protobuf::MessageFull for UninterpretedOption {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UninterpretedOption_NamePart>>(
+                "name",
+                |m: &UninterpretedOption| { &m.name },
+                |m: &mut UninterpretedOption| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "identifier_value",
+                |m: &UninterpretedOption| { &m.identifier_value },
+                |m: &mut UninterpretedOption| { &mut m.identifier_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "positive_int_value",
+                |m: &UninterpretedOption| { &m.positive_int_value },
+                |m: &mut UninterpretedOption| { &mut m.positive_int_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "negative_int_value",
+                |m: &UninterpretedOption| { &m.negative_int_value },
+                |m: &mut UninterpretedOption| { &mut m.negative_int_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeDouble>(
+                "double_value",
+                |m: &UninterpretedOption| { &m.double_value },
+                |m: &mut UninterpretedOption| { &mut m.double_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "string_value",
+                |m: &UninterpretedOption| { &m.string_value },
+                |m: &mut UninterpretedOption| { &mut m.string_value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "aggregate_value",
+                |m: &UninterpretedOption| { &m.aggregate_value },
+                |m: &mut UninterpretedOption| { &mut m.aggregate_value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<UninterpretedOption>(
+                "UninterpretedOption",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -7260,6 +9473,14 @@ impl ::protobuf::reflect::ProtobufValue for UninterpretedOption {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for UninterpretedOption {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<UninterpretedOption> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct UninterpretedOption_NamePart {
     // message fields
@@ -7268,9 +9489,15 @@ pub struct UninterpretedOption_NamePart {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.UninterpretedOption.NamePart)
 }
 
 impl UninterpretedOption_NamePart {
+    pub const FIELD_NAME_PART_NUMBER: u32 = 1;
+    pub const FIELD_NAME_PART_TAG: u32 = 10;
+    pub const FIELD_IS_EXTENSION_NUMBER: u32 = 2;
+    pub const FIELD_IS_EXTENSION_TAG: u32 = 16;
+
     pub fn new() -> UninterpretedOption_NamePart {
         ::std::default::Default::default()
     }
@@ -7329,6 +9556,8 @@ impl UninterpretedOption_NamePart {
     pub fn get_is_extension(&self) -> bool {
         self.is_extension.unwrap_or(false)
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.UninterpretedOption.NamePart)
 }
 
 impl ::protobuf::Message for UninterpretedOption_NamePart {
@@ -7364,6 +9593,16 @@ impl ::protobuf::Message for UninterpretedOption_NamePart {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name_part.is_some() {
+            self.name_part = other.name_part.clone();
+        }
+        if other.is_extension.is_some() {
+            self.is_extension = other.is_extension.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -7394,6 +9633,47 @@ impl ::protobuf::Message for UninterpretedOption_NamePart {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name_part.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.is_extension {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name_part.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.is_extension {
+            os.write_bool(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name_part.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        if let Some(v) = self.is_extension {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -7412,49 +9692,45 @@ impl ::protobuf::Message for UninterpretedOption_NamePart {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> UninterpretedOption_NamePart {
-        UninterpretedOption_NamePart::new()
+    fn descriptor_static(_: ::std::option::Option<UninterpretedOption_NamePart>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<UninterpretedOption_NamePart>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name_part",
-                    |m: &UninterpretedOption_NamePart| { &m.name_part },
-                    |m: &mut UninterpretedOption_NamePart| { &mut m.name_part },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "is_extension",
-                    |m: &UninterpretedOption_NamePart| { &m.is_extension },
-                    |m: &mut UninterpretedOption_NamePart| { &mut m.is_extension },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<UninterpretedOption_NamePart>(
-                    "UninterpretedOption_NamePart",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> UninterpretedOption_NamePart {
+        UninterpretedOption_NamePart::new()
     }
 
     fn default_instance() -> &'static UninterpretedOption_NamePart {
-        static mut instance: ::protobuf::lazy::Lazy<UninterpretedOption_NamePart> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const UninterpretedOption_NamePart,
-        };
-        unsafe {
-            instance.get(UninterpretedOption_NamePart::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<UninterpretedOption_NamePart> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(UninterpretedOption_NamePart::new)
+    }
+}
+
+impl ::protobuf::MessageFull for UninterpretedOption_NamePart {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name_part",
+                |m: &UninterpretedOption_NamePart| { &m.name_part },
+                |m: &mut UninterpretedOption_NamePart| { &mut m.name_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "is_extension",
+                |m: &UninterpretedOption_NamePart| { &m.is_extension },
+                |m: &mut UninterpretedOption_NamePart| { &mut m.is_extension },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<UninterpretedOption_NamePart>(
+                "UninterpretedOption_NamePart",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -7472,9 +9748,17 @@ impl ::std::fmt::Debug for UninterpretedOption_NamePart {
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for UninterpretedOption_NamePart {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+impl ::protobuf::reflect::ProtobufValue for UninterpretedOption_NamePart {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for UninterpretedOption_NamePart {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<UninterpretedOption_NamePart> {
+        ::protobuf::parse_from_bytes(bytes)
     }
 }
 
@@ -7485,9 +9769,13 @@ pub struct SourceCodeInfo {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.SourceCodeInfo)
 }
 
 impl SourceCodeInfo {
+    pub const FIELD_LOCATION_NUMBER: u32 = 1;
+    pub const FIELD_LOCATION_TAG: u32 = 10;
+
     pub fn new() -> SourceCodeInfo {
         ::std::default::Default::default()
     }
@@ -7516,6 +9804,8 @@ impl SourceCodeInfo {
     pub fn get_location(&self) -> &[SourceCodeInfo_Location] {
         &self.location
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.SourceCodeInfo)
 }
 
 impl ::protobuf::Message for SourceCodeInfo {
@@ -7543,6 +9833,13 @@ impl ::protobuf::Message for SourceCodeInfo {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.location {
+            self.location.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -7570,6 +9867,42 @@ impl ::protobuf::Message for SourceCodeInfo {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        for value in &self.location {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.location {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.location {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -7588,44 +9921,40 @@ impl ::protobuf::Message for SourceCodeInfo {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> SourceCodeInfo {
-        SourceCodeInfo::new()
+    fn descriptor_static(_: ::std::option::Option<SourceCodeInfo>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SourceCodeInfo>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SourceCodeInfo_Location>>(
-                    "location",
-                    |m: &SourceCodeInfo| { &m.location },
-                    |m: &mut SourceCodeInfo| { &mut m.location },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<SourceCodeInfo>(
-                    "SourceCodeInfo",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> SourceCodeInfo {
+        SourceCodeInfo::new()
     }
 
     fn default_instance() -> &'static SourceCodeInfo {
-        static mut instance: ::protobuf::lazy::Lazy<SourceCodeInfo> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SourceCodeInfo,
-        };
-        unsafe {
-            instance.get(SourceCodeInfo::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<SourceCodeInfo> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(SourceCodeInfo::new)
+    }
+}
+
+impl ::protobuf::MessageFull for SourceCodeInfo {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<SourceCodeInfo_Location>>(
+                "location",
+                |m: &SourceCodeInfo| { &m.location },
+                |m: &mut SourceCodeInfo| { &mut m.location },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<SourceCodeInfo>(
+                "SourceCodeInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -7648,6 +9977,14 @@ impl ::protobuf::reflect::ProtobufValue for SourceCodeInfo {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for SourceCodeInfo {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<SourceCodeInfo> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct SourceCodeInfo_Location {
     // message fields
@@ -7659,9 +9996,21 @@ pub struct SourceCodeInfo_Location {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.SourceCodeInfo.Location)
 }
 
 impl SourceCodeInfo_Location {
+    pub const FIELD_PATH_NUMBER: u32 = 1;
+    pub const FIELD_PATH_TAG: u32 = 10;
+    pub const FIELD_SPAN_NUMBER: u32 = 2;
+    pub const FIELD_SPAN_TAG: u32 = 18;
+    pub const FIELD_LEADING_COMMENTS_NUMBER: u32 = 3;
+    pub const FIELD_LEADING_COMMENTS_TAG: u32 = 26;
+    pub const FIELD_TRAILING_COMMENTS_NUMBER: u32 = 4;
+    pub const FIELD_TRAILING_COMMENTS_TAG: u32 = 34;
+    pub const FIELD_LEADING_DETACHED_COMMENTS_NUMBER: u32 = 6;
+    pub const FIELD_LEADING_DETACHED_COMMENTS_TAG: u32 = 50;
+
     pub fn new() -> SourceCodeInfo_Location {
         ::std::default::Default::default()
     }
@@ -7812,6 +10161,8 @@ impl SourceCodeInfo_Location {
     pub fn get_leading_detached_comments(&self) -> &[::std::string::String] {
         &self.leading_detached_comments
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.SourceCodeInfo.Location)
 }
 
 impl ::protobuf::Message for SourceCodeInfo_Location {
@@ -7846,6 +10197,25 @@ impl ::protobuf::Message for SourceCodeInfo_Location {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.path {
+            self.path.push(v.clone());
+        };
+        for v in &other.span {
+            self.span.push(v.clone());
+        };
+        if other.leading_comments.is_some() {
+            self.leading_comments = other.leading_comments.clone();
+        }
+        if other.trailing_comments.is_some() {
+            self.trailing_comments = other.trailing_comments.clone();
+        }
+        for v in &other.leading_detached_comments {
+            self.leading_detached_comments.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -7904,6 +10274,84 @@ impl ::protobuf::Message for SourceCodeInfo_Location {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_varint_size(1, &self.path);
+        }
+        if !self.span.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_varint_size(2, &self.span);
+        }
+        if let Some(ref v) = self.leading_comments.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.trailing_comments.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        for value in &self.leading_detached_comments {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_varint_data_size(&self.path))?;
+            for v in &self.path {
+                os.write_int32_no_tag(*v)?;
+            };
+        }
+        if !self.span.is_empty() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_varint_data_size(&self.span))?;
+            for v in &self.span {
+                os.write_int32_no_tag(*v)?;
+            };
+        }
+        if let Some(ref v) = self.leading_comments.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.trailing_comments.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        for v in &self.leading_detached_comments {
+            os.write_string(6, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += 6 + 10 * self.path.len() as u64;
+        }
+        if !self.span.is_empty() {
+            my_size += 6 + 10 * self.span.len() as u64;
+        }
+        if let Some(ref v) = self.leading_comments.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v) as u64;
+        }
+        if let Some(ref v) = self.trailing_comments.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v) as u64;
+        }
+        for value in &self.leading_detached_comments {
+            my_size += ::protobuf::rt::string_size(6, &value) as u64;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -7922,64 +10370,60 @@ impl ::protobuf::Message for SourceCodeInfo_Location {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> SourceCodeInfo_Location {
-        SourceCodeInfo_Location::new()
+    fn descriptor_static(_: ::std::option::Option<SourceCodeInfo_Location>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<SourceCodeInfo_Location>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "path",
-                    |m: &SourceCodeInfo_Location| { &m.path },
-                    |m: &mut SourceCodeInfo_Location| { &mut m.path },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "span",
-                    |m: &SourceCodeInfo_Location| { &m.span },
-                    |m: &mut SourceCodeInfo_Location| { &mut m.span },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "leading_comments",
-                    |m: &SourceCodeInfo_Location| { &m.leading_comments },
-                    |m: &mut SourceCodeInfo_Location| { &mut m.leading_comments },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "trailing_comments",
-                    |m: &SourceCodeInfo_Location| { &m.trailing_comments },
-                    |m: &mut SourceCodeInfo_Location| { &mut m.trailing_comments },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "leading_detached_comments",
-                    |m: &SourceCodeInfo_Location| { &m.leading_detached_comments },
-                    |m: &mut SourceCodeInfo_Location| { &mut m.leading_detached_comments },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<SourceCodeInfo_Location>(
-                    "SourceCodeInfo_Location",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> SourceCodeInfo_Location {
+        SourceCodeInfo_Location::new()
     }
 
     fn default_instance() -> &'static SourceCodeInfo_Location {
-        static mut instance: ::protobuf::lazy::Lazy<SourceCodeInfo_Location> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const SourceCodeInfo_Location,
-        };
-        unsafe {
-            instance.get(SourceCodeInfo_Location::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<SourceCodeInfo_Location> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(SourceCodeInfo_Location::new)
+    }
+}
+
+impl ::protobuf::MessageFull for SourceCodeInfo_Location {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "path",
+                |m: &SourceCodeInfo_Location| { &m.path },
+                |m: &mut SourceCodeInfo_Location| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "span",
+                |m: &SourceCodeInfo_Location| { &m.span },
+                |m: &mut SourceCodeInfo_Location| { &mut m.span },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "leading_comments",
+                |m: &SourceCodeInfo_Location| { &m.leading_comments },
+                |m: &mut SourceCodeInfo_Location| { &mut m.leading_comments },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "trailing_comments",
+                |m: &SourceCodeInfo_Location| { &m.trailing_comments },
+                |m: &mut SourceCodeInfo_Location| { &mut m.trailing_comments },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "leading_detached_comments",
+                |m: &SourceCodeInfo_Location| { &m.leading_detached_comments },
+                |m: &mut SourceCodeInfo_Location| { &mut m.leading_detached_comments },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<SourceCodeInfo_Location>(
+                "SourceCodeInfo_Location",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -8006,6 +10450,14 @@ impl ::protobuf::reflect::ProtobufValue for SourceCodeInfo_Location {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for SourceCodeInfo_Location {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<SourceCodeInfo_Location> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct GeneratedCodeInfo {
     // message fields
@@ -8013,9 +10465,13 @@ pub struct GeneratedCodeInfo {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.GeneratedCodeInfo)
 }
 
 impl GeneratedCodeInfo {
+    pub const FIELD_ANNOTATION_NUMBER: u32 = 1;
+    pub const FIELD_ANNOTATION_TAG: u32 = 10;
+
     pub fn new() -> GeneratedCodeInfo {
         ::std::default::Default::default()
     }
@@ -8044,6 +10500,8 @@ impl GeneratedCodeInfo {
     pub fn get_annotation(&self) -> &[GeneratedCodeInfo_Annotation] {
         &self.annotation
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.GeneratedCodeInfo)
 }
 
 impl ::protobuf::Message for GeneratedCodeInfo {
@@ -8071,6 +10529,13 @@ impl ::protobuf::Message for GeneratedCodeInfo {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.annotation {
+            self.annotation.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -8098,6 +10563,42 @@ impl ::protobuf::Message for GeneratedCodeInfo {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        for value in &self.annotation {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.annotation {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.annotation {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -8116,44 +10617,40 @@ impl ::protobuf::Message for GeneratedCodeInfo {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> GeneratedCodeInfo {
-        GeneratedCodeInfo::new()
+    fn descriptor_static(_: ::std::option::Option<GeneratedCodeInfo>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<GeneratedCodeInfo>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<GeneratedCodeInfo_Annotation>>(
-                    "annotation",
-                    |m: &GeneratedCodeInfo| { &m.annotation },
-                    |m: &mut GeneratedCodeInfo| { &mut m.annotation },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<GeneratedCodeInfo>(
-                    "GeneratedCodeInfo",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> GeneratedCodeInfo {
+        GeneratedCodeInfo::new()
     }
 
     fn default_instance() -> &'static GeneratedCodeInfo {
-        static mut instance: ::protobuf::lazy::Lazy<GeneratedCodeInfo> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const GeneratedCodeInfo,
-        };
-        unsafe {
-            instance.get(GeneratedCodeInfo::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<GeneratedCodeInfo> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(GeneratedCodeInfo::new)
+    }
+}
+
+impl ::protobuf::MessageFull for GeneratedCodeInfo {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<GeneratedCodeInfo_Annotation>>(
+                "annotation",
+                |m: &GeneratedCodeInfo| { &m.annotation },
+                |m: &mut GeneratedCodeInfo| { &mut m.annotation },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<GeneratedCodeInfo>(
+                "GeneratedCodeInfo",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -8176,6 +10673,14 @@ impl ::protobuf::reflect::ProtobufValue for GeneratedCodeInfo {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for GeneratedCodeInfo {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<GeneratedCodeInfo> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct GeneratedCodeInfo_Annotation {
     // message fields
@@ -8186,9 +10691,19 @@ pub struct GeneratedCodeInfo_Annotation {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.GeneratedCodeInfo.Annotation)
 }
 
 impl GeneratedCodeInfo_Annotation {
+    pub const FIELD_PATH_NUMBER: u32 = 1;
+    pub const FIELD_PATH_TAG: u32 = 10;
+    pub const FIELD_SOURCE_FILE_NUMBER: u32 = 2;
+    pub const FIELD_SOURCE_FILE_TAG: u32 = 18;
+    pub const FIELD_BEGIN_NUMBER: u32 = 3;
+    pub const FIELD_BEGIN_TAG: u32 = 24;
+    pub const FIELD_END_NUMBER: u32 = 4;
+    pub const FIELD_END_TAG: u32 = 32;
+
     pub fn new() -> GeneratedCodeInfo_Annotation {
         ::std::default::Default::default()
     }
@@ -8291,6 +10806,8 @@ impl GeneratedCodeInfo_Annotation {
     pub fn get_end(&self) -> i32 {
         self.end.unwrap_or(0)
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.GeneratedCodeInfo.Annotation)
 }
 
 impl ::protobuf::Message for GeneratedCodeInfo_Annotation {
@@ -8330,6 +10847,22 @@ impl ::protobuf::Message for GeneratedCodeInfo_Annotation {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.path {
+            self.path.push(v.clone());
+        };
+        if other.source_file.is_some() {
+            self.source_file = other.source_file.clone();
+        }
+        if other.begin.is_some() {
+            self.begin = other.begin.clone();
+        }
+        if other.end.is_some() {
+            self.end = other.end.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -8377,6 +10910,70 @@ impl ::protobuf::Message for GeneratedCodeInfo_Annotation {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += ::protobuf::rt::vec_packed_varint_size(1, &self.path);
+        }
+        if let Some(ref v) = self.source_file.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.begin {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.end {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.path.is_empty() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            // TODO: Data size is computed again, it should be cached
+            os.write_raw_varint32(::protobuf::rt::vec_packed_varint_data_size(&self.path))?;
+            for v in &self.path {
+                os.write_int32_no_tag(*v)?;
+            };
+        }
+        if let Some(ref v) = self.source_file.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.begin {
+            os.write_int32(3, v)?;
+        }
+        if let Some(v) = self.end {
+            os.write_int32(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.path.is_empty() {
+            my_size += 6 + 10 * self.path.len() as u64;
+        }
+        if let Some(ref v) = self.source_file.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v) as u64;
+        }
+        if let Some(v) = self.begin {
+            my_size += 11;
+        }
+        if let Some(v) = self.end {
+            my_size += 11;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -8395,59 +10992,55 @@ impl ::protobuf::Message for GeneratedCodeInfo_Annotation {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
     }
 
-    fn new() -> GeneratedCodeInfo_Annotation {
-        GeneratedCodeInfo_Annotation::new()
+    fn descriptor_static(_: ::std::option::Option<GeneratedCodeInfo_Annotation>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
-    fn descriptor_static(_: ::std::option::Option<GeneratedCodeInfo_Annotation>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "path",
-                    |m: &GeneratedCodeInfo_Annotation| { &m.path },
-                    |m: &mut GeneratedCodeInfo_Annotation| { &mut m.path },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "source_file",
-                    |m: &GeneratedCodeInfo_Annotation| { &m.source_file },
-                    |m: &mut GeneratedCodeInfo_Annotation| { &mut m.source_file },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "begin",
-                    |m: &GeneratedCodeInfo_Annotation| { &m.begin },
-                    |m: &mut GeneratedCodeInfo_Annotation| { &mut m.begin },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "end",
-                    |m: &GeneratedCodeInfo_Annotation| { &m.end },
-                    |m: &mut GeneratedCodeInfo_Annotation| { &mut m.end },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<GeneratedCodeInfo_Annotation>(
-                    "GeneratedCodeInfo_Annotation",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn new() -> GeneratedCodeInfo_Annotation {
+        GeneratedCodeInfo_Annotation::new()
     }
 
     fn default_instance() -> &'static GeneratedCodeInfo_Annotation {
-        static mut instance: ::protobuf::lazy::Lazy<GeneratedCodeInfo_Annotation> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const GeneratedCodeInfo_Annotation,
-        };
-        unsafe {
-            instance.get(GeneratedCodeInfo_Annotation::new)
-        }
+        static instance: ::protobuf::lazy::LazyV2<GeneratedCodeInfo_Annotation> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(GeneratedCodeInfo_Annotation::new)
+    }
+}
+
+impl ::protobuf::MessageFull for GeneratedCodeInfo_Annotation {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "path",
+                |m: &GeneratedCodeInfo_Annotation| { &m.path },
+                |m: &mut GeneratedCodeInfo_Annotation| { &mut m.path },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "source_file",
+                |m: &GeneratedCodeInfo_Annotation| { &m.source_file },
+                |m: &mut GeneratedCodeInfo_Annotation| { &mut m.source_file },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "begin",
+                |m: &GeneratedCodeInfo_Annotation| { &m.begin },
+                |m: &mut GeneratedCodeInfo_Annotation| { &mut m.begin },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "end",
+                |m: &GeneratedCodeInfo_Annotation| { &m.end },
+                |m: &mut GeneratedCodeInfo_Annotation| { &mut m.end },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<GeneratedCodeInfo_Annotation>(
+                "GeneratedCodeInfo_Annotation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -8473,6 +11066,14 @@ impl ::protobuf::reflect::ProtobufValue for GeneratedCodeInfo_Annotation {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for GeneratedCodeInfo_Annotation {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<GeneratedCodeInfo_Annotation> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x20google/protobuf/descriptor.proto\x12\x0fgoogle.protobuf\"M\n\x11Fi\
     leDescriptorSet\x128\n\x04file\x18\x01\x20\x03(\x0b2$.google.protobuf.Fi\
@@ -9772,19 +12373,16 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x03\x12\x04\xa1\x06\x19\x1a\
 ";
 
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+static file_descriptor_proto_lazy: ::protobuf::lazy::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
     ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
-    }
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
+
+// @@protoc_insertion_point(module_scope)
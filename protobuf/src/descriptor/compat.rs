@@ -0,0 +1,427 @@
+//! Backward compatibility checks of schema changes.
+//!
+//! `check` compares old and new versions of a schema loaded into `DescriptorPool`
+//! and reports changes which break reading data or using generated code written
+//! for the old version, so schema registry can reject them:
+//!
+//! ```ignore
+//! let registered = DescriptorPool::new(registered_files)?;
+//! let submitted = DescriptorPool::new(submitted_files)?;
+//! if let Err(e) = descriptor::compat::check_compatible(&registered, &submitted) {
+//!     return Err(format!("schema rejected: {}", e));
+//! }
+//! ```
+//!
+//! Fields are matched by number, messages and enums by fully qualified name,
+//! so types can be moved between files. Field type changes which keep
+//! wire format compatible (e. g. `int32` to `int64`, `string` to `bytes`)
+//! are allowed, like described in protobuf language guide. Renaming a field
+//! keeps wire format but changes generated code, so it is reported.
+
+use std::error::Error;
+use std::fmt;
+
+use descriptor::DescriptorProto;
+use descriptor::EnumDescriptorProto;
+use descriptor::FieldDescriptorProto;
+use descriptor::FieldDescriptorProto_Label;
+use descriptor::FieldDescriptorProto_Type;
+use descriptor_pool::DescriptorPool;
+use descriptorx::full_name;
+use descriptorx::strip_leading_dot;
+
+
+/// Kind of breaking change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BreakingChangeKind {
+    /// Message is removed
+    MessageRemoved,
+    /// Enum is removed
+    EnumRemoved,
+    /// Enum has no value with given number
+    EnumValueRemoved(i32),
+    /// Field is removed and its number is not reserved
+    FieldRemoved(i32),
+    /// Field with the same name has different number: old and new numbers
+    FieldNumberChanged(i32, i32),
+    /// Field with the same number has different name: new name
+    FieldRenamed(String),
+    /// Field type is changed to incompatible one: old and new types
+    FieldTypeChanged(String, String),
+    /// Field label is changed, e. g. `optional` to `repeated`
+    FieldLabelChanged(FieldDescriptorProto_Label, FieldDescriptorProto_Label),
+    /// New field uses number reserved in old version
+    ReservedNumberUsed(i32),
+    /// New field uses name reserved in old version
+    ReservedNameUsed,
+}
+
+/// Change of schema which breaks compatibility.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakingChange {
+    /// Name of `.proto` file with the element (old file if element is removed).
+    pub file: String,
+    /// Fully qualified name of message, enum or field without leading dot.
+    pub element: String,
+    pub kind: BreakingChangeKind,
+}
+
+fn label_name(label: FieldDescriptorProto_Label) -> &'static str {
+    match label {
+        FieldDescriptorProto_Label::LABEL_OPTIONAL => "optional",
+        FieldDescriptorProto_Label::LABEL_REQUIRED => "required",
+        FieldDescriptorProto_Label::LABEL_REPEATED => "repeated",
+    }
+}
+
+impl fmt::Display for BreakingChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}: ", self.file, self.element)?;
+        match self.kind {
+            BreakingChangeKind::MessageRemoved => write!(f, "message removed"),
+            BreakingChangeKind::EnumRemoved => write!(f, "enum removed"),
+            BreakingChangeKind::EnumValueRemoved(number) => {
+                write!(f, "enum value {} removed", number)
+            }
+            BreakingChangeKind::FieldRemoved(number) => {
+                write!(f, "field {} removed without reserving its number", number)
+            }
+            BreakingChangeKind::FieldNumberChanged(old, new) => {
+                write!(f, "field number changed from {} to {}", old, new)
+            }
+            BreakingChangeKind::FieldRenamed(ref new) => {
+                write!(f, "field renamed to {}", new)
+            }
+            BreakingChangeKind::FieldTypeChanged(ref old, ref new) => {
+                write!(f, "field type changed from {} to {}", old, new)
+            }
+            BreakingChangeKind::FieldLabelChanged(old, new) => {
+                write!(f, "field label changed from {} to {}", label_name(old), label_name(new))
+            }
+            BreakingChangeKind::ReservedNumberUsed(number) => {
+                write!(f, "field uses reserved number {}", number)
+            }
+            BreakingChangeKind::ReservedNameUsed => write!(f, "field uses reserved name"),
+        }
+    }
+}
+
+/// Error returned by `check_compatible`.
+#[derive(Debug)]
+pub struct CompatError {
+    pub changes: Vec<BreakingChange>,
+}
+
+impl fmt::Display for CompatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} breaking change(s):", self.changes.len())?;
+        for c in &self.changes {
+            write!(f, "\n{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for CompatError {
+    fn description(&self) -> &str {
+        "breaking changes"
+    }
+}
+
+// fields of types in the same class have compatible wire format
+fn type_class(field_type: FieldDescriptorProto_Type) -> u32 {
+    match field_type {
+        FieldDescriptorProto_Type::TYPE_INT32 |
+        FieldDescriptorProto_Type::TYPE_UINT32 |
+        FieldDescriptorProto_Type::TYPE_INT64 |
+        FieldDescriptorProto_Type::TYPE_UINT64 |
+        FieldDescriptorProto_Type::TYPE_BOOL => 1,
+        FieldDescriptorProto_Type::TYPE_SINT32 | FieldDescriptorProto_Type::TYPE_SINT64 => 2,
+        FieldDescriptorProto_Type::TYPE_FIXED32 | FieldDescriptorProto_Type::TYPE_SFIXED32 => 3,
+        FieldDescriptorProto_Type::TYPE_FIXED64 | FieldDescriptorProto_Type::TYPE_SFIXED64 => 4,
+        FieldDescriptorProto_Type::TYPE_STRING | FieldDescriptorProto_Type::TYPE_BYTES => 5,
+        // enums, messages, groups, floating point types are only compatible
+        // with the same type
+        _ => 0,
+    }
+}
+
+// type as written in `.proto` file, e. g. `int32` or `.pkg.Message`
+fn type_display(field: &FieldDescriptorProto) -> String {
+    if !field.get_type_name().is_empty() {
+        return field.get_type_name().to_owned();
+    }
+    let name = format!("{:?}", field.get_field_type());
+    name["TYPE_".len()..].to_lowercase()
+}
+
+fn types_compatible(old: &FieldDescriptorProto, new: &FieldDescriptorProto) -> bool {
+    if old.get_field_type() == new.get_field_type() {
+        strip_leading_dot(old.get_type_name()) == strip_leading_dot(new.get_type_name())
+    } else {
+        let class = type_class(old.get_field_type());
+        class != 0 && class == type_class(new.get_field_type())
+    }
+}
+
+fn is_reserved(message: &DescriptorProto, number: i32) -> bool {
+    // end is exclusive
+    message.get_reserved_range().iter().any(|r| number >= r.get_start() && number < r.get_end())
+}
+
+struct Checker {
+    changes: Vec<BreakingChange>,
+}
+
+impl Checker {
+    fn add(&mut self, file: &str, element: String, kind: BreakingChangeKind) {
+        self.changes.push(BreakingChange {
+            file: file.to_owned(),
+            element: element,
+            kind: kind,
+        });
+    }
+
+    fn check_message(&mut self, name: &str, file: &str, old: &DescriptorProto,
+        new: &DescriptorProto)
+    {
+        for old_field in old.get_field() {
+            let field_name = full_name(name, old_field.get_name());
+            let number = old_field.get_number();
+            match new.get_field().iter().find(|f| f.get_number() == number) {
+                Some(new_field) => {
+                    if old_field.get_name() != new_field.get_name() {
+                        let new_name = new_field.get_name().to_owned();
+                        let kind = BreakingChangeKind::FieldRenamed(new_name);
+                        self.add(file, field_name.clone(), kind);
+                    }
+                    if !types_compatible(old_field, new_field) {
+                        let kind = BreakingChangeKind::FieldTypeChanged(
+                            type_display(old_field),
+                            type_display(new_field),
+                        );
+                        self.add(file, field_name.clone(), kind);
+                    }
+                    if old_field.get_label() != new_field.get_label() {
+                        let kind = BreakingChangeKind::FieldLabelChanged(
+                            old_field.get_label(),
+                            new_field.get_label(),
+                        );
+                        self.add(file, field_name, kind);
+                    }
+                }
+                None => {
+                    let renumbered =
+                        new.get_field().iter().find(|f| f.get_name() == old_field.get_name());
+                    if let Some(new_field) = renumbered {
+                        let kind =
+                            BreakingChangeKind::FieldNumberChanged(number, new_field.get_number());
+                        self.add(file, field_name, kind);
+                    } else if !is_reserved(new, number) {
+                        self.add(file, field_name, BreakingChangeKind::FieldRemoved(number));
+                    }
+                }
+            }
+        }
+
+        for new_field in new.get_field() {
+            let number = new_field.get_number();
+            if old.get_field().iter().any(|f| f.get_number() == number) {
+                continue;
+            }
+            let field_name = full_name(name, new_field.get_name());
+            if is_reserved(old, number) {
+                self.add(file, field_name.clone(), BreakingChangeKind::ReservedNumberUsed(number));
+            }
+            if old.get_reserved_name().iter().any(|n| n == new_field.get_name()) {
+                self.add(file, field_name, BreakingChangeKind::ReservedNameUsed);
+            }
+        }
+    }
+
+    fn check_enum(&mut self, name: &str, file: &str, old: &EnumDescriptorProto,
+        new: &EnumDescriptorProto)
+    {
+        for value in old.get_value() {
+            let number = value.get_number();
+            if !new.get_value().iter().any(|v| v.get_number() == number) {
+                let value_name = full_name(name, value.get_name());
+                self.add(file, value_name, BreakingChangeKind::EnumValueRemoved(number));
+            }
+        }
+    }
+}
+
+/// Find breaking changes between old and new versions of a schema.
+///
+/// Types which are only defined in `new` pool are not checked.
+pub fn check(old: &DescriptorPool, new: &DescriptorPool) -> Vec<BreakingChange> {
+    let mut checker = Checker { changes: Vec::new() };
+
+    let mut message_names = old.message_names();
+    message_names.sort();
+    for name in message_names {
+        let (old_file, old_message) = old.message_with_file(name).unwrap();
+        match new.message_with_file(name) {
+            Some((new_file, new_message)) => {
+                checker.check_message(name, new_file.get_name(), old_message, new_message);
+            }
+            // entries of removed map fields are reported as field changes
+            None if old_message.get_options().get_map_entry() => {}
+            None => {
+                let kind = BreakingChangeKind::MessageRemoved;
+                checker.add(old_file.get_name(), name.to_owned(), kind);
+            }
+        }
+    }
+
+    let mut enum_names = old.enum_names();
+    enum_names.sort();
+    for name in enum_names {
+        let (old_file, old_enum) = old.enum_with_file(name).unwrap();
+        match new.enum_with_file(name) {
+            Some((new_file, new_enum)) => {
+                checker.check_enum(name, new_file.get_name(), old_enum, new_enum);
+            }
+            None => {
+                let kind = BreakingChangeKind::EnumRemoved;
+                checker.add(old_file.get_name(), name.to_owned(), kind);
+            }
+        }
+    }
+    checker.changes
+}
+
+/// Check schema and return error if there are breaking changes.
+pub fn check_compatible(old: &DescriptorPool, new: &DescriptorPool) -> Result<(), CompatError> {
+    let changes = check(old, new);
+    if changes.is_empty() {
+        Ok(())
+    } else {
+        Err(CompatError { changes: changes })
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FileDescriptorProto;
+    use descriptor_builder::DescriptorBuilder;
+    use descriptor_builder::EnumBuilder;
+    use descriptor_builder::FieldBuilder;
+    use descriptor_builder::MessageBuilder;
+
+    use descriptor::FieldDescriptorProto_Type::*;
+
+    fn file(message: MessageBuilder, values: &[(&str, i32)]) -> FileDescriptorProto {
+        let mut e = EnumBuilder::new("Kind");
+        for &(name, number) in values {
+            e = e.value(name, number);
+        }
+        DescriptorBuilder::new("a.proto")
+            .package("a")
+            .message(message)
+            .enumeration(e)
+            .build()
+            .unwrap()
+    }
+
+    fn old() -> FileDescriptorProto {
+        let message = MessageBuilder::new("M")
+            .field(FieldBuilder::new("id", 1, TYPE_INT32))
+            .field(FieldBuilder::new("name", 2, TYPE_STRING))
+            .field(FieldBuilder::new("kind", 3, TYPE_ENUM).type_name(".a.Kind"))
+            .field(FieldBuilder::new("tags", 4, TYPE_STRING).repeated())
+            .reserved_range(10, 12)
+            .reserved_name("old")
+            .nested_message(MessageBuilder::new("Nested"));
+        file(message, &[("UNKNOWN", 0), ("FIRST", 1)])
+    }
+
+    fn pool(files: Vec<FileDescriptorProto>) -> DescriptorPool {
+        DescriptorPool::new(files).unwrap()
+    }
+
+    fn changes(old: FileDescriptorProto, new: FileDescriptorProto) -> Vec<String> {
+        check(&pool(vec![old]), &pool(vec![new])).iter().map(|c| c.to_string()).collect()
+    }
+
+    #[test]
+    fn compatible() {
+        assert_eq!(Vec::<String>::new(), changes(old(), old()));
+
+        // compatible types, new fields and values, removed field with reserved number
+        let message = MessageBuilder::new("M")
+            .field(FieldBuilder::new("id", 1, TYPE_INT64))
+            .field(FieldBuilder::new("name", 2, TYPE_BYTES))
+            .field(FieldBuilder::new("kind", 3, TYPE_ENUM).type_name("a.Kind"))
+            .field(FieldBuilder::new("extra", 5, TYPE_DOUBLE))
+            .reserved_range(4, 4)
+            .reserved_range(10, 12)
+            .nested_message(MessageBuilder::new("Nested"))
+            .nested_message(MessageBuilder::new("Added"));
+        let new = file(message, &[("UNKNOWN", 0), ("FIRST", 1), ("SECOND", 2)]);
+        assert_eq!(Vec::<String>::new(), changes(old(), new.clone()));
+        check_compatible(&pool(vec![old()]), &pool(vec![new])).unwrap();
+    }
+
+    #[test]
+    fn breaking() {
+        let message = MessageBuilder::new("M")
+            .field(FieldBuilder::new("id", 1, TYPE_SINT32))
+            .field(FieldBuilder::new("name", 5, TYPE_STRING))
+            .field(FieldBuilder::new("kind", 3, TYPE_MESSAGE).type_name(".a.M").repeated())
+            .field(FieldBuilder::new("old", 11, TYPE_INT32));
+        let new = file(message, &[("UNKNOWN", 0)]);
+        assert_eq!(
+            vec![
+                "a.proto: a.M.id: field type changed from int32 to sint32",
+                "a.proto: a.M.name: field number changed from 2 to 5",
+                "a.proto: a.M.kind: field type changed from .a.Kind to .a.M",
+                "a.proto: a.M.kind: field label changed from optional to repeated",
+                "a.proto: a.M.tags: field 4 removed without reserving its number",
+                "a.proto: a.M.old: field uses reserved number 11",
+                "a.proto: a.M.old: field uses reserved name",
+                "a.proto: a.M.Nested: message removed",
+                "a.proto: a.Kind.FIRST: enum value 1 removed",
+            ],
+            changes(old(), new)
+        );
+    }
+
+    #[test]
+    fn renamed() {
+        let message = MessageBuilder::new("M")
+            .field(FieldBuilder::new("id", 1, TYPE_INT32))
+            .field(FieldBuilder::new("name", 2, TYPE_STRING))
+            .field(FieldBuilder::new("kind", 3, TYPE_ENUM).type_name(".a.Kind"))
+            .field(FieldBuilder::new("labels", 4, TYPE_STRING).repeated())
+            .reserved_range(10, 12)
+            .reserved_name("old")
+            .nested_message(MessageBuilder::new("Nested"));
+        let new = file(message, &[("UNKNOWN", 0), ("FIRST", 1)]);
+        assert_eq!(vec!["a.proto: a.M.tags: field renamed to labels"], changes(old(), new));
+    }
+
+    #[test]
+    fn moved_between_files() {
+        let mut moved = old();
+        moved.set_name("b.proto".to_owned());
+        let mut empty = old();
+        empty.clear_message_type();
+        empty.clear_enum_type();
+        assert!(check(&pool(vec![old()]), &pool(vec![empty.clone(), moved])).is_empty());
+
+        let e = check_compatible(&pool(vec![old()]), &pool(vec![empty])).unwrap_err();
+        assert_eq!(3, e.changes.len());
+        assert_eq!(
+            BreakingChange {
+                file: "a.proto".to_owned(),
+                element: "a.Kind".to_owned(),
+                kind: BreakingChangeKind::EnumRemoved,
+            },
+            e.changes[2]
+        );
+    }
+}
@@ -0,0 +1,596 @@
+//! Construction of `FileDescriptorProto` in Rust code.
+//!
+//! Builders fill descriptor protos, and `DescriptorBuilder::build` checks
+//! names, field numbers and reserved ranges the same way `protoc` does,
+//! so invalid schemas are rejected before they reach code generator
+//! or dynamic message implementation:
+//!
+//! ```ignore
+//! let file = DescriptorBuilder::new("person.proto")
+//!     .package("test")
+//!     .message(MessageBuilder::new("Person")
+//!         .field(FieldBuilder::new("name", 1, FieldDescriptorProto_Type::TYPE_STRING))
+//!         .field(FieldBuilder::new("friends", 2, FieldDescriptorProto_Type::TYPE_MESSAGE)
+//!             .type_name(".test.Person")
+//!             .repeated())
+//!         .reserved_range(3, 5))
+//!     .build()?;
+//! ```
+//!
+//! References to other types (`type_name`) are not resolved,
+//! because they may point to types of dependencies.
+
+use std::collections::HashSet;
+use std::error::Error;
+use std::fmt;
+
+use descriptor::DescriptorProto;
+use descriptor::DescriptorProto_ReservedRange;
+use descriptor::EnumDescriptorProto;
+use descriptor::EnumValueDescriptorProto;
+use descriptor::FieldDescriptorProto;
+use descriptor::FieldDescriptorProto_Label;
+use descriptor::FieldDescriptorProto_Type;
+use descriptor::FileDescriptorProto;
+use descriptor::OneofDescriptorProto;
+use descriptorx;
+
+
+/// Max field number allowed by protobuf.
+pub const MAX_FIELD_NUMBER: i32 = (1 << 29) - 1;
+
+/// Field numbers reserved for protobuf implementation (inclusive).
+pub const RESERVED_FIELD_NUMBERS: (i32, i32) = (19000, 19999);
+
+/// Error returned when descriptor is invalid.
+///
+/// Names in errors are fully qualified, e. g. `pkg.Message.field`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DescriptorError {
+    /// Name is not a valid identifier
+    InvalidName(String),
+    /// Name is already defined in the same scope
+    DuplicateName(String),
+    /// Field number is outside of `1..=MAX_FIELD_NUMBER`
+    InvalidFieldNumber(String, i32),
+    /// Field number is used by other field of the message
+    DuplicateFieldNumber(String, i32),
+    /// Field number or name is reserved by the message or by protobuf
+    Reserved(String),
+    /// Reserved range is empty or overlaps with other range
+    InvalidReservedRange(String),
+    /// Message or enum field has no `type_name`
+    MissingTypeName(String),
+    /// Enum has no values
+    EmptyEnum(String),
+    /// Enum value number is used by other value and aliases are not allowed
+    DuplicateEnumValue(String, i32),
+    /// Construct is not allowed in proto3 syntax (required fields,
+    /// default values, first enum value not zero)
+    NotAllowedInProto3(String),
+    /// Field of oneof is repeated or required
+    InvalidOneofField(String),
+}
+
+impl fmt::Display for DescriptorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DescriptorError::InvalidName(ref name) => write!(f, "invalid name: {}", name),
+            DescriptorError::DuplicateName(ref name) => write!(f, "duplicate name: {}", name),
+            DescriptorError::InvalidFieldNumber(ref name, number) => {
+                write!(f, "invalid field number {}: {}", number, name)
+            }
+            DescriptorError::DuplicateFieldNumber(ref name, number) => {
+                write!(f, "duplicate field number {}: {}", number, name)
+            }
+            DescriptorError::Reserved(ref name) => write!(f, "reserved: {}", name),
+            DescriptorError::InvalidReservedRange(ref name) => {
+                write!(f, "invalid reserved range: {}", name)
+            }
+            DescriptorError::MissingTypeName(ref name) => {
+                write!(f, "missing type name: {}", name)
+            }
+            DescriptorError::EmptyEnum(ref name) => write!(f, "enum has no values: {}", name),
+            DescriptorError::DuplicateEnumValue(ref name, number) => {
+                write!(f, "duplicate enum value {}: {}", number, name)
+            }
+            DescriptorError::NotAllowedInProto3(ref name) => {
+                write!(f, "not allowed in proto3: {}", name)
+            }
+            DescriptorError::InvalidOneofField(ref name) => {
+                write!(f, "oneof field must be optional: {}", name)
+            }
+        }
+    }
+}
+
+impl Error for DescriptorError {
+    fn description(&self) -> &str {
+        match *self {
+            DescriptorError::InvalidName(..) => "invalid name",
+            DescriptorError::DuplicateName(..) => "duplicate name",
+            DescriptorError::InvalidFieldNumber(..) => "invalid field number",
+            DescriptorError::DuplicateFieldNumber(..) => "duplicate field number",
+            DescriptorError::Reserved(..) => "reserved field number or name",
+            DescriptorError::InvalidReservedRange(..) => "invalid reserved range",
+            DescriptorError::MissingTypeName(..) => "missing type name",
+            DescriptorError::EmptyEnum(..) => "enum has no values",
+            DescriptorError::DuplicateEnumValue(..) => "duplicate enum value",
+            DescriptorError::NotAllowedInProto3(..) => "not allowed in proto3",
+            DescriptorError::InvalidOneofField(..) => "oneof field must be optional",
+        }
+    }
+}
+
+
+/// Builder of `FileDescriptorProto`.
+#[derive(Debug, Clone)]
+pub struct DescriptorBuilder {
+    proto: FileDescriptorProto,
+}
+
+impl DescriptorBuilder {
+    /// New file with given name (e. g. `foo/bar.proto`) and proto2 syntax.
+    pub fn new(name: &str) -> DescriptorBuilder {
+        let mut proto = FileDescriptorProto::new();
+        proto.set_name(name.to_owned());
+        DescriptorBuilder { proto: proto }
+    }
+
+    pub fn package(mut self, package: &str) -> DescriptorBuilder {
+        self.proto.set_package(package.to_owned());
+        self
+    }
+
+    pub fn proto3(mut self) -> DescriptorBuilder {
+        self.proto.set_syntax("proto3".to_owned());
+        self
+    }
+
+    /// Name of imported file.
+    pub fn dependency(mut self, name: &str) -> DescriptorBuilder {
+        self.proto.mut_dependency().push(name.to_owned());
+        self
+    }
+
+    pub fn message(mut self, message: MessageBuilder) -> DescriptorBuilder {
+        self.proto.mut_message_type().push(message.proto);
+        self
+    }
+
+    pub fn enumeration(mut self, en: EnumBuilder) -> DescriptorBuilder {
+        self.proto.mut_enum_type().push(en.proto);
+        self
+    }
+
+    /// Validate and return the descriptor.
+    pub fn build(self) -> Result<FileDescriptorProto, DescriptorError> {
+        validate(&self.proto)?;
+        Ok(self.proto)
+    }
+}
+
+/// Builder of `DescriptorProto`.
+#[derive(Debug, Clone)]
+pub struct MessageBuilder {
+    proto: DescriptorProto,
+}
+
+impl MessageBuilder {
+    pub fn new(name: &str) -> MessageBuilder {
+        let mut proto = DescriptorProto::new();
+        proto.set_name(name.to_owned());
+        MessageBuilder { proto: proto }
+    }
+
+    pub fn field(mut self, field: FieldBuilder) -> MessageBuilder {
+        self.proto.mut_field().push(field.proto);
+        self
+    }
+
+    /// Oneof with given fields, fields are added to the message.
+    pub fn oneof(mut self, name: &str, fields: Vec<FieldBuilder>) -> MessageBuilder {
+        let index = self.proto.get_oneof_decl().len() as i32;
+        let mut oneof = OneofDescriptorProto::new();
+        oneof.set_name(name.to_owned());
+        self.proto.mut_oneof_decl().push(oneof);
+        for field in fields {
+            let mut proto = field.proto;
+            proto.set_oneof_index(index);
+            self.proto.mut_field().push(proto);
+        }
+        self
+    }
+
+    pub fn nested_message(mut self, message: MessageBuilder) -> MessageBuilder {
+        self.proto.mut_nested_type().push(message.proto);
+        self
+    }
+
+    pub fn nested_enum(mut self, en: EnumBuilder) -> MessageBuilder {
+        self.proto.mut_enum_type().push(en.proto);
+        self
+    }
+
+    /// Reserve field numbers from `start` to `end` inclusive,
+    /// like `reserved 9 to 11;` in `.proto` file.
+    pub fn reserved_range(mut self, start: i32, end: i32) -> MessageBuilder {
+        let mut range = DescriptorProto_ReservedRange::new();
+        range.set_start(start);
+        // end is exclusive in descriptor
+        range.set_end(end.saturating_add(1));
+        self.proto.mut_reserved_range().push(range);
+        self
+    }
+
+    pub fn reserved_name(mut self, name: &str) -> MessageBuilder {
+        self.proto.mut_reserved_name().push(name.to_owned());
+        self
+    }
+}
+
+/// Builder of `FieldDescriptorProto`.
+#[derive(Debug, Clone)]
+pub struct FieldBuilder {
+    proto: FieldDescriptorProto,
+}
+
+impl FieldBuilder {
+    /// New optional field.
+    pub fn new(name: &str, number: i32, field_type: FieldDescriptorProto_Type) -> FieldBuilder {
+        let mut proto = FieldDescriptorProto::new();
+        proto.set_name(name.to_owned());
+        proto.set_number(number);
+        proto.set_field_type(field_type);
+        proto.set_label(FieldDescriptorProto_Label::LABEL_OPTIONAL);
+        FieldBuilder { proto: proto }
+    }
+
+    /// Type of message or enum field, fully qualified names start with dot,
+    /// e. g. `.google.protobuf.Any`.
+    pub fn type_name(mut self, type_name: &str) -> FieldBuilder {
+        self.proto.set_type_name(type_name.to_owned());
+        self
+    }
+
+    pub fn repeated(mut self) -> FieldBuilder {
+        self.proto.set_label(FieldDescriptorProto_Label::LABEL_REPEATED);
+        self
+    }
+
+    pub fn required(mut self) -> FieldBuilder {
+        self.proto.set_label(FieldDescriptorProto_Label::LABEL_REQUIRED);
+        self
+    }
+
+    /// Default value in text form, as in `[default = ...]` option.
+    pub fn default_value(mut self, value: &str) -> FieldBuilder {
+        self.proto.set_default_value(value.to_owned());
+        self
+    }
+
+    pub fn json_name(mut self, json_name: &str) -> FieldBuilder {
+        self.proto.set_json_name(json_name.to_owned());
+        self
+    }
+}
+
+/// Builder of `EnumDescriptorProto`.
+#[derive(Debug, Clone)]
+pub struct EnumBuilder {
+    proto: EnumDescriptorProto,
+}
+
+impl EnumBuilder {
+    pub fn new(name: &str) -> EnumBuilder {
+        let mut proto = EnumDescriptorProto::new();
+        proto.set_name(name.to_owned());
+        EnumBuilder { proto: proto }
+    }
+
+    pub fn value(mut self, name: &str, number: i32) -> EnumBuilder {
+        let mut value = EnumValueDescriptorProto::new();
+        value.set_name(name.to_owned());
+        value.set_number(number);
+        self.proto.mut_value().push(value);
+        self
+    }
+
+    /// Allow several values with the same number.
+    pub fn allow_alias(mut self) -> EnumBuilder {
+        self.proto.mut_options().set_allow_alias(true);
+        self
+    }
+}
+
+
+/// Check descriptor built by other means (e. g. received from remote system)
+/// with the same rules as `DescriptorBuilder::build`.
+pub fn validate(file: &FileDescriptorProto) -> Result<(), DescriptorError> {
+    let proto3 = file.get_syntax() == "proto3";
+    let scope = file.get_package();
+    for part in scope.split('.').filter(|_| !scope.is_empty()) {
+        check_name(part, scope)?;
+    }
+
+    let mut names = HashSet::new();
+    for message in file.get_message_type() {
+        let name = descriptorx::full_name(scope, message.get_name());
+        check_unique(&mut names, message.get_name(), &name)?;
+        validate_message(message, &name, proto3)?;
+    }
+    for en in file.get_enum_type() {
+        let name = descriptorx::full_name(scope, en.get_name());
+        check_unique(&mut names, en.get_name(), &name)?;
+        validate_enum(en, &name, proto3)?;
+    }
+    Ok(())
+}
+
+fn check_name(name: &str, full_name: &str) -> Result<(), DescriptorError> {
+    let mut chars = name.chars();
+    let valid = match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(DescriptorError::InvalidName(full_name.to_owned()))
+    }
+}
+
+fn check_unique<'a>(
+    names: &mut HashSet<&'a str>,
+    name: &'a str,
+    full_name: &str,
+) -> Result<(), DescriptorError> {
+    check_name(name, full_name)?;
+    if names.insert(name) {
+        Ok(())
+    } else {
+        Err(DescriptorError::DuplicateName(full_name.to_owned()))
+    }
+}
+
+fn validate_message(
+    message: &DescriptorProto,
+    full_name: &str,
+    proto3: bool,
+) -> Result<(), DescriptorError> {
+    let mut ranges: Vec<(i32, i32)> = Vec::new();
+    for range in message.get_reserved_range() {
+        let (start, end) = (range.get_start(), range.get_end());
+        let overlaps = ranges.iter().any(|&(s, e)| start < e && s < end);
+        if start < 1 || start >= end || end > MAX_FIELD_NUMBER + 1 || overlaps {
+            return Err(DescriptorError::InvalidReservedRange(
+                format!("{}: {} to {}", full_name, start, end - 1),
+            ));
+        }
+        ranges.push((start, end));
+    }
+
+    // fields, oneofs and nested types share the scope
+    let mut names = HashSet::new();
+    let mut numbers = HashSet::new();
+    for field in message.get_field() {
+        let name = descriptorx::full_name(full_name, field.get_name());
+        check_unique(&mut names, field.get_name(), &name)?;
+        if message.get_reserved_name().iter().any(|n| n == field.get_name()) {
+            return Err(DescriptorError::Reserved(name));
+        }
+
+        let number = field.get_number();
+        if number < 1 || number > MAX_FIELD_NUMBER {
+            return Err(DescriptorError::InvalidFieldNumber(name, number));
+        }
+        if (number >= RESERVED_FIELD_NUMBERS.0 && number <= RESERVED_FIELD_NUMBERS.1)
+            || ranges.iter().any(|&(s, e)| number >= s && number < e)
+        {
+            return Err(DescriptorError::Reserved(format!("{} = {}", name, number)));
+        }
+        if !numbers.insert(number) {
+            return Err(DescriptorError::DuplicateFieldNumber(name, number));
+        }
+
+        match field.get_field_type() {
+            FieldDescriptorProto_Type::TYPE_MESSAGE
+            | FieldDescriptorProto_Type::TYPE_ENUM
+            | FieldDescriptorProto_Type::TYPE_GROUP => {
+                if field.get_type_name().is_empty() {
+                    return Err(DescriptorError::MissingTypeName(name));
+                }
+            }
+            _ => {}
+        }
+
+        if proto3 {
+            if field.get_label() == FieldDescriptorProto_Label::LABEL_REQUIRED
+                || field.has_default_value()
+            {
+                return Err(DescriptorError::NotAllowedInProto3(name));
+            }
+        }
+
+        if field.has_oneof_index() {
+            let index = field.get_oneof_index();
+            if index < 0 || index as usize >= message.get_oneof_decl().len() {
+                return Err(DescriptorError::InvalidOneofField(name));
+            }
+            if field.get_label() != FieldDescriptorProto_Label::LABEL_OPTIONAL {
+                return Err(DescriptorError::InvalidOneofField(name));
+            }
+        }
+    }
+
+    for oneof in message.get_oneof_decl() {
+        let name = descriptorx::full_name(full_name, oneof.get_name());
+        check_unique(&mut names, oneof.get_name(), &name)?;
+    }
+    for nested in message.get_nested_type() {
+        let name = descriptorx::full_name(full_name, nested.get_name());
+        check_unique(&mut names, nested.get_name(), &name)?;
+        validate_message(nested, &name, proto3)?;
+    }
+    for en in message.get_enum_type() {
+        let name = descriptorx::full_name(full_name, en.get_name());
+        check_unique(&mut names, en.get_name(), &name)?;
+        validate_enum(en, &name, proto3)?;
+    }
+    Ok(())
+}
+
+fn validate_enum(
+    en: &EnumDescriptorProto,
+    full_name: &str,
+    proto3: bool,
+) -> Result<(), DescriptorError> {
+    if en.get_value().is_empty() {
+        return Err(DescriptorError::EmptyEnum(full_name.to_owned()));
+    }
+    if proto3 && en.get_value()[0].get_number() != 0 {
+        return Err(DescriptorError::NotAllowedInProto3(
+            descriptorx::full_name(full_name, en.get_value()[0].get_name()),
+        ));
+    }
+
+    let allow_alias = en.get_options().get_allow_alias();
+    let mut names = HashSet::new();
+    let mut numbers = HashSet::new();
+    for value in en.get_value() {
+        let name = descriptorx::full_name(full_name, value.get_name());
+        check_unique(&mut names, value.get_name(), &name)?;
+        if !numbers.insert(value.get_number()) && !allow_alias {
+            return Err(DescriptorError::DuplicateEnumValue(name, value.get_number()));
+        }
+    }
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn person() -> MessageBuilder {
+        MessageBuilder::new("Person")
+            .field(FieldBuilder::new("name", 1, FieldDescriptorProto_Type::TYPE_STRING))
+            .field(
+                FieldBuilder::new("friends", 2, FieldDescriptorProto_Type::TYPE_MESSAGE)
+                    .type_name(".test.Person")
+                    .repeated(),
+            )
+            .reserved_range(3, 5)
+            .reserved_name("age")
+    }
+
+    fn build(message: MessageBuilder) -> Result<FileDescriptorProto, DescriptorError> {
+        DescriptorBuilder::new("a.proto").package("test").message(message).build()
+    }
+
+    #[test]
+    fn valid() {
+        let file = DescriptorBuilder::new("a.proto")
+            .package("test")
+            .proto3()
+            .message(
+                person()
+                    .oneof(
+                        "contact",
+                        vec![
+                            FieldBuilder::new("email", 6, FieldDescriptorProto_Type::TYPE_STRING),
+                            FieldBuilder::new("phone", 7, FieldDescriptorProto_Type::TYPE_STRING),
+                        ],
+                    )
+                    .nested_enum(EnumBuilder::new("Kind").value("UNKNOWN", 0).value("A", 1)),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!("proto3", file.get_syntax());
+        let message = &file.get_message_type()[0];
+        assert_eq!(4, message.get_field().len());
+        assert_eq!(0, message.get_field()[3].get_oneof_index());
+        assert_eq!("contact", message.get_oneof_decl()[0].get_name());
+        // `reserved 3 to 5` is stored with exclusive end
+        assert_eq!(6, message.get_reserved_range()[0].get_end());
+        assert_eq!("Kind", message.get_enum_type()[0].get_name());
+    }
+
+    #[test]
+    fn invalid_fields() {
+        let string = FieldDescriptorProto_Type::TYPE_STRING;
+        assert_eq!(
+            Err(DescriptorError::Reserved("test.Person.x = 4".to_owned())),
+            build(person().field(FieldBuilder::new("x", 4, string)))
+        );
+        assert_eq!(
+            Err(DescriptorError::Reserved("test.Person.x = 19500".to_owned())),
+            build(person().field(FieldBuilder::new("x", 19500, string)))
+        );
+        assert_eq!(
+            Err(DescriptorError::Reserved("test.Person.age".to_owned())),
+            build(person().field(FieldBuilder::new("age", 10, string)))
+        );
+        assert_eq!(
+            Err(DescriptorError::DuplicateFieldNumber("test.Person.x".to_owned(), 1)),
+            build(person().field(FieldBuilder::new("x", 1, string)))
+        );
+        assert_eq!(
+            Err(DescriptorError::DuplicateName("test.Person.name".to_owned())),
+            build(person().field(FieldBuilder::new("name", 10, string)))
+        );
+        assert_eq!(
+            Err(DescriptorError::InvalidFieldNumber("test.Person.x".to_owned(), 0)),
+            build(person().field(FieldBuilder::new("x", 0, string)))
+        );
+        assert_eq!(
+            Err(DescriptorError::InvalidName("test.Person.1x".to_owned())),
+            build(person().field(FieldBuilder::new("1x", 10, string)))
+        );
+        assert_eq!(
+            Err(DescriptorError::MissingTypeName("test.Person.x".to_owned())),
+            build(person().field(
+                FieldBuilder::new("x", 10, FieldDescriptorProto_Type::TYPE_MESSAGE),
+            ))
+        );
+        assert_eq!(
+            Err(DescriptorError::InvalidOneofField("test.Person.x".to_owned())),
+            build(person().oneof("o", vec![FieldBuilder::new("x", 10, string).repeated()]))
+        );
+        assert_eq!(
+            Err(DescriptorError::InvalidReservedRange("test.Person: 4 to 8".to_owned())),
+            build(person().reserved_range(4, 8))
+        );
+    }
+
+    #[test]
+    fn invalid_enums() {
+        assert_eq!(
+            Err(DescriptorError::EmptyEnum("E".to_owned())),
+            DescriptorBuilder::new("a.proto").enumeration(EnumBuilder::new("E")).build()
+        );
+        let en = EnumBuilder::new("E").value("A", 1).value("B", 1);
+        assert_eq!(
+            Err(DescriptorError::DuplicateEnumValue("E.B".to_owned(), 1)),
+            DescriptorBuilder::new("a.proto").enumeration(en.clone()).build()
+        );
+        assert!(DescriptorBuilder::new("a.proto").enumeration(en.allow_alias()).build().is_ok());
+        assert_eq!(
+            Err(DescriptorError::NotAllowedInProto3("E.A".to_owned())),
+            DescriptorBuilder::new("a.proto")
+                .proto3()
+                .enumeration(EnumBuilder::new("E").value("A", 1))
+                .build()
+        );
+        assert_eq!(
+            Err(DescriptorError::DuplicateName("E".to_owned())),
+            DescriptorBuilder::new("a.proto")
+                .message(MessageBuilder::new("E"))
+                .enumeration(EnumBuilder::new("E").value("A", 0))
+                .build()
+        );
+    }
+}
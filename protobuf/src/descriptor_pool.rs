@@ -0,0 +1,699 @@
+//! Sets of file descriptors loaded at runtime.
+//!
+//! `DescriptorPool` indexes messages and enums of a complete set of files
+//! (e. g. `FileDescriptorSet` fetched from schema registry, or produced by
+//! `protoc --descriptor_set_out --include_imports`) by full name.
+//! Files are ordered so that dependencies precede files importing them,
+//! and types of all fields are resolved when pool is created.
+//!
+//! Pool gives access to `DescriptorProto` and `EnumDescriptorProto` of all its types.
+//!
+//! Not implemented yet: reflection descriptors (`MessageDescriptor`, `EnumDescriptor`)
+//! of types loaded at runtime, and dynamic messages which could be parsed with them.
+//! Current descriptors need generated Rust types, so `message_descriptor`
+//! only finds messages compiled into the program, and messages of other types
+//! cannot be decoded with the pool.
+//!
+//! `VersionedDescriptorPool` allows replacing the pool while it is used:
+//! readers take a snapshot and resolve all names of a request with it,
+//! so they never see a mix of old and new schema:
+//!
+//! ```ignore
+//! let pool = VersionedDescriptorPool::new(DescriptorPool::new(files)?);
+//!
+//! // request thread
+//! let schema = pool.snapshot();
+//! let message = schema.message_by_name("pkg.Request").unwrap();
+//!
+//! // reload thread
+//! pool.reload(new_files)?;
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use core::parse_from_bytes;
+use descriptor::DescriptorProto;
+use descriptor::EnumDescriptorProto;
+use descriptor::FieldDescriptorProto;
+use descriptor::FileDescriptorProto;
+use descriptor::FileDescriptorSet;
+use descriptor_builder;
+use descriptor_builder::DescriptorError;
+use descriptorx::full_name;
+use descriptorx::strip_leading_dot;
+use reflect::all_registered_messages;
+use reflect::MessageDescriptor;
+
+
+/// Error returned when files cannot be added to pool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DescriptorPoolError {
+    /// File descriptor is invalid
+    Invalid(DescriptorError),
+    /// Two files have the same name
+    DuplicateFile(String),
+    /// File imports file not found in pool: file name and dependency name
+    MissingDependency(String, String),
+    /// Message is defined in two files
+    DuplicateMessage(String),
+    /// Enum is defined in two files, or has the same name as a message
+    DuplicateEnum(String),
+    /// Files import each other; first file is repeated at the end
+    DependencyCycle(Vec<String>),
+    /// Type of field or method is not found in its file or files it imports:
+    /// full name of field or method and type name
+    UnresolvedType(String, String),
+    /// Serialized `FileDescriptorSet` could not be parsed
+    Parse(String),
+}
+
+impl fmt::Display for DescriptorPoolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DescriptorPoolError::Invalid(ref e) => write!(f, "{}", e),
+            DescriptorPoolError::DuplicateFile(ref name) => {
+                write!(f, "duplicate file: {}", name)
+            }
+            DescriptorPoolError::MissingDependency(ref file, ref dependency) => {
+                write!(f, "dependency {} of file {} not found", dependency, file)
+            }
+            DescriptorPoolError::DuplicateMessage(ref name) => {
+                write!(f, "duplicate message: {}", name)
+            }
+            DescriptorPoolError::DuplicateEnum(ref name) => write!(f, "duplicate enum: {}", name),
+            DescriptorPoolError::DependencyCycle(ref files) => {
+                write!(f, "import cycle: {}", files.join(" -> "))
+            }
+            DescriptorPoolError::UnresolvedType(ref name, ref type_name) => {
+                write!(f, "type {} of {} not found", type_name, name)
+            }
+            DescriptorPoolError::Parse(ref e) => write!(f, "invalid descriptor set: {}", e),
+        }
+    }
+}
+
+impl Error for DescriptorPoolError {
+    fn description(&self) -> &str {
+        match *self {
+            DescriptorPoolError::Invalid(ref e) => e.description(),
+            DescriptorPoolError::DuplicateFile(..) => "duplicate file",
+            DescriptorPoolError::MissingDependency(..) => "missing dependency",
+            DescriptorPoolError::DuplicateMessage(..) => "duplicate message",
+            DescriptorPoolError::DuplicateEnum(..) => "duplicate enum",
+            DescriptorPoolError::DependencyCycle(..) => "import cycle",
+            DescriptorPoolError::UnresolvedType(..) => "unresolved type",
+            DescriptorPoolError::Parse(..) => "invalid descriptor set",
+        }
+    }
+}
+
+impl From<DescriptorError> for DescriptorPoolError {
+    fn from(e: DescriptorError) -> DescriptorPoolError {
+        DescriptorPoolError::Invalid(e)
+    }
+}
+
+
+/// Immutable set of files with all their dependencies.
+#[derive(Debug)]
+pub struct DescriptorPool {
+    version: u64,
+    // dependencies precede files importing them
+    files: Vec<FileDescriptorProto>,
+    // full name without leading dot -> index of file and indices of nested messages
+    messages: HashMap<String, (usize, Vec<usize>)>,
+    // full name -> index of file, indices of messages and index of enum in the last one
+    enums: HashMap<String, (usize, Vec<usize>, usize)>,
+}
+
+impl DescriptorPool {
+    /// Validate and index files.
+    ///
+    /// Files are checked with `descriptor_builder::validate`,
+    /// all imported files must be present in `files`, and types of fields,
+    /// extensions and methods must be defined in the same file or files it imports
+    /// (directly or not). Files may be given in any order.
+    pub fn new(files: Vec<FileDescriptorProto>) -> Result<DescriptorPool, DescriptorPoolError> {
+        for file in &files {
+            descriptor_builder::validate(file)?;
+        }
+        let order = sort_files(&files)?;
+        let mut files: Vec<Option<FileDescriptorProto>> = files.into_iter().map(Some).collect();
+        let files: Vec<FileDescriptorProto> =
+            order.into_iter().map(|i| files[i].take().unwrap()).collect();
+
+        let mut messages = HashMap::new();
+        let mut enums = HashMap::new();
+        for (i, file) in files.iter().enumerate() {
+            let mut path = Vec::new();
+            let package = file.get_package();
+            index_messages(package, file.get_message_type(), i, &mut path, &mut messages)?;
+        }
+        for (i, file) in files.iter().enumerate() {
+            let mut path = Vec::new();
+            let package = file.get_package();
+            index_enums(package, file, None, i, &mut path, &messages, &mut enums)?;
+        }
+
+        let pool = DescriptorPool {
+            version: 0,
+            files: files,
+            messages: messages,
+            enums: enums,
+        };
+        pool.check_types()?;
+        Ok(pool)
+    }
+
+    /// Load files of serialized `FileDescriptorSet`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<DescriptorPool, DescriptorPoolError> {
+        let mut set: FileDescriptorSet =
+            parse_from_bytes(bytes).map_err(|e| DescriptorPoolError::Parse(e.to_string()))?;
+        DescriptorPool::new(set.take_file().into_vec())
+    }
+
+    // check that referenced types are defined in the same file or its dependencies
+    fn check_types(&self) -> Result<(), DescriptorPoolError> {
+        let index_by_name: HashMap<&str, usize> =
+            self.files.iter().enumerate().map(|(i, f)| (f.get_name(), i)).collect();
+
+        // files visible from each file: the file and its transitive dependencies,
+        // which precede it
+        let mut visible: Vec<Vec<bool>> = Vec::with_capacity(self.files.len());
+        for (i, file) in self.files.iter().enumerate() {
+            let mut v = vec![false; self.files.len()];
+            v[i] = true;
+            for dependency in file.get_dependency() {
+                let j = index_by_name[&dependency[..]];
+                for k in 0..j + 1 {
+                    v[k] |= visible[j][k];
+                }
+            }
+            visible.push(v);
+        }
+
+        let resolve = |file_index: usize, scope: &str, type_name: &str| -> bool {
+            let found = |name: &str| {
+                let file = match self.messages.get(name) {
+                    Some(&(file, _)) => Some(file),
+                    None => self.enums.get(name).map(|&(file, _, _)| file),
+                };
+                file.map_or(false, |file| visible[file_index][file])
+            };
+            if type_name.starts_with('.') {
+                return found(&type_name[1..]);
+            }
+            // relative name is looked up in scope, then in enclosing scopes
+            let mut scope = scope;
+            loop {
+                if found(&full_name(scope, type_name)) {
+                    return true;
+                }
+                if scope.is_empty() {
+                    return false;
+                }
+                scope = match scope.rfind('.') {
+                    Some(pos) => &scope[..pos],
+                    None => "",
+                };
+            }
+        };
+
+        let check_field = |file_index: usize, scope: &str, field: &FieldDescriptorProto| {
+            for type_name in &[field.get_type_name(), field.get_extendee()] {
+                if !type_name.is_empty() && !resolve(file_index, scope, type_name) {
+                    return Err(DescriptorPoolError::UnresolvedType(
+                        full_name(scope, field.get_name()),
+                        type_name.to_string(),
+                    ));
+                }
+            }
+            Ok(())
+        };
+
+        for (name, &(file_index, _)) in &self.messages {
+            let message = self.message_by_name(name).unwrap();
+            for field in message.get_field().iter().chain(message.get_extension()) {
+                check_field(file_index, name, field)?;
+            }
+        }
+        for (i, file) in self.files.iter().enumerate() {
+            let package = file.get_package();
+            for field in file.get_extension() {
+                check_field(i, package, field)?;
+            }
+            for service in file.get_service() {
+                let service_name = full_name(package, service.get_name());
+                for method in service.get_method() {
+                    for type_name in &[method.get_input_type(), method.get_output_type()] {
+                        if !resolve(i, package, type_name) {
+                            return Err(DescriptorPoolError::UnresolvedType(
+                                full_name(&service_name, method.get_name()),
+                                type_name.to_string(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Version assigned by `VersionedDescriptorPool`, zero for standalone pool.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    /// Files of the pool, dependencies before files importing them.
+    pub fn files(&self) -> &[FileDescriptorProto] {
+        &self.files
+    }
+
+    pub fn file_by_name(&self, name: &str) -> Option<&FileDescriptorProto> {
+        self.files.iter().find(|f| f.get_name() == name)
+    }
+
+    /// Find message by full name, e. g. `pkg.Outer.Inner`.
+    ///
+    /// Leading dot is accepted, so `type_name` of message field
+    /// can be resolved with the pool.
+    pub fn message_by_name(&self, name: &str) -> Option<&DescriptorProto> {
+        self.message_with_file(name).map(|(_, m)| m)
+    }
+
+    /// Find message by full name together with file where it is defined.
+    pub fn message_with_file(
+        &self,
+        name: &str,
+    ) -> Option<(&FileDescriptorProto, &DescriptorProto)> {
+        let &(file_index, ref path) = self.messages.get(strip_leading_dot(name))?;
+        let file = &self.files[file_index];
+        Some((file, message_by_path(file, path)))
+    }
+
+    /// Full names of all messages in the pool, in no particular order.
+    pub fn message_names(&self) -> Vec<&str> {
+        self.messages.keys().map(|k| &k[..]).collect()
+    }
+
+    /// Find enum by full name, e. g. `pkg.Outer.Kind`; leading dot is accepted.
+    pub fn enum_by_name(&self, name: &str) -> Option<&EnumDescriptorProto> {
+        self.enum_with_file(name).map(|(_, e)| e)
+    }
+
+    /// Find enum by full name together with file where it is defined.
+    pub fn enum_with_file(
+        &self,
+        name: &str,
+    ) -> Option<(&FileDescriptorProto, &EnumDescriptorProto)> {
+        let &(file_index, ref path, index) = self.enums.get(strip_leading_dot(name))?;
+        let file = &self.files[file_index];
+        let enums = if path.is_empty() {
+            file.get_enum_type()
+        } else {
+            message_by_path(file, path).get_enum_type()
+        };
+        Some((file, &enums[index]))
+    }
+
+    /// Full names of all enums in the pool, in no particular order.
+    pub fn enum_names(&self) -> Vec<&str> {
+        self.enums.keys().map(|k| &k[..]).collect()
+    }
+
+    /// Reflection descriptor of message from the pool.
+    ///
+    /// `MessageDescriptor` needs generated message type, so descriptors are
+    /// available only for types compiled into the program and registered
+    /// (see `reflect::register_message`); `None` is returned for other messages.
+    /// Their structure is available from `message_by_name`.
+    /// Descriptors of messages loaded at runtime are not implemented yet.
+    pub fn message_descriptor(&self, name: &str) -> Option<&'static MessageDescriptor> {
+        let name = strip_leading_dot(name);
+        if !self.messages.contains_key(name) {
+            return None;
+        }
+        all_registered_messages().into_iter().find(|d| d.full_name() == name)
+    }
+}
+
+fn message_by_path<'a>(file: &'a FileDescriptorProto, path: &[usize]) -> &'a DescriptorProto {
+    let mut message = &file.get_message_type()[path[0]];
+    for &i in &path[1..] {
+        message = &message.get_nested_type()[i];
+    }
+    message
+}
+
+// order files so that dependencies precede files importing them,
+// files which do not depend on each other keep their order
+fn sort_files(files: &[FileDescriptorProto]) -> Result<Vec<usize>, DescriptorPoolError> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        InProgress,
+        Done,
+    }
+
+    struct Sort<'a> {
+        files: &'a [FileDescriptorProto],
+        by_name: HashMap<&'a str, usize>,
+        state: Vec<State>,
+        // files being visited, for reporting cycles
+        stack: Vec<usize>,
+        order: Vec<usize>,
+    }
+
+    impl<'a> Sort<'a> {
+        fn visit(&mut self, i: usize) -> Result<(), DescriptorPoolError> {
+            let files = self.files;
+            match self.state[i] {
+                State::Done => return Ok(()),
+                State::InProgress => {
+                    let start = self.stack.iter().position(|&j| j == i).unwrap();
+                    let mut cycle: Vec<String> = self.stack[start..]
+                        .iter()
+                        .map(|&j| files[j].get_name().to_owned())
+                        .collect();
+                    cycle.push(files[i].get_name().to_owned());
+                    return Err(DescriptorPoolError::DependencyCycle(cycle));
+                }
+                State::New => {}
+            }
+            self.state[i] = State::InProgress;
+            self.stack.push(i);
+            for dependency in files[i].get_dependency() {
+                let j = match self.by_name.get(&dependency[..]) {
+                    Some(&j) => j,
+                    None => {
+                        return Err(DescriptorPoolError::MissingDependency(
+                            files[i].get_name().to_owned(),
+                            dependency.clone(),
+                        ))
+                    }
+                };
+                self.visit(j)?;
+            }
+            self.stack.pop();
+            self.state[i] = State::Done;
+            self.order.push(i);
+            Ok(())
+        }
+    }
+
+    let mut by_name = HashMap::new();
+    for (i, file) in files.iter().enumerate() {
+        if by_name.insert(file.get_name(), i).is_some() {
+            return Err(DescriptorPoolError::DuplicateFile(file.get_name().to_owned()));
+        }
+    }
+    let mut sort = Sort {
+        files: files,
+        by_name: by_name,
+        state: vec![State::New; files.len()],
+        stack: Vec::new(),
+        order: Vec::new(),
+    };
+    for i in 0..files.len() {
+        sort.visit(i)?;
+    }
+    Ok(sort.order)
+}
+
+fn index_messages(
+    scope: &str,
+    messages: &[DescriptorProto],
+    file_index: usize,
+    path: &mut Vec<usize>,
+    r: &mut HashMap<String, (usize, Vec<usize>)>,
+) -> Result<(), DescriptorPoolError> {
+    for (i, message) in messages.iter().enumerate() {
+        let name = full_name(scope, message.get_name());
+        path.push(i);
+        index_messages(&name, message.get_nested_type(), file_index, path, r)?;
+        if r.insert(name.clone(), (file_index, path.clone())).is_some() {
+            return Err(DescriptorPoolError::DuplicateMessage(name));
+        }
+        path.pop();
+    }
+    Ok(())
+}
+
+// index enums of file (if `message` is `None`) or message at `path`
+fn index_enums(
+    scope: &str,
+    file: &FileDescriptorProto,
+    message: Option<&DescriptorProto>,
+    file_index: usize,
+    path: &mut Vec<usize>,
+    messages: &HashMap<String, (usize, Vec<usize>)>,
+    r: &mut HashMap<String, (usize, Vec<usize>, usize)>,
+) -> Result<(), DescriptorPoolError> {
+    let (enums, nested) = match message {
+        Some(m) => (m.get_enum_type(), m.get_nested_type()),
+        None => (file.get_enum_type(), file.get_message_type()),
+    };
+    for (i, e) in enums.iter().enumerate() {
+        let name = full_name(scope, e.get_name());
+        if messages.contains_key(&name) || r.contains_key(&name) {
+            return Err(DescriptorPoolError::DuplicateEnum(name));
+        }
+        r.insert(name, (file_index, path.clone(), i));
+    }
+    for (i, m) in nested.iter().enumerate() {
+        path.push(i);
+        let name = full_name(scope, m.get_name());
+        index_enums(&name, file, Some(m), file_index, path, messages, r)?;
+        path.pop();
+    }
+    Ok(())
+}
+
+
+/// Descriptor pool which can be replaced at runtime.
+///
+/// Snapshots are reference counted, so requests which started
+/// with old pool complete with it, while new requests use the new pool.
+#[derive(Debug)]
+pub struct VersionedDescriptorPool {
+    current: RwLock<Arc<DescriptorPool>>,
+}
+
+impl VersionedDescriptorPool {
+    /// Pool with version 1.
+    pub fn new(mut pool: DescriptorPool) -> VersionedDescriptorPool {
+        pool.version = 1;
+        VersionedDescriptorPool { current: RwLock::new(Arc::new(pool)) }
+    }
+
+    /// Current pool.
+    pub fn snapshot(&self) -> Arc<DescriptorPool> {
+        // lock is never held while code which may panic runs, so it cannot be poisoned
+        self.current.read().unwrap().clone()
+    }
+
+    /// Version of current pool.
+    pub fn version(&self) -> u64 {
+        self.snapshot().version
+    }
+
+    /// Replace current pool, version of the new pool is incremented.
+    /// Previous pool is returned.
+    pub fn swap(&self, mut pool: DescriptorPool) -> Arc<DescriptorPool> {
+        let mut current = self.current.write().unwrap();
+        pool.version = current.version + 1;
+        ::std::mem::replace(&mut *current, Arc::new(pool))
+    }
+
+    /// Build pool from files and make it current.
+    ///
+    /// Current pool is not changed if files are invalid.
+    /// Version of the new pool is returned.
+    pub fn reload(&self, files: Vec<FileDescriptorProto>) -> Result<u64, DescriptorPoolError> {
+        // build outside of lock, so readers are not blocked
+        let pool = DescriptorPool::new(files)?;
+        Ok(self.swap(pool).version + 1)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FieldDescriptorProto_Type;
+    use core::Message;
+    use descriptor;
+    use descriptor_builder::DescriptorBuilder;
+    use descriptor_builder::EnumBuilder;
+    use descriptor_builder::FieldBuilder;
+    use descriptor_builder::MessageBuilder;
+    use plugin;
+    use reflect::register_message;
+
+    fn common() -> FileDescriptorProto {
+        DescriptorBuilder::new("common.proto")
+            .package("common")
+            .message(MessageBuilder::new("Id").nested_message(MessageBuilder::new("Kind")))
+            .enumeration(EnumBuilder::new("Status").value("OK", 0))
+            .build()
+            .unwrap()
+    }
+
+    fn service(field: &str) -> FileDescriptorProto {
+        DescriptorBuilder::new("service.proto")
+            .package("service")
+            .dependency("common.proto")
+            .message(MessageBuilder::new("Request").field(
+                FieldBuilder::new(field, 1, FieldDescriptorProto_Type::TYPE_MESSAGE)
+                    .type_name(".common.Id"),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn lookup() {
+        let pool = DescriptorPool::new(vec![common(), service("id")]).unwrap();
+        assert_eq!(0, pool.version());
+
+        let (file, request) = pool.message_with_file("service.Request").unwrap();
+        assert_eq!("service.proto", file.get_name());
+        let type_name = request.get_field()[0].get_type_name();
+        assert_eq!("Id", pool.message_by_name(type_name).unwrap().get_name());
+        assert_eq!("Kind", pool.message_by_name("common.Id.Kind").unwrap().get_name());
+        assert!(pool.message_by_name("service.Response").is_none());
+
+        let mut names = pool.message_names();
+        names.sort();
+        assert_eq!(vec!["common.Id", "common.Id.Kind", "service.Request"], names);
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            DescriptorPoolError::MissingDependency(
+                "service.proto".to_owned(),
+                "common.proto".to_owned(),
+            ),
+            DescriptorPool::new(vec![service("id")]).unwrap_err()
+        );
+        assert_eq!(
+            DescriptorPoolError::DuplicateFile("common.proto".to_owned()),
+            DescriptorPool::new(vec![common(), common()]).unwrap_err()
+        );
+        let mut other = common();
+        other.set_name("other.proto".to_owned());
+        assert_eq!(
+            DescriptorPoolError::DuplicateMessage("common.Id.Kind".to_owned()),
+            DescriptorPool::new(vec![common(), other]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn dependency_order() {
+        let pool = DescriptorPool::new(vec![service("id"), common()]).unwrap();
+        let names: Vec<&str> = pool.files().iter().map(|f| f.get_name()).collect();
+        assert_eq!(vec!["common.proto", "service.proto"], names);
+
+        let mut common = common();
+        common.mut_dependency().push("service.proto".to_owned());
+        assert_eq!(
+            DescriptorPoolError::DependencyCycle(vec![
+                "service.proto".to_owned(),
+                "common.proto".to_owned(),
+                "service.proto".to_owned(),
+            ]),
+            DescriptorPool::new(vec![service("id"), common]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn enums() {
+        let pool = DescriptorPool::new(vec![common()]).unwrap();
+        assert_eq!(vec!["common.Status"], pool.enum_names());
+        let (file, e) = pool.enum_with_file(".common.Status").unwrap();
+        assert_eq!("common.proto", file.get_name());
+        assert_eq!("OK", e.get_value()[0].get_name());
+        assert!(pool.enum_by_name("common.Id").is_none());
+    }
+
+    #[test]
+    fn type_resolution() {
+        let file = |type_name: &str| {
+            DescriptorBuilder::new("resolve.proto")
+                .package("a.b")
+                .message(MessageBuilder::new("M")
+                    .field(FieldBuilder::new("f", 1, FieldDescriptorProto_Type::TYPE_MESSAGE)
+                        .type_name(type_name))
+                    .nested_message(MessageBuilder::new("Nested")))
+                .message(MessageBuilder::new("Other"))
+                .build()
+                .unwrap()
+        };
+        // relative names are resolved in enclosing scopes
+        for type_name in &["Nested", "M.Nested", "Other", "b.Other", "a.b.Other", ".a.b.Other"] {
+            DescriptorPool::new(vec![file(type_name)]).unwrap();
+        }
+        assert_eq!(
+            DescriptorPoolError::UnresolvedType("a.b.M.f".to_owned(), ".a.Other".to_owned()),
+            DescriptorPool::new(vec![file(".a.Other")]).unwrap_err()
+        );
+
+        // types of files which are not imported are not visible
+        let mut service = service("id");
+        service.clear_dependency();
+        assert_eq!(
+            DescriptorPoolError::UnresolvedType(
+                "service.Request.id".to_owned(),
+                ".common.Id".to_owned(),
+            ),
+            DescriptorPool::new(vec![common(), service]).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn descriptor_set() {
+        let mut set = FileDescriptorSet::new();
+        set.mut_file().push(plugin::file_descriptor_proto().clone());
+        set.mut_file().push(descriptor::file_descriptor_proto().clone());
+        let pool = DescriptorPool::from_bytes(&set.write_to_bytes().unwrap()).unwrap();
+        assert_eq!("google/protobuf/descriptor.proto", pool.files()[0].get_name());
+        assert!(pool.enum_by_name("google.protobuf.FieldDescriptorProto.Type").is_some());
+
+        register_message::<plugin::CodeGeneratorRequest>();
+        let name = "google.protobuf.compiler.CodeGeneratorRequest";
+        assert_eq!(name, pool.message_descriptor(name).unwrap().full_name());
+        assert!(pool.message_descriptor("google.protobuf.Timestamp").is_none());
+
+        match DescriptorPool::from_bytes(b"\xff") {
+            Err(DescriptorPoolError::Parse(..)) => {}
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn reload() {
+        let pool = VersionedDescriptorPool::new(
+            DescriptorPool::new(vec![common(), service("id")]).unwrap(),
+        );
+        assert_eq!(1, pool.version());
+        let old = pool.snapshot();
+
+        assert_eq!(2, pool.reload(vec![common(), service("new_id")]).unwrap());
+        assert!(pool.reload(vec![service("id")]).is_err());
+        assert_eq!(2, pool.version());
+
+        let new = pool.snapshot();
+        let field = |p: &DescriptorPool| {
+            p.message_by_name("service.Request").unwrap().get_field()[0].get_name().to_owned()
+        };
+        // old snapshot is not affected by reload
+        assert_eq!("id", field(&old));
+        assert_eq!("new_id", field(&new));
+        assert_eq!(1, old.version());
+    }
+}
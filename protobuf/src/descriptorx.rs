@@ -9,6 +9,7 @@ use descriptor::OneofDescriptorProto;
 
 use strx;
 use rust;
+use rustproto;
 
 
 // Copy-pasted from libsyntax.
@@ -21,6 +22,25 @@ fn ident_continue(c: char) -> bool {
     (c >= 'a' && c <= 'z') || (c >= 'A' && c <= 'Z') || (c >= '0' && c <= '9') || c == '_'
 }
 
+/// Fully qualified name (without leading dot) of definition `name`
+/// in package or message `scope`, e. g. `foo.Bar` for `Bar` in `foo`.
+pub fn full_name(scope: &str, name: &str) -> String {
+    if scope.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", scope, name)
+    }
+}
+
+/// Type name as used in descriptors (e. g. `.foo.Bar`) without leading dot.
+pub fn strip_leading_dot(type_name: &str) -> &str {
+    if type_name.starts_with('.') {
+        &type_name[1..]
+    } else {
+        type_name
+    }
+}
+
 pub fn proto_path_to_rust_mod(path: &str) -> String {
     let without_dir = strx::remove_to(path, '/');
     let without_suffix = strx::remove_suffix(without_dir, ".proto");
@@ -307,6 +327,11 @@ pub trait WithScope<'a> {
         r
     }
 
+    // protobuf name including package, without leading dot, e. g. `pkg.Foo.Bar`
+    fn full_name(&self) -> String {
+        full_name(self.get_file_descriptor().get_package(), &self.name_to_package())
+    }
+
     // rust type name of this descriptor
     fn rust_name(&self) -> String {
         let mut r = self.get_scope().rust_prefix();
@@ -523,8 +548,11 @@ impl<'a> FieldWithContext<'a> {
         self.field.get_name()
     }
 
-    // field name in generated code
+    // field name in generated code, `rustproto.rust_name` option if set
     pub fn rust_name(&self) -> String {
+        if let Some(rust_name) = rustproto::exts::rust_name.get(self.field.get_options()) {
+            return rust_name;
+        }
         if rust::is_rust_keyword(self.field.get_name()) {
             format!("field_{}", self.field.get_name())
         } else {
@@ -3,6 +3,9 @@ use std::error::Error;
 use std::fmt;
 use std::str;
 
+use core::Message;
+use reflect::ProtobufValueRef;
+use reflect::ReflectFieldRef;
 use wire_format::WireType;
 
 pub type ProtobufResult<T> = Result<T, ProtobufError>;
@@ -19,7 +22,136 @@ pub enum WireError {
     Utf8Error,
     InvalidEnumValue(i32),
     OverRecursionLimit,
+    OverSizeLimit,
+    NonCanonical,
     Other,
+    /// Error with its location in input, returned when parsing messages
+    /// with `ParseOptions::locate_errors` or `CodedInputStream::set_locate_errors`.
+    Located(Box<WireError>, Box<WireErrorLocation>),
+}
+
+impl WireError {
+    fn description(&self) -> &'static str {
+        match *self {
+            WireError::Utf8Error => "invalid UTF-8 sequence",
+            WireError::UnexpectedWireType(..) => "unexpected wire type",
+            WireError::InvalidEnumValue(..) => "invalid enum value",
+            WireError::IncorrectTag(..) => "incorrect tag",
+            WireError::IncorrectVarint => "incorrect varint",
+            WireError::IncompleteMap => "incomplete map",
+            WireError::UnexpectedEof => "unexpected EOF",
+            WireError::OverRecursionLimit => "over recursion limit",
+            WireError::OverSizeLimit => "over size limit",
+            WireError::NonCanonical => "non-canonical encoding",
+            WireError::Other => "other error",
+            WireError::Located(ref e, ..) => e.description(),
+        }
+    }
+}
+
+/// Where in the input `WireError` occurred.
+///
+/// Path is tracked through nested messages, so for input of message
+/// with field `repeated Inner inner = 2`, where `Inner` has field `string name = 1`,
+/// error in name of fourth element of `inner` has path `[(2, 3)]`
+/// and field number `1`, displayed as `2[3].1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WireErrorLocation {
+    /// Position in the input stream where the error was detected.
+    pub offset: u64,
+    /// Number of the field being read,
+    /// `None` if error occurred before the first tag of a message.
+    pub field_number: Option<u32>,
+    /// Message fields containing the field being read, outermost first,
+    /// as field number and index of occurrence of that field in containing message.
+    pub path: Vec<(u32, u32)>,
+}
+
+impl WireErrorLocation {
+    fn numbers(&self) -> Vec<(u32, Option<u32>)> {
+        let mut r: Vec<_> = self.path.iter().map(|&(n, i)| (n, Some(i))).collect();
+        if let Some(n) = self.field_number {
+            r.push((n, None));
+        }
+        r
+    }
+
+    /// Path of the field being read with field names like `inner[3].name`
+    /// resolved by reflection from the message the input was merged into.
+    ///
+    /// Index is displayed only for repeated fields.
+    /// Fields which cannot be resolved (e. g. unknown fields or fields
+    /// inside map entries) are displayed as numbers.
+    pub fn field_path(&self, message: &Message) -> String {
+        let mut r = String::new();
+        let mut message = Some(message);
+        for (n, index) in self.numbers() {
+            if !r.is_empty() {
+                r.push('.');
+            }
+            let field = message.and_then(|m| {
+                m.descriptor_dyn()?
+                    .fields()
+                    .iter()
+                    .find(|f| f.proto().get_number() as u32 == n)
+                    .map(|f| (m, f))
+            });
+            message = None;
+            match field {
+                Some((m, f)) => {
+                    r.push_str(f.name());
+                    match (f.get_reflect(m), index) {
+                        (ReflectFieldRef::Optional(v), Some(..)) => {
+                            if let Some(ProtobufValueRef::Message(m)) = v {
+                                message = Some(m);
+                            }
+                        }
+                        (ReflectFieldRef::Repeated(repeated), Some(index)) => {
+                            r.push_str(&format!("[{}]", index));
+                            if (index as usize) < repeated.len() {
+                                if let ProtobufValueRef::Message(m) =
+                                    repeated.get(index as usize).as_ref()
+                                {
+                                    message = Some(m);
+                                }
+                            }
+                        }
+                        (ReflectFieldRef::Map(..), Some(index)) => {
+                            r.push_str(&format!("[{}]", index));
+                        }
+                        (_, None) => {}
+                    }
+                }
+                None => {
+                    r.push_str(&format!("{}", n));
+                    if let Some(index) = index {
+                        r.push_str(&format!("[{}]", index));
+                    }
+                }
+            }
+        }
+        r
+    }
+}
+
+impl fmt::Display for WireErrorLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "offset {}", self.offset)?;
+        let numbers = self.numbers();
+        if !numbers.is_empty() {
+            write!(f, ", field ")?;
+            for (i, (n, index)) in numbers.into_iter().enumerate() {
+                if i != 0 {
+                    write!(f, ".")?;
+                }
+                write!(f, "{}", n)?;
+                if let Some(index) = index {
+                    write!(f, "[{}]", index)?;
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug)]
@@ -34,11 +166,33 @@ impl ProtobufError {
     pub fn message_not_initialized(message: &'static str) -> ProtobufError {
         ProtobufError::MessageNotInitialized { message: message }
     }
+
+    /// Wire error regardless of whether location is known.
+    pub fn wire_error(&self) -> Option<&WireError> {
+        match *self {
+            ProtobufError::WireError(WireError::Located(ref e, ..)) => Some(e),
+            ProtobufError::WireError(ref e) => Some(e),
+            _ => None,
+        }
+    }
+
+    /// Location of wire error in input.
+    pub fn location(&self) -> Option<&WireErrorLocation> {
+        match *self {
+            ProtobufError::WireError(WireError::Located(_, ref location)) => Some(location),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for ProtobufError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
+        match *self {
+            ProtobufError::WireError(WireError::Located(ref e, ref location)) => {
+                write!(f, "{:?} at {}", e, location)
+            }
+            _ => fmt::Debug::fmt(self, f),
+        }
     }
 }
 
@@ -47,19 +201,7 @@ impl Error for ProtobufError {
         match self {
             // not sure that cause should be included in message
             &ProtobufError::IoError(ref e) => e.description(),
-            &ProtobufError::WireError(ref e) => {
-                match *e {
-                    WireError::Utf8Error => "invalid UTF-8 sequence",
-                    WireError::UnexpectedWireType(..) => "unexpected wire type",
-                    WireError::InvalidEnumValue(..) => "invalid enum value",
-                    WireError::IncorrectTag(..) => "incorrect tag",
-                    WireError::IncorrectVarint => "incorrect varint",
-                    WireError::IncompleteMap => "incomplete map",
-                    WireError::UnexpectedEof => "unexpected EOF",
-                    WireError::OverRecursionLimit => "over recursion limit",
-                    WireError::Other => "other error",
-                }
-            }
+            &ProtobufError::WireError(ref e) => e.description(),
             &ProtobufError::Utf8(ref e) => &e.description(),
             &ProtobufError::MessageNotInitialized { .. } => "not all message fields set",
         }
@@ -104,3 +246,106 @@ impl From<ProtobufError> for io::Error {
         }
     }
 }
+
+
+/// Integer field value does not fit into type requested
+/// with generated `get_foo_as::<T>()` accessor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConvertError {
+    field_name: &'static str,
+    value: String,
+}
+
+impl ConvertError {
+    pub fn new<V : fmt::Display>(field_name: &'static str, value: V) -> ConvertError {
+        ConvertError {
+            field_name: field_name,
+            value: value.to_string(),
+        }
+    }
+
+    /// Name of the field in `.proto` file.
+    pub fn field_name(&self) -> &'static str {
+        self.field_name
+    }
+}
+
+impl fmt::Display for ConvertError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "value {} of field {} is out of range of target type",
+            self.value,
+            self.field_name
+        )
+    }
+}
+
+impl Error for ConvertError {
+    fn description(&self) -> &str {
+        "field value is out of range of target type"
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FileDescriptorProto;
+    use parse_options::ParseOptions;
+    use stream::CodedInputStream;
+
+    // `FileDescriptorProto` with second message type having
+    // first field with `name` of incorrect wire type
+    const MALFORMED: &[u8] = &[0x22, 0x00, 0x22, 0x04, 0x12, 0x02, 0x08, 0x01];
+
+    #[test]
+    fn not_located_by_default() {
+        match ::parse_from_bytes::<FileDescriptorProto>(MALFORMED) {
+            Err(ProtobufError::WireError(WireError::UnexpectedWireType(..))) => {}
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn location() {
+        let mut file = FileDescriptorProto::new();
+        let e = {
+            let mut is = CodedInputStream::from_bytes(MALFORMED);
+            is.set_locate_errors(true);
+            file.merge_from(&mut is).unwrap_err()
+        };
+        match e.wire_error() {
+            Some(&WireError::UnexpectedWireType(WireType::WireTypeVarint)) => {}
+            _ => panic!("{:?}", e),
+        }
+        let location = e.location().unwrap();
+        assert_eq!(7, location.offset);
+        assert_eq!(Some(1), location.field_number);
+        assert_eq!(vec![(4, 1), (2, 0)], location.path);
+        assert_eq!("offset 7, field 4[1].2[0].1", format!("{}", location));
+        assert_eq!("message_type[1].field[0].name", location.field_path(&file));
+    }
+
+    #[test]
+    fn location_top_level() {
+        // `name` of incorrect wire type
+        let options = ParseOptions::new().locate_errors(true);
+        let e = options.parse_from_bytes::<FileDescriptorProto>(&[0x08, 0x01]).unwrap_err();
+        match e {
+            ProtobufError::WireError(..) => {}
+            _ => panic!("{:?}", e),
+        }
+        let location = e.location().unwrap();
+        assert_eq!(Some(1), location.field_number);
+        assert!(location.path.is_empty());
+        assert_eq!("UnexpectedWireType(WireTypeVarint) at offset 1, field 1", format!("{}", e));
+    }
+
+    #[test]
+    fn convert_error() {
+        let e = ConvertError::new("size", -1);
+        assert_eq!("size", e.field_name());
+        assert_eq!("value -1 of field size is out of range of target type", format!("{}", e));
+    }
+}
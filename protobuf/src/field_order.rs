@@ -0,0 +1,182 @@
+//! Preserving order of fields of parsed messages.
+//!
+//! Generated code writes fields in order of field numbers, and unknown
+//! fields after known fields, so re-serialized foreign message may differ
+//! from the original bytes even if nothing changed. When bytes must be
+//! reproduced exactly (e. g. to verify signature or to compute content address),
+//! original order can be recorded during parsing and restored on write:
+//!
+//! ```ignore
+//! let (mut m, order) = parse_preserving_order::<Envelope>(&bytes)?;
+//! m.set_hops(m.get_hops() + 1);
+//! let bytes = write_preserving_order(&m, &order)?;
+//! ```
+//!
+//! Only order of top-level fields is preserved: nested messages are written
+//! as generated code writes them. Output is byte-exact if values of the original
+//! fields are encoded the same way as generated code encodes them,
+//! which is true for messages written by conforming implementations.
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::Hasher;
+
+use core::parse_from_bytes;
+use core::Message;
+use error::ProtobufResult;
+use wire_editor::WireEditor;
+
+
+/// Order of top-level fields of serialized message.
+///
+/// For each field occurrence only its number and hash of its bytes are stored.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FieldOrder {
+    fields: Vec<(u32, u64)>,
+}
+
+fn hash(data: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    hasher.write(data);
+    hasher.finish()
+}
+
+impl FieldOrder {
+    /// Record order of fields of serialized message.
+    ///
+    /// Fails if message is malformed.
+    pub fn scan(bytes: &[u8]) -> ProtobufResult<FieldOrder> {
+        let editor = WireEditor::new(bytes)?;
+        Ok(FieldOrder {
+            fields: editor.fields().iter().map(|f| (f.number, hash(f.data))).collect(),
+        })
+    }
+
+    /// Numbers of fields in order of occurrence, repeated fields
+    /// and map fields occur several times.
+    pub fn numbers(&self) -> Vec<u32> {
+        self.fields.iter().map(|&(number, _)| number).collect()
+    }
+
+    /// Reorder fields of serialized message.
+    ///
+    /// Each recorded occurrence takes an unused field with the same number,
+    /// preferably with the same bytes, so unchanged map entries and repeated
+    /// elements keep their positions. Fields not present in the record
+    /// (e. g. set after parsing) are written after recorded fields in original order.
+    pub fn reorder(&self, bytes: &[u8]) -> ProtobufResult<Vec<u8>> {
+        let editor = WireEditor::new(bytes)?;
+        let fields = editor.fields();
+
+        let hashes: Vec<u64> = fields.iter().map(|f| hash(f.data)).collect();
+
+        // unused fields by number
+        let mut by_number: HashMap<u32, Vec<usize>> = HashMap::new();
+        for (i, f) in fields.iter().enumerate() {
+            by_number.entry(f.number).or_insert_with(Vec::new).push(i);
+        }
+
+        let mut used = vec![false; fields.len()];
+        let mut r = Vec::with_capacity(bytes.len());
+        for &(number, h) in &self.fields {
+            let candidates = match by_number.get_mut(&number) {
+                Some(ref candidates) if candidates.is_empty() => continue,
+                Some(candidates) => candidates,
+                // field was cleared
+                None => continue,
+            };
+            let pos = candidates.iter().position(|&i| hashes[i] == h).unwrap_or(0);
+            let i = candidates.remove(pos);
+            used[i] = true;
+            r.extend_from_slice(fields[i].data);
+        }
+        for (f, used) in fields.iter().zip(used) {
+            if !used {
+                r.extend_from_slice(f.data);
+            }
+        }
+        Ok(r)
+    }
+}
+
+/// Parse message and record order of its fields.
+pub fn parse_preserving_order<M: Message>(bytes: &[u8]) -> ProtobufResult<(M, FieldOrder)> {
+    let order = FieldOrder::scan(bytes)?;
+    let m = parse_from_bytes(bytes)?;
+    Ok((m, order))
+}
+
+/// Serialize message writing fields in recorded order.
+pub fn write_preserving_order(m: &Message, order: &FieldOrder) -> ProtobufResult<Vec<u8>> {
+    order.reorder(&m.write_to_bytes()?)
+}
+
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FileDescriptorProto;
+    use unknown::UnknownValueRef;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    // fields of message in given order
+    fn concat(fields: &[(u32, UnknownValueRef)]) -> Vec<u8> {
+        let mut editor = WireEditor::new(&[]).unwrap();
+        for &(number, value) in fields {
+            editor.add(number, value);
+        }
+        editor.to_bytes()
+    }
+
+    fn file_bytes() -> Vec<u8> {
+        concat(&[
+            (3, UnknownValueRef::LengthDelimited(b"b.proto")),
+            (1000, UnknownValueRef::Varint(7)),
+            (1, UnknownValueRef::LengthDelimited(b"a.proto")),
+            (3, UnknownValueRef::LengthDelimited(b"c.proto")),
+            (2, UnknownValueRef::LengthDelimited(b"p")),
+        ])
+    }
+
+    #[test]
+    fn unchanged() {
+        let bytes = file_bytes();
+        let (m, order) = parse_preserving_order::<FileDescriptorProto>(&bytes).unwrap();
+        assert_eq!(vec![3, 1000, 1, 3, 2], order.numbers());
+        assert!(m.write_to_bytes().unwrap() != bytes);
+        assert_eq!(bytes, write_preserving_order(&m, &order).unwrap());
+    }
+
+    #[test]
+    fn changed() {
+        let (mut m, order) = parse_preserving_order::<FileDescriptorProto>(&file_bytes()).unwrap();
+        m.set_name("x.proto".to_owned());
+        m.clear_package();
+        m.mut_dependency()[1] = "d.proto".to_owned();
+        m.set_syntax("proto3".to_owned());
+
+        let expected = concat(&[
+            (3, UnknownValueRef::LengthDelimited(b"b.proto")),
+            (1000, UnknownValueRef::Varint(7)),
+            (1, UnknownValueRef::LengthDelimited(b"x.proto")),
+            (3, UnknownValueRef::LengthDelimited(b"d.proto")),
+            (12, UnknownValueRef::LengthDelimited(b"proto3")),
+        ]);
+        assert_eq!(expected, write_preserving_order(&m, &order).unwrap());
+    }
+
+    #[test]
+    fn map_entries() {
+        let mut bytes = Vec::new();
+        for key in &["c", "a", "d", "b"] {
+            let mut s = Struct::new();
+            s.mut_fields().insert(key.to_string(), Value::new());
+            bytes.extend(s.write_to_bytes().unwrap());
+        }
+        let (m, order) = parse_preserving_order::<Struct>(&bytes).unwrap();
+        assert_eq!(4, m.get_fields().len());
+        assert_eq!(bytes, write_preserving_order(&m, &order).unwrap());
+    }
+}
@@ -0,0 +1,289 @@
+//! Items generated code may reference.
+//!
+//! This is the contract between `protobuf-codegen` and the runtime.
+//! Generated code only uses items listed here (by their original paths,
+//! e. g. `::protobuf::rt::string_size`), and these paths are kept working
+//! in all `1.x` versions of the runtime, so code generated by any
+//! earlier `1.x` codegen compiles with newer runtime.
+//!
+//! When an item is replaced (for example, `lazy::Lazy` by a different
+//! lazy initialization primitive), the old item is kept at the old path,
+//! marked `#[deprecated]` when possible, and removed not earlier
+//! than in the next major version.
+//!
+//! Everything not reexported here (including other items of the `rt`
+//! module) is an implementation detail and may change in minor versions.
+//! `protobuf-test` checks that generated code references only listed items,
+//! and that code generated by rust-protobuf 1.6.0 compiles.
+//!
+//! Users should not use these items directly.
+
+pub use core::Message;
+pub use core::MessageFull;
+pub use core::ProtobufEnum;
+pub use core::ProtobufEnumFull;
+pub use core::parse_from_bytes;
+#[cfg(feature = "bytes")]
+pub use core::parse_from_carllerche_bytes;
+pub use clear::Clear;
+pub use convert::ProtobufConvert;
+pub use cached_size::CachedSize;
+pub use size_cache::SizeCache;
+pub use repeated::RepeatedField;
+pub use singular::SingularField;
+pub use singular::SingularPtrField;
+pub use lazy_message::LazyMessageField;
+pub use unknown::UnknownFields;
+pub use stream::CodedInputStream;
+pub use stream::CodedOutputStream;
+pub use error::ProtobufResult;
+pub use error::ProtobufError;
+pub use error::ConvertError;
+#[cfg(feature = "bytes")]
+pub use chars::Chars;
+#[cfg(feature = "smallvec")]
+pub use smallvec::SmallVec;
+
+// Generated types used by options extensions and well known type fields.
+pub use descriptor;
+pub use well_known_types;
+
+/// Crate used by `generate_arbitrary` codegen option.
+#[cfg(feature = "arbitrary")]
+pub use arbitrary;
+/// Crate used by `generate_proptest` codegen option.
+#[cfg(feature = "proptest")]
+pub use proptest;
+
+/// Items of `protobuf::rt` used by generated code.
+pub mod rt {
+    pub use rt::compute_raw_varint32_size;
+    pub use rt::compute_raw_varint64_size;
+    pub use rt::vec_packed_varint_data_size;
+    pub use rt::vec_packed_varint_zigzag_data_size;
+    pub use rt::vec_packed_enum_data_size;
+    pub use rt::vec_packed_varint_size;
+    pub use rt::vec_packed_varint_zigzag_size;
+    pub use rt::vec_packed_enum_size;
+    pub use rt::value_size;
+    pub use rt::value_varint_zigzag_size;
+    pub use rt::enum_size;
+    pub use rt::bytes_size;
+    pub use rt::string_size;
+    pub use rt::unknown_fields_size;
+    pub use rt::read_repeated_int32_into;
+    pub use rt::read_repeated_int64_into;
+    pub use rt::read_repeated_uint32_into;
+    pub use rt::read_repeated_uint64_into;
+    pub use rt::read_repeated_sint32_into;
+    pub use rt::read_repeated_sint64_into;
+    pub use rt::read_repeated_fixed32_into;
+    pub use rt::read_repeated_fixed64_into;
+    pub use rt::read_repeated_sfixed32_into;
+    pub use rt::read_repeated_sfixed64_into;
+    pub use rt::read_repeated_double_into;
+    pub use rt::read_repeated_float_into;
+    pub use rt::read_repeated_bool_into;
+    #[cfg(feature = "smallvec")]
+    pub use rt::read_repeated_smallvec_into;
+    pub use rt::read_repeated_enum_into;
+    pub use rt::read_repeated_enum_with_unknown_fields_into;
+    pub use rt::read_proto3_enum_with_unknown_fields_into;
+    pub use rt::read_proto2_enum_with_unknown_fields_into;
+    pub use rt::read_repeated_string_into;
+    pub use rt::read_repeated_vec_string_into;
+    #[cfg(feature = "bytes")]
+    pub use rt::read_repeated_carllerche_string_into;
+    pub use rt::read_singular_string_into;
+    #[cfg(feature = "bytes")]
+    pub use rt::read_singular_carllerche_string_into;
+    pub use rt::read_singular_proto3_string_into;
+    #[cfg(feature = "bytes")]
+    pub use rt::read_singular_proto3_carllerche_string_into;
+    pub use rt::read_repeated_bytes_into;
+    pub use rt::read_repeated_vec_bytes_into;
+    #[cfg(feature = "bytes")]
+    pub use rt::read_repeated_carllerche_bytes_into;
+    pub use rt::read_singular_bytes_into;
+    #[cfg(feature = "bytes")]
+    pub use rt::read_singular_carllerche_bytes_into;
+    pub use rt::read_singular_proto3_bytes_into;
+    #[cfg(feature = "bytes")]
+    pub use rt::read_singular_proto3_carllerche_bytes_into;
+    pub use rt::read_repeated_message_into;
+    pub use rt::read_repeated_vec_message_into;
+    pub use rt::read_singular_message_into;
+    pub use rt::read_singular_option_box_message_into;
+    pub use rt::read_singular_lazy_message_into;
+    pub use rt::read_unknown_or_skip_group;
+    pub use rt::unexpected_wire_type;
+    pub use rt::checked_convert;
+    pub use rt::compute_map_size;
+    pub use rt::write_map_with_cached_sizes;
+    pub use rt::compute_map_size_with_cache;
+    pub use rt::write_map_with_size_cache;
+    pub use rt::read_map_into;
+    #[cfg(feature = "miniz_oxide")]
+    pub use rt::parse_compressed_file_descriptor;
+    #[cfg(feature = "arbitrary")]
+    pub use rt::ArbitraryFields;
+    #[cfg(feature = "arbitrary")]
+    pub use rt::arbitrary_message;
+    #[cfg(feature = "arbitrary")]
+    pub use rt::arbitrary_len;
+    #[cfg(feature = "arbitrary")]
+    pub use rt::arbitrary_enum;
+    #[cfg(feature = "proptest")]
+    pub use rt::PROPTEST_MAX_LEN;
+    #[cfg(feature = "proptest")]
+    pub use rt::ProptestDepth;
+    #[cfg(feature = "proptest")]
+    pub use rt::proptest_enum;
+}
+
+/// Items of `protobuf::lazy` used by generated code.
+pub mod lazy {
+    pub use lazy::Lazy;
+    pub use lazy::LazyV2;
+    pub use lazy::ONCE_INIT;
+}
+
+/// Items of `protobuf::reflect` used by generated code.
+pub mod reflect {
+    pub use reflect::EnumDescriptor;
+    pub use reflect::MessageDescriptor;
+    pub use reflect::ProtobufValue;
+    pub use reflect::ProtobufValueRef;
+
+    /// Field accessors constructors used in generated `type_descriptor`.
+    pub mod accessor {
+        pub use reflect::accessor::make_singular_u32_accessor;
+        pub use reflect::accessor::make_singular_i32_accessor;
+        pub use reflect::accessor::make_singular_u64_accessor;
+        pub use reflect::accessor::make_singular_i64_accessor;
+        pub use reflect::accessor::make_singular_f32_accessor;
+        pub use reflect::accessor::make_singular_f64_accessor;
+        pub use reflect::accessor::make_singular_bool_accessor;
+        pub use reflect::accessor::make_singular_enum_accessor;
+        pub use reflect::accessor::make_singular_string_accessor;
+        pub use reflect::accessor::make_singular_bytes_accessor;
+        pub use reflect::accessor::make_singular_message_accessor;
+        pub use reflect::accessor::make_vec_accessor;
+        #[cfg(feature = "smallvec")]
+        pub use reflect::accessor::make_smallvec_accessor;
+        pub use reflect::accessor::make_repeated_field_accessor;
+        pub use reflect::accessor::make_option_accessor;
+        pub use reflect::accessor::make_singular_field_accessor;
+        pub use reflect::accessor::make_singular_ptr_field_accessor;
+        pub use reflect::accessor::make_lazy_message_field_accessor;
+        pub use reflect::accessor::make_option_box_accessor;
+        pub use reflect::accessor::make_simple_field_accessor;
+        pub use reflect::accessor::make_map_accessor;
+        pub use reflect::accessor::make_oneof_accessor;
+    }
+}
+
+/// Items of `protobuf::types` used by generated code.
+pub mod types {
+    pub use types::ProtobufType;
+    pub use types::ProtobufTypeFloat;
+    pub use types::ProtobufTypeDouble;
+    pub use types::ProtobufTypeInt32;
+    pub use types::ProtobufTypeInt64;
+    pub use types::ProtobufTypeUint32;
+    pub use types::ProtobufTypeUint64;
+    pub use types::ProtobufTypeSint32;
+    pub use types::ProtobufTypeSint64;
+    pub use types::ProtobufTypeFixed32;
+    pub use types::ProtobufTypeFixed64;
+    pub use types::ProtobufTypeSfixed32;
+    pub use types::ProtobufTypeSfixed64;
+    pub use types::ProtobufTypeBool;
+    pub use types::ProtobufTypeString;
+    pub use types::ProtobufTypeBytes;
+    pub use types::ProtobufTypeChars;
+    #[cfg(feature = "bytes")]
+    pub use types::ProtobufTypeCarllercheBytes;
+    #[cfg(feature = "bytes")]
+    pub use types::ProtobufTypeCarllercheChars;
+    pub use types::ProtobufTypeEnum;
+    pub use types::ProtobufTypeMessage;
+}
+
+/// Items of `protobuf::wire_format` used by generated code.
+pub mod wire_format {
+    pub use stream::wire_format::WireType;
+    pub use stream::wire_format::WireTypeVarint;
+    pub use stream::wire_format::WireTypeFixed64;
+    pub use stream::wire_format::WireTypeLengthDelimited;
+    pub use stream::wire_format::WireTypeStartGroup;
+    pub use stream::wire_format::WireTypeEndGroup;
+    pub use stream::wire_format::WireTypeFixed32;
+}
+
+/// Items of `protobuf::text_format` used by generated code.
+pub mod text_format {
+    pub use text_format::fmt;
+}
+
+/// Items of `protobuf::ext` used by generated code.
+pub mod ext {
+    pub use ext::ExtFieldOptional;
+    pub use ext::ExtFieldRepeated;
+}
+
+/// Items of `protobuf::wrapper_option` used by generated code.
+pub mod wrapper_option {
+    pub use wrapper_option::WrapperValue;
+}
+
+// Fail compilation if a path referenced by generated code disappears.
+#[cfg(test)]
+mod test {
+    #[allow(unused_imports)]
+    use ::{
+        CachedSize, Clear, CodedInputStream, CodedOutputStream, Message, ProtobufEnum,
+        ProtobufResult, RepeatedField, SingularField, SingularPtrField, SizeCache,
+        UnknownFields, parse_from_bytes,
+    };
+    #[allow(unused_imports)]
+    use ::lazy::{Lazy, LazyV2, ONCE_INIT};
+    #[allow(unused_imports)]
+    use ::reflect::{EnumDescriptor, MessageDescriptor, ProtobufValue, ProtobufValueRef};
+    #[allow(unused_imports)]
+    use ::rt::{
+        bytes_size, compute_map_size, compute_map_size_with_cache, compute_raw_varint32_size,
+        compute_raw_varint64_size, enum_size, read_map_into, read_proto2_enum_with_unknown_fields_into,
+        read_proto3_enum_with_unknown_fields_into, read_repeated_enum_with_unknown_fields_into,
+        read_repeated_message_into, read_singular_message_into, read_unknown_or_skip_group,
+        string_size, unexpected_wire_type, unknown_fields_size, value_size,
+        value_varint_zigzag_size, vec_packed_varint_data_size, vec_packed_varint_size,
+        write_map_with_cached_sizes, write_map_with_size_cache,
+    };
+    #[cfg(feature = "miniz_oxide")]
+    #[allow(unused_imports)]
+    use ::rt::parse_compressed_file_descriptor;
+    #[allow(unused_imports)]
+    use ::types::{ProtobufType, ProtobufTypeEnum, ProtobufTypeMessage, ProtobufTypeString};
+    #[allow(unused_imports)]
+    use ::wire_format::{WireTypeFixed32, WireTypeFixed64, WireTypeLengthDelimited, WireTypeVarint};
+    #[allow(unused_imports)]
+    use ::text_format::fmt;
+    #[allow(unused_imports)]
+    use ::ext::{ExtFieldOptional, ExtFieldRepeated};
+    #[allow(unused_imports)]
+    use ::descriptor::FileDescriptorProto;
+
+    #[test]
+    fn contract_items_are_same() {
+        use std::any::TypeId;
+        use super::*;
+
+        assert_eq!(TypeId::of::<CachedSize>(), TypeId::of::<::CachedSize>());
+        assert_eq!(TypeId::of::<SizeCache>(), TypeId::of::<::SizeCache>());
+        assert_eq!(
+            TypeId::of::<reflect::MessageDescriptor>(),
+            TypeId::of::<::reflect::MessageDescriptor>()
+        );
+    }
+}
@@ -0,0 +1,396 @@
+//! Reader of messages in gRPC framing.
+//!
+//! gRPC body is a sequence of messages, each prefixed with five bytes:
+//! compressed flag and big-endian length of the message.
+//! This module allows parsing such bodies (e. g. captured from the wire)
+//! without gRPC implementation:
+//!
+//! ```ignore
+//! let reader = GrpcFrameReader::<_, HelloReply>::new(&body[..]);
+//! for reply in reader {
+//!     println!("{:?}", reply?);
+//! }
+//! ```
+//!
+//! Compressed messages are decompressed with `Decompressor`
+//! matching `grpc-encoding` header of the call.
+
+use std::io;
+use std::io::Read;
+use std::marker;
+
+#[cfg(feature = "miniz_oxide")]
+use miniz_oxide;
+
+use core::Message;
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
+use parse_options::ParseOptions;
+
+
+/// Default limit of message size, same as default limit of gRPC implementations.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 4 << 20;
+
+/// Decompression algorithm of gRPC messages.
+pub trait Decompressor {
+    /// Decompress message.
+    ///
+    /// Decompressed message larger than `max_size` must be rejected,
+    /// so small compressed input cannot consume unbounded memory.
+    fn decompress(&self, compressed: &[u8], max_size: usize) -> io::Result<Vec<u8>>;
+}
+
+/// `gzip` encoding.
+#[cfg(feature = "miniz_oxide")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Gzip;
+
+#[cfg(feature = "miniz_oxide")]
+impl Decompressor for Gzip {
+    fn decompress(&self, compressed: &[u8], max_size: usize) -> io::Result<Vec<u8>> {
+        let deflate = gzip_deflate_data(compressed)?;
+        inflate_result(miniz_oxide::inflate::decompress_to_vec_with_limit(
+            deflate, max_size,
+        ))
+    }
+}
+
+/// `deflate` encoding, which is zlib format in gRPC.
+#[cfg(feature = "miniz_oxide")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Deflate;
+
+#[cfg(feature = "miniz_oxide")]
+impl Decompressor for Deflate {
+    fn decompress(&self, compressed: &[u8], max_size: usize) -> io::Result<Vec<u8>> {
+        inflate_result(miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(
+            compressed, max_size,
+        ))
+    }
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(feature = "miniz_oxide")]
+fn inflate_result(
+    r: Result<Vec<u8>, miniz_oxide::inflate::TINFLStatus>,
+) -> io::Result<Vec<u8>> {
+    r.map_err(|e| match e {
+        miniz_oxide::inflate::TINFLStatus::HasMoreOutput => {
+            invalid_data("decompressed message is too large")
+        }
+        _ => invalid_data("failed to decompress message"),
+    })
+}
+
+// deflate stream of gzip member, header and trailer are skipped
+#[cfg(feature = "miniz_oxide")]
+fn gzip_deflate_data(data: &[u8]) -> io::Result<&[u8]> {
+    const FHCRC: u8 = 0x02;
+    const FEXTRA: u8 = 0x04;
+    const FNAME: u8 = 0x08;
+    const FCOMMENT: u8 = 0x10;
+
+    let truncated = || invalid_data("truncated gzip data");
+
+    // magic, compression method, flags, mtime, extra flags, os
+    if data.len() < 10 {
+        return Err(truncated());
+    }
+    if data[0] != 0x1f || data[1] != 0x8b || data[2] != 8 {
+        return Err(invalid_data("not gzip data"));
+    }
+    let flags = data[3];
+    let mut pos = 10;
+
+    if flags & FEXTRA != 0 {
+        if data.len() < pos + 2 {
+            return Err(truncated());
+        }
+        pos += 2 + (data[pos] as usize | (data[pos + 1] as usize) << 8);
+    }
+    for &flag in &[FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            // zero-terminated string
+            match data.get(pos..).and_then(|d| d.iter().position(|&b| b == 0)) {
+                Some(len) => pos += len + 1,
+                None => return Err(truncated()),
+            }
+        }
+    }
+    if flags & FHCRC != 0 {
+        pos += 2;
+    }
+
+    // crc32 and size of decompressed data
+    if data.len() < pos + 8 {
+        return Err(truncated());
+    }
+    Ok(&data[pos..data.len() - 8])
+}
+
+/// Iterator over messages of gRPC body.
+///
+/// Reading stops at the end of input, or after the first error,
+/// because position of the next frame is unknown after error.
+pub struct GrpcFrameReader<R, M> {
+    reader: R,
+    decompressor: Option<Box<Decompressor>>,
+    max_message_size: usize,
+    parse_options: ParseOptions,
+    failed: bool,
+    _marker: marker::PhantomData<M>,
+}
+
+impl<R : Read, M : Message> GrpcFrameReader<R, M> {
+    /// Reader of uncompressed messages.
+    pub fn new(reader: R) -> GrpcFrameReader<R, M> {
+        GrpcFrameReader {
+            reader: reader,
+            decompressor: None,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            parse_options: ParseOptions::new(),
+            failed: false,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Decompress messages with compressed flag set.
+    ///
+    /// Without decompressor such messages are rejected with error.
+    pub fn decompressor<D : Decompressor + 'static>(
+        mut self,
+        decompressor: D,
+    ) -> GrpcFrameReader<R, M> {
+        self.decompressor = Some(Box::new(decompressor));
+        self
+    }
+
+    /// Max size of message in bytes, both compressed and decompressed,
+    /// `DEFAULT_MAX_MESSAGE_SIZE` by default.
+    ///
+    /// Larger messages are rejected with `WireError::OverSizeLimit`.
+    pub fn max_message_size(mut self, max_message_size: usize) -> GrpcFrameReader<R, M> {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Options used to parse messages.
+    pub fn parse_options(mut self, parse_options: ParseOptions) -> GrpcFrameReader<R, M> {
+        self.parse_options = parse_options;
+        self
+    }
+
+    /// Read next message, `None` at the end of input.
+    ///
+    /// End of input inside frame is an error.
+    pub fn read_message(&mut self) -> ProtobufResult<Option<M>> {
+        let r = self.read_message_impl();
+        if r.is_err() {
+            self.failed = true;
+        }
+        r
+    }
+
+    /// Get underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_exact(&mut self, buf: &mut [u8]) -> ProtobufResult<()> {
+        match self.reader.read_exact(buf) {
+            Ok(()) => Ok(()),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                Err(ProtobufError::WireError(WireError::UnexpectedEof))
+            }
+            Err(e) => Err(ProtobufError::IoError(e)),
+        }
+    }
+
+    fn read_message_impl(&mut self) -> ProtobufResult<Option<M>> {
+        let mut compressed = [0];
+        loop {
+            match self.reader.read(&mut compressed) {
+                Ok(0) => return Ok(None),
+                Ok(_) => break,
+                Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(ProtobufError::IoError(e)),
+            }
+        }
+
+        let mut len = [0; 4];
+        self.read_exact(&mut len)?;
+        let len = (len[0] as usize) << 24
+            | (len[1] as usize) << 16
+            | (len[2] as usize) << 8
+            | len[3] as usize;
+        if len > self.max_message_size {
+            return Err(ProtobufError::WireError(WireError::OverSizeLimit));
+        }
+
+        let mut message = vec![0; len];
+        self.read_exact(&mut message)?;
+
+        let message = match (compressed[0], &self.decompressor) {
+            (0, _) => message,
+            (1, &Some(ref decompressor)) => {
+                decompressor.decompress(&message, self.max_message_size)?
+            }
+            (1, &None) => {
+                return Err(ProtobufError::IoError(invalid_data(
+                    "compressed message, but decompressor is not configured",
+                )))
+            }
+            _ => return Err(ProtobufError::IoError(invalid_data("incorrect compressed flag"))),
+        };
+
+        self.parse_options.parse_from_bytes(&message).map(Some)
+    }
+}
+
+impl<R : Read, M : Message> Iterator for GrpcFrameReader<R, M> {
+    type Item = ProtobufResult<M>;
+
+    fn next(&mut self) -> Option<ProtobufResult<M>> {
+        if self.failed {
+            return None;
+        }
+        match self.read_message() {
+            Ok(Some(message)) => Some(Ok(message)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use well_known_types::StringValue;
+
+    fn message(value: &str) -> StringValue {
+        let mut m = StringValue::new();
+        m.set_value(value.to_owned());
+        m
+    }
+
+    fn frame(compressed: bool, data: &[u8]) -> Vec<u8> {
+        let len = data.len() as u32;
+        let mut r = vec![
+            compressed as u8,
+            (len >> 24) as u8,
+            (len >> 16) as u8,
+            (len >> 8) as u8,
+            len as u8,
+        ];
+        r.extend_from_slice(data);
+        r
+    }
+
+    fn read_all(body: &[u8]) -> Vec<ProtobufResult<StringValue>> {
+        GrpcFrameReader::new(body).collect()
+    }
+
+    // reverses bytes
+    struct Reverse;
+
+    impl Decompressor for Reverse {
+        fn decompress(&self, compressed: &[u8], _max_size: usize) -> io::Result<Vec<u8>> {
+            Ok(compressed.iter().rev().cloned().collect())
+        }
+    }
+
+    #[test]
+    fn uncompressed() {
+        let mut body = Vec::new();
+        body.extend(frame(false, &message("a").write_to_bytes().unwrap()));
+        body.extend(frame(false, &[]));
+        body.extend(frame(false, &message("bc").write_to_bytes().unwrap()));
+
+        let messages: Vec<StringValue> = read_all(&body).into_iter().map(|m| m.unwrap()).collect();
+        assert_eq!(vec![message("a"), message(""), message("bc")], messages);
+        assert!(read_all(&[]).is_empty());
+    }
+
+    #[test]
+    fn truncated() {
+        let body = frame(false, &message("abc").write_to_bytes().unwrap());
+        for len in 1..body.len() {
+            let messages = read_all(&body[..len]);
+            assert_eq!(1, messages.len());
+            match messages[0] {
+                Err(ProtobufError::WireError(WireError::UnexpectedEof)) => {}
+                ref r => panic!("expecting EOF error: {:?}", r),
+            }
+        }
+    }
+
+    #[test]
+    fn compressed() {
+        let mut data = message("abc").write_to_bytes().unwrap();
+        data.reverse();
+        let mut body = frame(true, &data);
+        body.extend(frame(false, &message("d").write_to_bytes().unwrap()));
+
+        // stops after error
+        assert_eq!(1, read_all(&body).len());
+        assert!(read_all(&body)[0].is_err());
+
+        let messages: Vec<StringValue> = GrpcFrameReader::new(&body[..])
+            .decompressor(Reverse)
+            .map(|m| m.unwrap())
+            .collect();
+        assert_eq!(vec![message("abc"), message("d")], messages);
+    }
+
+    #[test]
+    fn max_message_size() {
+        let body = frame(false, &message("abc").write_to_bytes().unwrap());
+        let mut reader: GrpcFrameReader<_, StringValue> =
+            GrpcFrameReader::new(&body[..]).max_message_size(4);
+        match reader.read_message() {
+            Err(ProtobufError::WireError(WireError::OverSizeLimit)) => {}
+            r => panic!("expecting size limit error: {:?}", r),
+        }
+        assert!(reader.next().is_none());
+    }
+
+    #[cfg(feature = "miniz_oxide")]
+    #[test]
+    fn gzip_and_deflate() {
+        let data = message(&"ab".repeat(100)).write_to_bytes().unwrap();
+
+        let mut gzip = vec![0x1f, 0x8b, 8, 0x08, 0, 0, 0, 0, 0, 0xff];
+        gzip.extend_from_slice(b"name\0");
+        gzip.extend(miniz_oxide::deflate::compress_to_vec(&data, 6));
+        // crc32 and size are not checked
+        gzip.extend_from_slice(&[0; 8]);
+        let zlib = miniz_oxide::deflate::compress_to_vec_zlib(&data, 6);
+        assert!(zlib.len() < data.len());
+
+        let m: StringValue = GrpcFrameReader::new(&frame(true, &gzip)[..])
+            .decompressor(Gzip)
+            .read_message()
+            .unwrap()
+            .unwrap();
+        assert_eq!(message(&"ab".repeat(100)), m);
+        let m: StringValue = GrpcFrameReader::new(&frame(true, &zlib)[..])
+            .decompressor(Deflate)
+            .read_message()
+            .unwrap()
+            .unwrap();
+        assert_eq!(message(&"ab".repeat(100)), m);
+
+        // decompressed size is limited too
+        let r: ProtobufResult<Option<StringValue>> =
+            GrpcFrameReader::new(&frame(true, &zlib)[..])
+                .decompressor(Deflate)
+                .max_message_size(100)
+                .read_message();
+        assert!(r.is_err());
+    }
+}
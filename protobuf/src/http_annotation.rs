@@ -0,0 +1,245 @@
+//! Read `(google.api.http)` method option.
+//!
+//! `google/api/annotations.proto` declares `http` extension of
+//! `google.protobuf.MethodOptions` used by HTTP/JSON gateways:
+//!
+//! ```proto
+//! rpc GetBook(GetBookRequest) returns (Book) {
+//!     option (google.api.http) = { get: "/v1/{name=books/*}" };
+//! }
+//! ```
+//!
+//! This crate does not include generated code for `google.api` package,
+//! so extension values stay in unknown fields of method options.
+//! This module decodes them into `HttpRule`:
+//!
+//! ```ignore
+//! for method in service.get_method() {
+//!     if let Some(rule) = method_http_rule(method)? {
+//!         let pattern = rule.pattern.unwrap();
+//!         println!("{} {} {}", method.get_name(), pattern.method(), pattern.path());
+//!     }
+//! }
+//! ```
+
+use core::Message;
+use descriptor::MethodDescriptorProto;
+use descriptor::MethodOptions;
+use error::ProtobufResult;
+use rt::unexpected_wire_type;
+use stream::wire_format::WireType;
+use stream::CodedInputStream;
+
+
+/// Field number of `google.api.http` extension of `MethodOptions`.
+pub const HTTP_FIELD_NUMBER: u32 = 72295728;
+
+/// HTTP method and path template of `HttpRule`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HttpPattern {
+    Get(String),
+    Put(String),
+    Post(String),
+    Delete(String),
+    Patch(String),
+    /// Method not listed above, e. g. `HEAD`.
+    Custom {
+        kind: String,
+        path: String,
+    },
+}
+
+impl HttpPattern {
+    /// HTTP method, e. g. `GET`.
+    pub fn method(&self) -> &str {
+        match *self {
+            HttpPattern::Get(..) => "GET",
+            HttpPattern::Put(..) => "PUT",
+            HttpPattern::Post(..) => "POST",
+            HttpPattern::Delete(..) => "DELETE",
+            HttpPattern::Patch(..) => "PATCH",
+            HttpPattern::Custom { ref kind, .. } => kind,
+        }
+    }
+
+    /// Path template, e. g. `/v1/{name=books/*}`.
+    pub fn path(&self) -> &str {
+        match *self {
+            HttpPattern::Get(ref path)
+            | HttpPattern::Put(ref path)
+            | HttpPattern::Post(ref path)
+            | HttpPattern::Delete(ref path)
+            | HttpPattern::Patch(ref path)
+            | HttpPattern::Custom { ref path, .. } => path,
+        }
+    }
+}
+
+/// Decoded `google.api.HttpRule` message.
+///
+/// Field names match the `.proto` definition.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HttpRule {
+    pub selector: String,
+    /// `pattern` oneof, `None` if not set.
+    pub pattern: Option<HttpPattern>,
+    pub body: String,
+    pub response_body: String,
+    pub additional_bindings: Vec<HttpRule>,
+}
+
+fn read_string(is: &mut CodedInputStream, wire_type: WireType) -> ProtobufResult<String> {
+    if wire_type != WireType::WireTypeLengthDelimited {
+        return Err(unexpected_wire_type(wire_type));
+    }
+    is.read_string()
+}
+
+fn read_custom_pattern(bytes: &[u8]) -> ProtobufResult<HttpPattern> {
+    let mut kind = String::new();
+    let mut path = String::new();
+    let mut is = CodedInputStream::from_bytes(bytes);
+    while !is.eof()? {
+        let (number, wire_type) = is.read_tag_unpack()?;
+        match number {
+            1 => kind = read_string(&mut is, wire_type)?,
+            2 => path = read_string(&mut is, wire_type)?,
+            _ => is.skip_field(wire_type)?,
+        }
+    }
+    Ok(HttpPattern::Custom {
+        kind: kind,
+        path: path,
+    })
+}
+
+impl HttpRule {
+    /// Parse serialized `HttpRule` message.
+    ///
+    /// Unknown fields are skipped.
+    pub fn parse_from_bytes(bytes: &[u8]) -> ProtobufResult<HttpRule> {
+        let mut r = HttpRule::default();
+        let mut is = CodedInputStream::from_bytes(bytes);
+        while !is.eof()? {
+            let (number, wire_type) = is.read_tag_unpack()?;
+            match number {
+                1 => r.selector = read_string(&mut is, wire_type)?,
+                2 => r.pattern = Some(HttpPattern::Get(read_string(&mut is, wire_type)?)),
+                3 => r.pattern = Some(HttpPattern::Put(read_string(&mut is, wire_type)?)),
+                4 => r.pattern = Some(HttpPattern::Post(read_string(&mut is, wire_type)?)),
+                5 => r.pattern = Some(HttpPattern::Delete(read_string(&mut is, wire_type)?)),
+                6 => r.pattern = Some(HttpPattern::Patch(read_string(&mut is, wire_type)?)),
+                7 => r.body = read_string(&mut is, wire_type)?,
+                8 => {
+                    if wire_type != WireType::WireTypeLengthDelimited {
+                        return Err(unexpected_wire_type(wire_type));
+                    }
+                    r.pattern = Some(read_custom_pattern(&is.read_bytes()?)?);
+                }
+                11 => {
+                    if wire_type != WireType::WireTypeLengthDelimited {
+                        return Err(unexpected_wire_type(wire_type));
+                    }
+                    let binding = HttpRule::parse_from_bytes(&is.read_bytes()?)?;
+                    r.additional_bindings.push(binding);
+                }
+                12 => r.response_body = read_string(&mut is, wire_type)?,
+                _ => is.skip_field(wire_type)?,
+            }
+        }
+        Ok(r)
+    }
+}
+
+/// `(google.api.http)` option, `None` if option is not set.
+///
+/// Fails if option value is not a valid `HttpRule`.
+pub fn http_rule(options: &MethodOptions) -> ProtobufResult<Option<HttpRule>> {
+    let values = match options.get_unknown_fields().get(HTTP_FIELD_NUMBER) {
+        Some(values) if !values.length_delimited.is_empty() => values,
+        _ => return Ok(None),
+    };
+    // several occurrences of message field are merged,
+    // which is the same as parsing their concatenation
+    let bytes = values.length_delimited.concat();
+    HttpRule::parse_from_bytes(&bytes).map(Some)
+}
+
+/// `(google.api.http)` option of the method.
+pub fn method_http_rule(method: &MethodDescriptorProto) -> ProtobufResult<Option<HttpRule>> {
+    http_rule(method.get_options())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use stream::CodedOutputStream;
+
+    fn message(fields: &[(u32, &[u8])]) -> Vec<u8> {
+        let mut r = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut r);
+            for &(number, bytes) in fields {
+                os.write_bytes(number, bytes).unwrap();
+            }
+            os.flush().unwrap();
+        }
+        r
+    }
+
+    fn method(rule: &[u8]) -> MethodDescriptorProto {
+        let mut options = MethodOptions::new();
+        options.mut_unknown_fields().add_length_delimited(HTTP_FIELD_NUMBER, rule.to_vec());
+        let mut method = MethodDescriptorProto::new();
+        method.set_options(options);
+        method
+    }
+
+    #[test]
+    fn rule() {
+        let custom = message(&[(1, b"HEAD"), (2, b"/v1/books")]);
+        let binding = message(&[(8, &custom)]);
+        let rule = message(&[
+            (2, b"/v1/{name=books/*}"),
+            (7, b"*"),
+            (11, &binding),
+            (12, b"book"),
+            (1000, b"unknown"),
+        ]);
+
+        let rule = method_http_rule(&method(&rule)).unwrap().unwrap();
+        let pattern = rule.pattern.as_ref().unwrap();
+        assert_eq!("GET", pattern.method());
+        assert_eq!("/v1/{name=books/*}", pattern.path());
+        assert_eq!("*", rule.body);
+        assert_eq!("book", rule.response_body);
+        assert_eq!(1, rule.additional_bindings.len());
+        let pattern = rule.additional_bindings[0].pattern.as_ref().unwrap();
+        assert_eq!("HEAD", pattern.method());
+        assert_eq!("/v1/books", pattern.path());
+    }
+
+    #[test]
+    fn merged() {
+        let mut method = method(&message(&[(4, b"/v1/books"), (7, b"book")]));
+        method.mut_options().mut_unknown_fields().add_length_delimited(
+            HTTP_FIELD_NUMBER,
+            message(&[(6, b"/v1/books/*")]),
+        );
+        let rule = method_http_rule(&method).unwrap().unwrap();
+        assert_eq!(Some(HttpPattern::Patch("/v1/books/*".to_owned())), rule.pattern);
+        assert_eq!("book", rule.body);
+    }
+
+    #[test]
+    fn not_set() {
+        assert_eq!(None, method_http_rule(&MethodDescriptorProto::new()).unwrap());
+    }
+
+    #[test]
+    fn malformed() {
+        // `get` field with varint wire type
+        assert!(method_http_rule(&method(&[0x10, 0x01])).is_err());
+    }
+}
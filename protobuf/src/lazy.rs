@@ -3,8 +3,12 @@
 
 use std::mem;
 use std::sync;
+use std::sync::OnceLock;
 
 /// Lasily initialized data.
+///
+/// Requires `static mut` and `unsafe` to use, so it is replaced by `LazyV2`
+/// in generated code. Kept for code generated by older versions of rust-protobuf.
 // Fields are public until `const` functions available in stable.
 pub struct Lazy<T> {
     #[doc(hidden)]
@@ -35,10 +39,28 @@ impl<T> Lazy<T> {
 /// Used to initialize `lock` field in `Lazy` struct.
 pub const ONCE_INIT: sync::Once = sync::ONCE_INIT;
 
+/// Lazily initialized data, safe to use in plain `static`.
+pub struct LazyV2<T> {
+    cell: OnceLock<T>,
+}
+
+impl<T> LazyV2<T> {
+    /// Uninitialized value.
+    pub const INIT: LazyV2<T> = LazyV2 { cell: OnceLock::new() };
+
+    /// Get lazy field value, initialize it with given function if not yet.
+    pub fn get<F>(&'static self, init: F) -> &'static T
+    where
+        F : FnOnce() -> T,
+    {
+        self.cell.get_or_init(init)
+    }
+}
+
 
 #[cfg(test)]
 mod test {
-    use super::{Lazy, ONCE_INIT};
+    use super::{Lazy, LazyV2, ONCE_INIT};
     use std::thread;
     use std::sync::{Arc, Barrier};
     use std::sync::atomic::{ATOMIC_ISIZE_INIT, AtomicIsize, Ordering};
@@ -95,4 +117,30 @@ mod test {
             assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 1);
         }
     }
+    #[test]
+    fn v2_many_threads_calling_get() {
+        const N_THREADS: usize = 32;
+
+        static LAZY: LazyV2<String> = LazyV2::INIT;
+        static CALL_COUNT: AtomicIsize = ATOMIC_ISIZE_INIT;
+
+        let barrier = Arc::new(Barrier::new(N_THREADS));
+        let threads: Vec<_> = (0..N_THREADS)
+            .map(|_| {
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    barrier.wait();
+                    LAZY.get(|| {
+                        CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+                        "Hello, world!".to_owned()
+                    })
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            assert_eq!("Hello, world!", thread.join().unwrap());
+        }
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 1);
+    }
 }
@@ -0,0 +1,345 @@
+//! Singular message field decoded on first access.
+
+use std::fmt;
+use std::sync::OnceLock;
+
+use clear::Clear;
+use core::Message;
+use error::ProtobufResult;
+use size_cache::SizeCache;
+use stream::CodedOutputStream;
+
+
+/// Like `SingularPtrField<M>`, but the message is stored serialized
+/// after parsing and decoded on first access.
+///
+/// Generated for message fields with `lazy_field` option. Useful for large
+/// nested messages which are often not inspected after parsing:
+/// parsing of outer message only copies bytes of such field, and
+/// serializing message with unmodified field writes these bytes back.
+///
+/// Serialized message is validated only when decoded. Decoding errors
+/// are ignored by `as_ref`: fields decoded before error are kept,
+/// use `try_as_ref` to get the error. Required fields of not yet decoded
+/// message are not checked by `is_initialized`.
+pub struct LazyMessageField<M> {
+    // serialized message, `None` if field is unset or message is mutated
+    bytes: Option<Vec<u8>>,
+    // decoded message, initialized from `bytes` on first access
+    message: OnceLock<Box<M>>,
+}
+
+impl<M> LazyMessageField<M> {
+    /// Field with given decoded message.
+    pub fn some(value: M) -> LazyMessageField<M> {
+        LazyMessageField {
+            bytes: None,
+            message: OnceLock::from(Box::new(value)),
+        }
+    }
+
+    /// Unset field.
+    pub fn none() -> LazyMessageField<M> {
+        LazyMessageField {
+            bytes: None,
+            message: OnceLock::new(),
+        }
+    }
+
+    /// Field with serialized message, which is decoded on first access.
+    pub fn from_bytes(bytes: Vec<u8>) -> LazyMessageField<M> {
+        LazyMessageField {
+            bytes: Some(bytes),
+            message: OnceLock::new(),
+        }
+    }
+
+    /// True iff field is set.
+    pub fn is_some(&self) -> bool {
+        self.bytes.is_some() || self.message.get().is_some()
+    }
+
+    /// True iff field is not set.
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
+    /// True if field is set and message is not decoded yet.
+    pub fn is_lazy(&self) -> bool {
+        self.bytes.is_some() && self.message.get().is_none()
+    }
+
+    /// Unset the field.
+    pub fn clear(&mut self) {
+        self.bytes = None;
+        self.message.take();
+    }
+}
+
+impl<M : Message> LazyMessageField<M> {
+    fn decode(bytes: &[u8]) -> ProtobufResult<M> {
+        let mut message = M::new();
+        message.merge_from_bytes(bytes)?;
+        Ok(message)
+    }
+
+    /// Decoded message, decoding it if necessary.
+    pub fn as_ref(&self) -> Option<&M> {
+        match self.bytes {
+            Some(ref bytes) => Some(self.message.get_or_init(|| {
+                let mut message = M::new();
+                // keep fields decoded before error, as documented
+                let _ = message.merge_from_bytes(bytes);
+                Box::new(message)
+            })),
+            None => self.message.get().map(|m| &**m),
+        }
+    }
+
+    /// Decoded message, or decoding error if the message is not decoded yet.
+    pub fn try_as_ref(&self) -> ProtobufResult<Option<&M>> {
+        if let Some(m) = self.message.get() {
+            return Ok(Some(m));
+        }
+        match self.bytes {
+            Some(ref bytes) => {
+                let message = LazyMessageField::decode(bytes)?;
+                Ok(Some(self.message.get_or_init(|| Box::new(message))))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Mutable decoded message. Serialized bytes are discarded,
+    /// so the message is serialized again when outer message is written.
+    pub fn as_mut(&mut self) -> Option<&mut M> {
+        if self.as_ref().is_some() {
+            self.bytes = None;
+        }
+        self.message.get_mut().map(|m| &mut **m)
+    }
+
+    /// Take decoded message, leaving the field unset.
+    pub fn take(&mut self) -> Option<M> {
+        self.as_ref();
+        self.bytes = None;
+        self.message.take().map(|m| *m)
+    }
+
+    /// Set the field to default message if it is unset, and return mutable message.
+    pub fn set_default(&mut self) -> &mut M {
+        if self.is_none() {
+            self.message = OnceLock::from(Box::new(M::new()));
+        }
+        self.as_mut().unwrap()
+    }
+
+    /// Merge serialized message into the field.
+    ///
+    /// If the message is not decoded, bytes are appended to serialized
+    /// message, which is equivalent to merging when decoded.
+    pub fn merge_from_bytes(&mut self, bytes: &[u8]) -> ProtobufResult<()> {
+        if let Some(ref mut serialized) = self.bytes {
+            serialized.extend_from_slice(bytes);
+            self.message.take();
+            return Ok(());
+        }
+        match self.message.get_mut() {
+            Some(message) => message.merge_from_bytes(bytes),
+            None => {
+                self.bytes = Some(bytes.to_owned());
+                Ok(())
+            }
+        }
+    }
+
+    /// Merge other field into this field like `Message::merge_from_message`.
+    ///
+    /// Message of other field is not decoded if it is not decoded yet.
+    /// Decoding errors are ignored as in `as_ref`.
+    pub fn merge_from_field(&mut self, other: &LazyMessageField<M>) {
+        if other.is_lazy() {
+            let _ = self.merge_from_bytes(other.bytes.as_ref().unwrap());
+        } else if let Some(m) = other.message.get() {
+            self.set_default().merge_from_message(m);
+        }
+    }
+
+    /// True if the message is not decoded yet, or decoded message is initialized.
+    pub fn is_initialized(&self) -> bool {
+        match self.message.get() {
+            Some(message) if self.bytes.is_none() => message.is_initialized(),
+            _ => true,
+        }
+    }
+
+    /// Serialized size of message without length delimiter.
+    pub fn compute_size(&self) -> u32 {
+        match self.bytes {
+            Some(ref bytes) => bytes.len() as u32,
+            None => self.message.get().map_or(0, |m| m.compute_size()),
+        }
+    }
+
+    /// Upper bound of serialized size of message without length delimiter.
+    pub fn size_hint_upper_bound(&self) -> u64 {
+        match self.bytes {
+            Some(ref bytes) => bytes.len() as u64,
+            None => self.message.get().map_or(0, |m| m.size_hint_upper_bound()),
+        }
+    }
+
+    /// Size computed by last `compute_size` call.
+    pub fn get_cached_size(&self) -> u32 {
+        match self.bytes {
+            Some(ref bytes) => bytes.len() as u32,
+            None => self.message.get().map_or(0, |m| m.get_cached_size()),
+        }
+    }
+
+    /// Write message without length delimiter, sizes must be computed.
+    pub fn write_to_with_cached_sizes(&self, os: &mut CodedOutputStream) -> ProtobufResult<()> {
+        match self.bytes {
+            Some(ref bytes) => os.write_raw_bytes(bytes),
+            None => match self.message.get() {
+                Some(m) => m.write_to_with_cached_sizes(os),
+                None => Ok(()),
+            },
+        }
+    }
+
+    /// Serialized size of message storing sizes in `sizes`.
+    pub fn compute_size_with_cache(&self, sizes: &mut SizeCache) -> u32 {
+        match self.bytes {
+            Some(ref bytes) => {
+                let slot = sizes.reserve_slot();
+                sizes.set_slot(slot, bytes.len() as u32);
+                bytes.len() as u32
+            }
+            None => self.message.get().map_or(0, |m| m.compute_size_with_cache(sizes)),
+        }
+    }
+
+    /// Write message without length delimiter, sizes must be stored in `sizes`.
+    pub fn write_to_with_size_cache(
+        &self,
+        os: &mut CodedOutputStream,
+        sizes: &mut SizeCache,
+    ) -> ProtobufResult<()> {
+        match self.bytes {
+            Some(ref bytes) => os.write_raw_bytes(bytes),
+            None => match self.message.get() {
+                Some(m) => m.write_to_with_size_cache(os, sizes),
+                None => Ok(()),
+            },
+        }
+    }
+}
+
+impl<M> Default for LazyMessageField<M> {
+    fn default() -> LazyMessageField<M> {
+        LazyMessageField::none()
+    }
+}
+
+impl<M : Clone> Clone for LazyMessageField<M> {
+    fn clone(&self) -> LazyMessageField<M> {
+        LazyMessageField {
+            bytes: self.bytes.clone(),
+            message: self.message.clone(),
+        }
+    }
+}
+
+impl<M> Clear for LazyMessageField<M> {
+    fn clear(&mut self) {
+        LazyMessageField::clear(self);
+    }
+}
+
+impl<M : Message + PartialEq> PartialEq for LazyMessageField<M> {
+    fn eq(&self, other: &LazyMessageField<M>) -> bool {
+        match (&self.bytes, &other.bytes) {
+            (&Some(ref a), &Some(ref b)) if a == b => true,
+            _ => self.as_ref() == other.as_ref(),
+        }
+    }
+}
+
+impl<M : Message + fmt::Debug> fmt::Debug for LazyMessageField<M> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FileOptions;
+
+    fn options_bytes() -> Vec<u8> {
+        let mut options = FileOptions::new();
+        options.set_java_package("a".to_owned());
+        options.write_to_bytes().unwrap()
+    }
+
+    #[test]
+    fn decode_on_access() {
+        let field = LazyMessageField::<FileOptions>::from_bytes(options_bytes());
+        assert!(field.is_some());
+        assert!(field.is_lazy());
+        assert_eq!("a", field.as_ref().unwrap().get_java_package());
+        assert!(!field.is_lazy());
+    }
+
+    #[test]
+    fn write_unmodified() {
+        let bytes = options_bytes();
+        let field = LazyMessageField::<FileOptions>::from_bytes(bytes.clone());
+        assert_eq!(bytes.len() as u32, field.compute_size());
+
+        let mut written = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut written);
+            field.write_to_with_cached_sizes(&mut os).unwrap();
+            os.flush().unwrap();
+        }
+        assert_eq!(bytes, written);
+    }
+
+    #[test]
+    fn mutate() {
+        let mut field = LazyMessageField::<FileOptions>::from_bytes(options_bytes());
+        field.as_mut().unwrap().set_java_outer_classname("A".to_owned());
+        assert!(!field.is_lazy());
+
+        let mut expected = FileOptions::new();
+        expected.set_java_package("a".to_owned());
+        expected.set_java_outer_classname("A".to_owned());
+        assert_eq!(expected.compute_size(), field.compute_size());
+        assert_eq!(LazyMessageField::some(expected), field);
+    }
+
+    #[test]
+    fn merge() {
+        let mut other = FileOptions::new();
+        other.set_java_outer_classname("A".to_owned());
+        let other_bytes = other.write_to_bytes().unwrap();
+
+        let mut field = LazyMessageField::<FileOptions>::none();
+        field.merge_from_bytes(&options_bytes()).unwrap();
+        field.merge_from_bytes(&other_bytes).unwrap();
+        assert!(field.is_lazy());
+        assert_eq!("a", field.as_ref().unwrap().get_java_package());
+        assert_eq!("A", field.as_ref().unwrap().get_java_outer_classname());
+    }
+
+    #[test]
+    fn decode_error() {
+        // truncated string
+        let field = LazyMessageField::<FileOptions>::from_bytes(vec![0x0a, 0x05, 0x61]);
+        assert!(field.try_as_ref().is_err());
+        assert!(field.as_ref().is_some());
+    }
+}
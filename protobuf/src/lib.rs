@@ -5,9 +5,24 @@
 
 #[cfg(feature = "bytes")]
 extern crate bytes;
+#[cfg(feature = "miniz_oxide")]
+extern crate miniz_oxide;
+#[cfg(feature = "serde_json")]
+extern crate serde_json;
+#[cfg(feature = "smallvec")]
+extern crate smallvec;
+/// `arbitrary` crate used by `Arbitrary` implementations generated
+/// with `generate_arbitrary` codegen option.
+#[cfg(feature = "arbitrary")]
+pub extern crate arbitrary;
+/// `proptest` crate used by strategies generated
+/// with `generate_proptest` codegen option.
+#[cfg(feature = "proptest")]
+pub extern crate proptest;
 
 pub use unknown::UnknownFields;
 pub use unknown::UnknownFieldsIter;
+pub use unknown::UnknownFieldsValuesIter;
 pub use unknown::UnknownValue;
 pub use unknown::UnknownValueRef;
 pub use unknown::UnknownValues;
@@ -15,27 +30,54 @@ pub use unknown::UnknownValuesIter;
 pub use repeated::RepeatedField;
 pub use singular::SingularField;
 pub use singular::SingularPtrField;
+pub use lazy_message::LazyMessageField;
 pub use clear::Clear;
+pub use convert::ProtobufConvert;
 pub use core::Message;
+pub use core::MessageFull;
 pub use core::ProtobufEnum;
+pub use core::ProtobufEnumFull;
 pub use core::parse_from_bytes;
 pub use core::parse_from_reader;
+pub use core::parse_partial_from_bytes;
 #[cfg(feature = "bytes")]
 pub use core::parse_from_carllerche_bytes;
 pub use core::parse_length_delimited_from;
 pub use core::parse_length_delimited_from_reader;
 pub use core::parse_length_delimited_from_bytes;
+pub use parallel::parse_batch_parallel;
+pub use parallel::parse_batch_parallel_with_threads;
+pub use parallel::BatchParseError;
+pub use parse_options::ParseOptions;
+pub use concat::ConcatBuilder;
+pub use arena::Arena;
+pub use arena::parse_from_bytes_in;
+pub use message_pool::MessagePool;
 pub use stream::CodedInputStream;
 pub use stream::CodedOutputStream;
+pub use stream::Utf8Policy;
+pub use stream::ParseStats;
 pub use stream::wire_format;
 pub use error::ProtobufResult;
 pub use error::ProtobufError;
+pub use error::ConvertError;
 pub use cached_size::CachedSize;
+pub use size_cache::SizeCache;
 #[cfg(feature = "bytes")]
 pub use chars::Chars;
+/// Storage of repeated fields with `smallvec_for_repeated` codegen option.
+#[cfg(feature = "smallvec")]
+pub use smallvec::SmallVec;
 
 // generated
-pub mod descriptor;
+#[path = "descriptor.rs"]
+mod descriptor_pb;
+/// Generated `descriptor.proto` messages and utilities operating on them.
+pub mod descriptor {
+    pub use descriptor_pb::*;
+
+    pub mod compat;
+}
 pub mod plugin;
 pub mod rustproto;
 
@@ -45,7 +87,9 @@ pub mod lazy;
 pub mod compiler_plugin;
 mod repeated;
 mod singular;
+mod lazy_message;
 mod clear;
+mod convert;
 pub mod reflect;
 pub mod text_format;
 pub mod stream;
@@ -53,6 +97,22 @@ pub mod error;
 pub mod types;
 pub mod well_known_types;
 pub mod ext;
+pub mod v3_compat;
+pub mod gen_support;
+pub mod patch;
+pub mod grpc_frame;
+pub mod wire_editor;
+pub mod descriptor_builder;
+pub mod descriptor_pool;
+pub mod cmp;
+pub mod canonical;
+pub mod field_order;
+pub mod wrapper_option;
+pub mod time_format;
+pub mod http_annotation;
+pub mod debug;
+#[cfg(feature = "serde_json")]
+pub mod struct_json;
 
 // used by test
 #[cfg(test)]
@@ -69,7 +129,13 @@ mod strx;
 #[doc(hidden)] // used by codegen
 pub mod rust;
 mod cached_size;
+mod size_cache;
 mod varint;
+mod parallel;
+mod parse_options;
+mod concat;
+mod arena;
+mod message_pool;
 #[cfg(feature = "bytes")]
 mod chars;
 
@@ -103,4 +169,5 @@ mod protobuf {
     pub use singular::SingularPtrField;
     pub use clear::Clear;
     pub use cached_size::CachedSize;
+    pub use size_cache::SizeCache;
 }
@@ -0,0 +1,96 @@
+//! Recycling messages in decode loops.
+//!
+//! `MessagePool` hands out empty messages and takes them back when they
+//! are no longer needed. Returned messages are cleared with `Clear`, which
+//! keeps capacity of `string`, `bytes`, repeated and nested message fields,
+//! so once the pool is warmed up decoding similar messages does not allocate:
+//!
+//! ```ignore
+//! let mut pool = MessagePool::<Event>::new();
+//! for bytes in frames {
+//!     let m = pool.parse_from_bytes(bytes)?;
+//!     process(&m);
+//!     pool.put(m);
+//! }
+//! ```
+//!
+//! Unlike `Arena` messages are owned by the caller until they are returned,
+//! so they can be sent to other threads or kept for arbitrary time.
+
+use core::Message;
+use error::ProtobufResult;
+use stream::CodedInputStream;
+
+
+/// Pool of cleared messages of one type.
+#[derive(Debug)]
+pub struct MessagePool<M> {
+    free: Vec<M>,
+    max_free: usize,
+}
+
+impl<M : Message> Default for MessagePool<M> {
+    fn default() -> MessagePool<M> {
+        MessagePool::new()
+    }
+}
+
+impl<M : Message> MessagePool<M> {
+    /// Create an empty pool keeping any number of returned messages.
+    pub fn new() -> MessagePool<M> {
+        MessagePool::with_max_free(usize::max_value())
+    }
+
+    /// Create an empty pool keeping at most `max_free` returned messages,
+    /// messages returned to full pool are dropped.
+    pub fn with_max_free(max_free: usize) -> MessagePool<M> {
+        MessagePool {
+            free: Vec::new(),
+            max_free: max_free,
+        }
+    }
+
+    /// Get an empty message, reusing a returned one if available.
+    pub fn get(&mut self) -> M {
+        self.free.pop().unwrap_or_else(M::new)
+    }
+
+    /// Return a message to the pool.
+    ///
+    /// Message is cleared preserving its allocated memory.
+    pub fn put(&mut self, mut m: M) {
+        if self.free.len() < self.max_free {
+            m.clear();
+            self.free.push(m);
+        }
+    }
+
+    /// Number of messages available for reuse.
+    pub fn free(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Drop all messages available for reuse.
+    pub fn shrink(&mut self) {
+        self.free = Vec::new();
+    }
+
+    /// Parse message from bytes into a pooled message.
+    ///
+    /// Like `parse_from_bytes`, fails if required fields are not set.
+    /// On error the message is returned to the pool.
+    pub fn parse_from_bytes(&mut self, bytes: &[u8]) -> ProtobufResult<M> {
+        let mut m = self.get();
+        let r = {
+            let mut is = CodedInputStream::from_bytes(bytes);
+            m.merge_from(&mut is).map_err(|e| is.locate_error(e))
+        };
+        match r.and_then(|()| m.check_initialized()) {
+            Ok(()) => Ok(m),
+            Err(e) => {
+                self.put(m);
+                Err(e)
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::slice;
 use std::mem;
 
+#[cfg(feature = "bytes")]
+use bytes::BytesMut;
+
 /// Slice from `vec[vec.len()..vec.capacity()]`
 pub unsafe fn remaining_capacity_as_slice_mut<A>(vec: &mut Vec<A>) -> &mut [A] {
     slice::from_raw_parts_mut(
@@ -9,6 +12,13 @@ pub unsafe fn remaining_capacity_as_slice_mut<A>(vec: &mut Vec<A>) -> &mut [A] {
     )
 }
 
+/// Slice from `bytes[bytes.len()..bytes.capacity()]`
+#[cfg(feature = "bytes")]
+pub unsafe fn bytes_mut_remaining_capacity_as_slice_mut(bytes: &mut BytesMut) -> &mut [u8] {
+    let len = bytes.len();
+    slice::from_raw_parts_mut(bytes.as_mut_ptr().offset(len as isize), bytes.capacity() - len)
+}
+
 pub unsafe fn remove_lifetime_mut<A : ?Sized>(a: &mut A) -> &'static mut A {
     mem::transmute(a)
 }
@@ -0,0 +1,140 @@
+//! Parse many independent messages using several threads.
+
+use std::error::Error;
+use std::fmt;
+use std::thread;
+
+use core::Message;
+use core::parse_from_bytes;
+use error::ProtobufError;
+
+
+/// Errors of `parse_batch_parallel`: failed frames with their indices.
+#[derive(Debug)]
+pub struct BatchParseError {
+    /// Index of frame in input slice and error parsing that frame,
+    /// sorted by index.
+    pub errors: Vec<(usize, ProtobufError)>,
+}
+
+impl BatchParseError {
+    /// Indices of frames failed to parse.
+    pub fn failed_indices(&self) -> Vec<usize> {
+        self.errors.iter().map(|&(i, _)| i).collect()
+    }
+}
+
+impl fmt::Display for BatchParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to parse {} frame(s)", self.errors.len())?;
+        for &(index, ref e) in &self.errors {
+            write!(f, "; frame {}: {}", index, e)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for BatchParseError {
+    fn description(&self) -> &str {
+        "failed to parse some frames of batch"
+    }
+}
+
+fn default_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// Parse each frame as a separate message using a thread per available CPU.
+///
+/// Frames are message bytes without length prefix. Messages are returned
+/// in the same order as frames. If any frame cannot be parsed, all frames
+/// are still attempted, and the error lists every failed frame index.
+pub fn parse_batch_parallel<M : Message>(frames: &[&[u8]]) -> Result<Vec<M>, BatchParseError> {
+    parse_batch_parallel_with_threads(frames, default_threads())
+}
+
+/// Same as `parse_batch_parallel`, but use at most `threads` threads.
+pub fn parse_batch_parallel_with_threads<M : Message>(
+    frames: &[&[u8]],
+    threads: usize,
+) -> Result<Vec<M>, BatchParseError> {
+    let threads = threads.max(1).min(frames.len());
+
+    let results: Vec<Result<M, ProtobufError>> = if threads <= 1 {
+        frames.iter().map(|f| parse_from_bytes::<M>(f)).collect()
+    } else {
+        let chunk_size = (frames.len() + threads - 1) / threads;
+        thread::scope(|s| {
+            let handles: Vec<_> = frames
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    s.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|f| parse_from_bytes::<M>(f))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|h| h.join().expect("parse thread panicked"))
+                .collect()
+        })
+    };
+
+    let mut messages = Vec::with_capacity(frames.len());
+    let mut errors = Vec::new();
+    for (index, r) in results.into_iter().enumerate() {
+        match r {
+            Ok(m) => messages.push(m),
+            Err(e) => errors.push((index, e)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(messages)
+    } else {
+        Err(BatchParseError { errors: errors })
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use well_known_types::Duration;
+
+    fn duration(seconds: i64) -> Vec<u8> {
+        let mut d = Duration::new();
+        d.set_seconds(seconds);
+        d.write_to_bytes().unwrap()
+    }
+
+    #[test]
+    fn preserves_order() {
+        let bytes: Vec<Vec<u8>> = (0..100).map(duration).collect();
+        let frames: Vec<&[u8]> = bytes.iter().map(|b| &b[..]).collect();
+        for &threads in &[1, 3, 8, 200] {
+            let parsed: Vec<Duration> = parse_batch_parallel_with_threads(&frames, threads).unwrap();
+            let seconds: Vec<i64> = parsed.iter().map(|d| d.get_seconds()).collect();
+            assert_eq!((0..100).collect::<Vec<i64>>(), seconds);
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let parsed: Vec<Duration> = parse_batch_parallel(&[]).unwrap();
+        assert!(parsed.is_empty());
+    }
+
+    #[test]
+    fn reports_failed_indices() {
+        let good = duration(1);
+        // truncated varint
+        let bad: &[u8] = &[0x08, 0x80];
+        let frames: Vec<&[u8]> = vec![&good, bad, &good, &good, bad];
+        let err = parse_batch_parallel_with_threads::<Duration>(&frames, 2).unwrap_err();
+        assert_eq!(vec![1, 4], err.failed_indices());
+    }
+}
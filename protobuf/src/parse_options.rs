@@ -0,0 +1,292 @@
+//! Options of message parsing.
+
+use std::io::Read;
+
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+
+use core::Message;
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
+use stream::CodedInputStream;
+use stream::Utf8Policy;
+use stream::WithCodedInputStream;
+use stream::DEFAULT_RECURSION_LIMIT;
+
+
+/// Options of message parsing.
+///
+/// Options are set with builder-style methods:
+///
+/// ```
+/// # use protobuf::ParseOptions;
+/// let options = ParseOptions::new()
+///     .max_size(1 << 20)
+///     .recursion_limit(20);
+/// ```
+///
+/// and then passed to `Message::parse` or to `parse_from_*` functions
+/// of this struct.
+#[derive(Clone, Debug)]
+pub struct ParseOptions {
+    recursion_limit: u32,
+    max_size: Option<u64>,
+    check_initialized: bool,
+    utf8_policy: Utf8Policy,
+    canonical: bool,
+    locate_errors: bool,
+    #[cfg(feature = "bytes")]
+    zero_copy: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions::new()
+    }
+}
+
+impl ParseOptions {
+    /// Options with the same behavior as `parse_from_*` functions.
+    pub fn new() -> ParseOptions {
+        ParseOptions {
+            recursion_limit: DEFAULT_RECURSION_LIMIT,
+            max_size: None,
+            check_initialized: true,
+            utf8_policy: Utf8Policy::Error,
+            canonical: false,
+            locate_errors: false,
+            #[cfg(feature = "bytes")]
+            zero_copy: true,
+        }
+    }
+
+    /// Max depth of nested messages, `100` by default.
+    pub fn recursion_limit(mut self, limit: u32) -> ParseOptions {
+        self.recursion_limit = limit;
+        self
+    }
+
+    /// Max size of serialized message in bytes, unlimited by default.
+    ///
+    /// Larger input is rejected with `WireError::OverSizeLimit`.
+    pub fn max_size(mut self, max_size: u64) -> ParseOptions {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Fail if required fields are not set, `true` by default.
+    pub fn check_initialized(mut self, check_initialized: bool) -> ParseOptions {
+        self.check_initialized = check_initialized;
+        self
+    }
+
+    /// Replace invalid UTF-8 sequences in strings with `U+FFFD`
+    /// instead of failing, `false` by default.
+    pub fn lossy_utf8(mut self, lossy_utf8: bool) -> ParseOptions {
+        self.utf8_policy = if lossy_utf8 {
+            Utf8Policy::Lossy
+        } else {
+            Utf8Policy::Error
+        };
+        self
+    }
+
+    /// Handling of invalid UTF-8 in strings, `Utf8Policy::Error` by default.
+    pub fn utf8_policy(mut self, policy: Utf8Policy) -> ParseOptions {
+        self.utf8_policy = policy;
+        self
+    }
+
+    /// Reject input which is not encoded canonically, `false` by default.
+    ///
+    /// With this option varints must have minimal length and bools
+    /// must be `0` or `1`, so there are fewer valid encodings of the same message,
+    /// which is required e. g. when hash of serialized message identifies it.
+    /// Other input is rejected with `WireError::NonCanonical`.
+    ///
+    /// Note that order of fields and duplicate fields are not checked.
+    pub fn canonical(mut self, canonical: bool) -> ParseOptions {
+        self.canonical = canonical;
+        self
+    }
+
+    /// Wrap wire errors in `WireError::Located` with offset, field number
+    /// and path of nested message fields where error occurred, `false` by default.
+    ///
+    /// Use `ProtobufError::wire_error` and `ProtobufError::location`
+    /// to inspect such errors.
+    pub fn locate_errors(mut self, locate_errors: bool) -> ParseOptions {
+        self.locate_errors = locate_errors;
+        self
+    }
+
+    /// When parsing from `Bytes`, let `Bytes` and `Chars` fields of the message
+    /// share memory with the input, `true` by default.
+    ///
+    /// When disabled, input is copied, so the message does not keep
+    /// the whole input buffer alive.
+    #[cfg(feature = "bytes")]
+    pub fn zero_copy(mut self, zero_copy: bool) -> ParseOptions {
+        self.zero_copy = zero_copy;
+        self
+    }
+
+    fn check_size(&self, size: u64) -> ProtobufResult<()> {
+        match self.max_size {
+            Some(max_size) if size > max_size => {
+                Err(ProtobufError::WireError(WireError::OverSizeLimit))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn parse_from<M : Message>(&self, is: &mut CodedInputStream) -> ProtobufResult<M> {
+        is.set_recursion_limit(self.recursion_limit);
+        is.set_utf8_policy(self.utf8_policy);
+        is.set_canonical(self.canonical);
+        is.set_locate_errors(self.locate_errors);
+        let mut r: M = Message::new();
+        r.merge_from(is).map_err(|e| is.locate_error(e))?;
+        if self.check_initialized {
+            r.check_initialized()?;
+        }
+        Ok(r)
+    }
+
+    /// Parse message from byte array.
+    pub fn parse_from_bytes<M : Message>(&self, bytes: &[u8]) -> ProtobufResult<M> {
+        self.check_size(bytes.len() as u64)?;
+        bytes.with_coded_input_stream(|is| self.parse_from::<M>(is))
+    }
+
+    /// Parse message from reader.
+    /// Parse stops on EOF or when error encountered.
+    pub fn parse_from_reader<M : Message>(&self, reader: &mut Read) -> ProtobufResult<M> {
+        match self.max_size {
+            Some(max_size) => {
+                // read one more byte to detect oversized input
+                let mut bytes = Vec::new();
+                reader.take(max_size.saturating_add(1)).read_to_end(&mut bytes)?;
+                self.parse_from_bytes(&bytes)
+            }
+            None => reader.with_coded_input_stream(|is| self.parse_from::<M>(is)),
+        }
+    }
+
+    /// Parse message from `Bytes` object.
+    /// Resulting message may share references to the passed bytes object
+    /// unless `zero_copy` is disabled.
+    #[cfg(feature = "bytes")]
+    pub fn parse_from_carllerche_bytes<M : Message>(&self, bytes: &Bytes) -> ProtobufResult<M> {
+        self.check_size(bytes.len() as u64)?;
+        if self.zero_copy {
+            WithCodedInputStream::with_coded_input_stream(bytes, |is| self.parse_from::<M>(is))
+        } else {
+            // fields read from slice are copied one by one
+            (&bytes[..]).with_coded_input_stream(|is| self.parse_from::<M>(is))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::DescriptorProto;
+    use descriptor::FileDescriptorProto;
+    use descriptor::UninterpretedOption_NamePart;
+
+    fn nested(depth: usize) -> Vec<u8> {
+        let mut m = DescriptorProto::new();
+        for _ in 0..depth {
+            let mut outer = DescriptorProto::new();
+            outer.mut_nested_type().push(m);
+            m = outer;
+        }
+        m.write_to_bytes().unwrap()
+    }
+
+    #[test]
+    fn default_options() {
+        let bytes = nested(10);
+        let m: DescriptorProto = ParseOptions::new().parse_from_bytes(&bytes).unwrap();
+        assert_eq!(bytes, m.write_to_bytes().unwrap());
+    }
+
+    #[test]
+    fn recursion_limit() {
+        let bytes = nested(10);
+        let options = ParseOptions::new().recursion_limit(5);
+        assert!(options.parse_from_bytes::<DescriptorProto>(&bytes).is_err());
+    }
+
+    #[test]
+    fn max_size() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        let bytes = file.write_to_bytes().unwrap();
+
+        let options = ParseOptions::new().max_size(bytes.len() as u64);
+        assert_eq!(file, options.parse_from_bytes(&bytes).unwrap());
+        assert_eq!(file, options.parse_from_reader(&mut &bytes[..]).unwrap());
+
+        let options = ParseOptions::new().max_size(bytes.len() as u64 - 1);
+        for r in vec![
+            options.parse_from_bytes::<FileDescriptorProto>(&bytes),
+            options.parse_from_reader::<FileDescriptorProto>(&mut &bytes[..]),
+        ] {
+            match r {
+                Err(ProtobufError::WireError(WireError::OverSizeLimit)) => {}
+                r => panic!("wrong result: {:?}", r),
+            }
+        }
+    }
+
+    #[test]
+    fn check_initialized() {
+        // required fields are not set
+        let bytes: &[u8] = &[];
+        assert!(ParseOptions::new().parse_from_bytes::<UninterpretedOption_NamePart>(bytes).is_err());
+        let options = ParseOptions::new().check_initialized(false);
+        let m: UninterpretedOption_NamePart = options.parse_from_bytes(bytes).unwrap();
+        assert!(!m.is_initialized());
+    }
+
+    #[test]
+    fn lossy_utf8() {
+        // name = "a\xffb"
+        let bytes: &[u8] = &[0x0a, 0x03, 0x61, 0xff, 0x62];
+        assert!(ParseOptions::new().parse_from_bytes::<FileDescriptorProto>(bytes).is_err());
+        let options = ParseOptions::new().lossy_utf8(true);
+        let m: FileDescriptorProto = options.parse_from_bytes(bytes).unwrap();
+        assert_eq!("a\u{fffd}b", m.get_name());
+    }
+
+    #[test]
+    fn utf8_policy() {
+        let bytes: &[u8] = &[0x0a, 0x03, 0x61, 0xff, 0x62];
+        let options = ParseOptions::new().utf8_policy(Utf8Policy::Error);
+        assert!(options.parse_from_bytes::<FileDescriptorProto>(bytes).is_err());
+        // `String` fields cannot hold invalid UTF-8
+        let options = ParseOptions::new().utf8_policy(Utf8Policy::Preserve);
+        let m: FileDescriptorProto = options.parse_from_bytes(bytes).unwrap();
+        assert_eq!("a\u{fffd}b", m.get_name());
+    }
+
+    #[test]
+    fn canonical() {
+        // name = "abc" with length encoded in two bytes
+        let bytes: &[u8] = &[0x0a, 0x83, 0x00, 0x61, 0x62, 0x63];
+        let m: FileDescriptorProto = ParseOptions::new().parse_from_bytes(bytes).unwrap();
+        assert_eq!("abc", m.get_name());
+        let options = ParseOptions::new().canonical(true);
+        let e = options.parse_from_bytes::<FileDescriptorProto>(bytes).unwrap_err();
+        match e.wire_error() {
+            Some(&WireError::NonCanonical) => {}
+            _ => panic!("wrong error: {:?}", e),
+        }
+        let bytes = m.write_to_bytes().unwrap();
+        assert_eq!(m, options.parse_from_bytes(&bytes).unwrap());
+    }
+}
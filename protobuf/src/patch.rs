@@ -0,0 +1,280 @@
+//! Patches: serialized fields of a message changed relative to a base message.
+//!
+//! Patch is a regular serialized message, so it is applied with merge:
+//! singular fields are overwritten, nested messages are merged,
+//! repeated fields are appended and map entries are inserted.
+//! Changes which cannot be expressed this way (e. g. cleared field,
+//! removed repeated element or map key) are reported by `diff_serialize`,
+//! and full message should be sent instead.
+//!
+//! ```ignore
+//! let patch = protobuf::patch::diff_serialize(&old, &new)?;
+//! // on the other side
+//! protobuf::patch::apply_patch(&mut replica, &patch)?;
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use core::Message;
+use descriptor::FieldDescriptorProto;
+use error::ProtobufError;
+use error::ProtobufResult;
+use reflect::FieldDescriptor;
+use reflect::MessageDescriptor;
+use reflect::ProtobufValueRef;
+use reflect::ReflectFieldRef;
+use reflect::descriptor_dyn;
+use reflect::record::map_entry;
+use reflect::record::write_value;
+use reflect::record::RecordError;
+use stream::CodedOutputStream;
+use stream::wire_format;
+
+
+/// Error returned by `diff_serialize`.
+#[derive(Debug)]
+pub enum PatchError {
+    /// Change of the field with given path (e. g. `inner.name`)
+    /// cannot be expressed as merge.
+    NotRepresentable(String),
+    /// Serialization error
+    Protobuf(ProtobufError),
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PatchError::NotRepresentable(ref path) => {
+                write!(f, "change cannot be expressed as merge: {}", path)
+            }
+            PatchError::Protobuf(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for PatchError {
+    fn description(&self) -> &str {
+        match *self {
+            PatchError::NotRepresentable(..) => "change cannot be expressed as merge",
+            PatchError::Protobuf(ref e) => e.description(),
+        }
+    }
+}
+
+impl From<ProtobufError> for PatchError {
+    fn from(e: ProtobufError) -> PatchError {
+        PatchError::Protobuf(e)
+    }
+}
+
+/// Serialize fields of `updated` which differ from `base`,
+/// so merging result into `base` makes it equal to `updated`.
+///
+/// Both messages must be of the same type.
+/// Values are compared by their serialized form, so nested messages
+/// with map fields may be sent whole even if they are unchanged.
+/// Changed message fields of oneofs are sent whole too, because generated
+/// code replaces such field instead of merging into it.
+///
+/// # Panics
+///
+/// If message is generated without reflection (e. g. for `LITE_RUNTIME`).
+pub fn diff_serialize(base: &Message, updated: &Message) -> Result<Vec<u8>, PatchError> {
+    assert_eq!(
+        descriptor_dyn(base).full_name(),
+        descriptor_dyn(updated).full_name(),
+        "messages of different types"
+    );
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        write_diff(&mut os, base, updated, "")?;
+        os.flush()?;
+    }
+    Ok(bytes)
+}
+
+/// Apply patch created by `diff_serialize` to the message it was created from.
+pub fn apply_patch(base: &mut Message, patch: &[u8]) -> ProtobufResult<()> {
+    base.merge_from_bytes(patch)
+}
+
+fn field_path(prefix: &str, field: &FieldDescriptor) -> String {
+    if prefix.is_empty() {
+        field.name().to_owned()
+    } else {
+        format!("{}.{}", prefix, field.name())
+    }
+}
+
+fn write_diff(
+    os: &mut CodedOutputStream,
+    base: &Message,
+    updated: &Message,
+    prefix: &str,
+) -> Result<(), PatchError> {
+    let descriptor = descriptor_dyn(updated);
+    for field in descriptor.fields() {
+        let path = field_path(prefix, field);
+        let proto = field.proto();
+        match (field.get_reflect(base), field.get_reflect(updated)) {
+            (ReflectFieldRef::Optional(b), ReflectFieldRef::Optional(u)) => match (b, u) {
+                (None, None) => {}
+                // generated code replaces oneof message when parsing it, so it is written whole
+                (Some(ProtobufValueRef::Message(b)), Some(ProtobufValueRef::Message(u)))
+                    if !proto.has_oneof_index() =>
+                {
+                    let mut nested = Vec::new();
+                    {
+                        let mut nested_os = CodedOutputStream::vec(&mut nested);
+                        write_diff(&mut nested_os, b, u, &path)?;
+                        nested_os.flush()?;
+                    }
+                    if !nested.is_empty() {
+                        os.write_bytes(proto.get_number() as u32, &nested)?;
+                    }
+                }
+                (Some(b), Some(u)) => {
+                    let u = value_bytes(proto, &u)?;
+                    if value_bytes(proto, &b)? != u {
+                        os.write_raw_bytes(&u)?;
+                    }
+                }
+                (None, Some(u)) => os.write_raw_bytes(&value_bytes(proto, &u)?)?,
+                (Some(..), None) => {
+                    if proto.has_oneof_index() && oneof_is_set(descriptor, proto, updated) {
+                        // cleared by setting other field of the oneof
+                        continue;
+                    }
+                    match zero_without_presence(descriptor, field)? {
+                        Some(zero) => os.write_raw_bytes(&zero)?,
+                        None => return Err(PatchError::NotRepresentable(path)),
+                    }
+                }
+            },
+            (ReflectFieldRef::Repeated(b), ReflectFieldRef::Repeated(u)) => {
+                if b.len() > u.len() {
+                    return Err(PatchError::NotRepresentable(path));
+                }
+                for i in 0..b.len() {
+                    let b = value_bytes(proto, &b.get(i).as_ref())?;
+                    if b != value_bytes(proto, &u.get(i).as_ref())? {
+                        return Err(PatchError::NotRepresentable(path));
+                    }
+                }
+                for i in b.len()..u.len() {
+                    os.write_raw_bytes(&value_bytes(proto, &u.get(i).as_ref())?)?;
+                }
+            }
+            (ReflectFieldRef::Map(b), ReflectFieldRef::Map(u)) => {
+                let entry = map_entry(descriptor, field).expect("map entry");
+                let key_proto = entry.get_field().iter().find(|f| f.get_number() == 1).unwrap();
+                let value_proto = entry.get_field().iter().find(|f| f.get_number() == 2).unwrap();
+                let mut base_entries = HashMap::new();
+                for (k, v) in b.reflect_iter() {
+                    base_entries.insert(
+                        value_bytes(key_proto, &k.as_ref())?,
+                        value_bytes(value_proto, &v.as_ref())?,
+                    );
+                }
+                for (k, v) in u.reflect_iter() {
+                    let mut entry_bytes = value_bytes(key_proto, &k.as_ref())?;
+                    let v = value_bytes(value_proto, &v.as_ref())?;
+                    if base_entries.remove(&entry_bytes).as_ref() != Some(&v) {
+                        entry_bytes.extend(v);
+                        os.write_bytes(proto.get_number() as u32, &entry_bytes)?;
+                    }
+                }
+                // keys cannot be removed by merge
+                if !base_entries.is_empty() {
+                    return Err(PatchError::NotRepresentable(path));
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+    if base.get_unknown_fields() != updated.get_unknown_fields() {
+        let path = if prefix.is_empty() {
+            "<unknown fields>".to_owned()
+        } else {
+            format!("{}.<unknown fields>", prefix)
+        };
+        return Err(PatchError::NotRepresentable(path));
+    }
+    Ok(())
+}
+
+// value serialized with tag of the field (or map entry field) described by `proto`
+fn value_bytes(proto: &FieldDescriptorProto, value: &ProtobufValueRef) -> ProtobufResult<Vec<u8>> {
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        let number = proto.get_number() as u32;
+        match *value {
+            ProtobufValueRef::Message(m) => {
+                os.write_tag(number, wire_format::WireTypeLengthDelimited)?;
+                os.write_raw_varint32(m.compute_size())?;
+                m.write_to_with_cached_sizes(&mut os)?;
+            }
+            // value is taken from the message, so its enum type is not checked
+            ref v => match write_value(&mut os, proto.get_name(), proto, None, &v.to_box()) {
+                Ok(()) => {}
+                Err(RecordError::Protobuf(e)) => return Err(e),
+                Err(e) => panic!("value does not match type of field {}: {}", proto.get_name(), e),
+            },
+        }
+        os.flush()?;
+    }
+    Ok(bytes)
+}
+
+fn oneof_is_set(
+    descriptor: &MessageDescriptor,
+    proto: &FieldDescriptorProto,
+    m: &Message,
+) -> bool {
+    descriptor.fields().iter().any(|f| {
+        f.proto().has_oneof_index() && f.proto().get_oneof_index() == proto.get_oneof_index() &&
+            match f.get_reflect(m) {
+                ReflectFieldRef::Optional(v) => v.is_some(),
+                _ => false,
+            }
+    })
+}
+
+// Serialized zero value of the field if the field has no presence
+// (proto3 scalar field), so setting it to zero is equivalent to clearing.
+fn zero_without_presence(
+    descriptor: &MessageDescriptor,
+    field: &FieldDescriptor,
+) -> ProtobufResult<Option<Vec<u8>>> {
+    use descriptor::FieldDescriptorProto_Type::*;
+
+    let proto = field.proto();
+    let number = proto.get_number() as u32;
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        match proto.get_field_type() {
+            TYPE_DOUBLE => os.write_double(number, 0.0)?,
+            TYPE_FLOAT => os.write_float(number, 0.0)?,
+            TYPE_FIXED64 => os.write_fixed64(number, 0)?,
+            TYPE_SFIXED64 => os.write_sfixed64(number, 0)?,
+            TYPE_FIXED32 => os.write_fixed32(number, 0)?,
+            TYPE_SFIXED32 => os.write_sfixed32(number, 0)?,
+            TYPE_STRING | TYPE_BYTES => os.write_bytes(number, &[])?,
+            TYPE_MESSAGE | TYPE_GROUP => return Ok(None),
+            _ => os.write_uint64(number, 0)?,
+        }
+        os.flush()?;
+    }
+    // field has presence if explicitly written zero value is reported as set
+    let mut m = descriptor.new_instance();
+    m.merge_from_bytes(&bytes)?;
+    match field.get_reflect(&*m) {
+        ReflectFieldRef::Optional(None) => Ok(Some(bytes)),
+        _ => Ok(None),
+    }
+}
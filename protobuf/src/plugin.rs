@@ -30,9 +30,17 @@ pub struct CodeGeneratorRequest {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.compiler.CodeGeneratorRequest)
 }
 
 impl CodeGeneratorRequest {
+    pub const FIELD_FILE_TO_GENERATE_NUMBER: u32 = 1;
+    pub const FIELD_FILE_TO_GENERATE_TAG: u32 = 10;
+    pub const FIELD_PARAMETER_NUMBER: u32 = 2;
+    pub const FIELD_PARAMETER_TAG: u32 = 18;
+    pub const FIELD_PROTO_FILE_NUMBER: u32 = 15;
+    pub const FIELD_PROTO_FILE_TAG: u32 = 122;
+
     pub fn new() -> CodeGeneratorRequest {
         ::std::default::Default::default()
     }
@@ -122,6 +130,8 @@ impl CodeGeneratorRequest {
     pub fn get_proto_file(&self) -> &[::protobuf::descriptor::FileDescriptorProto] {
         &self.proto_file
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.compiler.CodeGeneratorRequest)
 }
 
 impl ::protobuf::Message for CodeGeneratorRequest {
@@ -155,6 +165,19 @@ impl ::protobuf::Message for CodeGeneratorRequest {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.file_to_generate {
+            self.file_to_generate.push(v.clone());
+        };
+        if other.parameter.is_some() {
+            self.parameter = other.parameter.clone();
+        }
+        for v in &other.proto_file {
+            self.proto_file.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -194,6 +217,60 @@ impl ::protobuf::Message for CodeGeneratorRequest {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        for value in &self.file_to_generate {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        if let Some(ref v) = self.parameter.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        for value in &self.proto_file {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.file_to_generate {
+            os.write_string(1, &v)?;
+        };
+        if let Some(ref v) = self.parameter.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        for v in &self.proto_file {
+            os.write_tag(15, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.file_to_generate {
+            my_size += ::protobuf::rt::string_size(1, &value) as u64;
+        };
+        if let Some(ref v) = self.parameter.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v) as u64;
+        }
+        for value in &self.proto_file {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -212,54 +289,50 @@ impl ::protobuf::Message for CodeGeneratorRequest {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CodeGeneratorRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> CodeGeneratorRequest {
         CodeGeneratorRequest::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<CodeGeneratorRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "file_to_generate",
-                    |m: &CodeGeneratorRequest| { &m.file_to_generate },
-                    |m: &mut CodeGeneratorRequest| { &mut m.file_to_generate },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "parameter",
-                    |m: &CodeGeneratorRequest| { &m.parameter },
-                    |m: &mut CodeGeneratorRequest| { &mut m.parameter },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::descriptor::FileDescriptorProto>>(
-                    "proto_file",
-                    |m: &CodeGeneratorRequest| { &m.proto_file },
-                    |m: &mut CodeGeneratorRequest| { &mut m.proto_file },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorRequest>(
-                    "CodeGeneratorRequest",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static CodeGeneratorRequest {
+        static instance: ::protobuf::lazy::LazyV2<CodeGeneratorRequest> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(CodeGeneratorRequest::new)
     }
+}
 
-    fn default_instance() -> &'static CodeGeneratorRequest {
-        static mut instance: ::protobuf::lazy::Lazy<CodeGeneratorRequest> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CodeGeneratorRequest,
-        };
-        unsafe {
-            instance.get(CodeGeneratorRequest::new)
-        }
+impl ::protobuf::MessageFull for CodeGeneratorRequest {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "file_to_generate",
+                |m: &CodeGeneratorRequest| { &m.file_to_generate },
+                |m: &mut CodeGeneratorRequest| { &mut m.file_to_generate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "parameter",
+                |m: &CodeGeneratorRequest| { &m.parameter },
+                |m: &mut CodeGeneratorRequest| { &mut m.parameter },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::descriptor::FileDescriptorProto>>(
+                "proto_file",
+                |m: &CodeGeneratorRequest| { &m.proto_file },
+                |m: &mut CodeGeneratorRequest| { &mut m.proto_file },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorRequest>(
+                "CodeGeneratorRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -284,6 +357,14 @@ impl ::protobuf::reflect::ProtobufValue for CodeGeneratorRequest {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for CodeGeneratorRequest {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<CodeGeneratorRequest> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct CodeGeneratorResponse {
     // message fields
@@ -292,9 +373,15 @@ pub struct CodeGeneratorResponse {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.compiler.CodeGeneratorResponse)
 }
 
 impl CodeGeneratorResponse {
+    pub const FIELD_ERROR_NUMBER: u32 = 1;
+    pub const FIELD_ERROR_TAG: u32 = 10;
+    pub const FIELD_FILE_NUMBER: u32 = 15;
+    pub const FIELD_FILE_TAG: u32 = 122;
+
     pub fn new() -> CodeGeneratorResponse {
         ::std::default::Default::default()
     }
@@ -359,6 +446,8 @@ impl CodeGeneratorResponse {
     pub fn get_file(&self) -> &[CodeGeneratorResponse_File] {
         &self.file
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.compiler.CodeGeneratorResponse)
 }
 
 impl ::protobuf::Message for CodeGeneratorResponse {
@@ -389,6 +478,16 @@ impl ::protobuf::Message for CodeGeneratorResponse {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.error.is_some() {
+            self.error = other.error.clone();
+        }
+        for v in &other.file {
+            self.file.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -422,6 +521,51 @@ impl ::protobuf::Message for CodeGeneratorResponse {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.error.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        for value in &self.file {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.error.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        for v in &self.file {
+            os.write_tag(15, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.error.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        for value in &self.file {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -440,49 +584,45 @@ impl ::protobuf::Message for CodeGeneratorResponse {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CodeGeneratorResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> CodeGeneratorResponse {
         CodeGeneratorResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<CodeGeneratorResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "error",
-                    |m: &CodeGeneratorResponse| { &m.error },
-                    |m: &mut CodeGeneratorResponse| { &mut m.error },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CodeGeneratorResponse_File>>(
-                    "file",
-                    |m: &CodeGeneratorResponse| { &m.file },
-                    |m: &mut CodeGeneratorResponse| { &mut m.file },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorResponse>(
-                    "CodeGeneratorResponse",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static CodeGeneratorResponse {
+        static instance: ::protobuf::lazy::LazyV2<CodeGeneratorResponse> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(CodeGeneratorResponse::new)
     }
+}
 
-    fn default_instance() -> &'static CodeGeneratorResponse {
-        static mut instance: ::protobuf::lazy::Lazy<CodeGeneratorResponse> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CodeGeneratorResponse,
-        };
-        unsafe {
-            instance.get(CodeGeneratorResponse::new)
-        }
+impl ::protobuf::MessageFull for CodeGeneratorResponse {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "error",
+                |m: &CodeGeneratorResponse| { &m.error },
+                |m: &mut CodeGeneratorResponse| { &mut m.error },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CodeGeneratorResponse_File>>(
+                "file",
+                |m: &CodeGeneratorResponse| { &m.file },
+                |m: &mut CodeGeneratorResponse| { &mut m.file },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorResponse>(
+                "CodeGeneratorResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -506,6 +646,14 @@ impl ::protobuf::reflect::ProtobufValue for CodeGeneratorResponse {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for CodeGeneratorResponse {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<CodeGeneratorResponse> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct CodeGeneratorResponse_File {
     // message fields
@@ -515,9 +663,17 @@ pub struct CodeGeneratorResponse_File {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.compiler.CodeGeneratorResponse.File)
 }
 
 impl CodeGeneratorResponse_File {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_INSERTION_POINT_NUMBER: u32 = 2;
+    pub const FIELD_INSERTION_POINT_TAG: u32 = 18;
+    pub const FIELD_CONTENT_NUMBER: u32 = 15;
+    pub const FIELD_CONTENT_TAG: u32 = 122;
+
     pub fn new() -> CodeGeneratorResponse_File {
         ::std::default::Default::default()
     }
@@ -629,6 +785,8 @@ impl CodeGeneratorResponse_File {
             None => "",
         }
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.compiler.CodeGeneratorResponse.File)
 }
 
 impl ::protobuf::Message for CodeGeneratorResponse_File {
@@ -657,6 +815,19 @@ impl ::protobuf::Message for CodeGeneratorResponse_File {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.name.is_some() {
+            self.name = other.name.clone();
+        }
+        if other.insertion_point.is_some() {
+            self.insertion_point = other.insertion_point.clone();
+        }
+        if other.content.is_some() {
+            self.content = other.content.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -693,6 +864,56 @@ impl ::protobuf::Message for CodeGeneratorResponse_File {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.insertion_point.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(ref v) = self.content.as_ref() {
+            my_size += ::protobuf::rt::string_size(15, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.insertion_point.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(ref v) = self.content.as_ref() {
+            os.write_string(15, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v) as u64;
+        }
+        if let Some(ref v) = self.insertion_point.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v) as u64;
+        }
+        if let Some(ref v) = self.content.as_ref() {
+            my_size += ::protobuf::rt::string_size(15, &v) as u64;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -711,54 +932,50 @@ impl ::protobuf::Message for CodeGeneratorResponse_File {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<CodeGeneratorResponse_File>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> CodeGeneratorResponse_File {
         CodeGeneratorResponse_File::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<CodeGeneratorResponse_File>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &CodeGeneratorResponse_File| { &m.name },
-                    |m: &mut CodeGeneratorResponse_File| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "insertion_point",
-                    |m: &CodeGeneratorResponse_File| { &m.insertion_point },
-                    |m: &mut CodeGeneratorResponse_File| { &mut m.insertion_point },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "content",
-                    |m: &CodeGeneratorResponse_File| { &m.content },
-                    |m: &mut CodeGeneratorResponse_File| { &mut m.content },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorResponse_File>(
-                    "CodeGeneratorResponse_File",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static CodeGeneratorResponse_File {
+        static instance: ::protobuf::lazy::LazyV2<CodeGeneratorResponse_File> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(CodeGeneratorResponse_File::new)
     }
+}
 
-    fn default_instance() -> &'static CodeGeneratorResponse_File {
-        static mut instance: ::protobuf::lazy::Lazy<CodeGeneratorResponse_File> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const CodeGeneratorResponse_File,
-        };
-        unsafe {
-            instance.get(CodeGeneratorResponse_File::new)
-        }
+impl ::protobuf::MessageFull for CodeGeneratorResponse_File {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &CodeGeneratorResponse_File| { &m.name },
+                |m: &mut CodeGeneratorResponse_File| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "insertion_point",
+                |m: &CodeGeneratorResponse_File| { &m.insertion_point },
+                |m: &mut CodeGeneratorResponse_File| { &mut m.insertion_point },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "content",
+                |m: &CodeGeneratorResponse_File| { &m.content },
+                |m: &mut CodeGeneratorResponse_File| { &mut m.content },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<CodeGeneratorResponse_File>(
+                "CodeGeneratorResponse_File",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -783,6 +1000,14 @@ impl ::protobuf::reflect::ProtobufValue for CodeGeneratorResponse_File {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for CodeGeneratorResponse_File {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<CodeGeneratorResponse_File> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n%google/protobuf/compiler/plugin.proto\x12\x18google.protobuf.compiler\
     \x1a\x20google/protobuf/descriptor.proto\"\xa3\x01\n\x14CodeGeneratorReq\
@@ -982,19 +1207,16 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x02\x01\x03\x12\x04\x94\x01\x17\x19\
 ";
 
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+static file_descriptor_proto_lazy: ::protobuf::lazy::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
     ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
-    }
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
+
+// @@protoc_insertion_point(module_scope)
@@ -1,21 +1,31 @@
-use std::hash::Hash;
 use std::collections::HashMap;
 use std::fmt;
 
+#[cfg(feature = "smallvec")]
+use smallvec::Array;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+
 use core::Message;
 use core::ProtobufEnum;
 use core::message_down_cast;
+use descriptor::FieldDescriptorProto_Type;
+use reflect::EnumDescriptor;
 use reflect::EnumValueDescriptor;
+use reflect::MessageDescriptor;
 use types::*;
 
 use repeated::RepeatedField;
 use singular::SingularField;
 use singular::SingularPtrField;
+use lazy_message::LazyMessageField;
 
 use super::map::ReflectMap;
+use super::map::ReflectMapKey;
 use super::repeated::ReflectRepeated;
 use super::repeated::ReflectRepeatedEnum;
 use super::repeated::ReflectRepeatedMessage;
+use super::optional::ReflectOptionBox;
 use super::optional::ReflectOptional;
 use super::value::ProtobufValue;
 use super::value::ProtobufValueRef;
@@ -23,13 +33,25 @@ use super::ReflectFieldRef;
 
 
 /// this trait should not be used directly, use `FieldDescriptor` instead
-pub trait FieldAccessor {
+pub trait FieldAccessor : Send + Sync {
     fn name_generic(&self) -> &'static str;
     fn has_field_generic(&self, m: &Message) -> bool;
     fn len_field_generic(&self, m: &Message) -> usize;
     // TODO: should it return default value or panic on unset field?
     fn get_message_generic<'a>(&self, m: &'a Message) -> &'a Message;
     fn get_enum_generic(&self, m: &Message) -> &'static EnumValueDescriptor;
+    /// Enum type of this field, `None` if unknown to accessor.
+    fn enum_descriptor_generic(&self) -> Option<&'static EnumDescriptor> {
+        None
+    }
+    /// Message type of this field, `None` if unknown to accessor.
+    fn message_descriptor_generic(&self) -> Option<&'static MessageDescriptor> {
+        None
+    }
+    /// Types of keys and values if this is a map field.
+    fn map_types_generic(&self) -> Option<(FieldDescriptorProto_Type, FieldDescriptorProto_Type)> {
+        None
+    }
     fn get_str_generic<'a>(&self, m: &'a Message) -> &'a str;
     fn get_bytes_generic<'a>(&self, m: &'a Message) -> &'a [u8];
     fn get_u32_generic(&self, m: &Message) -> u32;
@@ -44,7 +66,7 @@ pub trait FieldAccessor {
 }
 
 
-trait GetSingularMessage<M> {
+trait GetSingularMessage<M> : Send + Sync {
     fn get_message<'a>(&self, m: &'a M) -> &'a Message;
 }
 
@@ -59,7 +81,7 @@ impl<M : Message, N : Message + 'static> GetSingularMessage<M> for GetSingularMe
 }
 
 
-trait GetSingularEnum<M> {
+trait GetSingularEnum<M> : Send + Sync {
     fn get_enum(&self, m: &M) -> &'static EnumValueDescriptor;
 }
 
@@ -69,7 +91,10 @@ struct GetSingularEnumImpl<M, E> {
 
 impl<M : Message, E : ProtobufEnum> GetSingularEnum<M> for GetSingularEnumImpl<M, E> {
     fn get_enum(&self, m: &M) -> &'static EnumValueDescriptor {
-        (self.get)(m).descriptor()
+        let value = (self.get)(m).value();
+        E::enum_descriptor_dyn()
+            .expect("enum is generated without descriptor")
+            .value_by_number(value)
     }
 }
 
@@ -89,7 +114,7 @@ trait GetRepeatedEnum<M : Message + 'static> {
 
 
 
-trait GetSetCopyFns<M> {
+trait GetSetCopyFns<M> : Send + Sync {
     fn get_field<'a>(&self, m: &'a M) -> ProtobufValueRef<'a>;
 }
 
@@ -125,7 +150,7 @@ impl<M : Message + 'static> SingularGetSet<M> {
     }
 }
 
-trait FieldAccessor2<M, R : ?Sized>
+trait FieldAccessor2<M, R : ?Sized> : Send + Sync
 where
     M : Message + 'static,
 {
@@ -155,7 +180,11 @@ enum FieldAccessorFunctions<M> {
     // repeated
     Repeated(Box<FieldAccessor2<M, ReflectRepeated>>),
     // protobuf 3 map
-    Map(Box<FieldAccessor2<M, ReflectMap>>),
+    Map {
+        accessor: Box<FieldAccessor2<M, ReflectMap>>,
+        key_type: FieldDescriptorProto_Type,
+        value_type: FieldDescriptorProto_Type,
+    },
 }
 
 impl<M> fmt::Debug for FieldAccessorFunctions<M> {
@@ -169,8 +198,8 @@ impl<M> fmt::Debug for FieldAccessorFunctions<M> {
                 write!(f, "Optional(..)"),
             &FieldAccessorFunctions::Repeated(..) =>
                 write!(f, "Repeated(..)"),
-            &FieldAccessorFunctions::Map(..) =>
-                write!(f, "Map(..)"),
+            &FieldAccessorFunctions::Map { .. } =>
+                write!(f, "Map {{ .. }}"),
         }
     }
 }
@@ -179,13 +208,16 @@ impl<M> fmt::Debug for FieldAccessorFunctions<M> {
 struct FieldAccessorImpl<M> {
     name: &'static str,
     fns: FieldAccessorFunctions<M>,
+    // called lazily, because enum descriptor may be not initialized yet
+    enum_descriptor: fn() -> Option<&'static EnumDescriptor>,
+    message_descriptor: fn() -> Option<&'static MessageDescriptor>,
 }
 
 impl<M : Message> FieldAccessorImpl<M> {
     fn get_value_option<'a>(&self, m: &'a M) -> Option<ProtobufValueRef<'a>> {
         match self.fns {
             FieldAccessorFunctions::Repeated(..) |
-            FieldAccessorFunctions::Map(..) => panic!("repeated"),
+            FieldAccessorFunctions::Map { .. } => panic!("repeated"),
             FieldAccessorFunctions::Simple(ref a) => Some(a.get_field(m).as_ref()),
             FieldAccessorFunctions::Optional(ref a) => {
                 a.get_field(m).to_option().map(|v| v.as_ref())
@@ -218,7 +250,7 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
             FieldAccessorFunctions::Simple(ref a) => {
                 a.get_field(message_down_cast(m)).is_non_zero()
             }
-            FieldAccessorFunctions::Map(..) | FieldAccessorFunctions::Repeated(..) => {
+            FieldAccessorFunctions::Map { .. } | FieldAccessorFunctions::Repeated(..) => {
                 panic!("has_xxx is not implemented for repeated");
             }
         }
@@ -227,7 +259,9 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
     fn len_field_generic(&self, m: &Message) -> usize {
         match self.fns {
             FieldAccessorFunctions::Repeated(ref a) => a.get_field(message_down_cast(m)).len(),
-            FieldAccessorFunctions::Map(ref a) => a.get_field(message_down_cast(m)).len(),
+            FieldAccessorFunctions::Map { ref accessor, .. } => {
+                accessor.get_field(message_down_cast(m)).len()
+            }
             FieldAccessorFunctions::Simple(..) |
             FieldAccessorFunctions::SingularHasGetSet { .. } |
             FieldAccessorFunctions::Optional(..) => {
@@ -260,6 +294,23 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
         }
     }
 
+    fn enum_descriptor_generic(&self) -> Option<&'static EnumDescriptor> {
+        (self.enum_descriptor)()
+    }
+
+    fn message_descriptor_generic(&self) -> Option<&'static MessageDescriptor> {
+        (self.message_descriptor)()
+    }
+
+    fn map_types_generic(&self) -> Option<(FieldDescriptorProto_Type, FieldDescriptorProto_Type)> {
+        match self.fns {
+            FieldAccessorFunctions::Map { key_type, value_type, .. } => {
+                Some((key_type, value_type))
+            }
+            _ => None,
+        }
+    }
+
     fn get_str_generic<'a>(&self, m: &'a Message) -> &'a str {
         match self.get_value_option(message_down_cast(m)) {
             Some(ProtobufValueRef::String(v)) => v,
@@ -337,8 +388,8 @@ impl<M : Message + 'static> FieldAccessor for FieldAccessorImpl<M> {
             FieldAccessorFunctions::Repeated(ref accessor2) => {
                 ReflectFieldRef::Repeated(accessor2.get_field(message_down_cast(m)))
             }
-            FieldAccessorFunctions::Map(ref accessor2) => {
-                ReflectFieldRef::Map(accessor2.get_field(message_down_cast(m)))
+            FieldAccessorFunctions::Map { ref accessor, .. } => {
+                ReflectFieldRef::Map(accessor.get_field(message_down_cast(m)))
             }
             FieldAccessorFunctions::Optional(ref accessor2) => {
                 ReflectFieldRef::Optional(
@@ -379,6 +430,22 @@ fn set_panic<A, B>(_: &mut A, _: B) {
     panic!()
 }
 
+fn no_enum_descriptor() -> Option<&'static EnumDescriptor> {
+    None
+}
+
+fn enum_descriptor_for<E : ProtobufEnum>() -> Option<&'static EnumDescriptor> {
+    E::enum_descriptor_dyn()
+}
+
+fn no_message_descriptor() -> Option<&'static MessageDescriptor> {
+    None
+}
+
+fn message_descriptor_for<M : Message>() -> Option<&'static MessageDescriptor> {
+    M::default_instance().descriptor_dyn()
+}
+
 // TODO: make_singular_xxx_accessor are used only for oneof fields
 // oneof codegen should be changed
 
@@ -389,6 +456,8 @@ pub fn make_singular_u32_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
@@ -406,6 +475,8 @@ pub fn make_singular_i32_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
@@ -423,6 +494,8 @@ pub fn make_singular_u64_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
@@ -440,6 +513,8 @@ pub fn make_singular_i64_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
@@ -457,6 +532,8 @@ pub fn make_singular_f32_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
@@ -474,6 +551,8 @@ pub fn make_singular_f64_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
@@ -491,6 +570,8 @@ pub fn make_singular_bool_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Copy(Box::new(GetSetCopyFnsImpl {
@@ -508,6 +589,8 @@ pub fn make_singular_enum_accessor<M : Message + 'static, E : ProtobufEnum + 'st
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: enum_descriptor_for::<E>,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Enum(Box::new(GetSingularEnumImpl { get: get })),
@@ -522,6 +605,8 @@ pub fn make_singular_string_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::String(get, set_panic),
@@ -536,6 +621,8 @@ pub fn make_singular_bytes_accessor<M : Message + 'static>(
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: no_message_descriptor,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Bytes(get, set_panic),
@@ -550,6 +637,8 @@ pub fn make_singular_message_accessor<M : Message + 'static, F : Message + 'stat
 ) -> Box<FieldAccessor + 'static> {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: no_enum_descriptor,
+        message_descriptor: message_descriptor_for::<F>,
         fns: FieldAccessorFunctions::SingularHasGetSet {
             has: has,
             get_set: SingularGetSet::Message(Box::new(GetSingularMessageImpl { get: get })),
@@ -587,6 +676,8 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
         fns: FieldAccessorFunctions::Repeated(Box::new(MessageGetMut::<M, Vec<V::Value>> {
             get_field: get_vec,
             mut_field: mut_vec,
@@ -595,6 +686,46 @@ where
 }
 
 
+#[cfg(feature = "smallvec")]
+impl<M, A> FieldAccessor2<M, ReflectRepeated> for MessageGetMut<M, SmallVec<A>>
+where
+    M : Message + 'static,
+    A : Array + 'static,
+    A::Item : ProtobufValue + 'static,
+{
+    fn get_field<'a>(&self, m: &'a M) -> &'a ReflectRepeated {
+        (self.get_field)(m) as &ReflectRepeated
+    }
+
+    fn mut_field<'a>(&self, m: &'a mut M) -> &'a mut ReflectRepeated {
+        (self.mut_field)(m) as &mut ReflectRepeated
+    }
+}
+
+
+#[cfg(feature = "smallvec")]
+pub fn make_smallvec_accessor<M, V, A>(
+    name: &'static str,
+    get_vec: for<'a> fn(&'a M) -> &'a SmallVec<A>,
+    mut_vec: for<'a> fn(&'a mut M) -> &'a mut SmallVec<A>,
+) -> Box<FieldAccessor + 'static>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+    A : Array<Item = V::Value> + 'static,
+{
+    Box::new(FieldAccessorImpl {
+        name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
+        fns: FieldAccessorFunctions::Repeated(Box::new(MessageGetMut::<M, SmallVec<A>> {
+            get_field: get_vec,
+            mut_field: mut_vec,
+        })),
+    })
+}
+
+
 impl<M, V> FieldAccessor2<M, ReflectRepeated> for MessageGetMut<M, RepeatedField<V>>
 where
     M : Message + 'static,
@@ -622,6 +753,8 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
         fns: FieldAccessorFunctions::Repeated(
             Box::new(MessageGetMut::<M, RepeatedField<V::Value>> {
                 get_field: get_vec,
@@ -656,6 +789,8 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
         fns: FieldAccessorFunctions::Optional(Box::new(MessageGetMut::<M, Option<V::Value>> {
             get_field: get_field,
             mut_field: mut_field,
@@ -690,6 +825,8 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
         fns: FieldAccessorFunctions::Optional(
             Box::new(MessageGetMut::<M, SingularField<V::Value>> {
                 get_field: get_field,
@@ -726,6 +863,8 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
         fns: FieldAccessorFunctions::Optional(
             Box::new(MessageGetMut::<M, SingularPtrField<V::Value>> {
                 get_field: get_field,
@@ -735,6 +874,91 @@ where
     })
 }
 
+impl<M, V> FieldAccessor2<M, ReflectOptional> for MessageGetMut<M, LazyMessageField<V>>
+where
+    M : Message + 'static,
+    V : Message + ProtobufValue + Clone + 'static,
+{
+    fn get_field<'a>(&self, m: &'a M) -> &'a ReflectOptional {
+        (self.get_field)(m) as &ReflectOptional
+    }
+
+    fn mut_field<'a>(&self, m: &'a mut M) -> &'a mut ReflectOptional {
+        (self.mut_field)(m) as &mut ReflectOptional
+    }
+}
+
+pub fn make_lazy_message_field_accessor<M, V>(
+    name: &'static str,
+    get_field: for<'a> fn(&'a M)
+        -> &'a LazyMessageField<V::Value>,
+    mut_field: for<'a> fn(&'a mut M)
+        -> &'a mut LazyMessageField<V::Value>,
+) -> Box<FieldAccessor + 'static>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+    V::Value : Message,
+{
+    Box::new(FieldAccessorImpl {
+        name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
+        fns: FieldAccessorFunctions::Optional(
+            Box::new(MessageGetMut::<M, LazyMessageField<V::Value>> {
+                get_field: get_field,
+                mut_field: mut_field,
+            }),
+        ),
+    })
+}
+
+// not `MessageGetMut<M, Option<Box<V>>>` which conflicts with `MessageGetMut<M, Option<V>>`
+struct OptionBoxGetMut<M, V>
+where
+    M : Message + 'static,
+{
+    get_field: for<'a> fn(&'a M) -> &'a Option<Box<V>>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut Option<Box<V>>,
+}
+
+impl<M, V> FieldAccessor2<M, ReflectOptional> for OptionBoxGetMut<M, V>
+where
+    M : Message + 'static,
+    V : Message + ProtobufValue + Clone + 'static,
+{
+    fn get_field<'a>(&self, m: &'a M) -> &'a ReflectOptional {
+        ReflectOptionBox::from_ref((self.get_field)(m)) as &ReflectOptional
+    }
+
+    fn mut_field<'a>(&self, m: &'a mut M) -> &'a mut ReflectOptional {
+        ReflectOptionBox::from_mut((self.mut_field)(m)) as &mut ReflectOptional
+    }
+}
+
+pub fn make_option_box_accessor<M, V>(
+    name: &'static str,
+    get_field: for<'a> fn(&'a M) -> &'a Option<Box<V::Value>>,
+    mut_field: for<'a> fn(&'a mut M) -> &'a mut Option<Box<V::Value>>,
+) -> Box<FieldAccessor + 'static>
+where
+    M : Message + 'static,
+    V : ProtobufType + 'static,
+    V::Value : Message,
+{
+    Box::new(FieldAccessorImpl {
+        name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
+        fns: FieldAccessorFunctions::Optional(
+            Box::new(OptionBoxGetMut::<M, V::Value> {
+                get_field: get_field,
+                mut_field: mut_field,
+            }),
+        ),
+    })
+}
+
 impl<M, V> FieldAccessor2<M, ProtobufValue> for MessageGetMut<M, V>
 where
     M : Message + 'static,
@@ -760,6 +984,8 @@ where
 {
     Box::new(FieldAccessorImpl {
         name: name,
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
         fns: FieldAccessorFunctions::Simple(Box::new(MessageGetMut::<M, V::Value> {
             get_field: get_field,
             mut_field: mut_field,
@@ -771,9 +997,8 @@ where
 impl<M, K, V> FieldAccessor2<M, ReflectMap> for MessageGetMut<M, HashMap<K, V>>
 where
     M : Message + 'static,
-    K : ProtobufValue + 'static,
+    K : ReflectMapKey + 'static,
     V : ProtobufValue + 'static,
-    K : Hash + Eq,
 {
     fn get_field<'a>(&self, m: &'a M) -> &'a ReflectMap {
         (self.get_field)(m) as &ReflectMap
@@ -796,15 +1021,52 @@ where
     M : Message + 'static,
     K : ProtobufType + 'static,
     V : ProtobufType + 'static,
-    <K as ProtobufType>::Value : Hash + Eq,
+    <K as ProtobufType>::Value : ReflectMapKey,
 {
     Box::new(FieldAccessorImpl {
         name: name,
-        fns: FieldAccessorFunctions::Map(
-            Box::new(MessageGetMut::<M, HashMap<K::Value, V::Value>> {
+        enum_descriptor: V::enum_descriptor,
+        message_descriptor: V::message_descriptor,
+        fns: FieldAccessorFunctions::Map {
+            accessor: Box::new(MessageGetMut::<M, HashMap<K::Value, V::Value>> {
                 get_field: get_field,
                 mut_field: mut_field,
             }),
-        ),
+            key_type: K::field_type(),
+            value_type: V::field_type(),
+        },
+    })
+}
+
+
+/// this trait should not be used directly, use `MessageDescriptor::which_oneof` instead
+pub trait OneofAccessor : Send + Sync {
+    fn name_generic(&self) -> &'static str;
+    /// Number of the field which is set, `None` if no field of the oneof is set.
+    fn which_generic(&self, m: &Message) -> Option<u32>;
+}
+
+struct OneofAccessorImpl<M> {
+    name: &'static str,
+    which: fn(&M) -> Option<u32>,
+}
+
+impl<M : Message> OneofAccessor for OneofAccessorImpl<M> {
+    fn name_generic(&self) -> &'static str {
+        self.name
+    }
+
+    fn which_generic(&self, m: &Message) -> Option<u32> {
+        (self.which)(message_down_cast(m))
+    }
+}
+
+pub fn make_oneof_accessor<M : Message + 'static>(
+    name: &'static str,
+    which: fn(&M) -> Option<u32>,
+) -> Box<OneofAccessor + 'static> {
+    Box::new(OneofAccessorImpl {
+        name: name,
+        which: which,
     })
 }
@@ -0,0 +1,318 @@
+//! Field-level difference of two messages.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use core::Message;
+#[cfg(feature = "with-groups")]
+use stream::CodedOutputStream;
+use unknown::UnknownValueRef;
+use unknown::UnknownValues;
+use well_known_types::FieldMask;
+
+use super::ProtobufValue;
+use super::descriptor_dyn;
+use super::ProtobufValueBox;
+use super::ProtobufValueRef;
+use super::ReflectFieldRef;
+
+
+/// Field which has different values in two compared messages.
+#[derive(Debug)]
+pub struct FieldDiff {
+    /// Path of the field from the root message, like `message_type[1].field[0].name`.
+    ///
+    /// Map entries are addressed by key, like `fields["a"]`,
+    /// unknown fields by field number.
+    pub path: String,
+    /// Value in the first message, `None` if the field is not set
+    /// or repeated field has fewer elements.
+    pub left: Option<ProtobufValueBox>,
+    /// Value in the second message.
+    pub right: Option<ProtobufValueBox>,
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fn value(f: &mut fmt::Formatter, value: &Option<ProtobufValueBox>) -> fmt::Result {
+            match *value {
+                Some(ref v) => write!(f, "{:?}", v),
+                None => write!(f, "<unset>"),
+            }
+        }
+
+        write!(f, "{}: ", self.path)?;
+        value(f, &self.left)?;
+        write!(f, " != ")?;
+        value(f, &self.right)
+    }
+}
+
+/// Find fields which differ in two messages of the same type.
+///
+/// Nested messages, elements of repeated fields and map entries
+/// are compared recursively, so each reported path is a scalar value,
+/// or a message set in one message only. Result is empty iff messages
+/// are equal, except unknown fields are compared by field number only.
+///
+/// # Panics
+///
+/// If messages are of different types, or generated without reflection.
+pub fn diff(left: &Message, right: &Message) -> Vec<FieldDiff> {
+    diff_impl(left, right, true)
+}
+
+/// Check messages are equal not taking unknown fields into account.
+///
+/// Unlike generated `PartialEq` this function treats messages as equal
+/// when they are received from peers having newer version of schema,
+/// and differ only in fields unknown to this program.
+///
+/// # Panics
+///
+/// If messages are of different types, or generated without reflection.
+pub fn eq_ignoring_unknown_fields(left: &Message, right: &Message) -> bool {
+    diff_impl(left, right, false).is_empty()
+}
+
+/// Check messages are equal except fields listed in the mask.
+///
+/// Mask paths are field names separated by dot, like `message_type.name`,
+/// and ignore the whole subtree, i. e. all elements of repeated field
+/// or all entries of map. Paths which do not exist in the message
+/// are ignored.
+///
+/// # Panics
+///
+/// If messages are of different types, or generated without reflection.
+pub fn eq_with_mask(left: &Message, right: &Message, ignore: &FieldMask) -> bool {
+    diff(left, right)
+        .iter()
+        .all(|d| ignore.get_paths().iter().any(|p| path_covers(p, &d.path)))
+}
+
+// mask path `a.b` covers diff paths `a.b`, `a.b.c`, `a.b[1]` and `a.b[1].c`
+fn path_covers(mask_path: &str, path: &str) -> bool {
+    let path = strip_indices(path);
+    path.starts_with(mask_path) && match path[mask_path.len()..].chars().next() {
+        None | Some('.') => true,
+        _ => false,
+    }
+}
+
+// remove repeated field indices and map keys from path
+fn strip_indices(path: &str) -> String {
+    let mut r = String::new();
+    let mut chars = path.chars();
+    while let Some(c) = chars.next() {
+        if c != '[' {
+            r.push(c);
+            continue;
+        }
+        // skip until closing bracket, string keys are quoted and escaped
+        let mut quoted = false;
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' if quoted => {
+                    chars.next();
+                }
+                '"' => quoted = !quoted,
+                ']' if !quoted => break,
+                _ => {}
+            }
+        }
+    }
+    r
+}
+
+fn diff_impl(left: &Message, right: &Message, unknown_fields: bool) -> Vec<FieldDiff> {
+    assert!(
+        Message::type_id(left) == Message::type_id(right),
+        "cannot diff messages of different types"
+    );
+
+    let mut r = Vec::new();
+    diff_messages("", left, right, unknown_fields, &mut r);
+    r
+}
+
+pub(crate) fn child_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{}.{}", path, name)
+    }
+}
+
+// values of map entry with the same key in both messages
+type MapEntryPair<'a> = (Option<&'a ProtobufValue>, Option<&'a ProtobufValue>);
+
+fn diff_messages(
+    path: &str,
+    left: &Message,
+    right: &Message,
+    unknown_fields: bool,
+    r: &mut Vec<FieldDiff>,
+) {
+    for field in descriptor_dyn(left).fields() {
+        let path = child_path(path, field.name());
+        match (field.get_reflect(left), field.get_reflect(right)) {
+            (ReflectFieldRef::Optional(l), ReflectFieldRef::Optional(r_value)) => {
+                diff_values(&path, l, r_value, unknown_fields, r);
+            }
+            (ReflectFieldRef::Repeated(l), ReflectFieldRef::Repeated(r_values)) => {
+                for i in 0..l.len().max(r_values.len()) {
+                    let l_value = if i < l.len() { Some(l.get(i).as_ref()) } else { None };
+                    let r_value = if i < r_values.len() {
+                        Some(r_values.get(i).as_ref())
+                    } else {
+                        None
+                    };
+                    let path = format!("{}[{}]", path, i);
+                    diff_values(&path, l_value, r_value, unknown_fields, r);
+                }
+            }
+            (ReflectFieldRef::Map(l), ReflectFieldRef::Map(r_map)) => {
+                // keys are sorted by their text form for stable report
+                let mut entries: BTreeMap<String, MapEntryPair> = BTreeMap::new();
+                for (k, v) in l.reflect_iter() {
+                    entries.entry(format_key(k.as_ref())).or_insert((None, None)).0 = Some(v);
+                }
+                for (k, v) in r_map.reflect_iter() {
+                    entries.entry(format_key(k.as_ref())).or_insert((None, None)).1 = Some(v);
+                }
+                for (key, (l, r_value)) in entries {
+                    diff_values(
+                        &format!("{}[{}]", path, key),
+                        l.map(|v| v.as_ref()),
+                        r_value.map(|v| v.as_ref()),
+                        unknown_fields,
+                        r,
+                    );
+                }
+            }
+            _ => unreachable!("same field of messages of the same type"),
+        }
+    }
+
+    if unknown_fields {
+        diff_unknown_fields(path, left, right, r);
+    }
+}
+
+fn diff_values(
+    path: &str,
+    left: Option<ProtobufValueRef>,
+    right: Option<ProtobufValueRef>,
+    unknown_fields: bool,
+    r: &mut Vec<FieldDiff>,
+) {
+    match (left, right) {
+        (None, None) => {}
+        (Some(ProtobufValueRef::Message(l)), Some(ProtobufValueRef::Message(r_message))) => {
+            diff_messages(path, l, r_message, unknown_fields, r);
+        }
+        (Some(ref l), Some(ref r_value)) if value_eq(l, r_value) => {}
+        (left, right) => r.push(FieldDiff {
+            path: path.to_owned(),
+            left: left.map(|v| v.to_box()),
+            right: right.map(|v| v.to_box()),
+        }),
+    }
+}
+
+// equality of scalar values, same as `PartialEq` of generated messages
+fn value_eq(left: &ProtobufValueRef, right: &ProtobufValueRef) -> bool {
+    match (left, right) {
+        (&ProtobufValueRef::U32(l), &ProtobufValueRef::U32(r)) => l == r,
+        (&ProtobufValueRef::U64(l), &ProtobufValueRef::U64(r)) => l == r,
+        (&ProtobufValueRef::I32(l), &ProtobufValueRef::I32(r)) => l == r,
+        (&ProtobufValueRef::I64(l), &ProtobufValueRef::I64(r)) => l == r,
+        (&ProtobufValueRef::F32(l), &ProtobufValueRef::F32(r)) => l == r,
+        (&ProtobufValueRef::F64(l), &ProtobufValueRef::F64(r)) => l == r,
+        (&ProtobufValueRef::Bool(l), &ProtobufValueRef::Bool(r)) => l == r,
+        (&ProtobufValueRef::String(l), &ProtobufValueRef::String(r)) => l == r,
+        (&ProtobufValueRef::Bytes(l), &ProtobufValueRef::Bytes(r)) => l == r,
+        (&ProtobufValueRef::Enum(l), &ProtobufValueRef::Enum(r)) => l.value() == r.value(),
+        _ => false,
+    }
+}
+
+pub(crate) fn format_key(key: ProtobufValueRef) -> String {
+    match key {
+        ProtobufValueRef::U32(v) => v.to_string(),
+        ProtobufValueRef::U64(v) => v.to_string(),
+        ProtobufValueRef::I32(v) => v.to_string(),
+        ProtobufValueRef::I64(v) => v.to_string(),
+        ProtobufValueRef::Bool(v) => v.to_string(),
+        ProtobufValueRef::String(v) => format!("{:?}", v),
+        _ => unreachable!("not a map key type"),
+    }
+}
+
+fn unknown_values_box(values: &UnknownValues) -> ProtobufValueBox {
+    ProtobufValueBox::Repeated(
+        values
+            .iter()
+            .map(|v| match v {
+                UnknownValueRef::Fixed32(v) => ProtobufValueBox::U32(v),
+                UnknownValueRef::Fixed64(v) | UnknownValueRef::Varint(v) => {
+                    ProtobufValueBox::U64(v)
+                }
+                UnknownValueRef::LengthDelimited(v) => ProtobufValueBox::Bytes(v.to_owned()),
+                #[cfg(feature = "with-groups")]
+                UnknownValueRef::Group(fields) => {
+                    // fields of group encoded like fields of message
+                    let mut bytes = Vec::new();
+                    {
+                        let mut os = CodedOutputStream::vec(&mut bytes);
+                        // writing to vec cannot fail
+                        os.write_unknown_fields(fields).unwrap();
+                        os.flush().unwrap();
+                    }
+                    ProtobufValueBox::Bytes(bytes)
+                }
+            })
+            .collect(),
+    )
+}
+
+pub(crate) fn diff_unknown_fields(
+    path: &str,
+    left: &Message,
+    right: &Message,
+    r: &mut Vec<FieldDiff>,
+) {
+    let mut numbers: Vec<u32> = left
+        .get_unknown_fields()
+        .iter()
+        .chain(right.get_unknown_fields().iter())
+        .map(|(number, _)| number)
+        .collect();
+    numbers.sort();
+    numbers.dedup();
+
+    for number in numbers {
+        let l = left.get_unknown_fields().get(number);
+        let r_values = right.get_unknown_fields().get(number);
+        if l == r_values {
+            continue;
+        }
+        r.push(FieldDiff {
+            path: child_path(path, &number.to_string()),
+            left: l.map(unknown_values_box),
+            right: r_values.map(unknown_values_box),
+        });
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn strip_indices_from_path() {
+        assert_eq!("a.b.c", strip_indices("a[1].b[\"x]\\\"[\"].c"));
+    }
+}
@@ -0,0 +1,182 @@
+//! Operations on messages known only as `&Message`.
+
+use core::Message;
+use stream::CodedInputStream;
+use stream::CodedOutputStream;
+use stream::Utf8Policy;
+
+use super::ProtobufValueRef;
+use super::descriptor_dyn;
+use super::ReflectFieldRef;
+
+
+/// Check that message has no fields set, or all fields have default values.
+///
+/// Repeated and map fields must be empty, singular fields must be unset
+/// or contain zero (`0`, `false`, empty string or bytes, enum value `0`),
+/// and nested messages must be default recursively.
+/// Message with unknown fields is not default.
+///
+/// Explicit proto2 `[default = ...]` values are not taken into account,
+/// so proto2 field set to non-zero default value is not default.
+///
+/// # Panics
+///
+/// If message is generated without reflection (e. g. for `LITE_RUNTIME`).
+pub fn is_default(m: &Message) -> bool {
+    if m.get_unknown_fields().iter().next().is_some() {
+        return false;
+    }
+
+    descriptor_dyn(m).fields().iter().all(|field| {
+        match field.get_reflect(m) {
+            ReflectFieldRef::Repeated(repeated) => repeated.len() == 0,
+            ReflectFieldRef::Map(map) => map.len() == 0,
+            ReflectFieldRef::Optional(None) => true,
+            ReflectFieldRef::Optional(Some(ProtobufValueRef::Message(nested))) => {
+                is_default(nested)
+            }
+            ReflectFieldRef::Optional(Some(value)) => !value.is_non_zero(),
+        }
+    })
+}
+
+/// Clear all fields of the message, including unknown fields.
+///
+/// Same as `Clear::clear`, which retains memory allocated by the message.
+/// After this call `is_default` returns `true` for the message.
+pub fn clear_dyn(m: &mut Message) {
+    m.clear();
+}
+
+/// Merge fields of `other` into `m`, like `Message::merge_from_message`.
+///
+/// `other` is serialized and merged with `merge_from`, so no reflective
+/// setters are needed. Messages of known type should be merged with
+/// `merge_from_message`, which generated code implements without serialization.
+///
+/// # Panics
+///
+/// If messages are of different types.
+pub fn merge_dyn(m: &mut Message, other: &Message) {
+    assert!(
+        Message::type_id(m) == Message::type_id(other),
+        "cannot merge messages of different types"
+    );
+
+    let mut bytes = Vec::new();
+    {
+        other.compute_size();
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        other.write_to_with_cached_sizes(&mut os).expect("write to vec");
+        os.flush().expect("write to vec");
+    }
+
+    let mut is = CodedInputStream::from_bytes(&bytes);
+    // `Chars` fields may hold invalid UTF-8 preserved by parser,
+    // and depth of serialized message is not limited
+    is.set_utf8_policy(Utf8Policy::Preserve);
+    is.set_recursion_limit(u32::max_value());
+    m.merge_from(&mut is).expect("parse serialized message of the same type");
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::DescriptorProto;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FileDescriptorProto;
+    use descriptor::MessageOptions;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    #[test]
+    fn default_instance() {
+        assert!(is_default(&FileDescriptorProto::new()));
+        assert!(is_default(&Struct::new()));
+    }
+
+    #[test]
+    fn fields_set() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        assert!(!is_default(&file));
+
+        let mut file = FileDescriptorProto::new();
+        file.mut_message_type().push(DescriptorProto::new());
+        assert!(!is_default(&file));
+
+        let mut s = Struct::new();
+        s.mut_fields().insert("a".to_owned(), Value::new());
+        assert!(!is_default(&s));
+
+        let mut m = DescriptorProto::new();
+        m.mut_unknown_fields().add_varint(1000, 1);
+        assert!(!is_default(&m));
+    }
+
+    #[test]
+    fn zero_values() {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name(String::new());
+        field.set_number(0);
+        assert!(is_default(&field));
+
+        // nested messages are checked recursively
+        let mut m = DescriptorProto::new();
+        m.mut_options();
+        assert!(is_default(&m));
+        m.mut_options().set_map_entry(true);
+        assert!(!is_default(&m));
+        m.set_options(MessageOptions::new());
+        assert!(is_default(&m));
+    }
+
+    #[test]
+    fn clear() {
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        file.mut_dependency().push("b.proto".to_owned());
+        file.mut_unknown_fields().add_varint(1000, 1);
+        {
+            let m: &mut Message = &mut file;
+            clear_dyn(m);
+            assert!(is_default(m));
+        }
+        assert_eq!("", file.get_name());
+        assert!(file.get_dependency().is_empty());
+    }
+
+    #[test]
+    fn merge() {
+        let mut base = FileDescriptorProto::new();
+        base.set_name("a.proto".to_owned());
+        base.mut_dependency().push("b.proto".to_owned());
+        base.mut_options().set_java_package("a".to_owned());
+
+        let mut other = FileDescriptorProto::new();
+        other.mut_dependency().push("c.proto".to_owned());
+        other.mut_options().set_java_outer_classname("A".to_owned());
+        other.mut_unknown_fields().add_varint(1000, 1);
+
+        let mut expected = base.clone();
+        expected.merge_from_bytes(&other.write_to_bytes().unwrap()).unwrap();
+        assert_eq!(&["b.proto", "c.proto"], expected.get_dependency());
+        assert_eq!("a", expected.get_options().get_java_package());
+
+        let mut merged = base.clone();
+        merged.merge_from_message(&other);
+        assert_eq!(expected, merged);
+
+        let mut merged = base.clone();
+        merge_dyn(&mut merged, &other);
+        assert_eq!(expected, merged);
+    }
+
+    #[test]
+    #[should_panic]
+    fn merge_different_types() {
+        merge_dyn(&mut FileDescriptorProto::new(), &DescriptorProto::new());
+    }
+}
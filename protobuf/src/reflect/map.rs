@@ -2,7 +2,98 @@ use std::hash::Hash;
 use std::collections::HashMap;
 use std::collections::hash_map;
 
+#[cfg(feature = "bytes")]
+use chars::Chars;
+
 use super::value::ProtobufValue;
+use super::value::ProtobufValueRef;
+
+
+/// Type of keys of map fields: integer, `bool` or string.
+pub trait ReflectMapKey : ProtobufValue + Eq + Hash + Sized {
+    /// Key equal to given value, `None` if value is of another type.
+    fn from_value_ref(value: &ProtobufValueRef) -> Option<Self>;
+
+    /// Value by key, `None` if map has no such key or key is of another type.
+    fn map_get<'a, V>(map: &'a HashMap<Self, V>, key: &ProtobufValueRef) -> Option<&'a V> {
+        match Self::from_value_ref(key) {
+            Some(key) => map.get(&key),
+            None => None,
+        }
+    }
+}
+
+impl ReflectMapKey for u32 {
+    fn from_value_ref(value: &ProtobufValueRef) -> Option<u32> {
+        match *value {
+            ProtobufValueRef::U32(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl ReflectMapKey for u64 {
+    fn from_value_ref(value: &ProtobufValueRef) -> Option<u64> {
+        match *value {
+            ProtobufValueRef::U64(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl ReflectMapKey for i32 {
+    fn from_value_ref(value: &ProtobufValueRef) -> Option<i32> {
+        match *value {
+            ProtobufValueRef::I32(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl ReflectMapKey for i64 {
+    fn from_value_ref(value: &ProtobufValueRef) -> Option<i64> {
+        match *value {
+            ProtobufValueRef::I64(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl ReflectMapKey for bool {
+    fn from_value_ref(value: &ProtobufValueRef) -> Option<bool> {
+        match *value {
+            ProtobufValueRef::Bool(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+impl ReflectMapKey for String {
+    fn from_value_ref(value: &ProtobufValueRef) -> Option<String> {
+        match *value {
+            ProtobufValueRef::String(v) => Some(v.to_owned()),
+            _ => None,
+        }
+    }
+
+    // lookup by `&str` without allocating key
+    fn map_get<'a, V>(map: &'a HashMap<String, V>, key: &ProtobufValueRef) -> Option<&'a V> {
+        match *key {
+            ProtobufValueRef::String(v) => map.get(v),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl ReflectMapKey for Chars {
+    fn from_value_ref(value: &ProtobufValueRef) -> Option<Chars> {
+        match *value {
+            ProtobufValueRef::String(v) => Some(Chars::from(v)),
+            _ => None,
+        }
+    }
+}
 
 
 /// Implemented for `HashMap` with appropriate keys and values
@@ -10,10 +101,12 @@ pub trait ReflectMap: 'static {
     fn reflect_iter(&self) -> ReflectMapIter;
 
     fn len(&self) -> usize;
+
+    /// Value by key, `None` if map has no such key or key is of another type.
+    fn get<'a>(&'a self, key: ProtobufValueRef) -> Option<&'a ProtobufValue>;
 }
 
-impl<K : ProtobufValue + Eq + Hash + 'static, V : ProtobufValue + 'static> ReflectMap
-    for HashMap<K, V> {
+impl<K : ReflectMapKey + 'static, V : ProtobufValue + 'static> ReflectMap for HashMap<K, V> {
     fn reflect_iter<'a>(&'a self) -> ReflectMapIter<'a> {
         ReflectMapIter { imp: Box::new(ReflectMapIterImpl::<'a, K, V> { iter: self.iter() }) }
     }
@@ -21,6 +114,10 @@ impl<K : ProtobufValue + Eq + Hash + 'static, V : ProtobufValue + 'static> Refle
     fn len(&self) -> usize {
         HashMap::len(self)
     }
+
+    fn get<'a>(&'a self, key: ProtobufValueRef) -> Option<&'a ProtobufValue> {
+        K::map_get(self, &key).map(|v| v as &ProtobufValue)
+    }
 }
 
 
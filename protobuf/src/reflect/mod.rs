@@ -5,16 +5,21 @@ use std::default::Default;
 use std::marker;
 
 use core::Message;
-use core::ProtobufEnum;
+use core::MessageFull;
+use core::ProtobufEnumFull;
 use descriptor::FileDescriptorProto;
 use descriptor::DescriptorProto;
 use descriptor::FieldDescriptorProto;
 use descriptor::EnumDescriptorProto;
 use descriptor::EnumValueDescriptorProto;
+use descriptor::OneofDescriptorProto;
 use descriptor::FieldDescriptorProto_Label;
+use descriptor::FieldDescriptorProto_Type;
 use descriptorx::find_enum_by_rust_name;
 use descriptorx::find_message_by_rust_name;
+use rustproto;
 use reflect::accessor::FieldAccessor;
+use reflect::accessor::OneofAccessor;
 
 
 pub mod accessor;
@@ -22,12 +27,39 @@ mod map;
 mod repeated;
 mod value;
 mod optional;
+mod dynamic;
+pub(crate) mod diff;
+mod rustproto_options;
+pub(crate) mod record;
+mod registry;
+mod template;
 
 use self::repeated::ReflectRepeated;
-use self::map::ReflectMap;
 
+pub use self::map::ReflectMap;
+pub use self::map::ReflectMapIter;
+pub use self::map::ReflectMapKey;
 pub use self::value::ProtobufValue;
 pub use self::value::ProtobufValueRef;
+pub use self::value::ProtobufValueBox;
+pub use self::dynamic::is_default;
+pub use self::dynamic::clear_dyn;
+pub use self::dynamic::merge_dyn;
+pub use self::diff::diff;
+pub use self::diff::FieldDiff;
+pub use self::diff::eq_ignoring_unknown_fields;
+pub use self::diff::eq_with_mask;
+pub use self::rustproto_options::RustprotoOptions;
+pub use self::record::Record;
+pub use self::record::RecordError;
+pub use self::record::to_record;
+pub use self::record::from_record;
+pub use self::record::merge_from_record;
+pub use self::registry::register_message;
+pub use self::registry::all_registered_messages;
+pub use self::template::TemplateError;
+pub use self::template::expand_placeholders;
+pub use self::template::expand_placeholders_with;
 
 
 pub struct FieldDescriptor {
@@ -59,6 +91,12 @@ impl FieldDescriptor {
         self.proto.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED
     }
 
+    /// Field is marked with `rustproto.redact` option,
+    /// so its value is not printed in `Debug` output.
+    pub fn is_redacted(&self) -> bool {
+        rustproto::exts::redact.get(self.proto.get_options()).unwrap_or(false)
+    }
+
     pub fn has_field(&self, m: &Message) -> bool {
         self.accessor.has_field_generic(m)
     }
@@ -71,6 +109,41 @@ impl FieldDescriptor {
         self.accessor.get_message_generic(m)
     }
 
+    /// Descriptor of enum type of this field or of its map values,
+    /// `None` if values of this field are not enums.
+    pub fn enum_descriptor(&self) -> Option<&'static EnumDescriptor> {
+        self.accessor.enum_descriptor_generic()
+    }
+
+    /// Descriptor of message type of this field or of its map values,
+    /// `None` if values of this field are not messages.
+    pub fn message_descriptor(&self) -> Option<&'static MessageDescriptor> {
+        self.accessor.message_descriptor_generic()
+    }
+
+    /// Field is a map field.
+    pub fn is_map(&self) -> bool {
+        self.accessor.map_types_generic().is_some()
+    }
+
+    /// Type of keys if this is a map field.
+    pub fn map_key_type(&self) -> Option<FieldDescriptorProto_Type> {
+        self.accessor.map_types_generic().map(|(k, _)| k)
+    }
+
+    /// Type of values if this is a map field.
+    pub fn map_value_type(&self) -> Option<FieldDescriptorProto_Type> {
+        self.accessor.map_types_generic().map(|(_, v)| v)
+    }
+
+    /// Map field value, panics if this is not a map field.
+    pub fn get_map<'a>(&self, m: &'a Message) -> &'a ReflectMap {
+        match self.accessor.get_reflect(m) {
+            ReflectFieldRef::Map(map) => map,
+            _ => panic!("not a map field: {}", self.name()),
+        }
+    }
+
     pub fn get_enum(&self, m: &Message) -> &'static EnumValueDescriptor {
         self.accessor.get_enum_generic(m)
     }
@@ -117,7 +190,7 @@ impl FieldDescriptor {
 }
 
 
-trait MessageFactory {
+trait MessageFactory : Send + Sync {
     fn new_instance(&self) -> Box<Message>;
 }
 
@@ -142,27 +215,53 @@ impl<M : 'static + Message + Default> MessageFactory for MessageFactoryTyped<M>
     }
 }
 
+/// Reflection information of message type.
+///
+/// Descriptors are created once and live forever, so they can be shared
+/// between threads as `&'static MessageDescriptor`.
 pub struct MessageDescriptor {
     full_name: String,
     proto: &'static DescriptorProto,
+    file: &'static FileDescriptorProto,
     factory: Box<MessageFactory + 'static>,
     fields: Vec<FieldDescriptor>,
+    oneofs: Vec<OneofDescriptor>,
 
     index_by_name: HashMap<String, usize>,
     index_by_number: HashMap<u32, usize>,
 }
 
+/// Descriptor of message given as trait object.
+///
+/// # Panics
+///
+/// If message is generated without descriptor (e. g. for `LITE_RUNTIME`).
+pub(crate) fn descriptor_dyn(m: &Message) -> &'static MessageDescriptor {
+    m.descriptor_dyn().expect("message is generated without descriptor")
+}
+
 impl MessageDescriptor {
-    pub fn for_type<M : Message>() -> &'static MessageDescriptor {
-        Message::descriptor_static(None::<M>)
+    pub fn for_type<M : MessageFull>() -> &'static MessageDescriptor {
+        M::type_descriptor()
     }
 
     pub fn new<M : 'static + Message + Default>(
         rust_name: &'static str,
         fields: Vec<Box<FieldAccessor + 'static>>,
         file: &'static FileDescriptorProto,
+    ) -> MessageDescriptor {
+        MessageDescriptor::new_with_oneofs::<M>(rust_name, fields, Vec::new(), file)
+    }
+
+    /// Used by generated code of messages with oneofs.
+    pub fn new_with_oneofs<M : 'static + Message + Default>(
+        rust_name: &'static str,
+        fields: Vec<Box<FieldAccessor + 'static>>,
+        oneofs: Vec<Box<OneofAccessor + 'static>>,
+        file: &'static FileDescriptorProto,
     ) -> MessageDescriptor {
         let proto = find_message_by_rust_name(file, rust_name);
+        registry::register_message_type::<M>();
 
         let mut field_proto_by_name = HashMap::new();
         for field_proto in proto.message.get_field() {
@@ -182,9 +281,32 @@ impl MessageDescriptor {
         }
         full_name.push_str(proto.message.get_name());
 
+        let mut oneof_accessors: HashMap<_, _> =
+            oneofs.into_iter().map(|a| (a.name_generic(), a)).collect();
+        let oneofs = proto
+            .message
+            .get_oneof_decl()
+            .iter()
+            .enumerate()
+            .map(|(i, oneof_proto)| OneofDescriptor {
+                proto: oneof_proto,
+                // groups have no accessors
+                field_numbers: proto
+                    .message
+                    .get_field()
+                    .iter()
+                    .filter(|f| f.has_oneof_index() && f.get_oneof_index() as usize == i)
+                    .filter(|f| f.get_field_type() != FieldDescriptorProto_Type::TYPE_GROUP)
+                    .map(|f| f.get_number() as u32)
+                    .collect(),
+                accessor: oneof_accessors.remove(oneof_proto.get_name()),
+            })
+            .collect();
+
         MessageDescriptor {
             full_name: full_name,
             proto: proto.message,
+            file: file,
             factory: Box::new(MessageFactoryTyped::<M>::new()),
             fields: fields
                 .into_iter()
@@ -193,6 +315,7 @@ impl MessageDescriptor {
                     FieldDescriptor::new(f, proto)
                 })
                 .collect(),
+            oneofs: oneofs,
             index_by_name: index_by_name,
             index_by_number: index_by_number,
         }
@@ -210,6 +333,15 @@ impl MessageDescriptor {
         &self.full_name[..]
     }
 
+    pub fn proto(&self) -> &'static DescriptorProto {
+        self.proto
+    }
+
+    /// File in which this message is defined.
+    pub fn file_descriptor_proto(&self) -> &'static FileDescriptorProto {
+        self.file
+    }
+
     pub fn fields<'a>(&'a self) -> &'a [FieldDescriptor] {
         &self.fields
     }
@@ -224,9 +356,62 @@ impl MessageDescriptor {
         let &index = self.index_by_number.get(&number).unwrap();
         &self.fields[index]
     }
+
+    /// Oneofs of this message in declaration order.
+    pub fn oneofs<'a>(&'a self) -> &'a [OneofDescriptor] {
+        &self.oneofs
+    }
+
+    pub fn get_oneof_by_name<'a>(&'a self, name: &str) -> Option<&'a OneofDescriptor> {
+        self.oneofs.iter().find(|o| o.name() == name)
+    }
+
+    /// Field of given oneof which is set in the message, `None` if no field is set.
+    ///
+    /// Panics if message has no oneof with given name.
+    pub fn which_oneof<'a>(&'a self, m: &Message, oneof_name: &str)
+        -> Option<&'a FieldDescriptor>
+    {
+        let oneof = self.get_oneof_by_name(oneof_name).unwrap_or_else(|| {
+            panic!("oneof not found in {}: {}", self.full_name, oneof_name)
+        });
+        let number = match oneof.accessor {
+            Some(ref accessor) => accessor.which_generic(m),
+            // descriptor is created by code generated without oneof accessors
+            None => oneof
+                .field_numbers
+                .iter()
+                .cloned()
+                .find(|&number| self.field_by_number(number).has_field(m)),
+        };
+        number.map(|number| self.field_by_number(number))
+    }
+}
+
+/// Oneof of a message.
+pub struct OneofDescriptor {
+    proto: &'static OneofDescriptorProto,
+    field_numbers: Vec<u32>,
+    accessor: Option<Box<OneofAccessor + 'static>>,
 }
 
-#[derive(Clone)]
+impl OneofDescriptor {
+    pub fn proto(&self) -> &'static OneofDescriptorProto {
+        self.proto
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.proto.get_name()
+    }
+
+    /// Numbers of fields of this oneof, use `MessageDescriptor::field_by_number`
+    /// to get their descriptors.
+    pub fn field_numbers(&self) -> &[u32] {
+        &self.field_numbers
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct EnumValueDescriptor {
     proto: &'static EnumValueDescriptorProto,
 }
@@ -243,6 +428,7 @@ impl EnumValueDescriptor {
     }
 }
 
+/// Reflection information of enum type, `Send + Sync` like `MessageDescriptor`.
 pub struct EnumDescriptor {
     proto: &'static EnumDescriptorProto,
     values: Vec<EnumValueDescriptor>,
@@ -256,8 +442,8 @@ impl EnumDescriptor {
         self.proto.get_name()
     }
 
-    pub fn for_type<E : ProtobufEnum>() -> &'static EnumDescriptor {
-        ProtobufEnum::enum_descriptor_static(None::<E>)
+    pub fn for_type<E : ProtobufEnumFull>() -> &'static EnumDescriptor {
+        E::type_descriptor()
     }
 
     pub fn new(rust_name: &'static str, file: &'static FileDescriptorProto) -> EnumDescriptor {
@@ -281,18 +467,45 @@ impl EnumDescriptor {
         }
     }
 
+    /// Enum values in `.proto` order.
+    pub fn values<'a>(&'a self) -> &'a [EnumValueDescriptor] {
+        &self.values
+    }
+
+    /// Find enum value by name, `None` if enum has no such value.
+    pub fn get_value_by_name<'a>(&'a self, name: &str) -> Option<&'a EnumValueDescriptor> {
+        self.index_by_name.get(name).map(|&index| &self.values[index])
+    }
+
+    /// Find enum value by number, `None` if enum has no such value.
+    pub fn get_value_by_number<'a>(&'a self, number: i32) -> Option<&'a EnumValueDescriptor> {
+        self.index_by_number.get(&number).map(|&index| &self.values[index])
+    }
+
+    /// Find enum value by name, panic if enum has no such value.
     pub fn value_by_name<'a>(&'a self, name: &str) -> &'a EnumValueDescriptor {
-        // TODO: clone is weird
-        let &index = self.index_by_name.get(&name.to_string()).unwrap();
-        &self.values[index]
+        self.get_value_by_name(name)
+            .unwrap_or_else(|| panic!("enum {} has no value {}", self.name(), name))
     }
 
+    /// Find enum value by number, panic if enum has no such value.
     pub fn value_by_number<'a>(&'a self, number: i32) -> &'a EnumValueDescriptor {
-        let &index = self.index_by_number.get(&number).unwrap();
-        &self.values[index]
+        self.get_value_by_number(number)
+            .unwrap_or_else(|| panic!("enum {} has no value {}", self.name(), number))
     }
 }
 
+// descriptors are shared as `&'static` references by multithreaded code,
+// adding a field which is not `Send + Sync` must fail compilation
+#[allow(dead_code)]
+fn assert_descriptors_send_sync() {
+    fn check<T : Send + Sync + 'static>() {}
+    check::<MessageDescriptor>();
+    check::<FieldDescriptor>();
+    check::<EnumDescriptor>();
+    check::<EnumValueDescriptor>();
+}
+
 
 pub enum ReflectFieldRef<'a> {
     Repeated(&'a ReflectRepeated),
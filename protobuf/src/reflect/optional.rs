@@ -2,6 +2,8 @@ use std::mem;
 
 use super::value::ProtobufValue;
 
+use core::Message;
+use lazy_message::LazyMessageField;
 use singular::*;
 
 pub trait ReflectOptional: 'static {
@@ -48,3 +50,48 @@ impl<V : ProtobufValue + Clone + 'static> ReflectOptional for SingularPtrField<V
         };
     }
 }
+
+impl<M : Message + ProtobufValue + Clone + 'static> ReflectOptional for LazyMessageField<M> {
+    fn to_option(&self) -> Option<&ProtobufValue> {
+        self.as_ref().map(|v| v as &ProtobufValue)
+    }
+
+    fn set_value(&mut self, value: &ProtobufValue) {
+        match value.as_any().downcast_ref::<M>() {
+            Some(v) => mem::replace(self, LazyMessageField::some(v.clone())),
+            None => panic!(),
+        };
+    }
+}
+
+/// `Option<Box<M>>` field viewed as `ReflectOptional`.
+///
+/// `ReflectOptional` cannot be implemented for `Option<Box<M>>`,
+/// because that would conflict with implementation for `Option<V>`,
+/// so references to fields are cast to references to this wrapper.
+#[repr(transparent)]
+pub struct ReflectOptionBox<M>(Option<Box<M>>);
+
+impl<M> ReflectOptionBox<M> {
+    pub fn from_ref(field: &Option<Box<M>>) -> &ReflectOptionBox<M> {
+        // safe because of `repr(transparent)`
+        unsafe { &*(field as *const Option<Box<M>> as *const ReflectOptionBox<M>) }
+    }
+
+    pub fn from_mut(field: &mut Option<Box<M>>) -> &mut ReflectOptionBox<M> {
+        unsafe { &mut *(field as *mut Option<Box<M>> as *mut ReflectOptionBox<M>) }
+    }
+}
+
+impl<M : Message + ProtobufValue + Clone + 'static> ReflectOptional for ReflectOptionBox<M> {
+    fn to_option(&self) -> Option<&ProtobufValue> {
+        self.0.as_ref().map(|v| &**v as &ProtobufValue)
+    }
+
+    fn set_value(&mut self, value: &ProtobufValue) {
+        match value.as_any().downcast_ref::<M>() {
+            Some(v) => self.0 = Some(Box::new(v.clone())),
+            None => panic!(),
+        };
+    }
+}
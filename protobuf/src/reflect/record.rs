@@ -0,0 +1,283 @@
+//! Conversion of messages to and from records: maps from field name to value.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::ptr;
+
+use core::Message;
+use descriptor::DescriptorProto;
+use descriptor::FieldDescriptorProto;
+use descriptor::FieldDescriptorProto_Label;
+use descriptor::FieldDescriptorProto_Type;
+use descriptorx::strip_leading_dot;
+use error::ProtobufError;
+use stream::CodedOutputStream;
+use stream::wire_format;
+
+use super::EnumDescriptor;
+use super::EnumValueDescriptor;
+use super::FieldDescriptor;
+use super::descriptor_dyn;
+use super::MessageDescriptor;
+use super::ProtobufValueBox;
+use super::ProtobufValueRef;
+use super::ReflectFieldRef;
+
+
+/// Loose form of a message: values of set fields by field name.
+///
+/// Repeated fields are stored as `ProtobufValueBox::Repeated`,
+/// map fields as `ProtobufValueBox::Map`, nested messages as
+/// `ProtobufValueBox::Record`.
+pub type Record = HashMap<String, ProtobufValueBox>;
+
+/// Error returned when record cannot be converted to message.
+#[derive(Debug)]
+pub enum RecordError {
+    /// Message has no field with given name
+    UnknownField(String),
+    /// Value type does not match type of the field with given name
+    TypeMismatch(String),
+    /// Serialized record could not be parsed as the message
+    Protobuf(ProtobufError),
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RecordError::UnknownField(ref name) => write!(f, "unknown field: {}", name),
+            RecordError::TypeMismatch(ref name) => write!(f, "type mismatch for field: {}", name),
+            RecordError::Protobuf(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for RecordError {
+    fn description(&self) -> &str {
+        match *self {
+            RecordError::UnknownField(..) => "unknown field",
+            RecordError::TypeMismatch(..) => "type mismatch",
+            RecordError::Protobuf(ref e) => e.description(),
+        }
+    }
+}
+
+impl From<ProtobufError> for RecordError {
+    fn from(e: ProtobufError) -> RecordError {
+        RecordError::Protobuf(e)
+    }
+}
+
+/// Convert message to record.
+///
+/// Unset singular fields (and proto3 fields with default values),
+/// empty repeated and map fields are not included. Nested messages
+/// (including elements of repeated fields and map values) are converted to records.
+///
+/// # Panics
+///
+/// If message is generated without reflection (e. g. for `LITE_RUNTIME`).
+pub fn to_record(m: &Message) -> Record {
+    record_of(m, true)
+}
+
+// like `to_record`, but nested messages are copied as `ProtobufValueBox::Message`
+pub(crate) fn to_record_shallow(m: &Message) -> Record {
+    record_of(m, false)
+}
+
+fn record_of(m: &Message, nested_records: bool) -> Record {
+    let value_box = |v: ProtobufValueRef| match v {
+        ProtobufValueRef::Message(m) if nested_records => ProtobufValueBox::Record(to_record(m)),
+        v => v.to_box(),
+    };
+    let mut record = Record::new();
+    for field in descriptor_dyn(m).fields() {
+        let value = match field.get_reflect(m) {
+            ReflectFieldRef::Optional(Some(v)) => value_box(v),
+            ReflectFieldRef::Optional(None) => continue,
+            ReflectFieldRef::Repeated(repeated) => {
+                if repeated.len() == 0 {
+                    continue;
+                }
+                ProtobufValueBox::Repeated(
+                    repeated.reflect_iter().map(|v| value_box(v.as_ref())).collect(),
+                )
+            }
+            ReflectFieldRef::Map(map) => {
+                if map.len() == 0 {
+                    continue;
+                }
+                ProtobufValueBox::Map(
+                    map.reflect_iter()
+                        .map(|(k, v)| (k.as_ref().to_box(), value_box(v.as_ref())))
+                        .collect(),
+                )
+            }
+        };
+        record.insert(field.name().to_owned(), value);
+    }
+    record
+}
+
+/// Update message with fields of the record.
+///
+/// Like in `merge_from_bytes`, singular fields are overwritten,
+/// repeated and map fields are appended, nested messages are merged.
+/// Enum fields accept `ProtobufValueBox::I32` in addition to `Enum` of the field's enum type.
+/// Message fields accept both `ProtobufValueBox::Record` and `Message` of the field's type.
+pub fn merge_from_record(m: &mut Message, record: &Record) -> Result<(), RecordError> {
+    let descriptor = descriptor_dyn(m);
+    // serialize record and parse it, so no reflective setters are needed
+    let mut bytes = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        for (name, value) in record {
+            let field = match descriptor.fields().iter().find(|f| f.name() == name) {
+                Some(field) => field,
+                None => return Err(RecordError::UnknownField(name.clone())),
+            };
+            write_field(&mut os, descriptor, field, value)?;
+        }
+        os.flush()?;
+    }
+    m.merge_from_bytes(&bytes)?;
+    Ok(())
+}
+
+/// Convert record to message.
+pub fn from_record<M : Message>(record: &Record) -> Result<M, RecordError> {
+    let mut m = M::new();
+    merge_from_record(&mut m, record)?;
+    Ok(m)
+}
+
+// map entry message of the field, `None` if field is not a map
+pub(crate) fn map_entry(
+    message: &MessageDescriptor,
+    field: &FieldDescriptor,
+) -> Option<&'static DescriptorProto> {
+    let proto = field.proto();
+    if proto.get_label() != FieldDescriptorProto_Label::LABEL_REPEATED ||
+        proto.get_field_type() != FieldDescriptorProto_Type::TYPE_MESSAGE
+    {
+        return None;
+    }
+    // map entry is always nested in the message
+    let entry_name = proto.get_type_name().rsplit('.').next().unwrap();
+    message
+        .proto
+        .get_nested_type()
+        .iter()
+        .find(|m| m.get_name() == entry_name && m.get_options().get_map_entry())
+}
+
+fn write_field(
+    os: &mut CodedOutputStream,
+    message: &MessageDescriptor,
+    field: &FieldDescriptor,
+    value: &ProtobufValueBox,
+) -> Result<(), RecordError> {
+    let proto = field.proto();
+    let number = proto.get_number() as u32;
+    let repeated = proto.get_label() == FieldDescriptorProto_Label::LABEL_REPEATED;
+    match (map_entry(message, field), value) {
+        (Some(entry), &ProtobufValueBox::Map(ref entries)) => {
+            let key = entry.get_field().iter().find(|f| f.get_number() == 1).unwrap();
+            let value = entry.get_field().iter().find(|f| f.get_number() == 2).unwrap();
+            for &(ref k, ref v) in entries {
+                let mut entry_bytes = Vec::new();
+                {
+                    let mut entry_os = CodedOutputStream::vec(&mut entry_bytes);
+                    write_value(&mut entry_os, proto.get_name(), key, Some(field), k)?;
+                    write_value(&mut entry_os, proto.get_name(), value, Some(field), v)?;
+                    entry_os.flush()?;
+                }
+                os.write_bytes(number, &entry_bytes)?;
+            }
+            Ok(())
+        }
+        (None, &ProtobufValueBox::Repeated(ref values)) if repeated => {
+            // parsers accept unpacked elements of packed fields
+            for v in values {
+                write_value(os, proto.get_name(), proto, Some(field), v)?;
+            }
+            Ok(())
+        }
+        (None, &ProtobufValueBox::Repeated(..)) |
+        (None, &ProtobufValueBox::Map(..)) |
+        (Some(..), _) => Err(RecordError::TypeMismatch(proto.get_name().to_owned())),
+        (None, v) if !repeated => write_value(os, proto.get_name(), proto, Some(field), v),
+        (None, _) => Err(RecordError::TypeMismatch(proto.get_name().to_owned())),
+    }
+}
+
+// value is of the enum type, not just of an enum with the same number
+fn is_value_of(e: &EnumDescriptor, value: &EnumValueDescriptor) -> bool {
+    e.values().iter().any(|v| ptr::eq(v.proto, value.proto))
+}
+
+fn write_message(os: &mut CodedOutputStream, number: u32, m: &Message) -> Result<(), RecordError> {
+    os.write_tag(number, wire_format::WireTypeLengthDelimited)?;
+    os.write_raw_varint32(m.compute_size())?;
+    m.write_to_with_cached_sizes(os)?;
+    Ok(())
+}
+
+// write value of field or map entry field described by `proto`,
+// `field_name` is name of record field reported in error;
+// enum and message values are checked against types of `field` (or its map values)
+pub(crate) fn write_value(
+    os: &mut CodedOutputStream,
+    field_name: &str,
+    proto: &FieldDescriptorProto,
+    field: Option<&FieldDescriptor>,
+    value: &ProtobufValueBox,
+) -> Result<(), RecordError> {
+    use descriptor::FieldDescriptorProto_Type::*;
+
+    // `None` if unknown, then values are not checked
+    let enum_descriptor = field.and_then(|f| f.enum_descriptor());
+    let message_descriptor = field.and_then(|f| f.message_descriptor());
+    let number = proto.get_number() as u32;
+    match (proto.get_field_type(), value) {
+        (TYPE_DOUBLE, &ProtobufValueBox::F64(v)) => os.write_double(number, v)?,
+        (TYPE_FLOAT, &ProtobufValueBox::F32(v)) => os.write_float(number, v)?,
+        (TYPE_INT64, &ProtobufValueBox::I64(v)) => os.write_int64(number, v)?,
+        (TYPE_UINT64, &ProtobufValueBox::U64(v)) => os.write_uint64(number, v)?,
+        (TYPE_INT32, &ProtobufValueBox::I32(v)) => os.write_int32(number, v)?,
+        (TYPE_FIXED64, &ProtobufValueBox::U64(v)) => os.write_fixed64(number, v)?,
+        (TYPE_FIXED32, &ProtobufValueBox::U32(v)) => os.write_fixed32(number, v)?,
+        (TYPE_BOOL, &ProtobufValueBox::Bool(v)) => os.write_bool(number, v)?,
+        (TYPE_STRING, &ProtobufValueBox::String(ref v)) => os.write_string(number, v)?,
+        (TYPE_BYTES, &ProtobufValueBox::Bytes(ref v)) => os.write_bytes(number, v)?,
+        (TYPE_UINT32, &ProtobufValueBox::U32(v)) => os.write_uint32(number, v)?,
+        (TYPE_ENUM, &ProtobufValueBox::Enum(v))
+            if enum_descriptor.map_or(true, |e| is_value_of(e, v)) =>
+        {
+            os.write_enum(number, v.value())?
+        }
+        (TYPE_ENUM, &ProtobufValueBox::I32(v)) => os.write_enum(number, v)?,
+        (TYPE_SFIXED32, &ProtobufValueBox::I32(v)) => os.write_sfixed32(number, v)?,
+        (TYPE_SFIXED64, &ProtobufValueBox::I64(v)) => os.write_sfixed64(number, v)?,
+        (TYPE_SINT32, &ProtobufValueBox::I32(v)) => os.write_sint32(number, v)?,
+        (TYPE_SINT64, &ProtobufValueBox::I64(v)) => os.write_sint64(number, v)?,
+        (TYPE_MESSAGE, &ProtobufValueBox::Message(ref m))
+            if m.descriptor_dyn().map(|d| d.full_name())
+                == Some(strip_leading_dot(proto.get_type_name())) =>
+        {
+            write_message(os, number, &**m)?
+        }
+        (TYPE_MESSAGE, &ProtobufValueBox::Record(ref record)) => match message_descriptor {
+            Some(descriptor) => {
+                let mut m = descriptor.new_instance();
+                merge_from_record(&mut *m, record)?;
+                write_message(os, number, &*m)?
+            }
+            None => return Err(RecordError::TypeMismatch(field_name.to_owned())),
+        },
+        _ => return Err(RecordError::TypeMismatch(field_name.to_owned())),
+    }
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Descriptors of messages used in the program.
+//!
+//! Message type is registered when its descriptor is created, which happens
+//! on first call of `MessageFull::type_descriptor`, `MessageDescriptor::for_type` and similar
+//! functions. `register_message` does that explicitly, so tools like
+//! reflection servers can register types they serve at startup
+//! and then list them with `all_registered_messages`.
+
+use std::sync::Mutex;
+
+use core::Message;
+use core::MessageFull;
+use reflect::MessageDescriptor;
+
+
+// `descriptor_static` functions of registered message types;
+// descriptor cannot be stored here, because type is registered
+// while its descriptor is being initialized
+static MESSAGES: Mutex<Vec<fn() -> &'static MessageDescriptor>> = Mutex::new(Vec::new());
+
+pub(crate) fn register_message_type<M : Message>() {
+    fn descriptor<M : Message>() -> &'static MessageDescriptor {
+        M::default_instance().descriptor_dyn().expect("registered message has descriptor")
+    }
+    MESSAGES.lock().unwrap_or_else(|e| e.into_inner()).push(descriptor::<M>);
+}
+
+/// Register message type, so it is returned by `all_registered_messages`.
+pub fn register_message<M : MessageFull>() -> &'static MessageDescriptor {
+    MessageDescriptor::for_type::<M>()
+}
+
+/// Descriptors of all registered messages in order of registration.
+pub fn all_registered_messages() -> Vec<&'static MessageDescriptor> {
+    // release the lock before calling the functions,
+    // which may wait for initialization registering another type
+    let functions = MESSAGES.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    let mut r: Vec<&'static MessageDescriptor> = Vec::with_capacity(functions.len());
+    for f in functions {
+        let d = f();
+        // descriptor created with `MessageDescriptor::new` outside of generated code
+        // registers the same type again
+        if !r.iter().any(|&p| p as *const MessageDescriptor == d as *const MessageDescriptor) {
+            r.push(d);
+        }
+    }
+    r
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::thread;
+
+    use super::*;
+    use descriptor::FileDescriptorSet;
+    use well_known_types::Duration;
+    use well_known_types::Timestamp;
+
+    fn registered(full_name: &str) -> bool {
+        all_registered_messages().iter().any(|d| d.full_name() == full_name)
+    }
+
+    #[test]
+    fn register() {
+        assert_eq!("FileDescriptorSet", register_message::<FileDescriptorSet>().name());
+        assert!(registered("google.protobuf.FileDescriptorSet"));
+        register_message::<FileDescriptorSet>();
+        let count = all_registered_messages()
+            .iter()
+            .filter(|d| d.full_name() == "google.protobuf.FileDescriptorSet")
+            .count();
+        assert_eq!(1, count);
+    }
+
+    #[test]
+    fn share_between_threads() {
+        let descriptors: Vec<&'static MessageDescriptor> = thread::spawn(|| {
+            vec![register_message::<Duration>(), MessageDescriptor::for_type::<Timestamp>()]
+        }).join().unwrap();
+        assert_eq!("Duration", descriptors[0].name());
+        assert!(registered("google.protobuf.Duration"));
+        assert!(registered("google.protobuf.Timestamp"));
+    }
+}
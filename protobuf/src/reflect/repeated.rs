@@ -1,5 +1,10 @@
 use std::slice;
 
+#[cfg(feature = "smallvec")]
+use smallvec::Array;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+
 use super::value::ProtobufValue;
 use super::value::ProtobufValueRef;
 
@@ -60,6 +65,27 @@ impl<V : ProtobufValue + 'static> ReflectRepeated for RepeatedField<V> {
     }
 }
 
+#[cfg(feature = "smallvec")]
+impl<A> ReflectRepeated for SmallVec<A>
+where
+    A : Array + 'static,
+    A::Item : ProtobufValue + 'static,
+{
+    fn reflect_iter<'a>(&'a self) -> ReflectRepeatedIter<'a> {
+        ReflectRepeatedIter {
+            imp: Box::new(ReflectRepeatedIterImplSlice::<'a, A::Item> { iter: self.iter() }),
+        }
+    }
+
+    fn len(&self) -> usize {
+        SmallVec::len(self)
+    }
+
+    fn get(&self, index: usize) -> &ProtobufValue {
+        &self[index]
+    }
+}
+
 trait ReflectRepeatedIterTrait<'a> {
     fn next(&mut self) -> Option<&'a ProtobufValue>;
 }
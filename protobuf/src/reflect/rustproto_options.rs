@@ -0,0 +1,334 @@
+//! `rustproto` options of descriptors, as seen by code generator.
+
+use std::ptr;
+
+use descriptor::DescriptorProto;
+use descriptor::FieldDescriptorProto;
+use descriptor::FileDescriptorProto;
+use rustproto;
+
+use super::FieldDescriptor;
+use super::MessageDescriptor;
+
+
+/// `rustproto` options which affect generated code of a message or a field.
+///
+/// Options are resolved the same way as in code generator: file options
+/// (e. g. `expose_fields_all`) are overridden by options of enclosing messages,
+/// which are overridden by message options, which are overridden by field options.
+/// `None` means option is not specified in `.proto` file.
+///
+/// Options passed to code generator invocation (`Customize` in build script)
+/// are not stored in descriptors, so they cannot be reported.
+///
+/// Tooling can compare options of messages from different crates
+/// to detect code generated with different options, which has different API.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct RustprotoOptions {
+    pub expose_oneof: Option<bool>,
+    pub expose_fields: Option<bool>,
+    pub generate_accessors: Option<bool>,
+    pub carllerche_bytes_for_bytes: Option<bool>,
+    pub carllerche_bytes_for_string: Option<bool>,
+    pub lazy_field: Option<bool>,
+    pub option_box_for_messages: Option<bool>,
+    pub ref_accessors_only: Option<bool>,
+    pub repeated_field_vec: Option<bool>,
+    pub smallvec_for_repeated: Option<u32>,
+    pub json_names: Option<bool>,
+    pub generate_display: Option<bool>,
+    pub has_bits: Option<bool>,
+    /// Only specified for file.
+    pub generate_visitor: Option<bool>,
+    pub generate_into_bytes: Option<bool>,
+    pub generate_arbitrary: Option<bool>,
+    pub generate_proptest: Option<bool>,
+    /// Only specified for field.
+    pub redact: Option<bool>,
+    /// Only specified for field.
+    pub checked_as: Option<bool>,
+    /// Only specified for field.
+    pub rust_field_type: Option<String>,
+    /// Only specified for field.
+    pub rust_name: Option<String>,
+}
+
+impl RustprotoOptions {
+    /// Options specified for all messages of the file.
+    pub fn for_file(file: &FileDescriptorProto) -> RustprotoOptions {
+        let source = file.get_options();
+        RustprotoOptions {
+            expose_oneof: rustproto::exts::expose_oneof_all.get(source),
+            expose_fields: rustproto::exts::expose_fields_all.get(source),
+            generate_accessors: rustproto::exts::generate_accessors_all.get(source),
+            carllerche_bytes_for_bytes: rustproto::exts::carllerche_bytes_for_bytes_all.get(source),
+            carllerche_bytes_for_string: rustproto::exts::carllerche_bytes_for_string_all
+                .get(source),
+            lazy_field: rustproto::exts::lazy_field_all.get(source),
+            option_box_for_messages: rustproto::exts::option_box_for_messages_all
+                .get(source),
+            ref_accessors_only: rustproto::exts::ref_accessors_only_all.get(source),
+            repeated_field_vec: rustproto::exts::repeated_field_vec_all.get(source),
+            smallvec_for_repeated: rustproto::exts::smallvec_for_repeated_all.get(source),
+            json_names: rustproto::exts::json_names_all.get(source),
+            generate_display: rustproto::exts::generate_display_all.get(source),
+            has_bits: rustproto::exts::has_bits_all.get(source),
+            generate_visitor: rustproto::exts::generate_visitor_all.get(source),
+            generate_into_bytes: rustproto::exts::generate_into_bytes_all.get(source),
+            generate_arbitrary: rustproto::exts::generate_arbitrary_all.get(source),
+            generate_proptest: rustproto::exts::generate_proptest_all.get(source),
+            redact: None,
+            checked_as: None,
+            rust_field_type: None,
+            rust_name: None,
+        }
+    }
+
+    /// Options of message defined in the file.
+    ///
+    /// # Panics
+    ///
+    /// If message is not a part of the file (compared by address).
+    pub fn for_message_proto(
+        file: &FileDescriptorProto,
+        message: &DescriptorProto,
+    ) -> RustprotoOptions {
+        let mut path = Vec::new();
+        assert!(
+            find_message_path(file.get_message_type(), message, &mut path),
+            "message {} is not found in file {}",
+            message.get_name(),
+            file.get_name()
+        );
+
+        let mut r = RustprotoOptions::for_file(file);
+        for m in path {
+            r.update_with(&RustprotoOptions::from_message_options(m));
+        }
+        r
+    }
+
+    /// Options of field of message defined in the file.
+    ///
+    /// # Panics
+    ///
+    /// If message is not a part of the file (compared by address).
+    pub fn for_field_proto(
+        file: &FileDescriptorProto,
+        message: &DescriptorProto,
+        field: &FieldDescriptorProto,
+    ) -> RustprotoOptions {
+        let mut r = RustprotoOptions::for_message_proto(file, message);
+        r.update_with(&RustprotoOptions::from_field_options(field));
+        r
+    }
+
+    /// Options of generated message.
+    pub fn for_message(message: &MessageDescriptor) -> RustprotoOptions {
+        RustprotoOptions::for_message_proto(message.file, message.proto)
+    }
+
+    /// Options of field of generated message.
+    pub fn for_field(message: &MessageDescriptor, field: &FieldDescriptor) -> RustprotoOptions {
+        RustprotoOptions::for_field_proto(message.file, message.proto, field.proto)
+    }
+
+    fn from_message_options(message: &DescriptorProto) -> RustprotoOptions {
+        let source = message.get_options();
+        RustprotoOptions {
+            expose_oneof: rustproto::exts::expose_oneof.get(source),
+            expose_fields: rustproto::exts::expose_fields.get(source),
+            generate_accessors: rustproto::exts::generate_accessors.get(source),
+            carllerche_bytes_for_bytes: rustproto::exts::carllerche_bytes_for_bytes.get(source),
+            carllerche_bytes_for_string: rustproto::exts::carllerche_bytes_for_string.get(source),
+            lazy_field: rustproto::exts::lazy_field.get(source),
+            option_box_for_messages: rustproto::exts::option_box_for_messages
+                .get(source),
+            ref_accessors_only: rustproto::exts::ref_accessors_only.get(source),
+            repeated_field_vec: rustproto::exts::repeated_field_vec.get(source),
+            smallvec_for_repeated: rustproto::exts::smallvec_for_repeated.get(source),
+            json_names: rustproto::exts::json_names.get(source),
+            generate_display: rustproto::exts::generate_display.get(source),
+            has_bits: rustproto::exts::has_bits.get(source),
+            generate_visitor: None,
+            generate_into_bytes: rustproto::exts::generate_into_bytes.get(source),
+            generate_arbitrary: rustproto::exts::generate_arbitrary.get(source),
+            generate_proptest: rustproto::exts::generate_proptest.get(source),
+            redact: None,
+            checked_as: None,
+            rust_field_type: None,
+            rust_name: None,
+        }
+    }
+
+    fn from_field_options(field: &FieldDescriptorProto) -> RustprotoOptions {
+        let source = field.get_options();
+        RustprotoOptions {
+            expose_oneof: None,
+            expose_fields: rustproto::exts::expose_fields_field.get(source),
+            generate_accessors: rustproto::exts::generate_accessors_field.get(source),
+            carllerche_bytes_for_bytes: rustproto::exts::carllerche_bytes_for_bytes_field
+                .get(source),
+            carllerche_bytes_for_string: rustproto::exts::carllerche_bytes_for_string_field
+                .get(source),
+            lazy_field: rustproto::exts::lazy_field_field.get(source),
+            option_box_for_messages: rustproto::exts::option_box_for_messages_field
+                .get(source),
+            ref_accessors_only: rustproto::exts::ref_accessors_only_field.get(source),
+            repeated_field_vec: rustproto::exts::repeated_field_vec_field.get(source),
+            smallvec_for_repeated: rustproto::exts::smallvec_for_repeated_field.get(source),
+            json_names: None,
+            generate_display: None,
+            has_bits: None,
+            generate_visitor: None,
+            generate_into_bytes: None,
+            generate_arbitrary: None,
+            generate_proptest: None,
+            redact: rustproto::exts::redact.get(source),
+            checked_as: rustproto::exts::checked_as.get(source),
+            rust_field_type: rustproto::exts::rust_field_type.get(source),
+            rust_name: rustproto::exts::rust_name.get(source),
+        }
+    }
+
+    /// Update fields of self with fields defined in other options.
+    pub fn update_with(&mut self, that: &RustprotoOptions) {
+        if let Some(v) = that.expose_oneof {
+            self.expose_oneof = Some(v);
+        }
+        if let Some(v) = that.expose_fields {
+            self.expose_fields = Some(v);
+        }
+        if let Some(v) = that.generate_accessors {
+            self.generate_accessors = Some(v);
+        }
+        if let Some(v) = that.carllerche_bytes_for_bytes {
+            self.carllerche_bytes_for_bytes = Some(v);
+        }
+        if let Some(v) = that.carllerche_bytes_for_string {
+            self.carllerche_bytes_for_string = Some(v);
+        }
+        if let Some(v) = that.lazy_field {
+            self.lazy_field = Some(v);
+        }
+        if let Some(v) = that.option_box_for_messages {
+            self.option_box_for_messages = Some(v);
+        }
+        if let Some(v) = that.ref_accessors_only {
+            self.ref_accessors_only = Some(v);
+        }
+        if let Some(v) = that.repeated_field_vec {
+            self.repeated_field_vec = Some(v);
+        }
+        if let Some(v) = that.smallvec_for_repeated {
+            self.smallvec_for_repeated = Some(v);
+        }
+        if let Some(v) = that.json_names {
+            self.json_names = Some(v);
+        }
+        if let Some(v) = that.generate_display {
+            self.generate_display = Some(v);
+        }
+        if let Some(v) = that.has_bits {
+            self.has_bits = Some(v);
+        }
+        if let Some(v) = that.generate_visitor {
+            self.generate_visitor = Some(v);
+        }
+        if let Some(v) = that.generate_into_bytes {
+            self.generate_into_bytes = Some(v);
+        }
+        if let Some(v) = that.generate_arbitrary {
+            self.generate_arbitrary = Some(v);
+        }
+        if let Some(v) = that.generate_proptest {
+            self.generate_proptest = Some(v);
+        }
+        if let Some(v) = that.redact {
+            self.redact = Some(v);
+        }
+        if let Some(v) = that.checked_as {
+            self.checked_as = Some(v);
+        }
+        if let Some(ref v) = that.rust_field_type {
+            self.rust_field_type = Some(v.clone());
+        }
+        if let Some(ref v) = that.rust_name {
+            self.rust_name = Some(v.clone());
+        }
+    }
+}
+
+// messages from top-level to `target` inclusive
+fn find_message_path<'a>(
+    messages: &'a [DescriptorProto],
+    target: &DescriptorProto,
+    path: &mut Vec<&'a DescriptorProto>,
+) -> bool {
+    for m in messages {
+        path.push(m);
+        if ptr::eq(m, target) || find_message_path(m.get_nested_type(), target, path) {
+            return true;
+        }
+        path.pop();
+    }
+    false
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::Message;
+    use descriptor;
+    use descriptor::FieldDescriptorProto_Type;
+
+    #[test]
+    fn all_extensions() {
+        let extensions = rustproto::file_descriptor_proto().get_extension();
+        assert!(!extensions.is_empty());
+        for ext in extensions {
+            let mut file = FileDescriptorProto::new();
+            file.mut_message_type().push(DescriptorProto::new());
+            file.mut_message_type()[0].mut_field().push(FieldDescriptorProto::new());
+
+            let options = match ext.get_extendee() {
+                ".google.protobuf.FileOptions" => file.mut_options().mut_unknown_fields(),
+                ".google.protobuf.MessageOptions" => {
+                    file.mut_message_type()[0].mut_options().mut_unknown_fields()
+                }
+                ".google.protobuf.FieldOptions" => {
+                    file.mut_message_type()[0].mut_field()[0].mut_options().mut_unknown_fields()
+                }
+                e => panic!("{}: {}", ext.get_name(), e),
+            };
+            let number = ext.get_number() as u32;
+            match ext.get_field_type() {
+                FieldDescriptorProto_Type::TYPE_BOOL => options.add_varint(number, 1),
+                FieldDescriptorProto_Type::TYPE_UINT32 => options.add_varint(number, 5),
+                FieldDescriptorProto_Type::TYPE_STRING => {
+                    options.add_length_delimited(number, b"x".to_vec())
+                }
+                t => panic!("{}: {:?}", ext.get_name(), t),
+            }
+
+            let message = &file.get_message_type()[0];
+            assert_ne!(
+                RustprotoOptions::default(),
+                RustprotoOptions::for_field_proto(&file, message, &message.get_field()[0]),
+                "option {} is not reported",
+                ext.get_name()
+            );
+        }
+    }
+
+    #[test]
+    fn generated() {
+        let message = MessageDescriptor::for_type::<descriptor::FileDescriptorProto>();
+        assert_eq!(RustprotoOptions::default(), RustprotoOptions::for_message(message));
+        assert_eq!(
+            RustprotoOptions::default(),
+            RustprotoOptions::for_field(message, message.field_by_name("name"))
+        );
+    }
+}
@@ -0,0 +1,213 @@
+//! Expansion of `${VAR}` placeholders in string fields of messages.
+//!
+//! Configuration messages often contain values which depend on environment:
+//!
+//! ```ignore
+//! let mut config: Config = text_format::parse_from_str(&text)?;
+//! // `data_dir: "${HOME}/data"` becomes `data_dir: "/home/user/data"`
+//! expand_placeholders_with(&mut config, |name| env::var(name).ok(), true)?;
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use core::Message;
+
+use super::ProtobufValueBox;
+use super::descriptor_dyn;
+use super::record::merge_from_record;
+use super::record::to_record_shallow;
+
+
+/// Error returned when placeholders cannot be expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateError {
+    /// Variable is not defined
+    UndefinedVariable {
+        /// Path of the field, e. g. `options.java_package`
+        field: String,
+        name: String,
+    },
+    /// `${` without closing `}`
+    UnclosedPlaceholder {
+        /// Path of the field
+        field: String,
+    },
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TemplateError::UndefinedVariable { ref field, ref name } => {
+                write!(f, "undefined variable {} in field {}", name, field)
+            }
+            TemplateError::UnclosedPlaceholder { ref field } => {
+                write!(f, "unclosed placeholder in field {}", field)
+            }
+        }
+    }
+}
+
+impl Error for TemplateError {
+    fn description(&self) -> &str {
+        match *self {
+            TemplateError::UndefinedVariable { .. } => "undefined variable",
+            TemplateError::UnclosedPlaceholder { .. } => "unclosed placeholder",
+        }
+    }
+}
+
+// expanded string, `None` if string contains no placeholders
+fn expand_str<F>(s: &str, lookup: &F, field: &str) -> Result<Option<String>, TemplateError>
+where
+    F : Fn(&str) -> Option<String>,
+{
+    if !s.contains('$') {
+        return Ok(None);
+    }
+    let mut r = String::new();
+    let mut rem = s;
+    while let Some(pos) = rem.find('$') {
+        r.push_str(&rem[..pos]);
+        rem = &rem[pos..];
+        if rem.starts_with("$$") {
+            r.push('$');
+            rem = &rem[2..];
+        } else if rem.starts_with("${") {
+            let end = match rem.find('}') {
+                Some(end) => end,
+                None => {
+                    return Err(TemplateError::UnclosedPlaceholder {
+                        field: field.to_owned(),
+                    })
+                }
+            };
+            let name = &rem[2..end];
+            match lookup(name) {
+                Some(value) => r.push_str(&value),
+                None => {
+                    return Err(TemplateError::UndefinedVariable {
+                        field: field.to_owned(),
+                        name: name.to_owned(),
+                    })
+                }
+            }
+            rem = &rem[end + 1..];
+        } else {
+            r.push('$');
+            rem = &rem[1..];
+        }
+    }
+    r.push_str(rem);
+    Ok(Some(r))
+}
+
+// returns `true` if value is changed
+fn expand_value<F>(value: &mut ProtobufValueBox, lookup: &F, nested: bool, field: &str)
+    -> Result<bool, TemplateError>
+where
+    F : Fn(&str) -> Option<String>,
+{
+    match *value {
+        ProtobufValueBox::String(ref mut s) => match expand_str(s, lookup, field)? {
+            Some(expanded) => {
+                *s = expanded;
+                Ok(true)
+            }
+            None => Ok(false),
+        },
+        ProtobufValueBox::Message(ref mut m) if nested => {
+            expand_message(&mut **m, lookup, nested, field)
+        }
+        ProtobufValueBox::Repeated(ref mut values) => {
+            let mut changed = false;
+            for v in values {
+                changed |= expand_value(v, lookup, nested, field)?;
+            }
+            Ok(changed)
+        }
+        ProtobufValueBox::Map(ref mut entries) => {
+            let mut changed = false;
+            for &mut (_, ref mut v) in entries {
+                changed |= expand_value(v, lookup, nested, field)?;
+            }
+            Ok(changed)
+        }
+        _ => Ok(false),
+    }
+}
+
+fn expand_message<F>(m: &mut Message, lookup: &F, nested: bool, path: &str)
+    -> Result<bool, TemplateError>
+where
+    F : Fn(&str) -> Option<String>,
+{
+    let mut record = to_record_shallow(m);
+    let mut changed = false;
+    // fields are visited in declaration order, so the same error is reported each time
+    for field in descriptor_dyn(m).fields() {
+        if let Some(value) = record.get_mut(field.name()) {
+            let field_path = match path {
+                "" => field.name().to_owned(),
+                path => format!("{}.{}", path, field.name()),
+            };
+            changed |= expand_value(value, lookup, nested, &field_path)?;
+        }
+    }
+    if changed {
+        // record contains all set fields, so message is rebuilt from it
+        let unknown_fields = m.get_unknown_fields().clone();
+        m.clear();
+        merge_from_record(m, &record).expect("record of the same message");
+        *m.mut_unknown_fields() = unknown_fields;
+    }
+    Ok(changed)
+}
+
+/// Replace `${NAME}` placeholders in string fields of the message
+/// with values returned by `lookup`.
+///
+/// `$$` is replaced with `$`, `$` not followed by `{` or `$` is kept as is.
+/// Elements of repeated fields and values of map fields are expanded,
+/// map keys and `bytes` fields are not. Nested messages are expanded
+/// only if `nested` is `true`.
+///
+/// Message is not modified if some placeholder cannot be expanded.
+///
+/// # Panics
+///
+/// If message is generated without reflection (e. g. for `LITE_RUNTIME`).
+pub fn expand_placeholders_with<F>(m: &mut Message, lookup: F, nested: bool)
+    -> Result<(), TemplateError>
+where
+    F : Fn(&str) -> Option<String>,
+{
+    expand_message(m, &lookup, nested, "").map(|_| ())
+}
+
+/// Replace `${NAME}` placeholders in string fields of the message
+/// with values of `vars`.
+///
+/// See `expand_placeholders_with` for details.
+pub fn expand_placeholders(m: &mut Message, vars: &HashMap<String, String>, nested: bool)
+    -> Result<(), TemplateError>
+{
+    expand_placeholders_with(m, |name| vars.get(name).cloned(), nested)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn escape() {
+        let lookup = |name: &str| if name == "NAME" { Some("a".to_owned()) } else { None };
+        assert_eq!(
+            Some("${NAME} $NAME a$".to_owned()),
+            expand_str("$${NAME} $NAME ${NAME}$", &lookup, "f").unwrap()
+        );
+        assert_eq!(None, expand_str("a", &lookup, "f").unwrap());
+    }
+}
@@ -6,6 +6,7 @@ use bytes::Bytes;
 use chars::Chars;
 
 use core::*;
+use stream::CodedOutputStream;
 use super::*;
 
 pub trait ProtobufValue: Any + 'static {
@@ -143,6 +144,23 @@ pub enum ProtobufValueRef<'a> {
 }
 
 impl<'a> ProtobufValueRef<'a> {
+    /// Copy referenced value. Nested message is copied by serializing it.
+    pub fn to_box(&self) -> ProtobufValueBox {
+        match *self {
+            ProtobufValueRef::U32(v) => ProtobufValueBox::U32(v),
+            ProtobufValueRef::U64(v) => ProtobufValueBox::U64(v),
+            ProtobufValueRef::I32(v) => ProtobufValueBox::I32(v),
+            ProtobufValueRef::I64(v) => ProtobufValueBox::I64(v),
+            ProtobufValueRef::F32(v) => ProtobufValueBox::F32(v),
+            ProtobufValueRef::F64(v) => ProtobufValueBox::F64(v),
+            ProtobufValueRef::Bool(v) => ProtobufValueBox::Bool(v),
+            ProtobufValueRef::String(v) => ProtobufValueBox::String(v.to_owned()),
+            ProtobufValueRef::Bytes(v) => ProtobufValueBox::Bytes(v.to_owned()),
+            ProtobufValueRef::Enum(v) => ProtobufValueBox::Enum(v),
+            ProtobufValueRef::Message(v) => ProtobufValueBox::Message(clone_message(v)),
+        }
+    }
+
     pub fn is_non_zero(&self) -> bool {
         match *self {
             ProtobufValueRef::U32(v) => v != 0,
@@ -159,3 +177,39 @@ impl<'a> ProtobufValueRef<'a> {
         }
     }
 }
+
+/// Owned value of a message field, used as value in `Record`.
+#[derive(Debug)]
+pub enum ProtobufValueBox {
+    U32(u32),
+    U64(u64),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+    Bool(bool),
+    String(String),
+    Bytes(Vec<u8>),
+    Enum(&'static EnumValueDescriptor),
+    Message(Box<Message>),
+    /// Nested message converted with `to_record`
+    Record(Record),
+    /// Elements of repeated field
+    Repeated(Vec<ProtobufValueBox>),
+    /// Entries of map field
+    Map(Vec<(ProtobufValueBox, ProtobufValueBox)>),
+}
+
+// `Message` has no `clone` usable through trait object
+fn clone_message(m: &Message) -> Box<Message> {
+    let mut bytes = Vec::new();
+    m.compute_size();
+    {
+        let mut os = CodedOutputStream::vec(&mut bytes);
+        m.write_to_with_cached_sizes(&mut os).expect("write to vec");
+        os.flush().expect("write to vec");
+    }
+    let mut r = descriptor_dyn(m).new_instance();
+    r.merge_from_bytes(&bytes).expect("parse serialized message");
+    r
+}
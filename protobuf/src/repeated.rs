@@ -10,6 +10,8 @@ use std::ops::Index;
 use std::ops::IndexMut;
 use std::ops::Deref;
 use std::ops::DerefMut;
+use std::ops::Bound;
+use std::ops::RangeBounds;
 use std::cmp::Ordering;
 use std::fmt;
 
@@ -235,11 +237,62 @@ impl<T> RepeatedField<T> {
     #[inline]
     pub fn sort_by<F>(&mut self, compare: F)
     where
-        F : Fn(&T, &T) -> Ordering,
+        F : FnMut(&T, &T) -> Ordering,
     {
         self.as_mut_slice().sort_by(compare)
     }
 
+    /// Binary search sorted elements with given comparator.
+    #[inline]
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F : FnMut(&T) -> Ordering,
+    {
+        self.as_ref().binary_search_by(f)
+    }
+
+    /// Retain only elements for which predicate returns `true`,
+    /// preserving their order.
+    ///
+    /// Removed elements are not dropped, but kept for reuse like cleared elements.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F : FnMut(&T) -> bool,
+    {
+        let mut kept = 0;
+        for i in 0..self.len {
+            if f(&self.vec[i]) {
+                self.vec.swap(kept, i);
+                kept += 1;
+            }
+        }
+        self.len = kept;
+    }
+
+    /// Remove elements in given range, returning them in iterator.
+    ///
+    /// # Panics
+    ///
+    /// If range is out of bounds.
+    pub fn drain<R>(&mut self, range: R) -> vec::Drain<T>
+    where
+        R : RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => self.len,
+        };
+        assert!(start <= end && end <= self.len);
+        self.len -= end - start;
+        self.vec.drain(start..end)
+    }
+
     /// Get data as raw pointer.
     #[inline]
     pub fn as_ptr(&self) -> *const T {
@@ -309,6 +362,21 @@ impl<T : Clone> RepeatedField<T> {
     pub fn to_vec(&self) -> Vec<T> {
         self.as_ref().to_vec()
     }
+
+    /// Append copies of slice elements.
+    ///
+    /// Previously cleared elements are overwritten with `clone_from`,
+    /// so their allocations are reused.
+    pub fn extend_from_slice(&mut self, values: &[T]) {
+        for value in values {
+            if self.len == self.vec.len() {
+                self.vec.push(value.clone());
+            } else {
+                self.vec[self.len].clone_from(value);
+            }
+            self.len += 1;
+        }
+    }
 }
 
 impl<T : Clone> Clone for RepeatedField<T> {
@@ -328,6 +396,14 @@ impl<T> FromIterator<T> for RepeatedField<T> {
     }
 }
 
+impl<T> Extend<T> for RepeatedField<T> {
+    fn extend<I : IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
 impl<'a, T> IntoIterator for &'a RepeatedField<T> {
     type Item = &'a T;
     type IntoIter = slice::Iter<'a, T>;
@@ -337,6 +413,15 @@ impl<'a, T> IntoIterator for &'a RepeatedField<T> {
     }
 }
 
+impl<'a, T> IntoIterator for &'a mut RepeatedField<T> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> slice::IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
 impl<T : PartialEq> PartialEq for RepeatedField<T> {
     #[inline]
     fn eq(&self, other: &RepeatedField<T>) -> bool {
@@ -422,7 +507,7 @@ mod test {
         v.push(10);
         v.push(20);
         v.clear();
-        assert_eq!(v.as_mut_slice(), &mut []);
+        assert_eq!(v.as_mut_slice(), &mut [0; 0]);
         v.push(30);
         assert_eq!(v.as_mut_slice(), &mut [30]);
     }
@@ -435,4 +520,45 @@ mod test {
         v.clear();
         assert_eq!("".to_string(), *v.push_default());
     }
+
+    #[test]
+    fn retain() {
+        let mut v = RepeatedField::from_vec(vec![1, 2, 3, 4, 5, 6]);
+        v.truncate(5);
+        v.retain(|&x| x % 2 == 1);
+        assert_eq!(&[1, 3, 5], v.as_ref());
+        // storage of removed elements is kept
+        assert_eq!(6, v.vec.len());
+    }
+
+    #[test]
+    fn drain() {
+        let mut v = RepeatedField::from_vec(vec![1, 2, 3, 4, 5]);
+        v.truncate(4);
+        assert_eq!(vec![2, 3], v.drain(1..3).collect::<Vec<_>>());
+        assert_eq!(&[1, 4], v.as_ref());
+        assert_eq!(vec![1, 4], v.drain(..).collect::<Vec<_>>());
+        assert!(v.is_empty());
+    }
+
+    #[test]
+    fn extend_from_slice() {
+        let mut v = RepeatedField::from_vec(vec!["a".to_string(), "b".to_string()]);
+        v.clear();
+        v.extend_from_slice(&["c".to_string(), "d".to_string(), "e".to_string()]);
+        v.extend(vec!["f".to_string()]);
+        assert_eq!(&["c", "d", "e", "f"], &v.iter().map(|s| &s[..]).collect::<Vec<_>>()[..]);
+    }
+
+    #[test]
+    fn iterators() {
+        let mut v = RepeatedField::from_vec(vec![3, 1, 2]);
+        for x in &mut v {
+            *x *= 10;
+        }
+        v.sort_by(|a, b| a.cmp(b));
+        assert_eq!(Ok(1), v.binary_search_by(|x| x.cmp(&20)));
+        v.truncate(2);
+        assert_eq!(vec![10, 20], v.into_vec());
+    }
 }
@@ -4,11 +4,31 @@
 use std::default::Default;
 use std::hash::Hash;
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
 
 #[cfg(feature = "bytes")]
 use bytes::Bytes;
 #[cfg(feature = "bytes")]
 use chars::Chars;
+#[cfg(feature = "smallvec")]
+use smallvec::Array;
+#[cfg(feature = "smallvec")]
+use smallvec::SmallVec;
+#[cfg(feature = "miniz_oxide")]
+use miniz_oxide;
+#[cfg(feature = "miniz_oxide")]
+use descriptor::FileDescriptorProto;
+#[cfg(feature = "arbitrary")]
+use std::cell::Cell;
+#[cfg(feature = "arbitrary")]
+use arbitrary;
+#[cfg(feature = "arbitrary")]
+use arbitrary::Unstructured;
+#[cfg(feature = "arbitrary")]
+use stream::DEFAULT_RECURSION_LIMIT;
+#[cfg(feature = "proptest")]
+use proptest;
 
 use core::*;
 use zigzag::*;
@@ -18,17 +38,20 @@ use stream::wire_format::WireTypeFixed32;
 use stream::wire_format::WireTypeFixed64;
 use stream::wire_format::WireTypeLengthDelimited;
 use stream::wire_format::WireTypeVarint;
+use error::ConvertError;
 use error::ProtobufError;
 use error::ProtobufResult;
 use error::WireError;
 use singular::SingularField;
 use singular::SingularPtrField;
+use lazy_message::LazyMessageField;
 use repeated::RepeatedField;
 use stream::CodedInputStream;
 use stream::CodedOutputStream;
 use types::*;
 
 use unknown::UnknownFields;
+use size_cache::SizeCache;
 
 
 /// Given `u64` value compute varint encoded length.
@@ -146,9 +169,7 @@ pub fn vec_packed_varint_zigzag_data_size<T : ProtobufVarintZigzag>(vec: &[T]) -
 
 /// Size of serialized repeated packed enum field, excluding length and tag.
 pub fn vec_packed_enum_data_size<E : ProtobufEnum>(vec: &[E]) -> u32 {
-    vec.iter()
-        .map(|e| compute_raw_varint32_size(e.value() as u32))
-        .fold(0, |a, i| a + i)
+    vec.iter().map(|e| enum_size_no_tag(*e)).fold(0, |a, i| a + i)
 }
 
 /// Size of serialized data with length prefix and tag
@@ -258,6 +279,15 @@ pub fn unknown_fields_size(unknown_fields: &UnknownFields) -> u32 {
         for bytes in &values.length_delimited {
             r += bytes_size_no_tag(&bytes);
         }
+
+        // start and end group tags
+        #[cfg(feature = "with-groups")]
+        {
+            r += tag_size(number) * 2 * values.group.len() as u32;
+            for group in &values.group {
+                r += unknown_fields_size(group);
+            }
+        }
     }
     r
 }
@@ -471,6 +501,33 @@ pub fn read_repeated_bool_into(
     }
 }
 
+/// Read repeated numeric or `bool` field stored as `SmallVec`.
+#[cfg(feature = "smallvec")]
+pub fn read_repeated_smallvec_into<T, A>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut SmallVec<A>,
+) -> ProtobufResult<()>
+where
+    T : ProtobufType,
+    A : Array<Item = T::Value>,
+{
+    if wire_type == WireTypeLengthDelimited {
+        let len = is.read_raw_varint64()?;
+        let old_limit = is.push_limit(len)?;
+        while !is.eof()? {
+            target.push(T::read(is)?);
+        }
+        is.pop_limit(old_limit);
+        Ok(())
+    } else if wire_type == T::wire_type() {
+        target.push(T::read(is)?);
+        Ok(())
+    } else {
+        Err(unexpected_wire_type(wire_type))
+    }
+}
+
 /// Read repeated `enum` field into given vec.
 /// This function is no longer called from generated code, remove in 1.5.
 pub fn read_repeated_enum_into<E : ProtobufEnum>(
@@ -603,6 +660,21 @@ pub fn read_repeated_string_into(
     }
 }
 
+/// Read repeated `string` field stored as `Vec<String>`.
+pub fn read_repeated_vec_string_into(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<String>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            target.push(is.read_string()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read repeated `Chars` field into given vec.
 #[cfg(feature = "bytes")]
 pub fn read_repeated_carllerche_string_into(
@@ -693,6 +765,21 @@ pub fn read_repeated_bytes_into(
     }
 }
 
+/// Read repeated `bytes` field stored as `Vec<Vec<u8>>`.
+pub fn read_repeated_vec_bytes_into(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<Vec<u8>>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            target.push(is.read_bytes()?);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read repeated `Bytes` field into given vec.
 #[cfg(feature = "bytes")]
 pub fn read_repeated_carllerche_bytes_into(
@@ -777,8 +864,9 @@ pub fn read_repeated_message_into<M : Message + Default>(
     match wire_type {
         WireTypeLengthDelimited => {
             is.incr_recursion()?;
+            let index = target.len() as u32;
             let tmp = target.push_default();
-            let res = is.merge_message(tmp);
+            let res = is.merge_message_at(index, tmp);
             is.decr_recursion();
             res
         }
@@ -786,6 +874,26 @@ pub fn read_repeated_message_into<M : Message + Default>(
     }
 }
 
+/// Read repeated `message` field stored as `Vec<M>`.
+pub fn read_repeated_vec_message_into<M : Message + Default>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Vec<M>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.incr_recursion()?;
+            let mut tmp = M::default();
+            let res = is.merge_message_at(target.len() as u32, &mut tmp);
+            is.decr_recursion();
+            res?;
+            target.push(tmp);
+            Ok(())
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
 /// Read singular `message` field.
 pub fn read_singular_message_into<M : Message + Default>(
     wire_type: WireType,
@@ -795,7 +903,12 @@ pub fn read_singular_message_into<M : Message + Default>(
     match wire_type {
         WireTypeLengthDelimited => {
             is.incr_recursion()?;
-            let tmp = target.set_default();
+            // merge into existing message as specified for repeated occurrences
+            let tmp = if target.is_some() {
+                target.as_mut().unwrap()
+            } else {
+                target.set_default()
+            };
             let res = is.merge_message(tmp);
             is.decr_recursion();
             res
@@ -804,18 +917,45 @@ pub fn read_singular_message_into<M : Message + Default>(
     }
 }
 
-fn skip_group(is: &mut CodedInputStream) -> ProtobufResult<()> {
-    loop {
-        let (_, wire_type) = is.read_tag_unpack()?;
-        if wire_type == wire_format::WireTypeEndGroup {
-            return Ok(());
+/// Read singular `message` field with `option_box_for_messages` option.
+pub fn read_singular_option_box_message_into<M : Message>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut Option<Box<M>>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            is.incr_recursion()?;
+            // merge into existing message as specified for repeated occurrences
+            let tmp = target.get_or_insert_with(|| Box::new(M::new()));
+            let res = is.merge_message(&mut **tmp);
+            is.decr_recursion();
+            res
         }
-        is.skip_field(wire_type)?;
+        _ => Err(unexpected_wire_type(wire_type)),
+    }
+}
+
+/// Read singular `message` field with `lazy_field` option.
+/// Message bytes are not decoded.
+pub fn read_singular_lazy_message_into<M : Message>(
+    wire_type: WireType,
+    is: &mut CodedInputStream,
+    target: &mut LazyMessageField<M>,
+) -> ProtobufResult<()> {
+    match wire_type {
+        WireTypeLengthDelimited => {
+            let bytes = is.read_bytes()?;
+            target.merge_from_bytes(&bytes)
+        }
+        _ => Err(unexpected_wire_type(wire_type)),
     }
 }
 
 /// Handle unknown field in generated code.
-/// Either store a value in unknown, or skip a group.
+/// Store a value in unknown fields. With `with-groups` feature groups are stored
+/// with their nested fields, so they are written back on serialization,
+/// otherwise groups are skipped.
 pub fn read_unknown_or_skip_group(
     field_number: u32,
     wire_type: WireType,
@@ -823,7 +963,14 @@ pub fn read_unknown_or_skip_group(
     unknown_fields: &mut UnknownFields,
 ) -> ProtobufResult<()> {
     match wire_type {
-        wire_format::WireTypeStartGroup => skip_group(is),
+        #[cfg(feature = "with-groups")]
+        wire_format::WireTypeStartGroup => {
+            let group = is.read_unknown_group(field_number)?;
+            unknown_fields.add_group(field_number, group);
+            Ok(())
+        }
+        #[cfg(not(feature = "with-groups"))]
+        wire_format::WireTypeStartGroup => is.skip_group(field_number),
         _ => {
             let unknown = is.read_unknown(wire_type)?;
             unknown_fields.add_value(field_number, unknown);
@@ -841,6 +988,16 @@ pub fn unexpected_wire_type(wire_type: WireType) -> ProtobufError {
     ProtobufError::WireError(WireError::UnexpectedWireType(wire_type))
 }
 
+/// Convert integer field value to another integer type,
+/// used in generated `get_foo_as::<T>()` accessors.
+pub fn checked_convert<V, T>(field_name: &'static str, value: V) -> Result<T, ConvertError>
+where
+    V : Copy + fmt::Display,
+    T : TryFrom<V>,
+{
+    T::try_from(value).map_err(|_| ConvertError::new(field_name, value))
+}
+
 
 /// Compute serialized size of `map` field and cache nested field sizes.
 pub fn compute_map_size<K, V>(field_number: u32, map: &HashMap<K::Value, V::Value>) -> u32
@@ -892,6 +1049,65 @@ where
     Ok(())
 }
 
+/// Compute serialized size of `map` field storing nested message sizes in `sizes`.
+pub fn compute_map_size_with_cache<K, V>(
+    field_number: u32,
+    map: &HashMap<K::Value, V::Value>,
+    sizes: &mut SizeCache,
+) -> u32
+where
+    K : ProtobufType,
+    V : ProtobufType,
+    K::Value : Eq + Hash,
+{
+    let mut sum = 0;
+    for (k, v) in map {
+        let key_tag_size = 1;
+        let value_tag_size = 1;
+
+        let key_len = K::compute_size_with_length_delimiter(k);
+        let value_size = V::compute_size_with_cache(v, sizes);
+        let value_len = if V::wire_type() == WireType::WireTypeLengthDelimited {
+            compute_raw_varint32_size(value_size) + value_size
+        } else {
+            value_size
+        };
+
+        let entry_len = key_tag_size + key_len + value_tag_size + value_len;
+        sum += tag_size(field_number) + compute_raw_varint32_size(entry_len) + entry_len;
+    }
+    sum
+}
+
+/// Write map, message sizes must be already stored in `sizes`.
+pub fn write_map_with_size_cache<K, V>(
+    field_number: u32,
+    map: &HashMap<K::Value, V::Value>,
+    os: &mut CodedOutputStream,
+    sizes: &mut SizeCache,
+) -> ProtobufResult<()>
+where
+    K : ProtobufType,
+    V : ProtobufType,
+    K::Value : Eq + Hash,
+{
+    for (k, v) in map {
+        let key_tag_size = 1;
+        let value_tag_size = 1;
+
+        let key_len = K::compute_size_with_length_delimiter(k);
+        let value_len = V::get_size_from_cache_with_length_delimiter(v, sizes);
+
+        let entry_len = key_tag_size + key_len + value_tag_size + value_len;
+
+        os.write_tag(field_number, WireType::WireTypeLengthDelimited)?;
+        os.write_raw_varint32(entry_len)?;
+        K::write_with_cached_size(1, k, os)?;
+        V::write_with_size_cache(2, v, os, sizes)?;
+    }
+    Ok(())
+}
+
 /// Read `map` field.
 pub fn read_map_into<K, V>(
     wire_type: WireType,
@@ -907,37 +1123,190 @@ where
         return Err(unexpected_wire_type(wire_type));
     }
 
-    let mut key = None;
-    let mut value = None;
-
     let len = is.read_raw_varint32()?;
     let old_limit = is.push_limit(len as u64)?;
-    while !is.eof()? {
-        let (field_number, wire_type) = is.read_tag_unpack()?;
-        match field_number {
-            1 => {
-                if wire_type != K::wire_type() {
-                    return Err(unexpected_wire_type(wire_type));
+    let (key, value) = is.read_nested(target.len() as u32, |is| {
+        let mut key = None;
+        let mut value = None;
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != K::wire_type() {
+                        return Err(unexpected_wire_type(wire_type));
+                    }
+                    key = Some(K::read(is)?);
                 }
-                key = Some(K::read(is)?);
-            }
-            2 => {
-                if wire_type != V::wire_type() {
-                    return Err(unexpected_wire_type(wire_type));
+                2 => {
+                    if wire_type != V::wire_type() {
+                        return Err(unexpected_wire_type(wire_type));
+                    }
+                    value = Some(V::read(is)?);
                 }
-                value = Some(V::read(is)?);
+                _ => is.skip_field(wire_type)?,
             }
-            _ => is.skip_field(wire_type)?,
         }
-    }
+        match (key, value) {
+            (Some(key), Some(value)) => Ok((key, value)),
+            _ => Err(ProtobufError::WireError(WireError::IncompleteMap)),
+        }
+    })?;
     is.pop_limit(old_limit);
 
-    match (key, value) {
-        (None, _) | (_, None) => return Err(ProtobufError::WireError(WireError::IncompleteMap)),
-        (Some(key), Some(value)) => {
-            target.insert(key, value);
+    target.insert(key, value);
+
+    Ok(())
+}
+
+/// Parse file descriptor embedded in generated code
+/// with `compress_descriptor` codegen option.
+#[cfg(feature = "miniz_oxide")]
+pub fn parse_compressed_file_descriptor(data: &[u8]) -> FileDescriptorProto {
+    let bytes = miniz_oxide::inflate::decompress_to_vec(data)
+        .expect("failed to decompress embedded file descriptor");
+    parse_from_bytes(&bytes).unwrap()
+}
+
+/// Maximum number of elements of repeated and map fields
+/// in messages generated by `Arbitrary` implementations.
+#[cfg(feature = "arbitrary")]
+pub const ARBITRARY_MAX_LEN: usize = 16;
+
+/// Fields of message filled by `Arbitrary` implementation,
+/// generated with `generate_arbitrary` codegen option.
+#[cfg(feature = "arbitrary")]
+pub trait ArbitraryFields {
+    /// Set fields to arbitrary values, unknown fields are not generated.
+    fn arbitrary_fields(&mut self, u: &mut Unstructured) -> arbitrary::Result<()>;
+}
+
+#[cfg(feature = "arbitrary")]
+thread_local! {
+    // nesting level of message being generated by `arbitrary_message`
+    static ARBITRARY_DEPTH: Cell<u32> = Cell::new(0);
+}
+
+/// `Arbitrary::arbitrary` of generated message.
+///
+/// Messages nested deeper than default recursion limit of `CodedInputStream`
+/// are left empty, so values of recursive types are finite and can be parsed back.
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_message<M>(u: &mut Unstructured) -> arbitrary::Result<M>
+where
+    M : Message + ArbitraryFields,
+{
+    // restores depth also if `arbitrary_fields` panics,
+    // so fuzzer catching panics keeps generating nested messages
+    struct RestoreDepth(u32);
+
+    impl Drop for RestoreDepth {
+        fn drop(&mut self) {
+            ARBITRARY_DEPTH.with(|d| d.set(self.0));
         }
     }
 
-    Ok(())
+    let mut m = M::new();
+    let depth = ARBITRARY_DEPTH.with(|d| d.get());
+    if depth < DEFAULT_RECURSION_LIMIT {
+        let _restore = RestoreDepth(depth);
+        ARBITRARY_DEPTH.with(|d| d.set(depth + 1));
+        m.arbitrary_fields(u)?;
+    }
+    Ok(m)
+}
+
+/// Number of elements of repeated or map field, at most `ARBITRARY_MAX_LEN`.
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_len(u: &mut Unstructured) -> arbitrary::Result<usize> {
+    u.int_in_range(0..=ARBITRARY_MAX_LEN)
+}
+
+/// One of values of enum.
+#[cfg(feature = "arbitrary")]
+pub fn arbitrary_enum<E : ProtobufEnum>(u: &mut Unstructured) -> arbitrary::Result<E> {
+    u.choose(E::values()).map(|e| *e)
+}
+
+/// Maximum number of elements of repeated and map fields
+/// in messages generated by proptest strategies.
+#[cfg(feature = "proptest")]
+pub const PROPTEST_MAX_LEN: usize = 8;
+
+/// Default nesting depth of messages generated by proptest strategies.
+#[cfg(feature = "proptest")]
+pub const PROPTEST_MAX_DEPTH: u32 = 4;
+
+/// Parameters of proptest `Arbitrary` implementation of generated message:
+/// how many levels of nested messages are generated.
+///
+/// Optional, repeated and map message fields are left empty at depth zero,
+/// so strategies of recursive messages are finite.
+#[cfg(feature = "proptest")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProptestDepth(pub u32);
+
+#[cfg(feature = "proptest")]
+impl Default for ProptestDepth {
+    fn default() -> ProptestDepth {
+        ProptestDepth(PROPTEST_MAX_DEPTH)
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl ProptestDepth {
+    /// Depth of messages nested in message of this depth,
+    /// `None` if nested messages must not be generated.
+    pub fn nested(self) -> Option<ProptestDepth> {
+        match self.0 {
+            0 => None,
+            d => Some(ProptestDepth(d - 1)),
+        }
+    }
+}
+
+/// Strategy selecting one of values of enum.
+#[cfg(feature = "proptest")]
+pub fn proptest_enum<E>() -> proptest::sample::Select<E>
+where
+    E : ProtobufEnum + fmt::Debug,
+{
+    proptest::sample::select(E::values())
+}
+
+#[cfg(all(test, feature = "miniz_oxide"))]
+mod test {
+    use super::*;
+    use descriptor;
+
+    #[test]
+    fn compressed_file_descriptor() {
+        let file = descriptor::file_descriptor_proto();
+        let compressed =
+            miniz_oxide::deflate::compress_to_vec(&file.write_to_bytes().unwrap(), 9);
+        assert!(compressed.len() < file.compute_size() as usize);
+        assert_eq!(*file, parse_compressed_file_descriptor(&compressed));
+    }
+}
+
+#[cfg(all(test, feature = "arbitrary"))]
+mod test_arbitrary {
+    use std::panic;
+
+    use super::*;
+    use well_known_types::Empty;
+
+    impl ArbitraryFields for Empty {
+        fn arbitrary_fields(&mut self, _u: &mut Unstructured) -> arbitrary::Result<()> {
+            panic!("arbitrary_fields");
+        }
+    }
+
+    #[test]
+    fn depth_restored_on_panic() {
+        let r = panic::catch_unwind(|| {
+            arbitrary_message::<Empty>(&mut Unstructured::new(&[]))
+        });
+        assert!(r.is_err());
+        assert_eq!(0, ARBITRARY_DEPTH.with(|d| d.get()));
+    }
 }
@@ -34,6 +34,30 @@ pub mod exts {
 
     pub const carllerche_bytes_for_string_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
 
+    pub const lazy_field_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17013, phantom: ::std::marker::PhantomData };
+
+    pub const option_box_for_messages_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17018, phantom: ::std::marker::PhantomData };
+
+    pub const repeated_field_vec_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17020, phantom: ::std::marker::PhantomData };
+
+    pub const smallvec_for_repeated_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeUint32> = ::protobuf::ext::ExtFieldOptional { field_number: 17022, phantom: ::std::marker::PhantomData };
+
+    pub const ref_accessors_only_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17014, phantom: ::std::marker::PhantomData };
+
+    pub const json_names_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17015, phantom: ::std::marker::PhantomData };
+
+    pub const generate_display_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17017, phantom: ::std::marker::PhantomData };
+
+    pub const has_bits_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17019, phantom: ::std::marker::PhantomData };
+
+    pub const generate_visitor_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17023, phantom: ::std::marker::PhantomData };
+
+    pub const generate_into_bytes_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17024, phantom: ::std::marker::PhantomData };
+
+    pub const generate_arbitrary_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17027, phantom: ::std::marker::PhantomData };
+
+    pub const generate_proptest_all: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FileOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17028, phantom: ::std::marker::PhantomData };
+
     pub const expose_oneof: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17001, phantom: ::std::marker::PhantomData };
 
     pub const expose_fields: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
@@ -44,6 +68,28 @@ pub mod exts {
 
     pub const carllerche_bytes_for_string: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
 
+    pub const lazy_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17013, phantom: ::std::marker::PhantomData };
+
+    pub const option_box_for_messages: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17018, phantom: ::std::marker::PhantomData };
+
+    pub const repeated_field_vec: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17020, phantom: ::std::marker::PhantomData };
+
+    pub const smallvec_for_repeated: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeUint32> = ::protobuf::ext::ExtFieldOptional { field_number: 17022, phantom: ::std::marker::PhantomData };
+
+    pub const ref_accessors_only: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17014, phantom: ::std::marker::PhantomData };
+
+    pub const json_names: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17015, phantom: ::std::marker::PhantomData };
+
+    pub const generate_display: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17017, phantom: ::std::marker::PhantomData };
+
+    pub const has_bits: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17019, phantom: ::std::marker::PhantomData };
+
+    pub const generate_into_bytes: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17024, phantom: ::std::marker::PhantomData };
+
+    pub const generate_arbitrary: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17027, phantom: ::std::marker::PhantomData };
+
+    pub const generate_proptest: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::MessageOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17028, phantom: ::std::marker::PhantomData };
+
     pub const expose_fields_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17003, phantom: ::std::marker::PhantomData };
 
     pub const generate_accessors_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17004, phantom: ::std::marker::PhantomData };
@@ -51,6 +97,24 @@ pub mod exts {
     pub const carllerche_bytes_for_bytes_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17011, phantom: ::std::marker::PhantomData };
 
     pub const carllerche_bytes_for_string_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17012, phantom: ::std::marker::PhantomData };
+
+    pub const lazy_field_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17013, phantom: ::std::marker::PhantomData };
+
+    pub const option_box_for_messages_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17018, phantom: ::std::marker::PhantomData };
+
+    pub const repeated_field_vec_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17020, phantom: ::std::marker::PhantomData };
+
+    pub const smallvec_for_repeated_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeUint32> = ::protobuf::ext::ExtFieldOptional { field_number: 17022, phantom: ::std::marker::PhantomData };
+
+    pub const ref_accessors_only_field: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17014, phantom: ::std::marker::PhantomData };
+
+    pub const redact: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17016, phantom: ::std::marker::PhantomData };
+
+    pub const checked_as: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeBool> = ::protobuf::ext::ExtFieldOptional { field_number: 17021, phantom: ::std::marker::PhantomData };
+
+    pub const rust_field_type: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional { field_number: 17025, phantom: ::std::marker::PhantomData };
+
+    pub const rust_name: ::protobuf::ext::ExtFieldOptional<::protobuf::descriptor::FieldOptions, ::protobuf::types::ProtobufTypeString> = ::protobuf::ext::ExtFieldOptional { field_number: 17026, phantom: ::std::marker::PhantomData };
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
@@ -63,102 +127,149 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     che_bytes_for_bytes_all\x18\xf3\x84\x01\x20\x01(\x08\x12\x1c.google.prot\
     obuf.FileOptionsR\x1acarllercheBytesForBytesAll:d\n\x1fcarllerche_bytes_\
     for_string_all\x18\xf4\x84\x01\x20\x01(\x08\x12\x1c.google.protobuf.File\
-    OptionsR\x1bcarllercheBytesForStringAll:D\n\x0cexpose_oneof\x18\xe9\x84\
-    \x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x0bexposeOneof\
-    :F\n\rexpose_fields\x18\xeb\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf\
-    .MessageOptionsR\x0cexposeFields:P\n\x12generate_accessors\x18\xec\x84\
-    \x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x11generateAcc\
-    essors:^\n\x1acarllerche_bytes_for_bytes\x18\xf3\x84\x01\x20\x01(\x08\
-    \x12\x1f.google.protobuf.MessageOptionsR\x17carllercheBytesForBytes:`\n\
-    \x1bcarllerche_bytes_for_string\x18\xf4\x84\x01\x20\x01(\x08\x12\x1f.goo\
-    gle.protobuf.MessageOptionsR\x18carllercheBytesForString:O\n\x13expose_f\
-    ields_field\x18\xeb\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOp\
-    tionsR\x11exposeFieldsField:Y\n\x18generate_accessors_field\x18\xec\x84\
-    \x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x16generateAcces\
-    sorsField:g\n\x20carllerche_bytes_for_bytes_field\x18\xf3\x84\x01\x20\
-    \x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x1ccarllercheBytesForByt\
-    esField:i\n!carllerche_bytes_for_string_field\x18\xf4\x84\x01\x20\x01(\
-    \x08\x12\x1d.google.protobuf.FieldOptionsR\x1dcarllercheBytesForStringFi\
-    eldJ\xf4\x0e\n\x06\x12\x04\0\0,\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\
-    \n\x02\x03\0\x12\x03\x02\x07)\nh\n\x01\x02\x12\x03\x07\x08\x112^\x20see\
-    \x20https://github.com/gogo/protobuf/blob/master/gogoproto/gogo.proto\n\
-    \x20for\x20the\x20original\x20idea\n\n\t\n\x01\x07\x12\x04\t\0\x14\x01\n\
-    7\n\x02\x07\0\x12\x03\x0b\x04+\x1a,\x20When\x20true,\x20oneof\x20field\
-    \x20is\x20generated\x20public\n\n\n\n\x03\x07\0\x02\x12\x03\t\x07\"\n\n\
-    \n\x03\x07\0\x04\x12\x03\x0b\x04\x0c\n\n\n\x03\x07\0\x05\x12\x03\x0b\r\
-    \x11\n\n\n\x03\x07\0\x01\x12\x03\x0b\x12\"\n\n\n\x03\x07\0\x03\x12\x03\
-    \x0b%*\nI\n\x02\x07\x01\x12\x03\r\x04,\x1a>\x20When\x20true\x20all\x20fi\
-    elds\x20are\x20public,\x20and\x20not\x20accessors\x20generated\n\n\n\n\
-    \x03\x07\x01\x02\x12\x03\t\x07\"\n\n\n\x03\x07\x01\x04\x12\x03\r\x04\x0c\
-    \n\n\n\x03\x07\x01\x05\x12\x03\r\r\x11\n\n\n\x03\x07\x01\x01\x12\x03\r\
-    \x12#\n\n\n\x03\x07\x01\x03\x12\x03\r&+\nP\n\x02\x07\x02\x12\x03\x0f\x04\
-    1\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20acce\
-    ssors\x20are\x20not\x20generated\n\n\n\n\x03\x07\x02\x02\x12\x03\t\x07\"\
-    \n\n\n\x03\x07\x02\x04\x12\x03\x0f\x04\x0c\n\n\n\x03\x07\x02\x05\x12\x03\
-    \x0f\r\x11\n\n\n\x03\x07\x02\x01\x12\x03\x0f\x12(\n\n\n\x03\x07\x02\x03\
-    \x12\x03\x0f+0\n2\n\x02\x07\x03\x12\x03\x11\x049\x1a'\x20Use\x20`bytes::\
-    Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\x03\x02\x12\x03\t\x07\
-    \"\n\n\n\x03\x07\x03\x04\x12\x03\x11\x04\x0c\n\n\n\x03\x07\x03\x05\x12\
-    \x03\x11\r\x11\n\n\n\x03\x07\x03\x01\x12\x03\x11\x120\n\n\n\x03\x07\x03\
-    \x03\x12\x03\x1138\n3\n\x02\x07\x04\x12\x03\x13\x04:\x1a(\x20Use\x20`byt\
-    es::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\x07\x04\x02\x12\x03\
-    \t\x07\"\n\n\n\x03\x07\x04\x04\x12\x03\x13\x04\x0c\n\n\n\x03\x07\x04\x05\
-    \x12\x03\x13\r\x11\n\n\n\x03\x07\x04\x01\x12\x03\x13\x121\n\n\n\x03\x07\
-    \x04\x03\x12\x03\x1349\n\t\n\x01\x07\x12\x04\x16\0!\x01\n7\n\x02\x07\x05\
-    \x12\x03\x18\x04'\x1a,\x20When\x20true,\x20oneof\x20field\x20is\x20gener\
-    ated\x20public\n\n\n\n\x03\x07\x05\x02\x12\x03\x16\x07%\n\n\n\x03\x07\
-    \x05\x04\x12\x03\x18\x04\x0c\n\n\n\x03\x07\x05\x05\x12\x03\x18\r\x11\n\n\
-    \n\x03\x07\x05\x01\x12\x03\x18\x12\x1e\n\n\n\x03\x07\x05\x03\x12\x03\x18\
-    !&\nI\n\x02\x07\x06\x12\x03\x1a\x04(\x1a>\x20When\x20true\x20all\x20fiel\
-    ds\x20are\x20public,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\
-    \x07\x06\x02\x12\x03\x16\x07%\n\n\n\x03\x07\x06\x04\x12\x03\x1a\x04\x0c\
-    \n\n\n\x03\x07\x06\x05\x12\x03\x1a\r\x11\n\n\n\x03\x07\x06\x01\x12\x03\
-    \x1a\x12\x1f\n\n\n\x03\x07\x06\x03\x12\x03\x1a\"'\nP\n\x02\x07\x07\x12\
-    \x03\x1c\x04-\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20\
-    etc.\x20accessors\x20are\x20not\x20generated\n\n\n\n\x03\x07\x07\x02\x12\
-    \x03\x16\x07%\n\n\n\x03\x07\x07\x04\x12\x03\x1c\x04\x0c\n\n\n\x03\x07\
-    \x07\x05\x12\x03\x1c\r\x11\n\n\n\x03\x07\x07\x01\x12\x03\x1c\x12$\n\n\n\
-    \x03\x07\x07\x03\x12\x03\x1c',\n2\n\x02\x07\x08\x12\x03\x1e\x045\x1a'\
-    \x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\
-    \x08\x02\x12\x03\x16\x07%\n\n\n\x03\x07\x08\x04\x12\x03\x1e\x04\x0c\n\n\
-    \n\x03\x07\x08\x05\x12\x03\x1e\r\x11\n\n\n\x03\x07\x08\x01\x12\x03\x1e\
-    \x12,\n\n\n\x03\x07\x08\x03\x12\x03\x1e/4\n3\n\x02\x07\t\x12\x03\x20\x04\
-    6\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\
-    \x07\t\x02\x12\x03\x16\x07%\n\n\n\x03\x07\t\x04\x12\x03\x20\x04\x0c\n\n\
-    \n\x03\x07\t\x05\x12\x03\x20\r\x11\n\n\n\x03\x07\t\x01\x12\x03\x20\x12-\
-    \n\n\n\x03\x07\t\x03\x12\x03\x2005\n\t\n\x01\x07\x12\x04#\0,\x01\nI\n\
-    \x02\x07\n\x12\x03%\x04.\x1a>\x20When\x20true\x20all\x20fields\x20are\
-    \x20public,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\x07\n\
-    \x02\x12\x03#\x07#\n\n\n\x03\x07\n\x04\x12\x03%\x04\x0c\n\n\n\x03\x07\n\
-    \x05\x12\x03%\r\x11\n\n\n\x03\x07\n\x01\x12\x03%\x12%\n\n\n\x03\x07\n\
-    \x03\x12\x03%(-\nP\n\x02\x07\x0b\x12\x03'\x043\x1aE\x20When\x20false,\
-    \x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20g\
-    enerated\n\n\n\n\x03\x07\x0b\x02\x12\x03#\x07#\n\n\n\x03\x07\x0b\x04\x12\
-    \x03'\x04\x0c\n\n\n\x03\x07\x0b\x05\x12\x03'\r\x11\n\n\n\x03\x07\x0b\x01\
-    \x12\x03'\x12*\n\n\n\x03\x07\x0b\x03\x12\x03'-2\n2\n\x02\x07\x0c\x12\x03\
-    )\x04;\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\
-    \x03\x07\x0c\x02\x12\x03#\x07#\n\n\n\x03\x07\x0c\x04\x12\x03)\x04\x0c\n\
-    \n\n\x03\x07\x0c\x05\x12\x03)\r\x11\n\n\n\x03\x07\x0c\x01\x12\x03)\x122\
-    \n\n\n\x03\x07\x0c\x03\x12\x03)5:\n3\n\x02\x07\r\x12\x03+\x04<\x1a(\x20U\
-    se\x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\x07\r\x02\
-    \x12\x03#\x07#\n\n\n\x03\x07\r\x04\x12\x03+\x04\x0c\n\n\n\x03\x07\r\x05\
-    \x12\x03+\r\x11\n\n\n\x03\x07\r\x01\x12\x03+\x123\n\n\n\x03\x07\r\x03\
-    \x12\x03+6;\
+    OptionsR\x1bcarllercheBytesForStringAll:D\n\x0elazy_field_all\x18\xf5\
+    \x84\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x0clazyFieldA\
+    ll:\\\n\x1boption_box_for_messages_all\x18\xfa\x84\x01\x20\x01(\x08\x12\
+    \x1c.google.protobuf.FileOptionsR\x17optionBoxForMessagesAll:S\n\x16repe\
+    ated_field_vec_all\x18\xfc\x84\x01\x20\x01(\x08\x12\x1c.google.protobuf.\
+    FileOptionsR\x13repeatedFieldVecAll:Y\n\x19smallvec_for_repeated_all\x18\
+    \xfe\x84\x01\x20\x01(\r\x12\x1c.google.protobuf.FileOptionsR\x16smallvec\
+    ForRepeatedAll:S\n\x16ref_accessors_only_all\x18\xf6\x84\x01\x20\x01(\
+    \x08\x12\x1c.google.protobuf.FileOptionsR\x13refAccessorsOnlyAll:D\n\x0e\
+    json_names_all\x18\xf7\x84\x01\x20\x01(\x08\x12\x1c.google.protobuf.File\
+    OptionsR\x0cjsonNamesAll:P\n\x14generate_display_all\x18\xf9\x84\x01\x20\
+    \x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x12generateDisplayAll:@\n\
+    \x0chas_bits_all\x18\xfb\x84\x01\x20\x01(\x08\x12\x1c.google.protobuf.Fi\
+    leOptionsR\nhasBitsAll:P\n\x14generate_visitor_all\x18\xff\x84\x01\x20\
+    \x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x12generateVisitorAll:U\n\
+    \x17generate_into_bytes_all\x18\x80\x85\x01\x20\x01(\x08\x12\x1c.google.\
+    protobuf.FileOptionsR\x14generateIntoBytesAll:T\n\x16generate_arbitrary_\
+    all\x18\x83\x85\x01\x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\
+    \x14generateArbitraryAll:R\n\x15generate_proptest_all\x18\x84\x85\x01\
+    \x20\x01(\x08\x12\x1c.google.protobuf.FileOptionsR\x13generateProptestAl\
+    l:D\n\x0cexpose_oneof\x18\xe9\x84\x01\x20\x01(\x08\x12\x1f.google.protob\
+    uf.MessageOptionsR\x0bexposeOneof:F\n\rexpose_fields\x18\xeb\x84\x01\x20\
+    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x0cexposeFields:P\n\
+    \x12generate_accessors\x18\xec\x84\x01\x20\x01(\x08\x12\x1f.google.proto\
+    buf.MessageOptionsR\x11generateAccessors:^\n\x1acarllerche_bytes_for_byt\
+    es\x18\xf3\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
+    \x17carllercheBytesForBytes:`\n\x1bcarllerche_bytes_for_string\x18\xf4\
+    \x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x18carller\
+    cheBytesForString:@\n\nlazy_field\x18\xf5\x84\x01\x20\x01(\x08\x12\x1f.g\
+    oogle.protobuf.MessageOptionsR\tlazyField:X\n\x17option_box_for_messages\
+    \x18\xfa\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
+    \x14optionBoxForMessages:O\n\x12repeated_field_vec\x18\xfc\x84\x01\x20\
+    \x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\x10repeatedFieldVec:U\
+    \n\x15smallvec_for_repeated\x18\xfe\x84\x01\x20\x01(\r\x12\x1f.google.pr\
+    otobuf.MessageOptionsR\x13smallvecForRepeated:O\n\x12ref_accessors_only\
+    \x18\xf6\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
+    \x10refAccessorsOnly:@\n\njson_names\x18\xf7\x84\x01\x20\x01(\x08\x12\
+    \x1f.google.protobuf.MessageOptionsR\tjsonNames:L\n\x10generate_display\
+    \x18\xf9\x84\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
+    \x0fgenerateDisplay:<\n\x08has_bits\x18\xfb\x84\x01\x20\x01(\x08\x12\x1f\
+    .google.protobuf.MessageOptionsR\x07hasBits:Q\n\x13generate_into_bytes\
+    \x18\x80\x85\x01\x20\x01(\x08\x12\x1f.google.protobuf.MessageOptionsR\
+    \x11generateIntoBytes:P\n\x12generate_arbitrary\x18\x83\x85\x01\x20\x01(\
+    \x08\x12\x1f.google.protobuf.MessageOptionsR\x11generateArbitrary:N\n\
+    \x11generate_proptest\x18\x84\x85\x01\x20\x01(\x08\x12\x1f.google.protob\
+    uf.MessageOptionsR\x10generateProptest:O\n\x13expose_fields_field\x18\
+    \xeb\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x11expos\
+    eFieldsField:Y\n\x18generate_accessors_field\x18\xec\x84\x01\x20\x01(\
+    \x08\x12\x1d.google.protobuf.FieldOptionsR\x16generateAccessorsField:g\n\
+    \x20carllerche_bytes_for_bytes_field\x18\xf3\x84\x01\x20\x01(\x08\x12\
+    \x1d.google.protobuf.FieldOptionsR\x1ccarllercheBytesForBytesField:i\n!c\
+    arllerche_bytes_for_string_field\x18\xf4\x84\x01\x20\x01(\x08\x12\x1d.go\
+    ogle.protobuf.FieldOptionsR\x1dcarllercheBytesForStringField:I\n\x10lazy\
+    _field_field\x18\xf5\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldO\
+    ptionsR\x0elazyFieldField:a\n\x1doption_box_for_messages_field\x18\xfa\
+    \x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\x19optionBox\
+    ForMessagesField:X\n\x18repeated_field_vec_field\x18\xfc\x84\x01\x20\x01\
+    (\x08\x12\x1d.google.protobuf.FieldOptionsR\x15repeatedFieldVecField:^\n\
+    \x1bsmallvec_for_repeated_field\x18\xfe\x84\x01\x20\x01(\r\x12\x1d.googl\
+    e.protobuf.FieldOptionsR\x18smallvecForRepeatedField:X\n\x18ref_accessor\
+    s_only_field\x18\xf6\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldO\
+    ptionsR\x15refAccessorsOnlyField:7\n\x06redact\x18\xf8\x84\x01\x20\x01(\
+    \x08\x12\x1d.google.protobuf.FieldOptionsR\x06redact:>\n\nchecked_as\x18\
+    \xfd\x84\x01\x20\x01(\x08\x12\x1d.google.protobuf.FieldOptionsR\tchecked\
+    As:G\n\x0frust_field_type\x18\x81\x85\x01\x20\x01(\t\x12\x1d.google.prot\
+    obuf.FieldOptionsR\rrustFieldType:<\n\trust_name\x18\x82\x85\x01\x20\x01\
+    (\t\x12\x1d.google.protobuf.FieldOptionsR\x08rustNameJ\xf4\x0e\n\x06\x12\
+    \x04\0\0,\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\
+    \x02\x07)\nh\n\x01\x02\x12\x03\x07\x08\x112^\x20see\x20https://github.co\
+    m/gogo/protobuf/blob/master/gogoproto/gogo.proto\n\x20for\x20the\x20orig\
+    inal\x20idea\n\n\t\n\x01\x07\x12\x04\t\0\x14\x01\n7\n\x02\x07\0\x12\x03\
+    \x0b\x04+\x1a,\x20When\x20true,\x20oneof\x20field\x20is\x20generated\x20\
+    public\n\n\n\n\x03\x07\0\x02\x12\x03\t\x07\"\n\n\n\x03\x07\0\x04\x12\x03\
+    \x0b\x04\x0c\n\n\n\x03\x07\0\x05\x12\x03\x0b\r\x11\n\n\n\x03\x07\0\x01\
+    \x12\x03\x0b\x12\"\n\n\n\x03\x07\0\x03\x12\x03\x0b%*\nI\n\x02\x07\x01\
+    \x12\x03\r\x04,\x1a>\x20When\x20true\x20all\x20fields\x20are\x20public,\
+    \x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\x07\x01\x02\x12\x03\
+    \t\x07\"\n\n\n\x03\x07\x01\x04\x12\x03\r\x04\x0c\n\n\n\x03\x07\x01\x05\
+    \x12\x03\r\r\x11\n\n\n\x03\x07\x01\x01\x12\x03\r\x12#\n\n\n\x03\x07\x01\
+    \x03\x12\x03\r&+\nP\n\x02\x07\x02\x12\x03\x0f\x041\x1aE\x20When\x20false\
+    ,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20\
+    generated\n\n\n\n\x03\x07\x02\x02\x12\x03\t\x07\"\n\n\n\x03\x07\x02\x04\
+    \x12\x03\x0f\x04\x0c\n\n\n\x03\x07\x02\x05\x12\x03\x0f\r\x11\n\n\n\x03\
+    \x07\x02\x01\x12\x03\x0f\x12(\n\n\n\x03\x07\x02\x03\x12\x03\x0f+0\n2\n\
+    \x02\x07\x03\x12\x03\x11\x049\x1a'\x20Use\x20`bytes::Bytes`\x20for\x20`b\
+    ytes`\x20fields\n\n\n\n\x03\x07\x03\x02\x12\x03\t\x07\"\n\n\n\x03\x07\
+    \x03\x04\x12\x03\x11\x04\x0c\n\n\n\x03\x07\x03\x05\x12\x03\x11\r\x11\n\n\
+    \n\x03\x07\x03\x01\x12\x03\x11\x120\n\n\n\x03\x07\x03\x03\x12\x03\x1138\
+    \n3\n\x02\x07\x04\x12\x03\x13\x04:\x1a(\x20Use\x20`bytes::Bytes`\x20for\
+    \x20`string`\x20fields\n\n\n\n\x03\x07\x04\x02\x12\x03\t\x07\"\n\n\n\x03\
+    \x07\x04\x04\x12\x03\x13\x04\x0c\n\n\n\x03\x07\x04\x05\x12\x03\x13\r\x11\
+    \n\n\n\x03\x07\x04\x01\x12\x03\x13\x121\n\n\n\x03\x07\x04\x03\x12\x03\
+    \x1349\n\t\n\x01\x07\x12\x04\x16\0!\x01\n7\n\x02\x07\x11\x12\x03\x18\x04\
+    '\x1a,\x20When\x20true,\x20oneof\x20field\x20is\x20generated\x20public\n\
+    \n\n\n\x03\x07\x11\x02\x12\x03\x16\x07%\n\n\n\x03\x07\x11\x04\x12\x03\
+    \x18\x04\x0c\n\n\n\x03\x07\x11\x05\x12\x03\x18\r\x11\n\n\n\x03\x07\x11\
+    \x01\x12\x03\x18\x12\x1e\n\n\n\x03\x07\x11\x03\x12\x03\x18!&\nI\n\x02\
+    \x07\x12\x12\x03\x1a\x04(\x1a>\x20When\x20true\x20all\x20fields\x20are\
+    \x20public,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\x07\x12\
+    \x02\x12\x03\x16\x07%\n\n\n\x03\x07\x12\x04\x12\x03\x1a\x04\x0c\n\n\n\
+    \x03\x07\x12\x05\x12\x03\x1a\r\x11\n\n\n\x03\x07\x12\x01\x12\x03\x1a\x12\
+    \x1f\n\n\n\x03\x07\x12\x03\x12\x03\x1a\"'\nP\n\x02\x07\x13\x12\x03\x1c\
+    \x04-\x1aE\x20When\x20false,\x20`get_`,\x20`set_`,\x20`mut_`\x20etc.\x20\
+    accessors\x20are\x20not\x20generated\n\n\n\n\x03\x07\x13\x02\x12\x03\x16\
+    \x07%\n\n\n\x03\x07\x13\x04\x12\x03\x1c\x04\x0c\n\n\n\x03\x07\x13\x05\
+    \x12\x03\x1c\r\x11\n\n\n\x03\x07\x13\x01\x12\x03\x1c\x12$\n\n\n\x03\x07\
+    \x13\x03\x12\x03\x1c',\n2\n\x02\x07\x14\x12\x03\x1e\x045\x1a'\x20Use\x20\
+    `bytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07\x14\x02\x12\
+    \x03\x16\x07%\n\n\n\x03\x07\x14\x04\x12\x03\x1e\x04\x0c\n\n\n\x03\x07\
+    \x14\x05\x12\x03\x1e\r\x11\n\n\n\x03\x07\x14\x01\x12\x03\x1e\x12,\n\n\n\
+    \x03\x07\x14\x03\x12\x03\x1e/4\n3\n\x02\x07\x15\x12\x03\x20\x046\x1a(\
+    \x20Use\x20`bytes::Bytes`\x20for\x20`string`\x20fields\n\n\n\n\x03\x07\
+    \x15\x02\x12\x03\x16\x07%\n\n\n\x03\x07\x15\x04\x12\x03\x20\x04\x0c\n\n\
+    \n\x03\x07\x15\x05\x12\x03\x20\r\x11\n\n\n\x03\x07\x15\x01\x12\x03\x20\
+    \x12-\n\n\n\x03\x07\x15\x03\x12\x03\x2005\n\t\n\x01\x07\x12\x04#\0,\x01\
+    \nI\n\x02\x07!\x12\x03%\x04.\x1a>\x20When\x20true\x20all\x20fields\x20ar\
+    e\x20public,\x20and\x20not\x20accessors\x20generated\n\n\n\n\x03\x07!\
+    \x02\x12\x03#\x07#\n\n\n\x03\x07!\x04\x12\x03%\x04\x0c\n\n\n\x03\x07!\
+    \x05\x12\x03%\r\x11\n\n\n\x03\x07!\x01\x12\x03%\x12%\n\n\n\x03\x07!\x03\
+    \x12\x03%(-\nP\n\x02\x07\"\x12\x03'\x043\x1aE\x20When\x20false,\x20`get_\
+    `,\x20`set_`,\x20`mut_`\x20etc.\x20accessors\x20are\x20not\x20generated\
+    \n\n\n\n\x03\x07\"\x02\x12\x03#\x07#\n\n\n\x03\x07\"\x04\x12\x03'\x04\
+    \x0c\n\n\n\x03\x07\"\x05\x12\x03'\r\x11\n\n\n\x03\x07\"\x01\x12\x03'\x12\
+    *\n\n\n\x03\x07\"\x03\x12\x03'-2\n2\n\x02\x07#\x12\x03)\x04;\x1a'\x20Use\
+    \x20`bytes::Bytes`\x20for\x20`bytes`\x20fields\n\n\n\n\x03\x07#\x02\x12\
+    \x03#\x07#\n\n\n\x03\x07#\x04\x12\x03)\x04\x0c\n\n\n\x03\x07#\x05\x12\
+    \x03)\r\x11\n\n\n\x03\x07#\x01\x12\x03)\x122\n\n\n\x03\x07#\x03\x12\x03)\
+    5:\n3\n\x02\x07$\x12\x03+\x04<\x1a(\x20Use\x20`bytes::Bytes`\x20for\x20`\
+    string`\x20fields\n\n\n\n\x03\x07$\x02\x12\x03#\x07#\n\n\n\x03\x07$\x04\
+    \x12\x03+\x04\x0c\n\n\n\x03\x07$\x05\x12\x03+\r\x11\n\n\n\x03\x07$\x01\
+    \x12\x03+\x123\n\n\n\x03\x07$\x03\x12\x03+6;\
 ";
 
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+static file_descriptor_proto_lazy: ::protobuf::lazy::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
     ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
-    }
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
+
+// @@protoc_insertion_point(module_scope)
@@ -4,6 +4,7 @@ use std::option;
 use std::default::Default;
 use std::fmt;
 use std::mem;
+use std::ops::Deref;
 
 use clear::Clear;
 
@@ -122,6 +123,28 @@ impl<T> SingularField<T> {
         self.as_mut().into_iter()
     }
 
+    /// Get mutable reference to data, setting it to the result of `f` first if empty.
+    #[inline]
+    pub fn get_or_insert_with<'a, F>(&'a mut self, f: F) -> &'a mut T
+    where
+        F : FnOnce() -> T,
+    {
+        if !self.set {
+            self.value = f();
+            self.set = true;
+        }
+        &mut self.value
+    }
+
+    /// View data as dereferenced reference option, e. g. `Option<&str>` for `String`.
+    #[inline]
+    pub fn as_deref<'a>(&'a self) -> Option<&'a T::Target>
+    where
+        T : Deref,
+    {
+        self.as_ref().map(|v| &**v)
+    }
+
     /// Clear this object.
     /// Note, contained object destructor is not called, so allocated memory could be reused.
     #[inline]
@@ -159,6 +182,12 @@ impl<T : Default> SingularField<T> {
             None
         }
     }
+
+    /// Take data or return default value if empty, clear this object.
+    #[inline]
+    pub fn take_or_default(&mut self) -> T {
+        self.take().unwrap_or_default()
+    }
 }
 
 impl<T> SingularPtrField<T> {
@@ -293,6 +322,32 @@ impl<T> SingularPtrField<T> {
         self.as_mut().into_iter()
     }
 
+    /// Get mutable reference to data, setting it to the result of `f` first if empty.
+    /// Previously allocated box is reused.
+    #[inline]
+    pub fn get_or_insert_with<'a, F>(&'a mut self, f: F) -> &'a mut T
+    where
+        F : FnOnce() -> T,
+    {
+        if !self.set {
+            match self.value {
+                Some(ref mut b) => **b = f(),
+                None => self.value = Some(Box::new(f())),
+            }
+            self.set = true;
+        }
+        self.as_mut().unwrap()
+    }
+
+    /// View data as dereferenced reference option.
+    #[inline]
+    pub fn as_deref<'a>(&'a self) -> Option<&'a T::Target>
+    where
+        T : Deref,
+    {
+        self.as_ref().map(|v| &**v)
+    }
+
     /// Take data as option, leaving this object empty.
     #[inline]
     pub fn take(&mut self) -> Option<T> {
@@ -304,6 +359,15 @@ impl<T> SingularPtrField<T> {
         }
     }
 
+    /// Take data or return default value if empty, leaving this object empty.
+    #[inline]
+    pub fn take_or_default(&mut self) -> T
+    where
+        T : Default,
+    {
+        self.take().unwrap_or_default()
+    }
+
     /// Clear this object, but do not call destructor of underlying data.
     #[inline]
     pub fn clear(&mut self) {
@@ -471,6 +535,7 @@ impl<'a, T> IntoIterator for &'a SingularPtrField<T> {
 mod test {
     use clear::Clear;
     use super::SingularField;
+    use super::SingularPtrField;
 
     #[test]
     fn test_set_default_clears() {
@@ -495,4 +560,23 @@ mod test {
         x.set_default();
         assert_eq!(0, x.as_ref().unwrap().b);
     }
+
+    #[test]
+    fn test_get_or_insert_with() {
+        let mut x: SingularField<String> = SingularField::none();
+        x.get_or_insert_with(|| "a".to_owned()).push('b');
+        x.get_or_insert_with(|| unreachable!()).push('c');
+        assert_eq!(Some("abc"), x.as_deref());
+        assert_eq!("abc", x.take_or_default());
+        assert_eq!("", x.take_or_default());
+
+        let mut y: SingularPtrField<Vec<u8>> = SingularPtrField::none();
+        y.get_or_insert_with(Vec::new).push(1);
+        assert_eq!(Some(&[1][..]), y.as_deref());
+        y.clear();
+        assert_eq!(None, y.as_deref());
+        y.get_or_insert_with(|| vec![2]);
+        assert_eq!(vec![2], y.take_or_default());
+        assert!(y.take_or_default().is_empty());
+    }
 }
@@ -0,0 +1,125 @@
+/// Caller-owned storage for sizes of messages computed before serialization.
+///
+/// `Message::compute_size` stores sizes in `cached_size` fields of messages,
+/// so serializing the same message from several threads concurrently
+/// is awkward. `SizeCache` is an alternative: sizes of a message and
+/// all nested messages are stored here, and serialization with
+/// `Message::write_to_with_sizes` does not modify the message.
+///
+/// Sizes are stored in the order messages are serialized, so the cache
+/// must be filled by `Message::compute_size_with_cache` and then
+/// consumed by `Message::write_to_with_size_cache` of the same unmodified message.
+#[derive(Debug, Default, Clone)]
+pub struct SizeCache {
+    sizes: Vec<u32>,
+    // position of next size to be read when writing
+    pos: usize,
+}
+
+impl SizeCache {
+    pub fn new() -> SizeCache {
+        Default::default()
+    }
+
+    /// Forget all sizes, keep allocated memory.
+    pub fn clear(&mut self) {
+        self.sizes.clear();
+        self.pos = 0;
+    }
+
+    /// Number of sizes stored.
+    pub fn len(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// Reserve a slot for the message which size is being computed.
+    /// Used in generated code.
+    #[doc(hidden)]
+    pub fn reserve_slot(&mut self) -> usize {
+        self.sizes.push(0);
+        self.sizes.len() - 1
+    }
+
+    /// Store size of message in previously reserved slot.
+    /// Used in generated code.
+    #[doc(hidden)]
+    pub fn set_slot(&mut self, slot: usize, size: u32) {
+        self.sizes[slot] = size;
+    }
+
+    /// Get size of next message to be written without consuming it.
+    /// Used in generated code.
+    #[doc(hidden)]
+    pub fn peek_size(&self) -> u32 {
+        match self.sizes.get(self.pos) {
+            Some(&size) => size,
+            None => panic!("size cache is exhausted, compute_size_with_cache must be called first"),
+        }
+    }
+
+    /// Get size of next message to be written.
+    /// Used in generated code.
+    #[doc(hidden)]
+    pub fn take_size(&mut self) -> u32 {
+        let size = self.peek_size();
+        self.pos += 1;
+        size
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::SizeCache;
+    use core::Message;
+    use well_known_types::ListValue;
+    use well_known_types::Struct;
+    use well_known_types::Value;
+
+    #[test]
+    fn slots_are_read_in_reservation_order() {
+        let mut sizes = SizeCache::new();
+        let outer = sizes.reserve_slot();
+        let inner = sizes.reserve_slot();
+        sizes.set_slot(inner, 3);
+        sizes.set_slot(outer, 10);
+        assert_eq!(2, sizes.len());
+        assert_eq!(10, sizes.peek_size());
+        assert_eq!(10, sizes.take_size());
+        assert_eq!(3, sizes.take_size());
+
+        sizes.clear();
+        assert_eq!(0, sizes.len());
+    }
+
+    #[test]
+    fn write_nested_messages() {
+        let mut inner = Struct::new();
+        inner.mut_fields().insert("s".to_owned(), {
+            let mut v = Value::new();
+            v.set_string_value("abc".to_owned());
+            v
+        });
+        let mut list = ListValue::new();
+        for i in 0..3 {
+            let mut v = Value::new();
+            v.set_number_value(i as f64);
+            list.mut_values().push(v);
+        }
+        let mut outer = Struct::new();
+        outer.mut_fields().insert("inner".to_owned(), {
+            let mut v = Value::new();
+            v.set_struct_value(inner);
+            v
+        });
+        outer.mut_fields().insert("list".to_owned(), {
+            let mut v = Value::new();
+            v.set_list_value(list);
+            v
+        });
+
+        let mut sizes = SizeCache::new();
+        let bytes = outer.write_to_bytes_with_sizes(&mut sizes).unwrap();
+        assert_eq!(outer.write_to_bytes().unwrap(), bytes);
+    }
+}
@@ -2,15 +2,19 @@ use std::mem;
 use std::io;
 use std::io::{BufRead, Read};
 use std::io::Write;
-use std::slice;
+use std::cmp;
 
 #[cfg(feature = "bytes")]
 use bytes::Bytes;
 #[cfg(feature = "bytes")]
+use bytes::BytesMut;
+#[cfg(feature = "bytes")]
 use chars::Chars;
 
 use varint;
 use misc::remaining_capacity_as_slice_mut;
+#[cfg(feature = "bytes")]
+use misc::bytes_mut_remaining_capacity_as_slice_mut;
 use misc::remove_lifetime_mut;
 use core::Message;
 use core::ProtobufEnum;
@@ -24,6 +28,7 @@ use zigzag::encode_zig_zag_64;
 use error::ProtobufResult;
 use error::ProtobufError;
 use error::WireError;
+use error::WireErrorLocation;
 use buf_read_iter::BufReadIter;
 
 // Equal to the default buffer size of `BufWriter`, so when
@@ -31,7 +36,7 @@ use buf_read_iter::BufReadIter;
 const OUTPUT_STREAM_BUFFER_SIZE: usize = 8 * 1024;
 
 // Default recursion level limit. 100 is the default value of C++'s implementation.
-const DEFAULT_RECURSION_LIMIT: u32 = 100;
+pub(crate) const DEFAULT_RECURSION_LIMIT: u32 = 100;
 
 
 pub mod wire_format {
@@ -121,10 +126,72 @@ pub mod wire_format {
 
 }
 
+/// Value of field returned by `CodedInputStream::read_event`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum EventValue {
+    Varint(u64),
+    Fixed64(u64),
+    Fixed32(u32),
+    /// Length of payload of length-delimited field.
+    /// Payload itself is not read by `read_event`.
+    LengthDelimited(u32),
+    /// Start or end of group, no value.
+    Group,
+}
+
+/// Field returned by `CodedInputStream::read_event`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct DecodeEvent {
+    pub field_number: u32,
+    pub wire_type: wire_format::WireType,
+    pub value: EventValue,
+}
+
+/// What to do with invalid UTF-8 in `string` fields.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Utf8Policy {
+    /// Fail with `WireError::Utf8Error`.
+    Error,
+    /// Replace invalid sequences with `U+FFFD`.
+    Lossy,
+    /// Keep original bytes in `Chars` fields (with `carllerche_bytes_for_string`
+    /// option): text is lossy, but original bytes are available with
+    /// `Chars::raw_bytes` and written back when message is serialized.
+    /// `String` fields are read as with `Lossy`.
+    Preserve,
+}
+
+/// Statistics of input read by `CodedInputStream`, returned by `CodedInputStream::stats`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct ParseStats {
+    /// Max depth of nested messages, `0` if no nested messages were read.
+    pub max_depth: u32,
+    /// Number of tags read, including tags of fields of nested messages.
+    pub fields: u64,
+    /// Number of bytes of unknown and skipped field values, not including their tags.
+    pub unknown_bytes: u64,
+}
+
 pub struct CodedInputStream<'a> {
     source: BufReadIter<'a>,
     recursion_level: u32,
     recursion_limit: u32,
+    utf8_policy: Utf8Policy,
+    canonical: bool,
+    locate_errors: bool,
+    stats: ParseStats,
+    // end position of payload of last length-delimited event
+    event_payload_end: Option<u64>,
+    // number of field of last tag read, `0` before first tag of a message
+    field_number: u32,
+}
+
+fn le_bytes_4(b: &[u8]) -> [u8; 4] {
+    [b[0], b[1], b[2], b[3]]
+}
+
+fn le_bytes_8(b: &[u8]) -> [u8; 8] {
+    [b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7]]
 }
 
 impl<'a> CodedInputStream<'a> {
@@ -140,6 +207,10 @@ impl<'a> CodedInputStream<'a> {
         CodedInputStream::from_buf_read_iter(BufReadIter::from_byte_slice(bytes))
     }
 
+    /// Stream reading from `Bytes` object.
+    ///
+    /// `read_carllerche_bytes` and `read_carllerche_chars` of this stream
+    /// return slices of `bytes` sharing its buffer instead of copying data.
     #[cfg(feature = "bytes")]
     pub fn from_carllerche_bytes(bytes: &'a Bytes) -> CodedInputStream<'a> {
         CodedInputStream::from_buf_read_iter(BufReadIter::from_bytes(bytes))
@@ -150,6 +221,12 @@ impl<'a> CodedInputStream<'a> {
             source: source,
             recursion_level: 0,
             recursion_limit: DEFAULT_RECURSION_LIMIT,
+            utf8_policy: Utf8Policy::Error,
+            canonical: false,
+            locate_errors: false,
+            stats: ParseStats::default(),
+            event_payload_end: None,
+            field_number: 0,
         }
     }
 
@@ -158,12 +235,68 @@ impl<'a> CodedInputStream<'a> {
         self.recursion_limit = limit;
     }
 
+    /// Replace invalid UTF-8 sequences in strings with `U+FFFD`
+    /// instead of returning an error.
+    pub fn set_lossy_utf8(&mut self, lossy: bool) {
+        self.utf8_policy = if lossy {
+            Utf8Policy::Lossy
+        } else {
+            Utf8Policy::Error
+        };
+    }
+
+    /// Set handling of invalid UTF-8 in strings, `Utf8Policy::Error` by default.
+    pub fn set_utf8_policy(&mut self, policy: Utf8Policy) {
+        self.utf8_policy = policy;
+    }
+
+    /// Accept only canonical encoding of varints and bools, `false` by default.
+    ///
+    /// When enabled, varints with redundant trailing zero bytes (e. g. `80 00` for `0`)
+    /// or with bits beyond 64th, and bools other than `0` and `1`
+    /// are rejected with `WireError::NonCanonical`.
+    pub fn set_canonical(&mut self, canonical: bool) {
+        self.canonical = canonical;
+    }
+
+    /// Wrap wire errors of parsed messages in `WireError::Located`
+    /// with their location in input, `false` by default.
+    ///
+    /// Use `ProtobufError::wire_error` and `ProtobufError::location`
+    /// to inspect such errors.
+    pub fn set_locate_errors(&mut self, locate_errors: bool) {
+        self.locate_errors = locate_errors;
+    }
+
+    /// Statistics of input read so far.
+    ///
+    /// Services parsing messages from untrusted sources can use these
+    /// to monitor inputs and to choose limits like recursion limit.
+    pub fn stats(&self) -> ParseStats {
+        self.stats
+    }
+
+    /// Reset statistics, e. g. before reading next message from the same stream.
+    pub fn reset_stats(&mut self) {
+        self.stats = ParseStats::default();
+    }
+
+    // `len` is length of varint and `last` is its last byte
+    #[inline]
+    fn check_canonical_varint(&self, len: usize, last: u8) -> ProtobufResult<()> {
+        if (len > 1 && last == 0) || (len == 10 && last > 1) {
+            return Err(ProtobufError::WireError(WireError::NonCanonical));
+        }
+        Ok(())
+    }
+
     #[inline]
     pub(crate) fn incr_recursion(&mut self) -> ProtobufResult<()> {
         if self.recursion_level >= self.recursion_limit {
             return Err(ProtobufError::WireError(WireError::OverRecursionLimit));
         }
         self.recursion_level += 1;
+        self.stats.max_depth = cmp::max(self.stats.max_depth, self.recursion_level);
         Ok(())
     }
 
@@ -228,6 +361,9 @@ impl<'a> CodedInputStream<'a> {
             r = r | (((b & 0x7f) as u64) << (i * 7));
             i += 1;
             if b < 0x80 {
+                if self.canonical {
+                    self.check_canonical_varint(i, b)?;
+                }
                 return Ok(r);
             }
         }
@@ -235,69 +371,26 @@ impl<'a> CodedInputStream<'a> {
 
     #[inline(always)]
     pub fn read_raw_varint64(&mut self) -> ProtobufResult<u64> {
-        'slow: loop {
-            let ret;
-            let consume;
-
-            loop {
-                let rem = self.source.remaining_in_buf();
-
-                if rem.len() >= 1 {
-                    // most varints are in practice fit in 1 byte
-                    if rem[0] < 0x80 {
-                        ret = rem[0] as u64;
-                        consume = 1;
-                    } else {
-                        // handle case of two bytes too
-                        if rem.len() >= 2 && rem[1] < 0x80 {
-                            ret = (rem[0] & 0x7f) as u64 | (rem[1] as u64) << 7;
-                            consume = 2;
-                        } else if rem.len() >= 10 {
-                            // Read from array when buf at at least 10 bytes,
-                            // max len for varint.
-                            let mut r: u64 = 0;
-                            let mut i: usize = 0;
-                            {
-                                let rem = rem;
-                                loop {
-                                    if i == 10 {
-                                        return Err(
-                                            ProtobufError::WireError(WireError::IncorrectVarint),
-                                        );
-                                    }
-
-                                    let b = if true {
-                                        // skip range check
-                                        unsafe { *rem.get_unchecked(i) }
-                                    } else {
-                                        rem[i]
-                                    };
-
-                                    // TODO: may overflow if i == 9
-                                    r = r | (((b & 0x7f) as u64) << (i * 7));
-                                    i += 1;
-                                    if b < 0x80 {
-                                        break;
-                                    }
-                                }
-                            }
-                            consume = i;
-                            ret = r;
-                        } else {
-                            break 'slow;
+        let (r, consume) = {
+            let rem = self.source.remaining_in_buf();
+            // most varints are in practice fit in 1 byte
+            if rem.len() >= 1 && rem[0] < 0x80 {
+                (rem[0] as u64, 1)
+            } else {
+                match varint::decode_varint64(rem)? {
+                    Some((r, len)) => {
+                        if self.canonical {
+                            self.check_canonical_varint(len, rem[len - 1])?;
                         }
+                        (r, len)
                     }
-                } else {
-                    break 'slow;
+                    // varint crosses buffer boundary
+                    None => return self.read_raw_varint64_slow(),
                 }
-                break;
             }
-
-            self.source.consume(consume);
-            return Ok(ret);
-        }
-
-        self.read_raw_varint64_slow()
+        };
+        self.source.consume(consume);
+        Ok(r)
     }
 
     #[inline(always)]
@@ -306,31 +399,36 @@ impl<'a> CodedInputStream<'a> {
     }
 
 
+    // Fixed-size values are decoded from bytes explicitly
+    // rather than by reinterpreting memory, so decoding does not depend on
+    // byte order of the target.
+
     pub fn read_raw_little_endian32(&mut self) -> ProtobufResult<u32> {
-        let mut r = 0u32;
-        let bytes: &mut [u8] = unsafe {
-            let p: *mut u8 = mem::transmute(&mut r);
-            slice::from_raw_parts_mut(p, mem::size_of::<u32>())
-        };
-        self.read(bytes)?;
-        Ok(r.to_le())
+        let mut bytes = [0; 4];
+        self.read(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
     }
 
     pub fn read_raw_little_endian64(&mut self) -> ProtobufResult<u64> {
-        let mut r = 0u64;
-        let bytes: &mut [u8] = unsafe {
-            let p: *mut u8 = mem::transmute(&mut r);
-            slice::from_raw_parts_mut(p, mem::size_of::<u64>())
-        };
-        self.read(bytes)?;
-        Ok(r.to_le())
+        let mut bytes = [0; 8];
+        self.read(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
     }
 
+    /// Read field tag.
+    ///
+    /// Tags with field number zero or with reserved wire types 6 and 7 are rejected
+    /// with `WireError::IncorrectTag` in all parsing functions: length of values
+    /// of unknown wire types is not known, so such fields cannot be skipped.
     #[inline]
     pub fn read_tag(&mut self) -> ProtobufResult<wire_format::Tag> {
         let v = self.read_raw_varint32()?;
         match wire_format::Tag::new(v) {
-            Some(tag) => Ok(tag),
+            Some(tag) => {
+                self.field_number = tag.field_number();
+                self.stats.fields += 1;
+                Ok(tag)
+            }
             None => Err(ProtobufError::WireError(WireError::IncorrectTag(v))),
         }
     }
@@ -342,13 +440,11 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn read_double(&mut self) -> ProtobufResult<f64> {
-        let bits = self.read_raw_little_endian64()?;
-        unsafe { Ok(mem::transmute::<u64, f64>(bits)) }
+        self.read_raw_little_endian64().map(f64::from_bits)
     }
 
     pub fn read_float(&mut self) -> ProtobufResult<f32> {
-        let bits = self.read_raw_little_endian32()?;
-        unsafe { Ok(mem::transmute::<u32, f32>(bits)) }
+        self.read_raw_little_endian32().map(f32::from_bits)
     }
 
     pub fn read_int64(&mut self) -> ProtobufResult<i64> {
@@ -392,7 +488,11 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn read_bool(&mut self) -> ProtobufResult<bool> {
-        self.read_raw_varint32().map(|v| v != 0)
+        let v = self.read_raw_varint64()?;
+        if self.canonical && v > 1 {
+            return Err(ProtobufError::WireError(WireError::NonCanonical));
+        }
+        Ok(v != 0)
     }
 
     pub fn read_enum<E : ProtobufEnum>(&mut self) -> ProtobufResult<E> {
@@ -403,33 +503,53 @@ impl<'a> CodedInputStream<'a> {
         }
     }
 
-    pub fn read_repeated_packed_double_into(
+    // Read packed fixed-size values. If whole field is in the buffer,
+    // values are decoded from it in one pass without per-value limit checks.
+    fn read_repeated_packed_fixed_into<T>(
         &mut self,
-        target: &mut Vec<f64>,
+        target: &mut Vec<T>,
+        size: usize,
+        decode: fn(&[u8]) -> T,
+        read: fn(&mut CodedInputStream<'a>) -> ProtobufResult<T>,
     ) -> ProtobufResult<()> {
         let len = self.read_raw_varint64()?;
 
-        target.reserve((len / 4) as usize);
+        target.reserve((len / size as u64) as usize);
 
         let old_limit = self.push_limit(len)?;
+        if len % size as u64 == 0 && self.source.remaining_in_buf_len() as u64 == len {
+            target.extend(self.source.remaining_in_buf().chunks(size).map(decode));
+            self.source.consume(len as usize);
+        }
         while !self.eof()? {
-            target.push(self.read_double()?);
+            target.push(read(self)?);
         }
         self.pop_limit(old_limit);
         Ok(())
     }
 
-    pub fn read_repeated_packed_float_into(&mut self, target: &mut Vec<f32>) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 4) as usize);
+    pub fn read_repeated_packed_double_into(
+        &mut self,
+        target: &mut Vec<f64>,
+    ) -> ProtobufResult<()> {
+        self.read_repeated_packed_fixed_into(
+            target,
+            8,
+            |b| f64::from_bits(u64::from_le_bytes(le_bytes_8(b))),
+            |is| is.read_double(),
+        )
+    }
 
-        let old_limit = self.push_limit(len)?;
-        while !self.eof()? {
-            target.push(self.read_float()?);
-        }
-        self.pop_limit(old_limit);
-        Ok(())
+    pub fn read_repeated_packed_float_into(
+        &mut self,
+        target: &mut Vec<f32>,
+    ) -> ProtobufResult<()> {
+        self.read_repeated_packed_fixed_into(
+            target,
+            4,
+            |b| f32::from_bits(u32::from_le_bytes(le_bytes_4(b))),
+            |is| is.read_float(),
+        )
     }
 
     pub fn read_repeated_packed_int64_into(&mut self, target: &mut Vec<i64>) -> ProtobufResult<()> {
@@ -508,64 +628,48 @@ impl<'a> CodedInputStream<'a> {
         &mut self,
         target: &mut Vec<u64>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 8) as usize);
-
-        let old_limit = self.push_limit(len)?;
-        while !self.eof()? {
-            target.push(self.read_fixed64()?);
-        }
-        self.pop_limit(old_limit);
-        Ok(())
+        self.read_repeated_packed_fixed_into(
+            target,
+            8,
+            |b| u64::from_le_bytes(le_bytes_8(b)),
+            |is| is.read_fixed64(),
+        )
     }
 
     pub fn read_repeated_packed_fixed32_into(
         &mut self,
         target: &mut Vec<u32>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 4) as usize);
-
-        let old_limit = self.push_limit(len)?;
-        while !self.eof()? {
-            target.push(self.read_fixed32()?);
-        }
-        self.pop_limit(old_limit);
-        Ok(())
+        self.read_repeated_packed_fixed_into(
+            target,
+            4,
+            |b| u32::from_le_bytes(le_bytes_4(b)),
+            |is| is.read_fixed32(),
+        )
     }
 
     pub fn read_repeated_packed_sfixed64_into(
         &mut self,
         target: &mut Vec<i64>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 8) as usize);
-
-        let old_limit = self.push_limit(len)?;
-        while !self.eof()? {
-            target.push(self.read_sfixed64()?);
-        }
-        self.pop_limit(old_limit);
-        Ok(())
+        self.read_repeated_packed_fixed_into(
+            target,
+            8,
+            |b| i64::from_le_bytes(le_bytes_8(b)),
+            |is| is.read_sfixed64(),
+        )
     }
 
     pub fn read_repeated_packed_sfixed32_into(
         &mut self,
         target: &mut Vec<i32>,
     ) -> ProtobufResult<()> {
-        let len = self.read_raw_varint64()?;
-
-        target.reserve((len / 4) as usize);
-
-        let old_limit = self.push_limit(len)?;
-        while !self.eof()? {
-            target.push(self.read_sfixed32()?);
-        }
-        self.pop_limit(old_limit);
-        Ok(())
+        self.read_repeated_packed_fixed_into(
+            target,
+            4,
+            |b| i32::from_le_bytes(le_bytes_4(b)),
+            |is| is.read_sfixed32(),
+        )
     }
 
     pub fn read_repeated_packed_bool_into(&mut self, target: &mut Vec<bool>) -> ProtobufResult<()> {
@@ -598,6 +702,16 @@ impl<'a> CodedInputStream<'a> {
     pub fn read_unknown(
         &mut self,
         wire_type: wire_format::WireType,
+    ) -> ProtobufResult<UnknownValue> {
+        let start = self.pos();
+        let r = self.read_unknown_value(wire_type)?;
+        self.stats.unknown_bytes += self.pos() - start;
+        Ok(r)
+    }
+
+    fn read_unknown_value(
+        &mut self,
+        wire_type: wire_format::WireType,
     ) -> ProtobufResult<UnknownValue> {
         match wire_type {
             wire_format::WireTypeVarint => {
@@ -620,6 +734,71 @@ impl<'a> CodedInputStream<'a> {
         self.read_unknown(wire_type).map(|_| ())
     }
 
+    /// Skip fields of group with given field number, which start group tag is already read,
+    /// including nested groups, until the matching end group tag.
+    ///
+    /// End group tag with field number other than number of the innermost open group
+    /// is an error, like in other protobuf implementations.
+    pub fn skip_group(&mut self, field_number: u32) -> ProtobufResult<()> {
+        // field numbers of open groups, iterative to not overflow the stack on crafted input
+        let mut groups = vec![field_number];
+        while let Some(&open) = groups.last() {
+            let (number, wire_type) = self.read_tag_unpack()?;
+            match wire_type {
+                wire_format::WireTypeStartGroup => groups.push(number),
+                wire_format::WireTypeEndGroup if number == open => {
+                    groups.pop();
+                }
+                // fails on end group tag of other field
+                _ => self.skip_field(wire_type)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Read fields of group with given field number, which start group tag is already read,
+    /// including nested groups, until the matching end group tag.
+    ///
+    /// Nesting of groups is limited by recursion limit, like nesting of messages.
+    #[cfg(feature = "with-groups")]
+    pub fn read_unknown_group(&mut self, field_number: u32) -> ProtobufResult<UnknownFields> {
+        let start = self.pos();
+        let r = self.read_unknown_group_fields(field_number)?;
+        self.stats.unknown_bytes += self.pos() - start;
+        Ok(r)
+    }
+
+    #[cfg(feature = "with-groups")]
+    fn read_unknown_group_fields(&mut self, field_number: u32) -> ProtobufResult<UnknownFields> {
+        self.incr_recursion()?;
+        let r = self.read_unknown_group_fields_no_recursion_check(field_number);
+        self.decr_recursion();
+        r
+    }
+
+    #[cfg(feature = "with-groups")]
+    fn read_unknown_group_fields_no_recursion_check(
+        &mut self,
+        field_number: u32,
+    ) -> ProtobufResult<UnknownFields> {
+        let mut fields = UnknownFields::new();
+        loop {
+            let (number, wire_type) = self.read_tag_unpack()?;
+            match wire_type {
+                wire_format::WireTypeStartGroup => {
+                    let group = self.read_unknown_group_fields(number)?;
+                    fields.add_group(number, group);
+                }
+                wire_format::WireTypeEndGroup if number == field_number => return Ok(fields),
+                // fails on end group tag of other field
+                _ => {
+                    let value = self.read_unknown_value(wire_type)?;
+                    fields.add_value(number, value);
+                }
+            }
+        }
+    }
+
     /// Read raw bytes into the supplied vector.  The vector will be resized as needed and
     /// overwritten.
     pub fn read_raw_bytes_into(&mut self, count: u32, target: &mut Vec<u8>) -> ProtobufResult<()> {
@@ -642,8 +821,19 @@ impl<'a> CodedInputStream<'a> {
     }
 
     pub fn skip_raw_bytes(&mut self, count: u32) -> ProtobufResult<()> {
-        // TODO: make it more efficient
-        self.read_raw_bytes(count).map(|_| ())
+        let mut rem = count as usize;
+        while rem > 0 {
+            let len = {
+                let buf = self.source.fill_buf()?;
+                if buf.is_empty() {
+                    return Err(ProtobufError::WireError(WireError::UnexpectedEof));
+                }
+                cmp::min(buf.len(), rem)
+            };
+            self.source.consume(len);
+            rem -= len;
+        }
+        Ok(())
     }
 
     pub fn read_bytes(&mut self) -> ProtobufResult<Vec<u8>> {
@@ -652,16 +842,33 @@ impl<'a> CodedInputStream<'a> {
         Ok(r)
     }
 
+    /// Read length-delimited `bytes` value.
+    ///
+    /// When the stream is created with `from_carllerche_bytes`,
+    /// result is a slice of the input, otherwise data is copied.
+    /// Note `Bytes` stores short values (up to 31 bytes on 64-bit platforms)
+    /// inline, such values are copied but not allocated.
     #[cfg(feature = "bytes")]
     pub fn read_carllerche_bytes(&mut self) -> ProtobufResult<Bytes> {
         let len = self.read_raw_varint32()?;
         self.read_raw_callerche_bytes(len as usize)
     }
 
+    /// Read length-delimited `string` value, same as `read_carllerche_bytes`
+    /// with UTF-8 validation.
     #[cfg(feature = "bytes")]
     pub fn read_carllerche_chars(&mut self) -> ProtobufResult<Chars> {
         let bytes = self.read_carllerche_bytes()?;
-        Ok(Chars::from_bytes(bytes)?)
+        match Chars::from_bytes(bytes.clone()) {
+            Ok(chars) => Ok(chars),
+            Err(e) => match self.utf8_policy {
+                Utf8Policy::Error => Err(e.into()),
+                Utf8Policy::Lossy => {
+                    Ok(Chars::from(String::from_utf8_lossy(&bytes).into_owned()))
+                }
+                Utf8Policy::Preserve => Ok(Chars::from_bytes_preserving(bytes)),
+            },
+        }
     }
 
     pub fn read_bytes_into(&mut self, target: &mut Vec<u8>) -> ProtobufResult<()> {
@@ -676,35 +883,197 @@ impl<'a> CodedInputStream<'a> {
         Ok(r)
     }
 
+    /// Read string replacing content of `target`, reusing its buffer.
     pub fn read_string_into(&mut self, target: &mut String) -> ProtobufResult<()> {
-        // assert string is empty, otherwize UTF-8 validation is too expensive
-        assert!(target.is_empty());
+        // previous content is replaced, so only read bytes need UTF-8 validation
+        target.clear();
         // take target's buffer
         let mut vec = mem::replace(target, String::new()).into_bytes();
         self.read_bytes_into(&mut vec)?;
 
         let s = match String::from_utf8(vec) {
             Ok(t) => t,
-            Err(_) => return Err(ProtobufError::WireError(WireError::Utf8Error)),
+            Err(e) => {
+                if self.utf8_policy == Utf8Policy::Error {
+                    return Err(ProtobufError::WireError(WireError::Utf8Error));
+                }
+                String::from_utf8_lossy(e.as_bytes()).into_owned()
+            }
         };
         mem::replace(target, s);
         Ok(())
     }
 
-    pub fn merge_message<M : Message>(&mut self, message: &mut M) -> ProtobufResult<()> {
+    /// Read next field of the message without decoding message into a struct.
+    /// Returns `None` on EOF or when limit is reached.
+    ///
+    /// Payload of length-delimited field is not read by this function. It can be
+    /// read with `read_event_payload_into`, read as nested message fields
+    /// after `enter_event_payload`, or it is skipped by the next `read_event` call.
+    pub fn read_event(&mut self) -> ProtobufResult<Option<DecodeEvent>> {
+        self.skip_event_payload()?;
+        if self.eof()? {
+            return Ok(None);
+        }
+        let (field_number, wire_type) = self.read_tag_unpack()?;
+        let value = match wire_type {
+            wire_format::WireTypeVarint => EventValue::Varint(self.read_raw_varint64()?),
+            wire_format::WireTypeFixed64 => EventValue::Fixed64(self.read_raw_little_endian64()?),
+            wire_format::WireTypeFixed32 => EventValue::Fixed32(self.read_raw_little_endian32()?),
+            wire_format::WireTypeLengthDelimited => {
+                let len = self.read_raw_varint32()?;
+                self.event_payload_end = Some(self.pos() + len as u64);
+                EventValue::LengthDelimited(len)
+            }
+            wire_format::WireTypeStartGroup | wire_format::WireTypeEndGroup => EventValue::Group,
+        };
+        Ok(Some(DecodeEvent {
+            field_number: field_number,
+            wire_type: wire_type,
+            value: value,
+        }))
+    }
+
+    /// Skip unread part of payload of the last length-delimited event.
+    pub fn skip_event_payload(&mut self) -> ProtobufResult<()> {
+        if let Some(end) = self.event_payload_end.take() {
+            let pos = self.pos();
+            if pos < end {
+                self.skip_raw_bytes((end - pos) as u32)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Read payload of the last length-delimited event into the supplied vector.
+    /// The vector will be resized as needed and overwritten.
+    pub fn read_event_payload_into(&mut self, target: &mut Vec<u8>) -> ProtobufResult<()> {
+        let end = self.event_payload_end
+            .take()
+            .expect("last event is not length-delimited");
+        let len = end - self.pos();
+        self.read_raw_bytes_into(len as u32, target)
+    }
+
+    /// Limit the stream to payload of the last length-delimited event,
+    /// so following `read_event` calls return fields of the nested message.
+    ///
+    /// Returns the previous limit, which must be restored with `pop_limit`.
+    pub fn enter_event_payload(&mut self) -> ProtobufResult<u64> {
+        let end = self.event_payload_end
+            .take()
+            .expect("last event is not length-delimited");
+        let len = end - self.pos();
+        self.push_limit(len)
+    }
+
+    /// Attach location in input to `WireError` if `locate_errors` is enabled,
+    /// unless it is located already.
+    pub(crate) fn locate_error(&self, e: ProtobufError) -> ProtobufError {
+        if !self.locate_errors {
+            return e;
+        }
+        match e {
+            e @ ProtobufError::WireError(WireError::Located(..)) => e,
+            ProtobufError::WireError(e) => {
+                let location = WireErrorLocation {
+                    offset: self.pos(),
+                    field_number: if self.field_number != 0 {
+                        Some(self.field_number)
+                    } else {
+                        None
+                    },
+                    path: Vec::new(),
+                };
+                ProtobufError::WireError(WireError::Located(Box::new(e), Box::new(location)))
+            }
+            e => e,
+        }
+    }
+
+    /// Read fields of nested message (or map entry) with `read`.
+    ///
+    /// `index` is occurrence index of the nested message field in containing message.
+    /// Path of nested messages is only built when error is returned,
+    /// so successful parsing does not pay for error locations.
+    pub(crate) fn read_nested<T, F>(&mut self, index: u32, read: F) -> ProtobufResult<T>
+    where
+        F : FnOnce(&mut CodedInputStream<'a>) -> ProtobufResult<T>,
+    {
+        let field_number = self.field_number;
+        self.field_number = 0;
+        let r = read(self).map_err(|e| {
+            let mut e = self.locate_error(e);
+            // top-level length-delimited messages are not fields
+            if field_number != 0 {
+                if let ProtobufError::WireError(WireError::Located(_, ref mut location)) = e {
+                    location.path.insert(0, (field_number, index));
+                }
+            }
+            e
+        });
+        self.field_number = field_number;
+        r
+    }
+
+    /// `merge_message` for `index`-th occurrence of a repeated field.
+    pub(crate) fn merge_message_at<M : Message>(
+        &mut self,
+        index: u32,
+        message: &mut M,
+    ) -> ProtobufResult<()> {
         let len = self.read_raw_varint64()?;
         let old_limit = self.push_limit(len)?;
-        message.merge_from(self)?;
+        self.read_nested(index, |is| message.merge_from(is))?;
         self.pop_limit(old_limit);
         Ok(())
     }
 
+    pub fn merge_message<M : Message>(&mut self, message: &mut M) -> ProtobufResult<()> {
+        self.merge_message_at(0, message)
+    }
+
     pub fn read_message<M : Message>(&mut self) -> ProtobufResult<M> {
         let mut r: M = Message::new();
         self.merge_message(&mut r)?;
         r.check_initialized()?;
         Ok(r)
     }
+
+    /// Read message occupying exactly next `len` bytes.
+    ///
+    /// Unlike `read_message`, length is not read from the stream, so this
+    /// function can be used for container formats which store length
+    /// of message elsewhere and interleave messages with other data.
+    ///
+    /// When this function returns, stream is positioned right after the message
+    /// unless input ends before `len` bytes: if message is malformed
+    /// or has required fields unset, the rest of the message is skipped
+    /// and an error is returned, so the next section can still be read.
+    /// If input is shorter than `len`, `WireError::UnexpectedEof` is returned
+    /// and the stream is at the end of input.
+    pub fn read_message_bounded<M : Message>(&mut self, len: u64) -> ProtobufResult<M> {
+        let old_limit = self.push_limit(len)?;
+        let mut r: M = Message::new();
+        let merged = self.read_nested(0, |is| r.merge_from(is));
+        // message is also read until end of input, which may be before limit
+        let skipped = self.skip_to_limit();
+        self.pop_limit(old_limit);
+        skipped?;
+        merged?;
+        r.check_initialized()?;
+        Ok(r)
+    }
+
+    fn skip_to_limit(&mut self) -> ProtobufResult<()> {
+        loop {
+            let rem = self.bytes_until_limit();
+            if rem == 0 {
+                return Ok(());
+            }
+            self.skip_raw_bytes(cmp::min(rem, u32::max_value() as u64) as u32)?;
+        }
+    }
 }
 
 impl<'a> Read for CodedInputStream<'a> {
@@ -753,6 +1122,19 @@ impl<'a> WithCodedOutputStream for &'a mut Vec<u8> {
     }
 }
 
+#[cfg(feature = "bytes")]
+impl<'a> WithCodedOutputStream for &'a mut BytesMut {
+    fn with_coded_output_stream<T, F>(self, cb: F) -> ProtobufResult<T>
+    where
+        F : FnOnce(&mut CodedOutputStream) -> ProtobufResult<T>,
+    {
+        let mut os = CodedOutputStream::carllerche_bytes_mut(self);
+        let r = cb(&mut os)?;
+        os.flush()?;
+        Ok(r)
+    }
+}
+
 pub fn with_coded_output_stream_to_bytes<F>(cb: F) -> ProtobufResult<Vec<u8>>
 where
     F : FnOnce(&mut CodedOutputStream) -> ProtobufResult<()>,
@@ -821,6 +1203,8 @@ impl<'a> WithCodedInputStream for &'a Bytes {
 enum OutputTarget<'a> {
     Write(&'a mut Write, Vec<u8>),
     Vec(&'a mut Vec<u8>),
+    #[cfg(feature = "bytes")]
+    BytesMut(&'a mut BytesMut),
     Bytes,
 }
 
@@ -874,6 +1258,20 @@ impl<'a> CodedOutputStream<'a> {
         }
     }
 
+    /// `CodedOutputStream` which writes directly to `BytesMut`.
+    ///
+    /// Caller should call `flush` at the end to guarantee bytes contain
+    /// all written data. Written data can then be frozen into `Bytes`
+    /// and passed to network code without copying.
+    #[cfg(feature = "bytes")]
+    pub fn carllerche_bytes_mut(bytes: &'a mut BytesMut) -> CodedOutputStream<'a> {
+        CodedOutputStream {
+            target: OutputTarget::BytesMut(bytes),
+            buffer: &mut [],
+            position: 0,
+        }
+    }
+
     pub fn check_eof(&self) {
         match self.target {
             OutputTarget::Bytes => {
@@ -883,6 +1281,10 @@ impl<'a> CodedOutputStream<'a> {
             OutputTarget::Vec(..) => {
                 panic!("must not be called with Writer or Vec");
             }
+            #[cfg(feature = "bytes")]
+            OutputTarget::BytesMut(..) => {
+                panic!("must not be called with BytesMut");
+            }
         }
     }
 
@@ -900,6 +1302,18 @@ impl<'a> CodedOutputStream<'a> {
                 self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
                 self.position = 0;
             },
+            #[cfg(feature = "bytes")]
+            OutputTarget::BytesMut(ref mut bytes) => unsafe {
+                let bytes_len = bytes.len();
+                assert!(bytes_len + self.position <= bytes.capacity());
+                bytes.set_len(bytes_len + self.position);
+                // grow geometrically, `BytesMut::reserve` may allocate
+                // exactly the requested size
+                let additional = cmp::max(bytes.capacity(), OUTPUT_STREAM_BUFFER_SIZE);
+                bytes.reserve(additional);
+                self.buffer = remove_lifetime_mut(bytes_mut_remaining_capacity_as_slice_mut(bytes));
+                self.position = 0;
+            },
             OutputTarget::Bytes => {
                 panic!("refresh_buffer must not be called on CodedOutputStream create from slice");
             }
@@ -910,11 +1324,26 @@ impl<'a> CodedOutputStream<'a> {
     pub fn flush(&mut self) -> ProtobufResult<()> {
         match self.target {
             OutputTarget::Bytes => Ok(()),
-            OutputTarget::Write(..) |
-            OutputTarget::Vec(..) => {
-                // TODO: must not reserve additional in Vec
-                self.refresh_buffer()
-            }
+            OutputTarget::Write(..) => self.refresh_buffer(),
+            OutputTarget::Vec(ref mut vec) => unsafe {
+                // unlike `refresh_buffer`, do not reserve additional space,
+                // so writing exactly remaining capacity does not reallocate
+                let vec_len = vec.len();
+                assert!(vec_len + self.position <= vec.capacity());
+                vec.set_len(vec_len + self.position);
+                self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
+                self.position = 0;
+                Ok(())
+            },
+            #[cfg(feature = "bytes")]
+            OutputTarget::BytesMut(ref mut bytes) => unsafe {
+                let bytes_len = bytes.len();
+                assert!(bytes_len + self.position <= bytes.capacity());
+                bytes.set_len(bytes_len + self.position);
+                self.buffer = remove_lifetime_mut(bytes_mut_remaining_capacity_as_slice_mut(bytes));
+                self.position = 0;
+                Ok(())
+            },
         }
     }
 
@@ -959,6 +1388,14 @@ impl<'a> CodedOutputStream<'a> {
                     self.buffer = remove_lifetime_mut(remaining_capacity_as_slice_mut(vec));
                 }
             }
+            #[cfg(feature = "bytes")]
+            OutputTarget::BytesMut(ref mut target) => {
+                target.extend_from_slice(bytes);
+                unsafe {
+                    self.buffer =
+                        remove_lifetime_mut(bytes_mut_remaining_capacity_as_slice_mut(target));
+                }
+            }
         }
         Ok(())
     }
@@ -1000,23 +1437,19 @@ impl<'a> CodedOutputStream<'a> {
     }
 
     pub fn write_raw_little_endian32(&mut self, value: u32) -> ProtobufResult<()> {
-        let bytes = unsafe { mem::transmute::<_, [u8; 4]>(value.to_le()) };
-        self.write_raw_bytes(&bytes)
+        self.write_raw_bytes(&value.to_le_bytes())
     }
 
     pub fn write_raw_little_endian64(&mut self, value: u64) -> ProtobufResult<()> {
-        let bytes = unsafe { mem::transmute::<_, [u8; 8]>(value.to_le()) };
-        self.write_raw_bytes(&bytes)
+        self.write_raw_bytes(&value.to_le_bytes())
     }
 
     pub fn write_float_no_tag(&mut self, value: f32) -> ProtobufResult<()> {
-        let bits = unsafe { mem::transmute::<f32, u32>(value) };
-        self.write_raw_little_endian32(bits)
+        self.write_raw_little_endian32(value.to_bits())
     }
 
     pub fn write_double_no_tag(&mut self, value: f64) -> ProtobufResult<()> {
-        let bits = unsafe { mem::transmute::<f64, u64>(value) };
-        self.write_raw_little_endian64(bits)
+        self.write_raw_little_endian64(value.to_bits())
     }
 
     pub fn write_float(&mut self, field_number: u32, value: f32) -> ProtobufResult<()> {
@@ -1086,12 +1519,16 @@ impl<'a> CodedOutputStream<'a> {
         self.write_enum_no_tag(value.value())
     }
 
+    /// Write value without tag. Only fields are written for group,
+    /// because end group tag needs field number, use `write_unknown` to write group.
     pub fn write_unknown_no_tag(&mut self, unknown: UnknownValueRef) -> ProtobufResult<()> {
         match unknown {
             UnknownValueRef::Fixed64(fixed64) => self.write_raw_little_endian64(fixed64),
             UnknownValueRef::Fixed32(fixed32) => self.write_raw_little_endian32(fixed32),
             UnknownValueRef::Varint(varint) => self.write_raw_varint64(varint),
             UnknownValueRef::LengthDelimited(bytes) => self.write_bytes_no_tag(bytes),
+            #[cfg(feature = "with-groups")]
+            UnknownValueRef::Group(fields) => self.write_unknown_fields(fields),
         }
     }
 
@@ -1181,6 +1618,12 @@ impl<'a> CodedOutputStream<'a> {
     ) -> ProtobufResult<()> {
         self.write_tag(field_number, value.wire_type())?;
         self.write_unknown_no_tag(value)?;
+        #[cfg(feature = "with-groups")]
+        {
+            if let UnknownValueRef::Group(..) = value {
+                self.write_tag(field_number, wire_format::WireTypeEndGroup)?;
+            }
+        }
         Ok(())
     }
 
@@ -1252,10 +1695,14 @@ mod test {
     use hex::decode_hex;
     use error::ProtobufResult;
     use error::ProtobufError;
+    use error::WireError;
 
     use super::wire_format;
     use super::CodedInputStream;
     use super::CodedOutputStream;
+    use super::ParseStats;
+    #[cfg(feature = "bytes")]
+    use super::Utf8Policy;
 
     fn test_read_partial<F>(hex: &str, mut callback: F)
     where
@@ -1359,11 +1806,255 @@ mod test {
         });
     }
 
+    #[test]
+    fn test_input_stream_read_tag_reserved_wire_type() {
+        for &(hex, tag) in &[("0e", 14), ("0f", 15), ("be 3e", 7998), ("00", 0)] {
+            test_read_partial(hex, |is| match is.read_tag() {
+                Err(ProtobufError::WireError(WireError::IncorrectTag(t))) => assert_eq!(tag, t),
+                r => panic!("{:?}", r.map(|t| t.unpack())),
+            });
+        }
+    }
+
+    #[test]
+    fn test_input_stream_skip_group() {
+        // group 1 with nested group 2 and varint field, then field 3
+        test_read_partial("08 01 13 1b 1c 14 0c 18 05", |is| {
+            is.skip_group(1).unwrap();
+            assert_eq!((3, wire_format::WireTypeVarint), is.read_tag_unpack().unwrap());
+        });
+        // nested group is closed with end group tag of outer group
+        test_read_partial("13 0c 14", |is| match is.skip_group(1) {
+            Err(ProtobufError::WireError(WireError::UnexpectedWireType(
+                wire_format::WireTypeEndGroup,
+            ))) => {}
+            r => panic!("{:?}", r),
+        });
+        test_read_partial("08 01", |is| match is.skip_group(1) {
+            Err(ProtobufError::WireError(WireError::UnexpectedEof)) => {}
+            r => panic!("{:?}", r),
+        });
+    }
+
+    #[cfg(feature = "with-groups")]
+    #[test]
+    fn test_input_stream_read_unknown_group() {
+        // group 1 with varint field and nested group 2, then field 3
+        test_read_partial("08 01 13 1d 07 00 00 00 14 0c 18 05", |is| {
+            let group = is.read_unknown_group(1).unwrap();
+            assert_eq!("1: varint 1\n2: group {3: fixed32 7}", group.debug_dump());
+            assert_eq!((3, wire_format::WireTypeVarint), is.read_tag_unpack().unwrap());
+        });
+        test_read_partial("13 0c 14", |is| match is.read_unknown_group(1) {
+            Err(ProtobufError::WireError(WireError::UnexpectedWireType(
+                wire_format::WireTypeEndGroup,
+            ))) => {}
+            r => panic!("{:?}", r),
+        });
+        test_read_partial("08 01", |is| match is.read_unknown_group(1) {
+            Err(ProtobufError::WireError(WireError::UnexpectedEof)) => {}
+            r => panic!("{:?}", r),
+        });
+        // nesting is limited
+        test_read_partial("0b 0b 0b 0c 0c 0c", |is| {
+            is.set_recursion_limit(2);
+            match is.read_unknown_group(1) {
+                Err(ProtobufError::WireError(WireError::OverRecursionLimit)) => {}
+                r => panic!("{:?}", r),
+            }
+        });
+    }
+
+    #[cfg(feature = "with-groups")]
+    #[test]
+    fn test_unknown_group_round_trip() {
+        use core::Message;
+        use core::parse_from_bytes;
+        use descriptor::DescriptorProto;
+        use unknown::UnknownFields;
+
+        // single field in each group, because order of unknown fields is unspecified
+        let mut nested = UnknownFields::new();
+        nested.add_length_delimited(2, b"a".to_vec());
+        let mut group = UnknownFields::new();
+        group.add_varint(3, 1);
+        group.add_group(3, nested);
+        let mut m = DescriptorProto::new();
+        m.set_name("m".to_owned());
+        m.mut_unknown_fields().add_group(1000, group);
+
+        let bytes = m.write_to_bytes().unwrap();
+        assert_eq!(bytes.len() as u32, m.compute_size());
+        assert_eq!("0a 01 6d c3 3e 18 01 1b 12 01 61 1c c4 3e", encode_hex(&bytes));
+        assert_eq!(m, parse_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_input_stream_stats() {
+        use core::Message;
+        use descriptor::DescriptorProto;
+
+        let mut inner = DescriptorProto::new();
+        inner.set_name("i".to_owned());
+        inner.mut_unknown_fields().add_length_delimited(1000, b"abc".to_vec());
+        let mut outer = DescriptorProto::new();
+        outer.mut_nested_type().push(DescriptorProto::new());
+        outer.mut_nested_type().push(inner);
+        let bytes = outer.write_to_bytes().unwrap();
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        assert_eq!(ParseStats::default(), is.stats());
+        let mut m = DescriptorProto::new();
+        m.merge_from(&mut is).unwrap();
+        assert_eq!(m, outer);
+
+        let stats = is.stats();
+        assert_eq!(1, stats.max_depth);
+        // two nested messages, name and unknown field
+        assert_eq!(4, stats.fields);
+        // length and value
+        assert_eq!(4, stats.unknown_bytes);
+
+        is.reset_stats();
+        assert_eq!(ParseStats::default(), is.stats());
+    }
+
+    #[test]
+    fn test_input_stream_read_message_bounded() {
+        use core::Message;
+        use descriptor::FileDescriptorProto;
+        use descriptor::UninterpretedOption_NamePart;
+
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        let message = file.write_to_bytes().unwrap();
+        let len = message.len() as u64;
+
+        let mut bytes = message.clone();
+        bytes.extend_from_slice(b"raw");
+        // invalid wire type
+        bytes.extend_from_slice(&[0x0f, 0x01]);
+        bytes.extend_from_slice(&message);
+
+        let mut is = CodedInputStream::from_bytes(&bytes);
+        assert_eq!(file, is.read_message_bounded(len).unwrap());
+        assert_eq!(len, is.pos());
+        assert_eq!(b"raw".to_vec(), is.read_raw_bytes(3).unwrap());
+        assert!(is.read_message_bounded::<FileDescriptorProto>(2).is_err());
+        assert_eq!(len + 5, is.pos());
+        // message must be initialized
+        assert!(is.read_message_bounded::<UninterpretedOption_NamePart>(len).is_err());
+        assert!(is.eof().unwrap());
+
+        let mut is = CodedInputStream::from_bytes(&message);
+        match is.read_message_bounded::<FileDescriptorProto>(len + 1) {
+            Err(ProtobufError::WireError(WireError::UnexpectedEof)) => {}
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_input_stream_canonical() {
+        fn read<F, V>(hex: &str, canonical: bool, callback: F) -> ProtobufResult<V>
+        where
+            F : Fn(&mut CodedInputStream) -> ProtobufResult<V>,
+        {
+            let d = decode_hex(hex);
+            // one byte buffer to check varints crossing buffer boundary
+            let mut cursor = io::Cursor::new(d);
+            let mut reader = io::BufReader::with_capacity(1, &mut cursor);
+            let mut is = CodedInputStream::from_buffered_reader(&mut reader);
+            is.set_canonical(canonical);
+            callback(&mut is)
+        }
+
+        for &hex in &["00", "80 01", "ff ff ff ff ff ff ff ff ff 01"] {
+            assert!(read(hex, true, |is| is.read_raw_varint64()).is_ok());
+            test_read_partial(hex, |is| {
+                is.set_canonical(true);
+                is.read_raw_varint64().unwrap();
+            });
+        }
+        for &hex in &["80 00", "ff 80 00", "ff ff ff ff ff ff ff ff ff 02"] {
+            assert!(read(hex, false, |is| is.read_raw_varint64()).is_ok());
+            match read(hex, true, |is| is.read_raw_varint64()) {
+                Err(ProtobufError::WireError(WireError::NonCanonical)) => {}
+                r => panic!("{:?}", r),
+            }
+            test_read_partial(hex, |is| {
+                is.set_canonical(true);
+                match is.read_raw_varint64() {
+                    Err(ProtobufError::WireError(WireError::NonCanonical)) => {}
+                    r => panic!("{:?}", r),
+                }
+            });
+        }
+
+        assert_eq!(true, read("01", true, |is| is.read_bool()).unwrap());
+        assert_eq!(true, read("02", false, |is| is.read_bool()).unwrap());
+        match read("02", true, |is| is.read_bool()) {
+            Err(ProtobufError::WireError(WireError::NonCanonical)) => {}
+            r => panic!("{:?}", r),
+        }
+    }
+
+    #[test]
+    fn test_input_stream_read_repeated_packed_fixed() {
+        let hex = "0c 01 00 00 00 ff ff ff ff 00 00 80 3f";
+        test_read_v(hex, vec![1, 0xffffffff, 0x3f800000], |reader| {
+            let mut v = Vec::new();
+            reader.read_repeated_packed_fixed32_into(&mut v).map(|_| v)
+        });
+        test_read_v(hex, vec![1, -1, 0x3f800000], |reader| {
+            let mut v = Vec::new();
+            reader.read_repeated_packed_sfixed32_into(&mut v).map(|_| v)
+        });
+        test_read_v(
+            "10 f1 e2 d3 c4 b5 a6 07 f8 01 00 00 00 00 00 00 00",
+            vec![0xf807a6b5c4d3e2f1, 1],
+            |reader| {
+                let mut v = Vec::new();
+                reader.read_repeated_packed_fixed64_into(&mut v).map(|_| v)
+            },
+        );
+        test_read_v("10 00 00 00 00 00 00 f0 3f 00 00 00 00 00 00 00 c0", vec![1.0, -2.0], |reader| {
+            let mut v = Vec::new();
+            reader.read_repeated_packed_double_into(&mut v).map(|_| v)
+        });
+
+        // field split between buffer refills
+        let bytes = decode_hex(hex);
+        let mut reader = io::BufReader::with_capacity(3, &bytes[..]);
+        let mut is = CodedInputStream::from_buffered_reader(&mut reader as &mut BufRead);
+        let mut v = Vec::new();
+        is.read_repeated_packed_float_into(&mut v).unwrap();
+        assert_eq!(3, v.len());
+        assert_eq!(1.0, v[2]);
+        assert!(v[1].is_nan());
+
+        // length is not a multiple of value size
+        test_read_partial("03 01 00 00", |reader| {
+            let mut v = Vec::new();
+            assert!(reader.read_repeated_packed_fixed32_into(&mut v).is_err());
+        });
+    }
+
     #[test]
     fn test_input_stream_read_int32() {
         test_read_v("02", 2, |reader| reader.read_int32());
     }
 
+    #[test]
+    fn test_input_stream_read_raw_little_endian() {
+        test_read_v("f1 e2 d3 c4", 0xc4d3e2f1, |is| is.read_raw_little_endian32());
+        test_read_v(
+            "f1 e2 d3 c4 b5 a6 07 f8",
+            0xf807a6b5c4d3e2f1,
+            |is| is.read_raw_little_endian64(),
+        );
+        test_read_v("ff ff ff ff ff ff ff 7f", i64::max_value(), |is| is.read_sfixed64());
+    }
+
     #[test]
     fn test_input_stream_read_float() {
         test_read_v("95 73 13 61", 17e19, |is| is.read_float());
@@ -1393,6 +2084,56 @@ mod test {
         })
     }
 
+    #[test]
+    fn test_input_stream_read_string_invalid_utf8() {
+        test_read_partial("03 61 ff 62", |is| {
+            assert!(is.read_string().is_err());
+        });
+        test_read("03 61 ff 62", |is| {
+            is.set_lossy_utf8(true);
+            assert_eq!("a\u{fffd}b", is.read_string().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_read_string_into_reuses_buffer() {
+        test_read("03 61 62 63", |is| {
+            let mut s = String::with_capacity(10);
+            s.push_str("previous");
+            let ptr = s.as_ptr();
+            is.read_string_into(&mut s).unwrap();
+            assert_eq!("abc", s);
+            assert_eq!(ptr, s.as_ptr());
+        });
+        test_read("03 61 62 63", |is| {
+            let mut v = vec![1; 10];
+            let ptr = v.as_ptr();
+            is.read_bytes_into(&mut v).unwrap();
+            assert_eq!(b"abc", &v[..]);
+            assert_eq!(ptr, v.as_ptr());
+        });
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_input_stream_read_carllerche_chars_utf8_policy() {
+        test_read_partial("03 61 ff 62", |is| {
+            assert!(is.read_carllerche_chars().is_err());
+        });
+        test_read("03 61 ff 62", |is| {
+            is.set_utf8_policy(Utf8Policy::Lossy);
+            let chars = is.read_carllerche_chars().unwrap();
+            assert_eq!("a\u{fffd}b", &*chars);
+            assert!(chars.is_valid_utf8());
+        });
+        test_read("03 61 ff 62", |is| {
+            is.set_utf8_policy(Utf8Policy::Preserve);
+            let chars = is.read_carllerche_chars().unwrap();
+            assert_eq!("a\u{fffd}b", &*chars);
+            assert_eq!(b"a\xffb", chars.raw_bytes());
+        });
+    }
+
     #[test]
     fn test_input_stream_limits() {
         test_read("aa bb cc", |is| {
@@ -1426,6 +2167,108 @@ mod test {
         });
     }
 
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn test_input_stream_read_carllerche_bytes_zero_copy() {
+        use bytes::Bytes;
+
+        // values are longer than inline capacity of `Bytes`
+        let a = repeat(b'a').take(40).collect::<Vec<_>>();
+        let b = repeat(b'b').take(50).collect::<Vec<_>>();
+        let mut data = Vec::new();
+        {
+            let mut os = CodedOutputStream::vec(&mut data);
+            os.write_raw_varint32(a.len() as u32).unwrap();
+            os.write_raw_bytes(&a).unwrap();
+            // nested message with string field 1
+            os.write_raw_varint32(b.len() as u32 + 2).unwrap();
+            os.write_tag(1, wire_format::WireTypeLengthDelimited).unwrap();
+            os.write_raw_varint32(b.len() as u32).unwrap();
+            os.write_raw_bytes(&b).unwrap();
+            os.flush().unwrap();
+        }
+        let input = Bytes::from(data);
+        let mut is = CodedInputStream::from_carllerche_bytes(&input);
+
+        let read_a = is.read_carllerche_bytes().unwrap();
+        assert_eq!(&a[..], &read_a[..]);
+        assert_eq!(input[1..].as_ptr(), read_a.as_ptr());
+
+        let len = is.read_raw_varint32().unwrap();
+        let old_limit = is.push_limit(len as u64).unwrap();
+        assert_eq!((1, wire_format::WireTypeLengthDelimited), is.read_tag_unpack().unwrap());
+        let read_b = is.read_carllerche_chars().unwrap();
+        assert_eq!(&b[..], read_b.as_bytes());
+        assert_eq!(input[44..].as_ptr(), read_b.as_ptr());
+        is.pop_limit(old_limit);
+        assert!(is.eof().unwrap());
+    }
+
+    #[test]
+    fn test_input_stream_read_event() {
+        use super::DecodeEvent;
+        use super::EventValue;
+
+        fn event(
+            field_number: u32,
+            wire_type: wire_format::WireType,
+            value: EventValue,
+        ) -> Option<DecodeEvent> {
+            Some(DecodeEvent {
+                field_number: field_number,
+                wire_type: wire_type,
+                value: value,
+            })
+        }
+
+        // 1: 150, 2: "abc", 3: { 1: 1 }, 2: "de", 4: fixed32 1
+        test_read("08 96 01 12 03 61 62 63 1a 02 08 01 12 02 64 65 25 01 00 00 00", |is| {
+            assert_eq!(
+                event(1, wire_format::WireTypeVarint, EventValue::Varint(150)),
+                is.read_event().unwrap()
+            );
+            // payload is skipped
+            assert_eq!(
+                event(2, wire_format::WireTypeLengthDelimited, EventValue::LengthDelimited(3)),
+                is.read_event().unwrap()
+            );
+
+            assert_eq!(
+                event(3, wire_format::WireTypeLengthDelimited, EventValue::LengthDelimited(2)),
+                is.read_event().unwrap()
+            );
+            let old_limit = is.enter_event_payload().unwrap();
+            assert_eq!(
+                event(1, wire_format::WireTypeVarint, EventValue::Varint(1)),
+                is.read_event().unwrap()
+            );
+            assert_eq!(None, is.read_event().unwrap());
+            is.pop_limit(old_limit);
+
+            assert_eq!(
+                event(2, wire_format::WireTypeLengthDelimited, EventValue::LengthDelimited(2)),
+                is.read_event().unwrap()
+            );
+            let mut payload = Vec::new();
+            is.read_event_payload_into(&mut payload).unwrap();
+            assert_eq!(b"de", &payload[..]);
+
+            assert_eq!(
+                event(4, wire_format::WireTypeFixed32, EventValue::Fixed32(1)),
+                is.read_event().unwrap()
+            );
+            assert_eq!(None, is.read_event().unwrap());
+        });
+    }
+
+    #[test]
+    fn test_input_stream_read_event_truncated_payload() {
+        test_read_partial("12 03 61", |is| {
+            is.read_event().unwrap();
+            assert!(is.read_event().is_err());
+        });
+    }
+
     fn test_write<F>(expected: &str, mut gen: F)
     where
         F : FnMut(&mut CodedOutputStream) -> ProtobufResult<()>,
@@ -1468,6 +2311,22 @@ mod test {
             r.drain(..7);
             assert_eq!(encode_hex(&expected_bytes), encode_hex(&r));
         }
+
+        // write to BytesMut
+        #[cfg(feature = "bytes")]
+        {
+            use bytes::BytesMut;
+
+            let mut r = BytesMut::new();
+            r.extend_from_slice(&[11, 22, 33]);
+            {
+                let mut os = CodedOutputStream::carllerche_bytes_mut(&mut r);
+                gen(&mut os).unwrap();
+                os.flush().unwrap();
+            }
+
+            assert_eq!(encode_hex(&expected_bytes), encode_hex(&r[3..]));
+        }
     }
 
     #[test]
@@ -0,0 +1,127 @@
+//! Conversion of `google.protobuf.Struct` to and from `serde_json::Value`.
+//!
+//! `Struct`, `Value` and `ListValue` represent arbitrary JSON,
+//! and these functions convert them to and from `serde_json` values:
+//!
+//! ```ignore
+//! let s = struct_from_json(json_object);
+//! let json = serde_json::Value::Object(struct_to_json(&s));
+//! ```
+//!
+//! Module is available with `with-serde-json` feature.
+
+use serde_json;
+use serde_json::Map;
+use serde_json::Number;
+
+use repeated::RepeatedField;
+use well_known_types::ListValue;
+use well_known_types::NullValue;
+use well_known_types::Struct;
+use well_known_types::Value;
+use well_known_types::Value_oneof_kind;
+
+
+/// Convert `Value` to JSON.
+///
+/// `Value` with no kind set is converted to `null`. JSON cannot represent
+/// NaN and infinite numbers, so these are converted to `null` too.
+pub fn value_to_json(value: &Value) -> serde_json::Value {
+    match value.kind {
+        None | Some(Value_oneof_kind::null_value(..)) => serde_json::Value::Null,
+        Some(Value_oneof_kind::number_value(n)) => number_to_json(n),
+        Some(Value_oneof_kind::string_value(ref s)) => serde_json::Value::String(s.clone()),
+        Some(Value_oneof_kind::bool_value(b)) => serde_json::Value::Bool(b),
+        Some(Value_oneof_kind::struct_value(ref s)) => serde_json::Value::Object(struct_to_json(s)),
+        Some(Value_oneof_kind::list_value(ref l)) => {
+            serde_json::Value::Array(list_value_to_json(l))
+        }
+    }
+}
+
+// integral numbers are converted to integers, so they are printed as `1` rather than `1.0`
+fn number_to_json(n: f64) -> serde_json::Value {
+    // max integer such that all smaller integers are exactly representable as `f64`
+    const MAX_EXACT: f64 = 9007199254740992.0;
+    if n.fract() == 0.0 && n.abs() <= MAX_EXACT {
+        return serde_json::Value::Number(Number::from(n as i64));
+    }
+    match Number::from_f64(n) {
+        Some(n) => serde_json::Value::Number(n),
+        None => serde_json::Value::Null,
+    }
+}
+
+/// Convert `Struct` to JSON object.
+pub fn struct_to_json(s: &Struct) -> Map<String, serde_json::Value> {
+    s.get_fields().iter().map(|(k, v)| (k.clone(), value_to_json(v))).collect()
+}
+
+/// Convert `ListValue` to JSON array.
+pub fn list_value_to_json(list: &ListValue) -> Vec<serde_json::Value> {
+    list.get_values().iter().map(value_to_json).collect()
+}
+
+/// Convert JSON to `Value`.
+///
+/// Numbers are converted to `f64`, so integers larger than `2^53` lose precision.
+pub fn value_from_json(json: serde_json::Value) -> Value {
+    let mut r = Value::new();
+    match json {
+        serde_json::Value::Null => r.set_null_value(NullValue::NULL_VALUE),
+        serde_json::Value::Bool(b) => r.set_bool_value(b),
+        // `as_f64` returns `None` only for arbitrary precision numbers
+        serde_json::Value::Number(n) => r.set_number_value(n.as_f64().unwrap_or(::std::f64::NAN)),
+        serde_json::Value::String(s) => r.set_string_value(s),
+        serde_json::Value::Array(a) => r.set_list_value(list_value_from_json(a)),
+        serde_json::Value::Object(o) => r.set_struct_value(struct_from_json(o)),
+    }
+    r
+}
+
+/// Convert JSON object to `Struct`.
+pub fn struct_from_json(object: Map<String, serde_json::Value>) -> Struct {
+    let mut r = Struct::new();
+    r.set_fields(object.into_iter().map(|(k, v)| (k, value_from_json(v))).collect());
+    r
+}
+
+/// Convert JSON array to `ListValue`.
+pub fn list_value_from_json(array: Vec<serde_json::Value>) -> ListValue {
+    let mut r = ListValue::new();
+    r.set_values(RepeatedField::from_vec(array.into_iter().map(value_from_json).collect()));
+    r
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let json: serde_json::Value = serde_json::from_str(
+            r#"{"a": null, "b": 1.5, "c": "x", "d": true, "e": {"f": [1, "g", []]}}"#,
+        ).unwrap();
+        let object = match json {
+            serde_json::Value::Object(ref o) => o.clone(),
+            _ => unreachable!(),
+        };
+
+        let s = struct_from_json(object);
+        assert_eq!(5, s.get_fields().len());
+        assert_eq!(1.5, s.get_fields()["b"].get_number_value());
+        let list = s.get_fields()["e"].get_struct_value().get_fields()["f"].get_list_value();
+        assert_eq!("g", list.get_values()[1].get_string_value());
+
+        assert_eq!(json, serde_json::Value::Object(struct_to_json(&s)));
+    }
+
+    #[test]
+    fn not_representable() {
+        assert_eq!(serde_json::Value::Null, value_to_json(&Value::new()));
+        let mut value = Value::new();
+        value.set_number_value(::std::f64::INFINITY);
+        assert_eq!(serde_json::Value::Null, value_to_json(&value));
+    }
+}
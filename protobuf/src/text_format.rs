@@ -1,9 +1,11 @@
 use std;
+use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::Write;
 use core::Message;
 use reflect::ReflectFieldRef;
 use reflect::ProtobufValueRef;
+use reflect::descriptor_dyn;
 
 
 fn quote_bytes_to(bytes: &[u8], buf: &mut String) {
@@ -13,6 +15,7 @@ fn quote_bytes_to(bytes: &[u8], buf: &mut String) {
             b'\r' => buf.push_str(r"\r"),
             b'\t' => buf.push_str(r"\t"),
             b'"' => buf.push_str("\\\""),
+            b'\'' => buf.push_str("\\'"),
             b'\\' => buf.push_str(r"\\"),
             b'\x20'...b'\x7e' => buf.push(c as char),
             _ => {
@@ -37,55 +40,68 @@ pub fn quote_escape_bytes(bytes: &[u8]) -> String {
     r
 }
 
+/// Unescape C-style escaped string, e. g. `default` option value
+/// or string literal of text format (without quotes).
+///
+/// Octal (`\123`) and hex (`\x7f`) escapes produce bytes,
+/// non-ASCII characters are copied as UTF-8, so output of
+/// `quote_escape_bytes` is unescaped to the original bytes.
 pub fn unescape_string(string: &str) -> Vec<u8> {
-    fn parse_if_digit(chars: &mut std::str::Chars) -> u8 {
+    fn parse_digit(chars: &mut std::str::Chars, radix: u32) -> Option<u32> {
         let mut copy = chars.clone();
-        let f = match copy.next() {
-            None => return 0,
-            Some(f) => f,
-        };
-        let d = match f {
-            '0'...'9' => (f as u8 - b'0'),
-            _ => return 0,
-        };
-        *chars = copy;
-        d
-    }
-
-    fn parse_hex_digit(chars: &mut std::str::Chars) -> u8 {
-        match chars.next().unwrap() {
-            c @ '0'...'9' => (c as u8) - b'0',
-            c @ 'a'...'f' => (c as u8) - b'a' + 10,
-            c @ 'A'...'F' => (c as u8) - b'A' + 10,
-            _ => panic!("incorrect hex escape"),
+        let d = copy.next().and_then(|c| c.to_digit(radix));
+        if d.is_some() {
+            *chars = copy;
         }
+        d
     }
 
-    fn parse_escape_rem(chars: &mut std::str::Chars) -> u8 {
-        let n = chars.next().unwrap();
-        match n {
-            'a' => return b'\x07',
-            'b' => return b'\x08',
-            'f' => return b'\x0c',
-            'n' => return b'\n',
-            'r' => return b'\r',
-            't' => return b'\t',
-            'v' => return b'\x0b',
-            '"' => return b'"',
-            '\'' => return b'\'',
-            '0'...'9' => {
-                let d1 = n as u8 - b'0';
-                let d2 = parse_if_digit(chars);
-                let d3 = parse_if_digit(chars);
-                return (d1 * 64 + d2 * 8 + d3) as u8;
-            },
+    fn parse_escape_rem(chars: &mut std::str::Chars, r: &mut Vec<u8>) {
+        let n = match chars.next() {
+            Some(n) => n,
+            None => {
+                // trailing backslash is kept
+                r.push(b'\\');
+                return;
+            }
+        };
+        let b = match n {
+            'a' => b'\x07',
+            'b' => b'\x08',
+            'f' => b'\x0c',
+            'n' => b'\n',
+            'r' => b'\r',
+            't' => b'\t',
+            'v' => b'\x0b',
+            '0'...'7' => {
+                // up to three octal digits
+                let mut v = n as u32 - '0' as u32;
+                for _ in 0..2 {
+                    match parse_digit(chars, 8) {
+                        Some(d) => v = v * 8 + d,
+                        None => break,
+                    }
+                }
+                v as u8
+            }
             'x' => {
-                let d1 = parse_hex_digit(chars);
-                let d2 = parse_hex_digit(chars);
-                return d1 * 16 + d2;
+                // one or two hex digits
+                match parse_digit(chars, 16) {
+                    Some(d1) => match parse_digit(chars, 16) {
+                        Some(d2) => (d1 * 16 + d2) as u8,
+                        None => d1 as u8,
+                    },
+                    None => b'x',
+                }
+            }
+            // `\"`, `\'`, `\\`, `\?` and unknown escapes
+            c => {
+                let mut utf8 = [0; 4];
+                r.extend_from_slice(c.encode_utf8(&mut utf8).as_bytes());
+                return;
             }
-            c => return c as u8, // TODO: validate ASCII
         };
+        r.push(b);
     }
 
     let mut chars = string.chars();
@@ -98,20 +114,113 @@ pub fn unescape_string(string: &str) -> Vec<u8> {
         };
 
         if f == '\\' {
-            r.push(parse_escape_rem(&mut chars));
+            parse_escape_rem(&mut chars, &mut r);
+        } else {
+            let mut utf8 = [0; 4];
+            r.extend_from_slice(f.encode_utf8(&mut utf8).as_bytes());
+        }
+    }
+}
+
+/// How `float` and `double` values are printed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FloatFormat {
+    /// Shortest representation parsed back to the same value,
+    /// laid out like `protoc` prints it: `0.1`, `1e+20`, `-inf`, `nan`.
+    Protoc,
+    /// Rust `Display`: `0.1`, `100000000000000000000`, `-inf`, `NaN`.
+    Display,
+}
+
+impl Default for FloatFormat {
+    fn default() -> FloatFormat {
+        FloatFormat::Protoc
+    }
+}
+
+/// Text format output options.
+#[derive(Clone, Debug, Default)]
+pub struct PrintOptions {
+    /// Multi-line output, see `print_pretty`.
+    pub pretty: bool,
+    /// Print values of fields with `rustproto.redact` option as `<redacted>`.
+    pub redact: bool,
+    pub float_format: FloatFormat,
+    /// Print unknown fields after known fields in format of
+    /// `UnknownFields::debug_dump`, e. g. `5: varint 150`.
+    pub unknown_fields: bool,
+}
+
+// `sci` is shortest round-trip representation in `{:e}` format, e. g. `-1.25e-7`;
+// it is printed like C `%g` with `precision`, or with `max_precision`
+// if more digits are needed, which is how `protoc` prints floats
+fn print_float_digits_to(sci: &str, precision: usize, max_precision: usize, buf: &mut String) {
+    let sci = if sci.starts_with('-') {
+        buf.push('-');
+        &sci[1..]
+    } else {
+        sci
+    };
+    let e = sci.find('e').expect("exponent");
+    let digits: String = sci[..e].chars().filter(|&c| c != '.').collect();
+    let exp: i32 = sci[e + 1..].parse().expect("exponent");
+    let precision = if digits.len() > precision { max_precision } else { precision };
+
+    if exp < -4 || exp >= precision as i32 {
+        buf.push_str(&digits[..1]);
+        if digits.len() > 1 {
+            buf.push('.');
+            buf.push_str(&digits[1..]);
+        }
+        write!(buf, "e{}{:02}", if exp < 0 { '-' } else { '+' }, exp.abs()).unwrap();
+    } else if exp < 0 {
+        buf.push_str("0.");
+        for _ in 0..(-exp - 1) {
+            buf.push('0');
+        }
+        buf.push_str(&digits);
+    } else {
+        let int_len = exp as usize + 1;
+        if digits.len() <= int_len {
+            buf.push_str(&digits);
+            for _ in digits.len()..int_len {
+                buf.push('0');
+            }
         } else {
-            r.push(f as u8); // TODO: escape UTF-8
+            buf.push_str(&digits[..int_len]);
+            buf.push('.');
+            buf.push_str(&digits[int_len..]);
         }
     }
 }
 
+macro_rules! print_float_fn {
+    ($name:ident, $ty:ty, $precision:expr, $max_precision:expr) => {
+        fn $name(v: $ty, float_format: FloatFormat, buf: &mut String) {
+            match float_format {
+                FloatFormat::Display => write!(buf, "{}", v).unwrap(),
+                FloatFormat::Protoc if v.is_nan() => buf.push_str("nan"),
+                FloatFormat::Protoc if v.is_infinite() => {
+                    buf.push_str(if v > 0.0 { "inf" } else { "-inf" })
+                }
+                FloatFormat::Protoc => {
+                    print_float_digits_to(&format!("{:e}", v), $precision, $max_precision, buf)
+                }
+            }
+        }
+    };
+}
+
+print_float_fn!(print_f32_to, f32, 6, 9);
+print_float_fn!(print_f64_to, f64, 15, 17);
+
 fn print_str_to(s: &str, buf: &mut String) {
     // TODO: keep printable Unicode
     quote_escape_bytes_to(s.as_bytes(), buf);
 }
 
-fn do_indent(buf: &mut String, pretty: bool, indent: usize) {
-    if pretty && indent > 0 {
+fn do_indent(buf: &mut String, options: &PrintOptions, indent: usize) {
+    if options.pretty && indent > 0 {
         for _ in 0..indent {
             buf.push_str("  ");
         }
@@ -120,43 +229,43 @@ fn do_indent(buf: &mut String, pretty: bool, indent: usize) {
 
 fn print_start_field(
     buf: &mut String,
-    pretty: bool,
+    options: &PrintOptions,
     indent: usize,
     first: &mut bool,
     field_name: &str,
 ) {
-    if !*first && !pretty {
+    if !*first && !options.pretty {
         buf.push_str(" ");
     }
-    do_indent(buf, pretty, indent);
+    do_indent(buf, options, indent);
     *first = false;
     buf.push_str(field_name);
 }
 
-fn print_end_field(buf: &mut String, pretty: bool) {
-    if pretty {
+fn print_end_field(buf: &mut String, options: &PrintOptions) {
+    if options.pretty {
         buf.push_str("\n");
     }
 }
 
 fn print_field(
     buf: &mut String,
-    pretty: bool,
+    options: &PrintOptions,
     indent: usize,
     first: &mut bool,
     field_name: &str,
     value: ProtobufValueRef,
 ) {
-    print_start_field(buf, pretty, indent, first, field_name);
+    print_start_field(buf, options, indent, first, field_name);
 
     match value {
         ProtobufValueRef::Message(m) => {
             buf.push_str(" {");
-            if pretty {
+            if options.pretty {
                 buf.push_str("\n");
             }
-            print_to_internal(m, buf, pretty, indent + 1);
-            do_indent(buf, pretty, indent);
+            print_to_internal(m, buf, options, indent + 1);
+            do_indent(buf, options, indent);
             buf.push_str("}");
         }
         ProtobufValueRef::Enum(e) => {
@@ -187,83 +296,172 @@ fn print_field(
             write!(buf, ": {}", v).unwrap();
         }
         ProtobufValueRef::F32(v) => {
-            write!(buf, ": {}", v).unwrap();
+            buf.push_str(": ");
+            print_f32_to(v, options.float_format, buf);
         }
         ProtobufValueRef::F64(v) => {
-            write!(buf, ": {}", v).unwrap();
+            buf.push_str(": ");
+            print_f64_to(v, options.float_format, buf);
         }
     }
 
-    print_end_field(buf, pretty);
+    print_end_field(buf, options);
 }
 
-fn print_to_internal(m: &Message, buf: &mut String, pretty: bool, indent: usize) {
-    let d = m.descriptor();
+fn compare_map_keys(a: ProtobufValueRef, b: ProtobufValueRef) -> Ordering {
+    match (a, b) {
+        (ProtobufValueRef::U32(a), ProtobufValueRef::U32(b)) => a.cmp(&b),
+        (ProtobufValueRef::U64(a), ProtobufValueRef::U64(b)) => a.cmp(&b),
+        (ProtobufValueRef::I32(a), ProtobufValueRef::I32(b)) => a.cmp(&b),
+        (ProtobufValueRef::I64(a), ProtobufValueRef::I64(b)) => a.cmp(&b),
+        (ProtobufValueRef::Bool(a), ProtobufValueRef::Bool(b)) => a.cmp(&b),
+        (ProtobufValueRef::String(a), ProtobufValueRef::String(b)) => a.cmp(b),
+        _ => unreachable!("keys of the same map"),
+    }
+}
+
+fn print_to_internal(m: &Message, buf: &mut String, options: &PrintOptions, indent: usize) {
+    let d = descriptor_dyn(m);
     let mut first = true;
     for f in d.fields() {
+        if options.redact && f.is_redacted() {
+            let is_set = match f.get_reflect(m) {
+                ReflectFieldRef::Map(map) => map.len() != 0,
+                ReflectFieldRef::Repeated(repeated) => repeated.len() != 0,
+                ReflectFieldRef::Optional(optional) => optional.is_some(),
+            };
+            if is_set {
+                print_start_field(buf, options, indent, &mut first, f.name());
+                buf.push_str(": <redacted>");
+                print_end_field(buf, options);
+            }
+            continue;
+        }
+
         match f.get_reflect(m) {
             ReflectFieldRef::Map(map) => {
-                for (k, v) in map {
-                    print_start_field(buf, pretty, indent, &mut first, f.name());
+                let mut entries: Vec<_> = map.into_iter().collect();
+                if options.pretty {
+                    // stable order for readable diffs
+                    entries.sort_by(|a, b| compare_map_keys(a.0.as_ref(), b.0.as_ref()));
+                }
+                for (k, v) in entries {
+                    print_start_field(buf, options, indent, &mut first, f.name());
                     buf.push_str(" {");
-                    if pretty {
+                    if options.pretty {
                         buf.push_str("\n");
                     }
 
                     let mut entry_first = true;
 
-                    print_field(buf, pretty, indent + 1, &mut entry_first, "key", k.as_ref());
                     print_field(
                         buf,
-                        pretty,
+                        options,
+                        indent + 1,
+                        &mut entry_first,
+                        "key",
+                        k.as_ref(),
+                    );
+                    print_field(
+                        buf,
+                        options,
                         indent + 1,
                         &mut entry_first,
                         "value",
                         v.as_ref(),
                     );
-                    do_indent(buf, pretty, indent);
+                    do_indent(buf, options, indent);
                     buf.push_str("}");
-                    print_end_field(buf, pretty);
+                    print_end_field(buf, options);
                 }
             }
             ReflectFieldRef::Repeated(repeated) => {
                 // TODO: do not print zeros for v3
                 for v in repeated {
-                    print_field(buf, pretty, indent, &mut first, f.name(), v.as_ref());
+                    print_field(buf, options, indent, &mut first, f.name(), v.as_ref());
                 }
             }
             ReflectFieldRef::Optional(optional) => {
                 if let Some(v) = optional {
-                    print_field(buf, pretty, indent, &mut first, f.name(), v);
+                    print_field(buf, options, indent, &mut first, f.name(), v);
                 }
             }
         }
     }
 
-    // TODO: unknown fields
+    if options.unknown_fields {
+        for (number, value) in m.get_unknown_fields().sorted_values() {
+            print_start_field(buf, options, indent, &mut first, &number.to_string());
+            buf.push_str(": ");
+            value.debug_dump_to(buf);
+            print_end_field(buf, options);
+        }
+    }
 }
 
 pub fn print_to(m: &Message, buf: &mut String) {
-    print_to_internal(m, buf, false, 0)
+    print_to_internal(m, buf, &PrintOptions::default(), 0)
 }
 
-fn print_to_string_internal(m: &Message, pretty: bool) -> String {
+/// Print message with given options.
+pub fn print_with_options_to(m: &Message, options: &PrintOptions, buf: &mut String) {
+    print_to_internal(m, buf, options, 0)
+}
+
+/// Print message with given options to string.
+pub fn print_to_string_with_options(m: &Message, options: &PrintOptions) -> String {
     let mut r = String::new();
-    print_to_internal(m, &mut r, pretty, 0);
-    r.to_string()
+    print_to_internal(m, &mut r, options, 0);
+    r
 }
 
 pub fn print_to_string(m: &Message) -> String {
-    print_to_string_internal(m, false)
+    print_to_string_with_options(m, &PrintOptions::default())
+}
+
+fn pretty_options() -> PrintOptions {
+    PrintOptions {
+        pretty: true,
+        ..Default::default()
+    }
+}
+
+/// Print message in multi-line form: one field per line,
+/// nested messages are indented, map entries are sorted by key.
+///
+/// Same output is produced by `{:#?}`, except that `Debug` hides
+/// values of fields with `rustproto.redact` option.
+pub fn print_pretty_to(m: &Message, buf: &mut String) {
+    print_to_internal(m, buf, &pretty_options(), 0)
+}
+
+/// Print message in multi-line form to string, see `print_pretty_to`.
+pub fn print_pretty(m: &Message) -> String {
+    print_to_string_with_options(m, &pretty_options())
 }
 
+/// Format message for `Debug`.
+///
+/// Unlike `print_to_string`, values of fields with `rustproto.redact` option
+/// are printed as `<redacted>`, so messages can be logged safely.
+/// Alternate flag (`{:#?}`) selects multi-line output of `print_pretty`,
+/// and sign flag (`{:+?}`) adds unknown fields, which helps diagnosing
+/// schema mismatch.
 pub fn fmt(m: &Message, f: &mut fmt::Formatter) -> fmt::Result {
-    let pretty = f.alternate();
-    f.write_str(&print_to_string_internal(m, pretty))
+    let options = PrintOptions {
+        pretty: f.alternate(),
+        redact: true,
+        unknown_fields: f.sign_plus(),
+        ..Default::default()
+    };
+    f.write_str(&print_to_string_with_options(m, &options))
 }
 
 #[cfg(test)]
 mod test {
+    use well_known_types::ListValue;
+    use well_known_types::Struct;
+    use well_known_types::Value;
 
     fn escape(data: &[u8]) -> String {
         let mut s = String::with_capacity(data.len() * 4);
@@ -280,7 +478,7 @@ mod test {
     fn test_print_to_bytes() {
         assert_eq!("ab", escape(b"ab"));
         assert_eq!("a\\\\023", escape(b"a\\023"));
-        assert_eq!("a\\r\\n\\t '\\\"\\\\", escape(b"a\r\n\t '\"\\"));
+        assert_eq!("a\\r\\n\\t \\'\\\"\\\\", escape(b"a\r\n\t '\"\\"));
         assert_eq!(
             "\\344\\275\\240\\345\\245\\275",
             escape("你好".as_bytes())
@@ -299,8 +497,147 @@ mod test {
         assert_eq!(b"aaa\x01bbb", &super::unescape_string("aaa\\x01bbb")[..]);
         assert_eq!(b"aaa\xcdbbb", &super::unescape_string("aaa\\xCDbbb")[..]);
         assert_eq!(b"aaa\xcdbbb", &super::unescape_string("aaa\\xCDbbb")[..]);
+        assert_eq!(b"a\x0fz", &super::unescape_string("a\\xfz")[..]);
+        assert_eq!(b"axyb", &super::unescape_string("a\\xyb")[..]);
+        // octal
+        assert_eq!(b"\x0a8", &super::unescape_string("\\0128")[..]);
+        assert_eq!(b"\xff", &super::unescape_string("\\377")[..]);
         // quotes
         assert_eq!(b"aaa\"bbb", &super::unescape_string("aaa\\\"bbb")[..]);
         assert_eq!(b"aaa\'bbb", &super::unescape_string("aaa\\\'bbb")[..]);
+        // unescaped UTF-8 and trailing backslash
+        assert_eq!("你好\\".as_bytes(), &super::unescape_string("你好\\")[..]);
+    }
+
+    #[test]
+    fn test_escape_round_trip() {
+        let bytes: Vec<u8> = (0..256).map(|b| b as u8).collect();
+        let escaped = escape(&bytes);
+        assert!(escaped.bytes().all(|c| c >= b'\x20' && c <= b'\x7e'));
+        assert_eq!(bytes, super::unescape_string(&escaped));
+        assert_eq!("\"a\\'b\\\"\"", super::quote_escape_bytes(b"a'b\""));
+    }
+
+    fn string_value(s: &str) -> Value {
+        let mut value = Value::new();
+        value.set_string_value(s.to_owned());
+        value
+    }
+
+    #[test]
+    fn test_print_pretty() {
+        let mut list = ListValue::new();
+        list.mut_values().push(string_value("x"));
+        let mut list_value = Value::new();
+        list_value.set_list_value(list);
+
+        let mut s = Struct::new();
+        s.mut_fields().insert("b".to_owned(), string_value("y"));
+        s.mut_fields().insert("a".to_owned(), list_value);
+        s.mut_fields().insert("c".to_owned(), Value::new());
+
+        let expected = "\
+fields {
+  key: \"a\"
+  value {
+    list_value {
+      values {
+        string_value: \"x\"
+      }
+    }
+  }
+}
+fields {
+  key: \"b\"
+  value {
+    string_value: \"y\"
+  }
+}
+fields {
+  key: \"c\"
+  value {
+  }
+}
+";
+        assert_eq!(expected, super::print_pretty(&s));
+        assert_eq!(expected, format!("{:#?}", s));
+
+        let mut buf = String::new();
+        super::print_pretty_to(&s, &mut buf);
+        assert_eq!(expected, buf);
+    }
+
+    fn f64_protoc(v: f64) -> String {
+        let mut buf = String::new();
+        super::print_f64_to(v, super::FloatFormat::Protoc, &mut buf);
+        buf
+    }
+
+    fn f32_protoc(v: f32) -> String {
+        let mut buf = String::new();
+        super::print_f32_to(v, super::FloatFormat::Protoc, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn test_print_float_protoc() {
+        assert_eq!("0", f64_protoc(0.0));
+        assert_eq!("-0", f64_protoc(-0.0));
+        assert_eq!("99", f64_protoc(99.0));
+        assert_eq!("0.1", f64_protoc(0.1));
+        assert_eq!("-1.5", f64_protoc(-1.5));
+        assert_eq!("0.0001", f64_protoc(0.0001));
+        assert_eq!("1e-05", f64_protoc(0.00001));
+        assert_eq!("123456789012345", f64_protoc(123456789012345.0));
+        assert_eq!("1e+15", f64_protoc(1e15));
+        assert_eq!("1.7976931348623157e+308", f64_protoc(::std::f64::MAX));
+        assert_eq!("0.30000000000000004", f64_protoc(0.1 + 0.2));
+        assert_eq!("inf", f64_protoc(::std::f64::INFINITY));
+        assert_eq!("-inf", f64_protoc(::std::f64::NEG_INFINITY));
+        assert_eq!("nan", f64_protoc(::std::f64::NAN));
+
+        assert_eq!("0.1", f32_protoc(0.1));
+        assert_eq!("123456", f32_protoc(123456.0));
+        assert_eq!("1e+06", f32_protoc(1000000.0));
+        assert_eq!("1234567", f32_protoc(1234567.0));
+        assert_eq!("3.4028235e+38", f32_protoc(::std::f32::MAX));
+    }
+
+    #[test]
+    fn test_print_float_options() {
+        let mut value = Value::new();
+        value.set_number_value(1e20);
+        assert_eq!("number_value: 1e+20", super::print_to_string(&value));
+        assert_eq!("number_value: 1e+20", format!("{:?}", value));
+
+        let options = super::PrintOptions {
+            float_format: super::FloatFormat::Display,
+            ..Default::default()
+        };
+        assert_eq!(
+            "number_value: 100000000000000000000",
+            super::print_to_string_with_options(&value, &options)
+        );
+    }
+    #[test]
+    fn test_print_unknown_fields() {
+        use core::Message;
+
+        let mut value = Value::new();
+        value.set_bool_value(true);
+        value.mut_unknown_fields().add_varint(20, 150);
+        value.mut_unknown_fields().add_length_delimited(10, vec![0x61, 0x62]);
+        let mut list = ListValue::new();
+        list.mut_values().push(value);
+
+        assert_eq!("values {bool_value: true}", format!("{:?}", list));
+        assert_eq!(
+            "values {bool_value: true 10: bytes 6162 20: varint 150}",
+            format!("{:+?}", list)
+        );
+        assert_eq!(
+            "values {\n  bool_value: true\n  10: bytes 6162\n  20: varint 150\n}\n",
+            format!("{:+#?}", list)
+        );
     }
 }
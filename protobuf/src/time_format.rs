@@ -0,0 +1,455 @@
+//! Canonical string representation of `Timestamp` and `Duration`.
+//!
+//! JSON mapping of proto3 represents these well-known types as strings:
+//! timestamps in RFC 3339 format, e. g. `1972-01-01T10:00:20.021Z`,
+//! and durations as seconds with `s` suffix, e. g. `3.000000001s`.
+//!
+//! ```
+//! use protobuf::time_format::*;
+//!
+//! let t = timestamp_from_str("1970-01-01T01:00:00.5+01:00").unwrap();
+//! assert_eq!((0, 500_000_000), (t.seconds, t.nanos));
+//! assert_eq!("1970-01-01T00:00:00.500Z", timestamp_to_string(&t).unwrap());
+//!
+//! let d = duration_from_str("-1.5s").unwrap();
+//! assert_eq!((-1, -500_000_000), (d.seconds, d.nanos));
+//! assert_eq!("-1.500s", duration_to_string(&d).unwrap());
+//! ```
+//!
+//! Output always contains 0, 3, 6 or 9 fractional digits and uses `Z` offset,
+//! input may contain 1 to 9 fractional digits and any offset.
+
+use std::error::Error;
+use std::fmt;
+
+use well_known_types::Duration;
+use well_known_types::Timestamp;
+
+
+/// Error converting `Timestamp` or `Duration` to or from string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TimeFormatError {
+    /// String is not a valid timestamp or duration.
+    InvalidFormat,
+    /// Value is outside of range allowed by the type definition:
+    /// years 0001 to 9999 for timestamps, about ±10000 years for durations,
+    /// or `nanos` is not a valid fraction of `seconds`.
+    OutOfRange,
+}
+
+impl TimeFormatError {
+    fn message(&self) -> &'static str {
+        match *self {
+            TimeFormatError::InvalidFormat => "invalid timestamp or duration format",
+            TimeFormatError::OutOfRange => "timestamp or duration is out of range",
+        }
+    }
+}
+
+impl fmt::Display for TimeFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl Error for TimeFormatError {
+    fn description(&self) -> &str {
+        self.message()
+    }
+}
+
+/// Result of time format functions.
+pub type TimeFormatResult<T> = Result<T, TimeFormatError>;
+
+// 0001-01-01T00:00:00Z
+const TIMESTAMP_MIN_SECONDS: i64 = -62135596800;
+// 9999-12-31T23:59:59Z
+const TIMESTAMP_MAX_SECONDS: i64 = 253402300799;
+// 10000 years of 365.25 days
+const DURATION_MAX_SECONDS: i64 = 315576000000;
+
+const NANOS_PER_SECOND: i32 = 1_000_000_000;
+const SECONDS_PER_DAY: i64 = 86400;
+
+
+// days since 1970-01-01 of proleptic Gregorian calendar date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    // months starting from March, so leap day is the last day of year
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+// inverse of `days_from_civil`
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = if days >= 0 { days } else { days - 146096 } / 146097;
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_from_march + 2) / 5 + 1;
+    let month = if month_from_march < 10 { month_from_march + 3 } else { month_from_march - 9 };
+    let year = year_of_era + era * 400;
+    (if month <= 2 { year + 1 } else { year }, month, day)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// fractional part of canonical output, `nanos` must be non-negative
+fn write_nanos(nanos: i32, r: &mut String) {
+    if nanos == 0 {
+    } else if nanos % 1_000_000 == 0 {
+        r.push_str(&format!(".{:03}", nanos / 1_000_000));
+    } else if nanos % 1_000 == 0 {
+        r.push_str(&format!(".{:06}", nanos / 1_000));
+    } else {
+        r.push_str(&format!(".{:09}", nanos));
+    }
+}
+
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Parser<'a> {
+        Parser {
+            input: input.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn next_is(&mut self, c: u8) -> bool {
+        if self.input.get(self.pos) == Some(&c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: u8) -> TimeFormatResult<()> {
+        if self.next_is(c) {
+            Ok(())
+        } else {
+            Err(TimeFormatError::InvalidFormat)
+        }
+    }
+
+    fn expect_end(&self) -> TimeFormatResult<()> {
+        if self.pos == self.input.len() {
+            Ok(())
+        } else {
+            Err(TimeFormatError::InvalidFormat)
+        }
+    }
+
+    fn digit(&mut self) -> Option<i64> {
+        match self.input.get(self.pos) {
+            Some(&c) if c >= b'0' && c <= b'9' => {
+                self.pos += 1;
+                Some((c - b'0') as i64)
+            }
+            _ => None,
+        }
+    }
+
+    // exactly `count` digits
+    fn fixed_digits(&mut self, count: usize) -> TimeFormatResult<i64> {
+        let mut r = 0;
+        for _ in 0..count {
+            r = r * 10 + self.digit().ok_or(TimeFormatError::InvalidFormat)?;
+        }
+        Ok(r)
+    }
+
+    // at least one digit, too large number is out of range
+    fn integer(&mut self) -> TimeFormatResult<i64> {
+        let mut r = self.digit().ok_or(TimeFormatError::InvalidFormat)?;
+        while let Some(d) = self.digit() {
+            r = r.checked_mul(10)
+                .and_then(|r| r.checked_add(d))
+                .ok_or(TimeFormatError::OutOfRange)?;
+        }
+        Ok(r)
+    }
+
+    // optional `.` followed by 1 to 9 digits, returns nanos
+    fn fraction(&mut self) -> TimeFormatResult<i32> {
+        if !self.next_is(b'.') {
+            return Ok(0);
+        }
+        let mut nanos = 0;
+        let mut count = 0;
+        while let Some(d) = self.digit() {
+            if count == 9 {
+                return Err(TimeFormatError::InvalidFormat);
+            }
+            nanos = nanos * 10 + d as i32;
+            count += 1;
+        }
+        if count == 0 {
+            return Err(TimeFormatError::InvalidFormat);
+        }
+        for _ in count..9 {
+            nanos *= 10;
+        }
+        Ok(nanos)
+    }
+}
+
+
+/// Format timestamp in RFC 3339 format with `Z` offset.
+pub fn timestamp_to_string(timestamp: &Timestamp) -> TimeFormatResult<String> {
+    let seconds = timestamp.seconds;
+    let nanos = timestamp.nanos;
+    if seconds < TIMESTAMP_MIN_SECONDS || seconds > TIMESTAMP_MAX_SECONDS
+        || nanos < 0 || nanos >= NANOS_PER_SECOND
+    {
+        return Err(TimeFormatError::OutOfRange);
+    }
+
+    let (year, month, day) = civil_from_days(seconds.div_euclid(SECONDS_PER_DAY));
+    let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+    let mut r = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        second_of_day / 3600,
+        second_of_day / 60 % 60,
+        second_of_day % 60
+    );
+    write_nanos(nanos, &mut r);
+    r.push('Z');
+    Ok(r)
+}
+
+/// Parse timestamp in RFC 3339 format.
+///
+/// Offset is applied, so the result is always in UTC.
+/// Leap seconds (`:60`) are not supported.
+pub fn timestamp_from_str(s: &str) -> TimeFormatResult<Timestamp> {
+    let mut p = Parser::new(s);
+    let year = p.fixed_digits(4)?;
+    p.expect(b'-')?;
+    let month = p.fixed_digits(2)?;
+    p.expect(b'-')?;
+    let day = p.fixed_digits(2)?;
+    p.expect(b'T')?;
+    let hour = p.fixed_digits(2)?;
+    p.expect(b':')?;
+    let minute = p.fixed_digits(2)?;
+    p.expect(b':')?;
+    let second = p.fixed_digits(2)?;
+    let nanos = p.fraction()?;
+
+    let offset_seconds = if p.next_is(b'Z') {
+        0
+    } else {
+        let sign = if p.next_is(b'+') {
+            1
+        } else {
+            p.expect(b'-')?;
+            -1
+        };
+        let offset_hour = p.fixed_digits(2)?;
+        p.expect(b':')?;
+        let offset_minute = p.fixed_digits(2)?;
+        if offset_hour >= 24 || offset_minute >= 60 {
+            return Err(TimeFormatError::InvalidFormat);
+        }
+        sign * (offset_hour * 3600 + offset_minute * 60)
+    };
+    p.expect_end()?;
+
+    if month < 1 || month > 12 || day < 1 || day > days_in_month(year, month)
+        || hour >= 24 || minute >= 60 || second >= 60
+    {
+        return Err(TimeFormatError::InvalidFormat);
+    }
+
+    let seconds = days_from_civil(year, month, day) * SECONDS_PER_DAY
+        + hour * 3600 + minute * 60 + second - offset_seconds;
+    if seconds < TIMESTAMP_MIN_SECONDS || seconds > TIMESTAMP_MAX_SECONDS {
+        return Err(TimeFormatError::OutOfRange);
+    }
+
+    let mut r = Timestamp::new();
+    r.seconds = seconds;
+    r.nanos = nanos;
+    Ok(r)
+}
+
+/// Format duration as seconds with `s` suffix.
+///
+/// `seconds` and `nanos` of negative duration must both be non-positive.
+pub fn duration_to_string(duration: &Duration) -> TimeFormatResult<String> {
+    let seconds = duration.seconds;
+    let nanos = duration.nanos;
+    if seconds < -DURATION_MAX_SECONDS || seconds > DURATION_MAX_SECONDS
+        || nanos <= -NANOS_PER_SECOND || nanos >= NANOS_PER_SECOND
+        || (seconds > 0 && nanos < 0) || (seconds < 0 && nanos > 0)
+    {
+        return Err(TimeFormatError::OutOfRange);
+    }
+
+    let mut r = String::new();
+    if seconds < 0 || nanos < 0 {
+        r.push('-');
+    }
+    r.push_str(&seconds.abs().to_string());
+    write_nanos(nanos.abs(), &mut r);
+    r.push('s');
+    Ok(r)
+}
+
+/// Parse duration in seconds with `s` suffix, e. g. `-1.5s`.
+pub fn duration_from_str(s: &str) -> TimeFormatResult<Duration> {
+    let mut p = Parser::new(s);
+    let negative = p.next_is(b'-');
+    let seconds = p.integer()?;
+    let nanos = p.fraction()?;
+    p.expect(b's')?;
+    p.expect_end()?;
+
+    if seconds > DURATION_MAX_SECONDS {
+        return Err(TimeFormatError::OutOfRange);
+    }
+
+    let mut r = Duration::new();
+    if negative {
+        r.seconds = -seconds;
+        r.nanos = -nanos;
+    } else {
+        r.seconds = seconds;
+        r.nanos = nanos;
+    }
+    Ok(r)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn timestamp(seconds: i64, nanos: i32) -> Timestamp {
+        let mut r = Timestamp::new();
+        r.seconds = seconds;
+        r.nanos = nanos;
+        r
+    }
+
+    fn duration(seconds: i64, nanos: i32) -> Duration {
+        let mut r = Duration::new();
+        r.seconds = seconds;
+        r.nanos = nanos;
+        r
+    }
+
+    #[test]
+    fn timestamp_round_trip() {
+        let cases = [
+            ("1970-01-01T00:00:00Z", 0, 0),
+            ("1972-01-01T10:00:20.021Z", 63108020, 21000000),
+            ("1969-12-31T23:59:59.000001Z", -1, 1000),
+            ("2000-02-29T12:34:56.000000001Z", 951827696, 1),
+            ("0001-01-01T00:00:00Z", TIMESTAMP_MIN_SECONDS, 0),
+            ("9999-12-31T23:59:59.999999999Z", TIMESTAMP_MAX_SECONDS, 999999999),
+        ];
+        for &(s, seconds, nanos) in &cases {
+            assert_eq!(s, timestamp_to_string(&timestamp(seconds, nanos)).unwrap());
+            assert_eq!(timestamp(seconds, nanos), timestamp_from_str(s).unwrap());
+        }
+    }
+
+    #[test]
+    fn timestamp_offset() {
+        assert_eq!(
+            timestamp(0, 100000000),
+            timestamp_from_str("1969-12-31T19:00:00.1-05:00").unwrap()
+        );
+        assert_eq!(
+            timestamp(TIMESTAMP_MIN_SECONDS, 0),
+            timestamp_from_str("0001-01-01T01:30:00+01:30").unwrap()
+        );
+    }
+
+    #[test]
+    fn timestamp_errors() {
+        for s in &[
+            "",
+            "1970-01-01",
+            "1970-01-01 00:00:00Z",
+            "1970-01-01T00:00:00",
+            "1970-01-01T00:00:00.Z",
+            "1970-01-01T00:00:00.1234567890Z",
+            "1970-13-01T00:00:00Z",
+            "1970-02-29T00:00:00Z",
+            "1970-01-01T24:00:00Z",
+            "1970-01-01T00:00:60Z",
+            "1970-01-01T00:00:00+1:00",
+            "1970-01-01T00:00:00Zx",
+        ] {
+            assert_eq!(Err(TimeFormatError::InvalidFormat), timestamp_from_str(s), "{}", s);
+        }
+        assert_eq!(
+            Err(TimeFormatError::OutOfRange),
+            timestamp_from_str("0001-01-01T00:00:00+00:01")
+        );
+        assert_eq!(
+            Err(TimeFormatError::OutOfRange),
+            timestamp_to_string(&timestamp(TIMESTAMP_MAX_SECONDS + 1, 0))
+        );
+        assert_eq!(Err(TimeFormatError::OutOfRange), timestamp_to_string(&timestamp(0, -1)));
+    }
+
+    #[test]
+    fn duration_round_trip() {
+        let cases = [
+            ("0s", 0, 0),
+            ("1.500s", 1, 500000000),
+            ("3.000000001s", 3, 1),
+            ("-0.000001s", 0, -1000),
+            ("-10.010s", -10, -10000000),
+            ("315576000000.999999999s", DURATION_MAX_SECONDS, 999999999),
+        ];
+        for &(s, seconds, nanos) in &cases {
+            assert_eq!(s, duration_to_string(&duration(seconds, nanos)).unwrap());
+            assert_eq!(duration(seconds, nanos), duration_from_str(s).unwrap());
+        }
+        assert_eq!(duration(-1, -500000000), duration_from_str("-1.5s").unwrap());
+    }
+
+    #[test]
+    fn duration_errors() {
+        for s in &["", "s", "1", "1.s", ".5s", "+1s", "1.0000000001s", "1 s", "1sx"] {
+            assert_eq!(Err(TimeFormatError::InvalidFormat), duration_from_str(s), "{}", s);
+        }
+        assert_eq!(Err(TimeFormatError::OutOfRange), duration_from_str("315576000001s"));
+        assert_eq!(Err(TimeFormatError::OutOfRange), duration_from_str("99999999999999999999s"));
+        assert_eq!(Err(TimeFormatError::OutOfRange), duration_to_string(&duration(1, -1)));
+        assert_eq!(
+            Err(TimeFormatError::OutOfRange),
+            duration_to_string(&duration(0, NANOS_PER_SECOND))
+        );
+    }
+}
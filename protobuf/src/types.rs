@@ -10,14 +10,23 @@ use stream::CodedOutputStream;
 use error::ProtobufResult;
 use core::ProtobufEnum;
 use core::Message;
+use descriptor::FieldDescriptorProto_Type;
 use wire_format::WireType;
 use rt;
+use reflect::EnumDescriptor;
+use reflect::MessageDescriptor;
 use reflect::ProtobufValue;
 use unknown::UnknownValues;
+use size_cache::SizeCache;
 
 pub trait ProtobufType {
     type Value: ProtobufValue + Clone + 'static;
 
+    /// Type of fields of this type in `.proto` file
+    fn field_type() -> FieldDescriptorProto_Type {
+        unimplemented!()
+    }
+
     fn wire_type() -> WireType;
 
     fn read(is: &mut CodedInputStream) -> ProtobufResult<Self::Value>;
@@ -61,6 +70,36 @@ pub trait ProtobufType {
         value: &Self::Value,
         os: &mut CodedOutputStream,
     ) -> ProtobufResult<()>;
+
+    /// Compute size storing sizes of messages in `sizes`
+    fn compute_size_with_cache(value: &Self::Value, _sizes: &mut SizeCache) -> u32 {
+        Self::compute_size(value)
+    }
+
+    /// Get size previously stored in `sizes` with length prefix
+    fn get_size_from_cache_with_length_delimiter(value: &Self::Value, _sizes: &SizeCache) -> u32 {
+        Self::compute_size_with_length_delimiter(value)
+    }
+
+    /// Write value using sizes previously stored in `sizes`
+    fn write_with_size_cache(
+        field_number: u32,
+        value: &Self::Value,
+        os: &mut CodedOutputStream,
+        _sizes: &mut SizeCache,
+    ) -> ProtobufResult<()> {
+        Self::write_with_cached_size(field_number, value, os)
+    }
+
+    /// Descriptor of enum type, `None` if this type is not an enum
+    fn enum_descriptor() -> Option<&'static EnumDescriptor> {
+        None
+    }
+
+    /// Descriptor of message type, `None` if this type is not a message
+    fn message_descriptor() -> Option<&'static MessageDescriptor> {
+        None
+    }
 }
 
 pub struct ProtobufTypeFloat;
@@ -91,6 +130,10 @@ pub struct ProtobufTypeMessage<M : Message>(marker::PhantomData<M>);
 impl ProtobufType for ProtobufTypeFloat {
     type Value = f32;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_FLOAT
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeFixed32
     }
@@ -115,6 +158,10 @@ impl ProtobufType for ProtobufTypeFloat {
 impl ProtobufType for ProtobufTypeDouble {
     type Value = f64;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_DOUBLE
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeFixed64
     }
@@ -139,6 +186,10 @@ impl ProtobufType for ProtobufTypeDouble {
 impl ProtobufType for ProtobufTypeInt32 {
     type Value = i32;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_INT32
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeVarint
     }
@@ -147,8 +198,13 @@ impl ProtobufType for ProtobufTypeInt32 {
         is.read_int32()
     }
 
+    fn get_from_unknown(unknown: &UnknownValues) -> Option<i32> {
+        unknown.varint.iter().rev().next().map(|&v| v as i32)
+    }
+
     fn compute_size(value: &i32) -> u32 {
-        rt::compute_raw_varint32_size(*value as u32)
+        // negative values are sign-extended to 64 bits on the wire
+        rt::compute_raw_varint64_size(*value as i64 as u64)
     }
 
     fn write_with_cached_size(
@@ -163,6 +219,10 @@ impl ProtobufType for ProtobufTypeInt32 {
 impl ProtobufType for ProtobufTypeInt64 {
     type Value = i64;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_INT64
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeVarint
     }
@@ -171,6 +231,10 @@ impl ProtobufType for ProtobufTypeInt64 {
         is.read_int64()
     }
 
+    fn get_from_unknown(unknown: &UnknownValues) -> Option<i64> {
+        unknown.varint.iter().rev().next().map(|&v| v as i64)
+    }
+
     fn compute_size(value: &i64) -> u32 {
         rt::compute_raw_varint64_size(*value as u64)
     }
@@ -187,6 +251,10 @@ impl ProtobufType for ProtobufTypeInt64 {
 impl ProtobufType for ProtobufTypeUint32 {
     type Value = u32;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_UINT32
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeVarint
     }
@@ -195,6 +263,10 @@ impl ProtobufType for ProtobufTypeUint32 {
         is.read_uint32()
     }
 
+    fn get_from_unknown(unknown: &UnknownValues) -> Option<u32> {
+        unknown.varint.iter().rev().next().map(|&v| v as u32)
+    }
+
     fn compute_size(value: &u32) -> u32 {
         rt::compute_raw_varint32_size(*value)
     }
@@ -211,6 +283,10 @@ impl ProtobufType for ProtobufTypeUint32 {
 impl ProtobufType for ProtobufTypeUint64 {
     type Value = u64;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_UINT64
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeVarint
     }
@@ -219,6 +295,10 @@ impl ProtobufType for ProtobufTypeUint64 {
         is.read_uint64()
     }
 
+    fn get_from_unknown(unknown: &UnknownValues) -> Option<u64> {
+        unknown.varint.iter().rev().next().map(|&v| v)
+    }
+
     fn compute_size(value: &u64) -> u32 {
         rt::compute_raw_varint64_size(*value)
     }
@@ -235,6 +315,10 @@ impl ProtobufType for ProtobufTypeUint64 {
 impl ProtobufType for ProtobufTypeSint32 {
     type Value = i32;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_SINT32
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeVarint
     }
@@ -259,6 +343,10 @@ impl ProtobufType for ProtobufTypeSint32 {
 impl ProtobufType for ProtobufTypeSint64 {
     type Value = i64;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_SINT64
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeVarint
     }
@@ -283,6 +371,10 @@ impl ProtobufType for ProtobufTypeSint64 {
 impl ProtobufType for ProtobufTypeFixed32 {
     type Value = u32;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_FIXED32
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeFixed32
     }
@@ -307,6 +399,10 @@ impl ProtobufType for ProtobufTypeFixed32 {
 impl ProtobufType for ProtobufTypeFixed64 {
     type Value = u64;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_FIXED64
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeFixed64
     }
@@ -331,6 +427,10 @@ impl ProtobufType for ProtobufTypeFixed64 {
 impl ProtobufType for ProtobufTypeSfixed32 {
     type Value = i32;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_SFIXED32
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeFixed32
     }
@@ -355,6 +455,10 @@ impl ProtobufType for ProtobufTypeSfixed32 {
 impl ProtobufType for ProtobufTypeSfixed64 {
     type Value = i64;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_SFIXED64
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeFixed64
     }
@@ -379,6 +483,10 @@ impl ProtobufType for ProtobufTypeSfixed64 {
 impl ProtobufType for ProtobufTypeBool {
     type Value = bool;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_BOOL
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeVarint
     }
@@ -407,6 +515,10 @@ impl ProtobufType for ProtobufTypeBool {
 impl ProtobufType for ProtobufTypeString {
     type Value = String;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_STRING
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeLengthDelimited
     }
@@ -415,6 +527,14 @@ impl ProtobufType for ProtobufTypeString {
         is.read_string()
     }
 
+    fn get_from_unknown(unknown: &UnknownValues) -> Option<String> {
+        // value with invalid UTF-8 is treated as not set
+        unknown
+            .length_delimited
+            .last()
+            .and_then(|ld| String::from_utf8(ld.clone()).ok())
+    }
+
     fn compute_size(value: &String) -> u32 {
         value.len() as u32
     }
@@ -431,6 +551,10 @@ impl ProtobufType for ProtobufTypeString {
 impl ProtobufType for ProtobufTypeBytes {
     type Value = Vec<u8>;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_BYTES
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeLengthDelimited
     }
@@ -439,6 +563,10 @@ impl ProtobufType for ProtobufTypeBytes {
         is.read_bytes()
     }
 
+    fn get_from_unknown(unknown: &UnknownValues) -> Option<Vec<u8>> {
+        unknown.length_delimited.last().cloned()
+    }
+
     fn compute_size(value: &Vec<u8>) -> u32 {
         value.len() as u32
     }
@@ -456,6 +584,10 @@ impl ProtobufType for ProtobufTypeBytes {
 impl ProtobufType for ProtobufTypeCarllercheBytes {
     type Value = Bytes;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_BYTES
+    }
+
     fn wire_type() -> WireType {
         ProtobufTypeBytes::wire_type()
     }
@@ -481,6 +613,10 @@ impl ProtobufType for ProtobufTypeCarllercheBytes {
 impl ProtobufType for ProtobufTypeCarllercheChars {
     type Value = Chars;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_STRING
+    }
+
     fn wire_type() -> WireType {
         ProtobufTypeBytes::wire_type()
     }
@@ -490,7 +626,7 @@ impl ProtobufType for ProtobufTypeCarllercheChars {
     }
 
     fn compute_size(value: &Chars) -> u32 {
-        value.len() as u32
+        value.raw_bytes().len() as u32
     }
 
     fn write_with_cached_size(
@@ -498,13 +634,17 @@ impl ProtobufType for ProtobufTypeCarllercheChars {
         value: &Chars,
         os: &mut CodedOutputStream,
     ) -> ProtobufResult<()> {
-        os.write_string(field_number, &value)
+        os.write_bytes(field_number, value.raw_bytes())
     }
 }
 
 impl<E : ProtobufEnum + ProtobufValue> ProtobufType for ProtobufTypeEnum<E> {
     type Value = E;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_ENUM
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeVarint
     }
@@ -514,7 +654,7 @@ impl<E : ProtobufEnum + ProtobufValue> ProtobufType for ProtobufTypeEnum<E> {
     }
 
     fn compute_size(value: &E) -> u32 {
-        rt::compute_raw_varint32_size(value.value() as u32) // TODO: wrap
+        rt::compute_raw_varint64_size(value.value() as i64 as u64)
     }
 
     fn write_with_cached_size(
@@ -524,11 +664,19 @@ impl<E : ProtobufEnum + ProtobufValue> ProtobufType for ProtobufTypeEnum<E> {
     ) -> ProtobufResult<()> {
         os.write_enum_obj(field_number, *value)
     }
+
+    fn enum_descriptor() -> Option<&'static EnumDescriptor> {
+        E::enum_descriptor_dyn()
+    }
 }
 
 impl<M : Message + Clone + ProtobufValue> ProtobufType for ProtobufTypeMessage<M> {
     type Value = M;
 
+    fn field_type() -> FieldDescriptorProto_Type {
+        FieldDescriptorProto_Type::TYPE_MESSAGE
+    }
+
     fn wire_type() -> WireType {
         WireType::WireTypeLengthDelimited
     }
@@ -555,4 +703,29 @@ impl<M : Message + Clone + ProtobufValue> ProtobufType for ProtobufTypeMessage<M
         value.write_to_with_cached_sizes(os)?;
         Ok(())
     }
+
+    fn compute_size_with_cache(value: &M, sizes: &mut SizeCache) -> u32 {
+        value.compute_size_with_cache(sizes)
+    }
+
+    fn get_size_from_cache_with_length_delimiter(_value: &M, sizes: &SizeCache) -> u32 {
+        let size = sizes.peek_size();
+        rt::compute_raw_varint32_size(size) + size
+    }
+
+    fn write_with_size_cache(
+        field_number: u32,
+        value: &M,
+        os: &mut CodedOutputStream,
+        sizes: &mut SizeCache,
+    ) -> ProtobufResult<()> {
+        os.write_tag(field_number, WireType::WireTypeLengthDelimited)?;
+        os.write_raw_varint32(sizes.take_size())?;
+        value.write_to_with_size_cache(os, sizes)?;
+        Ok(())
+    }
+
+    fn message_descriptor() -> Option<&'static MessageDescriptor> {
+        M::default_instance().descriptor_dyn()
+    }
 }
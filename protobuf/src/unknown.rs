@@ -1,9 +1,17 @@
 use std::collections::HashMap;
 use std::collections::hash_map;
 use std::default::Default;
+use std::fmt::Write;
 use std::slice;
 use stream::wire_format;
+use stream::CodedInputStream;
 use clear::Clear;
+use core::Message;
+use core::parse_from_bytes;
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
+use rt::read_unknown_or_skip_group;
 
 #[derive(Debug)]
 pub enum UnknownValue {
@@ -11,6 +19,10 @@ pub enum UnknownValue {
     Fixed64(u64),
     Varint(u64),
     LengthDelimited(Vec<u8>),
+    /// Fields of group, start and end group tags are not included.
+    /// Without `with-groups` feature groups are skipped when parsing.
+    #[cfg(feature = "with-groups")]
+    Group(UnknownFields),
 }
 
 impl UnknownValue {
@@ -24,15 +36,20 @@ impl UnknownValue {
             UnknownValue::Fixed64(fixed64) => UnknownValueRef::Fixed64(fixed64),
             UnknownValue::Varint(varint) => UnknownValueRef::Varint(varint),
             UnknownValue::LengthDelimited(ref bytes) => UnknownValueRef::LengthDelimited(&bytes),
+            #[cfg(feature = "with-groups")]
+            UnknownValue::Group(ref fields) => UnknownValueRef::Group(fields),
         }
     }
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum UnknownValueRef<'o> {
     Fixed32(u32),
     Fixed64(u64),
     Varint(u64),
     LengthDelimited(&'o [u8]),
+    #[cfg(feature = "with-groups")]
+    Group(&'o UnknownFields),
 }
 
 impl<'o> UnknownValueRef<'o> {
@@ -42,8 +59,65 @@ impl<'o> UnknownValueRef<'o> {
             UnknownValueRef::Fixed64(_) => wire_format::WireTypeFixed64,
             UnknownValueRef::Varint(_) => wire_format::WireTypeVarint,
             UnknownValueRef::LengthDelimited(_) => wire_format::WireTypeLengthDelimited,
+            #[cfg(feature = "with-groups")]
+            UnknownValueRef::Group(_) => wire_format::WireTypeStartGroup,
         }
     }
+
+    // value in `UnknownFields::debug_dump` format, e. g. `varint 150`,
+    // group fields are listed in braces, e. g. `group {1: varint 150, 2: fixed32 7}`
+    pub(crate) fn debug_dump_to(&self, buf: &mut String) {
+        match *self {
+            UnknownValueRef::Fixed32(v) => write!(buf, "fixed32 {}", v).unwrap(),
+            UnknownValueRef::Fixed64(v) => write!(buf, "fixed64 {}", v).unwrap(),
+            UnknownValueRef::Varint(v) => write!(buf, "varint {}", v).unwrap(),
+            UnknownValueRef::LengthDelimited(bytes) => {
+                buf.push_str("bytes ");
+                for b in bytes {
+                    write!(buf, "{:02x}", b).unwrap();
+                }
+            }
+            #[cfg(feature = "with-groups")]
+            UnknownValueRef::Group(fields) => {
+                buf.push_str("group {");
+                for (i, (number, value)) in fields.sorted_values().into_iter().enumerate() {
+                    if i != 0 {
+                        buf.push_str(", ");
+                    }
+                    write!(buf, "{}: ", number).unwrap();
+                    value.debug_dump_to(buf);
+                }
+                buf.push('}');
+            }
+        }
+    }
+
+    fn length_delimited(&self) -> ProtobufResult<&'o [u8]> {
+        match *self {
+            UnknownValueRef::LengthDelimited(bytes) => Ok(bytes),
+            _ => Err(ProtobufError::WireError(WireError::UnexpectedWireType(self.wire_type()))),
+        }
+    }
+
+    /// Parse length-delimited value as nested message of unknown type,
+    /// so its fields can be inspected as unknown fields.
+    ///
+    /// Fails if value is not length-delimited or not a valid message.
+    /// Note that strings and bytes are often valid messages too.
+    pub fn parse_unknown_fields(&self) -> ProtobufResult<UnknownFields> {
+        let mut is = CodedInputStream::from_bytes(self.length_delimited()?);
+        let mut r = UnknownFields::new();
+        while !is.eof()? {
+            let (number, wire_type) = is.read_tag_unpack()?;
+            read_unknown_or_skip_group(number, wire_type, &mut is, &mut r)?;
+        }
+        Ok(r)
+    }
+
+    /// Parse length-delimited value as nested message of given type.
+    pub fn parse_message<M : Message>(&self) -> ProtobufResult<M> {
+        parse_from_bytes(self.length_delimited()?)
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Debug, Default)]
@@ -52,6 +126,8 @@ pub struct UnknownValues {
     pub fixed64: Vec<u64>,
     pub varint: Vec<u64>,
     pub length_delimited: Vec<Vec<u8>>,
+    #[cfg(feature = "with-groups")]
+    pub group: Vec<UnknownFields>,
 }
 
 impl UnknownValues {
@@ -63,6 +139,8 @@ impl UnknownValues {
             UnknownValue::LengthDelimited(length_delimited) => {
                 self.length_delimited.push(length_delimited)
             }
+            #[cfg(feature = "with-groups")]
+            UnknownValue::Group(group) => self.group.push(group),
         };
     }
 
@@ -72,6 +150,8 @@ impl UnknownValues {
             fixed64: self.fixed64.iter(),
             varint: self.varint.iter(),
             length_delimited: self.length_delimited.iter(),
+            #[cfg(feature = "with-groups")]
+            group: self.group.iter(),
         }
     }
 }
@@ -90,6 +170,8 @@ pub struct UnknownValuesIter<'o> {
     fixed64: slice::Iter<'o, u64>,
     varint: slice::Iter<'o, u64>,
     length_delimited: slice::Iter<'o, Vec<u8>>,
+    #[cfg(feature = "with-groups")]
+    group: slice::Iter<'o, UnknownFields>,
 }
 
 impl<'o> Iterator for UnknownValuesIter<'o> {
@@ -112,6 +194,13 @@ impl<'o> Iterator for UnknownValuesIter<'o> {
         if length_delimited.is_some() {
             return Some(UnknownValueRef::LengthDelimited(&length_delimited.unwrap()));
         }
+        #[cfg(feature = "with-groups")]
+        {
+            let group = self.group.next();
+            if group.is_some() {
+                return Some(UnknownValueRef::Group(group.unwrap()));
+            }
+        }
         None
     }
 }
@@ -161,20 +250,102 @@ impl UnknownFields {
             .push(length_delimited);
     }
 
+    /// Add group with given fields.
+    #[cfg(feature = "with-groups")]
+    pub fn add_group(&mut self, number: u32, group: UnknownFields) {
+        self.find_field(&number).group.push(group);
+    }
+
     pub fn add_value(&mut self, number: u32, value: UnknownValue) {
         self.find_field(&number).add_value(value);
     }
 
+    /// Append all values of other fields.
+    pub fn merge_from(&mut self, other: &UnknownFields) {
+        for (number, values) in other {
+            let field = self.find_field(&number);
+            field.fixed32.extend_from_slice(&values.fixed32);
+            field.fixed64.extend_from_slice(&values.fixed64);
+            field.varint.extend_from_slice(&values.varint);
+            field.length_delimited.extend(values.length_delimited.iter().cloned());
+            #[cfg(feature = "with-groups")]
+            field.group.extend(values.group.iter().cloned());
+        }
+    }
+
     pub fn iter<'s>(&'s self) -> UnknownFieldsIter<'s> {
         UnknownFieldsIter { entries: self.fields.as_ref().map(|m| m.iter()) }
     }
 
+    /// Iterate all values with their field numbers.
+    ///
+    /// Fields are returned in unspecified order, values of the same field
+    /// are grouped by wire type, each group in order of occurrence.
+    pub fn iter_values<'s>(&'s self) -> UnknownFieldsValuesIter<'s> {
+        UnknownFieldsValuesIter {
+            fields: self.iter(),
+            values: None,
+        }
+    }
+
+    // values sorted by field number, values of the same field
+    // are grouped by wire type like in `iter_values`
+    pub(crate) fn sorted_values<'s>(&'s self) -> Vec<(u32, UnknownValueRef<'s>)> {
+        let mut r: Vec<_> = self.iter_values().collect();
+        // sort is stable, so order of values of the same field is preserved
+        r.sort_by_key(|&(number, _)| number);
+        r
+    }
+
+    /// Readable listing of unknown fields for diagnostics, one value per line:
+    /// field number, wire type and value, e. g. `5: varint 150`.
+    /// Length-delimited values are printed as hex, e. g. `7: bytes 0a0161`,
+    /// and groups on the same line, e. g. `9: group {1: varint 150}`.
+    ///
+    /// Fields are sorted by number, and values of the same field are grouped
+    /// by wire type: fixed32, fixed64, varint, bytes, group. So the output depends
+    /// only on field values and is stable across runs and versions.
+    pub fn debug_dump(&self) -> String {
+        let mut r = String::new();
+        for (number, value) in self.sorted_values() {
+            if !r.is_empty() {
+                r.push('\n');
+            }
+            write!(r, "{}: ", number).unwrap();
+            value.debug_dump_to(&mut r);
+        }
+        r
+    }
+
     pub fn get(&self, field_number: u32) -> Option<&UnknownValues> {
         match self.fields {
             Some(ref map) => map.get(&field_number),
             None => None,
         }
     }
+
+    /// Remove all values of the field, returning them.
+    pub fn remove(&mut self, field_number: u32) -> Option<UnknownValues> {
+        match self.fields {
+            Some(ref mut map) => map.remove(&field_number),
+            None => None,
+        }
+    }
+
+    /// Keep only fields for which predicate returns `true`.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F : FnMut(u32, &UnknownValues) -> bool,
+    {
+        if let Some(ref mut map) = self.fields {
+            map.retain(|&number, values| f(number, values));
+        }
+    }
+
+    /// `true` if there are no unknown fields.
+    pub fn is_empty(&self) -> bool {
+        self.iter().all(|(_, values)| values.iter().next().is_none())
+    }
 }
 
 impl Clear for UnknownFields {
@@ -208,3 +379,143 @@ impl<'s> Iterator for UnknownFieldsIter<'s> {
         }
     }
 }
+
+/// Iterator returned by `UnknownFields::iter_values`.
+pub struct UnknownFieldsValuesIter<'s> {
+    fields: UnknownFieldsIter<'s>,
+    values: Option<(u32, UnknownValuesIter<'s>)>,
+}
+
+impl<'s> Iterator for UnknownFieldsValuesIter<'s> {
+    type Item = (u32, UnknownValueRef<'s>);
+
+    fn next(&mut self) -> Option<(u32, UnknownValueRef<'s>)> {
+        loop {
+            if let Some((number, ref mut values)) = self.values {
+                if let Some(value) = values.next() {
+                    return Some((number, value));
+                }
+            }
+            match self.fields.next() {
+                Some((number, values)) => self.values = Some((number, values.iter())),
+                None => return None,
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FileDescriptorProto;
+
+    fn fields() -> UnknownFields {
+        let mut fields = UnknownFields::new();
+        fields.add_varint(1, 10);
+        fields.add_fixed32(1, 11);
+        fields.add_varint(1, 12);
+        fields.add_fixed64(2, 20);
+        fields.add_length_delimited(3, vec![0x08, 0x01, 0x15, 1, 0, 0, 0]);
+        fields
+    }
+
+    #[test]
+    fn iter_values() {
+        let mut values: Vec<(u32, UnknownValueRef)> = Vec::new();
+        let fields = fields();
+        values.extend(fields.iter_values());
+        values.sort_by_key(|&(number, _)| number);
+        assert_eq!(
+            vec![
+                (1, UnknownValueRef::Fixed32(11)),
+                (1, UnknownValueRef::Varint(10)),
+                (1, UnknownValueRef::Varint(12)),
+                (2, UnknownValueRef::Fixed64(20)),
+                (3, UnknownValueRef::LengthDelimited(&[0x08, 0x01, 0x15, 1, 0, 0, 0])),
+            ],
+            values
+        );
+        assert_eq!(0, UnknownFields::new().iter_values().count());
+    }
+
+    #[test]
+    fn debug_dump() {
+        let mut fields = fields();
+        fields.add_length_delimited(0, Vec::new());
+        fields.add_varint(4, 3);
+        assert_eq!(
+            "0: bytes \n\
+             1: fixed32 11\n\
+             1: varint 10\n\
+             1: varint 12\n\
+             2: fixed64 20\n\
+             3: bytes 08011501000000\n\
+             4: varint 3",
+            fields.debug_dump()
+        );
+        assert_eq!("", UnknownFields::new().debug_dump());
+    }
+
+    #[cfg(feature = "with-groups")]
+    #[test]
+    fn debug_dump_groups() {
+        let mut fields = fields();
+        fields.add_length_delimited(0, Vec::new());
+        fields.add_group(4, UnknownFields::new());
+        let mut group = UnknownFields::new();
+        group.add_varint(2, 1);
+        group.add_varint(1, 2);
+        group.add_group(1, UnknownFields::new());
+        fields.add_group(4, group);
+        fields.add_varint(4, 3);
+        assert_eq!(
+            "0: bytes \n\
+             1: fixed32 11\n\
+             1: varint 10\n\
+             1: varint 12\n\
+             2: fixed64 20\n\
+             3: bytes 08011501000000\n\
+             4: varint 3\n\
+             4: group {}\n\
+             4: group {1: varint 2, 1: group {}, 2: varint 1}",
+            fields.debug_dump()
+        );
+    }
+
+    #[test]
+    fn remove_and_retain() {
+        let mut fields = fields();
+        assert_eq!(vec![10, 12], fields.remove(1).unwrap().varint);
+        assert!(fields.remove(1).is_none());
+        assert!(fields.get(1).is_none());
+        assert!(!fields.is_empty());
+
+        fields.retain(|number, _| number != 2);
+        assert!(fields.get(2).is_none());
+        assert!(fields.get(3).is_some());
+
+        fields.retain(|_, _| false);
+        assert!(fields.is_empty());
+        assert!(UnknownFields::new().is_empty());
+        assert!(UnknownFields::new().remove(1).is_none());
+    }
+
+    #[test]
+    fn parse_nested() {
+        let fields = fields();
+        let value = fields.get(3).unwrap().iter().next().unwrap();
+        let nested = value.parse_unknown_fields().unwrap();
+        assert_eq!(vec![1], nested.get(1).unwrap().varint);
+        assert_eq!(vec![1], nested.get(2).unwrap().fixed32);
+
+        assert!(UnknownValueRef::Varint(1).parse_unknown_fields().is_err());
+        assert!(UnknownValueRef::LengthDelimited(&[0x08]).parse_unknown_fields().is_err());
+
+        let mut file = FileDescriptorProto::new();
+        file.set_name("a.proto".to_owned());
+        let bytes = file.write_to_bytes().unwrap();
+        let value = UnknownValueRef::LengthDelimited(&bytes);
+        assert_eq!(file, value.parse_message().unwrap());
+    }
+}
@@ -0,0 +1,204 @@
+//! Shims with names of rust-protobuf 3 API.
+//!
+//! These allow code written against rust-protobuf 3 (or code being migrated to it)
+//! to work with this runtime. Generated code contains v3-style field getters
+//! when generated with `Customize::v3_compat` option.
+
+use std::fmt;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::io::Read;
+use std::marker::PhantomData;
+
+#[cfg(feature = "bytes")]
+use bytes::Bytes;
+
+use core::Message;
+use core::ProtobufEnum;
+use core::parse_from_bytes;
+use core::parse_from_reader;
+#[cfg(feature = "bytes")]
+use core::parse_from_carllerche_bytes;
+use error::ProtobufResult;
+use singular::SingularPtrField;
+
+
+/// Singular message field, v3 name of `SingularPtrField`.
+pub type MessageField<T> = SingularPtrField<T>;
+
+/// Methods of v3 `MessageField` missing in `SingularPtrField`.
+pub trait MessageFieldExt<T> {
+    /// Get a reference to the message or to the default instance if field is not set.
+    fn get_or_default(&self) -> &T;
+
+    /// Get a mutable reference to the message, initializing field with default if not set.
+    fn mut_or_insert_default(&mut self) -> &mut T;
+}
+
+impl<T : Message> MessageFieldExt<T> for SingularPtrField<T> {
+    fn get_or_default(&self) -> &T {
+        match self.as_ref() {
+            Some(v) => v,
+            None => T::default_instance(),
+        }
+    }
+
+    fn mut_or_insert_default(&mut self) -> &mut T {
+        if self.is_none() {
+            *self = SingularPtrField::some(T::new());
+        }
+        self.as_mut().unwrap()
+    }
+}
+
+
+/// Enum value which may be not known to the generated enum, as in v3.
+pub struct EnumOrUnknown<E : ProtobufEnum> {
+    value: i32,
+    _marker: PhantomData<E>,
+}
+
+impl<E : ProtobufEnum> EnumOrUnknown<E> {
+    /// Construct from known enum value.
+    pub fn new(e: E) -> EnumOrUnknown<E> {
+        EnumOrUnknown::from_i32(e.value())
+    }
+
+    /// Construct from any `i32` value.
+    pub fn from_i32(value: i32) -> EnumOrUnknown<E> {
+        EnumOrUnknown {
+            value: value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the `i32` value.
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    /// Get the enum value or `Err` with `i32` value if it is unknown.
+    pub fn enum_value(&self) -> Result<E, i32> {
+        E::from_i32(self.value).ok_or(self.value)
+    }
+
+    /// Get the enum value or `default` if value is unknown.
+    pub fn enum_value_or(&self, default: E) -> E {
+        self.enum_value().unwrap_or(default)
+    }
+
+    /// Get the enum value or the default enum value if value is unknown.
+    pub fn enum_value_or_default(&self) -> E
+    where
+        E : Default,
+    {
+        self.enum_value().unwrap_or_default()
+    }
+}
+
+impl<E : ProtobufEnum> From<E> for EnumOrUnknown<E> {
+    fn from(e: E) -> Self {
+        EnumOrUnknown::new(e)
+    }
+}
+
+impl<E : ProtobufEnum> Clone for EnumOrUnknown<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E : ProtobufEnum> Copy for EnumOrUnknown<E> {}
+
+impl<E : ProtobufEnum> PartialEq for EnumOrUnknown<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<E : ProtobufEnum> Eq for EnumOrUnknown<E> {}
+
+impl<E : ProtobufEnum> Hash for EnumOrUnknown<E> {
+    fn hash<H : Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<E : ProtobufEnum + Default> Default for EnumOrUnknown<E> {
+    fn default() -> Self {
+        EnumOrUnknown::new(E::default())
+    }
+}
+
+impl<E : ProtobufEnum + fmt::Debug> fmt::Debug for EnumOrUnknown<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.enum_value() {
+            Ok(e) => fmt::Debug::fmt(&e, f),
+            Err(v) => fmt::Debug::fmt(&v, f),
+        }
+    }
+}
+
+
+/// v3 associated functions of `Message`.
+pub trait MessageExt : Message + Sized {
+    /// Parse message from byte array.
+    fn parse_from_bytes(bytes: &[u8]) -> ProtobufResult<Self> {
+        parse_from_bytes(bytes)
+    }
+
+    /// Parse message from reader.
+    fn parse_from_reader(reader: &mut Read) -> ProtobufResult<Self> {
+        parse_from_reader(reader)
+    }
+
+    /// Parse message from `Bytes` object.
+    #[cfg(feature = "bytes")]
+    fn parse_from_tokio_bytes(bytes: &Bytes) -> ProtobufResult<Self> {
+        parse_from_carllerche_bytes(bytes)
+    }
+}
+
+impl<M : Message> MessageExt for M {}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use descriptor::FieldDescriptorProto;
+    use descriptor::FieldDescriptorProto_Type;
+    use descriptor::FieldOptions;
+
+    #[test]
+    fn enum_or_unknown() {
+        let known = EnumOrUnknown::new(FieldDescriptorProto_Type::TYPE_STRING);
+        assert_eq!(9, known.value());
+        assert_eq!(Ok(FieldDescriptorProto_Type::TYPE_STRING), known.enum_value());
+        assert_eq!("TYPE_STRING", format!("{:?}", known));
+
+        let unknown = EnumOrUnknown::<FieldDescriptorProto_Type>::from_i32(1000);
+        assert_eq!(Err(1000), unknown.enum_value());
+        assert_eq!(
+            FieldDescriptorProto_Type::TYPE_BOOL,
+            unknown.enum_value_or(FieldDescriptorProto_Type::TYPE_BOOL)
+        );
+        assert_eq!("1000", format!("{:?}", unknown));
+    }
+
+    #[test]
+    fn message_field() {
+        let mut field: MessageField<FieldOptions> = MessageField::none();
+        assert!(!field.get_or_default().get_packed());
+        field.mut_or_insert_default().set_packed(true);
+        assert!(field.get_or_default().get_packed());
+    }
+
+    #[test]
+    fn parse_from_bytes_associated() {
+        let mut field = FieldDescriptorProto::new();
+        field.set_name("f".to_owned());
+        let bytes = field.write_to_bytes().unwrap();
+        let parsed = FieldDescriptorProto::parse_from_bytes(&bytes).unwrap();
+        assert_eq!(field, parsed);
+    }
+}
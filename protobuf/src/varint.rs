@@ -1,3 +1,7 @@
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
+
 /// Encode u64 as varint.
 /// Panics if buffer length is less than 10.
 #[inline]
@@ -33,3 +37,83 @@ pub fn encode_varint32(mut value: u32, buf: &mut [u8]) -> usize {
         i + 1
     }
 }
+
+/// Decode varint from the start of `buf`.
+///
+/// Returns decoded value and varint length, or `None` if `buf` ends
+/// before the last byte of varint. Varint longer than 10 bytes is an error.
+#[inline]
+pub fn decode_varint64(buf: &[u8]) -> ProtobufResult<Option<(u64, usize)>> {
+    if buf.len() >= 10 {
+        unsafe { decode_varint64_unchecked(buf).map(Some) }
+    } else {
+        Ok(decode_varint64_short(buf))
+    }
+}
+
+// unrolled decoding without bounds checks, `buf` must have at least 10 bytes
+#[inline(always)]
+unsafe fn decode_varint64_unchecked(buf: &[u8]) -> ProtobufResult<(u64, usize)> {
+    macro_rules! byte {
+        ($buf:ident, $r:ident, $i:expr) => {
+            let b = *$buf.get_unchecked($i);
+            $r |= ((b & 0x7f) as u64) << ($i * 7);
+            if b < 0x80 {
+                return Ok(($r, $i + 1));
+            }
+        };
+    }
+
+    let mut r = 0;
+    byte!(buf, r, 0);
+    byte!(buf, r, 1);
+    byte!(buf, r, 2);
+    byte!(buf, r, 3);
+    byte!(buf, r, 4);
+    byte!(buf, r, 5);
+    byte!(buf, r, 6);
+    byte!(buf, r, 7);
+    byte!(buf, r, 8);
+    byte!(buf, r, 9);
+    Err(ProtobufError::WireError(WireError::IncorrectVarint))
+}
+
+// `buf` has less than 10 bytes
+fn decode_varint64_short(buf: &[u8]) -> Option<(u64, usize)> {
+    let mut r = 0;
+    for (i, &b) in buf.iter().enumerate() {
+        r |= ((b & 0x7f) as u64) << (i * 7);
+        if b < 0x80 {
+            return Some((r, i + 1));
+        }
+    }
+    None
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let values = [0, 1, 0x7f, 0x80, 0x3fff, 0x4000, 1 << 35, 1 << 62, u64::max_value()];
+        for &v in &values {
+            let mut buf = [0; 10];
+            let len = encode_varint64(v, &mut buf);
+            // short path
+            assert_eq!(Some((v, len)), decode_varint64(&buf[..len]).unwrap());
+            // unrolled path
+            let mut padded = buf.to_vec();
+            padded.extend_from_slice(&[0xff; 10]);
+            assert_eq!(Some((v, len)), decode_varint64(&padded).unwrap());
+        }
+    }
+
+    #[test]
+    fn decode_incomplete() {
+        assert_eq!(None, decode_varint64(&[]).unwrap());
+        assert_eq!(None, decode_varint64(&[0x80, 0x80]).unwrap());
+        assert!(decode_varint64(&[0x80; 10]).is_err());
+    }
+}
@@ -29,9 +29,15 @@ pub struct Any {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Any)
 }
 
 impl Any {
+    pub const FIELD_TYPE_URL_NUMBER: u32 = 1;
+    pub const FIELD_TYPE_URL_TAG: u32 = 10;
+    pub const FIELD_VALUE_NUMBER: u32 = 2;
+    pub const FIELD_VALUE_TAG: u32 = 18;
+
     pub fn new() -> Any {
         ::std::default::Default::default()
     }
@@ -87,6 +93,8 @@ impl Any {
     pub fn get_value(&self) -> &[u8] {
         &self.value
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Any)
 }
 
 impl ::protobuf::Message for Any {
@@ -112,6 +120,16 @@ impl ::protobuf::Message for Any {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.type_url.is_empty() {
+            self.type_url = other.type_url.clone();
+        }
+        if !other.value.is_empty() {
+            self.value = other.value.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -142,6 +160,47 @@ impl ::protobuf::Message for Any {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.type_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.type_url);
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.type_url.is_empty() {
+            os.write_string(1, &self.type_url)?;
+        }
+        if !self.value.is_empty() {
+            os.write_bytes(2, &self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.type_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.type_url) as u64;
+        }
+        if !self.value.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.value) as u64;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -160,49 +219,45 @@ impl ::protobuf::Message for Any {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Any>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Any {
         Any::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Any>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "type_url",
-                    |m: &Any| { &m.type_url },
-                    |m: &mut Any| { &mut m.type_url },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                    "value",
-                    |m: &Any| { &m.value },
-                    |m: &mut Any| { &mut m.value },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Any>(
-                    "Any",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Any {
+        static instance: ::protobuf::lazy::LazyV2<Any> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Any::new)
     }
+}
 
-    fn default_instance() -> &'static Any {
-        static mut instance: ::protobuf::lazy::Lazy<Any> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Any,
-        };
-        unsafe {
-            instance.get(Any::new)
-        }
+impl ::protobuf::MessageFull for Any {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type_url",
+                |m: &Any| { &m.type_url },
+                |m: &mut Any| { &mut m.type_url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "value",
+                |m: &Any| { &m.value },
+                |m: &mut Any| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Any>(
+                "Any",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -226,6 +281,14 @@ impl ::protobuf::reflect::ProtobufValue for Any {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Any {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Any> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x19google/protobuf/any.proto\x12\x0fgoogle.protobuf\"6\n\x03Any\x12\
     \x19\n\x08type_url\x18\x01\x20\x01(\tR\x07typeUrl\x12\x14\n\x05value\x18\
@@ -368,19 +431,16 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x05\x04\0\x02\x01\x03\x12\x04\x89\x01\x10\x11b\x06proto3\
 ";
 
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+static file_descriptor_proto_lazy: ::protobuf::lazy::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
     ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
-    }
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
+
+// @@protoc_insertion_point(module_scope)
@@ -34,9 +34,25 @@ pub struct Api {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Api)
 }
 
 impl Api {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_METHODS_NUMBER: u32 = 2;
+    pub const FIELD_METHODS_TAG: u32 = 18;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 3;
+    pub const FIELD_OPTIONS_TAG: u32 = 26;
+    pub const FIELD_VERSION_NUMBER: u32 = 4;
+    pub const FIELD_VERSION_TAG: u32 = 34;
+    pub const FIELD_SOURCE_CONTEXT_NUMBER: u32 = 5;
+    pub const FIELD_SOURCE_CONTEXT_TAG: u32 = 42;
+    pub const FIELD_MIXINS_NUMBER: u32 = 6;
+    pub const FIELD_MIXINS_TAG: u32 = 50;
+    pub const FIELD_SYNTAX_NUMBER: u32 = 7;
+    pub const FIELD_SYNTAX_TAG: u32 = 56;
+
     pub fn new() -> Api {
         ::std::default::Default::default()
     }
@@ -215,6 +231,8 @@ impl Api {
     pub fn get_syntax(&self) -> ::protobuf::well_known_types::Syntax {
         self.syntax
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Api)
 }
 
 impl ::protobuf::Message for Api {
@@ -275,6 +293,31 @@ impl ::protobuf::Message for Api {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        for v in &other.methods {
+            self.methods.push(v.clone());
+        };
+        for v in &other.options {
+            self.options.push(v.clone());
+        };
+        if !other.version.is_empty() {
+            self.version = other.version.clone();
+        }
+        if let Some(v) = other.source_context.as_ref() {
+            self.mut_source_context().merge_from_message(v);
+        }
+        for v in &other.mixins {
+            self.mixins.push(v.clone());
+        };
+        if other.syntax != ::protobuf::well_known_types::Syntax::SYNTAX_PROTO2 {
+            self.syntax = other.syntax.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -347,6 +390,108 @@ impl ::protobuf::Message for Api {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.methods {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.options {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.version);
+        }
+        if let Some(ref v) = self.source_context.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.mixins {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.syntax != ::protobuf::well_known_types::Syntax::SYNTAX_PROTO2 {
+            my_size += ::protobuf::rt::enum_size(7, self.syntax);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.methods {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.options {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if !self.version.is_empty() {
+            os.write_string(4, &self.version)?;
+        }
+        if let Some(ref v) = self.source_context.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        for v in &self.mixins {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if self.syntax != ::protobuf::well_known_types::Syntax::SYNTAX_PROTO2 {
+            os.write_enum(7, self.syntax.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name) as u64;
+        }
+        for value in &self.methods {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.options {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if !self.version.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.version) as u64;
+        }
+        if let Some(ref v) = self.source_context.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        for value in &self.mixins {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if self.syntax != ::protobuf::well_known_types::Syntax::SYNTAX_PROTO2 {
+            my_size += 11;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -365,74 +510,70 @@ impl ::protobuf::Message for Api {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Api>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Api {
         Api::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Api>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &Api| { &m.name },
-                    |m: &mut Api| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Method>>(
-                    "methods",
-                    |m: &Api| { &m.methods },
-                    |m: &mut Api| { &mut m.methods },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Option>>(
-                    "options",
-                    |m: &Api| { &m.options },
-                    |m: &mut Api| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "version",
-                    |m: &Api| { &m.version },
-                    |m: &mut Api| { &mut m.version },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::SourceContext>>(
-                    "source_context",
-                    |m: &Api| { &m.source_context },
-                    |m: &mut Api| { &mut m.source_context },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Mixin>>(
-                    "mixins",
-                    |m: &Api| { &m.mixins },
-                    |m: &mut Api| { &mut m.mixins },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<::protobuf::well_known_types::Syntax>>(
-                    "syntax",
-                    |m: &Api| { &m.syntax },
-                    |m: &mut Api| { &mut m.syntax },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Api>(
-                    "Api",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Api {
+        static instance: ::protobuf::lazy::LazyV2<Api> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Api::new)
     }
+}
 
-    fn default_instance() -> &'static Api {
-        static mut instance: ::protobuf::lazy::Lazy<Api> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Api,
-        };
-        unsafe {
-            instance.get(Api::new)
-        }
+impl ::protobuf::MessageFull for Api {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Api| { &m.name },
+                |m: &mut Api| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Method>>(
+                "methods",
+                |m: &Api| { &m.methods },
+                |m: &mut Api| { &mut m.methods },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Option>>(
+                "options",
+                |m: &Api| { &m.options },
+                |m: &mut Api| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "version",
+                |m: &Api| { &m.version },
+                |m: &mut Api| { &mut m.version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::SourceContext>>(
+                "source_context",
+                |m: &Api| { &m.source_context },
+                |m: &mut Api| { &mut m.source_context },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Mixin>>(
+                "mixins",
+                |m: &Api| { &m.mixins },
+                |m: &mut Api| { &mut m.mixins },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<::protobuf::well_known_types::Syntax>>(
+                "syntax",
+                |m: &Api| { &m.syntax },
+                |m: &mut Api| { &mut m.syntax },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Api>(
+                "Api",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -461,6 +602,14 @@ impl ::protobuf::reflect::ProtobufValue for Api {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Api {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Api> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Method {
     // message fields
@@ -474,9 +623,25 @@ pub struct Method {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Method)
 }
 
 impl Method {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_REQUEST_TYPE_URL_NUMBER: u32 = 2;
+    pub const FIELD_REQUEST_TYPE_URL_TAG: u32 = 18;
+    pub const FIELD_REQUEST_STREAMING_NUMBER: u32 = 3;
+    pub const FIELD_REQUEST_STREAMING_TAG: u32 = 24;
+    pub const FIELD_RESPONSE_TYPE_URL_NUMBER: u32 = 4;
+    pub const FIELD_RESPONSE_TYPE_URL_TAG: u32 = 34;
+    pub const FIELD_RESPONSE_STREAMING_NUMBER: u32 = 5;
+    pub const FIELD_RESPONSE_STREAMING_TAG: u32 = 40;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 6;
+    pub const FIELD_OPTIONS_TAG: u32 = 50;
+    pub const FIELD_SYNTAX_NUMBER: u32 = 7;
+    pub const FIELD_SYNTAX_TAG: u32 = 56;
+
     pub fn new() -> Method {
         ::std::default::Default::default()
     }
@@ -628,6 +793,8 @@ impl Method {
     pub fn get_syntax(&self) -> ::protobuf::well_known_types::Syntax {
         self.syntax
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Method)
 }
 
 impl ::protobuf::Message for Method {
@@ -681,6 +848,31 @@ impl ::protobuf::Message for Method {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if !other.request_type_url.is_empty() {
+            self.request_type_url = other.request_type_url.clone();
+        }
+        if other.request_streaming != false {
+            self.request_streaming = other.request_streaming.clone();
+        }
+        if !other.response_type_url.is_empty() {
+            self.response_type_url = other.response_type_url.clone();
+        }
+        if other.response_streaming != false {
+            self.response_streaming = other.response_streaming.clone();
+        }
+        for v in &other.options {
+            self.options.push(v.clone());
+        };
+        if other.syntax != ::protobuf::well_known_types::Syntax::SYNTAX_PROTO2 {
+            self.syntax = other.syntax.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -744,6 +936,96 @@ impl ::protobuf::Message for Method {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.request_type_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.request_type_url);
+        }
+        if self.request_streaming != false {
+            my_size += 2;
+        }
+        if !self.response_type_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.response_type_url);
+        }
+        if self.response_streaming != false {
+            my_size += 2;
+        }
+        for value in &self.options {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.syntax != ::protobuf::well_known_types::Syntax::SYNTAX_PROTO2 {
+            my_size += ::protobuf::rt::enum_size(7, self.syntax);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.request_type_url.is_empty() {
+            os.write_string(2, &self.request_type_url)?;
+        }
+        if self.request_streaming != false {
+            os.write_bool(3, self.request_streaming)?;
+        }
+        if !self.response_type_url.is_empty() {
+            os.write_string(4, &self.response_type_url)?;
+        }
+        if self.response_streaming != false {
+            os.write_bool(5, self.response_streaming)?;
+        }
+        for v in &self.options {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if self.syntax != ::protobuf::well_known_types::Syntax::SYNTAX_PROTO2 {
+            os.write_enum(7, self.syntax.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name) as u64;
+        }
+        if !self.request_type_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.request_type_url) as u64;
+        }
+        if self.request_streaming != false {
+            my_size += 2;
+        }
+        if !self.response_type_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.response_type_url) as u64;
+        }
+        if self.response_streaming != false {
+            my_size += 2;
+        }
+        for value in &self.options {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if self.syntax != ::protobuf::well_known_types::Syntax::SYNTAX_PROTO2 {
+            my_size += 11;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -762,74 +1044,70 @@ impl ::protobuf::Message for Method {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Method>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Method {
         Method::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Method>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &Method| { &m.name },
-                    |m: &mut Method| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "request_type_url",
-                    |m: &Method| { &m.request_type_url },
-                    |m: &mut Method| { &mut m.request_type_url },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "request_streaming",
-                    |m: &Method| { &m.request_streaming },
-                    |m: &mut Method| { &mut m.request_streaming },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "response_type_url",
-                    |m: &Method| { &m.response_type_url },
-                    |m: &mut Method| { &mut m.response_type_url },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "response_streaming",
-                    |m: &Method| { &m.response_streaming },
-                    |m: &mut Method| { &mut m.response_streaming },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Option>>(
-                    "options",
-                    |m: &Method| { &m.options },
-                    |m: &mut Method| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<::protobuf::well_known_types::Syntax>>(
-                    "syntax",
-                    |m: &Method| { &m.syntax },
-                    |m: &mut Method| { &mut m.syntax },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Method>(
-                    "Method",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Method {
+        static instance: ::protobuf::lazy::LazyV2<Method> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Method::new)
     }
+}
 
-    fn default_instance() -> &'static Method {
-        static mut instance: ::protobuf::lazy::Lazy<Method> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Method,
-        };
-        unsafe {
-            instance.get(Method::new)
-        }
+impl ::protobuf::MessageFull for Method {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Method| { &m.name },
+                |m: &mut Method| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "request_type_url",
+                |m: &Method| { &m.request_type_url },
+                |m: &mut Method| { &mut m.request_type_url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "request_streaming",
+                |m: &Method| { &m.request_streaming },
+                |m: &mut Method| { &mut m.request_streaming },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "response_type_url",
+                |m: &Method| { &m.response_type_url },
+                |m: &mut Method| { &mut m.response_type_url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "response_streaming",
+                |m: &Method| { &m.response_streaming },
+                |m: &mut Method| { &mut m.response_streaming },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Option>>(
+                "options",
+                |m: &Method| { &m.options },
+                |m: &mut Method| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<::protobuf::well_known_types::Syntax>>(
+                "syntax",
+                |m: &Method| { &m.syntax },
+                |m: &mut Method| { &mut m.syntax },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Method>(
+                "Method",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -858,6 +1136,14 @@ impl ::protobuf::reflect::ProtobufValue for Method {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Method {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Method> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Mixin {
     // message fields
@@ -866,9 +1152,15 @@ pub struct Mixin {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Mixin)
 }
 
 impl Mixin {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_ROOT_NUMBER: u32 = 2;
+    pub const FIELD_ROOT_TAG: u32 = 18;
+
     pub fn new() -> Mixin {
         ::std::default::Default::default()
     }
@@ -924,6 +1216,8 @@ impl Mixin {
     pub fn get_root(&self) -> &str {
         &self.root
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Mixin)
 }
 
 impl ::protobuf::Message for Mixin {
@@ -949,6 +1243,16 @@ impl ::protobuf::Message for Mixin {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if !other.root.is_empty() {
+            self.root = other.root.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -979,6 +1283,47 @@ impl ::protobuf::Message for Mixin {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if !self.root.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.root);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if !self.root.is_empty() {
+            os.write_string(2, &self.root)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name) as u64;
+        }
+        if !self.root.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.root) as u64;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -997,49 +1342,45 @@ impl ::protobuf::Message for Mixin {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Mixin>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Mixin {
         Mixin::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Mixin>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &Mixin| { &m.name },
-                    |m: &mut Mixin| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "root",
-                    |m: &Mixin| { &m.root },
-                    |m: &mut Mixin| { &mut m.root },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Mixin>(
-                    "Mixin",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Mixin {
+        static instance: ::protobuf::lazy::LazyV2<Mixin> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Mixin::new)
     }
+}
 
-    fn default_instance() -> &'static Mixin {
-        static mut instance: ::protobuf::lazy::Lazy<Mixin> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Mixin,
-        };
-        unsafe {
-            instance.get(Mixin::new)
-        }
+impl ::protobuf::MessageFull for Mixin {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Mixin| { &m.name },
+                |m: &mut Mixin| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "root",
+                |m: &Mixin| { &m.root },
+                |m: &mut Mixin| { &mut m.root },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Mixin>(
+                "Mixin",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -1063,6 +1404,14 @@ impl ::protobuf::reflect::ProtobufValue for Mixin {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Mixin {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Mixin> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x19google/protobuf/api.proto\x12\x0fgoogle.protobuf\x1a$google/protob\
     uf/source_context.proto\x1a\x1agoogle/protobuf/type.proto\"\xc1\x02\n\
@@ -1294,19 +1643,16 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x12\x04\xc7\x01\x10\x11b\x06proto3\
 ";
 
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+static file_descriptor_proto_lazy: ::protobuf::lazy::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
     ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
-    }
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
+
+// @@protoc_insertion_point(module_scope)
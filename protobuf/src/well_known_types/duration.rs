@@ -29,9 +29,15 @@ pub struct Duration {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Duration)
 }
 
 impl Duration {
+    pub const FIELD_SECONDS_NUMBER: u32 = 1;
+    pub const FIELD_SECONDS_TAG: u32 = 8;
+    pub const FIELD_NANOS_NUMBER: u32 = 2;
+    pub const FIELD_NANOS_TAG: u32 = 16;
+
     pub fn new() -> Duration {
         ::std::default::Default::default()
     }
@@ -65,6 +71,8 @@ impl Duration {
     pub fn get_nanos(&self) -> i32 {
         self.nanos
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Duration)
 }
 
 impl ::protobuf::Message for Duration {
@@ -98,6 +106,16 @@ impl ::protobuf::Message for Duration {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.seconds != 0 {
+            self.seconds = other.seconds.clone();
+        }
+        if other.nanos != 0 {
+            self.nanos = other.nanos.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -128,6 +146,47 @@ impl ::protobuf::Message for Duration {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if self.seconds != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.seconds, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.nanos != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.nanos, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if self.seconds != 0 {
+            os.write_int64(1, self.seconds)?;
+        }
+        if self.nanos != 0 {
+            os.write_int32(2, self.nanos)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if self.seconds != 0 {
+            my_size += 11;
+        }
+        if self.nanos != 0 {
+            my_size += 11;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -146,49 +205,45 @@ impl ::protobuf::Message for Duration {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Duration>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Duration {
         Duration::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Duration>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                    "seconds",
-                    |m: &Duration| { &m.seconds },
-                    |m: &mut Duration| { &mut m.seconds },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "nanos",
-                    |m: &Duration| { &m.nanos },
-                    |m: &mut Duration| { &mut m.nanos },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Duration>(
-                    "Duration",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Duration {
+        static instance: ::protobuf::lazy::LazyV2<Duration> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Duration::new)
     }
+}
 
-    fn default_instance() -> &'static Duration {
-        static mut instance: ::protobuf::lazy::Lazy<Duration> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Duration,
-        };
-        unsafe {
-            instance.get(Duration::new)
-        }
+impl ::protobuf::MessageFull for Duration {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "seconds",
+                |m: &Duration| { &m.seconds },
+                |m: &mut Duration| { &mut m.seconds },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "nanos",
+                |m: &Duration| { &m.nanos },
+                |m: &mut Duration| { &mut m.nanos },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Duration>(
+                "Duration",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -212,6 +267,14 @@ impl ::protobuf::reflect::ProtobufValue for Duration {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Duration {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Duration> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x1egoogle/protobuf/duration.proto\x12\x0fgoogle.protobuf\":\n\x08Dura\
     tion\x12\x18\n\x07seconds\x18\x01\x20\x01(\x03R\x07seconds\x12\x14\n\x05\
@@ -336,19 +399,16 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     3\
 ";
 
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+static file_descriptor_proto_lazy: ::protobuf::lazy::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
     ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
-    }
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
+
+// @@protoc_insertion_point(module_scope)
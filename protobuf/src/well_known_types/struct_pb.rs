@@ -28,9 +28,13 @@ pub struct Struct {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Struct)
 }
 
 impl Struct {
+    pub const FIELD_FIELDS_NUMBER: u32 = 1;
+    pub const FIELD_FIELDS_TAG: u32 = 10;
+
     pub fn new() -> Struct {
         ::std::default::Default::default()
     }
@@ -59,6 +63,8 @@ impl Struct {
     pub fn get_fields(&self) -> &::std::collections::HashMap<::std::string::String, Value> {
         &self.fields
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Struct)
 }
 
 impl ::protobuf::Message for Struct {
@@ -81,6 +87,13 @@ impl ::protobuf::Message for Struct {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for (k, v) in &other.fields {
+            self.fields.insert(k.clone(), v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -101,6 +114,32 @@ impl ::protobuf::Message for Struct {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size_with_cache::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Value>>(1, &self.fields, sizes);
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        ::protobuf::rt::write_map_with_size_cache::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Value>>(1, &self.fields, os, sizes)?;
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::compute_map_size::<::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Value>>(1, &self.fields) as u64;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -119,44 +158,40 @@ impl ::protobuf::Message for Struct {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Struct>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Struct {
         Struct::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Struct>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Value>>(
-                    "fields",
-                    |m: &Struct| { &m.fields },
-                    |m: &mut Struct| { &mut m.fields },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Struct>(
-                    "Struct",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Struct {
+        static instance: ::protobuf::lazy::LazyV2<Struct> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Struct::new)
     }
+}
 
-    fn default_instance() -> &'static Struct {
-        static mut instance: ::protobuf::lazy::Lazy<Struct> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Struct,
-        };
-        unsafe {
-            instance.get(Struct::new)
-        }
+impl ::protobuf::MessageFull for Struct {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_map_accessor::<_, ::protobuf::types::ProtobufTypeString, ::protobuf::types::ProtobufTypeMessage<Value>>(
+                "fields",
+                |m: &Struct| { &m.fields },
+                |m: &mut Struct| { &mut m.fields },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Struct>(
+                "Struct",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -179,13 +214,22 @@ impl ::protobuf::reflect::ProtobufValue for Struct {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Struct {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Struct> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Value {
     // message oneof groups
-    kind: ::std::option::Option<Value_oneof_kind>,
+    pub kind: ::std::option::Option<Value_oneof_kind>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Value)
 }
 
 #[derive(Clone,PartialEq)]
@@ -198,7 +242,56 @@ pub enum Value_oneof_kind {
     list_value(ListValue),
 }
 
+impl ::std::convert::From<NullValue> for Value_oneof_kind {
+    fn from(v: NullValue) -> Value_oneof_kind {
+        Value_oneof_kind::null_value(v)
+    }
+}
+
+impl ::std::convert::From<f64> for Value_oneof_kind {
+    fn from(v: f64) -> Value_oneof_kind {
+        Value_oneof_kind::number_value(v)
+    }
+}
+
+impl ::std::convert::From<::std::string::String> for Value_oneof_kind {
+    fn from(v: ::std::string::String) -> Value_oneof_kind {
+        Value_oneof_kind::string_value(v)
+    }
+}
+
+impl ::std::convert::From<bool> for Value_oneof_kind {
+    fn from(v: bool) -> Value_oneof_kind {
+        Value_oneof_kind::bool_value(v)
+    }
+}
+
+impl ::std::convert::From<Struct> for Value_oneof_kind {
+    fn from(v: Struct) -> Value_oneof_kind {
+        Value_oneof_kind::struct_value(v)
+    }
+}
+
+impl ::std::convert::From<ListValue> for Value_oneof_kind {
+    fn from(v: ListValue) -> Value_oneof_kind {
+        Value_oneof_kind::list_value(v)
+    }
+}
+
 impl Value {
+    pub const FIELD_NULL_VALUE_NUMBER: u32 = 1;
+    pub const FIELD_NULL_VALUE_TAG: u32 = 8;
+    pub const FIELD_NUMBER_VALUE_NUMBER: u32 = 2;
+    pub const FIELD_NUMBER_VALUE_TAG: u32 = 17;
+    pub const FIELD_STRING_VALUE_NUMBER: u32 = 3;
+    pub const FIELD_STRING_VALUE_TAG: u32 = 26;
+    pub const FIELD_BOOL_VALUE_NUMBER: u32 = 4;
+    pub const FIELD_BOOL_VALUE_TAG: u32 = 32;
+    pub const FIELD_STRUCT_VALUE_NUMBER: u32 = 5;
+    pub const FIELD_STRUCT_VALUE_TAG: u32 = 42;
+    pub const FIELD_LIST_VALUE_NUMBER: u32 = 6;
+    pub const FIELD_LIST_VALUE_TAG: u32 = 50;
+
     pub fn new() -> Value {
         ::std::default::Default::default()
     }
@@ -424,20 +517,25 @@ impl Value {
             _ => ListValue::default_instance(),
         }
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Value)
 }
 
 impl ::protobuf::Message for Value {
     fn is_initialized(&self) -> bool {
-        if let Some(Value_oneof_kind::struct_value(ref v)) = self.kind {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
-        if let Some(Value_oneof_kind::list_value(ref v)) = self.kind {
-            if !v.is_initialized() {
-                return false;
-            }
-        }
+        match self.kind {
+            ::std::option::Option::Some(Value_oneof_kind::struct_value(ref v)) => {
+                if !v.is_initialized() {
+                    return false;
+                }
+            },
+            ::std::option::Option::Some(Value_oneof_kind::list_value(ref v)) => {
+                if !v.is_initialized() {
+                    return false;
+                }
+            },
+            _ => {},
+        };
         true
     }
 
@@ -489,6 +587,13 @@ impl ::protobuf::Message for Value {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.kind.is_some() {
+            self.kind = other.kind.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -557,6 +662,103 @@ impl ::protobuf::Message for Value {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Value_oneof_kind::null_value(v) => {
+                    my_size += ::protobuf::rt::enum_size(1, v);
+                },
+                &Value_oneof_kind::number_value(v) => {
+                    my_size += 9;
+                },
+                &Value_oneof_kind::string_value(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v);
+                },
+                &Value_oneof_kind::bool_value(v) => {
+                    my_size += 2;
+                },
+                &Value_oneof_kind::struct_value(ref v) => {
+                    let len = v.compute_size_with_cache(sizes);
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+                &Value_oneof_kind::list_value(ref v) => {
+                    let len = v.compute_size_with_cache(sizes);
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Value_oneof_kind::null_value(v) => {
+                    os.write_enum(1, v.value())?;
+                },
+                &Value_oneof_kind::number_value(v) => {
+                    os.write_double(2, v)?;
+                },
+                &Value_oneof_kind::string_value(ref v) => {
+                    os.write_string(3, v)?;
+                },
+                &Value_oneof_kind::bool_value(v) => {
+                    os.write_bool(4, v)?;
+                },
+                &Value_oneof_kind::struct_value(ref v) => {
+                    os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(sizes.take_size())?;
+                    v.write_to_with_size_cache(os, sizes)?;
+                },
+                &Value_oneof_kind::list_value(ref v) => {
+                    os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+                    os.write_raw_varint32(sizes.take_size())?;
+                    v.write_to_with_size_cache(os, sizes)?;
+                },
+            };
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if let ::std::option::Option::Some(ref v) = self.kind {
+            match v {
+                &Value_oneof_kind::null_value(v) => {
+                    my_size += 11;
+                },
+                &Value_oneof_kind::number_value(v) => {
+                    my_size += 9;
+                },
+                &Value_oneof_kind::string_value(ref v) => {
+                    my_size += ::protobuf::rt::string_size(3, &v) as u64;
+                },
+                &Value_oneof_kind::bool_value(v) => {
+                    my_size += 2;
+                },
+                &Value_oneof_kind::struct_value(ref v) => {
+                    let len = v.size_hint_upper_bound();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+                },
+                &Value_oneof_kind::list_value(ref v) => {
+                    let len = v.size_hint_upper_bound();
+                    my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+                },
+            };
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -575,80 +777,85 @@ impl ::protobuf::Message for Value {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Value>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Value {
         Value::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Value>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, NullValue>(
-                    "null_value",
-                    Value::has_null_value,
-                    Value::get_null_value,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor::<_>(
-                    "number_value",
-                    Value::has_number_value,
-                    Value::get_number_value,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
-                    "string_value",
-                    Value::has_string_value,
-                    Value::get_string_value,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
-                    "bool_value",
-                    Value::has_bool_value,
-                    Value::get_bool_value,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Struct>(
-                    "struct_value",
-                    Value::has_struct_value,
-                    Value::get_struct_value,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListValue>(
-                    "list_value",
-                    Value::has_list_value,
-                    Value::get_list_value,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Value>(
-                    "Value",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Value {
+        static instance: ::protobuf::lazy::LazyV2<Value> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Value::new)
     }
+}
 
-    fn default_instance() -> &'static Value {
-        static mut instance: ::protobuf::lazy::Lazy<Value> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Value,
-        };
-        unsafe {
-            instance.get(Value::new)
-        }
+impl ::protobuf::MessageFull for Value {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_enum_accessor::<_, NullValue>(
+                "null_value",
+                Value::has_null_value,
+                Value::get_null_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_f64_accessor::<_>(
+                "number_value",
+                Value::has_number_value,
+                Value::get_number_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_string_accessor::<_>(
+                "string_value",
+                Value::has_string_value,
+                Value::get_string_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_bool_accessor::<_>(
+                "bool_value",
+                Value::has_bool_value,
+                Value::get_bool_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, Struct>(
+                "struct_value",
+                Value::has_struct_value,
+                Value::get_struct_value,
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_message_accessor::<_, ListValue>(
+                "list_value",
+                Value::has_list_value,
+                Value::get_list_value,
+            ));
+            let mut oneofs = ::std::vec::Vec::new();
+            oneofs.push(::protobuf::reflect::accessor::make_oneof_accessor(
+                "kind",
+                |m: &Value| match m.kind {
+                    ::std::option::Option::Some(Value_oneof_kind::null_value(..)) => ::std::option::Option::Some(1),
+                    ::std::option::Option::Some(Value_oneof_kind::number_value(..)) => ::std::option::Option::Some(2),
+                    ::std::option::Option::Some(Value_oneof_kind::string_value(..)) => ::std::option::Option::Some(3),
+                    ::std::option::Option::Some(Value_oneof_kind::bool_value(..)) => ::std::option::Option::Some(4),
+                    ::std::option::Option::Some(Value_oneof_kind::struct_value(..)) => ::std::option::Option::Some(5),
+                    ::std::option::Option::Some(Value_oneof_kind::list_value(..)) => ::std::option::Option::Some(6),
+                    _ => ::std::option::Option::None,
+                },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_with_oneofs::<Value>(
+                "Value",
+                fields,
+                oneofs,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
 impl ::protobuf::Clear for Value {
     fn clear(&mut self) {
-        self.clear_null_value();
-        self.clear_number_value();
-        self.clear_string_value();
-        self.clear_bool_value();
-        self.clear_struct_value();
-        self.clear_list_value();
+        self.kind = ::std::option::Option::None;
         self.unknown_fields.clear();
     }
 }
@@ -665,6 +872,14 @@ impl ::protobuf::reflect::ProtobufValue for Value {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Value {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Value> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct ListValue {
     // message fields
@@ -672,9 +887,13 @@ pub struct ListValue {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.ListValue)
 }
 
 impl ListValue {
+    pub const FIELD_VALUES_NUMBER: u32 = 1;
+    pub const FIELD_VALUES_TAG: u32 = 10;
+
     pub fn new() -> ListValue {
         ::std::default::Default::default()
     }
@@ -703,6 +922,8 @@ impl ListValue {
     pub fn get_values(&self) -> &[Value] {
         &self.values
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.ListValue)
 }
 
 impl ::protobuf::Message for ListValue {
@@ -730,6 +951,13 @@ impl ::protobuf::Message for ListValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        for v in &other.values {
+            self.values.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -757,6 +985,42 @@ impl ::protobuf::Message for ListValue {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        for value in &self.values {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.values {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        for value in &self.values {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -775,44 +1039,40 @@ impl ::protobuf::Message for ListValue {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ListValue>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> ListValue {
         ListValue::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ListValue>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Value>>(
-                    "values",
-                    |m: &ListValue| { &m.values },
-                    |m: &mut ListValue| { &mut m.values },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<ListValue>(
-                    "ListValue",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static ListValue {
+        static instance: ::protobuf::lazy::LazyV2<ListValue> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(ListValue::new)
     }
+}
 
-    fn default_instance() -> &'static ListValue {
-        static mut instance: ::protobuf::lazy::Lazy<ListValue> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ListValue,
-        };
-        unsafe {
-            instance.get(ListValue::new)
-        }
+impl ::protobuf::MessageFull for ListValue {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Value>>(
+                "values",
+                |m: &ListValue| { &m.values },
+                |m: &mut ListValue| { &mut m.values },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<ListValue>(
+                "ListValue",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -835,6 +1095,14 @@ impl ::protobuf::reflect::ProtobufValue for ListValue {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for ListValue {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<ListValue> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum NullValue {
     NULL_VALUE = 0,
@@ -859,16 +1127,21 @@ impl ::protobuf::ProtobufEnum for NullValue {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<NullValue>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("NullValue", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for NullValue {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("NullValue", file_descriptor_proto())
+        })
     }
 }
 
@@ -883,7 +1156,7 @@ impl ::std::default::Default for NullValue {
 
 impl ::protobuf::reflect::ProtobufValue for NullValue {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -1026,19 +1299,16 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x1bb\x06proto3\
 ";
 
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+static file_descriptor_proto_lazy: ::protobuf::lazy::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
     ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
-    }
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
+
+// @@protoc_insertion_point(module_scope)
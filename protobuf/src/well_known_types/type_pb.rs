@@ -33,9 +33,23 @@ pub struct Type {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Type)
 }
 
 impl Type {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_FIELDS_NUMBER: u32 = 2;
+    pub const FIELD_FIELDS_TAG: u32 = 18;
+    pub const FIELD_ONEOFS_NUMBER: u32 = 3;
+    pub const FIELD_ONEOFS_TAG: u32 = 26;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 4;
+    pub const FIELD_OPTIONS_TAG: u32 = 34;
+    pub const FIELD_SOURCE_CONTEXT_NUMBER: u32 = 5;
+    pub const FIELD_SOURCE_CONTEXT_TAG: u32 = 42;
+    pub const FIELD_SYNTAX_NUMBER: u32 = 6;
+    pub const FIELD_SYNTAX_TAG: u32 = 48;
+
     pub fn new() -> Type {
         ::std::default::Default::default()
     }
@@ -188,6 +202,8 @@ impl Type {
     pub fn get_syntax(&self) -> Syntax {
         self.syntax
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Type)
 }
 
 impl ::protobuf::Message for Type {
@@ -240,6 +256,28 @@ impl ::protobuf::Message for Type {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        for v in &other.fields {
+            self.fields.push(v.clone());
+        };
+        for v in &other.oneofs {
+            self.oneofs.push(v.clone());
+        };
+        for v in &other.options {
+            self.options.push(v.clone());
+        };
+        if let Some(v) = other.source_context.as_ref() {
+            self.mut_source_context().merge_from_message(v);
+        }
+        if other.syntax != Syntax::SYNTAX_PROTO2 {
+            self.syntax = other.syntax.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -303,6 +341,95 @@ impl ::protobuf::Message for Type {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.fields {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.oneofs {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        for value in &self.options {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.source_context.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.syntax != Syntax::SYNTAX_PROTO2 {
+            my_size += ::protobuf::rt::enum_size(6, self.syntax);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.fields {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.oneofs {
+            os.write_string(3, &v)?;
+        };
+        for v in &self.options {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if let Some(ref v) = self.source_context.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        if self.syntax != Syntax::SYNTAX_PROTO2 {
+            os.write_enum(6, self.syntax.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name) as u64;
+        }
+        for value in &self.fields {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.oneofs {
+            my_size += ::protobuf::rt::string_size(3, &value) as u64;
+        };
+        for value in &self.options {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if let Some(ref v) = self.source_context.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        if self.syntax != Syntax::SYNTAX_PROTO2 {
+            my_size += 11;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -321,69 +448,65 @@ impl ::protobuf::Message for Type {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Type>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Type {
         Type::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Type>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &Type| { &m.name },
-                    |m: &mut Type| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Field>>(
-                    "fields",
-                    |m: &Type| { &m.fields },
-                    |m: &mut Type| { &mut m.fields },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "oneofs",
-                    |m: &Type| { &m.oneofs },
-                    |m: &mut Type| { &mut m.oneofs },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Option>>(
-                    "options",
-                    |m: &Type| { &m.options },
-                    |m: &mut Type| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::SourceContext>>(
-                    "source_context",
-                    |m: &Type| { &m.source_context },
-                    |m: &mut Type| { &mut m.source_context },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Syntax>>(
-                    "syntax",
-                    |m: &Type| { &m.syntax },
-                    |m: &mut Type| { &mut m.syntax },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Type>(
-                    "Type",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Type {
+        static instance: ::protobuf::lazy::LazyV2<Type> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Type::new)
     }
+}
 
-    fn default_instance() -> &'static Type {
-        static mut instance: ::protobuf::lazy::Lazy<Type> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Type,
-        };
-        unsafe {
-            instance.get(Type::new)
-        }
+impl ::protobuf::MessageFull for Type {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Type| { &m.name },
+                |m: &mut Type| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Field>>(
+                "fields",
+                |m: &Type| { &m.fields },
+                |m: &mut Type| { &mut m.fields },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "oneofs",
+                |m: &Type| { &m.oneofs },
+                |m: &mut Type| { &mut m.oneofs },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Option>>(
+                "options",
+                |m: &Type| { &m.options },
+                |m: &mut Type| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::SourceContext>>(
+                "source_context",
+                |m: &Type| { &m.source_context },
+                |m: &mut Type| { &mut m.source_context },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Syntax>>(
+                "syntax",
+                |m: &Type| { &m.syntax },
+                |m: &mut Type| { &mut m.syntax },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Type>(
+                "Type",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -411,6 +534,14 @@ impl ::protobuf::reflect::ProtobufValue for Type {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Type {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Type> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Field {
     // message fields
@@ -427,9 +558,31 @@ pub struct Field {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Field)
 }
 
 impl Field {
+    pub const FIELD_KIND_NUMBER: u32 = 1;
+    pub const FIELD_KIND_TAG: u32 = 8;
+    pub const FIELD_CARDINALITY_NUMBER: u32 = 2;
+    pub const FIELD_CARDINALITY_TAG: u32 = 16;
+    pub const FIELD_NUMBER_NUMBER: u32 = 3;
+    pub const FIELD_NUMBER_TAG: u32 = 24;
+    pub const FIELD_NAME_NUMBER: u32 = 4;
+    pub const FIELD_NAME_TAG: u32 = 34;
+    pub const FIELD_TYPE_URL_NUMBER: u32 = 6;
+    pub const FIELD_TYPE_URL_TAG: u32 = 50;
+    pub const FIELD_ONEOF_INDEX_NUMBER: u32 = 7;
+    pub const FIELD_ONEOF_INDEX_TAG: u32 = 56;
+    pub const FIELD_PACKED_NUMBER: u32 = 8;
+    pub const FIELD_PACKED_TAG: u32 = 64;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 9;
+    pub const FIELD_OPTIONS_TAG: u32 = 74;
+    pub const FIELD_JSON_NAME_NUMBER: u32 = 10;
+    pub const FIELD_JSON_NAME_TAG: u32 = 82;
+    pub const FIELD_DEFAULT_VALUE_NUMBER: u32 = 11;
+    pub const FIELD_DEFAULT_VALUE_TAG: u32 = 90;
+
     pub fn new() -> Field {
         ::std::default::Default::default()
     }
@@ -637,6 +790,8 @@ impl Field {
     pub fn get_default_value(&self) -> &str {
         &self.default_value
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Field)
 }
 
 impl ::protobuf::Message for Field {
@@ -703,6 +858,40 @@ impl ::protobuf::Message for Field {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if other.kind != Field_Kind::TYPE_UNKNOWN {
+            self.kind = other.kind.clone();
+        }
+        if other.cardinality != Field_Cardinality::CARDINALITY_UNKNOWN {
+            self.cardinality = other.cardinality.clone();
+        }
+        if other.number != 0 {
+            self.number = other.number.clone();
+        }
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if !other.type_url.is_empty() {
+            self.type_url = other.type_url.clone();
+        }
+        if other.oneof_index != 0 {
+            self.oneof_index = other.oneof_index.clone();
+        }
+        if other.packed != false {
+            self.packed = other.packed.clone();
+        }
+        for v in &other.options {
+            self.options.push(v.clone());
+        };
+        if !other.json_name.is_empty() {
+            self.json_name = other.json_name.clone();
+        }
+        if !other.default_value.is_empty() {
+            self.default_value = other.default_value.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -784,6 +973,123 @@ impl ::protobuf::Message for Field {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if self.kind != Field_Kind::TYPE_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(1, self.kind);
+        }
+        if self.cardinality != Field_Cardinality::CARDINALITY_UNKNOWN {
+            my_size += ::protobuf::rt::enum_size(2, self.cardinality);
+        }
+        if self.number != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.name);
+        }
+        if !self.type_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.type_url);
+        }
+        if self.oneof_index != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.oneof_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.packed != false {
+            my_size += 2;
+        }
+        for value in &self.options {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.json_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.json_name);
+        }
+        if !self.default_value.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.default_value);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if self.kind != Field_Kind::TYPE_UNKNOWN {
+            os.write_enum(1, self.kind.value())?;
+        }
+        if self.cardinality != Field_Cardinality::CARDINALITY_UNKNOWN {
+            os.write_enum(2, self.cardinality.value())?;
+        }
+        if self.number != 0 {
+            os.write_int32(3, self.number)?;
+        }
+        if !self.name.is_empty() {
+            os.write_string(4, &self.name)?;
+        }
+        if !self.type_url.is_empty() {
+            os.write_string(6, &self.type_url)?;
+        }
+        if self.oneof_index != 0 {
+            os.write_int32(7, self.oneof_index)?;
+        }
+        if self.packed != false {
+            os.write_bool(8, self.packed)?;
+        }
+        for v in &self.options {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if !self.json_name.is_empty() {
+            os.write_string(10, &self.json_name)?;
+        }
+        if !self.default_value.is_empty() {
+            os.write_string(11, &self.default_value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if self.kind != Field_Kind::TYPE_UNKNOWN {
+            my_size += 11;
+        }
+        if self.cardinality != Field_Cardinality::CARDINALITY_UNKNOWN {
+            my_size += 11;
+        }
+        if self.number != 0 {
+            my_size += 11;
+        }
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(4, &self.name) as u64;
+        }
+        if !self.type_url.is_empty() {
+            my_size += ::protobuf::rt::string_size(6, &self.type_url) as u64;
+        }
+        if self.oneof_index != 0 {
+            my_size += 11;
+        }
+        if self.packed != false {
+            my_size += 2;
+        }
+        for value in &self.options {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if !self.json_name.is_empty() {
+            my_size += ::protobuf::rt::string_size(10, &self.json_name) as u64;
+        }
+        if !self.default_value.is_empty() {
+            my_size += ::protobuf::rt::string_size(11, &self.default_value) as u64;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -802,89 +1108,85 @@ impl ::protobuf::Message for Field {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Field>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Field {
         Field::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Field>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Field_Kind>>(
-                    "kind",
-                    |m: &Field| { &m.kind },
-                    |m: &mut Field| { &mut m.kind },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Field_Cardinality>>(
-                    "cardinality",
-                    |m: &Field| { &m.cardinality },
-                    |m: &mut Field| { &mut m.cardinality },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "number",
-                    |m: &Field| { &m.number },
-                    |m: &mut Field| { &mut m.number },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &Field| { &m.name },
-                    |m: &mut Field| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "type_url",
-                    |m: &Field| { &m.type_url },
-                    |m: &mut Field| { &mut m.type_url },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "oneof_index",
-                    |m: &Field| { &m.oneof_index },
-                    |m: &mut Field| { &mut m.oneof_index },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "packed",
-                    |m: &Field| { &m.packed },
-                    |m: &mut Field| { &mut m.packed },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Option>>(
-                    "options",
-                    |m: &Field| { &m.options },
-                    |m: &mut Field| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "json_name",
-                    |m: &Field| { &m.json_name },
-                    |m: &mut Field| { &mut m.json_name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "default_value",
-                    |m: &Field| { &m.default_value },
-                    |m: &mut Field| { &mut m.default_value },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Field>(
-                    "Field",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Field {
+        static instance: ::protobuf::lazy::LazyV2<Field> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Field::new)
     }
+}
 
-    fn default_instance() -> &'static Field {
-        static mut instance: ::protobuf::lazy::Lazy<Field> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Field,
-        };
-        unsafe {
-            instance.get(Field::new)
-        }
+impl ::protobuf::MessageFull for Field {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Field_Kind>>(
+                "kind",
+                |m: &Field| { &m.kind },
+                |m: &mut Field| { &mut m.kind },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Field_Cardinality>>(
+                "cardinality",
+                |m: &Field| { &m.cardinality },
+                |m: &mut Field| { &mut m.cardinality },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "number",
+                |m: &Field| { &m.number },
+                |m: &mut Field| { &mut m.number },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Field| { &m.name },
+                |m: &mut Field| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "type_url",
+                |m: &Field| { &m.type_url },
+                |m: &mut Field| { &mut m.type_url },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "oneof_index",
+                |m: &Field| { &m.oneof_index },
+                |m: &mut Field| { &mut m.oneof_index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "packed",
+                |m: &Field| { &m.packed },
+                |m: &mut Field| { &mut m.packed },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Option>>(
+                "options",
+                |m: &Field| { &m.options },
+                |m: &mut Field| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "json_name",
+                |m: &Field| { &m.json_name },
+                |m: &mut Field| { &mut m.json_name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "default_value",
+                |m: &Field| { &m.default_value },
+                |m: &mut Field| { &mut m.default_value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Field>(
+                "Field",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -916,6 +1218,14 @@ impl ::protobuf::reflect::ProtobufValue for Field {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Field {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Field> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum Field_Kind {
     TYPE_UNKNOWN = 0,
@@ -994,16 +1304,21 @@ impl ::protobuf::ProtobufEnum for Field_Kind {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<Field_Kind>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Field_Kind", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for Field_Kind {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("Field_Kind", file_descriptor_proto())
+        })
     }
 }
 
@@ -1018,7 +1333,7 @@ impl ::std::default::Default for Field_Kind {
 
 impl ::protobuf::reflect::ProtobufValue for Field_Kind {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -1055,16 +1370,21 @@ impl ::protobuf::ProtobufEnum for Field_Cardinality {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<Field_Cardinality>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Field_Cardinality", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for Field_Cardinality {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("Field_Cardinality", file_descriptor_proto())
+        })
     }
 }
 
@@ -1079,7 +1399,7 @@ impl ::std::default::Default for Field_Cardinality {
 
 impl ::protobuf::reflect::ProtobufValue for Field_Cardinality {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -1094,9 +1414,21 @@ pub struct Enum {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Enum)
 }
 
 impl Enum {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_ENUMVALUE_NUMBER: u32 = 2;
+    pub const FIELD_ENUMVALUE_TAG: u32 = 18;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 3;
+    pub const FIELD_OPTIONS_TAG: u32 = 26;
+    pub const FIELD_SOURCE_CONTEXT_NUMBER: u32 = 4;
+    pub const FIELD_SOURCE_CONTEXT_TAG: u32 = 34;
+    pub const FIELD_SYNTAX_NUMBER: u32 = 5;
+    pub const FIELD_SYNTAX_TAG: u32 = 40;
+
     pub fn new() -> Enum {
         ::std::default::Default::default()
     }
@@ -1224,6 +1556,8 @@ impl Enum {
     pub fn get_syntax(&self) -> Syntax {
         self.syntax
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Enum)
 }
 
 impl ::protobuf::Message for Enum {
@@ -1273,6 +1607,25 @@ impl ::protobuf::Message for Enum {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        for v in &other.enumvalue {
+            self.enumvalue.push(v.clone());
+        };
+        for v in &other.options {
+            self.options.push(v.clone());
+        };
+        if let Some(v) = other.source_context.as_ref() {
+            self.mut_source_context().merge_from_message(v);
+        }
+        if other.syntax != Syntax::SYNTAX_PROTO2 {
+            self.syntax = other.syntax.clone();
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1330,6 +1683,86 @@ impl ::protobuf::Message for Enum {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        for value in &self.enumvalue {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.options {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.source_context.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.syntax != Syntax::SYNTAX_PROTO2 {
+            my_size += ::protobuf::rt::enum_size(5, self.syntax);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        for v in &self.enumvalue {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        for v in &self.options {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        if let Some(ref v) = self.source_context.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        if self.syntax != Syntax::SYNTAX_PROTO2 {
+            os.write_enum(5, self.syntax.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name) as u64;
+        }
+        for value in &self.enumvalue {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        for value in &self.options {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        if let Some(ref v) = self.source_context.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        if self.syntax != Syntax::SYNTAX_PROTO2 {
+            my_size += 11;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -1348,64 +1781,60 @@ impl ::protobuf::Message for Enum {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Enum>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Enum {
         Enum::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Enum>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &Enum| { &m.name },
-                    |m: &mut Enum| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumValue>>(
-                    "enumvalue",
-                    |m: &Enum| { &m.enumvalue },
-                    |m: &mut Enum| { &mut m.enumvalue },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Option>>(
-                    "options",
-                    |m: &Enum| { &m.options },
-                    |m: &mut Enum| { &mut m.options },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::SourceContext>>(
-                    "source_context",
-                    |m: &Enum| { &m.source_context },
-                    |m: &mut Enum| { &mut m.source_context },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Syntax>>(
-                    "syntax",
-                    |m: &Enum| { &m.syntax },
-                    |m: &mut Enum| { &mut m.syntax },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Enum>(
-                    "Enum",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Enum {
+        static instance: ::protobuf::lazy::LazyV2<Enum> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Enum::new)
     }
+}
 
-    fn default_instance() -> &'static Enum {
-        static mut instance: ::protobuf::lazy::Lazy<Enum> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Enum,
-        };
-        unsafe {
-            instance.get(Enum::new)
-        }
+impl ::protobuf::MessageFull for Enum {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Enum| { &m.name },
+                |m: &mut Enum| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<EnumValue>>(
+                "enumvalue",
+                |m: &Enum| { &m.enumvalue },
+                |m: &mut Enum| { &mut m.enumvalue },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Option>>(
+                "options",
+                |m: &Enum| { &m.options },
+                |m: &mut Enum| { &mut m.options },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::SourceContext>>(
+                "source_context",
+                |m: &Enum| { &m.source_context },
+                |m: &mut Enum| { &mut m.source_context },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Syntax>>(
+                "syntax",
+                |m: &Enum| { &m.syntax },
+                |m: &mut Enum| { &mut m.syntax },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Enum>(
+                "Enum",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -1432,6 +1861,14 @@ impl ::protobuf::reflect::ProtobufValue for Enum {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Enum {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Enum> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct EnumValue {
     // message fields
@@ -1441,9 +1878,17 @@ pub struct EnumValue {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.EnumValue)
 }
 
 impl EnumValue {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_NUMBER_NUMBER: u32 = 2;
+    pub const FIELD_NUMBER_TAG: u32 = 16;
+    pub const FIELD_OPTIONS_NUMBER: u32 = 3;
+    pub const FIELD_OPTIONS_TAG: u32 = 26;
+
     pub fn new() -> EnumValue {
         ::std::default::Default::default()
     }
@@ -1513,6 +1958,8 @@ impl EnumValue {
     pub fn get_options(&self) -> &[Option] {
         &self.options
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.EnumValue)
 }
 
 impl ::protobuf::Message for EnumValue {
@@ -1550,6 +1997,19 @@ impl ::protobuf::Message for EnumValue {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if other.number != 0 {
+            self.number = other.number.clone();
+        }
+        for v in &other.options {
+            self.options.push(v.clone());
+        };
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1589,6 +2049,60 @@ impl ::protobuf::Message for EnumValue {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if self.number != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.number, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.options {
+            let len = value.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if self.number != 0 {
+            os.write_int32(2, self.number)?;
+        }
+        for v in &self.options {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name) as u64;
+        }
+        if self.number != 0 {
+            my_size += 11;
+        }
+        for value in &self.options {
+            let len = value.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -1607,54 +2121,50 @@ impl ::protobuf::Message for EnumValue {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<EnumValue>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> EnumValue {
         EnumValue::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<EnumValue>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &EnumValue| { &m.name },
-                    |m: &mut EnumValue| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
-                    "number",
-                    |m: &EnumValue| { &m.number },
-                    |m: &mut EnumValue| { &mut m.number },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Option>>(
-                    "options",
-                    |m: &EnumValue| { &m.options },
-                    |m: &mut EnumValue| { &mut m.options },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<EnumValue>(
-                    "EnumValue",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static EnumValue {
+        static instance: ::protobuf::lazy::LazyV2<EnumValue> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(EnumValue::new)
     }
+}
 
-    fn default_instance() -> &'static EnumValue {
-        static mut instance: ::protobuf::lazy::Lazy<EnumValue> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const EnumValue,
-        };
-        unsafe {
-            instance.get(EnumValue::new)
-        }
+impl ::protobuf::MessageFull for EnumValue {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &EnumValue| { &m.name },
+                |m: &mut EnumValue| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt32>(
+                "number",
+                |m: &EnumValue| { &m.number },
+                |m: &mut EnumValue| { &mut m.number },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Option>>(
+                "options",
+                |m: &EnumValue| { &m.options },
+                |m: &mut EnumValue| { &mut m.options },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<EnumValue>(
+                "EnumValue",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -1679,6 +2189,14 @@ impl ::protobuf::reflect::ProtobufValue for EnumValue {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for EnumValue {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<EnumValue> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Option {
     // message fields
@@ -1687,9 +2205,15 @@ pub struct Option {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
+    // @@protoc_insertion_point(message:google.protobuf.Option)
 }
 
 impl Option {
+    pub const FIELD_NAME_NUMBER: u32 = 1;
+    pub const FIELD_NAME_TAG: u32 = 10;
+    pub const FIELD_VALUE_NUMBER: u32 = 2;
+    pub const FIELD_VALUE_TAG: u32 = 18;
+
     pub fn new() -> Option {
         ::std::default::Default::default()
     }
@@ -1752,6 +2276,8 @@ impl Option {
     pub fn get_value(&self) -> &::protobuf::well_known_types::Any {
         self.value.as_ref().unwrap_or_else(|| ::protobuf::well_known_types::Any::default_instance())
     }
+
+    // @@protoc_insertion_point(message_impl:google.protobuf.Option)
 }
 
 impl ::protobuf::Message for Option {
@@ -1782,6 +2308,16 @@ impl ::protobuf::Message for Option {
         ::std::result::Result::Ok(())
     }
 
+    fn merge_from_message(&mut self, other: &Self) {
+        if !other.name.is_empty() {
+            self.name = other.name.clone();
+        }
+        if let Some(v) = other.value.as_ref() {
+            self.mut_value().merge_from_message(v);
+        }
+        self.unknown_fields.merge_from(&other.unknown_fields);
+    }
+
     // Compute sizes of nested messages
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
@@ -1815,6 +2351,51 @@ impl ::protobuf::Message for Option {
         self.cached_size.get()
     }
 
+    // Compute sizes of nested messages storing them in `sizes`
+    #[allow(unused_variables)]
+    fn compute_size_with_cache(&self, sizes: &mut ::protobuf::SizeCache) -> u32 {
+        let slot = sizes.reserve_slot();
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name);
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            let len = v.compute_size_with_cache(sizes);
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        sizes.set_slot(slot, my_size);
+        my_size
+    }
+
+    #[allow(unused_variables)]
+    fn write_to_with_size_cache(&self, os: &mut ::protobuf::CodedOutputStream, sizes: &mut ::protobuf::SizeCache) -> ::protobuf::ProtobufResult<()> {
+        if !self.name.is_empty() {
+            os.write_string(1, &self.name)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(sizes.take_size())?;
+            v.write_to_with_size_cache(os, sizes)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    #[allow(unused_variables)]
+    fn size_hint_upper_bound(&self) -> u64 {
+        let mut my_size = 0;
+        if !self.name.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.name) as u64;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            let len = v.size_hint_upper_bound();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint64_size(len) as u64 + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields()) as u64;
+        my_size
+    }
+
     fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
         &self.unknown_fields
     }
@@ -1833,49 +2414,45 @@ impl ::protobuf::Message for Option {
         self
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::Message::descriptor_static(None::<Self>)
+    fn descriptor_dyn(&self) -> ::std::option::Option<&'static ::protobuf::reflect::MessageDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::MessageFull>::type_descriptor())
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Option>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        <Self as ::protobuf::MessageFull>::type_descriptor()
     }
 
     fn new() -> Option {
         Option::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Option>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    |m: &Option| { &m.name },
-                    |m: &mut Option| { &mut m.name },
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
-                    "value",
-                    |m: &Option| { &m.value },
-                    |m: &mut Option| { &mut m.value },
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Option>(
-                    "Option",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    fn default_instance() -> &'static Option {
+        static instance: ::protobuf::lazy::LazyV2<Option> = ::protobuf::lazy::LazyV2::INIT;
+        instance.get(Option::new)
     }
+}
 
-    fn default_instance() -> &'static Option {
-        static mut instance: ::protobuf::lazy::Lazy<Option> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Option,
-        };
-        unsafe {
-            instance.get(Option::new)
-        }
+impl ::protobuf::MessageFull for Option {
+    fn type_descriptor() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "name",
+                |m: &Option| { &m.name },
+                |m: &mut Option| { &mut m.name },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<::protobuf::well_known_types::Any>>(
+                "value",
+                |m: &Option| { &m.value },
+                |m: &mut Option| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new::<Option>(
+                "Option",
+                fields,
+                file_descriptor_proto()
+            )
+        })
     }
 }
 
@@ -1899,6 +2476,14 @@ impl ::protobuf::reflect::ProtobufValue for Option {
     }
 }
 
+impl<'a> ::std::convert::TryFrom<&'a [u8]> for Option {
+    type Error = ::protobuf::ProtobufError;
+
+    fn try_from(bytes: &'a [u8]) -> ::protobuf::ProtobufResult<Option> {
+        ::protobuf::parse_from_bytes(bytes)
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum Syntax {
     SYNTAX_PROTO2 = 0,
@@ -1926,16 +2511,21 @@ impl ::protobuf::ProtobufEnum for Syntax {
         values
     }
 
+    fn enum_descriptor_dyn() -> ::std::option::Option<&'static ::protobuf::reflect::EnumDescriptor> {
+        ::std::option::Option::Some(<Self as ::protobuf::ProtobufEnumFull>::type_descriptor())
+    }
+
     fn enum_descriptor_static(_: ::std::option::Option<Syntax>) -> &'static ::protobuf::reflect::EnumDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                ::protobuf::reflect::EnumDescriptor::new("Syntax", file_descriptor_proto())
-            })
-        }
+        <Self as ::protobuf::ProtobufEnumFull>::type_descriptor()
+    }
+}
+
+impl ::protobuf::ProtobufEnumFull for Syntax {
+    fn type_descriptor() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::lazy::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new("Syntax", file_descriptor_proto())
+        })
     }
 }
 
@@ -1950,7 +2540,7 @@ impl ::std::default::Default for Syntax {
 
 impl ::protobuf::reflect::ProtobufValue for Syntax {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+        ::protobuf::reflect::ProtobufValueRef::Enum(::protobuf::ProtobufEnumFull::value_descriptor(self))
     }
 }
 
@@ -2262,19 +2852,16 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x06proto3\
 ";
 
-static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
-    lock: ::protobuf::lazy::ONCE_INIT,
-    ptr: 0 as *const ::protobuf::descriptor::FileDescriptorProto,
-};
+static file_descriptor_proto_lazy: ::protobuf::lazy::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::LazyV2::INIT;
 
 fn parse_descriptor_proto() -> ::protobuf::descriptor::FileDescriptorProto {
     ::protobuf::parse_from_bytes(file_descriptor_proto_data).unwrap()
 }
 
 pub fn file_descriptor_proto() -> &'static ::protobuf::descriptor::FileDescriptorProto {
-    unsafe {
-        file_descriptor_proto_lazy.get(|| {
-            parse_descriptor_proto()
-        })
-    }
+    file_descriptor_proto_lazy.get(|| {
+        parse_descriptor_proto()
+    })
 }
+
+// @@protoc_insertion_point(module_scope)
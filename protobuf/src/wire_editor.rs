@@ -0,0 +1,279 @@
+//! Editing of serialized messages without parsing.
+//!
+//! `WireEditor` scans top-level fields of serialized message, and
+//! produces a new message with selected fields removed, replaced or added.
+//! Other fields are copied as is, and nested messages and groups are not decoded,
+//! so e. g. a proxy can rewrite header fields of a large payload cheaply:
+//!
+//! ```ignore
+//! let mut editor = WireEditor::new(&request)?;
+//! editor.replace(1, UnknownValueRef::LengthDelimited(b"rewritten"));
+//! editor.remove(5);
+//! let request = editor.to_bytes();
+//! ```
+
+use std::io::Write;
+
+use error::ProtobufError;
+use error::ProtobufResult;
+use error::WireError;
+use rt::tag_size;
+use stream::wire_format;
+use stream::CodedInputStream;
+use stream::CodedOutputStream;
+use unknown::UnknownValueRef;
+use wire_format::WireType;
+
+
+/// Value of top-level field of serialized message.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WireValue<'a> {
+    Fixed32(u32),
+    Fixed64(u64),
+    Varint(u64),
+    /// Strings, bytes, nested messages and packed repeated fields.
+    LengthDelimited(&'a [u8]),
+    /// Serialized fields of group, without start and end group tags.
+    Group(&'a [u8]),
+}
+
+impl<'a> WireValue<'a> {
+    pub fn wire_type(&self) -> WireType {
+        match *self {
+            WireValue::Fixed32(..) => wire_format::WireTypeFixed32,
+            WireValue::Fixed64(..) => wire_format::WireTypeFixed64,
+            WireValue::Varint(..) => wire_format::WireTypeVarint,
+            WireValue::LengthDelimited(..) => wire_format::WireTypeLengthDelimited,
+            WireValue::Group(..) => wire_format::WireTypeStartGroup,
+        }
+    }
+}
+
+/// Top-level field of serialized message.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WireField<'a> {
+    pub number: u32,
+    /// Value of the field.
+    pub value: WireValue<'a>,
+    /// Serialized field including tag, and end group tag for groups.
+    pub data: &'a [u8],
+}
+
+#[derive(Debug)]
+enum Edit {
+    Keep,
+    Remove,
+    Replace(Vec<u8>),
+}
+
+/// Editor of top-level fields of serialized message.
+///
+/// Edits are applied in `to_bytes` or `write_to_writer`.
+/// Field order is preserved: replaced fields are written in place
+/// of the original, and added fields are written after all fields
+/// of the original message.
+#[derive(Debug)]
+pub struct WireEditor<'a> {
+    input: &'a [u8],
+    fields: Vec<WireField<'a>>,
+    // offset of field in input
+    offsets: Vec<usize>,
+    edits: Vec<Edit>,
+    // serialized added fields with their numbers
+    added: Vec<(u32, Vec<u8>)>,
+}
+
+fn serialize_field(number: u32, value: UnknownValueRef) -> Vec<u8> {
+    let mut r = Vec::new();
+    {
+        let mut os = CodedOutputStream::vec(&mut r);
+        // writing to vec cannot fail
+        os.write_unknown(number, value).unwrap();
+        os.flush().unwrap();
+    }
+    r
+}
+
+impl<'a> WireEditor<'a> {
+    /// Scan fields of serialized message.
+    ///
+    /// Fails if message is malformed, only tags and lengths of top-level fields
+    /// and tags of groups are checked.
+    pub fn new(input: &'a [u8]) -> ProtobufResult<WireEditor<'a>> {
+        let mut fields = Vec::new();
+        let mut offsets = Vec::new();
+
+        let mut is = CodedInputStream::from_bytes(input);
+        while !is.eof()? {
+            let start = is.pos() as usize;
+            let (number, wire_type) = is.read_tag_unpack()?;
+            let value = match wire_type {
+                wire_format::WireTypeVarint => WireValue::Varint(is.read_raw_varint64()?),
+                wire_format::WireTypeFixed64 => WireValue::Fixed64(is.read_fixed64()?),
+                wire_format::WireTypeFixed32 => WireValue::Fixed32(is.read_fixed32()?),
+                wire_format::WireTypeLengthDelimited => {
+                    let len = is.read_raw_varint32()?;
+                    let pos = is.pos() as usize;
+                    is.skip_raw_bytes(len)?;
+                    WireValue::LengthDelimited(&input[pos..pos + len as usize])
+                }
+                wire_format::WireTypeStartGroup => {
+                    let pos = is.pos() as usize;
+                    is.skip_group(number)?;
+                    // end group tag has the same number as start tag
+                    let end = is.pos() as usize - tag_size(number) as usize;
+                    WireValue::Group(&input[pos..end])
+                }
+                _ => {
+                    return Err(ProtobufError::WireError(
+                        WireError::UnexpectedWireType(wire_type),
+                    ))
+                }
+            };
+            fields.push(WireField {
+                number: number,
+                value: value,
+                data: &input[start..is.pos() as usize],
+            });
+            offsets.push(start);
+        }
+
+        let edits = fields.iter().map(|_| Edit::Keep).collect();
+        Ok(WireEditor {
+            input: input,
+            fields: fields,
+            offsets: offsets,
+            edits: edits,
+            added: Vec::new(),
+        })
+    }
+
+    /// Fields of the original message in order of occurrence, edits are not reflected.
+    pub fn fields(&self) -> &[WireField<'a>] {
+        &self.fields
+    }
+
+    /// Last occurrence of the field in the original message,
+    /// which is the value of singular field when message is parsed.
+    pub fn get(&self, number: u32) -> Option<&WireField<'a>> {
+        self.fields.iter().rev().find(|f| f.number == number)
+    }
+
+    /// Remove all occurrences of the field, including added ones.
+    pub fn remove(&mut self, number: u32) {
+        for (field, edit) in self.fields.iter().zip(self.edits.iter_mut()) {
+            if field.number == number {
+                *edit = Edit::Remove;
+            }
+        }
+        self.added.retain(|&(n, _)| n != number);
+    }
+
+    /// Replace field with a single value.
+    ///
+    /// Value is written in place of the first occurrence of the field,
+    /// other occurrences are removed. If the field does not exist, it is added.
+    pub fn replace(&mut self, number: u32, value: UnknownValueRef) {
+        let first = self.fields.iter().position(|f| f.number == number);
+        self.remove(number);
+        match first {
+            Some(i) => self.edits[i] = Edit::Replace(serialize_field(number, value)),
+            None => self.add(number, value),
+        }
+    }
+
+    /// Add field value after all fields of the original message.
+    ///
+    /// For singular fields this overrides the original value,
+    /// repeated fields get an additional element.
+    pub fn add(&mut self, number: u32, value: UnknownValueRef) {
+        self.added.push((number, serialize_field(number, value)));
+    }
+
+    /// Parts of the result: unchanged ranges of input are not split.
+    fn chunks(&self) -> Vec<&[u8]> {
+        let mut r = Vec::new();
+        // start of unchanged range of input
+        let mut keep_start = None;
+        for (i, edit) in self.edits.iter().enumerate() {
+            match *edit {
+                Edit::Keep => {
+                    if keep_start.is_none() {
+                        keep_start = Some(self.offsets[i]);
+                    }
+                }
+                Edit::Remove | Edit::Replace(..) => {
+                    if let Some(start) = keep_start.take() {
+                        r.push(&self.input[start..self.offsets[i]]);
+                    }
+                    if let Edit::Replace(ref data) = *edit {
+                        r.push(&data[..]);
+                    }
+                }
+            }
+        }
+        if let Some(start) = keep_start {
+            r.push(&self.input[start..]);
+        }
+        for &(_, ref data) in &self.added {
+            r.push(&data[..]);
+        }
+        r
+    }
+
+    /// Serialized message with edits applied.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let chunks = self.chunks();
+        let mut r = Vec::with_capacity(chunks.iter().map(|c| c.len()).sum());
+        for chunk in chunks {
+            r.extend_from_slice(chunk);
+        }
+        r
+    }
+
+    /// Write message with edits applied.
+    pub fn write_to_writer(&self, w: &mut Write) -> ProtobufResult<()> {
+        for chunk in self.chunks() {
+            w.write_all(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn chunks() {
+        // fields 1, 2, 3 and 3 with varint values
+        let bytes = [0x08, 0x01, 0x10, 0x02, 0x18, 0x03, 0x18, 0x04];
+        let editor = WireEditor::new(&bytes).unwrap();
+        assert_eq!(1, editor.chunks().len());
+
+        // fields 1 and 2 are copied as single chunk
+        let mut editor = WireEditor::new(&bytes).unwrap();
+        editor.remove(3);
+        assert_eq!(vec![&bytes[..4]], editor.chunks());
+
+        let mut editor = WireEditor::new(&bytes).unwrap();
+        editor.replace(2, UnknownValueRef::Varint(5));
+        editor.add(4, UnknownValueRef::Varint(6));
+        assert_eq!(4, editor.chunks().len());
+        assert_eq!(
+            &[0x08, 0x01, 0x10, 0x05, 0x18, 0x03, 0x18, 0x04, 0x20, 0x06],
+            &editor.to_bytes()[..]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        // end group tag without start tag
+        assert!(WireEditor::new(&[0x0c]).is_err());
+        // group without end tag
+        assert!(WireEditor::new(&[0x0b, 0x08, 0x01]).is_err());
+        // truncated length-delimited field
+        assert!(WireEditor::new(&[0x0a, 0x02, 0x00]).is_err());
+    }
+}
@@ -0,0 +1,150 @@
+//! Access fields of wrapper types (`google.protobuf.Int32Value` etc.) as `Option`.
+//!
+//! Generated code represents wrapper fields as `SingularPtrField<Int32Value>`,
+//! which is how proto3 expresses nullable scalars. Generated messages have
+//! `get_foo_opt()` and `set_foo_opt()` accessors for such fields; for exposed fields
+//! `WrapperFieldExt` allows reading and writing them as `Option<i32>` directly:
+//!
+//! ```
+//! # use protobuf::SingularPtrField;
+//! # use protobuf::well_known_types::Int32Value;
+//! use protobuf::wrapper_option::WrapperFieldExt;
+//!
+//! let mut field: SingularPtrField<Int32Value> = SingularPtrField::none();
+//! assert_eq!(None, field.to_option());
+//! field.set_option(Some(10));
+//! assert_eq!(Some(10), field.to_option());
+//! ```
+
+use core::Message;
+use singular::SingularPtrField;
+use well_known_types::BoolValue;
+use well_known_types::BytesValue;
+use well_known_types::DoubleValue;
+use well_known_types::FloatValue;
+use well_known_types::Int32Value;
+use well_known_types::Int64Value;
+use well_known_types::StringValue;
+use well_known_types::UInt32Value;
+use well_known_types::UInt64Value;
+
+
+/// Well-known message wrapping a single value.
+pub trait WrapperValue : Message {
+    /// Type of `value` field.
+    type Value : Clone;
+
+    /// Wrap a value.
+    fn from_value(value: Self::Value) -> Self;
+
+    /// Unwrap a value.
+    fn into_value(self) -> Self::Value;
+
+    /// Reference to wrapped value.
+    fn value_ref(&self) -> &Self::Value;
+}
+
+macro_rules! wrapper_value {
+    ($wrapper:ident, $value:ty) => {
+        impl WrapperValue for $wrapper {
+            type Value = $value;
+
+            fn from_value(value: $value) -> $wrapper {
+                let mut r = $wrapper::new();
+                r.value = value;
+                r
+            }
+
+            fn into_value(self) -> $value {
+                self.value
+            }
+
+            fn value_ref(&self) -> &$value {
+                &self.value
+            }
+        }
+    };
+}
+
+wrapper_value!(DoubleValue, f64);
+wrapper_value!(FloatValue, f32);
+wrapper_value!(Int64Value, i64);
+wrapper_value!(UInt64Value, u64);
+wrapper_value!(Int32Value, i32);
+wrapper_value!(UInt32Value, u32);
+wrapper_value!(BoolValue, bool);
+wrapper_value!(StringValue, String);
+wrapper_value!(BytesValue, Vec<u8>);
+
+
+/// Methods of wrapper fields operating on wrapped values.
+pub trait WrapperFieldExt<W : WrapperValue> {
+    /// Field with given value, unset if value is `None`.
+    fn from_option(value: Option<W::Value>) -> Self;
+
+    /// Copy of wrapped value, `None` if field is not set.
+    fn to_option(&self) -> Option<W::Value>;
+
+    /// Reference to wrapped value, `None` if field is not set.
+    fn as_option_ref(&self) -> Option<&W::Value>;
+
+    /// Set field to wrapped value or clear it if value is `None`.
+    fn set_option(&mut self, value: Option<W::Value>);
+
+    /// Take wrapped value leaving field unset.
+    fn take_option(&mut self) -> Option<W::Value>;
+}
+
+impl<W : WrapperValue> WrapperFieldExt<W> for SingularPtrField<W> {
+    fn from_option(value: Option<W::Value>) -> SingularPtrField<W> {
+        SingularPtrField::from_option(value.map(W::from_value))
+    }
+
+    fn to_option(&self) -> Option<W::Value> {
+        self.as_option_ref().cloned()
+    }
+
+    fn as_option_ref(&self) -> Option<&W::Value> {
+        self.as_ref().map(W::value_ref)
+    }
+
+    fn set_option(&mut self, value: Option<W::Value>) {
+        *self = <SingularPtrField<W> as WrapperFieldExt<W>>::from_option(value);
+    }
+
+    fn take_option(&mut self) -> Option<W::Value> {
+        self.take().map(W::into_value)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scalar() {
+        let mut field: SingularPtrField<Int32Value> = WrapperFieldExt::from_option(Some(0));
+        // zero value is distinct from unset field
+        assert!(field.is_some());
+        assert_eq!(Some(0), field.to_option());
+
+        field.set_option(None);
+        assert!(field.is_none());
+        assert_eq!(None, field.to_option());
+
+        field.set_option(Some(-3));
+        assert_eq!(-3, field.get_ref().get_value());
+        assert_eq!(Some(-3), field.take_option());
+        assert!(field.is_none());
+    }
+
+    #[test]
+    fn string() {
+        let mut field: SingularPtrField<StringValue> = SingularPtrField::none();
+        assert_eq!(None, field.as_option_ref());
+        field.set_option(Some("abc".to_owned()));
+        assert_eq!(Some("abc"), field.as_option_ref().map(|s| &s[..]));
+        assert_eq!(Some("abc".to_owned()), field.take_option());
+    }
+}